@@ -1,11 +1,21 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::Arc;
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+};
+
+use parking_lot::RwLock;
 
 use crate::{
-    block::{Block, BlockAPI, BlockRef, BlockV1, Round},
+    base_committer::{BaseCommitter, BaseCommitterOptions},
+    block::{Block, BlockAPI, BlockRef, BlockV1, Round, VerifiedBlock},
+    commit::{Commit, LeaderStatus},
     context::Context,
+    dag_state::DagState,
+    leader_schedule::LeaderSchedule,
+    storage::Store,
     threshold_clock::ThresholdClock,
 };
 
@@ -16,18 +26,52 @@ pub(crate) struct Core {
     context: Arc<Context>,
     threshold_clock: ThresholdClock,
     last_own_block: Block,
+    /// The leader schedule, shared with the committer.
+    leader_schedule: LeaderSchedule,
+    /// Decides whether the leaders are committed or skipped.
+    committer: BaseCommitter,
+    /// The round of the last leader decided by the committer.
+    last_decided_leader: Round,
+    /// The last commit, from which the next commit is indexed and linearized.
+    last_commit: Option<Commit>,
+    dag_state: Arc<RwLock<DagState>>,
+    store: Arc<dyn Store>,
 }
 
 #[allow(dead_code)]
 impl Core {
-    pub(crate) fn new(context: Arc<Context>) -> Self {
+    pub(crate) fn new(
+        context: Arc<Context>,
+        dag_state: Arc<RwLock<DagState>>,
+        store: Arc<dyn Store>,
+    ) -> Self {
         // TODO: restore the threshold clock round based on the last quorum data in storage when crash/recover
         let threshold_clock = ThresholdClock::new(0, context.clone());
+        let leader_schedule = LeaderSchedule::from_store(context.clone(), store.as_ref());
+        let committer = BaseCommitter::new(
+            context.clone(),
+            leader_schedule.clone(),
+            dag_state.clone(),
+            BaseCommitterOptions::default(),
+        );
+        let last_commit = store
+            .read_last_commit()
+            .expect("Reading the last commit should not fail");
+        let last_decided_leader = last_commit
+            .as_ref()
+            .map(|commit| commit.leader.round)
+            .unwrap_or_default();
 
         Self {
             context,
             threshold_clock,
             last_own_block: Block::V1(BlockV1::default()), // TODO: restore on crash/recovery
+            leader_schedule,
+            committer,
+            last_decided_leader,
+            last_commit,
+            dag_state,
+            store,
         }
     }
 
@@ -36,9 +80,108 @@ impl Core {
     pub(crate) fn add_blocks(&mut self, _blocks: Vec<Block>) -> Vec<BlockRef> {
         let _scope = monitored_scope("Core::add_blocks");
 
+        let commits = self.try_commit();
+        self.handle_commits(commits);
+
         vec![]
     }
 
+    /// Decides the leaders after the last decided leader, and returns the commits of the
+    /// committed ones in commit order. Leaders are decided in round order, so the decision
+    /// stops at the first leader that can not be decided yet.
+    fn try_commit(&mut self) -> Vec<Commit> {
+        let _scope = monitored_scope("Core::try_commit");
+
+        let highest_round = self.threshold_clock.get_round();
+        let mut leaders = VecDeque::new();
+        for round in (self.last_decided_leader + 1..=highest_round).rev() {
+            let Some(leader) = self.committer.elect_leader(round) else {
+                continue;
+            };
+            let mut status = self.committer.try_direct_decide(leader);
+            if let LeaderStatus::Undecided(_) = status {
+                status = self.committer.try_indirect_decide(leader, leaders.iter());
+            }
+            leaders.push_front(status);
+        }
+
+        let mut commits = vec![];
+        for status in leaders {
+            match status {
+                LeaderStatus::Undecided(_) => break,
+                LeaderStatus::Skip(slot) => self.last_decided_leader = slot.round,
+                LeaderStatus::Commit(block) => {
+                    self.last_decided_leader = block.round();
+                    let commit = self.linearize_sub_dag(block);
+                    self.last_commit = Some(commit.clone());
+                    commits.push(commit);
+                }
+            }
+        }
+        commits
+    }
+
+    /// Builds the commit of the leader, with the blocks in its causal history which are not
+    /// committed yet, sorted by round and then by author.
+    fn linearize_sub_dag(&self, leader: VerifiedBlock) -> Commit {
+        let mut last_committed_rounds = self
+            .last_commit
+            .as_ref()
+            .map(|commit| commit.last_committed_rounds.clone())
+            .unwrap_or_else(|| vec![0; self.context.committee.size()]);
+
+        let mut blocks = vec![leader.reference()];
+        let mut visited = HashSet::from([leader.reference()]);
+        let mut buffer = vec![leader.clone()];
+        let dag_state = self.dag_state.read();
+        while let Some(block) = buffer.pop() {
+            for ancestor in block.ancestors() {
+                if ancestor.round <= last_committed_rounds[ancestor.author]
+                    || !visited.insert(*ancestor)
+                {
+                    continue;
+                }
+                blocks.push(*ancestor);
+                buffer.push(
+                    dag_state
+                        .get_uncommitted_block(ancestor)
+                        .expect("We should have the whole sub-dag by now"),
+                );
+            }
+        }
+        blocks.sort_by_key(|block| (block.round, block.author));
+
+        for block in &blocks {
+            let last_committed_round = &mut last_committed_rounds[block.author];
+            *last_committed_round = (*last_committed_round).max(block.round);
+        }
+
+        Commit {
+            index: self
+                .last_commit
+                .as_ref()
+                .map(|commit| commit.index + 1)
+                .unwrap_or(1),
+            leader: leader.reference(),
+            blocks,
+            last_committed_rounds,
+        }
+    }
+
+    /// Persists the commits decided by the committer, in commit order. The reputation of
+    /// their leaders drives the leader schedule of the later rounds.
+    fn handle_commits(&mut self, commits: Vec<Commit>) {
+        let _scope = monitored_scope("Core::handle_commits");
+
+        if commits.is_empty() {
+            return;
+        }
+        self.store
+            .write(vec![], commits.clone())
+            .expect("Writing commits should not fail");
+        self.leader_schedule.handle_commits(&commits);
+    }
+
     /// Force creating a new block for the dictated round. This is used when a leader timeout occurs.
     pub fn force_new_block(&mut self, round: Round) -> Option<Block> {
         if self.last_proposed_round() < round {
@@ -82,7 +225,7 @@ impl Core {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::metrics::test_metrics;
+    use crate::{metrics::test_metrics, storage::mem_store::MemStore};
     use consensus_config::Committee;
     use consensus_config::{AuthorityIndex, Parameters};
     use sui_protocol_config::ProtocolConfig;
@@ -99,7 +242,10 @@ mod test {
             metrics,
         ));
 
-        let core = Core::new(context);
+        let store = Arc::new(MemStore::new());
+        let dag_state = Arc::new(RwLock::new(DagState::new(context.clone(), store.clone())));
+
+        let core = Core::new(context, dag_state, store);
 
         assert_eq!(core.last_proposed_round(), 0);
     }
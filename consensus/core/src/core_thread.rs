@@ -148,8 +148,11 @@ impl CoreThreadDispatcher {
 mod test {
     use super::*;
     use crate::context::Context;
+    use crate::dag_state::DagState;
     use crate::metrics::test_metrics;
+    use crate::storage::mem_store::MemStore;
     use consensus_config::{AuthorityIndex, Committee, Parameters};
+    use parking_lot::RwLock;
     use sui_protocol_config::ProtocolConfig;
 
     #[tokio::test]
@@ -164,7 +167,9 @@ mod test {
             metrics,
         ));
 
-        let core = Core::new(context.clone());
+        let store = Arc::new(MemStore::new());
+        let dag_state = Arc::new(RwLock::new(DagState::new(context.clone(), store.clone())));
+        let core = Core::new(context.clone(), dag_state, store);
         let (core_dispatcher, handle) = CoreThreadDispatcher::start(core, context);
 
         // Now create some clones of the dispatcher
@@ -1,30 +1,78 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
+use parking_lot::{Mutex, RwLock};
 use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use tracing::{debug, trace};
 
-use consensus_config::AuthorityIndex;
+use consensus_config::{AuthorityIndex, Stake};
 
-use crate::context::Context;
+use crate::{
+    block::Round,
+    commit::{Commit, CommitIndex},
+    context::Context,
+    storage::Store,
+};
+
+/// Number of commits whose leaders are scored for each update of the leader
+/// swap table.
+pub(crate) const CONSENSUS_COMMITS_PER_SCHEDULE: usize = 300;
 
 /// The LeaderSchedule is responsible for producing the leader schedule across
-/// an epoch. For now it is a simple wrapper around Context to provide a leader
-/// for a round deterministically.
-// TODO: complete full leader schedule changes
+/// an epoch. Leaders are elected deterministically in proportion to their stake,
+/// and the leaders with the worst reputation are swapped with the ones with the
+/// best reputation, as dictated by the [LeaderSwapTable].
 #[derive(Clone)]
 pub(crate) struct LeaderSchedule {
     context: Arc<Context>,
+    leader_swap_table: Arc<RwLock<LeaderSwapTable>>,
+    /// Commits received since the last update of the leader swap table.
+    unscored_commits: Arc<Mutex<Vec<Commit>>>,
 }
 
 #[allow(unused)]
 impl LeaderSchedule {
     pub fn new(context: Arc<Context>) -> Self {
-        Self { context }
+        Self {
+            context,
+            leader_swap_table: Arc::new(RwLock::new(LeaderSwapTable::default())),
+            unscored_commits: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Restores the leader schedule from the commits in store, on startup or
+    /// after a crash. The swap table is recomputed from the last full range of
+    /// `CONSENSUS_COMMITS_PER_SCHEDULE` commits, and the commits after it are
+    /// kept to be scored with the next ones.
+    pub fn from_store(context: Arc<Context>, store: &dyn Store) -> Self {
+        let leader_schedule = Self::new(context);
+        let Some(last_commit) = store
+            .read_last_commit()
+            .expect("Reading the last commit should not fail")
+        else {
+            return leader_schedule;
+        };
+        let commits_per_schedule = CONSENSUS_COMMITS_PER_SCHEDULE as CommitIndex;
+        let last_scored_index = last_commit.index - last_commit.index % commits_per_schedule;
+        let start_index = last_scored_index.saturating_sub(commits_per_schedule) + 1;
+        let commits = store
+            .scan_commits(start_index)
+            .expect("Scanning commits should not fail");
+        leader_schedule.handle_commits(&commits);
+        leader_schedule
     }
 
     pub fn elect_leader(&self, round: u32, leader_offset: u32) -> AuthorityIndex {
+        let leader = self.elect_scheduled_leader(round, leader_offset);
+        self.leader_swap_table
+            .read()
+            .swap(leader, round, leader_offset)
+            .unwrap_or(leader)
+    }
+
+    fn elect_scheduled_leader(&self, round: u32, leader_offset: u32) -> AuthorityIndex {
         cfg_if::cfg_if! {
             // TODO: we need to differentiate the leader strategy in tests, so for
             // some type of testing (ex sim tests) we can use the staked approach.
@@ -41,9 +89,11 @@ impl LeaderSchedule {
 
         // To ensure that we elect different leaders for the same round (using
         // different offset) we are using the round number as seed to shuffle in
-        // a weighted way the results, but skip based on the offset.
+        // a weighted way the results, but skip based on the offset. The epoch is
+        // part of the seed too, so that every epoch has a different schedule.
         // TODO: use a cache in case this proves to be computationally expensive
         let mut seed_bytes = [0u8; 32];
+        seed_bytes[..8].copy_from_slice(&self.context.committee.epoch().to_le_bytes());
         seed_bytes[32 - 4..].copy_from_slice(&(round).to_le_bytes());
         let mut rng = StdRng::from_seed(seed_bytes);
 
@@ -64,16 +114,200 @@ impl LeaderSchedule {
 
         leader_index
     }
+
+    /// Scores the leaders of every `CONSENSUS_COMMITS_PER_SCHEDULE` commits,
+    /// and updates the leader swap table from these scores for the rounds
+    /// after the last scored leader. Commits must be handled in commit order,
+    /// so that all authorities update the table at the same commit.
+    pub fn handle_commits(&self, commits: &[Commit]) {
+        let mut unscored_commits = self.unscored_commits.lock();
+        for commit in commits {
+            unscored_commits.push(commit.clone());
+            if unscored_commits.len() < CONSENSUS_COMMITS_PER_SCHEDULE {
+                continue;
+            }
+            let reputation_scores =
+                ReputationScores::from_commits(&self.context, &unscored_commits);
+            self.update_leader_swap_table(LeaderSwapTable::new(
+                &self.context,
+                commit.leader.round + 1,
+                &reputation_scores,
+            ));
+            unscored_commits.clear();
+        }
+    }
+
+    /// Replaces the leader swap table. All authorities must update the table
+    /// at the same round, from the same reputation scores, so that they keep
+    /// agreeing on the leaders.
+    pub fn update_leader_swap_table(&self, table: LeaderSwapTable) {
+        let mut current = self.leader_swap_table.write();
+        assert!(
+            table.round > current.round,
+            "The new leader swap table of round {} should be more recent than the current one of round {}",
+            table.round,
+            current.round
+        );
+        debug!("Updating {:?}", table);
+        *current = table;
+    }
+}
+
+/// Scores of the authorities over a range of commits.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ReputationScores {
+    /// Score of each authority, by index.
+    pub scores_per_authority: Vec<u64>,
+}
+
+#[allow(unused)]
+impl ReputationScores {
+    /// Scores the authorities by the number of their blocks committed as
+    /// leaders. Leaders are elected in proportion to their stake, so the
+    /// counts are normalized by stake. Leaders which are consistently too slow
+    /// to be committed, and are skipped instead, end up with the lowest scores.
+    pub fn from_commits(context: &Context, commits: &[Commit]) -> Self {
+        let committee = &context.committee;
+        let mut committed_leaders = vec![0u64; committee.size()];
+        for commit in commits {
+            committed_leaders[commit.leader.author.value()] += 1;
+        }
+        let scores_per_authority = committee
+            .authorities()
+            .map(|(index, authority)| {
+                committed_leaders[index] * committee.total_stake() / authority.stake.max(1)
+            })
+            .collect();
+        Self {
+            scores_per_authority,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LeaderSwapTable {
+    /// The round from which the table is in effect.
+    round: Round,
+    /// The authorities with the best scores, up to the bad nodes stake
+    /// threshold. They replace the bad nodes on the schedule.
+    good_nodes: Vec<AuthorityIndex>,
+    /// The authorities with the worst scores, up to the bad nodes stake
+    /// threshold. They are swapped with a good node every time they are
+    /// elected as leader.
+    bad_nodes: BTreeSet<AuthorityIndex>,
+}
+
+#[allow(unused)]
+impl LeaderSwapTable {
+    pub fn new(context: &Context, round: Round, reputation_scores: &ReputationScores) -> Self {
+        let bad_nodes_stake_threshold = context
+            .protocol_config
+            .consensus_bad_nodes_stake_threshold();
+        assert!(
+            (0..=33).contains(&bad_nodes_stake_threshold),
+            "The bad_nodes_stake_threshold should be in range [0 - 33], out of bounds parameter detected"
+        );
+
+        // Ties are broken by index, so that all authorities compute the same table.
+        let mut authorities = context
+            .committee
+            .authorities()
+            .map(|(index, _)| (index, reputation_scores.scores_per_authority[index]))
+            .collect::<Vec<_>>();
+        authorities.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let good_nodes = Self::retrieve_first_nodes(
+            context,
+            authorities.iter().map(|(index, _)| *index),
+            bad_nodes_stake_threshold,
+        );
+        let bad_nodes = Self::retrieve_first_nodes(
+            context,
+            authorities.iter().rev().map(|(index, _)| *index),
+            bad_nodes_stake_threshold,
+        )
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+
+        debug!(
+            "Reputation scores on round {round}: {:?}, good nodes: {:?}, bad nodes: {:?}",
+            reputation_scores.scores_per_authority, good_nodes, bad_nodes
+        );
+        Self {
+            round,
+            good_nodes,
+            bad_nodes,
+        }
+    }
+
+    /// Returns the good node to elect instead of `leader` if it is a bad node
+    /// and the table is in effect at `round`, or None otherwise. The good
+    /// nodes are shuffled with the round as seed, without weighting them by
+    /// stake, so that all good nodes replace bad nodes equally often, and the
+    /// offset picks different good nodes for the different leaders of a round.
+    pub fn swap(
+        &self,
+        leader: AuthorityIndex,
+        round: Round,
+        offset: u32,
+    ) -> Option<AuthorityIndex> {
+        if round < self.round || !self.bad_nodes.contains(&leader) || self.good_nodes.is_empty() {
+            return None;
+        }
+        let mut seed_bytes = [0u8; 32];
+        seed_bytes[32 - 4..].copy_from_slice(&round.to_le_bytes());
+        let mut rng = StdRng::from_seed(seed_bytes);
+
+        let mut good_nodes = self.good_nodes.clone();
+        good_nodes.shuffle(&mut rng);
+        let good_node = good_nodes[offset as usize % good_nodes.len()];
+        trace!("Swapping bad leader {leader} -> {good_node} for round {round}");
+        Some(good_node)
+    }
+
+    /// Retrieves the first `authorities` until their total stake would exceed
+    /// `stake_threshold` percent of the total stake.
+    fn retrieve_first_nodes(
+        context: &Context,
+        authorities: impl Iterator<Item = AuthorityIndex>,
+        stake_threshold: u64,
+    ) -> Vec<AuthorityIndex> {
+        let committee = &context.committee;
+        let mut nodes = vec![];
+        let mut total_stake: Stake = 0;
+        for index in authorities {
+            let stake = committee.stake(index);
+            if (total_stake + stake) * 100 > stake_threshold * committee.total_stake() {
+                break;
+            }
+            total_stake += stake;
+            nodes.push(index);
+        }
+        nodes
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::metrics::test_metrics;
+    use crate::{block::BlockRef, metrics::test_metrics, storage::mem_store::MemStore};
     use consensus_config::{Committee, Parameters};
     use sui_protocol_config::ProtocolConfig;
 
+    fn context_for_test(epoch: u64, stakes: Vec<u64>) -> Arc<Context> {
+        let committee = Committee::new_for_test(epoch, stakes).0;
+        let mut protocol_config = ProtocolConfig::get_for_min_version();
+        protocol_config.set_consensus_bad_nodes_stake_threshold(33);
+        Arc::new(Context::new(
+            AuthorityIndex::new_for_test(0),
+            committee,
+            Parameters::default(),
+            protocol_config,
+            test_metrics(),
+        ))
+    }
+
     #[test]
     fn test_elect_leader() {
         let committee = Committee::new_for_test(0, vec![1, 1, 1, 1]).0;
@@ -137,4 +371,163 @@ mod tests {
             leader_schedule.elect_leader_stake_based(1, 2)
         );
     }
+
+    #[test]
+    fn test_elect_leader_stake_based_epoch_seed() {
+        let schedule = |epoch| {
+            let leader_schedule = LeaderSchedule::new(context_for_test(epoch, vec![1, 2, 3, 4]));
+            (0..20)
+                .map(|round| leader_schedule.elect_leader_stake_based(round, 0))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(schedule(1), schedule(1));
+        assert_ne!(schedule(1), schedule(2));
+    }
+
+    #[test]
+    fn test_reputation_scores_from_commits() {
+        let context = context_for_test(0, vec![1, 1, 2, 4]);
+        let commit = |author| Commit {
+            leader: BlockRef {
+                author: AuthorityIndex::new_for_test(author),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let commits = vec![commit(0), commit(0), commit(2), commit(3), commit(3)];
+        assert_eq!(
+            ReputationScores::from_commits(&context, &commits).scores_per_authority,
+            vec![16, 0, 4, 4]
+        );
+    }
+
+    #[test]
+    fn test_leader_swap_table() {
+        let context = context_for_test(0, vec![1, 1, 1, 1, 1, 1, 1]);
+        let scores = ReputationScores {
+            scores_per_authority: vec![5, 0, 6, 3, 1, 4, 2],
+        };
+        let table = LeaderSwapTable::new(&context, 10, &scores);
+        let good_nodes = [
+            AuthorityIndex::new_for_test(2),
+            AuthorityIndex::new_for_test(0),
+        ];
+        assert_eq!(table.good_nodes, good_nodes);
+        assert_eq!(
+            table.bad_nodes,
+            BTreeSet::from([
+                AuthorityIndex::new_for_test(1),
+                AuthorityIndex::new_for_test(4)
+            ])
+        );
+
+        assert_eq!(table.swap(AuthorityIndex::new_for_test(3), 11, 0), None);
+        let swapped = table.swap(AuthorityIndex::new_for_test(1), 11, 0).unwrap();
+        assert!(good_nodes.contains(&swapped));
+        // different offsets elect different good nodes
+        assert_ne!(
+            table.swap(AuthorityIndex::new_for_test(1), 11, 1),
+            Some(swapped)
+        );
+
+        // the table is not in effect before its round
+        assert_eq!(table.swap(AuthorityIndex::new_for_test(1), 9, 0), None);
+
+        let leader_schedule = LeaderSchedule::new(context);
+        assert_eq!(
+            leader_schedule.elect_leader(15, 0),
+            AuthorityIndex::new_for_test(1)
+        );
+        leader_schedule.update_leader_swap_table(table);
+        assert!(good_nodes.contains(&leader_schedule.elect_leader(15, 0)));
+        assert_eq!(
+            leader_schedule.elect_leader(8, 0),
+            AuthorityIndex::new_for_test(1)
+        );
+        assert_eq!(
+            leader_schedule.elect_leader(16, 0),
+            AuthorityIndex::new_for_test(2)
+        );
+    }
+
+    /// Commits where authority 0 leads half of the commits, and authority 3 is
+    /// never committed.
+    fn commits_for_test(count: usize) -> Vec<Commit> {
+        (0..count)
+            .map(|i| Commit {
+                index: i as u64 + 1,
+                leader: BlockRef {
+                    round: i as Round + 1,
+                    author: AuthorityIndex::new_for_test(if i % 2 == 0 {
+                        0
+                    } else {
+                        1 + (i as u32 / 2) % 2
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_handle_commits_updates_leader_swap_table() {
+        let context = context_for_test(0, vec![1, 1, 1, 1]);
+        let leader_schedule = LeaderSchedule::new(context);
+        let commits = commits_for_test(CONSENSUS_COMMITS_PER_SCHEDULE);
+        let (last_commit, commits) = commits.split_last().unwrap();
+
+        leader_schedule.handle_commits(commits);
+        assert_eq!(
+            leader_schedule.elect_leader(303, 0),
+            AuthorityIndex::new_for_test(3)
+        );
+
+        leader_schedule.handle_commits(std::slice::from_ref(last_commit));
+        assert_eq!(
+            leader_schedule.elect_leader(303, 0),
+            AuthorityIndex::new_for_test(0)
+        );
+        // rounds up to the last scored leader keep their leaders
+        assert_eq!(
+            leader_schedule.elect_leader(299, 0),
+            AuthorityIndex::new_for_test(3)
+        );
+        assert_eq!(
+            leader_schedule.elect_leader(302, 0),
+            AuthorityIndex::new_for_test(2)
+        );
+    }
+
+    #[test]
+    fn test_from_store() {
+        let context = context_for_test(0, vec![1, 1, 1, 1]);
+        let store = MemStore::new();
+
+        // Without commits, the schedule starts without swaps.
+        let leader_schedule = LeaderSchedule::from_store(context.clone(), &store);
+        assert_eq!(
+            leader_schedule.elect_leader(303, 0),
+            AuthorityIndex::new_for_test(3)
+        );
+
+        // The commits after the last scored one are kept for the next update.
+        let commits = commits_for_test(2 * CONSENSUS_COMMITS_PER_SCHEDULE + 50);
+        store.write(vec![], commits.clone()).unwrap();
+        let leader_schedule = LeaderSchedule::from_store(context.clone(), &store);
+        let expected = LeaderSchedule::new(context);
+        expected.handle_commits(&commits);
+
+        assert_eq!(leader_schedule.unscored_commits.lock().len(), 50);
+        assert_eq!(
+            *leader_schedule.unscored_commits.lock(),
+            *expected.unscored_commits.lock()
+        );
+        for round in 595..610 {
+            assert_eq!(
+                leader_schedule.elect_leader(round, 0),
+                expected.elect_leader(round, 0)
+            );
+        }
+    }
 }
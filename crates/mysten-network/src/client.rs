@@ -6,6 +6,8 @@ use crate::{
     multiaddr::{parse_dns, parse_ip4, parse_ip6, Multiaddr, Protocol},
 };
 use eyre::{eyre, Context, Result};
+use std::sync::Arc;
+use tokio_rustls::TlsConnector;
 use tonic::transport::{Channel, Endpoint, Uri};
 
 pub async fn connect(address: &Multiaddr) -> Result<Channel> {
@@ -33,6 +35,18 @@ pub(crate) fn connect_lazy_with_config(address: &Multiaddr, config: &Config) ->
     Ok(channel)
 }
 
+pub(crate) fn connect_lazy_with_config_and_tls(
+    address: &Multiaddr,
+    config: &Config,
+    tls_config: rustls::ClientConfig,
+) -> Result<Channel> {
+    let channel = endpoint_from_multiaddr(address)?
+        .apply_config(config)
+        .with_tls_connector(TlsConnector::from(Arc::new(tls_config)))?
+        .connect_lazy();
+    Ok(channel)
+}
+
 fn endpoint_from_multiaddr(addr: &Multiaddr) -> Result<MyEndpoint> {
     let mut iter = addr.iter();
 
@@ -69,6 +83,8 @@ struct MyEndpoint {
     endpoint: Endpoint,
     #[cfg(unix)]
     uds_connector: Option<std::path::PathBuf>,
+    tls_connector: Option<TlsConnector>,
+    tcp_nodelay: bool,
 }
 
 impl MyEndpoint {
@@ -77,6 +93,9 @@ impl MyEndpoint {
             endpoint,
             #[cfg(unix)]
             uds_connector: None,
+            tls_connector: None,
+            // Same default as tonic.
+            tcp_nodelay: true,
         }
     }
 
@@ -91,13 +110,28 @@ impl MyEndpoint {
     #[cfg(unix)]
     fn with_uds_connector(self, path: std::path::PathBuf) -> Self {
         Self {
-            endpoint: self.endpoint,
             uds_connector: Some(path),
+            ..self
+        }
+    }
+
+    /// Connect over TLS. Only supported for TCP connections.
+    fn with_tls_connector(self, tls_connector: TlsConnector) -> Result<Self> {
+        #[cfg(unix)]
+        if self.uds_connector.is_some() {
+            return Err(eyre!("TLS is not supported over unix sockets"));
         }
+        Ok(Self {
+            tls_connector: Some(tls_connector),
+            ..self
+        })
     }
 
     fn apply_config(mut self, config: &Config) -> Self {
         self.endpoint = apply_config_to_endpoint(config, self.endpoint);
+        if let Some(tcp_nodelay) = config.tcp_nodelay {
+            self.tcp_nodelay = tcp_nodelay;
+        }
         self
     }
 
@@ -114,6 +148,13 @@ impl MyEndpoint {
                 }));
         }
 
+        if let Some(tls_connector) = self.tls_connector {
+            let tcp_nodelay = self.tcp_nodelay;
+            return self.endpoint.connect_with_connector_lazy(tower::service_fn(
+                move |uri: Uri| connect_tls(tls_connector.clone(), uri, tcp_nodelay),
+            ));
+        }
+
         self.endpoint.connect_lazy()
     }
 
@@ -136,6 +177,31 @@ impl MyEndpoint {
     }
 }
 
+async fn connect_tls(
+    tls_connector: TlsConnector,
+    uri: Uri,
+    tcp_nodelay: bool,
+) -> std::io::Result<tokio_rustls::client::TlsStream<tokio::net::TcpStream>> {
+    let invalid_uri = || {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("invalid uri {uri}"),
+        )
+    };
+    // IPv6 hosts are enclosed in brackets in uris.
+    let host = uri
+        .host()
+        .ok_or_else(invalid_uri)?
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let port = uri.port_u16().ok_or_else(invalid_uri)?;
+    let server_name = rustls::ServerName::try_from(host).map_err(|_| invalid_uri())?;
+
+    let stream = tokio::net::TcpStream::connect((host, port)).await?;
+    stream.set_nodelay(tcp_nodelay)?;
+    tls_connector.connect(server_name, stream).await
+}
+
 fn apply_config_to_endpoint(config: &Config, mut endpoint: Endpoint) -> Endpoint {
     if let Some(limit) = config.concurrency_limit_per_connection {
         endpoint = endpoint.concurrency_limit(limit);
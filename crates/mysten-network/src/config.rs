@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::metrics::{DefaultMetricsCallbackProvider, MetricsCallbackProvider};
 use crate::{
-    client::{connect_lazy_with_config, connect_with_config},
+    client::{connect_lazy_with_config, connect_lazy_with_config_and_tls, connect_with_config},
     server::ServerBuilder,
     Multiaddr,
 };
@@ -97,4 +97,13 @@ impl Config {
     pub fn connect_lazy(&self, addr: &Multiaddr) -> Result<Channel> {
         connect_lazy_with_config(addr, self)
     }
+
+    /// Like `connect_lazy`, but connects over TLS with the given config.
+    pub fn connect_lazy_with_tls(
+        &self,
+        addr: &Multiaddr,
+        tls_config: rustls::ClientConfig,
+    ) -> Result<Channel> {
+        connect_lazy_with_config_and_tls(addr, self, tls_config)
+    }
 }
@@ -127,6 +127,12 @@ impl Multiaddr {
         None
     }
 
+    /// Whether the address is served over TLS, e.g. `/dns/localhost/tcp/8080/https`.
+    pub fn is_https(&self) -> bool {
+        self.iter()
+            .any(|component| matches!(component, Protocol::Https))
+    }
+
     pub fn port(&self) -> Option<u16> {
         for component in self.iter() {
             match component {
@@ -332,4 +338,13 @@ mod test {
         assert_eq!(Some("mysten.sui".to_string()), multi_addr_dns.hostname());
         assert_eq!(Some(10501u16), multi_addr_dns.port());
     }
+
+    #[test]
+    fn test_is_https() {
+        let multi_addr_https = Multiaddr(multiaddr!(Dns("mysten.sui"), Tcp(10501u16), Https));
+        assert!(multi_addr_https.is_https());
+
+        let multi_addr_http = Multiaddr(multiaddr!(Dns("mysten.sui"), Tcp(10501u16), Http));
+        assert!(!multi_addr_http.is_https());
+    }
 }
@@ -10,10 +10,15 @@ use crate::{
 };
 use eyre::{eyre, Result};
 use futures::FutureExt;
+use std::future::Future;
+use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 use std::{convert::Infallible, net::SocketAddr};
-use tokio::net::{TcpListener, ToSocketAddrs};
-use tokio_stream::wrappers::TcpListenerStream;
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio_rustls::server::TlsStream;
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
+use tokio_stream::StreamExt;
 use tonic::codegen::http::HeaderValue;
 use tonic::{
     body::BoxBody,
@@ -34,10 +39,18 @@ use tower_http::classify::{GrpcErrorsAsFailures, SharedClassifier};
 use tower_http::propagate_header::PropagateHeaderLayer;
 use tower_http::set_header::SetRequestHeaderLayer;
 use tower_http::trace::{DefaultMakeSpan, DefaultOnBodyChunk, DefaultOnEos, TraceLayer};
+use tracing::debug;
+
+/// Connections that don't complete the TLS handshake in time are dropped.
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Number of connections that completed the TLS handshake and wait to be served.
+const TLS_ACCEPTED_CONNECTIONS_BUFFER: usize = 128;
 
 pub struct ServerBuilder<M: MetricsCallbackProvider = DefaultMetricsCallbackProvider> {
     router: Router<WrapperService<M>>,
     health_reporter: tonic_health::server::HealthReporter,
+    tls_config: Option<Arc<rustls::ServerConfig>>,
 }
 
 type AddPathToHeaderFunction = fn(&Request<Body>) -> Option<HeaderValue>;
@@ -133,9 +146,16 @@ impl<M: MetricsCallbackProvider> ServerBuilder<M> {
         Self {
             router,
             health_reporter,
+            tls_config: None,
         }
     }
 
+    /// Serve TCP connections over TLS with the given config. Unix sockets are served without TLS.
+    pub fn with_tls_config(mut self, tls_config: rustls::ServerConfig) -> Self {
+        self.tls_config = Some(Arc::new(tls_config));
+        self
+    }
+
     pub fn health_reporter(&self) -> tonic_health::server::HealthReporter {
         self.health_reporter.clone()
     }
@@ -166,30 +186,21 @@ impl<M: MetricsCallbackProvider> ServerBuilder<M> {
                     let (local_addr, incoming) =
                         tcp_listener_and_update_multiaddr(addr, (dns_name.as_ref(), tcp_port))
                             .await?;
-                    let server = Box::pin(
-                        self.router
-                            .serve_with_incoming_shutdown(incoming, rx_cancellation),
-                    );
+                    let server = serve_tcp(self.router, incoming, self.tls_config, rx_cancellation);
                     (local_addr, server)
                 }
                 Protocol::Ip4(_) => {
                     let (socket_addr, _http_or_https) = parse_ip4(addr)?;
                     let (local_addr, incoming) =
                         tcp_listener_and_update_multiaddr(addr, socket_addr).await?;
-                    let server = Box::pin(
-                        self.router
-                            .serve_with_incoming_shutdown(incoming, rx_cancellation),
-                    );
+                    let server = serve_tcp(self.router, incoming, self.tls_config, rx_cancellation);
                     (local_addr, server)
                 }
                 Protocol::Ip6(_) => {
                     let (socket_addr, _http_or_https) = parse_ip6(addr)?;
                     let (local_addr, incoming) =
                         tcp_listener_and_update_multiaddr(addr, socket_addr).await?;
-                    let server = Box::pin(
-                        self.router
-                            .serve_with_incoming_shutdown(incoming, rx_cancellation),
-                    );
+                    let server = serve_tcp(self.router, incoming, self.tls_config, rx_cancellation);
                     (local_addr, server)
                 }
                 // Protocol::Memory(_) => todo!(),
@@ -217,6 +228,63 @@ impl<M: MetricsCallbackProvider> ServerBuilder<M> {
     }
 }
 
+fn serve_tcp<M: MetricsCallbackProvider>(
+    router: Router<WrapperService<M>>,
+    incoming: TcpListenerStream,
+    tls_config: Option<Arc<rustls::ServerConfig>>,
+    signal: impl Future<Output = ()> + Send + 'static,
+) -> BoxFuture<(), tonic::transport::Error> {
+    match tls_config {
+        Some(tls_config) => Box::pin(
+            router.serve_with_incoming_shutdown(tls_incoming(incoming, tls_config), signal),
+        ),
+        None => Box::pin(router.serve_with_incoming_shutdown(incoming, signal)),
+    }
+}
+
+/// Performs the TLS handshake of incoming connections concurrently, so that slow or malicious
+/// clients can't hold up other connections, and yields the connections that completed it.
+fn tls_incoming(
+    mut incoming: TcpListenerStream,
+    tls_config: Arc<rustls::ServerConfig>,
+) -> ReceiverStream<std::io::Result<TlsStream<TcpStream>>> {
+    let acceptor = tokio_rustls::TlsAcceptor::from(tls_config);
+    let (tx, rx) = tokio::sync::mpsc::channel(TLS_ACCEPTED_CONNECTIONS_BUFFER);
+    tokio::spawn(async move {
+        loop {
+            let stream = tokio::select! {
+                stream = incoming.next() => stream,
+                // The server has shut down.
+                _ = tx.closed() => return,
+            };
+            match stream {
+                Some(Ok(stream)) => {
+                    let acceptor = acceptor.clone();
+                    let tx = tx.clone();
+                    tokio::spawn(async move {
+                        match tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream))
+                            .await
+                        {
+                            Ok(Ok(stream)) => {
+                                let _ = tx.send(Ok(stream)).await;
+                            }
+                            Ok(Err(e)) => debug!("TLS handshake failed: {e}"),
+                            Err(_) => debug!("TLS handshake timed out"),
+                        }
+                    });
+                }
+                Some(Err(e)) => {
+                    if tx.send(Err(e)).await.is_err() {
+                        return;
+                    }
+                }
+                None => return,
+            }
+        }
+    });
+    ReceiverStream::new(rx)
+}
+
 async fn tcp_listener_and_update_multiaddr<T: ToSocketAddrs>(
     address: &Multiaddr,
     socket_addr: T,
@@ -418,6 +486,123 @@ mod test {
         assert!(metrics.metrics_called.lock().unwrap().deref());
     }
 
+    #[tokio::test]
+    async fn tls() {
+        use fastcrypto::ed25519::Ed25519KeyPair;
+        use fastcrypto::traits::KeyPair;
+        use sui_tls::{
+            CertVerifier, HashSetAllow, SelfSignedCertificate, ServerCertVerifier,
+            SUI_VALIDATOR_SERVER_NAME,
+        };
+
+        let mut rng = rand::thread_rng();
+        let server_keypair = Ed25519KeyPair::generate(&mut rng);
+        let server_public_key = server_keypair.public().to_owned();
+        let server_certificate =
+            SelfSignedCertificate::new(server_keypair.private(), SUI_VALIDATOR_SERVER_NAME);
+        let client_keypair = Ed25519KeyPair::generate(&mut rng);
+        let client_certificate =
+            SelfSignedCertificate::new(client_keypair.private(), SUI_VALIDATOR_SERVER_NAME);
+        let unknown_client_keypair = Ed25519KeyPair::generate(&mut rng);
+        let unknown_client_certificate =
+            SelfSignedCertificate::new(unknown_client_keypair.private(), SUI_VALIDATOR_SERVER_NAME);
+        let allower = HashSetAllow::new();
+        allower
+            .inner()
+            .write()
+            .unwrap()
+            .insert(client_keypair.public().to_owned());
+        let tls_config = CertVerifier::new(allower)
+            .rustls_server_config(
+                vec![server_certificate.rustls_certificate()],
+                server_certificate.rustls_private_key(),
+            )
+            .unwrap();
+
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/0/https".parse().unwrap();
+        let config = Config::new();
+        let mut server = config
+            .server_builder()
+            .with_tls_config(tls_config)
+            .bind(&address)
+            .await
+            .unwrap();
+        let address = server.local_addr().to_owned();
+        let cancel_handle = server.take_cancel_handle().unwrap();
+        let server_handle = tokio::spawn(server.serve());
+
+        let client_tls_config = |public_key, certificate: &SelfSignedCertificate| {
+            ServerCertVerifier::new(public_key, SUI_VALIDATOR_SERVER_NAME.to_string())
+                .rustls_client_config(
+                    rustls::DEFAULT_CIPHER_SUITES,
+                    vec![certificate.rustls_certificate()],
+                    certificate.rustls_private_key(),
+                )
+                .unwrap()
+        };
+        let request = || HealthCheckRequest {
+            service: "".to_owned(),
+        };
+
+        // An allowed client that expects the key of the server can connect
+        let channel = config
+            .connect_lazy_with_tls(
+                &address,
+                client_tls_config(server_public_key.clone(), &client_certificate),
+            )
+            .unwrap();
+        HealthClient::new(channel).check(request()).await.unwrap();
+
+        // A client that expects another key can't
+        let other_public_key = Ed25519KeyPair::generate(&mut rng).public().to_owned();
+        let channel = config
+            .connect_lazy_with_tls(
+                &address,
+                client_tls_config(other_public_key, &client_certificate),
+            )
+            .unwrap();
+        HealthClient::new(channel)
+            .check(request())
+            .await
+            .unwrap_err();
+
+        // Neither can a client whose key is not allowed
+        let channel = config
+            .connect_lazy_with_tls(
+                &address,
+                client_tls_config(server_public_key.clone(), &unknown_client_certificate),
+            )
+            .unwrap();
+        HealthClient::new(channel)
+            .check(request())
+            .await
+            .unwrap_err();
+
+        // Nor a client that doesn't authenticate
+        let channel = config
+            .connect_lazy_with_tls(
+                &address,
+                ServerCertVerifier::new(server_public_key, SUI_VALIDATOR_SERVER_NAME.to_string())
+                    .rustls_client_config_with_no_client_auth(rustls::DEFAULT_CIPHER_SUITES)
+                    .unwrap(),
+            )
+            .unwrap();
+        HealthClient::new(channel)
+            .check(request())
+            .await
+            .unwrap_err();
+
+        // Neither can a client that doesn't use TLS
+        let channel = config.connect_lazy(&address).unwrap();
+        HealthClient::new(channel)
+            .check(request())
+            .await
+            .unwrap_err();
+
+        cancel_handle.send(()).unwrap();
+        server_handle.await.unwrap().unwrap();
+    }
+
     async fn test_multiaddr(address: Multiaddr) {
         let config = Config::new();
         let mut server = config.server_builder().bind(&address).await.unwrap();
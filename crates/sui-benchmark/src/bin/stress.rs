@@ -130,6 +130,7 @@ async fn main() -> Result<()> {
         .unwrap();
     let prev_benchmark_stats_path = opts.compare_with.clone();
     let curr_benchmark_stats_path = opts.benchmark_stats_path.clone();
+    let benchmark_stats_csv_path = opts.benchmark_stats_csv_path.clone();
     let registry_clone = registry.clone();
     let handle = std::thread::spawn(move || {
         client_runtime.block_on(async move {
@@ -202,6 +203,9 @@ async fn main() -> Result<()> {
                         let serialized = serde_json::to_string(&benchmark_stats)?;
                         std::fs::write(curr_benchmark_stats_path, serialized)?;
                     }
+                    if !benchmark_stats_csv_path.is_empty() {
+                        std::fs::write(benchmark_stats_csv_path, benchmark_stats.to_csv())?;
+                    }
                 }
                 Err(e) => eprintln!("{e}"),
             },
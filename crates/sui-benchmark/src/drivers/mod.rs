@@ -185,6 +185,26 @@ impl BenchmarkStats {
         table.add_row(row);
         table
     }
+
+    /// Renders the stats as a CSV header and row, with latencies in milliseconds.
+    pub fn to_csv(&self) -> String {
+        let duration = self.duration.as_secs();
+        let histogram = &self.latency_ms.histogram;
+        format!(
+            "duration_s,tps,cps,error_rate,latency_min,latency_p50,latency_p90,latency_p99,\
+            latency_max,total_gas_used\n{},{},{},{},{},{},{},{},{},{}\n",
+            duration,
+            self.num_success_txes / duration,
+            self.num_success_cmds / duration,
+            self.num_error_txes as f64 / (self.num_error_txes + self.num_success_txes) as f64,
+            histogram.min(),
+            histogram.value_at_quantile(0.5),
+            histogram.value_at_quantile(0.9),
+            histogram.value_at_quantile(0.99),
+            histogram.max(),
+            self.total_gas_used,
+        )
+    }
 }
 
 /// A comparison between an old and a new benchmark.
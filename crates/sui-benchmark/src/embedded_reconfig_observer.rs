@@ -64,7 +64,7 @@ impl EmbeddedReconfigObserver {
                     new_epoch, "Observed a new epoch, attempting to reconfig: {committee_info}"
                 );
                 auth_agg
-                    .recreate_with_net_addresses(committee_info, &network_config, false)
+                    .recreate_with_net_addresses(committee_info, &network_config, None, false)
                     .map(Arc::new)
                     .map_err(|se| anyhow!("Failed to recreate due to: {:?}", se.to_string()))
             }
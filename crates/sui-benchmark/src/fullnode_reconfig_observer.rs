@@ -79,6 +79,7 @@ impl ReconfigObserver<NetworkAuthorityClient> for FullNodeReconfigObserver {
                             self.safe_client_metrics_base.clone(),
                             self.auth_agg_metrics.clone(),
                             Arc::new(HashMap::new()),
+                            None,
                         ) {
                             Ok(auth_agg) => {
                                 quorum_driver.update_validators(Arc::new(auth_agg)).await
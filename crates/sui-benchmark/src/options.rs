@@ -90,6 +90,9 @@ pub struct Opts {
     /// Path where previous benchmark stats is stored to use for comparison
     #[clap(long, default_value = "", global = true)]
     pub compare_with: String,
+    /// Path where benchmark stats are written as CSV, to be collected across runs
+    #[clap(long, default_value = "", global = true)]
+    pub benchmark_stats_csv_path: String,
     // Stat collection interval seconds
     #[clap(long, default_value = "10", global = true)]
     pub stat_collection_interval: u64,
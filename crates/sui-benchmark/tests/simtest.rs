@@ -34,6 +34,7 @@ mod test {
     use sui_types::base_types::{ObjectRef, SuiAddress};
     use sui_types::full_checkpoint_content::CheckpointData;
     use sui_types::messages_checkpoint::VerifiedCheckpoint;
+    use sui_types::sui_system_state::SuiSystemStateTrait;
     use test_cluster::{TestCluster, TestClusterBuilder};
     use tracing::{error, info};
     use typed_store::traits::Map;
@@ -284,6 +285,37 @@ mod test {
         test_simulated_load(TestInitData::new(&test_cluster).await, 120).await;
     }
 
+    // Crashes validators at the points where checkpoints are partially committed, and checks that
+    // every node recovers and keeps up with reconfiguration.
+    #[sim_test(config = "test_config()")]
+    async fn test_simulated_load_crashes_during_checkpoint_commit() {
+        sui_protocol_config::ProtocolConfig::poison_get_for_min_version();
+        let test_cluster = build_test_cluster(4, 10000).await;
+
+        let commit_fail_points = [
+            "checkpoint-computed-before-stored",
+            "checkpoint-certified-before-output",
+            "highest-executed-checkpoint",
+        ];
+        let dead_validator: Arc<Mutex<Option<DeadValidator>>> = Default::default();
+        let keep_alive_nodes = get_keep_alive_nodes(&test_cluster);
+        register_fail_points(&commit_fail_points, move || {
+            handle_failpoint(dead_validator.clone(), keep_alive_nodes.clone(), 0.02);
+        });
+
+        test_simulated_load(TestInitData::new(&test_cluster).await, 60).await;
+
+        for fail_point in commit_fail_points {
+            clear_fail_point(fail_point);
+        }
+        // Give the crashed validators time to restart, then check that all of them catch up.
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        let system_state = test_cluster.wait_for_epoch(None).await;
+        test_cluster
+            .wait_for_epoch_all_nodes(system_state.epoch())
+            .await;
+    }
+
     #[sim_test(config = "test_config()")]
     async fn test_simulated_load_checkpoint_pruning() {
         let test_cluster = build_test_cluster(4, 1000).await;
@@ -5,6 +5,7 @@ use crate::{
     error::{BridgeError, BridgeResult},
     types::{BridgeAction, BridgeCommittee, SignedBridgeAction, VerifiedSignedBridgeAction},
 };
+use ethers::types::Bytes as EthBytes;
 use fastcrypto::{
     encoding::{Encoding, Hex},
     secp256k1::{
@@ -109,6 +110,18 @@ impl BridgeAuthoritySignInfo {
     }
 }
 
+/// Ethereum recovers signers with `ecrecover`, which takes the recovery id offset by 27.
+const ETH_RECOVERY_ID_OFFSET: u8 = 27;
+
+/// Converts a signature to the `r || s || v` format of Ethereum, which the bridge contracts
+/// verify with `ecrecover`.
+pub fn to_eth_signature(signature: &BridgeAuthorityRecoverableSignature) -> EthBytes {
+    let mut bytes = signature.as_ref().to_vec();
+    // Unwrap safe: recoverable signatures are 65 bytes, the last of which is the recovery id.
+    *bytes.last_mut().unwrap() += ETH_RECOVERY_ID_OFFSET;
+    bytes.into()
+}
+
 /// Verifies a SignedBridgeAction (response from bridge authority to bridge client)
 /// represents the right BridgeAction, and is signed by the right authority.
 pub fn verify_signed_bridge_action(
@@ -140,7 +153,7 @@ mod tests {
     use crate::test_utils::{get_test_authority_and_key, get_test_sui_to_eth_bridge_action};
     use crate::types::BridgeAction;
     use crate::types::SignedBridgeAction;
-    use fastcrypto::traits::KeyPair;
+    use fastcrypto::traits::{KeyPair, RecoverableSignature, ToFromBytes};
     use prometheus::Registry;
     use std::sync::Arc;
     use sui_types::crypto::get_key_pair;
@@ -221,4 +234,25 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_to_eth_signature() {
+        let (_, pubkey, secret) = get_test_authority_and_key(5000, 9999);
+        let action = get_test_sui_to_eth_bridge_action(None, Some(1), Some(1), Some(100));
+        let sig = BridgeAuthoritySignInfo::new(&action, &secret);
+
+        let eth_sig = to_eth_signature(&sig.signature);
+        let parsed = ethers::types::Signature::try_from(eth_sig.as_ref()).unwrap();
+        assert!(parsed.v == 27 || parsed.v == 28);
+        assert_eq!(&eth_sig[..64], &sig.signature.as_ref()[..64]);
+
+        // The signer is recovered from the Ethereum format, as `ecrecover` does.
+        let mut bytes = eth_sig.to_vec();
+        bytes[64] -= ETH_RECOVERY_ID_OFFSET;
+        let recovered = BridgeAuthorityRecoverableSignature::from_bytes(&bytes)
+            .unwrap()
+            .recover_with_hash::<Keccak256>(&action.to_bytes())
+            .unwrap();
+        assert_eq!(recovered, pubkey);
+    }
 }
@@ -4,11 +4,13 @@
 use crate::abi::EthToSuiTokenBridgeV1;
 use crate::crypto::BridgeAuthorityPublicKeyBytes;
 use crate::crypto::{
-    BridgeAuthorityPublicKey, BridgeAuthorityRecoverableSignature, BridgeAuthoritySignInfo,
+    to_eth_signature, BridgeAuthorityPublicKey, BridgeAuthorityRecoverableSignature,
+    BridgeAuthoritySignInfo,
 };
 use crate::error::{BridgeError, BridgeResult};
 use crate::events::EmittedSuiToEthTokenBridgeV1;
 use ethers::types::Address as EthAddress;
+use ethers::types::Bytes as EthBytes;
 use ethers::types::Log;
 use ethers::types::H256;
 pub use ethers::types::H256 as EthTransactionHash;
@@ -301,6 +303,13 @@ pub struct BridgeCommitteeValiditySignInfo {
     pub signatures: BTreeMap<BridgeAuthorityPublicKeyBytes, BridgeAuthorityRecoverableSignature>,
 }
 
+impl BridgeCommitteeValiditySignInfo {
+    /// The signatures in the format the bridge contracts on Ethereum expect, ordered by signer.
+    pub fn to_eth_signatures(&self) -> Vec<EthBytes> {
+        self.signatures.values().map(to_eth_signature).collect()
+    }
+}
+
 pub type SignedBridgeAction = Envelope<BridgeAction, BridgeAuthoritySignInfo>;
 pub type VerifiedSignedBridgeAction = VerifiedEnvelope<BridgeAction, BridgeAuthoritySignInfo>;
 pub type CertifiedBridgeAction = Envelope<BridgeAction, BridgeCommitteeValiditySignInfo>;
@@ -319,8 +328,10 @@ impl BridgeEventDigest {
 impl Message for BridgeAction {
     type DigestType = BridgeEventDigest;
 
-    // this is not encoded in message today
-    const SCOPE: IntentScope = IntentScope::BridgeEventUnused;
+    // Bridge actions are signed over their message bytes, which start with
+    // `BRIDGE_MESSAGE_PREFIX` rather than an intent, so that the signatures can be
+    // verified by the bridge contracts on Ethereum.
+    const SCOPE: IntentScope = IntentScope::BridgeAction;
 
     // this is not used today
     fn digest(&self) -> Self::DigestType {
@@ -27,6 +27,7 @@ use sui_types::committee::EpochId;
 use sui_types::crypto::AuthorityPublicKeyBytes;
 use sui_types::crypto::KeypairTraits;
 use sui_types::crypto::NetworkKeyPair;
+use sui_types::crypto::NetworkPublicKey;
 use sui_types::crypto::SuiKeyPair;
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 
@@ -168,6 +169,13 @@ pub struct NodeConfig {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_with_range: Option<RunWithRange>,
+
+    /// Serve the validator gRPC service over TLS. Clients only connect with TLS to validators
+    /// whose on-chain network address uses `https`, so the address must be updated to match,
+    /// which takes effect at the next epoch. The allowed cipher suites also apply to the
+    /// connections this node makes to other validators over TLS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator_tls_config: Option<ValidatorTlsConfig>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
@@ -335,6 +343,57 @@ impl NodeConfig {
             })
             .collect()
     }
+
+    /// The fields that differ between this config and `other`, as paths of their serialized
+    /// names, e.g. `authority-store-pruning-config.num-epochs-to-retain`. Fields that are not
+    /// maps are compared as a whole.
+    pub fn changed_fields(&self, other: &NodeConfig) -> Vec<String> {
+        let mut changed = Vec::new();
+        diff_yaml_values(
+            "",
+            &serde_yaml::to_value(self).expect("Serialization should not fail"),
+            &serde_yaml::to_value(other).expect("Serialization should not fail"),
+            &mut changed,
+        );
+        changed
+    }
+}
+
+fn diff_yaml_values(
+    path: &str,
+    old: &serde_yaml::Value,
+    new: &serde_yaml::Value,
+    changed: &mut Vec<String>,
+) {
+    use serde_yaml::Value;
+
+    let (Value::Mapping(old), Value::Mapping(new)) = (old, new) else {
+        if old != new {
+            changed.push(path.to_string());
+        }
+        return;
+    };
+    let keys = old.iter().map(|(key, _)| key).chain(
+        new.iter()
+            .map(|(key, _)| key)
+            .filter(|key| !old.contains_key(key)),
+    );
+    for key in keys {
+        let name = key
+            .as_str()
+            .map_or_else(|| format!("{:?}", key), str::to_string);
+        let field = if path.is_empty() {
+            name
+        } else {
+            format!("{path}.{name}")
+        };
+        diff_yaml_values(
+            &field,
+            old.get(key).unwrap_or(&Value::Null),
+            new.get(key).unwrap_or(&Value::Null),
+            changed,
+        );
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -370,6 +429,10 @@ pub struct ConsensusConfig {
     /// on consensus latency estimates.
     pub submit_delay_step_override_millis: Option<u64>,
 
+    /// Tuning of the Narwhal layer, such as `batch_size`, `max_batch_delay`, `min_header_delay`
+    /// and `max_header_delay`, validated when the validator starts. Omitted fields take the
+    /// defaults of narwhal_config::Parameters. Validators run a single worker, the one advertised
+    /// in their on-chain validator info.
     pub narwhal_config: ConsensusParameters,
 
     /// The choice of consensus protocol to run. We default to Narwhal.
@@ -422,6 +485,14 @@ pub struct CheckpointExecutorConfig {
     #[serde(default = "default_local_execution_timeout_sec")]
     pub local_execution_timeout_sec: u64,
 
+    /// Time to commit an executed checkpoint to the db above which the number of checkpoints
+    /// executed concurrently is halved, down to 1. Below it, the number grows back by one per
+    /// checkpoint, up to `checkpoint_execution_max_concurrency`.
+    ///
+    /// If unspecified, this will default to `1000`. Set it to null to disable backpressure.
+    #[serde(default = "default_checkpoint_execution_target_commit_latency_ms")]
+    pub checkpoint_execution_target_commit_latency_ms: Option<u64>,
+
     /// Optional directory used for data ingestion pipeline
     /// When specified, each executed checkpoint will be saved in a local directory for post processing
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -459,6 +530,13 @@ pub struct ExpensiveSafetyCheckConfig {
 
     #[serde(default)]
     enable_secondary_index_checks: bool,
+
+    /// If enabled, after executing each transaction, we will check that its effects are
+    /// consistent: no object appears twice in the effects, every object changed by the
+    /// transaction is at the lamport version, the lamport version is greater than the
+    /// versions of all input objects, and SUI is conserved.
+    #[serde(default)]
+    enable_effects_invariant_check: bool,
     // TODO: Add more expensive checks here
 }
 
@@ -471,6 +549,7 @@ impl ExpensiveSafetyCheckConfig {
             enable_state_consistency_check: true,
             force_disable_state_consistency_check: false,
             enable_secondary_index_checks: false, // Disable by default for now
+            enable_effects_invariant_check: true,
         }
     }
 
@@ -482,6 +561,7 @@ impl ExpensiveSafetyCheckConfig {
             enable_state_consistency_check: false,
             force_disable_state_consistency_check: true,
             enable_secondary_index_checks: false,
+            enable_effects_invariant_check: false,
         }
     }
 
@@ -510,6 +590,10 @@ impl ExpensiveSafetyCheckConfig {
     pub fn enable_secondary_index_checks(&self) -> bool {
         self.enable_secondary_index_checks
     }
+
+    pub fn enable_effects_invariant_check(&self) -> bool {
+        self.enable_effects_invariant_check
+    }
 }
 
 fn default_checkpoint_execution_max_concurrency() -> usize {
@@ -520,11 +604,17 @@ fn default_local_execution_timeout_sec() -> u64 {
     30
 }
 
+fn default_checkpoint_execution_target_commit_latency_ms() -> Option<u64> {
+    Some(1000)
+}
+
 impl Default for CheckpointExecutorConfig {
     fn default() -> Self {
         Self {
             checkpoint_execution_max_concurrency: default_checkpoint_execution_max_concurrency(),
             local_execution_timeout_sec: default_local_execution_timeout_sec(),
+            checkpoint_execution_target_commit_latency_ms:
+                default_checkpoint_execution_target_commit_latency_ms(),
             data_ingestion_dir: None,
         }
     }
@@ -688,6 +778,41 @@ pub struct TransactionKeyValueStoreWriteConfig {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OverloadThresholdConfig {
     pub max_txn_age_in_queue: Duration,
+
+    /// Maximum number of transactions from the same sender that the validator
+    /// signs concurrently.
+    #[serde(default = "default_max_transactions_in_flight_per_sender")]
+    pub max_transactions_in_flight_per_sender: usize,
+
+    /// Maximum number of transactions from the same client IP address that the
+    /// validator signs concurrently.
+    #[serde(default = "default_max_transactions_in_flight_per_client")]
+    pub max_transactions_in_flight_per_client: usize,
+
+    /// Number of transactions pending execution above which the validator stops
+    /// signing new transactions. It is lower than the threshold for certificates,
+    /// so that already certified transactions keep making progress under load.
+    #[serde(default = "default_max_transaction_manager_queue_length_for_signing")]
+    pub max_transaction_manager_queue_length_for_signing: usize,
+
+    /// Number of transactions pending execution above which transactions are
+    /// admitted for signing by gas price. Between this length and
+    /// `max_transaction_manager_queue_length_for_signing`, the gas price required
+    /// to be signed rises linearly from the reference gas price to
+    /// `max_gas_price_multiplier_under_load` times the reference gas price.
+    #[serde(default = "default_min_transaction_manager_queue_length_for_gas_price_priority")]
+    pub min_transaction_manager_queue_length_for_gas_price_priority: usize,
+
+    /// Multiple of the reference gas price required to be signed when the queue
+    /// of transactions pending execution is about to be full.
+    #[serde(default = "default_max_gas_price_multiplier_under_load")]
+    pub max_gas_price_multiplier_under_load: u64,
+
+    /// Delay that the validator asks clients to wait before retrying when it rejects a
+    /// transaction because it is overloaded while its queue of transactions pending execution
+    /// is less than a quarter full. The delay doubles with each further quarter.
+    #[serde(default = "default_min_overload_retry_after_ms")]
+    pub min_overload_retry_after_ms: u64,
     // TODO: Move other thresholds here as well, including `MAX_TM_QUEUE_LENGTH`
     // and `MAX_PER_OBJECT_QUEUE_LENGTH`.
 }
@@ -696,6 +821,14 @@ impl Default for OverloadThresholdConfig {
     fn default() -> Self {
         Self {
             max_txn_age_in_queue: Duration::from_secs(1), // 1 second
+            max_transactions_in_flight_per_sender: default_max_transactions_in_flight_per_sender(),
+            max_transactions_in_flight_per_client: default_max_transactions_in_flight_per_client(),
+            max_transaction_manager_queue_length_for_signing:
+                default_max_transaction_manager_queue_length_for_signing(),
+            min_transaction_manager_queue_length_for_gas_price_priority:
+                default_min_transaction_manager_queue_length_for_gas_price_priority(),
+            max_gas_price_multiplier_under_load: default_max_gas_price_multiplier_under_load(),
+            min_overload_retry_after_ms: default_min_overload_retry_after_ms(),
         }
     }
 }
@@ -704,6 +837,30 @@ fn default_overload_threshold_config() -> OverloadThresholdConfig {
     OverloadThresholdConfig::default()
 }
 
+fn default_max_transactions_in_flight_per_sender() -> usize {
+    1_000
+}
+
+fn default_max_transactions_in_flight_per_client() -> usize {
+    5_000
+}
+
+fn default_max_transaction_manager_queue_length_for_signing() -> usize {
+    50_000
+}
+
+fn default_min_transaction_manager_queue_length_for_gas_price_priority() -> usize {
+    25_000
+}
+
+fn default_max_gas_price_multiplier_under_load() -> u64 {
+    10
+}
+
+fn default_min_overload_retry_after_ms() -> u64 {
+    250
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Eq)]
 pub struct Genesis {
     #[serde(flatten)]
@@ -882,6 +1039,23 @@ impl AuthorityKeyPairWithPath {
     }
 }
 
+/// TLS on the validator gRPC service. The validator authenticates with its network key, and
+/// clients must authenticate with theirs, which must belong to the current committee or be
+/// allowed explicitly.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ValidatorTlsConfig {
+    /// Names of the allowed cipher suites, e.g. `TLS13_AES_256_GCM_SHA384`. If empty, the
+    /// rustls defaults are used.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_cipher_suites: Vec<String>,
+
+    /// Network keys of the nodes outside of the committee allowed to connect, e.g. the full
+    /// nodes that submit transactions to this validator.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_client_network_keys: Vec<NetworkPublicKey>,
+}
+
 /// Configurations which determine how we dump state debug info.
 /// Debug info is dumped when a node forks.
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
@@ -956,6 +1130,30 @@ mod tests {
             worker_key_pair.public()
         );
     }
+
+    #[test]
+    fn changed_fields() {
+        const TEMPLATE: &str = include_str!("../data/fullnode-template.yaml");
+        let config: NodeConfig = serde_yaml::from_str(TEMPLATE).unwrap();
+        assert!(config.changed_fields(&config.clone()).is_empty());
+
+        let mut new_config = config.clone();
+        new_config
+            .authority_store_pruning_config
+            .num_epochs_to_retain = 5;
+        new_config
+            .authority_store_pruning_config
+            .num_epochs_to_retain_for_checkpoints = Some(2);
+        new_config.admin_interface_port += 1;
+        assert_eq!(
+            config.changed_fields(&new_config),
+            vec![
+                "admin-interface-port",
+                "authority-store-pruning-config.num-epochs-to-retain",
+                "authority-store-pruning-config.num-epochs-to-retain-for-checkpoints",
+            ]
+        );
+    }
 }
 
 // RunWithRange is used to specify the ending epoch/checkpoint to process.
@@ -85,7 +85,7 @@ use sui_types::effects::{
     InputSharedObject, SignedTransactionEffects, TransactionEffects, TransactionEffectsAPI,
     TransactionEvents, VerifiedCertifiedTransactionEffects, VerifiedSignedTransactionEffects,
 };
-use sui_types::error::{ExecutionError, UserInputError};
+use sui_types::error::{ExecutionError, QueueDepthClass, UserInputError};
 use sui_types::event::{Event, EventID};
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
 use sui_types::gas::{GasCostSummary, SuiGasStatus};
@@ -102,11 +102,15 @@ use sui_types::messages_checkpoint::{
 };
 use sui_types::messages_consensus::AuthorityCapabilities;
 use sui_types::messages_grpc::{
-    HandleTransactionResponse, LayoutGenerationOption, ObjectInfoRequest, ObjectInfoRequestKind,
-    ObjectInfoResponse, TransactionInfoRequest, TransactionInfoResponse, TransactionStatus,
+    ConflictingTransactionsRequest, ConflictingTransactionsResponse, EpochStateCommitmentRequest,
+    EpochStateCommitmentResponse, HandleTransactionResponse, InclusionGasPriceRequest,
+    InclusionGasPriceResponse, LayoutGenerationOption, ObjectInfoRequest, ObjectInfoRequestKind,
+    ObjectInfoResponse, ObjectOwnershipProofRequest, ObjectOwnershipProofResponse,
+    TransactionInfoRequest, TransactionInfoResponse, TransactionStatus,
 };
 use sui_types::metrics::{BytecodeVerifierMetrics, LimitsMetrics};
 use sui_types::object::{MoveObject, Owner, PastObjectRead, OBJECT_START_VERSION};
+use sui_types::object_proof::ObjectOwnershipProof;
 use sui_types::storage::{GetSharedLocks, ObjectKey, ObjectOrTombstone, ObjectStore, WriteKind};
 use sui_types::sui_system_state::epoch_start_sui_system_state::EpochStartSystemStateTrait;
 use sui_types::sui_system_state::SuiSystemStateTrait;
@@ -128,8 +132,11 @@ use crate::authority::authority_per_epoch_store::{AuthorityPerEpochStore, CertTx
 use crate::authority::authority_per_epoch_store_pruner::AuthorityPerEpochStorePruner;
 use crate::authority::authority_store::{ExecutionLockReadGuard, ObjectLockStatus};
 use crate::authority::authority_store_pruner::AuthorityStorePruner;
+use crate::authority::effects_invariants::check_effects_invariants;
 use crate::authority::epoch_start_configuration::EpochStartConfigTrait;
 use crate::authority::epoch_start_configuration::EpochStartConfiguration;
+use crate::authority::historical_state_view::HistoricalStateView;
+use crate::authority_client::ValidatorClientTlsConfig;
 use crate::checkpoints::checkpoint_executor::CheckpointExecutor;
 use crate::checkpoints::CheckpointStore;
 use crate::consensus_adapter::ConsensusAdapter;
@@ -175,10 +182,13 @@ pub mod authority_test_utils;
 pub mod authority_per_epoch_store;
 pub mod authority_per_epoch_store_pruner;
 
+pub mod authority_store_migrations;
 pub mod authority_store_pruner;
 pub mod authority_store_tables;
 pub mod authority_store_types;
+pub mod effects_invariants;
 pub mod epoch_start_configuration;
+pub mod historical_state_view;
 pub mod test_authority_builder;
 
 pub(crate) mod authority_notify_read;
@@ -640,8 +650,8 @@ pub struct AuthorityState {
     tx_execution_shutdown: Mutex<Option<oneshot::Sender<()>>>,
 
     pub metrics: Arc<AuthorityMetrics>,
-    _pruner: AuthorityStorePruner,
-    _authority_per_epoch_pruner: AuthorityPerEpochStorePruner,
+    pruner: AuthorityStorePruner,
+    authority_per_epoch_pruner: AuthorityPerEpochStorePruner,
 
     /// Take db checkpoints of different dbs
     db_checkpoint_config: DBCheckpointConfig,
@@ -656,8 +666,11 @@ pub struct AuthorityState {
     /// Config for state dumping on forks
     debug_dump_config: StateDebugDumpConfig,
 
-    /// Config for when we consider the node overloaded.
-    overload_threshold_config: OverloadThresholdConfig,
+    /// Config for when we consider the node overloaded. It can be reloaded at runtime.
+    overload_threshold_config: ArcSwap<OverloadThresholdConfig>,
+
+    /// TLS settings used by the clients this node creates to connect to validators.
+    validator_client_tls_config: Option<ValidatorClientTlsConfig>,
 }
 
 /// The authority state encapsulates all state, drives execution, and ensures safety.
@@ -684,7 +697,27 @@ impl AuthorityState {
     }
 
     pub fn max_txn_age_in_queue(&self) -> Duration {
-        self.overload_threshold_config.max_txn_age_in_queue
+        self.overload_threshold_config.load().max_txn_age_in_queue
+    }
+
+    pub fn validator_client_tls_config(&self) -> Option<&ValidatorClientTlsConfig> {
+        self.validator_client_tls_config.as_ref()
+    }
+
+    pub fn overload_threshold_config(&self) -> Arc<OverloadThresholdConfig> {
+        self.overload_threshold_config.load_full()
+    }
+
+    pub fn update_overload_threshold_config(&self, config: OverloadThresholdConfig) {
+        self.overload_threshold_config.store(Arc::new(config));
+    }
+
+    pub fn pruning_config(&self) -> AuthorityStorePruningConfig {
+        self.pruner.config()
+    }
+
+    pub fn update_pruning_config(&self, config: AuthorityStorePruningConfig) {
+        self.pruner.update_config(config);
     }
 
     pub fn get_epoch_state_commitments(
@@ -707,7 +740,7 @@ impl AuthorityState {
 
     /// This is a private method and should be kept that way. It doesn't check whether
     /// the provided transaction is a system transaction, and hence can only be called internally.
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?transaction.digest(), epoch = epoch_store.epoch()))]
     async fn handle_transaction_impl(
         &self,
         transaction: VerifiedTransaction,
@@ -778,7 +811,7 @@ impl AuthorityState {
     }
 
     /// Initiate a new transaction.
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?transaction.digest(), epoch = epoch_store.epoch()))]
     pub async fn handle_transaction(
         &self,
         epoch_store: &Arc<AuthorityPerEpochStore>,
@@ -851,11 +884,67 @@ impl AuthorityState {
         Ok(())
     }
 
+    /// Overload check for signing new transactions. New transactions are shed before
+    /// certificates, so that already certified transactions keep making progress. When
+    /// the queue of transactions pending execution fills up, transactions paying less
+    /// than the inclusion gas price threshold are shed first.
+    pub(crate) fn check_system_overload_for_signing(
+        &self,
+        consensus_adapter: &Arc<ConsensusAdapter>,
+        tx_data: &SenderSignedData,
+        epoch_store: &AuthorityPerEpochStore,
+    ) -> SuiResult {
+        let inflight_queue_len = self.transaction_manager.inflight_queue_len();
+        let overload_threshold_config = self.overload_threshold_config.load();
+        let threshold = overload_threshold_config.max_transaction_manager_queue_length_for_signing;
+        fp_ensure!(
+            inflight_queue_len < threshold,
+            SuiError::TooManyTransactionsPendingExecution {
+                queue_len: inflight_queue_len,
+                threshold,
+            }
+        );
+        let gas_price = tx_data.transaction_data().gas_price();
+        let threshold = inclusion_gas_price_threshold(
+            &overload_threshold_config,
+            inflight_queue_len,
+            epoch_store.reference_gas_price(),
+        );
+        fp_ensure!(
+            gas_price >= threshold,
+            SuiError::GasPriceTooLowUnderLoad {
+                gas_price,
+                threshold,
+            }
+        );
+        self.check_system_overload(consensus_adapter, tx_data)
+    }
+
+    /// Attaches the current queue depth class and a retry-after hint to an overload error, so
+    /// that clients back off from this validator instead of retrying into deeper overload. The
+    /// hint doubles with each queue depth class.
+    pub(crate) fn with_overload_hint(&self, error: SuiError) -> SuiError {
+        if !error.is_overload() {
+            return error;
+        }
+        let overload_threshold_config = self.overload_threshold_config.load();
+        let queue_depth = QueueDepthClass::new(
+            self.transaction_manager.inflight_queue_len(),
+            overload_threshold_config.max_transaction_manager_queue_length_for_signing,
+        );
+        SuiError::ValidatorOverloaded {
+            error: Box::new(error),
+            queue_depth,
+            retry_after_ms: overload_threshold_config.min_overload_retry_after_ms
+                << queue_depth as u32,
+        }
+    }
+
     /// Executes a transaction that's known to have correct effects.
     /// For such transaction, we don't have to wait for consensus to set shared object
     /// locks because we already know the shared object versions based on the effects.
     /// This function can be called by a fullnode only.
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?transaction.digest(), epoch = epoch_store.epoch()))]
     pub async fn fullnode_execute_certificate_with_effects(
         &self,
         transaction: &VerifiedExecutableTransaction,
@@ -914,7 +1003,7 @@ impl AuthorityState {
     }
 
     /// Executes a certificate for its effects.
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?certificate.digest(), epoch = epoch_store.epoch()))]
     pub async fn execute_certificate(
         &self,
         certificate: &VerifiedCertificate,
@@ -955,7 +1044,7 @@ impl AuthorityState {
     /// If this cannot be satisfied by the caller, execute_certificate() should be called instead.
     ///
     /// Should only be called within sui-core.
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?certificate.digest(), epoch = epoch_store.epoch()))]
     pub async fn try_execute_immediately(
         &self,
         certificate: &VerifiedExecutableTransaction,
@@ -1076,7 +1165,7 @@ impl AuthorityState {
         .map_err(|e| SuiError::FileIOError(e.to_string()))
     }
 
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?certificate.digest(), epoch = epoch_store.epoch()))]
     pub(crate) async fn process_certificate(
         &self,
         tx_guard: CertTxGuard,
@@ -1219,7 +1308,7 @@ impl AuthorityState {
         Ok((effects, execution_error_opt))
     }
 
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?certificate.digest(), epoch = epoch_store.epoch()))]
     async fn commit_certificate(
         &self,
         certificate: &VerifiedExecutableTransaction,
@@ -1345,7 +1434,7 @@ impl AuthorityState {
     /// non-transient error, e.g. the transaction input is somehow invalid, the correct
     /// locks are not held, etc. However, this is not entirely true, as a transient db read error
     /// may also cause this function to fail.
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(level = "trace", skip_all, fields(tx_digest = ?certificate.digest(), epoch = epoch_store.epoch()))]
     async fn prepare_certificate(
         &self,
         _execution_guard: &ExecutionLockReadGuard<'_>,
@@ -1404,6 +1493,29 @@ impl AuthorityState {
                 tx_digest,
             );
 
+        if self
+            .expensive_safety_check_config
+            .enable_effects_invariant_check()
+        {
+            let mut layout_resolver =
+                epoch_store
+                    .executor()
+                    .type_layout_resolver(Box::new(TemporaryPackageStore::new(
+                        &inner_temp_store,
+                        self.database.clone(),
+                    )));
+            // The epoch change mints and burns SUI.
+            if let Err(report) = check_effects_invariants(
+                &inner_temp_store,
+                &effects,
+                !tx_data.kind().is_end_of_epoch_tx(),
+                self.database.as_ref(),
+                layout_resolver.as_mut(),
+            ) {
+                panic!("{report}\nEffects: {effects:?}");
+            }
+        }
+
         fail_point_if!("cp_execution_nondeterminism", || {
             #[cfg(msim)]
             self.create_fail_state(certificate, epoch_store, &mut effects);
@@ -1777,6 +1889,155 @@ impl AuthorityState {
         )
     }
 
+    /// Runs the transaction in dev-inspect mode against the objects as of the end of
+    /// `checkpoint`, to query what a view function returned at that point. Objects are read at
+    /// their version at the checkpoint, regardless of the versions in the transaction, and the
+    /// transaction runs under the protocol config of the epoch of the checkpoint. Checks are
+    /// skipped and a dummy gas coin is used.
+    pub async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender: SuiAddress,
+        transaction_kind: TransactionKind,
+        checkpoint: CheckpointSequenceNumber,
+        gas_price: Option<u64>,
+        gas_budget: Option<u64>,
+    ) -> SuiResult<DevInspectResults> {
+        let epoch_store = self.load_epoch_store_one_call_per_task();
+
+        if !self.is_fullnode(&epoch_store) {
+            return Err(SuiError::UnsupportedFeatureError {
+                error: "dev-inspect is only supported on fullnodes".to_string(),
+            });
+        }
+
+        if transaction_kind.is_system_tx() {
+            return Err(SuiError::UnsupportedFeatureError {
+                error: "system transactions are not supported".to_string(),
+            });
+        }
+
+        let highest_executed = self
+            .checkpoint_store
+            .get_highest_executed_checkpoint_seq_number()?
+            .ok_or(UserInputError::LatestCheckpointSequenceNumberNotFound)?;
+        let view = HistoricalStateView::new(self.database.clone(), checkpoint, highest_executed)?;
+        let summary = self
+            .checkpoint_store
+            .get_checkpoint_by_sequence_number(checkpoint)?
+            .ok_or(UserInputError::VerifiedCheckpointNotFound(checkpoint))?;
+        let epoch = summary.epoch;
+        // Epochs started before the execution config was stored run under the current rules.
+        let protocol_config = match self.database.get_epoch_execution_config(epoch)? {
+            Some(config) => config.protocol_config(),
+            None => epoch_store.protocol_config().clone(),
+        };
+        let epoch_start_timestamp_ms = match epoch.checked_sub(1) {
+            Some(previous_epoch) => self
+                .checkpoint_store
+                .get_epoch_last_checkpoint(previous_epoch)?
+                .map(|checkpoint| checkpoint.timestamp_ms),
+            None => self
+                .checkpoint_store
+                .get_checkpoint_by_sequence_number(0)?
+                .map(|checkpoint| checkpoint.timestamp_ms),
+        }
+        .unwrap_or(summary.timestamp_ms);
+
+        let reference_gas_price = epoch_store.reference_gas_price();
+        let max_tx_gas = protocol_config.max_tx_gas();
+        let price = gas_price.unwrap_or(reference_gas_price);
+        let budget = gas_budget.unwrap_or(max_tx_gas);
+
+        let mut input_objects = vec![];
+        for kind in transaction_kind.input_objects()? {
+            let id = kind.object_id();
+            let object = view
+                .get_object(&id)?
+                .ok_or(UserInputError::ObjectNotFound {
+                    object_id: id,
+                    version: None,
+                })?;
+            let kind = match kind {
+                InputObjectKind::ImmOrOwnedMoveObject(_) => {
+                    InputObjectKind::ImmOrOwnedMoveObject(object.compute_object_reference())
+                }
+                kind => kind,
+            };
+            input_objects.push(ObjectReadResult::new(kind, object.into()));
+        }
+        let mut receiving_objects = vec![];
+        for object_ref in transaction_kind.receiving_objects() {
+            let object = view.get_object_by_key(&object_ref.0, object_ref.1)?.ok_or(
+                UserInputError::ObjectNotFound {
+                    object_id: object_ref.0,
+                    version: Some(object_ref.1),
+                },
+            )?;
+            receiving_objects.push(ReceivingObjectReadResult::new(
+                object_ref,
+                ReceivingObjectReadResultKind::Object(object),
+            ));
+        }
+
+        let dummy_gas_object =
+            Object::new_gas_with_balance_and_owner_for_testing(DEV_INSPECT_GAS_COIN_VALUE, sender);
+        let gas_object_ref = dummy_gas_object.compute_object_reference();
+        input_objects.push(ObjectReadResult::new(
+            InputObjectKind::ImmOrOwnedMoveObject(gas_object_ref),
+            dummy_gas_object.into(),
+        ));
+        let checked_input_objects = sui_transaction_checks::check_dev_inspect_input(
+            &protocol_config,
+            &transaction_kind,
+            input_objects.into(),
+            receiving_objects.into(),
+        )?;
+        let gas_status =
+            SuiGasStatus::new(max_tx_gas, price, reference_gas_price, &protocol_config)?;
+
+        let transaction = TransactionData::new_with_gas_data(
+            transaction_kind.clone(),
+            sender,
+            GasData {
+                payment: vec![gas_object_ref],
+                owner: sender,
+                price,
+                budget,
+            },
+        );
+        let executor = sui_execution::executor(&protocol_config, /* silent */ true)
+            .expect("Creating an executor should not fail here");
+        let transaction_digest = TransactionDigest::new(default_hash(&transaction));
+        let (inner_temp_store, effects, execution_result) = executor.dev_inspect_transaction(
+            &view,
+            &protocol_config,
+            self.metrics.limits_metrics.clone(),
+            /* expensive checks */ false,
+            self.certificate_deny_config.certificate_deny_set(),
+            &epoch,
+            epoch_start_timestamp_ms,
+            checked_input_objects,
+            vec![gas_object_ref],
+            gas_status,
+            transaction_kind,
+            sender,
+            transaction_digest,
+            /* skip_all_checks */ true,
+        );
+
+        let package_store = TemporaryPackageStore::new(&inner_temp_store, self.database.clone());
+        let mut layout_resolver = epoch_store
+            .executor()
+            .type_layout_resolver(Box::new(package_store));
+
+        DevInspectResults::new(
+            effects,
+            inner_temp_store.events.clone(),
+            execution_result,
+            layout_resolver.as_mut(),
+        )
+    }
+
     // Only used for testing because of how epoch store is loaded.
     pub fn reference_gas_price_for_testing(&self) -> Result<u64, anyhow::Error> {
         let epoch_store = self.epoch_store_for_testing();
@@ -2068,7 +2329,7 @@ impl AuthorityState {
         }))
     }
 
-    #[instrument(level = "trace", skip_all, err)]
+    #[instrument(level = "trace", skip_all, err, fields(tx_digest = ?certificate.digest(), epoch = epoch_store.epoch()))]
     async fn post_process_one_tx(
         &self,
         certificate: &VerifiedExecutableTransaction,
@@ -2244,6 +2505,111 @@ impl AuthorityState {
         })
     }
 
+    #[instrument(level = "trace", skip_all)]
+    pub fn handle_conflicting_transactions_request(
+        &self,
+        request: ConflictingTransactionsRequest,
+    ) -> SuiResult<ConflictingTransactionsResponse> {
+        let conflicts = self
+            .database
+            .get_conflicting_transactions(request.object_id)?;
+        Ok(ConflictingTransactionsResponse { conflicts })
+    }
+
+    /// Proves the latest state of an object with the certificate of the transaction that wrote
+    /// it, and the checkpoint that includes that transaction. Certificate signatures are only
+    /// kept for the current epoch, so objects last written in earlier epochs have no proof.
+    #[instrument(level = "trace", skip_all)]
+    pub fn handle_object_ownership_proof_request(
+        &self,
+        request: ObjectOwnershipProofRequest,
+    ) -> SuiResult<ObjectOwnershipProofResponse> {
+        let epoch_store = self.load_epoch_store_one_call_per_task();
+        let proof = self.get_object_ownership_proof(&request.object_id, &epoch_store)?;
+        Ok(ObjectOwnershipProofResponse { proof })
+    }
+
+    fn get_object_ownership_proof(
+        &self,
+        object_id: &ObjectID,
+        epoch_store: &AuthorityPerEpochStore,
+    ) -> SuiResult<Option<ObjectOwnershipProof>> {
+        let Some(object) = self.database.get_object(object_id)? else {
+            return Ok(None);
+        };
+        let digest = object.previous_transaction;
+        let Some(cert_sig) = epoch_store.get_transaction_cert_sig(&digest)? else {
+            return Ok(None);
+        };
+        let Some(checkpoint) = self.get_transaction_checkpoint(&digest, epoch_store)? else {
+            return Ok(None);
+        };
+        let transaction = self
+            .database
+            .get_transaction_block(&digest)?
+            .ok_or(SuiError::TransactionNotFound { digest })?;
+        let effects = self
+            .database
+            .get_executed_effects(&digest)?
+            .ok_or(SuiError::TransactionNotFound { digest })?;
+        let checkpoint_contents = self
+            .checkpoint_store
+            .get_checkpoint_contents(&checkpoint.content_digest)?
+            .ok_or_else(|| SuiError::GenericAuthorityError {
+                error: format!(
+                    "Contents of checkpoint {} not found",
+                    checkpoint.sequence_number
+                ),
+            })?;
+        Ok(Some(ObjectOwnershipProof {
+            object,
+            certificate: CertifiedTransaction::new_from_data_and_sig(
+                transaction.into_message(),
+                cert_sig,
+            ),
+            effects,
+            checkpoint: checkpoint.into_inner(),
+            checkpoint_contents,
+        }))
+    }
+
+    /// Returns the certified commitment to the live object set at the end of the epoch, with the
+    /// live object set digest accumulated by this authority, so that clients can check that the
+    /// state of this authority agrees with the committee.
+    #[instrument(level = "trace", skip_all)]
+    pub fn handle_epoch_state_commitment_request(
+        &self,
+        request: EpochStateCommitmentRequest,
+    ) -> SuiResult<EpochStateCommitmentResponse> {
+        let checkpoint = self
+            .checkpoint_store
+            .get_epoch_last_checkpoint(request.epoch)?
+            .map(VerifiedCheckpoint::into_inner);
+        let root_state_digest = self.database.try_get_root_state_hash(request.epoch)?;
+        Ok(EpochStateCommitmentResponse {
+            checkpoint,
+            root_state_digest,
+        })
+    }
+
+    #[instrument(level = "trace", skip_all)]
+    pub fn handle_inclusion_gas_price_request(
+        &self,
+        _request: InclusionGasPriceRequest,
+    ) -> SuiResult<InclusionGasPriceResponse> {
+        let epoch_store = self.load_epoch_store_one_call_per_task();
+        let reference_gas_price = epoch_store.reference_gas_price();
+        Ok(InclusionGasPriceResponse {
+            epoch: epoch_store.epoch(),
+            reference_gas_price,
+            inclusion_gas_price: inclusion_gas_price_threshold(
+                &self.overload_threshold_config.load(),
+                self.transaction_manager.inflight_queue_len(),
+                reference_gas_price,
+            ),
+        })
+    }
+
     #[instrument(level = "trace", skip_all)]
     pub fn handle_checkpoint_request(
         &self,
@@ -2347,6 +2713,7 @@ impl AuthorityState {
         debug_dump_config: StateDebugDumpConfig,
         overload_threshold_config: OverloadThresholdConfig,
         archive_readers: ArchiveReaderBalancer,
+        validator_client_tls_config: Option<ValidatorClientTlsConfig>,
     ) -> Arc<Self> {
         Self::check_protocol_version(supported_protocol_versions, epoch_store.protocol_version());
 
@@ -2360,9 +2727,9 @@ impl AuthorityState {
         ));
         let (tx_execution_shutdown, rx_execution_shutdown) = oneshot::channel();
 
-        let _authority_per_epoch_pruner =
+        let authority_per_epoch_pruner =
             AuthorityPerEpochStorePruner::new(epoch_store.get_parent_path(), &pruning_config);
-        let _pruner = AuthorityStorePruner::new(
+        let pruner = AuthorityStorePruner::new(
             store.perpetual_tables.clone(),
             checkpoint_store.clone(),
             store.objects_lock_table.clone(),
@@ -2387,14 +2754,15 @@ impl AuthorityState {
             transaction_manager,
             tx_execution_shutdown: Mutex::new(Some(tx_execution_shutdown)),
             metrics,
-            _pruner,
-            _authority_per_epoch_pruner,
+            pruner,
+            authority_per_epoch_pruner,
             db_checkpoint_config: db_checkpoint_config.clone(),
             expensive_safety_check_config,
             transaction_deny_config,
             certificate_deny_config,
             debug_dump_config,
-            overload_threshold_config,
+            overload_threshold_config: ArcSwap::from_pointee(overload_threshold_config),
+            validator_client_tls_config,
         });
 
         // Start a task to execute ready certificates.
@@ -2453,15 +2821,21 @@ impl AuthorityState {
     fn clear_object_per_epoch_marker_table(
         &self,
         _execution_guard: &ExecutionLockWriteGuard<'_>,
+        new_epoch: EpochId,
     ) -> SuiResult<()> {
-        // We can safely delete all entries in the per epoch marker table since this is only called
-        // at epoch boundaries (during reconfiguration). Therefore any entries that currently
-        // exist can be removed. Because of this we can use the `schedule_delete_all` method.
-        Ok(self
-            .database
-            .perpetual_tables
-            .object_per_epoch_marker_table
-            .schedule_delete_all()?)
+        // We can safely delete all entries of previous epochs in the per epoch marker table since
+        // this is only called at epoch boundaries (during reconfiguration). The table is keyed by
+        // epoch first, so the entries of all previous epochs are dropped with a single range
+        // deletion, without scanning the table.
+        let table = &self.database.perpetual_tables.object_per_epoch_marker_table;
+        let mut batch = table.batch();
+        batch.schedule_delete_range(
+            table,
+            &(EpochId::MIN, ObjectKey::ZERO),
+            &(new_epoch, ObjectKey::ZERO),
+        )?;
+        batch.write()?;
+        Ok(())
     }
 
     fn create_owner_index_if_empty(
@@ -2546,7 +2920,7 @@ impl AuthorityState {
                 .epoch_start_state()
                 .protocol_version(),
         );
-        self.clear_object_per_epoch_marker_table(&execution_lock)?;
+        self.clear_object_per_epoch_marker_table(&execution_lock, new_committee.epoch)?;
         self.db()
             .set_epoch_start_configuration(&epoch_start_configuration)
             .await?;
@@ -2579,6 +2953,8 @@ impl AuthorityState {
             )
             .await?;
         assert_eq!(new_epoch_store.epoch(), new_epoch);
+        // The database of the new epoch is open, so the databases of old epochs can be dropped.
+        self.authority_per_epoch_pruner.prune_now();
         self.transaction_manager.reconfigure(new_epoch);
         *execution_lock = new_epoch;
         // drop execution_lock after epoch store was updated
@@ -4481,6 +4857,19 @@ impl AuthorityState {
         Ok(())
     }
 
+    /// Returns whether `address` is in the deny list of the regulated coin `coin_type`, in its
+    /// canonical form.
+    pub fn is_address_denied_for_coin(&self, address: SuiAddress, coin_type: String) -> bool {
+        if !self
+            .load_epoch_store_one_call_per_task()
+            .coin_deny_list_state_enabled()
+        {
+            return false;
+        }
+        DenyList::check_coin_deny_list(address, BTreeSet::from([coin_type]), &self.database)
+            .is_err()
+    }
+
     #[instrument(level = "error", skip_all)]
     async fn reopen_epoch_db(
         &self,
@@ -4548,6 +4937,28 @@ impl AuthorityState {
     }
 }
 
+/// The lowest gas price of transactions admitted for signing with `queue_len` transactions
+/// pending execution. It is the reference gas price until the queue reaches
+/// `min_transaction_manager_queue_length_for_gas_price_priority`, then rises linearly to
+/// `max_gas_price_multiplier_under_load` times the reference gas price when the queue is full.
+pub(crate) fn inclusion_gas_price_threshold(
+    config: &OverloadThresholdConfig,
+    queue_len: usize,
+    reference_gas_price: u64,
+) -> u64 {
+    let start = config.min_transaction_manager_queue_length_for_gas_price_priority;
+    let end = config.max_transaction_manager_queue_length_for_signing;
+    if queue_len <= start || end <= start {
+        return reference_gas_price;
+    }
+    let progress = (queue_len.min(end) - start) as u128;
+    let range = (end - start) as u128;
+    let max_increase = (reference_gas_price as u128)
+        * (config.max_gas_price_multiplier_under_load.saturating_sub(1) as u128);
+    let increase = max_increase * progress / range;
+    (reference_gas_price as u128 + increase).min(u64::MAX as u128) as u64
+}
+
 #[async_trait]
 impl TransactionKeyValueStoreTrait for AuthorityState {
     async fn multi_get(
@@ -4792,6 +5203,9 @@ pub struct NodeStateDump {
     pub executed_epoch: u64,
     pub reference_gas_price: u64,
     pub protocol_version: u64,
+    /// The config the transaction was executed with. Missing in dumps of older nodes.
+    #[serde(default)]
+    pub protocol_config: Option<ProtocolConfig>,
     pub epoch_start_timestamp_ms: u64,
     pub computed_effects: TransactionEffects,
     pub expected_effects_digest: TransactionEffectsDigest,
@@ -4876,6 +5290,7 @@ impl NodeStateDump {
             reference_gas_price,
             epoch_start_timestamp_ms,
             protocol_version,
+            protocol_config: Some(epoch_store.protocol_config().clone()),
             relevant_system_packages,
             shared_objects,
             loaded_child_objects,
@@ -8,6 +8,7 @@ use fastcrypto_zkp::bn254::zk_login_api::ZkLoginEnv;
 use futures::future::{join_all, select, Either};
 use futures::FutureExt;
 use itertools::{izip, Itertools};
+use lru::LruCache;
 use narwhal_executor::ExecutionIndices;
 use parking_lot::RwLock;
 use parking_lot::{Mutex, RwLockReadGuard, RwLockWriteGuard};
@@ -16,6 +17,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::future::Future;
 use std::iter;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use sui_config::node::ExpensiveSafetyCheckConfig;
@@ -43,7 +45,9 @@ use typed_store::{
 };
 
 use super::epoch_start_configuration::EpochStartConfigTrait;
-use crate::authority::epoch_start_configuration::{EpochFlag, EpochStartConfiguration};
+use crate::authority::epoch_start_configuration::{
+    EpochExecutionConfig, EpochFlag, EpochStartConfiguration,
+};
 use crate::authority::{AuthorityStore, ResolverWrapper};
 use crate::checkpoints::{
     BuilderCheckpointSummary, CheckpointCommitHeight, CheckpointServiceNotify, EpochStats,
@@ -77,6 +81,7 @@ use sui_types::executable_transaction::{
 use sui_types::message_envelope::TrustedEnvelope;
 use sui_types::messages_checkpoint::{
     CheckpointContents, CheckpointSequenceNumber, CheckpointSignatureMessage, CheckpointSummary,
+    CheckpointTimestamp,
 };
 use sui_types::messages_consensus::{
     check_total_jwk_size, AuthorityCapabilities, ConsensusTransaction, ConsensusTransactionKey,
@@ -100,6 +105,8 @@ const LAST_CONSENSUS_STATS_ADDR: u64 = 0;
 const RECONFIG_STATE_INDEX: u64 = 0;
 const OVERRIDE_PROTOCOL_UPGRADE_BUFFER_STAKE_INDEX: u64 = 0;
 pub const EPOCH_DB_PREFIX: &str = "epoch_";
+/// Number of certificates seen in the batches of other validators that are cached in memory.
+const PEER_BATCHED_CERTIFICATES_CACHE_SIZE: usize = 100_000;
 
 // CertLockGuard and CertTxGuard are functionally identical right now, but we retain a distinction
 // anyway. If we need to support distributed object storage, having this distinction will be
@@ -258,6 +265,11 @@ pub struct AuthorityPerEpochStore {
     /// In particular, this lock is always acquired after taking read or write lock on reconfig state
     pending_consensus_certificates: Mutex<HashSet<TransactionDigest>>,
 
+    /// Cache of AuthorityEpochTables::peer_batched_certificates, holding the certificates
+    /// recently seen in the batches of other validators.
+    /// Lock ordering: this is a 'leaf' lock, no other locks should be acquired in the scope of this lock
+    peer_batched_certificates_cache: Mutex<LruCache<TransactionDigest, ()>>,
+
     /// MutexTable for transaction locks (prevent concurrent execution of same transaction)
     mutex_table: MutexTable<TransactionDigest>,
 
@@ -430,6 +442,11 @@ pub struct AuthorityEpochTables {
 
     /// Records the round numbers for which we have written randomness.
     randomness_rounds_written: DBMap<RandomnessRound, ()>,
+
+    /// Certificates seen in the batches of other validators, which are likely to be sequenced
+    /// without this validator submitting them as well. Persisted so that a restarted validator
+    /// keeps postponing their submission.
+    peer_batched_certificates: DBMap<TransactionDigest, ()>,
 }
 
 // DeferralKey requires both the round to which the tx should be deferred (so that we can
@@ -695,8 +712,27 @@ impl AuthorityPerEpochStore {
         let protocol_version = epoch_start_configuration
             .epoch_start_state()
             .protocol_version();
+        // Live execution always follows this binary's definition of the protocol version, so that
+        // all nodes on the same binary agree. The config the epoch started with is only stored for
+        // replay and diagnostics; a mismatch is reported rather than acted upon.
         let protocol_config =
             ProtocolConfig::get_for_version(protocol_version, chain_identifier.chain());
+        let execution_config = EpochExecutionConfig::new(&protocol_config);
+        match store
+            .perpetual_tables
+            .get_or_insert_epoch_execution_config(epoch_id, execution_config.clone())
+        {
+            Ok(stored) => {
+                let mismatch = stored != execution_config;
+                if mismatch {
+                    error!(
+                        "Execution config of epoch {epoch_id} differs from the one it started with"
+                    );
+                }
+                metrics.epoch_execution_config_mismatch.set(mismatch as i64);
+            }
+            Err(e) => error!("Failed to store the execution config of epoch {epoch_id}: {e:?}"),
+        }
 
         let execution_component = ExecutionComponents::new(
             &protocol_config,
@@ -777,6 +813,9 @@ impl AuthorityPerEpochStore {
             checkpoint_state_notify_read: NotifyRead::new(),
             end_of_publish: Mutex::new(end_of_publish),
             pending_consensus_certificates: Mutex::new(pending_consensus_certificates),
+            peer_batched_certificates_cache: Mutex::new(LruCache::new(
+                NonZeroUsize::new(PEER_BATCHED_CERTIFICATES_CACHE_SIZE).unwrap(),
+            )),
             mutex_table: MutexTable::new(MUTEX_TABLE_SIZE),
             epoch_open_time: current_time,
             epoch_close_time: Default::default(),
@@ -1495,6 +1534,48 @@ impl AuthorityPerEpochStore {
         self.pending_consensus_certificates.lock().clone()
     }
 
+    /// Whether the certificate was submitted to consensus and is not sequenced yet.
+    pub fn is_pending_consensus_certificate(&self, tx_digest: &TransactionDigest) -> bool {
+        self.pending_consensus_certificates
+            .lock()
+            .contains(tx_digest)
+    }
+
+    /// Records certificates included in a batch of another validator.
+    pub fn record_peer_batched_certificates(
+        &self,
+        tx_digests: Vec<TransactionDigest>,
+    ) -> SuiResult {
+        self.tables()?
+            .peer_batched_certificates
+            .multi_insert(tx_digests.iter().map(|tx_digest| (*tx_digest, ())))?;
+        let mut cache = self.peer_batched_certificates_cache.lock();
+        for tx_digest in tx_digests {
+            cache.put(tx_digest, ());
+        }
+        Ok(())
+    }
+
+    /// Whether the certificate was seen in a batch of another validator in this epoch.
+    pub fn is_peer_batched_certificate(&self, tx_digest: &TransactionDigest) -> SuiResult<bool> {
+        if self
+            .peer_batched_certificates_cache
+            .lock()
+            .contains(tx_digest)
+        {
+            return Ok(true);
+        }
+        Ok(self
+            .tables()?
+            .peer_batched_certificates
+            .contains_key(tx_digest)?)
+    }
+
+    #[cfg(test)]
+    pub fn clear_peer_batched_certificates_cache_for_test(&self) {
+        self.peer_batched_certificates_cache.lock().clear();
+    }
+
     pub fn deferred_transactions_empty(&self) -> bool {
         self.tables()
             .expect("deferred transactions should not be read past end of epoch")
@@ -2767,6 +2848,17 @@ impl AuthorityPerEpochStore {
         Ok(iter.collect())
     }
 
+    /// Returns the timestamp of the last consensus commit a checkpoint is pending for.
+    pub fn last_pending_checkpoint_timestamp_ms(&self) -> SuiResult<Option<CheckpointTimestamp>> {
+        Ok(self
+            .tables()?
+            .pending_checkpoints
+            .unbounded_iter()
+            .skip_to_last()
+            .next()
+            .map(|(_, checkpoint)| checkpoint.details.timestamp_ms))
+    }
+
     pub fn get_pending_checkpoint(
         &self,
         index: &CheckpointCommitHeight,
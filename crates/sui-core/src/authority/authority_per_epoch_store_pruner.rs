@@ -4,41 +4,62 @@ use crate::authority::authority_per_epoch_store::EPOCH_DB_PREFIX;
 use itertools::Itertools;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use sui_config::node::AuthorityStorePruningConfig;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, Notify};
 use tracing::log::{error, info};
 use typed_store::rocks::safe_drop_db;
 
+/// Drops the databases of old epochs. Each epoch keeps its epoch-scoped tables in its own
+/// directory, so the tables of an epoch are removed wholesale instead of row by row.
 pub struct AuthorityPerEpochStorePruner {
     _cancel_handle: oneshot::Sender<()>,
+    prune_notify: Arc<Notify>,
 }
 
 impl AuthorityPerEpochStorePruner {
     pub fn new(parent_path: PathBuf, config: &AuthorityStorePruningConfig) -> Self {
         let (_cancel_handle, mut recv) = tokio::sync::oneshot::channel();
+        let prune_notify = Arc::new(Notify::new());
         let num_latest_epoch_dbs_to_retain = config.num_latest_epoch_dbs_to_retain;
         if num_latest_epoch_dbs_to_retain == 0 || num_latest_epoch_dbs_to_retain == usize::MAX {
             info!("Skipping pruning of epoch tables as we want to retain all versions");
-            return Self { _cancel_handle };
+            return Self {
+                _cancel_handle,
+                prune_notify,
+            };
         }
         let mut prune_interval =
             tokio::time::interval(Duration::from_secs(config.epoch_db_pruning_period_secs));
+        let notified = prune_notify.clone();
         tokio::task::spawn(async move {
             loop {
                 tokio::select! {
-                    _ = prune_interval.tick() => {
-                        info!("Starting pruning of epoch tables");
-                        match Self::prune_old_directories(&parent_path, num_latest_epoch_dbs_to_retain) {
-                            Ok(pruned_count) => info!("Finished pruning old epoch databases. Pruned {} dbs", pruned_count),
-                            Err(err) => error!("Error while removing old epoch databases {:?}", err),
-                        }
-                    }
+                    _ = prune_interval.tick() => {}
+                    _ = notified.notified() => {}
                     _ = &mut recv => break,
                 }
+                info!("Starting pruning of epoch tables");
+                match Self::prune_old_directories(&parent_path, num_latest_epoch_dbs_to_retain) {
+                    Ok(pruned_count) => info!(
+                        "Finished pruning old epoch databases. Pruned {} dbs",
+                        pruned_count
+                    ),
+                    Err(err) => error!("Error while removing old epoch databases {:?}", err),
+                }
             }
         });
-        Self { _cancel_handle }
+        Self {
+            _cancel_handle,
+            prune_notify,
+        }
+    }
+
+    /// Prunes the databases of old epochs without waiting for the next pruning period. Called at
+    /// reconfiguration, once the database of the new epoch has been opened.
+    pub fn prune_now(&self) {
+        self.prune_notify.notify_one();
     }
 
     fn prune_old_directories(
@@ -11,7 +11,9 @@ use crate::authority::authority_per_epoch_store::AuthorityPerEpochStore;
 use crate::authority::authority_store_types::{
     get_store_object_pair, ObjectContentDigest, StoreObject, StoreObjectPair, StoreObjectWrapper,
 };
-use crate::authority::epoch_start_configuration::{EpochFlag, EpochStartConfiguration};
+use crate::authority::epoch_start_configuration::{
+    EpochExecutionConfig, EpochFlag, EpochStartConfiguration,
+};
 use either::Either;
 use fastcrypto::hash::{HashFunction, MultisetHash, Sha3_256};
 use futures::stream::FuturesUnordered;
@@ -23,6 +25,7 @@ use sui_types::digests::TransactionEventsDigest;
 use sui_types::error::UserInputError;
 use sui_types::message_envelope::Message;
 use sui_types::messages_checkpoint::ECMHLiveObjectSetDigest;
+use sui_types::messages_grpc::ConflictingTransactions;
 use sui_types::object::Owner;
 use sui_types::storage::{
     get_module, BackingPackageStore, ChildObjectResolver, InputKey, MarkerValue, ObjectKey,
@@ -163,6 +166,7 @@ impl AuthorityStore {
         registry: &Registry,
     ) -> SuiResult<Arc<Self>> {
         let epoch_start_configuration = if perpetual_tables.database_is_empty()? {
+            perpetual_tables.init_schema_version()?;
             info!("Creating new epoch start config from genesis");
 
             let epoch_start_configuration = EpochStartConfiguration::new(
@@ -175,6 +179,8 @@ impl AuthorityStore {
                 .await?;
             epoch_start_configuration
         } else {
+            let report = perpetual_tables.migrate_schema(false)?;
+            info!("{}", report.to_string().trim_end());
             info!("Loading epoch start config from DB");
             perpetual_tables
                 .epoch_start_configuration
@@ -308,6 +314,18 @@ impl AuthorityStore {
         Ok(acc.1.digest().into())
     }
 
+    /// The root state hash of the epoch, or None if the epoch has not been accumulated yet.
+    pub fn try_get_root_state_hash(
+        &self,
+        epoch: EpochId,
+    ) -> SuiResult<Option<ECMHLiveObjectSetDigest>> {
+        Ok(self
+            .perpetual_tables
+            .root_state_hash_by_epoch
+            .get(&epoch)?
+            .map(|(_, acc)| acc.digest().into()))
+    }
+
     pub fn get_root_state_accumulator(
         &self,
         epoch: EpochId,
@@ -574,24 +592,14 @@ impl AuthorityStore {
         Ok(None)
     }
 
+    /// Reads the objects at the given (id, version) keys with a single multi-get of the objects
+    /// table, and another one of the indirect objects table for the contents stored separately.
     pub fn multi_get_object_by_key(
         &self,
         object_keys: &[ObjectKey],
     ) -> Result<Vec<Option<Object>>, SuiError> {
-        let wrappers = self
-            .perpetual_tables
-            .objects
-            .multi_get(object_keys.to_vec())?;
-        let mut ret = vec![];
-
-        for (idx, w) in wrappers.into_iter().enumerate() {
-            ret.push(
-                w.map(|object| self.perpetual_tables.object(&object_keys[idx], object))
-                    .transpose()?
-                    .flatten(),
-            );
-        }
-        Ok(ret)
+        let wrappers = self.perpetual_tables.objects.multi_get(object_keys)?;
+        self.perpetual_tables.multi_object(object_keys, wrappers)
     }
 
     /// Load a list of objects from the store by object reference.
@@ -1003,6 +1011,13 @@ impl AuthorityStore {
         Ok(self.perpetual_tables.epoch_start_configuration.get(&())?)
     }
 
+    pub fn get_epoch_execution_config(
+        &self,
+        epoch: EpochId,
+    ) -> SuiResult<Option<EpochExecutionConfig>> {
+        self.perpetual_tables.get_epoch_execution_config(epoch)
+    }
+
     /// Updates the state resulting from the execution of a certificate.
     ///
     /// Internally it checks that all locks for active inputs are at the correct
@@ -1358,6 +1373,16 @@ impl AuthorityStore {
                     info!(prev_tx_digest = ?previous_tx_digest,
                           cur_tx_digest = ?tx_digest,
                           "Cannot acquire lock: conflicting transaction!");
+                    // Record the attempted double spend, so that clients can find out which
+                    // transaction holds the lock.
+                    self.perpetual_tables.conflicting_transactions.insert(
+                        &(*obj_ref, tx_digest),
+                        &LockDetails {
+                            epoch,
+                            tx_digest: *previous_tx_digest,
+                        }
+                        .into(),
+                    )?;
                     return Err(SuiError::ObjectLockConflict {
                         obj_ref: *obj_ref,
                         pending_transaction: *previous_tx_digest,
@@ -1389,6 +1414,37 @@ impl AuthorityStore {
         Ok(())
     }
 
+    /// Returns the attempted double spends of any version of the object seen by this authority,
+    /// sorted by object version.
+    pub(crate) fn get_conflicting_transactions(
+        &self,
+        object_id: ObjectID,
+    ) -> SuiResult<Vec<ConflictingTransactions>> {
+        self.perpetual_tables
+            .conflicting_transactions
+            .safe_range_iter(
+                (
+                    (object_id, SequenceNumber::MIN, ObjectDigest::MIN),
+                    TransactionDigest::new([u8::MIN; 32]),
+                )
+                    ..=(
+                        (object_id, SequenceNumber::MAX, ObjectDigest::MAX),
+                        TransactionDigest::new([u8::MAX; 32]),
+                    ),
+            )
+            .map(|result| {
+                let ((obj_ref, conflicting_tx), lock) = result?;
+                let lock = lock.migrate().into_inner();
+                Ok(ConflictingTransactions {
+                    obj_ref,
+                    epoch: lock.epoch,
+                    locked_by_tx: lock.tx_digest,
+                    conflicting_tx,
+                })
+            })
+            .collect()
+    }
+
     /// Gets ObjectLockInfo that represents state of lock on an object.
     /// Returns UserInputError::ObjectNotFound if cannot find lock record for this object
     pub(crate) fn get_lock(&self, obj_ref: ObjectRef, epoch_id: EpochId) -> SuiLockResult {
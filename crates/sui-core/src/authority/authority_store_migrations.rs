@@ -0,0 +1,170 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Versioned migrations of the layout of the perpetual tables, so that a release that changes
+//! the layout can upgrade the database of an existing node in place.
+//!
+//! The version of the layout of a database is recorded in the `schema_version` table, and is 0
+//! for databases created before it was recorded. To change the layout:
+//! - Add a step to `MIGRATIONS` with the next version, that upgrades a database from the
+//!   previous version. The new version becomes `CURRENT_SCHEMA_VERSION`.
+//! - Steps are run in order when the node starts. The writes of each step are committed together
+//!   with the new version, so that a step is never partially applied.
+//!
+//! Migrations can be previewed with `sui-tool db-tool migrate --dry-run`.
+
+use std::fmt;
+
+use sui_types::error::{SuiError, SuiResult};
+use tracing::info;
+use typed_store::rocks::DBBatch;
+use typed_store::traits::Map;
+
+use super::authority_store_tables::AuthorityPerpetualTables;
+
+struct Migration {
+    /// The version of the layout after this step.
+    pub version: u64,
+    pub description: &'static str,
+    /// Adds the writes of the step to the batch, and returns the number of rows written.
+    run: fn(&AuthorityPerpetualTables, &mut DBBatch) -> SuiResult<u64>,
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    description: "record the schema version",
+    run: |_, _| Ok(0),
+}];
+
+pub const CURRENT_SCHEMA_VERSION: u64 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+#[derive(Debug)]
+pub struct MigrationStep {
+    pub version: u64,
+    pub description: &'static str,
+    pub rows: u64,
+}
+
+#[derive(Debug)]
+pub struct MigrationReport {
+    pub from_version: u64,
+    pub to_version: u64,
+    /// The steps that were run, or would be run in dry-run mode.
+    pub steps: Vec<MigrationStep>,
+    pub dry_run: bool,
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.steps.is_empty() {
+            return writeln!(f, "Schema version {} is up to date", self.from_version);
+        }
+        writeln!(
+            f,
+            "{} schema version {} to {}:",
+            if self.dry_run {
+                "Would migrate"
+            } else {
+                "Migrated"
+            },
+            self.from_version,
+            self.to_version
+        )?;
+        for step in &self.steps {
+            writeln!(
+                f,
+                "  {}: {} ({} rows)",
+                step.version, step.description, step.rows
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl AuthorityPerpetualTables {
+    pub fn get_schema_version(&self) -> SuiResult<u64> {
+        Ok(self.schema_version.get(&())?.unwrap_or_default())
+    }
+
+    /// Records the current schema version in a new database, which needs no migration.
+    pub fn init_schema_version(&self) -> SuiResult {
+        self.schema_version.insert(&(), &CURRENT_SCHEMA_VERSION)?;
+        Ok(())
+    }
+
+    /// Runs the migrations from the recorded schema version to `CURRENT_SCHEMA_VERSION`. In
+    /// dry-run mode, the writes of each step are computed but not committed.
+    pub fn migrate_schema(&self, dry_run: bool) -> SuiResult<MigrationReport> {
+        let from_version = self.get_schema_version()?;
+        if from_version > CURRENT_SCHEMA_VERSION {
+            return Err(SuiError::GenericStorageError(format!(
+                "Database schema version {} is newer than version {} of this release, \
+                 downgrades are not supported",
+                from_version, CURRENT_SCHEMA_VERSION
+            )));
+        }
+
+        let mut steps = Vec::new();
+        for migration in MIGRATIONS
+            .iter()
+            .filter(|migration| migration.version > from_version)
+        {
+            let mut batch = self.schema_version.batch();
+            let rows = (migration.run)(self, &mut batch)?;
+            batch.insert_batch(&self.schema_version, [((), migration.version)])?;
+            if !dry_run {
+                batch.write()?;
+                info!(
+                    "Migrated database schema to version {}: {} ({} rows)",
+                    migration.version, migration.description, rows
+                );
+            }
+            steps.push(MigrationStep {
+                version: migration.version,
+                description: migration.description,
+                rows,
+            });
+        }
+        Ok(MigrationReport {
+            from_version,
+            to_version: CURRENT_SCHEMA_VERSION,
+            steps,
+            dry_run,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrations_are_ordered() {
+        for (previous, migration) in MIGRATIONS.iter().zip(&MIGRATIONS[1..]) {
+            assert_eq!(migration.version, previous.version + 1);
+        }
+        assert_eq!(MIGRATIONS[0].version, 1);
+    }
+
+    #[test]
+    fn test_migrate_schema() {
+        let path = tempfile::tempdir().unwrap().into_path();
+        let tables = AuthorityPerpetualTables::open(&path, None);
+        assert_eq!(tables.get_schema_version().unwrap(), 0);
+
+        let report = tables.migrate_schema(true).unwrap();
+        assert_eq!(report.steps.len(), MIGRATIONS.len());
+        assert_eq!(tables.get_schema_version().unwrap(), 0);
+
+        let report = tables.migrate_schema(false).unwrap();
+        assert_eq!(report.steps.len(), MIGRATIONS.len());
+        assert_eq!(tables.get_schema_version().unwrap(), CURRENT_SCHEMA_VERSION);
+        assert!(tables.migrate_schema(false).unwrap().steps.is_empty());
+
+        tables
+            .schema_version
+            .insert(&(), &(CURRENT_SCHEMA_VERSION + 1))
+            .unwrap();
+        assert!(tables.migrate_schema(true).is_err());
+    }
+}
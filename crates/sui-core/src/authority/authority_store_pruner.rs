@@ -30,6 +30,7 @@ use sui_types::{
     storage::ObjectKey,
 };
 use tokio::sync::oneshot::{self, Sender};
+use tokio::sync::watch;
 use tokio::time::Instant;
 use tracing::{debug, error, info, warn};
 use typed_store::{Map, TypedStoreError};
@@ -51,6 +52,8 @@ static PERIODIC_PRUNING_TABLES: Lazy<HashSet<String>> = Lazy::new(|| {
 });
 pub struct AuthorityStorePruner {
     _objects_pruner_cancel_handle: oneshot::Sender<()>,
+    config_sender: watch::Sender<AuthorityStorePruningConfig>,
+    is_validator: bool,
 }
 
 pub struct AuthorityStorePruningMetrics {
@@ -528,7 +531,7 @@ impl AuthorityStorePruner {
     }
 
     fn setup_pruning(
-        config: AuthorityStorePruningConfig,
+        mut config_receiver: watch::Receiver<AuthorityStorePruningConfig>,
         epoch_duration_ms: u64,
         perpetual_db: Arc<AuthorityPerpetualTables>,
         checkpoint_store: Arc<CheckpointStore>,
@@ -538,6 +541,7 @@ impl AuthorityStorePruner {
         archive_readers: ArchiveReaderBalancer,
     ) -> Sender<()> {
         let (sender, mut recv) = tokio::sync::oneshot::channel();
+        let mut config = *config_receiver.borrow_and_update();
         debug!(
             "Starting object pruning service with num_epochs_to_retain={}",
             config.num_epochs_to_retain
@@ -577,18 +581,16 @@ impl AuthorityStorePruner {
             });
         }
 
-        metrics
-            .num_epochs_to_retain_for_objects
-            .set(config.num_epochs_to_retain as i64);
-        metrics.num_epochs_to_retain_for_checkpoints.set(
-            config
-                .num_epochs_to_retain_for_checkpoints
-                .unwrap_or_default() as i64,
-        );
+        Self::set_retention_metrics(&metrics, &config);
 
         tokio::task::spawn(async move {
             loop {
                 tokio::select! {
+                    Ok(()) = config_receiver.changed() => {
+                        config = *config_receiver.borrow_and_update();
+                        info!("Updated pruning config: {:?}", config);
+                        Self::set_retention_metrics(&metrics, &config);
+                    },
                     _ = objects_prune_interval.tick(), if config.num_epochs_to_retain != u64::MAX => {
                         if let Err(err) = Self::prune_objects_for_eligible_epochs(&perpetual_db, &checkpoint_store, &objects_lock_table, config, metrics.clone(), indirect_objects_threshold).await {
                             error!("Failed to prune objects: {:?}", err);
@@ -606,17 +608,24 @@ impl AuthorityStorePruner {
         sender
     }
 
-    pub fn new(
-        perpetual_db: Arc<AuthorityPerpetualTables>,
-        checkpoint_store: Arc<CheckpointStore>,
-        objects_lock_table: Arc<RwLockTable<ObjectContentDigest>>,
+    fn set_retention_metrics(
+        metrics: &AuthorityStorePruningMetrics,
+        config: &AuthorityStorePruningConfig,
+    ) {
+        metrics
+            .num_epochs_to_retain_for_objects
+            .set(config.num_epochs_to_retain as i64);
+        metrics.num_epochs_to_retain_for_checkpoints.set(
+            config
+                .num_epochs_to_retain_for_checkpoints
+                .unwrap_or_default() as i64,
+        );
+    }
+
+    fn adjust_config(
         mut pruning_config: AuthorityStorePruningConfig,
         is_validator: bool,
-        epoch_duration_ms: u64,
-        registry: &Registry,
-        indirect_objects_threshold: usize,
-        archive_readers: ArchiveReaderBalancer,
-    ) -> Self {
+    ) -> AuthorityStorePruningConfig {
         if pruning_config.num_epochs_to_retain > 0 && pruning_config.num_epochs_to_retain < u64::MAX
         {
             warn!("Using objects pruner with num_epochs_to_retain = {} can lead to performance issues", pruning_config.num_epochs_to_retain);
@@ -627,9 +636,25 @@ impl AuthorityStorePruner {
                 warn!("Consider using an aggressive pruner (num_epochs_to_retain = 0)");
             }
         }
+        pruning_config
+    }
+
+    pub fn new(
+        perpetual_db: Arc<AuthorityPerpetualTables>,
+        checkpoint_store: Arc<CheckpointStore>,
+        objects_lock_table: Arc<RwLockTable<ObjectContentDigest>>,
+        pruning_config: AuthorityStorePruningConfig,
+        is_validator: bool,
+        epoch_duration_ms: u64,
+        registry: &Registry,
+        indirect_objects_threshold: usize,
+        archive_readers: ArchiveReaderBalancer,
+    ) -> Self {
+        let (config_sender, config_receiver) =
+            watch::channel(Self::adjust_config(pruning_config, is_validator));
         AuthorityStorePruner {
             _objects_pruner_cancel_handle: Self::setup_pruning(
-                pruning_config,
+                config_receiver,
                 epoch_duration_ms,
                 perpetual_db,
                 checkpoint_store,
@@ -638,9 +663,22 @@ impl AuthorityStorePruner {
                 indirect_objects_threshold,
                 archive_readers,
             ),
+            config_sender,
+            is_validator,
         }
     }
 
+    /// Updates the retention and batch sizes of the running pruner. The initial delay and the
+    /// periodic compaction are only set up at startup and are not affected.
+    pub fn update_config(&self, pruning_config: AuthorityStorePruningConfig) {
+        self.config_sender
+            .send_replace(Self::adjust_config(pruning_config, self.is_validator));
+    }
+
+    pub fn config(&self) -> AuthorityStorePruningConfig {
+        *self.config_sender.borrow()
+    }
+
     pub fn compact(perpetual_db: &Arc<AuthorityPerpetualTables>) -> Result<(), TypedStoreError> {
         perpetual_db.objects.compact_range(
             &ObjectKey(ObjectID::ZERO, SequenceNumber::MIN),
@@ -5,6 +5,7 @@ use super::*;
 use crate::authority::authority_store::LockDetailsWrapper;
 use rocksdb::Options;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 use sui_types::accumulator::Accumulator;
 use sui_types::base_types::SequenceNumber;
@@ -22,7 +23,7 @@ use crate::authority::authority_store_types::{
     get_store_object_pair, try_construct_object, ObjectContentDigest, StoreData,
     StoreMoveObjectWrapper, StoreObject, StoreObjectPair, StoreObjectValue, StoreObjectWrapper,
 };
-use crate::authority::epoch_start_configuration::EpochStartConfiguration;
+use crate::authority::epoch_start_configuration::{EpochExecutionConfig, EpochStartConfiguration};
 use typed_store_derive::DBMapUtils;
 
 const ENV_VAR_OBJECTS_BLOCK_CACHE_SIZE: &str = "OBJECTS_BLOCK_CACHE_MB";
@@ -63,6 +64,12 @@ pub struct AuthorityPerpetualTables {
     #[default_options_override_fn = "owned_object_transaction_locks_table_default_config"]
     pub(crate) owned_object_transaction_locks: DBMap<ObjectRef, Option<LockDetailsWrapper>>,
 
+    /// Transactions that were rejected because the owned object version they use was already
+    /// locked by a different transaction in the same epoch, i.e. attempted double spends. Keyed by
+    /// the object version and the rejected transaction, the value is the lock it conflicted with.
+    /// Entries are kept so that clients can find out why their transaction can't be signed.
+    pub(crate) conflicting_transactions: DBMap<(ObjectRef, TransactionDigest), LockDetailsWrapper>,
+
     /// This is a map between the transaction digest and the corresponding transaction that's known to be
     /// executable. This means that it may have been executed locally, or it may have been synced through
     /// state-sync but hasn't been executed yet.
@@ -106,6 +113,11 @@ pub struct AuthorityPerpetualTables {
     /// Parameters of the system fixed at the epoch start
     pub(crate) epoch_start_configuration: DBMap<(), EpochStartConfiguration>,
 
+    /// The protocol config and gas cost table each epoch was executed with. Written once when
+    /// the epoch starts and never changed, so that transactions of the epoch are always
+    /// re-executed under the same rules.
+    pub(crate) epoch_execution_configs: DBMap<EpochId, EpochExecutionConfig>,
+
     /// A singleton table that stores latest pruned checkpoint. Used to keep objects pruner progress
     pub(crate) pruned_checkpoint: DBMap<(), CheckpointSequenceNumber>,
 
@@ -126,6 +138,10 @@ pub struct AuthorityPerpetualTables {
     /// objects that have been deleted. This table is meant to be pruned per-epoch, and all
     /// previous epochs other than the current epoch may be pruned safely.
     pub(crate) object_per_epoch_marker_table: DBMap<(EpochId, ObjectKey), MarkerValue>,
+
+    /// A singleton table that stores the version of the layout of these tables. See
+    /// `authority_store_migrations`.
+    pub(crate) schema_version: DBMap<(), u64>,
 }
 
 impl AuthorityPerpetualTables {
@@ -204,6 +220,53 @@ impl AuthorityPerpetualTables {
         Ok(Some(self.construct_object(object_key, store_object)?))
     }
 
+    // Constructs the objects of `object_keys` from their `StoreObjectWrapper`s, reading all the
+    // indirect objects with a single multi-get. An object is `None` if it is missing or was
+    // deleted/wrapped.
+    pub fn multi_object(
+        &self,
+        object_keys: &[ObjectKey],
+        store_objects: Vec<Option<StoreObjectWrapper>>,
+    ) -> Result<Vec<Option<Object>>, SuiError> {
+        let store_objects = store_objects
+            .into_iter()
+            .map(|store_object| match store_object?.migrate().into_inner() {
+                StoreObject::Value(store_object) => Some(store_object),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let indirect_digests = store_objects
+            .iter()
+            .flatten()
+            .filter_map(|store_object| match &store_object.data {
+                StoreData::IndirectObject(metadata) => Some(metadata.digest),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let indirect_objects = indirect_digests
+            .iter()
+            .zip(self.indirect_move_objects.multi_get(&indirect_digests)?)
+            .filter_map(|(digest, o)| Some((*digest, o?.migrate().into_inner())))
+            .collect::<HashMap<_, _>>();
+
+        store_objects
+            .into_iter()
+            .zip(object_keys)
+            .map(|(store_object, object_key)| {
+                let Some(store_object) = store_object else {
+                    return Ok(None);
+                };
+                let indirect_object = match &store_object.data {
+                    StoreData::IndirectObject(metadata) => {
+                        indirect_objects.get(&metadata.digest).cloned()
+                    }
+                    _ => None,
+                };
+                try_construct_object(object_key, store_object, indirect_object).map(Some)
+            })
+            .collect()
+    }
+
     pub fn object_reference(
         &self,
         object_key: &ObjectKey,
@@ -291,6 +354,30 @@ impl AuthorityPerpetualTables {
             .epoch())
     }
 
+    pub fn get_epoch_start_configuration(&self) -> SuiResult<Option<EpochStartConfiguration>> {
+        Ok(self.epoch_start_configuration.get(&())?)
+    }
+
+    pub fn get_epoch_execution_config(
+        &self,
+        epoch: EpochId,
+    ) -> SuiResult<Option<EpochExecutionConfig>> {
+        Ok(self.epoch_execution_configs.get(&epoch)?)
+    }
+
+    /// Returns the execution config stored for `epoch`, storing `config` first if there is none.
+    pub fn get_or_insert_epoch_execution_config(
+        &self,
+        epoch: EpochId,
+        config: EpochExecutionConfig,
+    ) -> SuiResult<EpochExecutionConfig> {
+        if let Some(stored) = self.epoch_execution_configs.get(&epoch)? {
+            return Ok(stored);
+        }
+        self.epoch_execution_configs.insert(&epoch, &config)?;
+        Ok(config)
+    }
+
     pub async fn set_epoch_start_configuration(
         &self,
         epoch_start_configuration: &EpochStartConfiguration,
@@ -459,11 +546,13 @@ impl AuthorityPerpetualTables {
         self.objects.unsafe_clear()?;
         self.indirect_move_objects.unsafe_clear()?;
         self.owned_object_transaction_locks.unsafe_clear()?;
+        self.conflicting_transactions.unsafe_clear()?;
         self.executed_effects.unsafe_clear()?;
         self.events.unsafe_clear()?;
         self.executed_transactions_to_checkpoint.unsafe_clear()?;
         self.root_state_hash_by_epoch.unsafe_clear()?;
         self.epoch_start_configuration.unsafe_clear()?;
+        // epoch_execution_configs is kept, re-execution must use the rules each epoch ran with.
         self.pruned_checkpoint.unsafe_clear()?;
         self.expected_network_sui_amount.unsafe_clear()?;
         self.expected_storage_fund_imbalance.unsafe_clear()?;
@@ -0,0 +1,229 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+use std::fmt;
+
+use sui_types::base_types::{ObjectID, SequenceNumber};
+use sui_types::digests::TransactionDigest;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
+use sui_types::inner_temporary_store::InnerTemporaryStore;
+use sui_types::storage::ObjectStore;
+use sui_types::type_resolver::LayoutResolver;
+
+/// A broken invariant of the effects of an executed transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EffectsInvariantViolation {
+    /// The object appears more than once in the effects.
+    DuplicateObject { id: ObjectID },
+    /// The object is created, mutated, unwrapped, deleted or wrapped at a version other than the
+    /// lamport version of the transaction.
+    NotAtLamportVersion {
+        id: ObjectID,
+        version: SequenceNumber,
+    },
+    /// The object is read or modified at a version that is not lower than the lamport version.
+    InputVersionNotLower {
+        id: ObjectID,
+        version: SequenceNumber,
+    },
+    /// The SUI held by the objects modified by the transaction is not equal to the SUI held by
+    /// the objects it wrote, plus the computation and non-refundable storage fees it burnt.
+    SuiNotConserved { input_sui: u64, output_sui: u64 },
+    /// The SUI held by the object could not be counted.
+    UncountedSui { id: ObjectID, error: String },
+}
+
+impl fmt::Display for EffectsInvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateObject { id } => {
+                write!(f, "object {id} appears more than once in the effects")
+            }
+            Self::NotAtLamportVersion { id, version } => {
+                write!(f, "object {id} is changed at version {version}")
+            }
+            Self::InputVersionNotLower { id, version } => {
+                write!(f, "input object {id} is at version {version}")
+            }
+            Self::SuiNotConserved {
+                input_sui,
+                output_sui,
+            } => write!(
+                f,
+                "SUI not conserved: {input_sui} SUI in inputs, {output_sui} SUI in outputs and fees"
+            ),
+            Self::UncountedSui { id, error } => {
+                write!(f, "could not count the SUI of object {id}: {error}")
+            }
+        }
+    }
+}
+
+/// Every invariant violated by the effects of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectsInvariantReport {
+    pub tx_digest: TransactionDigest,
+    pub lamport_version: SequenceNumber,
+    pub violations: Vec<EffectsInvariantViolation>,
+}
+
+impl fmt::Display for EffectsInvariantReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Effects invariants violated for transaction {} at lamport version {}:",
+            self.tx_digest, self.lamport_version
+        )?;
+        for violation in &self.violations {
+            write!(f, "\n  - {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the effects of an executed transaction are consistent with the objects it read
+/// and wrote:
+/// - No object appears more than once in the effects.
+/// - Every object created, mutated, unwrapped, deleted or wrapped by the transaction is at the
+///   lamport version (packages keep their own version).
+/// - The lamport version is greater than the versions of all Move input objects and of all
+///   objects modified by the transaction.
+/// - If `check_sui_conservation` is set, the transaction neither mints nor burns SUI, except for
+///   the fees it is charged. Input objects missing from `inner_temp_store`, such as dynamic
+///   fields, are read from `object_store`.
+pub fn check_effects_invariants(
+    inner_temp_store: &InnerTemporaryStore,
+    effects: &TransactionEffects,
+    check_sui_conservation: bool,
+    object_store: &dyn ObjectStore,
+    layout_resolver: &mut dyn LayoutResolver,
+) -> Result<(), EffectsInvariantReport> {
+    let lamport_version = inner_temp_store.lamport_version;
+    let mut violations = vec![];
+
+    let changed_objects = effects
+        .created()
+        .into_iter()
+        .chain(effects.mutated())
+        .chain(effects.unwrapped())
+        .map(|(object_ref, _)| object_ref)
+        .collect::<Vec<_>>();
+    let removed_objects = effects
+        .deleted()
+        .into_iter()
+        .chain(effects.unwrapped_then_deleted())
+        .chain(effects.wrapped())
+        .collect::<Vec<_>>();
+
+    let mut seen = HashSet::new();
+    for (id, _, _) in changed_objects.iter().chain(removed_objects.iter()) {
+        if !seen.insert(*id) {
+            violations.push(EffectsInvariantViolation::DuplicateObject { id: *id });
+        }
+    }
+
+    for (id, version, _) in &changed_objects {
+        let is_package = inner_temp_store
+            .written
+            .get(id)
+            .is_some_and(|object| object.is_package());
+        if !is_package && *version != lamport_version {
+            violations.push(EffectsInvariantViolation::NotAtLamportVersion {
+                id: *id,
+                version: *version,
+            });
+        }
+    }
+    for (id, version, _) in &removed_objects {
+        if *version != lamport_version {
+            violations.push(EffectsInvariantViolation::NotAtLamportVersion {
+                id: *id,
+                version: *version,
+            });
+        }
+    }
+
+    for (id, version) in effects.modified_at_versions() {
+        if version >= lamport_version {
+            violations.push(EffectsInvariantViolation::InputVersionNotLower { id, version });
+        }
+    }
+    for (id, object) in &inner_temp_store.input_objects {
+        if let Some(move_object) = object.data.try_as_move() {
+            if move_object.version() >= lamport_version {
+                violations.push(EffectsInvariantViolation::InputVersionNotLower {
+                    id: *id,
+                    version: move_object.version(),
+                });
+            }
+        }
+    }
+
+    if check_sui_conservation {
+        violations.extend(check_sui_conserved(
+            inner_temp_store,
+            effects,
+            object_store,
+            layout_resolver,
+        ));
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(EffectsInvariantReport {
+            tx_digest: *effects.transaction_digest(),
+            lamport_version,
+            violations,
+        })
+    }
+}
+
+/// All SUI in the objects modified by the transaction, coins and storage rebates alike, should
+/// flow either to the objects it wrote, or be burnt as computation or non-refundable storage
+/// fees. The storage cost flows into the storage rebate of the objects written.
+fn check_sui_conserved(
+    inner_temp_store: &InnerTemporaryStore,
+    effects: &TransactionEffects,
+    object_store: &dyn ObjectStore,
+    layout_resolver: &mut dyn LayoutResolver,
+) -> Option<EffectsInvariantViolation> {
+    let mut input_sui = 0;
+    for (id, version) in effects.modified_at_versions() {
+        let object = match inner_temp_store.input_objects.get(&id) {
+            Some(object) if object.version() == version => Ok(Some(object.clone())),
+            _ => object_store.get_object_by_key(&id, version),
+        };
+        let sui = match object {
+            Ok(Some(object)) => object
+                .get_total_sui(layout_resolver)
+                .map_err(|e| e.to_string()),
+            Ok(None) => Err(format!("input version {version} not found")),
+            Err(e) => Err(e.to_string()),
+        };
+        match sui {
+            Ok(sui) => input_sui += sui,
+            Err(error) => return Some(EffectsInvariantViolation::UncountedSui { id, error }),
+        }
+    }
+
+    let gas_summary = effects.gas_cost_summary();
+    let mut output_sui = gas_summary.computation_cost + gas_summary.non_refundable_storage_fee;
+    for (id, object) in &inner_temp_store.written {
+        match object.get_total_sui(layout_resolver) {
+            Ok(sui) => output_sui += sui,
+            Err(e) => {
+                return Some(EffectsInvariantViolation::UncountedSui {
+                    id: *id,
+                    error: e.to_string(),
+                })
+            }
+        }
+    }
+
+    (input_sui != output_sui).then_some(EffectsInvariantViolation::SuiNotConserved {
+        input_sui,
+        output_sui,
+    })
+}
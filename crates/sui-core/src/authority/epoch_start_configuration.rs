@@ -5,11 +5,14 @@ use enum_dispatch::enum_dispatch;
 use serde::{Deserialize, Serialize};
 
 use std::fmt;
+use sui_protocol_config::ProtocolConfig;
 use sui_types::authenticator_state::get_authenticator_state_obj_initial_shared_version;
 use sui_types::base_types::SequenceNumber;
 use sui_types::deny_list::get_deny_list_obj_initial_shared_version;
 use sui_types::epoch_data::EpochData;
 use sui_types::error::SuiResult;
+use sui_types::gas_model::gas_predicates::cost_table_for_version;
+use sui_types::gas_model::units_types::CostTable;
 use sui_types::messages_checkpoint::{CheckpointDigest, CheckpointTimestamp};
 use sui_types::randomness_state::get_randomness_state_obj_initial_shared_version;
 use sui_types::storage::ObjectStore;
@@ -265,6 +268,32 @@ impl EpochStartConfigTrait for EpochStartConfigurationV5 {
     }
 }
 
+/// The execution rules an epoch ran with: its protocol config and the gas cost table derived
+/// from it. Stored when the epoch starts so that transactions of past epochs can be re-executed
+/// under exactly these rules, even by a binary whose definition of the protocol version differs.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct EpochExecutionConfig {
+    /// `ProtocolConfig` in JSON, since unset fields are skipped when serializing, which BCS
+    /// can't read back.
+    protocol_config: String,
+    pub execution_cost_table: CostTable,
+}
+
+impl EpochExecutionConfig {
+    pub fn new(protocol_config: &ProtocolConfig) -> Self {
+        Self {
+            protocol_config: serde_json::to_string(protocol_config)
+                .expect("Serializing the protocol config cannot fail"),
+            execution_cost_table: cost_table_for_version(protocol_config.gas_model_version()),
+        }
+    }
+
+    pub fn protocol_config(&self) -> ProtocolConfig {
+        serde_json::from_str(&self.protocol_config)
+            .expect("Stored protocol config must be deserializable")
+    }
+}
+
 impl EpochFlag {
     pub fn default_flags_for_new_epoch() -> Vec<Self> {
         vec![
@@ -0,0 +1,196 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use sui_types::base_types::{EpochId, ObjectID, ObjectRef, SequenceNumber, VersionNumber};
+use sui_types::digests::TransactionDigest;
+use sui_types::error::{SuiError, SuiResult, UserInputError};
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::{Object, Owner};
+use sui_types::storage::{
+    BackingPackageStore, ChildObjectResolver, ObjectKey, ObjectStore, PackageObject, ParentSync,
+};
+use typed_store::traits::Map;
+
+use super::authority_store::AuthorityStore;
+
+/// A read-only view of the objects as of the end of a checkpoint, for executing transactions
+/// against past state. The version of an object at the checkpoint is the latest version written
+/// by a transaction of that checkpoint or an earlier one, so the view can only see the object
+/// versions that have not been pruned yet.
+///
+/// A version that was deleted or wrapped is stored without the transaction that deleted it, so
+/// the view can't tell whether an object deleted after its latest visible version still existed
+/// at the checkpoint, and reading such an object fails.
+pub struct HistoricalStateView {
+    store: Arc<AuthorityStore>,
+    checkpoint: CheckpointSequenceNumber,
+}
+
+impl HistoricalStateView {
+    /// Fails if the objects of `checkpoint` have been pruned, or the checkpoint has not been
+    /// executed yet.
+    pub fn new(
+        store: Arc<AuthorityStore>,
+        checkpoint: CheckpointSequenceNumber,
+        highest_executed_checkpoint: CheckpointSequenceNumber,
+    ) -> SuiResult<Self> {
+        if checkpoint > highest_executed_checkpoint {
+            return Err(UserInputError::VerifiedCheckpointNotFound(checkpoint).into());
+        }
+        let highest_pruned = store.perpetual_tables.get_highest_pruned_checkpoint()?;
+        if highest_pruned > 0 && checkpoint <= highest_pruned {
+            return Err(SuiError::UnsupportedFeatureError {
+                error: format!(
+                    "the objects of checkpoint {checkpoint} have been pruned, the earliest \
+                     checkpoint available is {}",
+                    highest_pruned + 1
+                ),
+            });
+        }
+        Ok(Self { store, checkpoint })
+    }
+
+    pub fn checkpoint(&self) -> CheckpointSequenceNumber {
+        self.checkpoint
+    }
+
+    /// Whether `digest` was executed in the checkpoint of the view or an earlier one.
+    fn is_visible(&self, digest: &TransactionDigest) -> SuiResult<bool> {
+        if *digest == TransactionDigest::genesis_marker() {
+            return Ok(true);
+        }
+        Ok(self
+            .store
+            .deprecated_get_transaction_checkpoint(digest)?
+            .is_some_and(|(_, checkpoint)| checkpoint <= self.checkpoint))
+    }
+
+    /// Returns the object with `object_id` at the checkpoint of the view, if its version there is
+    /// at most `version_bound`.
+    pub fn find_object(
+        &self,
+        object_id: ObjectID,
+        version_bound: SequenceNumber,
+    ) -> SuiResult<Option<Object>> {
+        let tables = &self.store.perpetual_tables;
+        let iter = tables
+            .objects
+            .safe_range_iter(
+                ObjectKey::min_for_id(&object_id)..=ObjectKey(object_id, version_bound),
+            )
+            .skip_prior_to(&ObjectKey(object_id, version_bound))?
+            .reverse();
+        // A tombstone above the current version, that may be before or after the checkpoint.
+        let mut unresolved_tombstone = None;
+        for result in iter {
+            let (key, value) = result?;
+            match tables.object(&key, value)? {
+                Some(object) => {
+                    if !self.is_visible(&object.previous_transaction)? {
+                        // Tombstones above a version written after the checkpoint are later too.
+                        unresolved_tombstone = None;
+                        continue;
+                    }
+                    if let Some(version) = unresolved_tombstone {
+                        return Err(SuiError::UnsupportedFeatureError {
+                            error: format!(
+                                "object {object_id} was deleted or wrapped at version {version}, \
+                                 which can't be placed before or after checkpoint {}",
+                                self.checkpoint
+                            ),
+                        });
+                    }
+                    return Ok(Some(object));
+                }
+                None => {
+                    unresolved_tombstone.get_or_insert(key.1);
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl ObjectStore for HistoricalStateView {
+    fn get_object(&self, object_id: &ObjectID) -> SuiResult<Option<Object>> {
+        self.find_object(*object_id, VersionNumber::MAX)
+    }
+
+    fn get_object_by_key(
+        &self,
+        object_id: &ObjectID,
+        version: VersionNumber,
+    ) -> SuiResult<Option<Object>> {
+        let Some(object) = self.store.get_object_by_key(object_id, version)? else {
+            return Ok(None);
+        };
+        Ok(self
+            .is_visible(&object.previous_transaction)?
+            .then_some(object))
+    }
+}
+
+impl BackingPackageStore for HistoricalStateView {
+    fn get_package_object(&self, package_id: &ObjectID) -> SuiResult<Option<PackageObject>> {
+        Ok(self
+            .get_object(package_id)?
+            .filter(|object| object.is_package())
+            .map(PackageObject::new))
+    }
+}
+
+impl ChildObjectResolver for HistoricalStateView {
+    fn read_child_object(
+        &self,
+        parent: &ObjectID,
+        child: &ObjectID,
+        child_version_upper_bound: SequenceNumber,
+    ) -> SuiResult<Option<Object>> {
+        let Some(child_object) = self.find_object(*child, child_version_upper_bound)? else {
+            return Ok(None);
+        };
+
+        let parent = *parent;
+        if child_object.owner != Owner::ObjectOwner(parent.into()) {
+            return Err(SuiError::InvalidChildObjectAccess {
+                object: *child,
+                given_parent: parent,
+                actual_owner: child_object.owner,
+            });
+        }
+        Ok(Some(child_object))
+    }
+
+    fn get_object_received_at_version(
+        &self,
+        owner: &ObjectID,
+        receiving_object_id: &ObjectID,
+        receive_object_at_version: SequenceNumber,
+        _epoch_id: EpochId,
+    ) -> SuiResult<Option<Object>> {
+        let Some(recv_object) =
+            self.get_object_by_key(receiving_object_id, receive_object_at_version)?
+        else {
+            return Ok(None);
+        };
+        // Whether the object was already received at this version is only recorded for the
+        // current epoch, and nothing is committed from the view, so only the owner is checked.
+        if recv_object.owner != Owner::AddressOwner((*owner).into()) {
+            return Ok(None);
+        }
+        Ok(Some(recv_object))
+    }
+}
+
+impl ParentSync for HistoricalStateView {
+    fn get_latest_parent_entry_ref_deprecated(
+        &self,
+        object_id: ObjectID,
+    ) -> SuiResult<Option<ObjectRef>> {
+        Ok(self
+            .get_object(&object_id)?
+            .map(|object| object.compute_object_reference()))
+    }
+}
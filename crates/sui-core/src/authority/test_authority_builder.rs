@@ -278,6 +278,7 @@ impl<'a> TestAuthorityBuilder<'a> {
             },
             overload_threshold_config,
             ArchiveReaderBalancer::default(),
+            None,
         )
         .await;
         // For any type of local testing that does not actually spawn a node, the checkpoint executor
@@ -4,7 +4,7 @@
 
 use crate::authority_client::{
     make_authority_clients_with_timeout_config, make_network_authority_clients_with_network_config,
-    AuthorityAPI, NetworkAuthorityClient,
+    AuthorityAPI, NetworkAuthorityClient, ValidatorClientTlsConfig,
 };
 use crate::safe_client::{SafeClient, SafeClientMetrics, SafeClientMetricsBase};
 use fastcrypto::traits::ToFromBytes;
@@ -12,6 +12,7 @@ use futures::{future::BoxFuture, stream::FuturesUnordered, StreamExt};
 use mysten_metrics::histogram::Histogram;
 use mysten_metrics::{monitored_future, spawn_monitored_task, GaugeGuard};
 use mysten_network::config::Config;
+use parking_lot::Mutex;
 use std::convert::AsRef;
 use sui_authority_aggregation::ReduceOutput;
 use sui_authority_aggregation::{quorum_map_then_reduce_with_timeout, AsyncResult};
@@ -24,6 +25,7 @@ use sui_types::crypto::{AuthorityPublicKeyBytes, AuthoritySignInfo};
 use sui_types::error::UserInputError;
 use sui_types::fp_ensure;
 use sui_types::message_envelope::Message;
+use sui_types::messages_checkpoint::ECMHLiveObjectSetDigest;
 use sui_types::object::Object;
 use sui_types::quorum_driver_types::GroupedErrors;
 use sui_types::sui_system_state::{SuiSystemState, SuiSystemStateTrait};
@@ -50,10 +52,11 @@ use sui_types::effects::{
     VerifiedCertifiedTransactionEffects,
 };
 use sui_types::messages_grpc::{
-    HandleCertificateResponseV2, LayoutGenerationOption, ObjectInfoRequest, TransactionInfoRequest,
+    ConflictingTransactions, HandleCertificateResponseV2, LayoutGenerationOption,
+    ObjectInfoRequest, TransactionInfoRequest,
 };
 use sui_types::messages_safe_client::PlainTransactionInfoResponse;
-use tokio::time::{sleep, timeout};
+use tokio::time::{sleep, timeout, Instant};
 
 use crate::authority::AuthorityStore;
 use crate::epoch::committee_store::CommitteeStore;
@@ -432,6 +435,70 @@ pub struct AuthorityAggregator<A: Clone> {
     pub timeouts: TimeoutConfig,
     /// Store here for clone during re-config.
     pub committee_store: Arc<CommitteeStore>,
+    /// Validators that asked to be left alone for a while because they are overloaded.
+    overload_backoff: Arc<OverloadBackoff>,
+}
+
+/// Validators that asked clients to back off because they are overloaded, with the overload
+/// error each one returned and the instant until which no request is sent to it.
+#[derive(Default)]
+struct OverloadBackoff {
+    validators: Mutex<HashMap<AuthorityName, (SuiError, Instant)>>,
+}
+
+impl OverloadBackoff {
+    /// Returns the overload error last returned by `name` if its retry-after hint has not
+    /// elapsed yet.
+    fn backing_off(&self, name: &AuthorityName) -> Option<SuiError> {
+        let mut validators = self.validators.lock();
+        match validators.get(name) {
+            Some((error, until)) if *until > Instant::now() => Some(error.clone()),
+            Some(_) => {
+                validators.remove(name);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Remembers the retry-after hint of an error returned by `name`. The hint is not extended
+    /// by the errors of requests that were skipped while backing off.
+    fn record(&self, name: AuthorityName, error: &SuiError) {
+        let Some(retry_after) = error.retry_after() else {
+            return;
+        };
+        let now = Instant::now();
+        let mut validators = self.validators.lock();
+        if !matches!(validators.get(&name), Some((_, until)) if *until > now) {
+            validators.insert(name, (error.clone(), now + retry_after));
+        }
+    }
+
+    /// The earliest instant at which the validators that are not backing off hold a quorum of
+    /// stake again, or `None` if they already do.
+    fn deadline(&self, committee: &Committee) -> Option<Instant> {
+        let now = Instant::now();
+        let mut backing_off: Vec<_> = self
+            .validators
+            .lock()
+            .iter()
+            .filter(|(_, (_, until))| *until > now)
+            .map(|(name, (_, until))| (*until, committee.weight(name)))
+            .collect();
+        backing_off.sort();
+        let mut available_stake =
+            committee.total_votes() - backing_off.iter().map(|(_, w)| w).sum::<StakeUnit>();
+        if available_stake >= committee.quorum_threshold() {
+            return None;
+        }
+        for (until, weight) in backing_off {
+            available_stake += weight;
+            if available_stake >= committee.quorum_threshold() {
+                return Some(until);
+            }
+        }
+        None
+    }
 }
 
 impl<A: Clone> AuthorityAggregator<A> {
@@ -473,6 +540,7 @@ impl<A: Clone> AuthorityAggregator<A> {
             safe_client_metrics_base,
             timeouts,
             committee_store,
+            overload_backoff: Default::default(),
         }
     }
 
@@ -496,6 +564,7 @@ impl<A: Clone> AuthorityAggregator<A> {
             timeouts: Default::default(),
             committee_store,
             validator_display_names,
+            overload_backoff: Default::default(),
         }
     }
 
@@ -509,16 +578,20 @@ impl<A: Clone> AuthorityAggregator<A> {
         &self,
         committee: CommitteeWithNetworkMetadata,
         network_config: &Config,
+        tls_config: Option<&ValidatorClientTlsConfig>,
         disallow_missing_intermediate_committees: bool,
     ) -> SuiResult<AuthorityAggregator<NetworkAuthorityClient>> {
-        let network_clients =
-            make_network_authority_clients_with_network_config(&committee, network_config)
-                .map_err(|err| SuiError::GenericAuthorityError {
-                    error: format!(
-                        "Failed to make authority clients from committee {committee}, err: {:?}",
-                        err
-                    ),
-                })?;
+        let network_clients = make_network_authority_clients_with_network_config(
+            &committee,
+            network_config,
+            tls_config,
+        )
+        .map_err(|err| SuiError::GenericAuthorityError {
+            error: format!(
+                "Failed to make authority clients from committee {committee}, err: {:?}",
+                err
+            ),
+        })?;
 
         let safe_clients = network_clients
             .into_iter()
@@ -563,6 +636,7 @@ impl<A: Clone> AuthorityAggregator<A> {
             safe_client_metrics_base: self.safe_client_metrics_base.clone(),
             committee_store: self.committee_store.clone(),
             validator_display_names: Arc::new(HashMap::new()),
+            overload_backoff: Default::default(),
         })
     }
 
@@ -592,6 +666,13 @@ impl<A: Clone> AuthorityAggregator<A> {
             .map(|(k, v)| (k, (*v).clone()))
             .collect()
     }
+
+    /// The earliest instant at which a request can reach a quorum of validators without
+    /// hitting the retry-after hints of overloaded ones, or `None` if it already can. Requests
+    /// are not sent to a validator until its hint has elapsed.
+    pub fn overload_backoff_deadline(&self) -> Option<Instant> {
+        self.overload_backoff.deadline(&self.committee)
+    }
 }
 
 fn create_safe_clients<A: Clone>(
@@ -627,6 +708,7 @@ impl AuthorityAggregator<NetworkAuthorityClient> {
         committee_store: &Arc<CommitteeStore>,
         safe_client_metrics_base: SafeClientMetricsBase,
         auth_agg_metrics: AuthAggMetrics,
+        tls_config: Option<&ValidatorClientTlsConfig>,
     ) -> anyhow::Result<Self> {
         // TODO: We should get the committee from the epoch store instead to ensure consistency.
         // Instead of this function use AuthorityEpochStore::epoch_start_configuration() to access this object everywhere
@@ -653,6 +735,7 @@ impl AuthorityAggregator<NetworkAuthorityClient> {
             safe_client_metrics_base,
             Arc::new(auth_agg_metrics),
             Arc::new(validator_display_names),
+            tls_config,
         )
     }
 
@@ -662,10 +745,14 @@ impl AuthorityAggregator<NetworkAuthorityClient> {
         safe_client_metrics_base: SafeClientMetricsBase,
         auth_agg_metrics: Arc<AuthAggMetrics>,
         validator_display_names: Arc<HashMap<AuthorityName, String>>,
+        tls_config: Option<&ValidatorClientTlsConfig>,
     ) -> anyhow::Result<Self> {
         let net_config = default_mysten_network_config();
-        let authority_clients =
-            make_network_authority_clients_with_network_config(&committee, &net_config)?;
+        let authority_clients = make_network_authority_clients_with_network_config(
+            &committee,
+            &net_config,
+            tls_config,
+        )?;
         Ok(Self::new_with_metrics(
             committee.committee,
             committee_store.clone(),
@@ -991,6 +1078,137 @@ where
         Ok(result.0)
     }
 
+    /// Get the attempted double spends of an object seen by each authority, e.g. to explain why a
+    /// transaction could not get a quorum of signatures. This is best effort: authorities that
+    /// fail to respond before the timeout are left out.
+    pub async fn get_conflicting_transactions(
+        &self,
+        object_id: ObjectID,
+    ) -> BTreeMap<AuthorityName, Vec<ConflictingTransactions>> {
+        let result = quorum_map_then_reduce_with_timeout(
+            self.committee.clone(),
+            self.authority_clients.clone(),
+            BTreeMap::new(),
+            |_name, client| {
+                Box::pin(async move {
+                    client
+                        .handle_conflicting_transactions_request(object_id)
+                        .await
+                })
+            },
+            |mut state, name, _weight, result| {
+                Box::pin(async move {
+                    match result {
+                        Ok(conflicts) => {
+                            state.insert(name, conflicts);
+                        }
+                        Err(err) => {
+                            debug!(
+                                ?object_id,
+                                "Failed to get conflicting transactions from validator {:?}: {:?}",
+                                name.concise(),
+                                err
+                            );
+                        }
+                    }
+                    ReduceOutput::Continue(state)
+                })
+            },
+            self.timeouts.pre_quorum_timeout,
+        )
+        .await;
+        // The reducer never ends the iteration early, so the state is returned once all the
+        // authorities responded or the timeout elapsed.
+        match result {
+            Ok((state, _)) | Err(state) => state,
+        }
+    }
+
+    /// Get the lowest gas price at which a transaction is currently signed by a quorum of
+    /// authorities, for clients to price their transactions when the network is under load.
+    /// Returns `None` if authorities with a quorum of stake did not respond before the timeout.
+    pub async fn get_inclusion_gas_price(&self) -> Option<u64> {
+        let epoch = self.committee.epoch;
+        let result = quorum_map_then_reduce_with_timeout(
+            self.committee.clone(),
+            self.authority_clients.clone(),
+            Vec::new(),
+            |_name, client| {
+                Box::pin(async move { client.handle_inclusion_gas_price_request().await })
+            },
+            |mut state, name, weight, result| {
+                Box::pin(async move {
+                    match result {
+                        Ok(response) if response.epoch == epoch => {
+                            state.push((response.inclusion_gas_price, weight));
+                        }
+                        Ok(response) => {
+                            debug!(
+                                "Validator {:?} returned the inclusion gas price of epoch {}, expected {}",
+                                name.concise(),
+                                response.epoch,
+                                epoch
+                            );
+                        }
+                        Err(err) => {
+                            debug!(
+                                "Failed to get inclusion gas price from validator {:?}: {:?}",
+                                name.concise(),
+                                err
+                            );
+                        }
+                    }
+                    ReduceOutput::Continue(state)
+                })
+            },
+            self.timeouts.pre_quorum_timeout,
+        )
+        .await;
+        let mut prices = match result {
+            Ok((state, _)) | Err(state) => state,
+        };
+        prices.sort_unstable();
+        let mut total_weight = 0;
+        for (price, weight) in prices {
+            total_weight += weight;
+            if total_weight >= self.committee.quorum_threshold() {
+                return Some(price);
+            }
+        }
+        None
+    }
+
+    /// Check the state of each authority at the end of `epoch` against the commitment of the
+    /// committee to the live object set. Returns, for each authority that responded before the
+    /// timeout, the committed digest, `None` if the authority doesn't have the last checkpoint of
+    /// the epoch, or the error if its accumulated live object set differs from the committed one.
+    pub async fn get_epoch_state_commitments(
+        &self,
+        epoch: EpochId,
+    ) -> BTreeMap<AuthorityName, SuiResult<Option<ECMHLiveObjectSetDigest>>> {
+        let result = quorum_map_then_reduce_with_timeout(
+            self.committee.clone(),
+            self.authority_clients.clone(),
+            BTreeMap::new(),
+            |_name, client| {
+                Box::pin(async move { client.handle_epoch_state_commitment_request(epoch).await })
+            },
+            |mut state, name, _weight, result| {
+                Box::pin(async move {
+                    state.insert(name, result);
+                    ReduceOutput::Continue(state)
+                })
+            },
+            self.timeouts.pre_quorum_timeout,
+        )
+        .await;
+        // The reducer never ends the iteration early, so the state is returned once all the
+        // authorities responded or the timeout elapsed.
+        match result {
+            Ok((state, _)) | Err(state) => state,
+        }
+    }
+
     /// Submits the transaction to a quorum of validators to make a certificate.
     pub async fn process_transaction(
         &self,
@@ -1029,9 +1247,12 @@ where
                 committee.clone(),
                 self.authority_clients.clone(),
                 state,
-                |_name, client| {
+                |name, client| {
                     Box::pin(
                         async move {
+                            if let Some(err) = self.overload_backoff.backing_off(&name) {
+                                return Err(err);
+                            }
                             let _guard = GaugeGuard::acquire(&self.metrics.inflight_transaction_requests);
                             client.handle_transaction(transaction_ref.clone()).await
                         },
@@ -1056,6 +1277,7 @@ where
                                     .with_label_values(&[&display_name, err.as_ref()])
                                     .inc();
                                 Self::record_rpc_error_maybe(self.metrics.clone(), &display_name, &err);
+                                self.overload_backoff.record(name, &err);
                                 let (retryable, categorized) = err.is_retryable();
                                 if !categorized {
                                     // TODO: Should minimize possible uncategorized errors here
@@ -1449,12 +1671,17 @@ where
         let metrics = self.metrics.clone();
         let metrics_clone = metrics.clone();
         let validator_display_names = self.validator_display_names.clone();
+        let overload_backoff = self.overload_backoff.clone();
+        let overload_backoff_clone = overload_backoff.clone();
         let (result, mut remaining_tasks) = quorum_map_then_reduce_with_timeout(
             committee.clone(),
             authority_clients.clone(),
             state,
             move |name, client| {
                 Box::pin(async move {
+                    if let Some(err) = overload_backoff_clone.backing_off(&name) {
+                        return Err(err);
+                    }
                     let _guard = GaugeGuard::acquire(&metrics_clone.inflight_certificate_requests);
                     client
                         .handle_certificate_v2(cert_ref)
@@ -1467,6 +1694,7 @@ where
             move |mut state, name, weight, response| {
                 let committee_clone = committee.clone();
                 let metrics = metrics.clone();
+                let overload_backoff = overload_backoff.clone();
                 let display_name = validator_display_names.get(&name).unwrap_or(&name.concise().to_string()).clone();
                 Box::pin(async move {
                     // We aggregate the effects response, until we have more than 2f
@@ -1495,6 +1723,7 @@ where
                                 .with_label_values(&[&display_name, err.as_ref()])
                                 .inc();
                             Self::record_rpc_error_maybe(metrics, &display_name, &err);
+                            overload_backoff.record(name, &err);
                             let (retryable, categorized) = err.is_retryable();
                             if !categorized {
                                 // TODO: Should minimize possible uncategorized errors here
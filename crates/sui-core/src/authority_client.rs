@@ -9,7 +9,8 @@ use std::collections::BTreeMap;
 use std::time::Duration;
 use sui_network::{api::ValidatorClient, tonic};
 use sui_types::base_types::AuthorityName;
-use sui_types::committee::CommitteeWithNetworkMetadata;
+use sui_types::committee::{CommitteeWithNetworkMetadata, NetworkMetadata};
+use sui_types::crypto::{KeypairTraits, NetworkKeyPair};
 use sui_types::messages_checkpoint::{
     CheckpointRequest, CheckpointRequestV2, CheckpointResponse, CheckpointResponseV2,
 };
@@ -19,8 +20,11 @@ use sui_types::{error::SuiError, transaction::*};
 
 use sui_network::tonic::transport::Channel;
 use sui_types::messages_grpc::{
-    HandleCertificateResponseV2, HandleTransactionResponse, ObjectInfoRequest, ObjectInfoResponse,
-    SystemStateRequest, TransactionInfoRequest, TransactionInfoResponse,
+    ConflictingTransactionsRequest, ConflictingTransactionsResponse, EpochStateCommitmentRequest,
+    EpochStateCommitmentResponse, HandleCertificateResponseV2, HandleTransactionResponse,
+    InclusionGasPriceRequest, InclusionGasPriceResponse, ObjectInfoRequest, ObjectInfoResponse,
+    ObjectOwnershipProofRequest, ObjectOwnershipProofResponse, SystemStateRequest,
+    TransactionInfoRequest, TransactionInfoResponse,
 };
 
 #[async_trait]
@@ -65,6 +69,30 @@ pub trait AuthorityAPI {
         &self,
         request: SystemStateRequest,
     ) -> Result<SuiSystemState, SuiError>;
+
+    /// Get the attempted double spends of an object seen by this authority.
+    async fn handle_conflicting_transactions_request(
+        &self,
+        request: ConflictingTransactionsRequest,
+    ) -> Result<ConflictingTransactionsResponse, SuiError>;
+
+    /// Get the lowest gas price of transactions this authority currently signs.
+    async fn handle_inclusion_gas_price_request(
+        &self,
+        request: InclusionGasPriceRequest,
+    ) -> Result<InclusionGasPriceResponse, SuiError>;
+
+    /// Get a proof of the latest state of an object.
+    async fn handle_object_ownership_proof_request(
+        &self,
+        request: ObjectOwnershipProofRequest,
+    ) -> Result<ObjectOwnershipProofResponse, SuiError>;
+
+    /// Get the commitment to the live object set at the end of an epoch.
+    async fn handle_epoch_state_commitment_request(
+        &self,
+        request: EpochStateCommitmentRequest,
+    ) -> Result<EpochStateCommitmentResponse, SuiError>;
 }
 
 #[derive(Clone)]
@@ -182,30 +210,126 @@ impl AuthorityAPI for NetworkAuthorityClient {
             .map(tonic::Response::into_inner)
             .map_err(Into::into)
     }
+
+    async fn handle_conflicting_transactions_request(
+        &self,
+        request: ConflictingTransactionsRequest,
+    ) -> Result<ConflictingTransactionsResponse, SuiError> {
+        self.client()
+            .get_conflicting_transactions(request)
+            .await
+            .map(tonic::Response::into_inner)
+            .map_err(Into::into)
+    }
+
+    async fn handle_inclusion_gas_price_request(
+        &self,
+        request: InclusionGasPriceRequest,
+    ) -> Result<InclusionGasPriceResponse, SuiError> {
+        self.client()
+            .get_inclusion_gas_price(request)
+            .await
+            .map(tonic::Response::into_inner)
+            .map_err(Into::into)
+    }
+
+    async fn handle_object_ownership_proof_request(
+        &self,
+        request: ObjectOwnershipProofRequest,
+    ) -> Result<ObjectOwnershipProofResponse, SuiError> {
+        self.client()
+            .get_object_ownership_proof(request)
+            .await
+            .map(tonic::Response::into_inner)
+            .map_err(Into::into)
+    }
+
+    async fn handle_epoch_state_commitment_request(
+        &self,
+        request: EpochStateCommitmentRequest,
+    ) -> Result<EpochStateCommitmentResponse, SuiError> {
+        self.client()
+            .get_epoch_state_commitment(request)
+            .await
+            .map(tonic::Response::into_inner)
+            .map_err(Into::into)
+    }
+}
+
+/// The TLS settings a node uses to connect to validators that serve their network address over
+/// `https`: the certificate of its network key, which validators require to authenticate it, and
+/// the cipher suites it allows.
+#[derive(Clone)]
+pub struct ValidatorClientTlsConfig {
+    certificate: sui_tls::rustls::Certificate,
+    private_key: sui_tls::rustls::PrivateKey,
+    cipher_suites: Vec<sui_tls::rustls::SupportedCipherSuite>,
+}
+
+impl ValidatorClientTlsConfig {
+    pub fn new(
+        network_key_pair: &NetworkKeyPair,
+        cipher_suites: Vec<sui_tls::rustls::SupportedCipherSuite>,
+    ) -> Self {
+        let certificate = sui_tls::SelfSignedCertificate::new(
+            network_key_pair.copy().private(),
+            sui_tls::SUI_VALIDATOR_SERVER_NAME,
+        );
+        Self {
+            certificate: certificate.rustls_certificate(),
+            private_key: certificate.rustls_private_key(),
+            cipher_suites,
+        }
+    }
 }
 
 pub fn make_network_authority_clients_with_network_config(
     committee: &CommitteeWithNetworkMetadata,
     network_config: &Config,
+    tls_config: Option<&ValidatorClientTlsConfig>,
 ) -> anyhow::Result<BTreeMap<AuthorityName, NetworkAuthorityClient>> {
     let mut authority_clients = BTreeMap::new();
     for (name, _stakes) in &committee.committee.voting_rights {
-        let address = &committee
-            .network_metadata
-            .get(name)
-            .ok_or_else(|| {
-                SuiError::from("Missing network metadata in CommitteeWithNetworkMetadata")
-            })?
-            .network_address;
-        let channel = network_config
-            .connect_lazy(address)
-            .map_err(|err| anyhow!(err.to_string()))?;
+        let network_metadata = committee.network_metadata.get(name).ok_or_else(|| {
+            SuiError::from("Missing network metadata in CommitteeWithNetworkMetadata")
+        })?;
+        let channel = connect_lazy_to_validator(network_metadata, network_config, tls_config)?;
         let client = NetworkAuthorityClient::new(channel);
         authority_clients.insert(*name, client);
     }
     Ok(authority_clients)
 }
 
+/// Validators that serve their network address over `https` must authenticate with the network
+/// key they registered on chain, and require the client to authenticate with its own. Without
+/// `tls_config`, the client doesn't authenticate, which only servers not requiring it accept.
+fn connect_lazy_to_validator(
+    network_metadata: &NetworkMetadata,
+    network_config: &Config,
+    tls_config: Option<&ValidatorClientTlsConfig>,
+) -> anyhow::Result<Channel> {
+    let address = &network_metadata.network_address;
+    let channel = if address.is_https() {
+        let verifier = sui_tls::ServerCertVerifier::new(
+            network_metadata.network_pubkey.clone(),
+            sui_tls::SUI_VALIDATOR_SERVER_NAME.to_string(),
+        );
+        let rustls_config = match tls_config {
+            Some(tls_config) => verifier.rustls_client_config(
+                &tls_config.cipher_suites,
+                vec![tls_config.certificate.clone()],
+                tls_config.private_key.clone(),
+            )?,
+            None => verifier
+                .rustls_client_config_with_no_client_auth(sui_tls::rustls::DEFAULT_CIPHER_SUITES)?,
+        };
+        network_config.connect_lazy_with_tls(address, rustls_config)
+    } else {
+        network_config.connect_lazy(address)
+    };
+    channel.map_err(|err| anyhow!(err.to_string()))
+}
+
 pub fn make_authority_clients_with_timeout_config(
     committee: &CommitteeWithNetworkMetadata,
     connect_timeout: Duration,
@@ -214,5 +338,5 @@ pub fn make_authority_clients_with_timeout_config(
     let mut network_config = mysten_network::config::Config::new();
     network_config.connect_timeout = Some(connect_timeout);
     network_config.request_timeout = Some(request_timeout);
-    make_network_authority_clients_with_network_config(committee, &network_config)
+    make_network_authority_clients_with_network_config(committee, &network_config, None)
 }
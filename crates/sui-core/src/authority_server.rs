@@ -7,20 +7,29 @@ use async_trait::async_trait;
 use mysten_metrics::histogram::Histogram as MystenHistogram;
 use mysten_metrics::spawn_monitored_task;
 use narwhal_worker::LazyNarwhalClient;
+use parking_lot::Mutex;
 use prometheus::{
     register_int_counter_vec_with_registry, register_int_counter_with_registry, IntCounter,
     IntCounterVec, Registry,
 };
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::net::IpAddr;
 use std::{io, sync::Arc};
 use sui_network::{
     api::{Validator, ValidatorServer},
     tonic,
 };
+use sui_types::base_types::SuiAddress;
+use sui_types::deserialization_limits::DeserializationLimits;
 use sui_types::effects::TransactionEvents;
 use sui_types::messages_consensus::ConsensusTransaction;
 use sui_types::messages_grpc::{
-    HandleCertificateResponseV2, HandleTransactionResponse, ObjectInfoRequest, ObjectInfoResponse,
-    SubmitCertificateResponse, SystemStateRequest, TransactionInfoRequest, TransactionInfoResponse,
+    ConflictingTransactionsRequest, ConflictingTransactionsResponse, EpochStateCommitmentRequest,
+    EpochStateCommitmentResponse, HandleCertificateResponseV2, HandleTransactionResponse,
+    InclusionGasPriceRequest, InclusionGasPriceResponse, ObjectInfoRequest, ObjectInfoResponse,
+    ObjectOwnershipProofRequest, ObjectOwnershipProofResponse, SubmitCertificateResponse,
+    SystemStateRequest, TransactionInfoRequest, TransactionInfoResponse,
 };
 use sui_types::multiaddr::Multiaddr;
 use sui_types::sui_system_state::SuiSystemState;
@@ -122,11 +131,16 @@ impl AuthorityServer {
     ) -> Result<AuthorityServerHandle, io::Error> {
         let mut server = mysten_network::config::Config::new()
             .server_builder()
-            .add_service(ValidatorServer::new(ValidatorService {
-                state: self.state,
-                consensus_adapter: self.consensus_adapter,
-                metrics: self.metrics.clone(),
-            }))
+            .add_service(
+                ValidatorServer::new(ValidatorService::new(
+                    self.state,
+                    self.consensus_adapter,
+                    self.metrics.clone(),
+                ))
+                .max_decoding_message_size(
+                    DeserializationLimits::get().max_validator_request_size_bytes(),
+                ),
+            )
             .bind(&address)
             .await
             .unwrap();
@@ -236,11 +250,62 @@ impl ValidatorServiceMetrics {
     }
 }
 
+/// Counts the transactions being signed per key (sender or client address), to limit the
+/// load a single client can put on the validator.
+struct InFlightCounts<K> {
+    counts: Mutex<HashMap<K, usize>>,
+}
+
+impl<K: Copy + Eq + Hash> InFlightCounts<K> {
+    fn new() -> Self {
+        Self {
+            counts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Admits one more transaction for `key`, unless `threshold` transactions are already in
+    /// flight for it, in which case the current count is returned. The transaction stays in
+    /// flight until the returned guard is dropped.
+    fn try_admit(&self, key: K, threshold: usize) -> Result<InFlightGuard<'_, K>, usize> {
+        let mut counts = self.counts.lock();
+        let count = counts.entry(key).or_default();
+        if *count >= threshold {
+            return Err(*count);
+        }
+        *count += 1;
+        Ok(InFlightGuard { counts: self, key })
+    }
+
+    #[cfg(test)]
+    fn in_flight(&self, key: &K) -> usize {
+        self.counts.lock().get(key).copied().unwrap_or_default()
+    }
+}
+
+struct InFlightGuard<'a, K: Copy + Eq + Hash> {
+    counts: &'a InFlightCounts<K>,
+    key: K,
+}
+
+impl<K: Copy + Eq + Hash> Drop for InFlightGuard<'_, K> {
+    fn drop(&mut self) {
+        let mut counts = self.counts.counts.lock();
+        if let Some(count) = counts.get_mut(&self.key) {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&self.key);
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ValidatorService {
     state: Arc<AuthorityState>,
     consensus_adapter: Arc<ConsensusAdapter>,
     metrics: Arc<ValidatorServiceMetrics>,
+    in_flight_per_sender: Arc<InFlightCounts<SuiAddress>>,
+    in_flight_per_client: Arc<InFlightCounts<IpAddr>>,
 }
 
 impl ValidatorService {
@@ -253,6 +318,8 @@ impl ValidatorService {
             state,
             consensus_adapter,
             metrics,
+            in_flight_per_sender: Arc::new(InFlightCounts::new()),
+            in_flight_per_client: Arc::new(InFlightCounts::new()),
         }
     }
 
@@ -288,8 +355,11 @@ impl ValidatorService {
             state,
             consensus_adapter,
             metrics,
+            in_flight_per_sender,
+            in_flight_per_client,
         } = self;
 
+        let client_addr = request.remote_addr().map(|addr| addr.ip());
         let transaction = request.into_inner();
 
         let epoch_store = state.load_epoch_store_one_call_per_task();
@@ -312,15 +382,61 @@ impl ValidatorService {
             .into());
         }
 
-        let overload_check_res =
-            state.check_system_overload(&consensus_adapter, transaction.data());
-        if let Err(error) = overload_check_res {
-            metrics
-                .num_rejected_tx_during_overload
-                .with_label_values(&[error.as_ref()])
-                .inc();
-            return Err(error.into());
-        }
+        // Limit the number of transactions signed concurrently for a single sender or client, and
+        // shed new transactions before certificates when the validator is overloaded.
+        let overload_threshold_config = state.overload_threshold_config();
+        let sender = transaction.data().transaction_data().sender();
+        let in_flight_guards = in_flight_per_sender
+            .try_admit(
+                sender,
+                overload_threshold_config.max_transactions_in_flight_per_sender,
+            )
+            .map_err(
+                |in_flight| SuiError::TooManyTransactionsInFlightFromSender {
+                    sender,
+                    in_flight,
+                    threshold: overload_threshold_config.max_transactions_in_flight_per_sender,
+                },
+            )
+            .and_then(|sender_guard| {
+                let client_guard = client_addr
+                    .map(|client| {
+                        in_flight_per_client
+                            .try_admit(
+                                client,
+                                overload_threshold_config.max_transactions_in_flight_per_client,
+                            )
+                            .map_err(
+                                |in_flight| SuiError::TooManyTransactionsInFlightFromClient {
+                                    client: client.to_string(),
+                                    in_flight,
+                                    threshold: overload_threshold_config
+                                        .max_transactions_in_flight_per_client,
+                                },
+                            )
+                    })
+                    .transpose()?;
+                Ok((sender_guard, client_guard))
+            });
+        let overload_check_res = in_flight_guards.and_then(|guards| {
+            state
+                .check_system_overload_for_signing(
+                    &consensus_adapter,
+                    transaction.data(),
+                    &epoch_store,
+                )
+                .map(|()| guards)
+        });
+        let _in_flight_guards = match overload_check_res {
+            Ok(guards) => guards,
+            Err(error) => {
+                metrics
+                    .num_rejected_tx_during_overload
+                    .with_label_values(&[error.as_ref()])
+                    .inc();
+                return Err(state.with_overload_hint(error).into());
+            }
+        };
 
         let _handle_tx_metrics_guard = metrics.handle_transaction_latency.start_timer();
 
@@ -333,7 +449,11 @@ impl ValidatorService {
         let tx_digest = transaction.digest();
 
         // Enable Trace Propagation across spans/processes using tx_digest
-        let span = error_span!("validator_state_process_tx", ?tx_digest);
+        let span = error_span!(
+            "validator_state_process_tx",
+            ?tx_digest,
+            epoch = epoch_store.epoch()
+        );
 
         let info = state
             .handle_transaction(&epoch_store, transaction)
@@ -358,6 +478,7 @@ impl ValidatorService {
             state,
             consensus_adapter,
             metrics,
+            ..
         } = self;
 
         let epoch_store = state.load_epoch_store_one_call_per_task();
@@ -421,7 +542,7 @@ impl ValidatorService {
                 .num_rejected_cert_during_overload
                 .with_label_values(&[error.as_ref()])
                 .inc();
-            return Err(error.into());
+            return Err(state.with_overload_hint(error).into());
         }
 
         // code block within reconfiguration lock
@@ -443,8 +564,12 @@ impl ValidatorService {
             // 3) All certificates are sent to consensus (at least by some authorities)
             // For shared objects this will wait until either timeout or we have heard back from consensus.
             // For owned objects this will return without waiting for certificate to be sequenced
-            // First do quick dirty non-async check
-            if !epoch_store.is_tx_cert_consensus_message_processed(&certificate)? {
+            // First do quick dirty non-async check.
+            // Certificates already pending in consensus, e.g. when the client retries, are not
+            // submitted again.
+            if !epoch_store.is_tx_cert_consensus_message_processed(&certificate)?
+                && !epoch_store.is_pending_consensus_certificate(certificate.digest())
+            {
                 let _metrics_guard = if shared_object_tx {
                     Some(metrics.consensus_latency.start_timer())
                 } else {
@@ -513,7 +638,11 @@ impl Validator for ValidatorService {
         // Spawns a task which handles the certificate. The task will unconditionally continue
         // processing in the event that the client connection is dropped.
         spawn_monitored_task!(async move {
-            let span = error_span!("submit_certificate", tx_digest = ?request.get_ref().digest());
+            let span = error_span!(
+                "submit_certificate",
+                tx_digest = ?request.get_ref().digest(),
+                epoch = request.get_ref().epoch()
+            );
             Self::handle_certificate(validator_service, request, false)
                 .instrument(span)
                 .await
@@ -530,7 +659,11 @@ impl Validator for ValidatorService {
         request.get_ref().verify_user_input()?;
         let validator_service = self.clone();
 
-        let span = error_span!("handle_certificate", tx_digest = ?request.get_ref().digest());
+        let span = error_span!(
+            "handle_certificate",
+            tx_digest = ?request.get_ref().digest(),
+            epoch = request.get_ref().epoch()
+        );
         Self::handle_certificate(validator_service, request, true)
             .instrument(span)
             .await
@@ -595,4 +728,50 @@ impl Validator for ValidatorService {
 
         return Ok(tonic::Response::new(response));
     }
+
+    async fn get_conflicting_transactions(
+        &self,
+        request: tonic::Request<ConflictingTransactionsRequest>,
+    ) -> Result<tonic::Response<ConflictingTransactionsResponse>, tonic::Status> {
+        let request = request.into_inner();
+
+        let response = self
+            .state
+            .handle_conflicting_transactions_request(request)?;
+
+        return Ok(tonic::Response::new(response));
+    }
+
+    async fn get_inclusion_gas_price(
+        &self,
+        request: tonic::Request<InclusionGasPriceRequest>,
+    ) -> Result<tonic::Response<InclusionGasPriceResponse>, tonic::Status> {
+        let request = request.into_inner();
+
+        let response = self.state.handle_inclusion_gas_price_request(request)?;
+
+        return Ok(tonic::Response::new(response));
+    }
+
+    async fn get_object_ownership_proof(
+        &self,
+        request: tonic::Request<ObjectOwnershipProofRequest>,
+    ) -> Result<tonic::Response<ObjectOwnershipProofResponse>, tonic::Status> {
+        let request = request.into_inner();
+
+        let response = self.state.handle_object_ownership_proof_request(request)?;
+
+        return Ok(tonic::Response::new(response));
+    }
+
+    async fn get_epoch_state_commitment(
+        &self,
+        request: tonic::Request<EpochStateCommitmentRequest>,
+    ) -> Result<tonic::Response<EpochStateCommitmentResponse>, tonic::Status> {
+        let request = request.into_inner();
+
+        let response = self.state.handle_epoch_state_commitment_request(request)?;
+
+        return Ok(tonic::Response::new(response));
+    }
 }
@@ -0,0 +1,71 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+/// Limits the number of checkpoints executed concurrently by the time it takes to execute a
+/// checkpoint and commit it to the db, so that a node catching up from far behind doesn't queue more
+/// writes than the db can absorb. The limit is halved when a commit is slower than the target,
+/// and grows by one when it is faster, between 1 and the configured maximum.
+pub(crate) struct ConcurrencyLimit {
+    limit: usize,
+    max_limit: usize,
+    target_commit_latency: Option<Duration>,
+}
+
+impl ConcurrencyLimit {
+    pub fn new(max_limit: usize, target_commit_latency: Option<Duration>) -> Self {
+        let max_limit = max_limit.max(1);
+        Self {
+            limit: max_limit,
+            max_limit,
+            target_commit_latency,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn record_commit_latency(&mut self, latency: Duration) {
+        let Some(target) = self.target_commit_latency else {
+            return;
+        };
+        self.limit = if latency > target {
+            (self.limit / 2).max(1)
+        } else {
+            (self.limit + 1).min(self.max_limit)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concurrency_limit() {
+        let target = Duration::from_millis(100);
+        let mut limit = ConcurrencyLimit::new(10, Some(target));
+        assert_eq!(limit.limit(), 10);
+
+        limit.record_commit_latency(target * 2);
+        assert_eq!(limit.limit(), 5);
+        for _ in 0..5 {
+            limit.record_commit_latency(target * 2);
+        }
+        assert_eq!(limit.limit(), 1);
+
+        limit.record_commit_latency(target);
+        assert_eq!(limit.limit(), 2);
+        for _ in 0..20 {
+            limit.record_commit_latency(target / 2);
+        }
+        assert_eq!(limit.limit(), 10);
+
+        // Without a target, the limit stays at the maximum.
+        let mut limit = ConcurrencyLimit::new(10, None);
+        limit.record_commit_latency(Duration::from_secs(10));
+        assert_eq!(limit.limit(), 10);
+    }
+}
@@ -15,7 +15,10 @@ pub struct CheckpointExecutorMetrics {
     pub checkpoint_exec_errors: IntCounter,
     pub checkpoint_exec_epoch: IntGauge,
     pub checkpoint_exec_inflight: IntGauge,
+    pub checkpoint_exec_concurrency_limit: IntGauge,
+    pub checkpoint_exec_sync_lag: IntGauge,
     pub checkpoint_exec_latency_us: Histogram,
+    pub checkpoint_commit_latency_us: Histogram,
     pub checkpoint_prepare_latency_us: Histogram,
     pub checkpoint_transaction_count: Histogram,
     pub checkpoint_contents_age_ms: Histogram,
@@ -62,11 +65,28 @@ impl CheckpointExecutorMetrics {
                 registry
             )
             .unwrap(),
+            checkpoint_exec_concurrency_limit: register_int_gauge_with_registry!(
+                "checkpoint_exec_concurrency_limit",
+                "Current limit on the number of checkpoints executed concurrently, lowered when commits are slow",
+                registry
+            )
+            .unwrap(),
+            checkpoint_exec_sync_lag: register_int_gauge_with_registry!(
+                "checkpoint_exec_sync_lag",
+                "Number of synced checkpoints that have not been executed yet",
+                registry
+            )
+            .unwrap(),
             checkpoint_exec_latency_us: Histogram::new_in_registry(
                 "checkpoint_exec_latency_us",
                 "Latency of executing a checkpoint from enqueue to all effects available, in microseconds",
                 registry,
             ),
+            checkpoint_commit_latency_us: Histogram::new_in_registry(
+                "checkpoint_commit_latency_us",
+                "Latency of executing a checkpoint and committing its effects, objects and finalization to the db, in microseconds",
+                registry,
+            ),
             checkpoint_prepare_latency_us: Histogram::new_in_registry(
                 "checkpoint_prepare_latency_us",
                 "Latency of preparing a checkpoint to enqueue for execution, in microseconds",
@@ -57,14 +57,18 @@ use crate::state_accumulator::StateAccumulator;
 use crate::transaction_manager::TransactionManager;
 use crate::{authority::EffectsNotifyRead, checkpoints::CheckpointStore};
 
+use self::concurrency_limit::ConcurrencyLimit;
 use self::metrics::CheckpointExecutorMetrics;
 
+mod concurrency_limit;
 mod data_ingestion_handler;
 mod metrics;
 #[cfg(test)]
 pub(crate) mod tests;
 
-type CheckpointExecutionBuffer = FuturesOrdered<JoinHandle<VerifiedCheckpoint>>;
+/// Executed checkpoints, with the time it took to execute and commit them. End of epoch
+/// checkpoints have no commit time, as they are committed after their change_epoch tx.
+type CheckpointExecutionBuffer = FuturesOrdered<JoinHandle<(VerifiedCheckpoint, Option<Duration>)>>;
 
 /// The interval to log checkpoint progress, in # of checkpoints processed.
 const CHECKPOINT_PROGRESS_LOG_COUNT_INTERVAL: u64 = 5000;
@@ -82,6 +86,7 @@ pub struct CheckpointExecutor {
     tx_manager: Arc<TransactionManager>,
     accumulator: Arc<StateAccumulator>,
     config: CheckpointExecutorConfig,
+    concurrency_limit: ConcurrencyLimit,
     metrics: Arc<CheckpointExecutorMetrics>,
 }
 
@@ -101,6 +106,7 @@ impl CheckpointExecutor {
             authority_store,
             tx_manager,
             accumulator,
+            concurrency_limit: Self::concurrency_limit(&config),
             config,
             metrics: CheckpointExecutorMetrics::new(prometheus_registry),
         }
@@ -113,17 +119,28 @@ impl CheckpointExecutor {
         tx_manager: Arc<TransactionManager>,
         accumulator: Arc<StateAccumulator>,
     ) -> Self {
+        let config = CheckpointExecutorConfig::default();
         Self {
             mailbox,
             checkpoint_store,
             authority_store,
             tx_manager,
             accumulator,
-            config: Default::default(),
+            concurrency_limit: Self::concurrency_limit(&config),
+            config,
             metrics: CheckpointExecutorMetrics::new_for_tests(),
         }
     }
 
+    fn concurrency_limit(config: &CheckpointExecutorConfig) -> ConcurrencyLimit {
+        ConcurrencyLimit::new(
+            config.checkpoint_execution_max_concurrency,
+            config
+                .checkpoint_execution_target_commit_latency_ms
+                .map(Duration::from_millis),
+        )
+    }
+
     /// Ensure that all checkpoints in the current epoch will be executed.
     /// We don't technically need &mut on self, but passing it to make sure only one instance is
     /// running at one time.
@@ -212,9 +229,12 @@ impl CheckpointExecutor {
                 // watermark accordingly. Note that given that checkpoints are guaranteed to
                 // be processed (added to FuturesOrdered) in seq_number order, using FuturesOrdered
                 // guarantees that we will also ratchet the watermarks in order.
-                Some(Ok(checkpoint)) = pending.next() => {
+                Some(Ok((checkpoint, commit_latency))) = pending.next() => {
                     self.process_executed_checkpoint(&checkpoint);
                     highest_executed = Some(checkpoint.clone());
+                    if let Some(commit_latency) = commit_latency {
+                        self.record_commit_latency(commit_latency);
+                    }
 
 
                     // Estimate TPS every 10k transactions or 30 sec
@@ -260,6 +280,16 @@ impl CheckpointExecutor {
         }
     }
 
+    fn record_commit_latency(&mut self, commit_latency: Duration) {
+        self.metrics
+            .checkpoint_commit_latency_us
+            .report(commit_latency.as_micros() as u64);
+        self.concurrency_limit.record_commit_latency(commit_latency);
+        self.metrics
+            .checkpoint_exec_concurrency_limit
+            .set(self.concurrency_limit.limit() as i64);
+    }
+
     pub fn set_inconsistent_state(&self, is_inconsistent_state: bool) {
         self.metrics
             .accumulator_inconsistent_state
@@ -333,9 +363,13 @@ impl CheckpointExecutor {
             debug!("No checkpoints to schedule, highest synced checkpoint is None",);
             return;
         };
+        let num_executed = *next_to_schedule - pending.len() as u64;
+        self.metrics.checkpoint_exec_sync_lag.set(
+            (latest_synced_checkpoint.sequence_number + 1).saturating_sub(num_executed) as i64,
+        );
 
         while *next_to_schedule <= *latest_synced_checkpoint.sequence_number()
-            && pending.len() < self.config.checkpoint_execution_max_concurrency
+            && pending.len() < self.concurrency_limit.limit()
         {
             let checkpoint = self
                 .checkpoint_store
@@ -396,27 +430,31 @@ impl CheckpointExecutor {
 
         pending.push_back(spawn_monitored_task!(async move {
             let epoch_store = epoch_store.clone();
-            while let Err(err) = execute_checkpoint(
-                checkpoint.clone(),
-                authority_store.clone(),
-                checkpoint_store.clone(),
-                epoch_store.clone(),
-                tx_manager.clone(),
-                accumulator.clone(),
-                local_execution_timeout_sec,
-                &metrics,
-                data_ingestion_dir.clone(),
-            )
-            .await
-            {
-                error!(
-                    "Error while executing checkpoint, will retry in 1s: {:?}",
-                    err
-                );
-                tokio::time::sleep(Duration::from_secs(1)).await;
-                metrics.checkpoint_exec_errors.inc();
+            loop {
+                match execute_checkpoint(
+                    checkpoint.clone(),
+                    authority_store.clone(),
+                    checkpoint_store.clone(),
+                    epoch_store.clone(),
+                    tx_manager.clone(),
+                    accumulator.clone(),
+                    local_execution_timeout_sec,
+                    &metrics,
+                    data_ingestion_dir.clone(),
+                )
+                .await
+                {
+                    Ok(commit_latency) => return (checkpoint, commit_latency),
+                    Err(err) => {
+                        error!(
+                            "Error while executing checkpoint, will retry in 1s: {:?}",
+                            err
+                        );
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        metrics.checkpoint_exec_errors.inc();
+                    }
+                }
             }
-            checkpoint
         }));
     }
 
@@ -472,7 +510,7 @@ impl CheckpointExecutor {
     /// perform special case logic (execute change_epoch tx, accumulate epoch,
     /// finalize transactions), then return true.
     pub async fn check_epoch_last_checkpoint(
-        &self,
+        &mut self,
         epoch_store: Arc<AuthorityPerEpochStore>,
         checkpoint: &Option<VerifiedCheckpoint>,
     ) -> bool {
@@ -496,6 +534,10 @@ impl CheckpointExecutor {
                         "Reached end of epoch, executing change_epoch transaction",
                     );
 
+                    // The rest of the checkpoint was executed without being committed, so its
+                    // commit latency is measured here, from the change_epoch tx execution to
+                    // the finalization of the checkpoint.
+                    let commit_start = Instant::now();
                     self.execute_change_epoch_tx(
                         change_epoch_execution_digests,
                         change_epoch_tx_digest,
@@ -538,6 +580,7 @@ impl CheckpointExecutor {
                         self.config.data_ingestion_dir.clone(),
                     )
                     .expect("Finalizing checkpoint cannot fail");
+                    self.record_commit_latency(commit_start.elapsed());
 
                     self.accumulator
                         .accumulate_epoch(
@@ -570,7 +613,7 @@ async fn execute_checkpoint(
     local_execution_timeout_sec: u64,
     metrics: &Arc<CheckpointExecutorMetrics>,
     data_ingestion_dir: Option<PathBuf>,
-) -> SuiResult {
+) -> SuiResult<Option<Duration>> {
     debug!("Preparing checkpoint for execution",);
     let prepare_start = Instant::now();
 
@@ -606,8 +649,7 @@ async fn execute_checkpoint(
         prepare_start,
         data_ingestion_dir,
     )
-    .await?;
-    Ok(())
+    .await
 }
 
 #[instrument(level = "error", skip_all, fields(seq = ?checkpoint.sequence_number(), epoch = ?epoch_store.epoch()))]
@@ -974,7 +1016,7 @@ async fn execute_transactions(
     metrics: &Arc<CheckpointExecutorMetrics>,
     prepare_start: Instant,
     data_ingestion_dir: Option<PathBuf>,
-) -> SuiResult {
+) -> SuiResult<Duration> {
     let effects_digests: HashMap<_, _> = execution_digests
         .iter()
         .map(|digest| (digest.transaction, digest.effects))
@@ -1057,7 +1099,13 @@ async fn execute_transactions(
         info!("Checkpoint execution took {:?}", exec_elapsed);
     }
 
-    Ok(())
+    // The commit of the effects and objects written by the execution is included, as the
+    // execution of each transaction commits them. An end of epoch checkpoint is only committed
+    // after its change_epoch tx, see check_epoch_last_checkpoint.
+    if checkpoint.end_of_epoch_data.is_some() {
+        return Ok(None);
+    }
+    Ok(Some(exec_elapsed))
 }
 
 #[instrument(level = "debug", skip_all)]
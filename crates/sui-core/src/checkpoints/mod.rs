@@ -776,7 +776,7 @@ impl CheckpointBuilder {
         info!("Shutting down CheckpointBuilder");
     }
 
-    #[instrument(level = "debug", skip_all)]
+    #[instrument(level = "debug", skip_all, fields(height = height, epoch = self.epoch_store.epoch()))]
     async fn make_checkpoint(
         &self,
         height: CheckpointCommitHeight,
@@ -801,7 +801,7 @@ impl CheckpointBuilder {
         Ok(())
     }
 
-    #[instrument(level = "debug", skip_all)]
+    #[instrument(level = "debug", skip_all, fields(height = height, epoch = self.epoch_store.epoch()))]
     async fn write_checkpoints(
         &self,
         height: CheckpointCommitHeight,
@@ -837,6 +837,11 @@ impl CheckpointBuilder {
                 [(sequence_number, summary)],
             )?;
         }
+
+        // Allow testing what happens if we crash after computing the checkpoints but before
+        // storing them, so they are computed again on restart.
+        fail_point!("checkpoint-computed-before-stored");
+
         batch.write()?;
 
         for (local_checkpoint, _) in &new_checkpoint {
@@ -1094,7 +1099,7 @@ impl CheckpointBuilder {
                 .unwrap_or(num_txns);
 
             let previous_digest = last_checkpoint.as_ref().map(|(_, c)| c.digest());
-            let summary = CheckpointSummary::new(
+            let mut summary = CheckpointSummary::new(
                 epoch,
                 sequence_number,
                 network_total_transactions,
@@ -1104,6 +1109,15 @@ impl CheckpointBuilder {
                 end_of_epoch_data,
                 timestamp_ms,
             );
+            if self
+                .epoch_store
+                .protocol_config()
+                .checkpoint_contents_merkle_root()
+            {
+                summary
+                    .checkpoint_commitments
+                    .push(contents.merkle_root().into());
+            }
             summary.report_checkpoint_age_ms(&self.metrics.last_created_checkpoint_age_ms);
             if last_checkpoint_of_epoch {
                 info!(
@@ -1145,7 +1159,7 @@ impl CheckpointBuilder {
         }
     }
 
-    #[instrument(level = "error", skip_all)]
+    #[instrument(level = "error", skip_all, fields(seq = checkpoint, epoch = self.epoch_store.epoch()))]
     async fn augment_epoch_last_checkpoint(
         &self,
         epoch_total_gas_cost: &GasCostSummary,
@@ -1376,6 +1390,9 @@ impl CheckpointAggregator {
                     );
 
                     self.tables.insert_certified_checkpoint(&summary)?;
+                    // Allow testing what happens if we crash after storing the certified
+                    // checkpoint but before sending it out.
+                    fail_point!("checkpoint-certified-before-output");
                     self.metrics
                         .last_certified_checkpoint
                         .set(current.summary.sequence_number as i64);
@@ -1536,9 +1553,12 @@ async fn diagnose_split_brain(
         .epoch_start_state()
         .get_sui_committee_with_network_metadata();
     let network_config = default_mysten_network_config();
-    let network_clients =
-        make_network_authority_clients_with_network_config(&committee, &network_config)
-            .expect("Failed to make authority clients from committee {committee}");
+    let network_clients = make_network_authority_clients_with_network_config(
+        &committee,
+        &network_config,
+        state.validator_client_tls_config(),
+    )
+    .expect("Failed to make authority clients from committee {committee}");
 
     // Query all disagreeing validators
     let response_futures = digest_to_validator
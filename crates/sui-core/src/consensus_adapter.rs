@@ -14,14 +14,15 @@ use narwhal_worker::LazyNarwhalClient;
 use parking_lot::{Mutex, RwLockReadGuard};
 use prometheus::Histogram;
 use prometheus::HistogramVec;
+use prometheus::IntCounter;
 use prometheus::IntCounterVec;
 use prometheus::IntGauge;
 use prometheus::IntGaugeVec;
 use prometheus::Registry;
 use prometheus::{
     register_histogram_vec_with_registry, register_histogram_with_registry,
-    register_int_counter_vec_with_registry, register_int_gauge_vec_with_registry,
-    register_int_gauge_with_registry,
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_vec_with_registry, register_int_gauge_with_registry,
 };
 use rand::rngs::StdRng;
 use rand::SeedableRng;
@@ -67,6 +68,9 @@ const SEQUENCING_CERTIFICATE_LATENCY_SEC_BUCKETS: &[f64] = &[
 
 const SEQUENCING_CERTIFICATE_POSITION_BUCKETS: &[f64] = &[0., 1., 2., 3., 5., 10.];
 
+const MAX_LATENCY: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_LATENCY: Duration = Duration::from_secs(3); // > p50 consensus latency with global deployment
+
 pub struct ConsensusAdapterMetrics {
     // Certificate sequencing metrics
     pub sequencing_certificate_attempt: IntCounterVec,
@@ -82,6 +86,7 @@ pub struct ConsensusAdapterMetrics {
     pub sequencing_in_flight_submissions: IntGauge,
     pub sequencing_estimated_latency: IntGauge,
     pub sequencing_resubmission_interval_ms: IntGauge,
+    pub sequencing_certificate_peer_batched: IntCounter,
 }
 
 impl ConsensusAdapterMetrics {
@@ -170,6 +175,12 @@ impl ConsensusAdapterMetrics {
                 registry,
             )
                 .unwrap(),
+            sequencing_certificate_peer_batched: register_int_counter_with_registry!(
+                "sequencing_certificate_peer_batched",
+                "Counts the certificates whose submission was postponed because another validator already included them in a batch.",
+                registry,
+            )
+                .unwrap(),
         }
     }
 
@@ -398,8 +409,6 @@ impl ConsensusAdapter {
         let (position, positions_moved, preceding_disconnected) =
             self.submission_position(committee, tx_digest);
 
-        const MAX_LATENCY: Duration = Duration::from_secs(5 * 60);
-        const DEFAULT_LATENCY: Duration = Duration::from_secs(3); // > p50 consensus latency with global deployment
         let latency = self.latency_observer.latency().unwrap_or(DEFAULT_LATENCY);
         self.metrics
             .sequencing_estimated_latency
@@ -424,6 +433,30 @@ impl ConsensusAdapter {
         )
     }
 
+    /// Returns the additional delay before submitting a certificate which another validator
+    /// already included in one of its batches, so that the batch has time to be sequenced. The
+    /// certificate is still submitted afterwards, in case the batch is never sequenced.
+    fn await_peer_batched_delay(
+        &self,
+        transaction: &ConsensusTransaction,
+        epoch_store: &AuthorityPerEpochStore,
+    ) -> Option<impl Future<Output = ()>> {
+        let ConsensusTransactionKind::UserTransaction(certificate) = &transaction.kind else {
+            return None;
+        };
+        // The epoch store is only unreadable past the end of the epoch, submit without delay.
+        if !epoch_store
+            .is_peer_batched_certificate(certificate.digest())
+            .unwrap_or(false)
+        {
+            return None;
+        }
+        self.metrics.sequencing_certificate_peer_batched.inc();
+        let latency = self.latency_observer.latency().unwrap_or(DEFAULT_LATENCY);
+        let latency = latency.clamp(DEFAULT_LATENCY, MAX_LATENCY);
+        Some(tokio::time::sleep(latency * 2))
+    }
+
     // According to the throughput profile we want to either allow some transaction duplication or not)
     // When throughput profile is Low and the validator is in position = 1, then it will submit to consensus with much lower latency.
     // When throughput profile is High then we go back to default operation and no-one co-submits.
@@ -676,6 +709,24 @@ impl ConsensusAdapter {
             }
         };
 
+        // Another validator may have included the transaction in a batch while we waited, in which
+        // case we give its batch some more time to be sequenced instead of sequencing a duplicate.
+        let processed_waiter = match processed_waiter {
+            Some(mut processed_waiter) => {
+                match self.await_peer_batched_delay(&transaction, epoch_store) {
+                    Some(await_peer_batched) => tokio::select! {
+                        _ = await_peer_batched => Some(processed_waiter),
+                        processed = &mut processed_waiter => {
+                            processed.expect("Storage error when waiting for consensus message processed");
+                            None
+                        }
+                    },
+                    None => Some(processed_waiter),
+                }
+            }
+            None => None,
+        };
+
         let transaction_key = transaction.key();
         // Log warnings for capability or end of publish transactions that fail to get sequenced
         let _monitor = if matches!(
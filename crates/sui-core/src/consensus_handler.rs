@@ -253,14 +253,24 @@ impl<T: ObjectStore + Send + Sync, C: CheckpointServiceNotify + Send + Sync>
             .epoch_store
             .epoch_start_config()
             .epoch_start_timestamp_ms();
-        let timestamp = if timestamp < epoch_start {
-            error!(
-                "Unexpected commit timestamp {timestamp} less then epoch start time {epoch_start}, author {leader_author}, round {round}",
-            );
-            epoch_start
+        let last_commit_timestamp = if self
+            .epoch_store
+            .protocol_config()
+            .enforce_checkpoint_timestamp_monotonicity()
+        {
+            self.epoch_store
+                .last_pending_checkpoint_timestamp_ms()
+                .expect("Unrecoverable error in consensus handler")
         } else {
-            timestamp
+            None
         };
+        let timestamp = commit_timestamp_ms(
+            timestamp,
+            epoch_start,
+            last_commit_timestamp,
+            leader_author,
+            round,
+        );
 
         info!(
             "Received consensus output {} at epoch {}",
@@ -790,6 +800,35 @@ impl SequencedConsensusTransaction {
     }
 }
 
+/// Returns the timestamp of a consensus commit, which the commit prologue writes to the Clock and
+/// which the checkpoints built from the commit carry. It is never earlier than the start of the
+/// epoch, nor than `last_commit_timestamp`, the timestamp of the previous commit, when set.
+fn commit_timestamp_ms(
+    timestamp: u64,
+    epoch_start: u64,
+    last_commit_timestamp: Option<u64>,
+    leader_author: AuthorityIndex,
+    round: u64,
+) -> u64 {
+    let timestamp = if timestamp < epoch_start {
+        error!(
+            "Unexpected commit timestamp {timestamp} less then epoch start time {epoch_start}, author {leader_author}, round {round}",
+        );
+        epoch_start
+    } else {
+        timestamp
+    };
+    match last_commit_timestamp {
+        Some(last) if timestamp < last => {
+            error!(
+                "Unexpected commit timestamp {timestamp} less then previous commit timestamp {last}, author {leader_author}, round {round}",
+            );
+            last
+        }
+        _ => timestamp,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerifiedSequencedConsensusTransaction(pub SequencedConsensusTransaction);
 
@@ -961,6 +1000,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_commit_timestamp_ms() {
+        // Commits are never earlier than the start of the epoch.
+        assert_eq!(commit_timestamp_ms(90, 100, None, 0, 1), 100);
+        assert_eq!(commit_timestamp_ms(110, 100, None, 0, 1), 110);
+        // With monotonicity enforced, nor than the previous commit.
+        assert_eq!(commit_timestamp_ms(110, 100, Some(120), 0, 2), 120);
+        assert_eq!(commit_timestamp_ms(90, 100, Some(120), 0, 2), 120);
+        assert_eq!(commit_timestamp_ms(130, 100, Some(120), 0, 2), 130);
+    }
+
     #[test]
     pub fn test_update_index_and_hash() {
         let index0 = ExecutionIndices {
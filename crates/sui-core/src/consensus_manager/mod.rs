@@ -10,6 +10,7 @@ use async_trait::async_trait;
 use enum_dispatch::enum_dispatch;
 use fastcrypto::traits::KeyPair;
 use mysten_metrics::RegistryService;
+pub use narwhal_node::primary_node::{AuthorityFrontier, ConsensusStatus};
 use prometheus::{register_int_gauge_with_registry, IntGauge, Registry};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -17,6 +18,7 @@ use std::time::Instant;
 use sui_config::{ConsensusConfig, NodeConfig};
 use sui_protocol_config::ProtocolVersion;
 use sui_types::committee::EpochId;
+use sui_types::error::SuiResult;
 use tokio::sync::{Mutex, MutexGuard};
 
 pub mod mysticeti_manager;
@@ -91,6 +93,26 @@ impl ConsensusManager {
             client,
         ))
     }
+
+    /// Returns a snapshot of the Narwhal DAG. Mysticeti does not report its status yet.
+    pub async fn consensus_status(&self) -> SuiResult<Option<ConsensusStatus>> {
+        match self {
+            Self::Narwhal(manager) => manager.consensus_status().await,
+            Self::Mysticeti(_) => Ok(None),
+        }
+    }
+
+    /// Waits until the output committed by consensus so far has been processed, before consensus
+    /// is shut down at the end of the epoch. Returns whether all of it was processed in time.
+    pub async fn wait_for_output_processed(
+        &self,
+        epoch_store: &AuthorityPerEpochStore,
+    ) -> SuiResult<bool> {
+        match self {
+            Self::Narwhal(manager) => manager.wait_for_output_processed(epoch_store).await,
+            Self::Mysticeti(_) => Ok(true),
+        }
+    }
 }
 
 pub struct ConsensusManagerMetrics {
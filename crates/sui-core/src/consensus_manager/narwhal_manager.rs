@@ -11,14 +11,16 @@ use fastcrypto::traits::KeyPair;
 use mysten_metrics::RegistryService;
 use narwhal_config::{Parameters, WorkerId};
 use narwhal_network::client::NetworkClient;
-use narwhal_node::primary_node::PrimaryNode;
+use narwhal_node::primary_node::{ConsensusStatus, PrimaryNode};
 use narwhal_node::worker_node::WorkerNodes;
 use narwhal_node::{CertificateStoreCacheMetrics, NodeStorage};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use sui_config::NodeConfig;
 use sui_types::committee::EpochId;
 use sui_types::crypto::{AuthorityKeyPair, NetworkKeyPair};
+use sui_types::error::SuiResult;
 use sui_types::sui_system_state::epoch_start_sui_system_state::EpochStartSystemStateTrait;
 use tokio::sync::Mutex;
 
@@ -26,6 +28,9 @@ use tokio::sync::Mutex;
 #[path = "../unit_tests/narwhal_manager_tests.rs"]
 pub mod narwhal_manager_tests;
 
+/// Maximum time to wait for the consensus output to be processed before shutting down Narwhal.
+const OUTPUT_PROCESSED_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub struct NarwhalConfiguration {
     pub primary_keypair: AuthorityKeyPair,
     pub network_keypair: NetworkKeyPair,
@@ -74,6 +79,47 @@ impl NarwhalManager {
         }
     }
 
+    /// Returns a snapshot of the DAG of the primary, or None if Narwhal is not running.
+    pub async fn consensus_status(&self) -> SuiResult<Option<ConsensusStatus>> {
+        Ok(self.primary_node.consensus_status().await?)
+    }
+
+    /// Waits until the consensus handler has processed every sub dag committed so far, so that
+    /// the last commits of the epoch are persisted before Narwhal is shut down. Gives up after
+    /// `OUTPUT_PROCESSED_TIMEOUT`, and returns whether all the committed sub dags were processed.
+    pub async fn wait_for_output_processed(
+        &self,
+        epoch_store: &AuthorityPerEpochStore,
+    ) -> SuiResult<bool> {
+        let Some((last_committed, _)) = self
+            .consensus_status()
+            .await?
+            .and_then(|status| status.latest_sub_dag)
+        else {
+            return Ok(true);
+        };
+        let deadline = Instant::now() + OUTPUT_PROCESSED_TIMEOUT;
+        loop {
+            let last_processed = epoch_store.get_last_consensus_stats()?.index.sub_dag_index;
+            if last_processed >= last_committed {
+                tracing::info!(
+                    "Consensus output of epoch {} is processed up to sub dag {last_processed}",
+                    epoch_store.epoch()
+                );
+                return Ok(true);
+            }
+            if Instant::now() >= deadline {
+                tracing::warn!(
+                    "Consensus output of epoch {} is processed up to sub dag {last_processed}, \
+                    but sub dag {last_committed} is committed",
+                    epoch_store.epoch()
+                );
+                return Ok(false);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
     fn get_store_path(&self, epoch: EpochId) -> PathBuf {
         let mut store_path = self.storage_base_path.clone();
         store_path.push(format!("{}", epoch));
@@ -126,6 +172,14 @@ impl ConsensusManagerTrait for NarwhalManager {
         let store_path = self.get_store_path(epoch);
         let store = NodeStorage::reopen(store_path, Some(self.store_cache_metrics.clone()));
 
+        // The store already has commits when the node restarts in the middle of the epoch.
+        let last_committed = store.consensus_store.get_latest_sub_dag_index();
+        if last_committed > 0 {
+            tracing::info!(
+                "Resuming Narwhal for epoch {epoch} after committed sub dag {last_committed}"
+            );
+        }
+
         // Create a new client.
         let network_client = NetworkClient::new_from_keypair(&self.network_keypair);
 
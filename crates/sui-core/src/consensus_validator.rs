@@ -79,6 +79,10 @@ impl SuiTxValidator {
         // verify the certificate signatures as a batch
         let cert_count = cert_batch.len();
         let ckpt_count = ckpt_batch.len();
+        let cert_digests = cert_batch
+            .iter()
+            .map(|certificate| *certificate.digest())
+            .collect::<Vec<_>>();
 
         self.epoch_store
             .signature_verifier
@@ -86,6 +90,15 @@ impl SuiTxValidator {
             .tap_err(|e| warn!("batch verification error: {}", e))
             .wrap_err("Malformed batch (failed to verify)")?;
 
+        // The batch comes from another validator, so our own submissions of its certificates
+        // can be postponed until it is sequenced.
+        if let Err(e) = self
+            .epoch_store
+            .record_peer_batched_certificates(cert_digests)
+        {
+            warn!("Failed to record certificates batched by another validator: {e}");
+        }
+
         // All checkpoint sigs have been verified, forward them to the checkpoint service
         for ckpt in ckpt_messages {
             self.checkpoint_service
@@ -249,11 +262,25 @@ mod tests {
             })
             .collect();
 
+        let epoch_store = state.epoch_store_for_testing();
+        assert!(certificates.iter().all(|cert| !epoch_store
+            .is_peer_batched_certificate(cert.digest())
+            .unwrap()));
+
         let batch = Batch::new(transaction_bytes, latest_protocol_config);
         let res_batch = validator
             .validate_batch(&batch, latest_protocol_config)
             .await;
         assert!(res_batch.is_ok(), "{res_batch:?}");
+        // the certificates of a valid batch are recorded as batched by another validator
+        assert!(certificates.iter().all(|cert| epoch_store
+            .is_peer_batched_certificate(cert.digest())
+            .unwrap()));
+        // and are still known once the in-memory cache is lost, e.g. after a restart
+        epoch_store.clear_peer_batched_certificates_cache_for_test();
+        assert!(certificates.iter().all(|cert| epoch_store
+            .is_peer_batched_certificate(cert.digest())
+            .unwrap()));
 
         let bogus_transaction_bytes: Vec<_> = certificates
             .into_iter()
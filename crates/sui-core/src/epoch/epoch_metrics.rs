@@ -76,6 +76,10 @@ pub struct EpochMetrics {
 
     /// Buffer stake current in effect for this epoch
     pub effective_buffer_stake: IntGauge,
+
+    /// Whether the execution config of this binary differs from the one stored when the epoch
+    /// started, e.g. after a restart with a binary that changed the current protocol version.
+    pub epoch_execution_config_mismatch: IntGauge,
 }
 
 impl EpochMetrics {
@@ -158,6 +162,11 @@ impl EpochMetrics {
                 "Buffer stake current in effect for this epoch",
                 registry,
             ).unwrap(),
+            epoch_execution_config_mismatch: register_int_gauge_with_registry!(
+                "epoch_execution_config_mismatch",
+                "Whether the execution config of this binary differs from the one the epoch started with",
+                registry,
+            ).unwrap(),
         };
         Arc::new(this)
     }
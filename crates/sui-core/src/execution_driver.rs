@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, VecDeque},
     sync::{Arc, Weak},
     time::Duration,
 };
@@ -10,10 +12,11 @@ use mysten_metrics::{monitored_scope, spawn_monitored_task};
 use sui_macros::fail_point_async;
 use sui_types::{
     digests::TransactionEffectsDigest, executable_transaction::VerifiedExecutableTransaction,
+    transaction::TransactionDataAPI,
 };
 use tokio::{
     sync::{mpsc::UnboundedReceiver, oneshot, Semaphore},
-    time::sleep,
+    time::{sleep, Instant},
 };
 use tracing::{error, error_span, info, trace, Instrument};
 
@@ -28,6 +31,64 @@ mod execution_driver_tests;
 pub const EXECUTION_MAX_ATTEMPTS: u32 = 10;
 const EXECUTION_FAILURE_RETRY_INTERVAL: Duration = Duration::from_secs(1);
 
+// Certificates waiting for execution longer than this are executed before those with a
+// higher gas price, so that low gas price certificates are not starved under load.
+const MAX_PRIORITIZATION_DELAY: Duration = Duration::from_secs(5);
+
+/// Certificates that are ready for execution, waiting for an execution slot. They are executed
+/// in order of gas price, then age, except that certificates waiting longer than
+/// `MAX_PRIORITIZATION_DELAY` are executed first, in order of age.
+struct ExecutionQueue<T> {
+    by_priority: BTreeMap<(Reverse<u64>, u64), T>,
+    by_age: VecDeque<(Instant, Reverse<u64>, u64)>,
+    next_seq: u64,
+}
+
+impl<T> ExecutionQueue<T> {
+    fn new() -> Self {
+        Self {
+            by_priority: BTreeMap::new(),
+            by_age: VecDeque::new(),
+            next_seq: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.by_priority.is_empty()
+    }
+
+    fn push(&mut self, item: T, gas_price: u64, now: Instant) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.by_priority.insert((Reverse(gas_price), seq), item);
+        self.by_age.push_back((now, Reverse(gas_price), seq));
+    }
+
+    fn pop(&mut self, now: Instant) -> Option<T> {
+        // Entries of certificates already executed by priority are removed lazily.
+        while let Some((enqueued_at, gas_price, seq)) = self.by_age.front().copied() {
+            if !self.by_priority.contains_key(&(gas_price, seq)) {
+                self.by_age.pop_front();
+                continue;
+            }
+            if now.duration_since(enqueued_at) >= MAX_PRIORITIZATION_DELAY {
+                self.by_age.pop_front();
+                return self.by_priority.remove(&(gas_price, seq));
+            }
+            break;
+        }
+        self.by_priority.pop_first().map(|(_, item)| item)
+    }
+}
+
+fn execution_priority(certificate: &VerifiedExecutableTransaction) -> u64 {
+    if certificate.is_system_tx() {
+        u64::MAX
+    } else {
+        certificate.data().transaction_data().gas_price()
+    }
+}
+
 /// When a notification that a new pending transaction is received we activate
 /// processing the transaction in a loop.
 pub async fn execution_process(
@@ -43,30 +104,50 @@ pub async fn execution_process(
     // Rate limit concurrent executions to # of cpus.
     let limit = Arc::new(Semaphore::new(num_cpus::get()));
 
+    let mut queue = ExecutionQueue::new();
+
     // Loop whenever there is a signal that a new transactions is ready to process.
     loop {
         let _scope = monitored_scope("ExecutionDriver::loop");
 
-        let certificate;
-        let expected_effects_digest;
-        tokio::select! {
-            result = rx_ready_certificates.recv() => {
-                if let Some((cert, fx_digest)) = result {
-                    certificate = cert;
-                    expected_effects_digest = fx_digest;
-                } else {
-                    // Should only happen after the AuthorityState has shut down and tx_ready_certificate
-                    // has been dropped by TransactionManager.
-                    info!("No more certificate will be received. Exiting executor ...");
-                    return;
-                };
-            }
+        // Wait for an execution slot before picking the next certificate, so that ready
+        // certificates accumulate in the queue and are prioritized when execution is saturated.
+        // Hold semaphore permit until task completes. unwrap ok because we never close
+        // the semaphore in this context.
+        let permit = tokio::select! {
+            permit = limit.clone().acquire_owned() => permit.unwrap(),
             _ = &mut rx_execution_shutdown => {
                 info!("Shutdown signal received. Exiting executor ...");
                 return;
             }
         };
 
+        while let Ok((cert, fx_digest)) = rx_ready_certificates.try_recv() {
+            let gas_price = execution_priority(&cert);
+            queue.push((cert, fx_digest), gas_price, Instant::now());
+        }
+        if queue.is_empty() {
+            tokio::select! {
+                result = rx_ready_certificates.recv() => {
+                    if let Some((cert, fx_digest)) = result {
+                        let gas_price = execution_priority(&cert);
+                        queue.push((cert, fx_digest), gas_price, Instant::now());
+                    } else {
+                        // Should only happen after the AuthorityState has shut down and tx_ready_certificate
+                        // has been dropped by TransactionManager.
+                        info!("No more certificate will be received. Exiting executor ...");
+                        return;
+                    };
+                }
+                _ = &mut rx_execution_shutdown => {
+                    info!("Shutdown signal received. Exiting executor ...");
+                    return;
+                }
+            };
+        }
+        let (certificate, expected_effects_digest) =
+            queue.pop(Instant::now()).expect("queue must not be empty");
+
         let authority = if let Some(authority) = authority_state.upgrade() {
             authority
         } else {
@@ -81,13 +162,9 @@ pub async fn execution_process(
         let epoch_store = authority.load_epoch_store_one_call_per_task();
 
         let digest = *certificate.digest();
+        let epoch = epoch_store.epoch();
         trace!(?digest, "Pending certificate execution activated.");
 
-        let limit = limit.clone();
-        // hold semaphore permit until task completes. unwrap ok because we never close
-        // the semaphore in this context.
-        let permit = limit.acquire_owned().await.unwrap();
-
         // Certificate execution can take significant time, so run it in a separate task.
         spawn_monitored_task!(async move {
             let _scope = monitored_scope("ExecutionDriver::task");
@@ -118,6 +195,6 @@ pub async fn execution_process(
                 .metrics
                 .execution_driver_executed_transactions
                 .inc();
-        }.instrument(error_span!("execution_driver", tx_digest = ?digest)));
+        }.instrument(error_span!("execution_driver", tx_digest = ?digest, epoch)));
     }
 }
@@ -24,6 +24,7 @@ pub struct QuorumDriverMetrics {
     pub(crate) total_attempts_retrying_conflicting_transaction: IntCounter,
     pub(crate) total_successful_attempts_retrying_conflicting_transaction: IntCounter,
     pub(crate) total_times_conflicting_transaction_already_finalized_when_retrying: IntCounter,
+    pub(crate) total_retries_delayed_by_overload: IntCounter,
 }
 
 impl QuorumDriverMetrics {
@@ -89,6 +90,12 @@ impl QuorumDriverMetrics {
                 registry,
             )
             .unwrap(),
+            total_retries_delayed_by_overload: register_int_counter_with_registry!(
+                "quorum_driver_total_retries_delayed_by_overload",
+                "Total number of retries delayed until overloaded validators no longer prevent a quorum",
+                registry,
+            )
+            .unwrap(),
         }
     }
 
@@ -122,7 +122,9 @@ impl<A: Clone> QuorumDriver<A> {
 
     /// Enqueue the task again if it hasn't maxed out the total retry attempts.
     /// If it has, notify failure.
-    /// Enqueuing happens only after the `next_retry_after`, if not, wait until that instant
+    /// Enqueuing happens only after the `next_retry_after`, if not, wait until that instant.
+    /// The retry is delayed further until overloaded validators that asked to be left alone
+    /// no longer prevent a quorum.
     async fn enqueue_again_maybe(
         &self,
         transaction: Transaction,
@@ -145,6 +147,14 @@ impl<A: Clone> QuorumDriver<A> {
         }
         let next_retry_after =
             Instant::now() + Duration::from_millis(200 * u64::pow(2, old_retry_times.into()));
+        let next_retry_after = match self.validators.load().overload_backoff_deadline() {
+            Some(deadline) if deadline > next_retry_after => {
+                debug!(tx_digest=?transaction.digest(), "Backing off from overloaded validators until {:?}", deadline);
+                self.metrics.total_retries_delayed_by_overload.inc();
+                deadline
+            }
+            _ => next_retry_after,
+        };
         sleep_until(next_retry_after).await;
 
         let tx_cert = match tx_cert {
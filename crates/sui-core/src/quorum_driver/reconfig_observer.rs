@@ -10,7 +10,7 @@ use tracing::{info, warn};
 use crate::{
     authority::AuthorityStore,
     authority_aggregator::{AuthAggMetrics, AuthorityAggregator},
-    authority_client::{AuthorityAPI, NetworkAuthorityClient},
+    authority_client::{AuthorityAPI, NetworkAuthorityClient, ValidatorClientTlsConfig},
     epoch::committee_store::CommitteeStore,
     safe_client::SafeClientMetricsBase,
 };
@@ -31,6 +31,7 @@ pub struct OnsiteReconfigObserver {
     committee_store: Arc<CommitteeStore>,
     safe_client_metrics_base: SafeClientMetricsBase,
     auth_agg_metrics: AuthAggMetrics,
+    tls_config: Option<ValidatorClientTlsConfig>,
 }
 
 impl OnsiteReconfigObserver {
@@ -40,6 +41,7 @@ impl OnsiteReconfigObserver {
         committee_store: Arc<CommitteeStore>,
         safe_client_metrics_base: SafeClientMetricsBase,
         auth_agg_metrics: AuthAggMetrics,
+        tls_config: Option<ValidatorClientTlsConfig>,
     ) -> Self {
         Self {
             reconfig_rx,
@@ -47,6 +49,7 @@ impl OnsiteReconfigObserver {
             committee_store,
             safe_client_metrics_base,
             auth_agg_metrics,
+            tls_config,
         }
     }
 
@@ -58,6 +61,7 @@ impl OnsiteReconfigObserver {
             &self.committee_store,
             self.safe_client_metrics_base.clone(),
             self.auth_agg_metrics.clone(),
+            self.tls_config.as_ref(),
         )
         .unwrap_or_else(|e| {
             panic!(
@@ -77,6 +81,7 @@ impl ReconfigObserver<NetworkAuthorityClient> for OnsiteReconfigObserver {
             committee_store: self.committee_store.clone(),
             safe_client_metrics_base: self.safe_client_metrics_base.clone(),
             auth_agg_metrics: self.auth_agg_metrics.clone(),
+            tls_config: self.tls_config.clone(),
         })
     }
 
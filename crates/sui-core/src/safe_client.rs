@@ -12,12 +12,16 @@ use sui_types::crypto::AuthorityPublicKeyBytes;
 use sui_types::effects::{SignedTransactionEffects, TransactionEffectsAPI};
 use sui_types::messages_checkpoint::{
     CertifiedCheckpointSummary, CheckpointRequest, CheckpointResponse, CheckpointSequenceNumber,
+    ECMHLiveObjectSetDigest,
 };
 use sui_types::messages_grpc::{
-    HandleCertificateResponseV2, ObjectInfoRequest, ObjectInfoResponse, SystemStateRequest,
+    ConflictingTransactions, ConflictingTransactionsRequest, EpochStateCommitmentRequest,
+    HandleCertificateResponseV2, InclusionGasPriceRequest, InclusionGasPriceResponse,
+    ObjectInfoRequest, ObjectInfoResponse, ObjectOwnershipProofRequest, SystemStateRequest,
     TransactionInfoRequest, TransactionStatus, VerifiedObjectInfoResponse,
 };
 use sui_types::messages_safe_client::PlainTransactionInfoResponse;
+use sui_types::object_proof::ObjectOwnershipProof;
 use sui_types::sui_system_state::SuiSystemState;
 use sui_types::{base_types::*, committee::*, fp_ensure};
 use sui_types::{
@@ -484,4 +488,115 @@ where
             .handle_system_state_object(SystemStateRequest { _unused: false })
             .await
     }
+
+    pub async fn handle_conflicting_transactions_request(
+        &self,
+        object_id: ObjectID,
+    ) -> Result<Vec<ConflictingTransactions>, SuiError> {
+        let response = self
+            .authority_client
+            .handle_conflicting_transactions_request(ConflictingTransactionsRequest { object_id })
+            .await?;
+        for conflict in &response.conflicts {
+            fp_ensure!(
+                conflict.obj_ref.0 == object_id && conflict.locked_by_tx != conflict.conflicting_tx,
+                SuiError::ByzantineAuthoritySuspicion {
+                    authority: self.address,
+                    reason: format!("Unexpected conflicting transactions: {:?}", conflict),
+                }
+            );
+        }
+        Ok(response.conflicts)
+    }
+
+    pub async fn handle_inclusion_gas_price_request(
+        &self,
+    ) -> Result<InclusionGasPriceResponse, SuiError> {
+        let response = self
+            .authority_client
+            .handle_inclusion_gas_price_request(InclusionGasPriceRequest { _unused: false })
+            .await?;
+        fp_ensure!(
+            response.inclusion_gas_price >= response.reference_gas_price,
+            SuiError::ByzantineAuthoritySuspicion {
+                authority: self.address,
+                reason: format!(
+                    "Inclusion gas price below the reference gas price: {:?}",
+                    response
+                ),
+            }
+        );
+        Ok(response)
+    }
+
+    /// Get a proof of the latest state of an object, verified against the committee of the
+    /// epoch of its checkpoint.
+    pub async fn handle_object_ownership_proof_request(
+        &self,
+        object_id: ObjectID,
+    ) -> Result<Option<ObjectOwnershipProof>, SuiError> {
+        let response = self
+            .authority_client
+            .handle_object_ownership_proof_request(ObjectOwnershipProofRequest { object_id })
+            .await?;
+        let Some(proof) = response.proof else {
+            return Ok(None);
+        };
+        let committee = self.get_committee(&proof.checkpoint.epoch)?;
+        let object_ref = proof
+            .verify(&committee)
+            .tap_err(|err| {
+                error!(?err, authority=?self.address, "Client error in handle_object_ownership_proof_request");
+            })?;
+        fp_ensure!(
+            object_ref.0 == object_id,
+            SuiError::ByzantineAuthoritySuspicion {
+                authority: self.address,
+                reason: format!("Unexpected object in ownership proof: {:?}", object_ref),
+            }
+        );
+        Ok(Some(proof))
+    }
+
+    /// Get the commitment of the committee to the live object set at the end of the epoch. Fails
+    /// if the live object set accumulated by the authority differs from the committed one.
+    pub async fn handle_epoch_state_commitment_request(
+        &self,
+        epoch: EpochId,
+    ) -> Result<Option<ECMHLiveObjectSetDigest>, SuiError> {
+        let response = self
+            .authority_client
+            .handle_epoch_state_commitment_request(EpochStateCommitmentRequest { epoch })
+            .await?;
+        let Some(checkpoint) = response.checkpoint else {
+            return Ok(None);
+        };
+        fp_ensure!(
+            checkpoint.epoch == epoch && checkpoint.end_of_epoch_data.is_some(),
+            SuiError::ByzantineAuthoritySuspicion {
+                authority: self.address,
+                reason: format!(
+                    "Checkpoint {} is not the last checkpoint of epoch {}",
+                    checkpoint.sequence_number, epoch
+                ),
+            }
+        );
+        let committee = self.get_committee(&epoch)?;
+        // Epochs that predate `commit_root_state_digest` have no commitment to check against.
+        let committed = checkpoint.live_object_set_digest().cloned();
+        match (&committed, &response.root_state_digest) {
+            (Some(_), Some(root_state_digest)) => {
+                checkpoint.verify_live_object_set_digest(&committee, root_state_digest)
+            }
+            _ => checkpoint.verify_authority_signatures(&committee),
+        }
+        .map_err(|err| {
+            error!(?err, authority=?self.address, "Client error in handle_epoch_state_commitment_request");
+            SuiError::ByzantineAuthoritySuspicion {
+                authority: self.address,
+                reason: err.to_string(),
+            }
+        })?;
+        Ok(committed)
+    }
 }
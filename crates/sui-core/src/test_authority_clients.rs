@@ -3,19 +3,33 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    future::Future,
     sync::{Arc, Mutex},
     time::Duration,
 };
 
+use crate::authority::authority_per_epoch_store::AuthorityPerEpochStore;
 use crate::authority::test_authority_builder::TestAuthorityBuilder;
+use crate::consensus_adapter::SubmitToConsensus;
 use crate::{authority::AuthorityState, authority_client::AuthorityAPI};
 use async_trait::async_trait;
 use mysten_metrics::spawn_monitored_task;
+use shared_crypto::intent::{Intent, IntentScope};
 use sui_config::genesis::Genesis;
+use sui_types::base_types::AuthorityName;
+use sui_types::crypto::{get_key_pair, AuthoritySignInfo};
+use sui_types::effects::SignedTransactionEffects;
 use sui_types::error::SuiResult;
+use sui_types::messages_checkpoint::{CheckpointSignatureMessage, SignedCheckpointSummary};
+use sui_types::messages_consensus::{ConsensusTransaction, ConsensusTransactionKind};
 use sui_types::messages_grpc::{
-    HandleCertificateResponseV2, HandleTransactionResponse, ObjectInfoRequest, ObjectInfoResponse,
-    SystemStateRequest, TransactionInfoRequest, TransactionInfoResponse,
+    ConflictingTransactionsRequest, ConflictingTransactionsResponse, EpochStateCommitmentRequest,
+    EpochStateCommitmentResponse, HandleCertificateResponseV2, HandleTransactionResponse,
+    InclusionGasPriceRequest, InclusionGasPriceResponse, ObjectInfoRequest, ObjectInfoResponse,
+    ObjectOwnershipProofRequest, ObjectOwnershipProofResponse, SystemStateRequest,
+    TransactionInfoRequest, TransactionInfoResponse, TransactionStatus,
 };
 use sui_types::sui_system_state::SuiSystemState;
 use sui_types::{
@@ -125,6 +139,34 @@ impl AuthorityAPI for LocalAuthorityClient {
     ) -> Result<SuiSystemState, SuiError> {
         self.state.get_sui_system_state_object_for_testing()
     }
+
+    async fn handle_conflicting_transactions_request(
+        &self,
+        request: ConflictingTransactionsRequest,
+    ) -> Result<ConflictingTransactionsResponse, SuiError> {
+        self.state.handle_conflicting_transactions_request(request)
+    }
+
+    async fn handle_inclusion_gas_price_request(
+        &self,
+        request: InclusionGasPriceRequest,
+    ) -> Result<InclusionGasPriceResponse, SuiError> {
+        self.state.handle_inclusion_gas_price_request(request)
+    }
+
+    async fn handle_object_ownership_proof_request(
+        &self,
+        request: ObjectOwnershipProofRequest,
+    ) -> Result<ObjectOwnershipProofResponse, SuiError> {
+        self.state.handle_object_ownership_proof_request(request)
+    }
+
+    async fn handle_epoch_state_commitment_request(
+        &self,
+        request: EpochStateCommitmentRequest,
+    ) -> Result<EpochStateCommitmentResponse, SuiError> {
+        self.state.handle_epoch_state_commitment_request(request)
+    }
 }
 
 impl LocalAuthorityClient {
@@ -289,6 +331,34 @@ impl AuthorityAPI for MockAuthorityApi {
     ) -> Result<SuiSystemState, SuiError> {
         unimplemented!();
     }
+
+    async fn handle_conflicting_transactions_request(
+        &self,
+        _request: ConflictingTransactionsRequest,
+    ) -> Result<ConflictingTransactionsResponse, SuiError> {
+        unimplemented!();
+    }
+
+    async fn handle_inclusion_gas_price_request(
+        &self,
+        _request: InclusionGasPriceRequest,
+    ) -> Result<InclusionGasPriceResponse, SuiError> {
+        unimplemented!();
+    }
+
+    async fn handle_object_ownership_proof_request(
+        &self,
+        _request: ObjectOwnershipProofRequest,
+    ) -> Result<ObjectOwnershipProofResponse, SuiError> {
+        unimplemented!();
+    }
+
+    async fn handle_epoch_state_commitment_request(
+        &self,
+        _request: EpochStateCommitmentRequest,
+    ) -> Result<EpochStateCommitmentResponse, SuiError> {
+        unimplemented!();
+    }
 }
 
 #[derive(Clone)]
@@ -356,6 +426,34 @@ impl AuthorityAPI for HandleTransactionTestAuthorityClient {
     ) -> Result<SuiSystemState, SuiError> {
         unimplemented!()
     }
+
+    async fn handle_conflicting_transactions_request(
+        &self,
+        _request: ConflictingTransactionsRequest,
+    ) -> Result<ConflictingTransactionsResponse, SuiError> {
+        unimplemented!()
+    }
+
+    async fn handle_inclusion_gas_price_request(
+        &self,
+        _request: InclusionGasPriceRequest,
+    ) -> Result<InclusionGasPriceResponse, SuiError> {
+        unimplemented!()
+    }
+
+    async fn handle_object_ownership_proof_request(
+        &self,
+        _request: ObjectOwnershipProofRequest,
+    ) -> Result<ObjectOwnershipProofResponse, SuiError> {
+        unimplemented!()
+    }
+
+    async fn handle_epoch_state_commitment_request(
+        &self,
+        _request: EpochStateCommitmentRequest,
+    ) -> Result<EpochStateCommitmentResponse, SuiError> {
+        unimplemented!()
+    }
 }
 
 impl HandleTransactionTestAuthorityClient {
@@ -401,3 +499,549 @@ impl Default for HandleTransactionTestAuthorityClient {
         Self::new()
     }
 }
+
+/// The requests of [AuthorityAPI] and the submissions to consensus, which the rules of a
+/// [ByzantineScenario] apply to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AuthorityMessageKind {
+    Transaction,
+    Certificate,
+    ObjectInfo,
+    TransactionInfo,
+    Checkpoint,
+    SystemState,
+    ConflictingTransactions,
+    InclusionGasPrice,
+    ObjectOwnershipProof,
+    EpochStateCommitment,
+    /// Transactions submitted by the authority to consensus, see [ByzantineConsensusClient].
+    Consensus,
+}
+
+impl AuthorityMessageKind {
+    pub const ALL: [AuthorityMessageKind; 11] = [
+        Self::Transaction,
+        Self::Certificate,
+        Self::ObjectInfo,
+        Self::TransactionInfo,
+        Self::Checkpoint,
+        Self::SystemState,
+        Self::ConflictingTransactions,
+        Self::InclusionGasPrice,
+        Self::ObjectOwnershipProof,
+        Self::EpochStateCommitment,
+        Self::Consensus,
+    ];
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ByzantineAction {
+    /// The request never reaches the authority, as if it was unreachable.
+    Drop,
+    /// The response is returned after the delay.
+    Delay(Duration),
+    /// The request is handled twice, and the second response is returned.
+    Duplicate,
+    /// The authority signatures of the response are replaced by signatures of another key.
+    /// Responses without authority signatures are returned unchanged.
+    Corrupt,
+    /// The first response to a request of the same kind is returned again, e.g. a stale vote
+    /// for a previous transaction.
+    Replay,
+    /// The request is handled by the fork of the authority, i.e. another state signing with the
+    /// same key, so that the authority equivocates.
+    Fork,
+}
+
+/// Applies `action` to the requests of `kind`, after `skip` of them were handled honestly, and
+/// to `times` of them, or to all of them if `times` is None.
+#[derive(Clone, Copy, Debug)]
+pub struct ByzantineRule {
+    pub kind: AuthorityMessageKind,
+    pub action: ByzantineAction,
+    pub skip: usize,
+    pub times: Option<usize>,
+}
+
+impl ByzantineRule {
+    pub fn new(kind: AuthorityMessageKind, action: ByzantineAction) -> Self {
+        Self {
+            kind,
+            action,
+            skip: 0,
+            times: None,
+        }
+    }
+
+    pub fn skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// Whether the rule applies to the request of its kind at `index`.
+    fn applies_to(&self, index: usize) -> bool {
+        index >= self.skip && self.times.map_or(true, |times| index < self.skip + times)
+    }
+}
+
+/// Script of the faults of each authority. When several rules apply to a request, the first
+/// one added wins.
+#[derive(Clone, Debug, Default)]
+pub struct ByzantineScenario {
+    rules: BTreeMap<AuthorityName, Vec<ByzantineRule>>,
+}
+
+impl ByzantineScenario {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rule(mut self, authority: AuthorityName, rule: ByzantineRule) -> Self {
+        self.rules.entry(authority).or_default().push(rule);
+        self
+    }
+
+    /// Drops all the requests to `authority`, and all its submissions to consensus.
+    pub fn with_partitioned_authority(self, authority: AuthorityName) -> Self {
+        AuthorityMessageKind::ALL
+            .into_iter()
+            .fold(self, |scenario, kind| {
+                scenario.with_rule(authority, ByzantineRule::new(kind, ByzantineAction::Drop))
+            })
+    }
+
+    /// Wraps the `clients` of the authorities according to the scenario. `forks` are the
+    /// clients handling the requests of the [ByzantineAction::Fork] rules.
+    pub fn apply<A: Clone>(
+        &self,
+        clients: BTreeMap<AuthorityName, A>,
+        mut forks: BTreeMap<AuthorityName, A>,
+    ) -> BTreeMap<AuthorityName, ByzantineAuthorityClient<A>> {
+        clients
+            .into_iter()
+            .map(|(name, client)| {
+                let rules = self.rules.get(&name).cloned().unwrap_or_default();
+                let client = ByzantineAuthorityClient::new(client, forks.remove(&name), rules);
+                (name, client)
+            })
+            .collect()
+    }
+
+    /// Wraps the consensus client of `authority` according to the scenario.
+    pub fn consensus_client<C>(
+        &self,
+        authority: AuthorityName,
+        inner: C,
+    ) -> ByzantineConsensusClient<C> {
+        let rules = self.rules.get(&authority).cloned().unwrap_or_default();
+        ByzantineConsensusClient::new(inner, rules)
+    }
+}
+
+/// The action of the first of `rules` applying to the message of `kind` at `index`.
+fn find_action(
+    rules: &[ByzantineRule],
+    kind: AuthorityMessageKind,
+    index: usize,
+) -> Option<ByzantineAction> {
+    rules
+        .iter()
+        .find(|rule| rule.kind == kind && rule.applies_to(index))
+        .map(|rule| rule.action)
+}
+
+/// Wraps the client of an authority to drop, delay, duplicate, corrupt or replay its requests
+/// according to the rules of a [ByzantineScenario].
+#[derive(Clone)]
+pub struct ByzantineAuthorityClient<A> {
+    inner: A,
+    fork: Option<A>,
+    rules: Arc<Vec<ByzantineRule>>,
+    request_counts: Arc<Mutex<HashMap<AuthorityMessageKind, usize>>>,
+    first_responses: Arc<Mutex<HashMap<AuthorityMessageKind, Box<dyn Any + Send>>>>,
+}
+
+impl<A: Clone> ByzantineAuthorityClient<A> {
+    pub fn new(inner: A, fork: Option<A>, rules: Vec<ByzantineRule>) -> Self {
+        Self {
+            inner,
+            fork,
+            rules: Arc::new(rules),
+            request_counts: Arc::new(Mutex::new(HashMap::new())),
+            first_responses: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    fn next_action(&self, kind: AuthorityMessageKind) -> Option<ByzantineAction> {
+        let index = {
+            let mut request_counts = self.request_counts.lock().unwrap();
+            let count = request_counts.entry(kind).or_default();
+            *count += 1;
+            *count - 1
+        };
+        find_action(&self.rules, kind, index)
+    }
+
+    async fn handle<R, F, Fut>(&self, kind: AuthorityMessageKind, handle: F) -> SuiResult<R>
+    where
+        R: ByzantineResponse + Clone + Send + 'static,
+        F: Fn(A) -> Fut,
+        Fut: Future<Output = SuiResult<R>>,
+    {
+        let response = match self.next_action(kind) {
+            None => handle(self.inner.clone()).await,
+            Some(ByzantineAction::Drop) => Err(SuiError::RpcError(
+                "Request dropped by byzantine scenario".to_owned(),
+                "Service was not ready".to_owned(),
+            )),
+            Some(ByzantineAction::Delay(delay)) => {
+                tokio::time::sleep(delay).await;
+                handle(self.inner.clone()).await
+            }
+            Some(ByzantineAction::Duplicate) => {
+                let _ = handle(self.inner.clone()).await;
+                handle(self.inner.clone()).await
+            }
+            Some(ByzantineAction::Corrupt) => handle(self.inner.clone()).await.map(R::corrupt),
+            Some(ByzantineAction::Replay) => {
+                let first_response = self
+                    .first_responses
+                    .lock()
+                    .unwrap()
+                    .get(&kind)
+                    .and_then(|response| response.downcast_ref::<R>())
+                    .cloned();
+                match first_response {
+                    Some(response) => Ok(response),
+                    None => handle(self.inner.clone()).await,
+                }
+            }
+            Some(ByzantineAction::Fork) => {
+                let fork = self
+                    .fork
+                    .clone()
+                    .expect("Fork rules require a fork of the authority");
+                handle(fork).await
+            }
+        };
+        if let Ok(response) = &response {
+            self.first_responses
+                .lock()
+                .unwrap()
+                .entry(kind)
+                .or_insert_with(|| Box::new(response.clone()));
+        }
+        response
+    }
+}
+
+#[async_trait]
+impl<A> AuthorityAPI for ByzantineAuthorityClient<A>
+where
+    A: AuthorityAPI + Clone + Send + Sync + 'static,
+{
+    async fn handle_transaction(
+        &self,
+        transaction: Transaction,
+    ) -> Result<HandleTransactionResponse, SuiError> {
+        self.handle(AuthorityMessageKind::Transaction, |client| {
+            let transaction = transaction.clone();
+            async move { client.handle_transaction(transaction).await }
+        })
+        .await
+    }
+
+    async fn handle_certificate_v2(
+        &self,
+        certificate: CertifiedTransaction,
+    ) -> Result<HandleCertificateResponseV2, SuiError> {
+        self.handle(AuthorityMessageKind::Certificate, |client| {
+            let certificate = certificate.clone();
+            async move { client.handle_certificate_v2(certificate).await }
+        })
+        .await
+    }
+
+    async fn handle_object_info_request(
+        &self,
+        request: ObjectInfoRequest,
+    ) -> Result<ObjectInfoResponse, SuiError> {
+        self.handle(AuthorityMessageKind::ObjectInfo, |client| {
+            let request = request.clone();
+            async move { client.handle_object_info_request(request).await }
+        })
+        .await
+    }
+
+    async fn handle_transaction_info_request(
+        &self,
+        request: TransactionInfoRequest,
+    ) -> Result<TransactionInfoResponse, SuiError> {
+        self.handle(AuthorityMessageKind::TransactionInfo, |client| {
+            let request = request.clone();
+            async move { client.handle_transaction_info_request(request).await }
+        })
+        .await
+    }
+
+    async fn handle_checkpoint(
+        &self,
+        request: CheckpointRequest,
+    ) -> Result<CheckpointResponse, SuiError> {
+        self.handle(AuthorityMessageKind::Checkpoint, |client| {
+            let request = request.clone();
+            async move { client.handle_checkpoint(request).await }
+        })
+        .await
+    }
+
+    async fn handle_checkpoint_v2(
+        &self,
+        request: CheckpointRequestV2,
+    ) -> Result<CheckpointResponseV2, SuiError> {
+        self.handle(AuthorityMessageKind::Checkpoint, |client| {
+            let request = request.clone();
+            async move { client.handle_checkpoint_v2(request).await }
+        })
+        .await
+    }
+
+    async fn handle_system_state_object(
+        &self,
+        request: SystemStateRequest,
+    ) -> Result<SuiSystemState, SuiError> {
+        self.handle(AuthorityMessageKind::SystemState, |client| {
+            let request = request.clone();
+            async move { client.handle_system_state_object(request).await }
+        })
+        .await
+    }
+
+    async fn handle_conflicting_transactions_request(
+        &self,
+        request: ConflictingTransactionsRequest,
+    ) -> Result<ConflictingTransactionsResponse, SuiError> {
+        self.handle(AuthorityMessageKind::ConflictingTransactions, |client| {
+            let request = request.clone();
+            async move {
+                client
+                    .handle_conflicting_transactions_request(request)
+                    .await
+            }
+        })
+        .await
+    }
+
+    async fn handle_inclusion_gas_price_request(
+        &self,
+        request: InclusionGasPriceRequest,
+    ) -> Result<InclusionGasPriceResponse, SuiError> {
+        self.handle(AuthorityMessageKind::InclusionGasPrice, |client| {
+            let request = request.clone();
+            async move { client.handle_inclusion_gas_price_request(request).await }
+        })
+        .await
+    }
+
+    async fn handle_object_ownership_proof_request(
+        &self,
+        request: ObjectOwnershipProofRequest,
+    ) -> Result<ObjectOwnershipProofResponse, SuiError> {
+        self.handle(AuthorityMessageKind::ObjectOwnershipProof, |client| {
+            let request = request.clone();
+            async move { client.handle_object_ownership_proof_request(request).await }
+        })
+        .await
+    }
+
+    async fn handle_epoch_state_commitment_request(
+        &self,
+        request: EpochStateCommitmentRequest,
+    ) -> Result<EpochStateCommitmentResponse, SuiError> {
+        self.handle(AuthorityMessageKind::EpochStateCommitment, |client| {
+            let request = request.clone();
+            async move { client.handle_epoch_state_commitment_request(request).await }
+        })
+        .await
+    }
+}
+
+/// Wraps the consensus client of an authority to drop, delay, duplicate, corrupt or replay its
+/// submissions according to the [AuthorityMessageKind::Consensus] rules of a
+/// [ByzantineScenario]. A dropped submission is lost on the way to consensus and is reported as
+/// submitted, a corrupted checkpoint signature is signed by another key, and a replay submits
+/// the first transaction submitted again instead. Fork rules don't apply to consensus.
+pub struct ByzantineConsensusClient<C> {
+    inner: C,
+    rules: Vec<ByzantineRule>,
+    submission_count: Mutex<usize>,
+    first_submission: Mutex<Option<ConsensusTransaction>>,
+}
+
+impl<C> ByzantineConsensusClient<C> {
+    pub fn new(inner: C, rules: Vec<ByzantineRule>) -> Self {
+        Self {
+            inner,
+            rules,
+            submission_count: Mutex::new(0),
+            first_submission: Mutex::new(None),
+        }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn next_action(&self) -> Option<ByzantineAction> {
+        let index = {
+            let mut submission_count = self.submission_count.lock().unwrap();
+            *submission_count += 1;
+            *submission_count - 1
+        };
+        find_action(&self.rules, AuthorityMessageKind::Consensus, index)
+    }
+}
+
+#[async_trait]
+impl<C: SubmitToConsensus> SubmitToConsensus for ByzantineConsensusClient<C> {
+    async fn submit_to_consensus(
+        &self,
+        transaction: &ConsensusTransaction,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) -> SuiResult {
+        self.first_submission
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| transaction.clone());
+        match self.next_action() {
+            None => {
+                self.inner
+                    .submit_to_consensus(transaction, epoch_store)
+                    .await
+            }
+            Some(ByzantineAction::Drop) => Ok(()),
+            Some(ByzantineAction::Delay(delay)) => {
+                tokio::time::sleep(delay).await;
+                self.inner
+                    .submit_to_consensus(transaction, epoch_store)
+                    .await
+            }
+            Some(ByzantineAction::Duplicate) => {
+                self.inner
+                    .submit_to_consensus(transaction, epoch_store)
+                    .await?;
+                self.inner
+                    .submit_to_consensus(transaction, epoch_store)
+                    .await
+            }
+            Some(ByzantineAction::Corrupt) => {
+                self.inner
+                    .submit_to_consensus(&corrupt_consensus_transaction(transaction), epoch_store)
+                    .await
+            }
+            Some(ByzantineAction::Replay) => {
+                let first_submission = self.first_submission.lock().unwrap().clone().unwrap();
+                self.inner
+                    .submit_to_consensus(&first_submission, epoch_store)
+                    .await
+            }
+            Some(ByzantineAction::Fork) => panic!("Fork rules don't apply to consensus"),
+        }
+    }
+}
+
+fn corrupt_consensus_transaction(transaction: &ConsensusTransaction) -> ConsensusTransaction {
+    match &transaction.kind {
+        ConsensusTransactionKind::CheckpointSignature(message) => {
+            let (summary, sig) = message.summary.clone().into_data_and_sig();
+            ConsensusTransaction::new_checkpoint_signature_message(CheckpointSignatureMessage {
+                summary: SignedCheckpointSummary::new_from_data_and_sig(
+                    summary,
+                    corrupt_sign_info(sig),
+                ),
+            })
+        }
+        _ => transaction.clone(),
+    }
+}
+
+/// Responses of [AuthorityAPI], as tampered with by [ByzantineAction::Corrupt].
+trait ByzantineResponse: Sized {
+    fn corrupt(self) -> Self {
+        self
+    }
+}
+
+impl ByzantineResponse for HandleTransactionResponse {
+    fn corrupt(self) -> Self {
+        Self {
+            status: corrupt_transaction_status(self.status),
+        }
+    }
+}
+
+impl ByzantineResponse for HandleCertificateResponseV2 {
+    fn corrupt(self) -> Self {
+        Self {
+            signed_effects: corrupt_signed_effects(self.signed_effects),
+            ..self
+        }
+    }
+}
+
+impl ByzantineResponse for TransactionInfoResponse {
+    fn corrupt(self) -> Self {
+        Self {
+            status: corrupt_transaction_status(self.status),
+            ..self
+        }
+    }
+}
+
+impl ByzantineResponse for ObjectInfoResponse {}
+impl ByzantineResponse for CheckpointResponse {}
+impl ByzantineResponse for CheckpointResponseV2 {}
+impl ByzantineResponse for SuiSystemState {}
+impl ByzantineResponse for ConflictingTransactionsResponse {}
+impl ByzantineResponse for InclusionGasPriceResponse {}
+impl ByzantineResponse for ObjectOwnershipProofResponse {}
+impl ByzantineResponse for EpochStateCommitmentResponse {}
+
+fn corrupt_transaction_status(status: TransactionStatus) -> TransactionStatus {
+    match status {
+        TransactionStatus::Signed(sig) => TransactionStatus::Signed(corrupt_sign_info(sig)),
+        TransactionStatus::Executed(cert_sig, signed_effects, events) => {
+            TransactionStatus::Executed(cert_sig, corrupt_signed_effects(signed_effects), events)
+        }
+    }
+}
+
+fn corrupt_signed_effects(signed_effects: SignedTransactionEffects) -> SignedTransactionEffects {
+    let (effects, sig) = signed_effects.into_data_and_sig();
+    SignedTransactionEffects::new_from_data_and_sig(effects, corrupt_sign_info(sig))
+}
+
+/// Signature claimed to be by the same authority, but signed by a random key.
+fn corrupt_sign_info(sig: AuthoritySignInfo) -> AuthoritySignInfo {
+    let (_, key): (_, AuthorityKeyPair) = get_key_pair();
+    AuthoritySignInfo::new(
+        sig.epoch,
+        &(),
+        Intent::sui_app(IntentScope::SenderSignedTransaction),
+        sig.authority,
+        &key,
+    )
+}
+
+#[cfg(test)]
+#[path = "unit_tests/byzantine_authority_tests.rs"]
+mod byzantine_authority_tests;
@@ -7,7 +7,9 @@ use crate::authority::{
 use crate::authority_aggregator::{AuthorityAggregator, TimeoutConfig};
 use crate::epoch::committee_store::CommitteeStore;
 use crate::state_accumulator::StateAccumulator;
-use crate::test_authority_clients::LocalAuthorityClient;
+use crate::test_authority_clients::{
+    ByzantineAuthorityClient, ByzantineScenario, LocalAuthorityClient,
+};
 use fastcrypto::hash::MultisetHash;
 use fastcrypto::traits::KeyPair;
 use futures::future::join_all;
@@ -306,15 +308,73 @@ pub async fn init_local_authorities_with_genesis(
     genesis: &Genesis,
     authorities: Vec<Arc<AuthorityState>>,
 ) -> AuthorityAggregator<LocalAuthorityClient> {
-    telemetry_subscribers::init_for_testing();
-    let committee = genesis.committee().unwrap();
-
     let mut clients = BTreeMap::new();
     for state in authorities {
         let name = state.name;
         let client = LocalAuthorityClient::new_from_authority(state);
         clients.insert(name, client);
     }
+    init_aggregator_with_clients(genesis, clients)
+}
+
+/// Like `init_local_authorities`, with the clients of the authorities wrapped according to the
+/// scenario built by `make_scenario` from the names of the authorities. The authorities at the
+/// indexes of `forked` also get a fork, i.e. a second state signing with the same key, to handle
+/// the requests of their `ByzantineAction::Fork` rules.
+pub async fn init_byzantine_local_authorities(
+    committee_size: usize,
+    genesis_objects: Vec<Object>,
+    forked: &[usize],
+    make_scenario: impl FnOnce(&[AuthorityName]) -> ByzantineScenario,
+) -> (
+    AuthorityAggregator<ByzantineAuthorityClient<LocalAuthorityClient>>,
+    Vec<Arc<AuthorityState>>,
+    Genesis,
+    ObjectID,
+) {
+    let (genesis, key_pairs, framework) = init_genesis(committee_size, genesis_objects).await;
+    let authorities = join_all(key_pairs.iter().map(|(_, key_pair)| {
+        TestAuthorityBuilder::new()
+            .with_genesis_and_keypair(&genesis, key_pair)
+            .build()
+    }))
+    .await;
+    let forks = join_all(forked.iter().map(|index| {
+        TestAuthorityBuilder::new()
+            .with_genesis_and_keypair(&genesis, &key_pairs[*index].1)
+            .build()
+    }))
+    .await;
+
+    let names = authorities
+        .iter()
+        .map(|state| state.name)
+        .collect::<Vec<_>>();
+    let clients = authorities
+        .iter()
+        .map(|state| {
+            (
+                state.name,
+                LocalAuthorityClient::new_from_authority(state.clone()),
+            )
+        })
+        .collect();
+    let forks = forks
+        .into_iter()
+        .map(|state| (state.name, LocalAuthorityClient::new_from_authority(state)))
+        .collect();
+    let clients = make_scenario(&names).apply(clients, forks);
+    let aggregator = init_aggregator_with_clients(&genesis, clients);
+    (aggregator, authorities, genesis, framework)
+}
+
+fn init_aggregator_with_clients<A: Clone>(
+    genesis: &Genesis,
+    clients: BTreeMap<AuthorityName, A>,
+) -> AuthorityAggregator<A> {
+    telemetry_subscribers::init_for_testing();
+    let committee = genesis.committee().unwrap();
+
     let timeouts = TimeoutConfig {
         pre_quorum_timeout: Duration::from_secs(5),
         post_quorum_timeout: Duration::from_secs(5),
@@ -281,9 +281,11 @@ impl TransactionInputLoader {
         receiving_objects: &[ObjectRef],
         _protocol_config: &ProtocolConfig,
     ) -> SuiResult<(InputObjects, ReceivingObjects)> {
-        let mut results = Vec::with_capacity(input_object_kinds.len());
+        let mut results = vec![None; input_object_kinds.len()];
+        let mut object_keys = Vec::with_capacity(input_object_kinds.len());
+        let mut fetch_indices = Vec::with_capacity(input_object_kinds.len());
         // Length of input_object_kinds have beeen checked via validity_check() for ProgrammableTransaction.
-        for kind in input_object_kinds {
+        for (i, kind) in input_object_kinds.iter().enumerate() {
             let obj = match kind {
                 InputObjectKind::MovePackage(id) => self
                     .store
@@ -291,17 +293,35 @@ impl TransactionInputLoader {
                     .map(|o| o.object().clone()),
 
                 InputObjectKind::SharedMoveObject { id, .. } => self.store.get_object(id)?,
+                // Versioned objects are read together below
                 InputObjectKind::ImmOrOwnedMoveObject(objref) => {
-                    self.store.get_object_by_key(&objref.0, objref.1)?
+                    object_keys.push(objref.into());
+                    fetch_indices.push(i);
+                    continue;
                 }
             }
             .ok_or_else(|| SuiError::from(kind.object_not_found_error()))?;
-            results.push(ObjectReadResult::new(*kind, obj.into()));
+            results[i] = Some(ObjectReadResult::new(*kind, obj.into()));
+        }
+
+        let objects = self.store.multi_get_object_by_key(&object_keys)?;
+        assert_eq!(objects.len(), fetch_indices.len());
+        for (index, object) in fetch_indices.into_iter().zip(objects) {
+            let kind = &input_object_kinds[index];
+            let object = object.ok_or_else(|| SuiError::from(kind.object_not_found_error()))?;
+            results[index] = Some(ObjectReadResult::new(*kind, object.into()));
         }
 
         let receiving_results = self.read_receiving_objects(receiving_objects, 0)?;
 
-        Ok((results.into(), receiving_results))
+        Ok((
+            results
+                .into_iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>()
+                .into(),
+            receiving_results,
+        ))
     }
 
     fn read_receiving_objects(
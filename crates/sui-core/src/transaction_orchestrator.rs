@@ -29,6 +29,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use sui_storage::write_path_pending_tx_log::WritePathPendingTransactionLog;
 use sui_types::base_types::TransactionDigest;
+use sui_types::digests::TransactionEffectsDigest;
 use sui_types::effects::{TransactionEffectsAPI, VerifiedCertifiedTransactionEffects};
 use sui_types::error::{SuiError, SuiResult};
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
@@ -40,6 +41,7 @@ use sui_types::quorum_driver_types::{
 use sui_types::sui_system_state::SuiSystemState;
 use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::Receiver;
+use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio::time::timeout;
 use tracing::{debug, error, error_span, info, instrument, warn, Instrument};
@@ -52,6 +54,16 @@ const LOCAL_EXECUTION_TIMEOUT: Duration = Duration::from_secs(10);
 
 const WAIT_FOR_FINALITY_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// A stage a transaction reaches before it is final, while it is being executed by the
+/// orchestrator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionExecutionStage {
+    /// The signatures of the transaction are verified and it is submitted to the quorum driver.
+    Accepted,
+    /// A quorum of validators certified the effects of the transaction.
+    Certified(TransactionEffectsDigest),
+}
+
 pub struct TransactiondOrchestrator<A: Clone> {
     quorum_driver_handler: Arc<QuorumDriverHandler<A>>,
     validator_state: Arc<AuthorityState>,
@@ -75,6 +87,7 @@ impl TransactiondOrchestrator<NetworkAuthorityClient> {
             validator_state.committee_store(),
             safe_client_metrics_base.clone(),
             auth_agg_metrics.clone(),
+            validator_state.validator_client_tls_config(),
         )?;
 
         let observer = OnsiteReconfigObserver::new(
@@ -83,6 +96,7 @@ impl TransactiondOrchestrator<NetworkAuthorityClient> {
             validator_state.clone_committee_store(),
             safe_client_metrics_base,
             auth_agg_metrics,
+            validator_state.validator_client_tls_config().cloned(),
         );
         Ok(TransactiondOrchestrator::new(
             Arc::new(validators),
@@ -157,6 +171,23 @@ where
         &self,
         request: ExecuteTransactionRequest,
     ) -> Result<ExecuteTransactionResponse, QuorumDriverError> {
+        self.execute_transaction_block_with_stages(request, None)
+            .await
+    }
+
+    /// Like [Self::execute_transaction_block], and also sends the stages the transaction
+    /// reaches to `stages`, as they are reached.
+    pub async fn execute_transaction_block_with_stages(
+        &self,
+        request: ExecuteTransactionRequest,
+        stages: Option<mpsc::UnboundedSender<TransactionExecutionStage>>,
+    ) -> Result<ExecuteTransactionResponse, QuorumDriverError> {
+        let report_stage = |stage| {
+            if let Some(stages) = &stages {
+                // The receiver may have stopped listening, which doesn't affect execution.
+                let _ = stages.send(stage);
+            }
+        };
         // TODO check if tx is already executed on this node.
         // Note: since EffectsCert is not stored today, we need to gather that from validators
         // (and maybe store it for caching purposes)
@@ -196,6 +227,7 @@ where
             warn!(?tx_digest, "QuorumDriverInternalError: {e:?}");
             QuorumDriverError::QuorumDriverInternalError(e)
         })?;
+        report_stage(TransactionExecutionStage::Accepted);
 
         let wait_for_local_execution = matches!(
             request.request_type,
@@ -221,6 +253,7 @@ where
             Ok(Ok(response)) => {
                 good_response_metrics.inc();
                 let QuorumDriverResponse { effects_cert, .. } = response;
+                report_stage(TransactionExecutionStage::Certified(*effects_cert.digest()));
                 if !wait_for_local_execution {
                     return Ok(ExecuteTransactionResponse::EffectsCert(Box::new((
                         FinalizedEffects::new_from_effects_cert(effects_cert.into()),
@@ -18,6 +18,7 @@ use sui_types::crypto::get_key_pair_from_rng;
 use sui_types::crypto::{get_key_pair, AccountKeyPair, AuthorityKeyPair};
 use sui_types::crypto::{AuthoritySignature, Signer};
 use sui_types::crypto::{KeypairTraits, Signature};
+use sui_types::error::QueueDepthClass;
 use sui_types::object::Object;
 use sui_types::transaction::*;
 use sui_types::utils::create_fake_transaction;
@@ -380,6 +381,27 @@ async fn test_quorum_map_and_reduce_timeout() {
     }
 }
 
+#[sim_test]
+async fn test_get_inclusion_gas_price() {
+    let (authorities, _, _, _) = init_local_authorities(4, vec![]).await;
+    let rgp = reference_gas_price(&authorities);
+
+    // Validators that are not under load sign transactions paying the reference gas price.
+    assert_eq!(authorities.get_inclusion_gas_price().await, Some(rgp));
+}
+
+#[sim_test]
+async fn test_get_epoch_state_commitments() {
+    let (authorities, _, _, _) = init_local_authorities(4, vec![]).await;
+
+    // The genesis epoch has not ended, so no authority has a commitment to its live object set.
+    let commitments = authorities.get_epoch_state_commitments(0).await;
+    assert_eq!(commitments.len(), 4);
+    assert!(commitments
+        .values()
+        .all(|commitment| matches!(commitment, Ok(None))));
+}
+
 #[sim_test]
 async fn test_map_reducer() {
     let (authorities, _, _, _) = init_local_authorities(4, vec![]).await;
@@ -1939,6 +1961,109 @@ async fn test_handle_overload_response() {
     .await;
 }
 
+#[tokio::test]
+async fn test_overload_backoff_deadline() {
+    let mut authorities = BTreeMap::new();
+    let mut clients = BTreeMap::new();
+    let mut authority_keys = Vec::new();
+    for _ in 0..4 {
+        let (_, sec): (_, AuthorityKeyPair) = get_key_pair();
+        let name: AuthorityName = sec.public().into();
+        authorities.insert(name, 1);
+        authority_keys.push((name, sec));
+        clients.insert(name, HandleTransactionTestAuthorityClient::new());
+    }
+
+    let (sender, sender_kp): (_, AccountKeyPair) = get_key_pair();
+    let txn = make_transfer_sui_transaction(
+        random_object_ref(),
+        SuiAddress::default(),
+        None,
+        sender,
+        &sender_kp,
+        666, // this is a dummy value which does not matter
+    );
+
+    let overloaded = |retry_after_ms| SuiError::ValidatorOverloaded {
+        error: Box::new(SuiError::TooManyTransactionsPendingConsensus),
+        queue_depth: QueueDepthClass::Full,
+        retry_after_ms,
+    };
+
+    // Two of the four validators ask to be left alone, so no quorum can be formed until the
+    // earlier of their hints has elapsed.
+    set_retryable_tx_info_response_error(&mut clients, &authority_keys);
+    set_tx_info_response_with_error(
+        &mut clients,
+        authority_keys.iter().take(1),
+        overloaded(10_000),
+    );
+    set_tx_info_response_with_error(
+        &mut clients,
+        authority_keys.iter().skip(1).take(1),
+        overloaded(60_000),
+    );
+
+    let agg = get_genesis_agg(authorities.clone(), clients.clone());
+    assert!(agg.overload_backoff_deadline().is_none());
+    let start = Instant::now();
+    assert_resp_err(
+        &agg,
+        txn.clone(),
+        |e| {
+            matches!(
+                e,
+                AggregatorProcessTransactionError::RetryableTransaction { .. }
+            )
+        },
+        |e| {
+            matches!(
+                e,
+                SuiError::ValidatorOverloaded { .. } | SuiError::RpcError(..)
+            )
+        },
+    )
+    .await;
+    let deadline = agg.overload_backoff_deadline().unwrap();
+    assert!(deadline >= start + Duration::from_secs(10));
+    assert!(deadline < Instant::now() + Duration::from_secs(60));
+
+    // Overloaded validators are skipped while backing off, which doesn't extend their hints.
+    assert_resp_err(
+        &agg,
+        txn.clone(),
+        |e| {
+            matches!(
+                e,
+                AggregatorProcessTransactionError::RetryableTransaction { .. }
+            )
+        },
+        |e| {
+            matches!(
+                e,
+                SuiError::ValidatorOverloaded { .. } | SuiError::RpcError(..)
+            )
+        },
+    )
+    .await;
+    assert_eq!(agg.overload_backoff_deadline(), Some(deadline));
+
+    // A single overloaded validator doesn't prevent a quorum.
+    clients = BTreeMap::new();
+    for (name, _) in &authority_keys {
+        clients.insert(*name, HandleTransactionTestAuthorityClient::new());
+    }
+    set_retryable_tx_info_response_error(&mut clients, &authority_keys);
+    set_tx_info_response_with_error(
+        &mut clients,
+        authority_keys.iter().take(1),
+        overloaded(10_000),
+    );
+    let agg = get_genesis_agg(authorities, clients);
+    let _ = agg.process_transaction(txn).await;
+    assert!(agg.overload_backoff_deadline().is_none());
+}
+
 #[tokio::test]
 async fn test_early_exit_with_too_many_conflicts() {
     let mut authorities = BTreeMap::new();
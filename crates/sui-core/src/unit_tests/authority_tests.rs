@@ -39,9 +39,12 @@ use sui_types::error::UserInputError;
 use sui_types::execution_status::{ExecutionFailureStatus, ExecutionStatus};
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages_consensus::{ConsensusCommitPrologue, ConsensusCommitPrologueV2};
+use sui_types::messages_grpc::ConflictingTransactions;
+use sui_types::move_package::MovePackage;
 use sui_types::object::Data;
 use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::randomness_state::get_randomness_state_obj_initial_shared_version;
+use sui_types::storage::MarkerValue;
 use sui_types::sui_system_state::SuiSystemStateWrapper;
 use sui_types::utils::{
     to_sender_signed_transaction, to_sender_signed_transaction_with_multi_signers,
@@ -56,6 +59,7 @@ use sui_types::{
 };
 
 use crate::authority::authority_store_tables::AuthorityPerpetualTables;
+use crate::authority::effects_invariants::{self, EffectsInvariantViolation};
 use crate::authority::move_integration_tests::build_and_publish_test_package_with_upgrade_cap;
 use crate::authority::test_authority_builder::TestAuthorityBuilder;
 use crate::{
@@ -1781,6 +1785,130 @@ async fn test_publish_dependent_module_ok() {
     assert!(authority.get_object(&dependent_module_id).await.is_ok());
 }
 
+// Test that publishing a module that imports a module missing from its dependency fails
+#[tokio::test]
+async fn test_publish_dependent_module_missing_module() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_payment_object_id = ObjectID::random();
+    let gas_payment_object = Object::with_id_owner_for_testing(gas_payment_object_id, sender);
+    let gas_payment_object_ref = gas_payment_object.compute_object_reference();
+    let genesis_module = match BuiltInFramework::genesis_objects()
+        .next()
+        .unwrap()
+        .into_inner()
+        .data
+    {
+        Data::Package(m) => CompiledModule::deserialize_with_defaults(
+            m.serialized_module_map().values().next().unwrap(),
+        )
+        .unwrap(),
+        _ => unreachable!(),
+    };
+    // import a module of the genesis package that doesn't exist
+    let mut dependent_module = make_dependent_module(&genesis_module);
+    *dependent_module.identifiers.last_mut().unwrap() = Identifier::new("missing_module").unwrap();
+    let dependent_module_bytes = {
+        let mut bytes = Vec::new();
+        dependent_module.serialize(&mut bytes).unwrap();
+        bytes
+    };
+
+    let authority = init_state_with_objects(vec![gas_payment_object]).await;
+    let rgp = authority.reference_gas_price_for_testing().unwrap();
+    let data = TransactionData::new_module(
+        sender,
+        gas_payment_object_ref,
+        vec![dependent_module_bytes],
+        vec![ObjectID::from(*genesis_module.address())],
+        rgp * TEST_ONLY_GAS_UNIT_FOR_PUBLISH,
+        rgp,
+    );
+    let transaction = to_sender_signed_transaction(data, &sender_key);
+    let (failure, _) = send_and_confirm_transaction(&authority, transaction)
+        .await
+        .unwrap()
+        .1
+        .into_data()
+        .into_status()
+        .unwrap_err();
+
+    assert_eq!(
+        ExecutionFailureStatus::PublishUpgradeMissingDependency,
+        failure,
+    );
+}
+
+// Test that publishing a module with two versions of the same package as dependencies fails
+#[tokio::test]
+async fn test_publish_dependent_module_duplicate_dependency_versions() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_payment_object_id = ObjectID::random();
+    let gas_payment_object = Object::with_id_owner_for_testing(gas_payment_object_id, sender);
+    let gas_payment_object_ref = gas_payment_object.compute_object_reference();
+    let genesis_package = match BuiltInFramework::genesis_objects()
+        .next()
+        .unwrap()
+        .into_inner()
+        .data
+    {
+        Data::Package(p) => p,
+        _ => unreachable!(),
+    };
+    let genesis_module = CompiledModule::deserialize_with_defaults(
+        genesis_package
+            .serialized_module_map()
+            .values()
+            .next()
+            .unwrap(),
+    )
+    .unwrap();
+    // a later version of the genesis package, stored at another id
+    let upgraded_package = MovePackage::new(
+        ObjectID::random(),
+        genesis_package.version().next(),
+        genesis_package.serialized_module_map().clone(),
+        u64::MAX,
+        genesis_package.type_origin_table().clone(),
+        genesis_package.linkage_table().clone(),
+    )
+    .unwrap();
+    let upgraded_package_id = upgraded_package.id();
+    let upgraded_package_object =
+        Object::new_from_package(upgraded_package, TransactionDigest::genesis_marker());
+
+    let dependent_module = make_dependent_module(&genesis_module);
+    let dependent_module_bytes = {
+        let mut bytes = Vec::new();
+        dependent_module.serialize(&mut bytes).unwrap();
+        bytes
+    };
+
+    let authority =
+        init_state_with_objects(vec![gas_payment_object, upgraded_package_object]).await;
+    let rgp = authority.reference_gas_price_for_testing().unwrap();
+    let data = TransactionData::new_module(
+        sender,
+        gas_payment_object_ref,
+        vec![dependent_module_bytes],
+        vec![genesis_package.id(), upgraded_package_id],
+        rgp * TEST_ONLY_GAS_UNIT_FOR_PUBLISH,
+        rgp,
+    );
+    let transaction = to_sender_signed_transaction(data, &sender_key);
+    let (failure, _) = send_and_confirm_transaction(&authority, transaction)
+        .await
+        .unwrap()
+        .1
+        .into_data()
+        .into_status()
+        .unwrap_err();
+
+    assert_eq!(
+        ExecutionFailureStatus::PublishUpgradeMissingDependency,
+        failure,
+    );
+}
+
 // Test that publishing a module with no dependencies works
 #[tokio::test]
 async fn test_publish_module_no_dependencies_ok() {
@@ -2084,6 +2212,181 @@ async fn test_conflicting_transactions() {
     }
 }
 
+#[tokio::test]
+async fn test_conflicting_transactions_are_recorded() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let object_id = ObjectID::random();
+    let gas_object_id = ObjectID::random();
+    let authority_state =
+        init_state_with_ids(vec![(sender, object_id), (sender, gas_object_id)]).await;
+
+    let rgp = authority_state.reference_gas_price_for_testing().unwrap();
+    let epoch_store = authority_state.load_epoch_store_one_call_per_task();
+    let object = authority_state
+        .get_object(&object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let gas_object = authority_state
+        .get_object(&gas_object_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let [tx1, tx2] = [dbg_addr(2), dbg_addr(3)].map(|recipient| {
+        init_transfer_transaction(
+            &authority_state,
+            sender,
+            &sender_key,
+            recipient,
+            object.compute_object_reference(),
+            gas_object.compute_object_reference(),
+            rgp * TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
+            rgp,
+        )
+    });
+
+    authority_state
+        .handle_transaction(&epoch_store, tx1.clone())
+        .await
+        .unwrap();
+    let err = authority_state
+        .handle_transaction(&epoch_store, tx2.clone())
+        .await
+        .unwrap_err();
+    let SuiError::ObjectLockConflict { obj_ref, .. } = err else {
+        panic!("Expected ObjectLockConflict, got {:?}", err);
+    };
+
+    // The rejected transaction is recorded against the object version it conflicted on.
+    let conflicts = authority_state
+        .handle_conflicting_transactions_request(ConflictingTransactionsRequest {
+            object_id: obj_ref.0,
+        })
+        .unwrap()
+        .conflicts;
+    assert_eq!(
+        conflicts,
+        vec![ConflictingTransactions {
+            obj_ref,
+            epoch: epoch_store.epoch(),
+            locked_by_tx: *tx1.digest(),
+            conflicting_tx: *tx2.digest(),
+        }]
+    );
+
+    // Retrying the signed transaction is not a conflict.
+    authority_state
+        .handle_transaction(&epoch_store, tx1.clone())
+        .await
+        .unwrap();
+    let conflicts = authority_state
+        .handle_conflicting_transactions_request(ConflictingTransactionsRequest {
+            object_id: obj_ref.0,
+        })
+        .unwrap()
+        .conflicts;
+    assert_eq!(conflicts.len(), 1);
+}
+
+#[tokio::test]
+async fn test_epoch_execution_config_is_stored() {
+    let authority_state = TestAuthorityBuilder::new().build().await;
+    let epoch_store = authority_state.load_epoch_store_one_call_per_task();
+
+    let execution_config = authority_state
+        .db()
+        .get_epoch_execution_config(epoch_store.epoch())
+        .unwrap()
+        .expect("Execution config of the current epoch must be stored");
+    assert_eq!(
+        serde_json::to_string(&execution_config.protocol_config()).unwrap(),
+        serde_json::to_string(epoch_store.protocol_config()).unwrap()
+    );
+    assert_eq!(
+        execution_config.execution_cost_table,
+        sui_types::gas_model::gas_predicates::cost_table_for_version(
+            epoch_store.protocol_config().gas_model_version()
+        )
+    );
+    assert!(authority_state
+        .db()
+        .get_epoch_execution_config(epoch_store.epoch() + 1)
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_clear_object_per_epoch_marker_table() {
+    let authority_state = TestAuthorityBuilder::new().build().await;
+    let table = &authority_state
+        .database
+        .perpetual_tables
+        .object_per_epoch_marker_table;
+    let object_id = ObjectID::random();
+    let old_markers = [
+        (0, ObjectKey::ZERO),
+        (1, ObjectKey(object_id, SequenceNumber::from(1))),
+        (1, ObjectKey::max_for_id(&object_id)),
+    ];
+    let current_markers = [
+        (2, ObjectKey::ZERO),
+        (2, ObjectKey(object_id, SequenceNumber::from(1))),
+        (3, ObjectKey(object_id, SequenceNumber::from(2))),
+    ];
+    table
+        .multi_insert(
+            old_markers
+                .iter()
+                .chain(&current_markers)
+                .map(|key| (*key, MarkerValue::OwnedDeleted)),
+        )
+        .unwrap();
+
+    let execution_lock = authority_state
+        .database
+        .execution_lock_for_reconfiguration()
+        .await;
+    authority_state
+        .clear_object_per_epoch_marker_table(&execution_lock, 2)
+        .unwrap();
+    drop(execution_lock);
+
+    for key in &old_markers {
+        assert!(!table.contains_key(key).unwrap(), "{key:?} not removed");
+    }
+    for key in &current_markers {
+        assert!(table.contains_key(key).unwrap(), "{key:?} removed");
+    }
+}
+
+#[test]
+fn test_inclusion_gas_price_threshold() {
+    let config = OverloadThresholdConfig {
+        min_transaction_manager_queue_length_for_gas_price_priority: 1_000,
+        max_transaction_manager_queue_length_for_signing: 2_000,
+        max_gas_price_multiplier_under_load: 5,
+        ..Default::default()
+    };
+    let rgp = 1_000;
+    assert_eq!(inclusion_gas_price_threshold(&config, 0, rgp), rgp);
+    assert_eq!(inclusion_gas_price_threshold(&config, 1_000, rgp), rgp);
+    assert_eq!(inclusion_gas_price_threshold(&config, 1_500, rgp), 3 * rgp);
+    assert_eq!(inclusion_gas_price_threshold(&config, 2_000, rgp), 5 * rgp);
+    assert_eq!(inclusion_gas_price_threshold(&config, 5_000, rgp), 5 * rgp);
+    assert_eq!(
+        inclusion_gas_price_threshold(&config, 2_000, u64::MAX),
+        u64::MAX
+    );
+
+    // Prioritization is disabled when it would start after signing stops.
+    let config = OverloadThresholdConfig {
+        min_transaction_manager_queue_length_for_gas_price_priority: 2_000,
+        ..config
+    };
+    assert_eq!(inclusion_gas_price_threshold(&config, 1_999, rgp), rgp);
+}
+
 #[tokio::test]
 async fn test_handle_transfer_transaction_double_spend() {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
@@ -3402,6 +3705,86 @@ async fn test_transfer_sui_with_amount() {
     );
 }
 
+#[tokio::test]
+async fn test_effects_invariant_violations() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let recipient = dbg_addr(2);
+    let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+    let authority_state = init_state_with_objects(vec![gas_object.clone()]).await;
+    let epoch_store = authority_state.epoch_store_for_testing();
+    let rgp = epoch_store.reference_gas_price();
+
+    let tx_data = TransactionData::new_transfer_sui(
+        recipient,
+        sender,
+        Some(500),
+        gas_object.compute_object_reference(),
+        rgp * TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
+        rgp,
+    );
+    let transaction = to_sender_signed_transaction(tx_data, &sender_key);
+    let certificate = init_certified_transaction(transaction, &authority_state);
+    let effects = authority_state
+        .execute_certificate(&certificate, &epoch_store)
+        .await
+        .unwrap()
+        .into_message();
+    assert!(effects.status().is_ok());
+
+    let mut written = WrittenObjects::new();
+    for ((id, _, _), _, _) in effects.all_changed_objects() {
+        let object = authority_state.get_object(&id).await.unwrap().unwrap();
+        written.insert(id, object);
+    }
+    let mut inner_temp_store = InnerTemporaryStore {
+        input_objects: BTreeMap::from([(gas_object.id(), gas_object.clone())]),
+        mutable_inputs: BTreeMap::new(),
+        written,
+        loaded_runtime_objects: BTreeMap::new(),
+        events: TransactionEvents::default(),
+        max_binary_format_version: 0,
+        no_extraneous_module_bytes: false,
+        runtime_packages_loaded_from_db: BTreeMap::new(),
+        lamport_version: effects.lamport_version(),
+    };
+    let check = |inner_temp_store: &InnerTemporaryStore| {
+        let mut layout_resolver = epoch_store
+            .executor()
+            .type_layout_resolver(Box::new(authority_state.database.clone()));
+        effects_invariants::check_effects_invariants(
+            inner_temp_store,
+            &effects,
+            true,
+            authority_state.database.as_ref(),
+            layout_resolver.as_mut(),
+        )
+    };
+    check(&inner_temp_store).unwrap();
+
+    // A coin appearing out of thin air breaks the conservation of SUI.
+    let minted = Object::with_id_owner_gas_for_testing(ObjectID::random(), recipient, 1000);
+    inner_temp_store.written.insert(minted.id(), minted);
+    let report = check(&inner_temp_store).unwrap_err();
+    assert_eq!(report.tx_digest, *certificate.digest());
+    let [EffectsInvariantViolation::SuiNotConserved {
+        input_sui,
+        output_sui,
+    }] = report.violations[..]
+    else {
+        panic!("Unexpected violations: {report}");
+    };
+    assert_eq!(output_sui, input_sui + 1000);
+
+    // Objects written at a version other than the lamport version are reported one by one.
+    inner_temp_store.lamport_version = effects.lamport_version().next();
+    let report = check(&inner_temp_store).unwrap_err();
+    for ((id, version, _), _, _) in effects.all_changed_objects() {
+        assert!(report
+            .violations
+            .contains(&EffectsInvariantViolation::NotAtLamportVersion { id, version }));
+    }
+}
+
 #[tokio::test]
 async fn test_store_revert_transfer_sui() {
     // This test checks the correctness of revert_state_update in SuiDataStore.
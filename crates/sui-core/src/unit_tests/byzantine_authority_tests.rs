@@ -0,0 +1,320 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::authority_aggregator::AuthorityAggregator;
+use crate::test_utils::{
+    init_byzantine_local_authorities, make_transfer_object_transaction,
+    make_transfer_sui_transaction,
+};
+use fastcrypto::traits::KeyPair;
+use sui_types::base_types::{ObjectRef, SuiAddress};
+use sui_types::crypto::AccountKeyPair;
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::messages_consensus::ConsensusTransactionKey;
+use sui_types::object::Object;
+
+type ByzantineAggregator = AuthorityAggregator<ByzantineAuthorityClient<LocalAuthorityClient>>;
+
+/// Records the keys of the transactions submitted to consensus.
+#[derive(Clone, Default)]
+struct RecordingConsensusClient(Arc<Mutex<Vec<ConsensusTransactionKey>>>);
+
+#[async_trait]
+impl SubmitToConsensus for RecordingConsensusClient {
+    async fn submit_to_consensus(
+        &self,
+        transaction: &ConsensusTransaction,
+        _epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) -> SuiResult {
+        self.0.lock().unwrap().push(transaction.key());
+        Ok(())
+    }
+}
+
+/// Sets up a committee of 4 equal stake authorities, the faults of which are scripted by
+/// `make_scenario`. Returns the aggregator, the authorities, the gas price, and a sender with an
+/// object and a gas object.
+async fn init_scenario(
+    forked: &[usize],
+    make_scenario: impl FnOnce(&[AuthorityName]) -> ByzantineScenario,
+) -> (
+    ByzantineAggregator,
+    Vec<Arc<AuthorityState>>,
+    u64,
+    (SuiAddress, AccountKeyPair),
+    ObjectRef,
+    ObjectRef,
+) {
+    let (sender, key): (_, AccountKeyPair) = get_key_pair();
+    let object = Object::with_owner_for_testing(sender);
+    let gas_object = Object::with_owner_for_testing(sender);
+    let (aggregator, authorities, genesis, _) = init_byzantine_local_authorities(
+        4,
+        vec![object.clone(), gas_object.clone()],
+        forked,
+        make_scenario,
+    )
+    .await;
+    let object_ref = genesis
+        .object(object.id())
+        .unwrap()
+        .compute_object_reference();
+    let gas_ref = genesis
+        .object(gas_object.id())
+        .unwrap()
+        .compute_object_reference();
+    let rgp = authorities[0].reference_gas_price_for_testing().unwrap();
+    (
+        aggregator,
+        authorities,
+        rgp,
+        (sender, key),
+        object_ref,
+        gas_ref,
+    )
+}
+
+#[test]
+fn test_byzantine_rule_schedule() {
+    let rule = ByzantineRule::new(AuthorityMessageKind::Transaction, ByzantineAction::Drop)
+        .skip(1)
+        .times(2);
+    assert!(!rule.applies_to(0));
+    assert!(rule.applies_to(1));
+    assert!(rule.applies_to(2));
+    assert!(!rule.applies_to(3));
+
+    let rule = ByzantineRule::new(AuthorityMessageKind::Transaction, ByzantineAction::Drop);
+    assert!(rule.applies_to(0));
+    assert!(rule.applies_to(1000));
+}
+
+#[tokio::test]
+async fn test_byzantine_corrupted_votes() {
+    // A single authority corrupting its votes is tolerated.
+    let (aggregator, _, rgp, (sender, key), object_ref, gas_ref) = init_scenario(&[], |names| {
+        ByzantineScenario::new()
+            .with_rule(
+                names[0],
+                ByzantineRule::new(AuthorityMessageKind::Transaction, ByzantineAction::Corrupt),
+            )
+            .with_rule(
+                names[0],
+                ByzantineRule::new(AuthorityMessageKind::Certificate, ByzantineAction::Corrupt),
+            )
+    })
+    .await;
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let tx = make_transfer_object_transaction(object_ref, gas_ref, sender, &key, recipient, rgp);
+    let cert = aggregator
+        .process_transaction(tx)
+        .await
+        .unwrap()
+        .into_cert_for_testing();
+    aggregator.process_certificate(cert).await.unwrap();
+
+    // Two of them can stop the transaction, but not get a certificate with a bad signature.
+    let (aggregator, _, rgp, (sender, key), object_ref, gas_ref) = init_scenario(&[], |names| {
+        names[..2]
+            .iter()
+            .fold(ByzantineScenario::new(), |scenario, name| {
+                scenario.with_rule(
+                    *name,
+                    ByzantineRule::new(AuthorityMessageKind::Transaction, ByzantineAction::Corrupt),
+                )
+            })
+    })
+    .await;
+    let tx = make_transfer_object_transaction(object_ref, gas_ref, sender, &key, recipient, rgp);
+    assert!(aggregator.process_transaction(tx).await.is_err());
+}
+
+#[tokio::test]
+async fn test_byzantine_stale_votes() {
+    // The first authority replays its vote for the first transaction to the later ones, and the
+    // second one handles every transaction twice, and answers certificates late.
+    let (aggregator, authorities, rgp, (sender, key), object_ref, gas_ref) =
+        init_scenario(&[], |names| {
+            ByzantineScenario::new()
+                .with_rule(
+                    names[0],
+                    ByzantineRule::new(AuthorityMessageKind::Transaction, ByzantineAction::Replay),
+                )
+                .with_rule(
+                    names[1],
+                    ByzantineRule::new(
+                        AuthorityMessageKind::Transaction,
+                        ByzantineAction::Duplicate,
+                    ),
+                )
+                .with_rule(
+                    names[1],
+                    ByzantineRule::new(
+                        AuthorityMessageKind::Certificate,
+                        ByzantineAction::Delay(Duration::from_millis(100)),
+                    ),
+                )
+        })
+        .await;
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let tx = make_transfer_object_transaction(object_ref, gas_ref, sender, &key, recipient, rgp);
+    let cert = aggregator
+        .process_transaction(tx)
+        .await
+        .unwrap()
+        .into_cert_for_testing();
+    let (effects, _) = aggregator.process_certificate(cert).await.unwrap();
+
+    // The stale vote of the first authority does not count towards the second transaction.
+    let tx = make_transfer_sui_transaction(
+        effects.gas_object().0,
+        recipient,
+        Some(1),
+        sender,
+        &key,
+        rgp,
+    );
+    let cert = aggregator
+        .process_transaction(tx)
+        .await
+        .unwrap()
+        .into_cert_for_testing();
+    let signers = cert
+        .auth_sig()
+        .authorities(&aggregator.committee)
+        .collect::<SuiResult<Vec<_>>>()
+        .unwrap();
+    assert!(!signers.contains(&&authorities[0].name));
+    aggregator.process_certificate(cert).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_byzantine_equivocation() {
+    // The last authority equivocates: its fork votes for a transaction conflicting with the one
+    // it voted for. The third authority misses the first transaction.
+    let (aggregator, _, rgp, (sender, key), object_ref, gas_ref) = init_scenario(&[3], |names| {
+        ByzantineScenario::new()
+            .with_rule(
+                names[2],
+                ByzantineRule::new(AuthorityMessageKind::Transaction, ByzantineAction::Drop)
+                    .times(1),
+            )
+            .with_rule(
+                names[3],
+                ByzantineRule::new(AuthorityMessageKind::Transaction, ByzantineAction::Fork)
+                    .skip(1),
+            )
+    })
+    .await;
+    let (recipient1, _): (_, AccountKeyPair) = get_key_pair();
+    let (recipient2, _): (_, AccountKeyPair) = get_key_pair();
+    let tx1 = make_transfer_object_transaction(object_ref, gas_ref, sender, &key, recipient1, rgp);
+    let tx2 = make_transfer_object_transaction(object_ref, gas_ref, sender, &key, recipient2, rgp);
+
+    let cert1 = aggregator
+        .process_transaction(tx1)
+        .await
+        .unwrap()
+        .into_cert_for_testing();
+    // Only the equivocating authority and the one which missed the first transaction vote for
+    // the second one, which is short of a quorum.
+    assert!(aggregator.process_transaction(tx2).await.is_err());
+    aggregator.process_certificate(cert1).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_byzantine_partitioned_authority() {
+    // The first authority is partitioned from the client for the whole transaction.
+    let (aggregator, authorities, rgp, (sender, key), object_ref, gas_ref) =
+        init_scenario(&[], |names| {
+            ByzantineScenario::new().with_partitioned_authority(names[0])
+        })
+        .await;
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let tx = make_transfer_object_transaction(object_ref, gas_ref, sender, &key, recipient, rgp);
+    let digest = *tx.digest();
+    let cert = aggregator
+        .process_transaction(tx)
+        .await
+        .unwrap()
+        .into_cert_for_testing();
+    aggregator.process_certificate(cert).await.unwrap();
+
+    for (index, authority) in authorities.iter().enumerate() {
+        let executed = authority.is_tx_already_executed(&digest).unwrap();
+        assert_eq!(executed, index != 0);
+    }
+
+    // Partitioning a second authority halts the network.
+    let (aggregator, _, rgp, (sender, key), object_ref, gas_ref) = init_scenario(&[], |names| {
+        ByzantineScenario::new()
+            .with_partitioned_authority(names[0])
+            .with_partitioned_authority(names[1])
+    })
+    .await;
+    let tx = make_transfer_object_transaction(object_ref, gas_ref, sender, &key, recipient, rgp);
+    assert!(aggregator.process_transaction(tx).await.is_err());
+}
+
+#[tokio::test]
+async fn test_byzantine_consensus_submissions() {
+    let state = TestAuthorityBuilder::new().build().await;
+    let epoch_store = state.epoch_store_for_testing();
+    let names = (0..4)
+        .map(|_| {
+            let (_, key): (_, AuthorityKeyPair) = get_key_pair();
+            AuthorityName::from(key.public())
+        })
+        .collect::<Vec<_>>();
+    let tx = |index: usize| ConsensusTransaction::new_end_of_publish(names[index]);
+
+    // The first submission is lost, the second one is submitted twice, and the third one is
+    // replaced by the first one.
+    let consensus = RecordingConsensusClient::default();
+    let client = ByzantineScenario::new()
+        .with_rule(
+            names[0],
+            ByzantineRule::new(AuthorityMessageKind::Consensus, ByzantineAction::Drop).times(1),
+        )
+        .with_rule(
+            names[0],
+            ByzantineRule::new(AuthorityMessageKind::Consensus, ByzantineAction::Duplicate)
+                .skip(1)
+                .times(1),
+        )
+        .with_rule(
+            names[0],
+            ByzantineRule::new(AuthorityMessageKind::Consensus, ByzantineAction::Replay)
+                .skip(2)
+                .times(1),
+        )
+        .consensus_client(names[0], consensus.clone());
+    for index in 0..4 {
+        client
+            .submit_to_consensus(&tx(index), &epoch_store)
+            .await
+            .unwrap();
+    }
+    assert_eq!(
+        *consensus.0.lock().unwrap(),
+        vec![tx(1).key(), tx(1).key(), tx(0).key(), tx(3).key()]
+    );
+
+    // Nothing submitted by a partitioned authority reaches consensus.
+    let scenario = ByzantineScenario::new().with_partitioned_authority(names[0]);
+    let partitioned = RecordingConsensusClient::default();
+    let honest = RecordingConsensusClient::default();
+    scenario
+        .consensus_client(names[0], partitioned.clone())
+        .submit_to_consensus(&tx(0), &epoch_store)
+        .await
+        .unwrap();
+    scenario
+        .consensus_client(names[1], honest.clone())
+        .submit_to_consensus(&tx(1), &epoch_store)
+        .await
+        .unwrap();
+    assert!(partitioned.0.lock().unwrap().is_empty());
+    assert_eq!(*honest.0.lock().unwrap(), vec![tx(1).key()]);
+}
@@ -149,6 +149,7 @@ async fn submit_transaction_to_consensus_adapter() {
 
     // Submit the transaction and ensure the adapter reports success to the caller. Note
     // that consensus may drop some transactions (so we may need to resubmit them).
+    let tx_digest = *certificate.digest();
     let transaction = ConsensusTransaction::new_certificate_message(&state.name, certificate);
     let waiter = adapter
         .submit(
@@ -157,7 +158,9 @@ async fn submit_transaction_to_consensus_adapter() {
             &epoch_store,
         )
         .unwrap();
+    assert!(epoch_store.is_pending_consensus_certificate(&tx_digest));
     waiter.await.unwrap();
+    assert!(!epoch_store.is_pending_consensus_certificate(&tx_digest));
 }
 
 pub struct ConsensusMockServer {
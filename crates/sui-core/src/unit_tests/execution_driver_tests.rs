@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::{ExecutionQueue, MAX_PRIORITIZATION_DELAY};
 use crate::authority::authority_tests::{send_consensus, send_consensus_no_execution};
 use crate::authority::{AuthorityState, EffectsNotifyRead};
 use crate::authority_aggregator::authority_aggregator_tests::{
@@ -588,6 +589,7 @@ async fn test_txn_age_overload() {
             gas_objects.clone(),
             OverloadThresholdConfig {
                 max_txn_age_in_queue: Duration::from_secs(5),
+                ..Default::default()
             },
         )
         .await;
@@ -699,3 +701,26 @@ async fn test_txn_age_overload() {
         message
     );
 }
+
+#[test]
+fn test_execution_queue_order() {
+    let mut queue = ExecutionQueue::new();
+    let start = tokio::time::Instant::now();
+    queue.push("low", 1_000, start);
+    queue.push("high", 5_000, start);
+    queue.push("medium", 2_000, start);
+    queue.push("high_later", 5_000, start);
+
+    // Higher gas price first, then older first.
+    assert_eq!(queue.pop(start), Some("high"));
+    assert_eq!(queue.pop(start), Some("high_later"));
+
+    // Certificates waiting too long are executed first regardless of gas price.
+    let later = start + MAX_PRIORITIZATION_DELAY;
+    queue.push("newest", 10_000, later);
+    assert_eq!(queue.pop(later), Some("low"));
+    assert_eq!(queue.pop(later), Some("medium"));
+    assert_eq!(queue.pop(later), Some("newest"));
+    assert_eq!(queue.pop(later), None);
+    assert!(queue.is_empty());
+}
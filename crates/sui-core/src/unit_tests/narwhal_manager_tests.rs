@@ -185,12 +185,23 @@ async fn test_narwhal_manager() {
         .into_iter()
         .zip(configs.validator_configs())
     {
+        // the status of consensus covers every authority of the committee
+        let status = narwhal_manager.consensus_status().await.unwrap().unwrap();
+        assert_eq!(status.frontier.len(), configs.validator_configs().len());
+
+        // the consensus output committed so far is processed before shutting down
+        assert!(narwhal_manager
+            .wait_for_output_processed(&state.epoch_store_for_testing())
+            .await
+            .unwrap());
+
         // stop narwhal instance
         narwhal_manager.shutdown().await;
 
         // ensure that no primary or worker node is running
         assert!(!narwhal_manager.is_running().await);
         assert!(!narwhal_manager.primary_node.is_running().await);
+        assert!(narwhal_manager.consensus_status().await.unwrap().is_none());
         assert!(narwhal_manager
             .worker_nodes
             .workers_running()
@@ -38,3 +38,26 @@ async fn test_simple_request() {
 
     client.handle_object_info_request(req).await.unwrap();
 }
+
+#[test]
+fn test_in_flight_counts() {
+    let counts = InFlightCounts::new();
+    let sender = dbg_addr(1);
+
+    let first = counts.try_admit(sender, 2).unwrap();
+    let second = counts.try_admit(sender, 2).unwrap();
+    assert_eq!(counts.in_flight(&sender), 2);
+    // The threshold is reached for this sender, but not for others.
+    assert_eq!(counts.try_admit(sender, 2).err(), Some(2));
+    let other = counts.try_admit(dbg_addr(2), 2).unwrap();
+
+    drop(first);
+    assert_eq!(counts.in_flight(&sender), 1);
+    let third = counts.try_admit(sender, 2).unwrap();
+
+    drop(second);
+    drop(third);
+    drop(other);
+    assert_eq!(counts.in_flight(&sender), 0);
+    assert!(counts.counts.lock().is_empty());
+}
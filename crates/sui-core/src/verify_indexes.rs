@@ -4,7 +4,10 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use anyhow::{anyhow, bail, Result};
-use sui_storage::{indexes::CoinInfo, IndexStore};
+use sui_storage::{
+    indexes::{owner_type_index_key, CoinInfo},
+    IndexStore,
+};
 use sui_types::{base_types::ObjectInfo, object::Owner};
 use tracing::info;
 use typed_store::traits::Map;
@@ -17,6 +20,7 @@ pub fn verify_indexes(database: Arc<AuthorityStore>, indexes: Arc<IndexStore>) -
     info!("Begin running index verification checks");
 
     let mut owner_index = BTreeMap::new();
+    let mut owner_type_index = BTreeMap::new();
     let mut coin_index = BTreeMap::new();
 
     tracing::info!("Reading live objects set");
@@ -32,6 +36,9 @@ pub fn verify_indexes(database: Arc<AuthorityStore>, indexes: Arc<IndexStore>) -
         let owner_index_key = (owner, object.id());
         let object_info = ObjectInfo::new(&object.compute_object_reference(), &object);
 
+        if let Some(key) = owner_type_index_key(owner, &object_info) {
+            owner_type_index.insert(key, object_info.clone());
+        }
         owner_index.insert(owner_index_key, object_info);
 
         // Coin Index Calculation
@@ -65,6 +72,25 @@ pub fn verify_indexes(database: Arc<AuthorityStore>, indexes: Arc<IndexStore>) -
     }
     tracing::info!("Owner index is good");
 
+    // Verify Owner Type Index
+    for (key, info) in indexes.tables().owner_type_index().unbounded_iter() {
+        let calculated_info = owner_type_index.remove(&key).ok_or_else(|| {
+            anyhow!(
+                "owner_type_index: found extra, unexpected entry {:?}",
+                (&key, &info)
+            )
+        })?;
+
+        if calculated_info != info {
+            bail!("owner_type_index: entry {key:?} is different: expected {calculated_info:?} found {info:?}");
+        }
+    }
+
+    if !owner_type_index.is_empty() {
+        bail!("owner_type_index: is missing entries: {owner_type_index:?}");
+    }
+    tracing::info!("Owner type index is good");
+
     // Verify Coin Index
     for (key, info) in indexes.tables().coin_index().unbounded_iter() {
         let calculated_info = coin_index.remove(&key).ok_or_else(|| {
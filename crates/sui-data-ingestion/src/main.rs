@@ -6,9 +6,10 @@ use prometheus::Registry;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::path::PathBuf;
+use std::sync::Arc;
 use sui_data_ingestion::{
-    DataIngestionMetrics, DynamoDBProgressStore, KVStoreTaskConfig, KVStoreWorker, S3TaskConfig,
-    S3Worker,
+    serve_archive, ArchiveStore, ArchiveTaskConfig, ArchiveWorker, DataIngestionMetrics,
+    DynamoDBProgressStore, KVStoreTaskConfig, KVStoreWorker, S3TaskConfig, S3Worker,
 };
 use sui_data_ingestion::{IndexerExecutor, WorkerPool};
 use tokio::signal;
@@ -19,6 +20,7 @@ use tokio::sync::oneshot;
 enum Task {
     S3(S3TaskConfig),
     KV(KVStoreTaskConfig),
+    Archive(ArchiveTaskConfig),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -125,6 +127,23 @@ async fn main() -> Result<()> {
                 );
                 executor.register(worker_pool).await?;
             }
+            Task::Archive(archive_config) => {
+                let store = Arc::new(ArchiveStore::open(&archive_config.path)?);
+                if let Some(address) = archive_config.server_address {
+                    let store = store.clone();
+                    tokio::spawn(async move {
+                        serve_archive(address, store)
+                            .await
+                            .expect("archive server failed")
+                    });
+                }
+                let worker_pool = WorkerPool::new(
+                    ArchiveWorker::new(store),
+                    task_config.name,
+                    task_config.concurrency,
+                );
+                executor.register(worker_pool).await?;
+            }
         };
     }
     executor
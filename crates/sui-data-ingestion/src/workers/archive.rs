@@ -0,0 +1,269 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Worker;
+use anyhow::Result;
+use async_trait::async_trait;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::net::SocketAddr;
+use std::os::unix::fs::FileExt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use sui_storage::http_key_value_store::TaggedKey;
+use sui_types::full_checkpoint_content::CheckpointData;
+use sui_types::storage::ObjectKey;
+use tracing::{error, info};
+use typed_store::rocks::{DBMap, MetricConf};
+use typed_store::traits::{TableSummary, TypedStoreDebug};
+use typed_store::Map;
+use typed_store_derive::DBMapUtils;
+
+const DATA_FILE_NAME: &str = "data";
+const INDEX_DIR_NAME: &str = "index";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ArchiveTaskConfig {
+    /// Directory of the archive files.
+    pub path: PathBuf,
+    /// Address to serve the archive on, in the protocol of the `HttpKVStore` of the fullnodes.
+    #[serde(default)]
+    pub server_address: Option<SocketAddr>,
+}
+
+/// Location of a value in the data file.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+struct Location {
+    offset: u64,
+    length: u64,
+}
+
+#[derive(DBMapUtils)]
+struct ArchiveIndexTables {
+    /// Location of the values in the data file, keyed by their `HttpKVStore` type and key.
+    locations: DBMap<(String, Vec<u8>), Location>,
+}
+
+struct DataWriter {
+    file: File,
+    len: u64,
+}
+
+/// Append-only store of the full history of the chain.
+///
+/// Values are BCS encoded and appended to the data file, and their locations are indexed in a
+/// RocksDB table, so that neither is held in memory. Reads go straight to the locations of the
+/// data file, concurrently with each other and with the writer. Nothing is ever overwritten or
+/// pruned. Values are keyed by the type and the key of the `HttpKVStore` protocol, so that the
+/// store can be served as is to the fullnodes. Values are only indexed once durably written, so
+/// the store stays consistent when the process crashes in the middle of a checkpoint: the data
+/// written after the last indexed value is never read. Values written again after a restart are
+/// appended again and shadow the previous ones.
+pub struct ArchiveStore {
+    writer: Mutex<DataWriter>,
+    reader: File,
+    index: ArchiveIndexTables,
+}
+
+impl ArchiveStore {
+    pub fn open(path: &std::path::Path) -> Result<Self> {
+        std::fs::create_dir_all(path)?;
+        let data_path = path.join(DATA_FILE_NAME);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&data_path)?;
+        let len = file.metadata()?.len();
+        let index = ArchiveIndexTables::open_tables_read_write(
+            path.join(INDEX_DIR_NAME),
+            MetricConf::new("archive"),
+            None,
+            None,
+        );
+        info!("Opened archive at {:?} with {} bytes of data", path, len);
+        Ok(Self {
+            writer: Mutex::new(DataWriter { file, len }),
+            reader: File::open(data_path)?,
+            index,
+        })
+    }
+
+    /// Appends the BCS encoded `values`, keyed by their `HttpKVStore` type and key.
+    pub fn append(&self, values: Vec<(&'static str, Vec<u8>, Vec<u8>)>) -> Result<()> {
+        let mut writer = self.writer.lock().unwrap();
+        let mut data = vec![];
+        let mut locations = vec![];
+        for (item_type, key, value) in values {
+            locations.push((
+                (item_type.to_string(), key),
+                Location {
+                    offset: writer.len + data.len() as u64,
+                    length: value.len() as u64,
+                },
+            ));
+            data.extend(value);
+        }
+        writer.file.write_all(&data)?;
+        writer.file.sync_data()?;
+        writer.len += data.len() as u64;
+
+        let mut batch = self.index.locations.batch();
+        batch.insert_batch(&self.index.locations, locations)?;
+        batch.write()?;
+        Ok(())
+    }
+
+    pub fn get(&self, item_type: &str, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let Some(location) = self
+            .index
+            .locations
+            .get(&(item_type.to_string(), key.to_vec()))?
+        else {
+            return Ok(None);
+        };
+        let mut value = vec![0; location.length as usize];
+        self.reader.read_exact_at(&mut value, location.offset)?;
+        Ok(Some(value))
+    }
+}
+
+/// Archives every transaction, effects, events, object version and checkpoint.
+#[derive(Clone)]
+pub struct ArchiveWorker {
+    store: Arc<ArchiveStore>,
+}
+
+impl ArchiveWorker {
+    pub fn new(store: Arc<ArchiveStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl Worker for ArchiveWorker {
+    async fn process_checkpoint(&self, checkpoint: CheckpointData) -> Result<()> {
+        let mut values = vec![];
+        let checkpoint_number = checkpoint.checkpoint_summary.sequence_number;
+        let checkpoint_number_bytes = bcs::to_bytes(&checkpoint_number)?;
+
+        for transaction in checkpoint.transactions {
+            let transaction_digest = transaction.transaction.digest().into_inner().to_vec();
+            values.push((
+                "tx",
+                transaction_digest.clone(),
+                bcs::to_bytes(&transaction.transaction)?,
+            ));
+            values.push((
+                "fx",
+                transaction_digest.clone(),
+                bcs::to_bytes(&transaction.effects)?,
+            ));
+            values.push(("tx2c", transaction_digest, checkpoint_number_bytes.clone()));
+            if let Some(tx_events) = transaction.events {
+                values.push((
+                    "ev",
+                    tx_events.digest().into_inner().to_vec(),
+                    bcs::to_bytes(&tx_events)?,
+                ));
+            }
+            for object in transaction.output_objects {
+                let object_key = ObjectKey(object.id(), object.version());
+                values.push(("ob", bcs::to_bytes(&object_key)?, bcs::to_bytes(&object)?));
+            }
+        }
+
+        let serialized_checkpoint_number =
+            bcs::to_bytes(&TaggedKey::CheckpointSequenceNumber(checkpoint_number))?;
+        let contents = bcs::to_bytes(&checkpoint.checkpoint_contents)?;
+        let summary = bcs::to_bytes(&checkpoint.checkpoint_summary)?;
+        values.push(("cc", serialized_checkpoint_number.clone(), contents.clone()));
+        values.push((
+            "cc",
+            checkpoint
+                .checkpoint_summary
+                .content_digest
+                .into_inner()
+                .to_vec(),
+            contents,
+        ));
+        values.push(("cs", serialized_checkpoint_number, summary.clone()));
+        values.push((
+            "cs",
+            checkpoint.checkpoint_summary.digest().into_inner().to_vec(),
+            summary,
+        ));
+
+        let store = self.store.clone();
+        tokio::task::spawn_blocking(move || store.append(values)).await?
+    }
+}
+
+/// Serves the archive at `GET /<base64url key>/<type>`, as expected by the `HttpKVStore` of the
+/// fullnodes, which fall back to it for the history they pruned.
+pub async fn serve_archive(address: SocketAddr, store: Arc<ArchiveStore>) -> Result<()> {
+    let app = Router::new()
+        .route("/:key/:item_type", get(get_archived_item))
+        .with_state(store);
+    info!("Serving the archive on {}", address);
+    axum::Server::bind(&address)
+        .serve(app.into_make_service())
+        .await?;
+    Ok(())
+}
+
+async fn get_archived_item(
+    State(store): State<Arc<ArchiveStore>>,
+    Path((key, item_type)): Path<(String, String)>,
+) -> Result<Vec<u8>, StatusCode> {
+    let key = base64_url::decode(&key).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let value = tokio::task::spawn_blocking(move || store.get(&item_type, &key))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|e| {
+            error!("Failed to read from the archive: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    value.ok_or(StatusCode::NOT_FOUND)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_store() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let store = ArchiveStore::open(dir.path())?;
+        store.append(vec![
+            ("tx", vec![1], vec![10, 11]),
+            ("fx", vec![1], vec![12]),
+        ])?;
+        store.append(vec![("tx", vec![2], vec![13, 14, 15])])?;
+        assert_eq!(store.get("tx", &[1])?, Some(vec![10, 11]));
+        assert_eq!(store.get("fx", &[1])?, Some(vec![12]));
+        assert_eq!(store.get("tx", &[2])?, Some(vec![13, 14, 15]));
+        assert_eq!(store.get("fx", &[2])?, None);
+        drop(store);
+
+        // simulate a crash after writing the data of a checkpoint, before indexing it
+        let mut data = OpenOptions::new()
+            .append(true)
+            .open(dir.path().join(DATA_FILE_NAME))?;
+        data.write_all(&[42, 0, 0, 0, 1])?;
+        drop(data);
+
+        let store = ArchiveStore::open(dir.path())?;
+        assert_eq!(store.get("tx", &[2])?, Some(vec![13, 14, 15]));
+        store.append(vec![("ev", vec![3], vec![16])])?;
+        drop(store);
+        let store = ArchiveStore::open(dir.path())?;
+        assert_eq!(store.get("tx", &[1])?, Some(vec![10, 11]));
+        assert_eq!(store.get("ev", &[3])?, Some(vec![16]));
+        Ok(())
+    }
+}
@@ -4,8 +4,10 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use sui_types::full_checkpoint_content::CheckpointData;
+mod archive;
 mod kv_store;
 mod s3;
+pub use archive::{serve_archive, ArchiveStore, ArchiveTaskConfig, ArchiveWorker};
 pub use kv_store::{KVStoreTaskConfig, KVStoreWorker};
 pub use s3::{S3TaskConfig, S3Worker};
 
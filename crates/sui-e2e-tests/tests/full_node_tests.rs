@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use fastcrypto::encoding::Base64;
 use futures::future;
 use jsonrpsee::core::client::{ClientT, Subscription, SubscriptionClientT};
 use jsonrpsee::rpc_params;
@@ -17,7 +18,10 @@ use sui_json_rpc_types::{
     type_and_fields_from_move_struct, EventPage, SuiEvent, SuiExecutionStatus,
     SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
 };
-use sui_json_rpc_types::{EventFilter, TransactionFilter};
+use sui_json_rpc_types::{
+    EventFilter, SignedTransactionBlockBytes, TransactionBlockStatus, TransactionBlockStatusUpdate,
+    TransactionFilter,
+};
 use sui_keys::keystore::AccountKeystore;
 use sui_macros::*;
 use sui_node::SuiNodeHandle;
@@ -969,6 +973,78 @@ async fn test_execute_tx_with_serialized_signature() -> Result<(), anyhow::Error
     Ok(())
 }
 
+#[sim_test]
+async fn test_full_node_sub_execute_transaction_blocks() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await;
+    let ws_client = test_cluster.fullnode_handle.ws_client().await;
+    let context = &mut test_cluster.wallet;
+
+    let txn_count = 4;
+    let txns = batch_make_transfer_transactions(context, txn_count).await;
+    let mut transactions: Vec<_> = txns
+        .iter()
+        .map(|txn| {
+            let (tx_bytes, signatures) = txn.to_tx_bytes_and_signatures();
+            SignedTransactionBlockBytes {
+                tx_bytes,
+                signatures,
+            }
+        })
+        .collect();
+    // A transaction that can't be deserialized fails without a digest.
+    transactions.push(SignedTransactionBlockBytes {
+        tx_bytes: Base64::from_bytes(&[0u8; 4]),
+        signatures: vec![],
+    });
+
+    let mut sub: Subscription<TransactionBlockStatusUpdate> = ws_client
+        .subscribe(
+            "sui_subscribeExecuteTransactionBlocks",
+            rpc_params![transactions],
+            "sui_unsubscribeExecuteTransactionBlocks",
+        )
+        .await
+        .unwrap();
+
+    // Accepted, certified and executed for each valid transaction, and failed for the last one.
+    let mut updates = vec![vec![]; txn_count + 1];
+    for _ in 0..txn_count * 3 + 1 {
+        let update = timeout(Duration::from_secs(30), sub.next())
+            .await?
+            .unwrap()?;
+        updates[update.index].push(update);
+    }
+
+    for (txn, updates) in txns.iter().zip(&updates) {
+        assert!(updates
+            .iter()
+            .all(|update| update.digest == Some(*txn.digest())));
+        let statuses: Vec<_> = updates.iter().map(|update| &update.status).collect();
+        assert!(
+            matches!(
+                statuses[..],
+                [
+                    TransactionBlockStatus::Accepted,
+                    TransactionBlockStatus::Certified { .. },
+                    TransactionBlockStatus::Executed {
+                        execution_status: SuiExecutionStatus::Success,
+                        confirmed_local_execution: true,
+                    },
+                ]
+            ),
+            "{statuses:?}"
+        );
+    }
+    let failed = &updates[txn_count];
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].digest, None);
+    assert!(matches!(
+        failed[0].status,
+        TransactionBlockStatus::Failed { .. }
+    ));
+    Ok(())
+}
+
 #[sim_test]
 async fn test_full_node_transaction_orchestrator_rpc_ok() -> Result<(), anyhow::Error> {
     let mut test_cluster = TestClusterBuilder::new().build().await;
@@ -1389,6 +1465,25 @@ async fn transfer_coin(
     Ok((object_to_send.0, sender, receiver, resp.digest, gas_object))
 }
 
+#[sim_test]
+async fn test_get_committee_with_network_metadata() -> Result<(), anyhow::Error> {
+    let test_cluster = TestClusterBuilder::new().build().await;
+
+    let committee = test_cluster
+        .sui_client()
+        .governance_api()
+        .get_committee_with_network_metadata()
+        .await?;
+
+    assert_eq!(committee.committee, *test_cluster.committee());
+    assert_eq!(committee.network_metadata.len(), 4);
+    for validator in test_cluster.swarm.active_validators() {
+        let metadata = &committee.network_metadata[&validator.name()];
+        assert_eq!(metadata.network_address, validator.config.network_address);
+    }
+    Ok(())
+}
+
 #[sim_test]
 async fn test_full_node_run_with_range_checkpoint() -> Result<(), anyhow::Error> {
     telemetry_subscribers::init_for_testing();
@@ -35,6 +35,7 @@ async fn test_onsite_reconfig_observer_basic() {
         fullnode.with(|node| node.clone_committee_store()),
         SafeClientMetricsBase::new(&registry),
         AuthAggMetrics::new(&registry),
+        None,
     );
     let qd_clone = qd.clone_quorum_driver();
     let observer_handle = tokio::task::spawn(async move { observer.run(qd_clone).await });
@@ -320,6 +320,56 @@ mod sim_only_tests {
         assert_eq!(Some(SequenceNumber::from(2)), mutated_to);
     }
 
+    #[sim_test]
+    async fn test_framework_upgrade_preserves_signed_transactions() {
+        // System packages are upgraded in-place, so a transaction signed against the old framework
+        // must still execute, with the same effects, once the framework has been upgraded.
+        let cluster = run_framework_upgrade("base", "compatible").await;
+        let context = &cluster.wallet;
+        let (sender, gas_objects) = context.get_one_account().await.unwrap();
+        let rgp = context.get_reference_gas_price().await.unwrap();
+
+        let [before_gas, after_gas, ..] = gas_objects[..] else {
+            panic!("Expected at least two gas objects for {sender}");
+        };
+        let [before, after] = [before_gas, after_gas].map(|gas_object| {
+            let mut builder = ProgrammableTransactionBuilder::new();
+            builder
+                .move_call(
+                    SUI_SYSTEM_PACKAGE_ID,
+                    ident_str!("msim_extra_1").to_owned(),
+                    ident_str!("entry_fun").to_owned(),
+                    /* type_arguments */ vec![],
+                    /* call_args */ vec![],
+                )
+                .unwrap();
+            context.sign_transaction(&TransactionData::new_programmable(
+                sender,
+                vec![gas_object],
+                builder.finish(),
+                rgp * TEST_ONLY_GAS_UNIT_FOR_GENERIC,
+                rgp,
+            ))
+        });
+
+        let before = context
+            .execute_transaction_must_succeed(before)
+            .await
+            .effects
+            .unwrap();
+        expect_upgrade_succeeded(&cluster).await;
+        let after = context
+            .execute_transaction_must_succeed(after)
+            .await
+            .effects
+            .unwrap();
+
+        assert_eq!(before.status(), after.status());
+        assert_eq!(before.created().len(), after.created().len());
+        assert_eq!(before.mutated().len(), after.mutated().len());
+        assert_eq!(before.deleted().len(), after.deleted().len());
+    }
+
     #[sim_test]
     async fn test_framework_incompatible_struct_layout() {
         // Upgrade attempts to change an existing struct layout
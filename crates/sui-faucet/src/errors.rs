@@ -40,6 +40,9 @@ pub enum FaucetError {
     #[error("Coin amounts sent are incorrect:`{0}`")]
     CoinAmountTransferredIncorrect(String),
 
+    #[error("Too many requests from {0}, please try again later.")]
+    TooManyRequests(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -114,6 +114,26 @@ pub struct FaucetConfig {
 
     #[clap(long, action = clap::ArgAction::Set, default_value_t = false)]
     pub batch_enabled: bool,
+
+    /// Maximum number of requests a single IP can make per rate limit window. Unlimited if
+    /// not set.
+    #[clap(long)]
+    pub max_requests_per_ip: Option<u64>,
+
+    /// Maximum number of requests for a single recipient address per rate limit window.
+    /// Unlimited if not set.
+    #[clap(long)]
+    pub max_requests_per_address: Option<u64>,
+
+    /// Length of the per IP and per address rate limit window, in seconds.
+    #[clap(long, default_value_t = 3600)]
+    pub rate_limit_window_secs: u64,
+
+    /// Header holding the IP of the client, e.g. X-Forwarded-For, used for the per IP rate limit
+    /// instead of the IP of the connection. Only set it when the faucet is behind a reverse proxy
+    /// that appends the client IP to that header, since clients can set it to anything.
+    #[clap(long)]
+    pub client_ip_header: Option<String>,
 }
 
 impl Default for FaucetConfig {
@@ -132,6 +152,10 @@ impl Default for FaucetConfig {
             batch_request_size: 500,
             ttl_expiration: 300,
             batch_enabled: false,
+            max_requests_per_ip: None,
+            max_requests_per_address: None,
+            rate_limit_window_secs: 3600,
+            client_ip_header: None,
         }
     }
 }
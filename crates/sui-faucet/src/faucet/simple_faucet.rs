@@ -1927,6 +1927,9 @@ mod tests {
         // Get the latest list of gas
         let results = SuiClientCommands::Gas {
             address: Some(KeyIdentity::Address(address)),
+            split: None,
+            merge: false,
+            gas_budget: None,
         }
         .execute(context)
         .await
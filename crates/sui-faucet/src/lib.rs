@@ -4,6 +4,7 @@
 mod errors;
 mod faucet;
 mod metrics;
+mod rate_limiter;
 mod requests;
 mod responses;
 
@@ -12,5 +13,6 @@ pub use metrics_layer::*;
 
 pub use errors::FaucetError;
 pub use faucet::*;
+pub use rate_limiter::RequestLimiter;
 pub use requests::*;
 pub use responses::*;
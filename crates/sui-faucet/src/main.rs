@@ -3,14 +3,14 @@
 
 use axum::{
     error_handling::HandleErrorLayer,
-    extract::Path,
+    extract::{ConnectInfo, Path},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
     BoxError, Extension, Json, Router,
 };
 use clap::Parser;
-use http::Method;
+use http::{HeaderMap, Method};
 use mysten_metrics::spawn_monitored_task;
 use std::env;
 use std::{
@@ -22,7 +22,7 @@ use std::{
 use sui_config::{sui_config_dir, SUI_CLIENT_CONFIG};
 use sui_faucet::{
     BatchFaucetResponse, BatchStatusFaucetResponse, Faucet, FaucetConfig, FaucetError,
-    FaucetRequest, FaucetResponse, RequestMetricsLayer, SimpleFaucet,
+    FaucetRequest, FaucetResponse, RequestLimiter, RequestMetricsLayer, SimpleFaucet,
 };
 use sui_sdk::wallet_context::WalletContext;
 use tower::{limit::RateLimitLayer, ServiceBuilder};
@@ -35,6 +35,7 @@ const CONCURRENCY_LIMIT: usize = 30;
 struct AppState<F = Arc<SimpleFaucet>> {
     faucet: F,
     config: FaucetConfig,
+    limiter: RequestLimiter,
 }
 
 const PROM_PORT_ADDR: &str = "0.0.0.0:9184";
@@ -61,9 +62,14 @@ async fn main() -> Result<(), anyhow::Error> {
         wallet_client_timeout_secs,
         ref write_ahead_log,
         wal_retry_interval,
+        max_requests_per_ip,
+        max_requests_per_address,
+        rate_limit_window_secs,
         ..
     } = config;
 
+    let client_ip_header = config.client_ip_header.clone();
+
     let context = create_wallet_context(wallet_client_timeout_secs).await?;
 
     let prom_binding = PROM_PORT_ADDR.parse().unwrap();
@@ -80,6 +86,13 @@ async fn main() -> Result<(), anyhow::Error> {
         .await
         .unwrap(),
         config,
+        limiter: RequestLimiter::new(
+            max_requests_per_ip,
+            max_requests_per_address,
+            Duration::from_secs(rate_limit_window_secs),
+            client_ip_header,
+            &prometheus_registry,
+        ),
     });
 
     // TODO: restrict access if needed
@@ -121,7 +134,7 @@ async fn main() -> Result<(), anyhow::Error> {
     let addr = SocketAddr::new(IpAddr::V4(host_ip), port);
     info!("listening on {}", addr);
     axum::Server::bind(&addr)
-        .serve(app.into_make_service())
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await?;
     Ok(())
 }
@@ -134,6 +147,8 @@ async fn health() -> &'static str {
 /// handler for batch_request_gas requests
 async fn batch_request_gas(
     Extension(state): Extension<Arc<AppState>>,
+    ConnectInfo(client): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(payload): Json<FaucetRequest>,
 ) -> impl IntoResponse {
     let id = Uuid::new_v4();
@@ -149,6 +164,17 @@ async fn batch_request_gas(
         );
     };
 
+    if let Err(e) = state.limiter.check(
+        state.limiter.client_ip(&headers, client.ip()),
+        request.recipient,
+    ) {
+        warn!(uuid =?id, "Request rate limited: {e}");
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(BatchFaucetResponse::from(e)),
+        );
+    }
+
     if state.config.batch_enabled {
         let result = spawn_monitored_task!(async move {
             state
@@ -239,6 +265,8 @@ async fn request_status(
 /// handler for all the request_gas requests
 async fn request_gas(
     Extension(state): Extension<Arc<AppState>>,
+    ConnectInfo(client): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(payload): Json<FaucetRequest>,
 ) -> impl IntoResponse {
     // ID for traceability
@@ -246,6 +274,13 @@ async fn request_gas(
     info!(uuid = ?id, "Got new gas request.");
     let result = match payload {
         FaucetRequest::FixedAmountRequest(requests) => {
+            if let Err(e) = state.limiter.check(
+                state.limiter.client_ip(&headers, client.ip()),
+                requests.recipient,
+            ) {
+                warn!(uuid =?id, "Request rate limited: {e}");
+                return (StatusCode::TOO_MANY_REQUESTS, Json(FaucetResponse::from(e)));
+            }
             // We spawn a tokio task for this such that connection drop will not interrupt
             // it and impact the recycling of coins
             spawn_monitored_task!(async move {
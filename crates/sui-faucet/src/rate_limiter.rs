@@ -0,0 +1,292 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use http::HeaderMap;
+use parking_lot::Mutex;
+use prometheus::{register_int_counter_with_registry, IntCounter, Registry};
+use sui_types::base_types::SuiAddress;
+
+use crate::FaucetError;
+
+/// Limits how many requests a single client IP and a single recipient address can make over a
+/// fixed time window, on top of the global rate limit of the service.
+pub struct RequestLimiter {
+    max_requests_per_ip: Option<u64>,
+    max_requests_per_address: Option<u64>,
+    window: Duration,
+    client_ip_header: Option<String>,
+    state: Mutex<LimiterState>,
+    total_requests_rate_limited: IntCounter,
+}
+
+#[derive(Default)]
+struct LimiterState {
+    per_ip: Windows<IpAddr>,
+    per_address: Windows<SuiAddress>,
+}
+
+/// The current window of each key, and the keys in the order their windows started, so that
+/// expired windows are pruned a few at a time, as they expire.
+struct Windows<K> {
+    current: HashMap<K, Window>,
+    starts: VecDeque<(Instant, K)>,
+}
+
+#[derive(Clone, Copy)]
+struct Window {
+    start: Instant,
+    count: u64,
+}
+
+impl RequestLimiter {
+    /// A limit of `None` disables limiting on that key. If `client_ip_header` is set, the IP of
+    /// the client is read from that header, e.g. `X-Forwarded-For`, which must be set by a
+    /// trusted proxy in front of the faucet.
+    pub fn new(
+        max_requests_per_ip: Option<u64>,
+        max_requests_per_address: Option<u64>,
+        window: Duration,
+        client_ip_header: Option<String>,
+        registry: &Registry,
+    ) -> Self {
+        Self {
+            max_requests_per_ip,
+            max_requests_per_address,
+            window,
+            client_ip_header,
+            state: Mutex::new(LimiterState::default()),
+            total_requests_rate_limited: register_int_counter_with_registry!(
+                "total_requests_rate_limited",
+                "Total number of requests rejected by the per IP or per address rate limit",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+
+    /// The IP of the client of a request received from `peer`. When the client IP header is
+    /// configured, this is the last address of the header, the one appended by the trusted
+    /// proxy: the previous ones are set by the client and can be forged. Falls back to `peer` if
+    /// the header is missing or invalid.
+    pub fn client_ip(&self, headers: &HeaderMap, peer: IpAddr) -> IpAddr {
+        self.client_ip_header
+            .as_ref()
+            .and_then(|header| headers.get_all(header.as_str()).iter().last())
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').next())
+            .and_then(|ip| ip.trim().parse().ok())
+            .unwrap_or(peer)
+    }
+
+    /// Record a request from `ip` for `recipient`. Returns an error, without recording the
+    /// request, if either the IP or the address has exhausted its requests for the window.
+    pub fn check(&self, ip: IpAddr, recipient: SuiAddress) -> Result<(), FaucetError> {
+        self.check_at(ip, recipient, Instant::now())
+    }
+
+    fn check_at(&self, ip: IpAddr, recipient: SuiAddress, now: Instant) -> Result<(), FaucetError> {
+        let mut state = self.state.lock();
+        let LimiterState {
+            per_ip,
+            per_address,
+        } = &mut *state;
+        per_ip.prune(now, self.window);
+        per_address.prune(now, self.window);
+
+        let ip_window = per_ip.get(ip, now, self.window);
+        if exceeded(ip_window, self.max_requests_per_ip) {
+            self.total_requests_rate_limited.inc();
+            return Err(FaucetError::TooManyRequests(format!("IP {ip}")));
+        }
+        let address_window = per_address.get(recipient, now, self.window);
+        if exceeded(address_window, self.max_requests_per_address) {
+            self.total_requests_rate_limited.inc();
+            return Err(FaucetError::TooManyRequests(format!("address {recipient}")));
+        }
+
+        if self.max_requests_per_ip.is_some() {
+            per_ip.record(ip, ip_window);
+        }
+        if self.max_requests_per_address.is_some() {
+            per_address.record(recipient, address_window);
+        }
+        Ok(())
+    }
+}
+
+impl<K> Default for Windows<K> {
+    fn default() -> Self {
+        Self {
+            current: HashMap::new(),
+            starts: VecDeque::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Copy> Windows<K> {
+    /// The window of `key` at `now`, starting a new one if the previous window has elapsed.
+    fn get(&self, key: K, now: Instant, length: Duration) -> Window {
+        match self.current.get(&key) {
+            Some(w) if now.duration_since(w.start) < length => *w,
+            _ => Window {
+                start: now,
+                count: 0,
+            },
+        }
+    }
+
+    fn record(&mut self, key: K, window: Window) {
+        if window.count == 0 {
+            self.starts.push_back((window.start, key));
+        }
+        self.current.insert(
+            key,
+            Window {
+                count: window.count + 1,
+                ..window
+            },
+        );
+    }
+
+    /// Drops the windows elapsed at `now`. Each window is popped once, so pruning costs O(1)
+    /// amortized per request.
+    fn prune(&mut self, now: Instant, length: Duration) {
+        while let Some((start, key)) = self.starts.front().copied() {
+            if now.duration_since(start) < length {
+                break;
+            }
+            self.starts.pop_front();
+            // The key may have started a new window since.
+            if self.current.get(&key).is_some_and(|w| w.start == start) {
+                self.current.remove(&key);
+            }
+        }
+    }
+}
+
+fn exceeded(window: Window, limit: Option<u64>) -> bool {
+    limit.map_or(false, |limit| window.count >= limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+    use sui_types::base_types::ObjectID;
+
+    #[test]
+    fn test_limits_per_ip_and_address() {
+        let limiter = RequestLimiter::new(
+            Some(3),
+            Some(2),
+            Duration::from_secs(60),
+            None,
+            &Registry::new(),
+        );
+        let ip1 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let ip2 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let addr1 = SuiAddress::random_for_testing_only();
+        let addr2 = SuiAddress::random_for_testing_only();
+        let addr3 = SuiAddress::random_for_testing_only();
+        let now = Instant::now();
+
+        // Address limit.
+        assert!(limiter.check_at(ip1, addr1, now).is_ok());
+        assert!(limiter.check_at(ip2, addr1, now).is_ok());
+        assert_eq!(
+            limiter.check_at(ip2, addr1, now),
+            Err(FaucetError::TooManyRequests(format!("address {addr1}")))
+        );
+
+        // IP limit.
+        assert!(limiter.check_at(ip1, addr2, now).is_ok());
+        assert!(limiter.check_at(ip1, addr3, now).is_ok());
+        assert_eq!(
+            limiter.check_at(ip1, addr3, now),
+            Err(FaucetError::TooManyRequests(format!("IP {ip1}")))
+        );
+        assert!(limiter.check_at(ip2, addr3, now).is_ok());
+
+        // Limits are reset once the window elapsed.
+        let later = now + Duration::from_secs(61);
+        assert!(limiter.check_at(ip1, addr1, later).is_ok());
+        assert_eq!(limiter.total_requests_rate_limited.get(), 2);
+    }
+
+    #[test]
+    fn test_disabled_limits() {
+        let limiter =
+            RequestLimiter::new(None, None, Duration::from_secs(60), None, &Registry::new());
+        let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let addr = SuiAddress::random_for_testing_only();
+        for _ in 0..100 {
+            assert!(limiter.check(ip, addr).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_elapsed_windows_are_pruned() {
+        let limiter = RequestLimiter::new(
+            Some(1),
+            Some(1),
+            Duration::from_secs(60),
+            None,
+            &Registry::new(),
+        );
+        let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let now = Instant::now();
+        for i in 0..10u8 {
+            let addr = SuiAddress::from(ObjectID::from_single_byte(i));
+            let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, i));
+            assert!(limiter.check_at(ip, addr, now).is_ok());
+        }
+        {
+            let state = limiter.state.lock();
+            assert_eq!(state.per_ip.current.len(), 10);
+            assert_eq!(state.per_address.current.len(), 10);
+        }
+
+        let addr = SuiAddress::from(ObjectID::from_single_byte(42));
+        assert!(limiter
+            .check_at(ip, addr, now + Duration::from_secs(61))
+            .is_ok());
+        let state = limiter.state.lock();
+        assert_eq!(state.per_ip.current.len(), 1);
+        assert_eq!(state.per_address.current.len(), 1);
+        assert_eq!(state.per_ip.starts.len(), 1);
+    }
+
+    #[test]
+    fn test_client_ip() {
+        let peer = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            "1.1.1.1, 2.2.2.2, 3.3.3.3".parse().unwrap(),
+        );
+
+        let untrusted =
+            RequestLimiter::new(None, None, Duration::from_secs(60), None, &Registry::new());
+        assert_eq!(untrusted.client_ip(&headers, peer), peer);
+
+        let trusted = RequestLimiter::new(
+            None,
+            None,
+            Duration::from_secs(60),
+            Some("X-Forwarded-For".to_string()),
+            &Registry::new(),
+        );
+        assert_eq!(
+            trusted.client_ip(&headers, peer),
+            IpAddr::V4(Ipv4Addr::new(3, 3, 3, 3))
+        );
+        assert_eq!(trusted.client_ip(&HeaderMap::new(), peer), peer);
+        headers.insert("x-forwarded-for", "not an ip".parse().unwrap());
+        assert_eq!(trusted.client_ip(&headers, peer), peer);
+    }
+}
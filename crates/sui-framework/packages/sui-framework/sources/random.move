@@ -4,9 +4,13 @@
 #[allow(unused_use)]
 // This module provides functionality for generating and using secure randomness.
 //
-// Randomness is currently write-only, until user-facing API is implemented.
+// Randomness is read through a `RandomGenerator`, created from the `Random` object, which derives
+// fresh random values from the randomness of the current round.
 module sui::random {
+    use std::bcs;
     use std::vector;
+    use sui::address;
+    use sui::hmac::hmac_sha3_256;
     use sui::object::{Self, UID};
     use sui::transfer;
     use sui::tx_context::{Self, TxContext};
@@ -16,9 +20,15 @@ module sui::random {
     const ENotSystemAddress: u64 = 0;
     const EWrongInnerVersion: u64 = 1;
     const EInvalidRandomnessUpdate: u64 = 2;
+    const EInvalidRange: u64 = 3;
+    const EInvalidLength: u64 = 4;
 
     const CURRENT_VERSION: u64 = 1;
 
+    // Number of bytes of randomness generated on top of the size of a range, to reduce the bias
+    // of taking the random value modulo the size of the range.
+    const RANGE_EXTRA_BYTES: u8 = 8;
+
     /// Singleton shared object which stores the global randomness state.
     /// The actual state is stored in a versioned inner field.
     struct Random has key {
@@ -74,7 +84,6 @@ module sui::random {
         inner
     }
 
-    #[allow(unused_function)] // TODO: remove annotation after implementing user-facing API
     fun load_inner(
         self: &Random,
     ): &RandomInner {
@@ -129,4 +138,174 @@ module sui::random {
     ) {
         update_randomness_state(self, new_round, new_bytes, ctx);
     }
+
+    /// Unique randomness generator, derived from the global randomness.
+    struct RandomGenerator has drop {
+        seed: vector<u8>,
+        counter: u16,
+        buffer: vector<u8>,
+    }
+
+    /// Create a generator. Every generator of a transaction derives different values.
+    ///
+    /// Functions using randomness should be non-public entry functions, otherwise other Move code
+    /// could call them and abort whenever it does not like the outcome. For the same reason, a
+    /// programmable transaction can only follow a command using Random with TransferObjects or
+    /// MergeCoins commands.
+    public fun new_generator(r: &Random, ctx: &mut TxContext): RandomGenerator {
+        let inner = load_inner(r);
+        let seed = hmac_sha3_256(
+            &inner.random_bytes,
+            &address::to_bytes(tx_context::fresh_object_address(ctx))
+        );
+        RandomGenerator { seed, counter: 0, buffer: vector[] }
+    }
+
+    // Get the next block of 32 random bytes.
+    fun derive_next_block(g: &mut RandomGenerator): vector<u8> {
+        g.counter = g.counter + 1;
+        hmac_sha3_256(&g.seed, &bcs::to_bytes(&g.counter))
+    }
+
+    /// Generate n random bytes.
+    public fun generate_bytes(g: &mut RandomGenerator, num_of_bytes: u16): vector<u8> {
+        let result = vector[];
+        // Append the full blocks of 32 bytes.
+        let num_of_blocks = num_of_bytes / 32;
+        while (num_of_blocks > 0) {
+            vector::append(&mut result, derive_next_block(g));
+            num_of_blocks = num_of_blocks - 1;
+        };
+        // Fill the buffer if needed, and take the remaining bytes from it.
+        let remaining = ((num_of_bytes % 32) as u64);
+        if (vector::length(&g.buffer) < remaining) {
+            let next_block = derive_next_block(g);
+            vector::append(&mut g.buffer, next_block);
+        };
+        while (remaining > 0) {
+            vector::push_back(&mut result, vector::pop_back(&mut g.buffer));
+            remaining = remaining - 1;
+        };
+        result
+    }
+
+    // Extract the given number of random bytes as a u256.
+    fun u256_from_bytes(g: &mut RandomGenerator, num_of_bytes: u8): u256 {
+        assert!(num_of_bytes <= 32, EInvalidLength);
+        if (vector::length(&g.buffer) < (num_of_bytes as u64)) {
+            let next_block = derive_next_block(g);
+            vector::append(&mut g.buffer, next_block);
+        };
+        let result: u256 = 0;
+        let i = 0;
+        while (i < num_of_bytes) {
+            let byte = vector::pop_back(&mut g.buffer);
+            result = (result << 8) + (byte as u256);
+            i = i + 1;
+        };
+        result
+    }
+
+    /// Generate a u256.
+    public fun generate_u256(g: &mut RandomGenerator): u256 {
+        u256_from_bytes(g, 32)
+    }
+
+    /// Generate a u128.
+    public fun generate_u128(g: &mut RandomGenerator): u128 {
+        (u256_from_bytes(g, 16) as u128)
+    }
+
+    /// Generate a u64.
+    public fun generate_u64(g: &mut RandomGenerator): u64 {
+        (u256_from_bytes(g, 8) as u64)
+    }
+
+    /// Generate a u32.
+    public fun generate_u32(g: &mut RandomGenerator): u32 {
+        (u256_from_bytes(g, 4) as u32)
+    }
+
+    /// Generate a u16.
+    public fun generate_u16(g: &mut RandomGenerator): u16 {
+        (u256_from_bytes(g, 2) as u16)
+    }
+
+    /// Generate a u8.
+    public fun generate_u8(g: &mut RandomGenerator): u8 {
+        (u256_from_bytes(g, 1) as u8)
+    }
+
+    /// Generate a boolean.
+    public fun generate_bool(g: &mut RandomGenerator): bool {
+        (u256_from_bytes(g, 1) & 1) == 1
+    }
+
+    // Generate a random number in [min, max], from num_of_bytes bytes on top of the extra ones.
+    fun u128_in_range(g: &mut RandomGenerator, min: u128, max: u128, num_of_bytes: u8): u128 {
+        assert!(min <= max, EInvalidRange);
+        if (min == max) {
+            return min
+        };
+        let range_size = ((max - min) as u256) + 1;
+        let rand = u256_from_bytes(g, num_of_bytes + RANGE_EXTRA_BYTES);
+        min + ((rand % range_size) as u128)
+    }
+
+    /// Generate a random u128 in [min, max] (with a bias of 2^{-64}).
+    public fun generate_u128_in_range(g: &mut RandomGenerator, min: u128, max: u128): u128 {
+        u128_in_range(g, min, max, 16)
+    }
+
+    /// Generate a random u64 in [min, max] (with a bias of 2^{-64}).
+    public fun generate_u64_in_range(g: &mut RandomGenerator, min: u64, max: u64): u64 {
+        (u128_in_range(g, (min as u128), (max as u128), 8) as u64)
+    }
+
+    /// Generate a random u32 in [min, max] (with a bias of 2^{-64}).
+    public fun generate_u32_in_range(g: &mut RandomGenerator, min: u32, max: u32): u32 {
+        (u128_in_range(g, (min as u128), (max as u128), 4) as u32)
+    }
+
+    /// Generate a random u16 in [min, max] (with a bias of 2^{-64}).
+    public fun generate_u16_in_range(g: &mut RandomGenerator, min: u16, max: u16): u16 {
+        (u128_in_range(g, (min as u128), (max as u128), 2) as u16)
+    }
+
+    /// Generate a random u8 in [min, max] (with a bias of 2^{-64}).
+    public fun generate_u8_in_range(g: &mut RandomGenerator, min: u8, max: u8): u8 {
+        (u128_in_range(g, (min as u128), (max as u128), 1) as u8)
+    }
+
+    /// Shuffle a vector using the random generator (Fisher–Yates/Knuth shuffle).
+    public fun shuffle<T>(g: &mut RandomGenerator, v: &mut vector<T>) {
+        let n = vector::length(v);
+        if (n == 0) {
+            return
+        };
+        assert!(n <= 0xFFFF, EInvalidLength);
+        let n = (n as u16);
+        let i: u16 = 0;
+        let end = n - 1;
+        while (i < end) {
+            let j = generate_u16_in_range(g, i, end);
+            vector::swap(v, (i as u64), (j as u64));
+            i = i + 1;
+        };
+    }
+
+    #[test_only]
+    public fun generator_seed(r: &RandomGenerator): &vector<u8> {
+        &r.seed
+    }
+
+    #[test_only]
+    public fun generator_counter(r: &RandomGenerator): u16 {
+        r.counter
+    }
+
+    #[test_only]
+    public fun generator_buffer(r: &RandomGenerator): &vector<u8> {
+        &r.buffer
+    }
 }
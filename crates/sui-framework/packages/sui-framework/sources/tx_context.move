@@ -57,6 +57,11 @@ module sui::tx_context {
        self.epoch_timestamp_ms
     }
 
+    /// Return the gas price of the current transaction, in MIST per unit of gas.
+    public fun gas_price(_self: &TxContext): u64 {
+        native_gas_price()
+    }
+
     /// Create an `address` that has not been used. As it is an object address, it will never
     /// occur as the address for a user.
     /// In other words, the generated address is a globally unique object ID.
@@ -77,6 +82,9 @@ module sui::tx_context {
     /// Native function for deriving an ID via hash(tx_hash || ids_created)
     native fun derive_id(tx_hash: vector<u8>, ids_created: u64): address;
 
+    /// Native function returning the gas price of the current transaction
+    native fun native_gas_price(): u64;
+
     // ==== test-only functions ====
 
     #[test_only]
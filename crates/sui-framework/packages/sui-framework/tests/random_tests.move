@@ -11,7 +11,10 @@ module sui::random_tests {
     use sui::random::{
         Self,
         Random,
-        update_randomness_state_for_testing,
+        update_randomness_state_for_testing, new_generator, generator_seed, generator_counter,
+        generator_buffer, generate_bytes, generate_u256, generate_u128, generate_u64, generate_u32,
+        generate_u16, generate_u8, generate_bool, generate_u128_in_range, generate_u64_in_range,
+        generate_u32_in_range, generate_u16_in_range, generate_u8_in_range, shuffle,
     };
 
     #[test]
@@ -30,8 +33,6 @@ module sui::random_tests {
             test_scenario::ctx(scenario)
         );
 
-        // TODO: Add more once user-facing API is implemented.
-
         test_scenario::return_shared(random_state);
         test_scenario::end(scenario_val);
     }
@@ -124,4 +125,123 @@ module sui::random_tests {
         test_scenario::return_shared(random_state);
         test_scenario::end(scenario_val);
     }
+
+    #[test]
+    fun random_tests_generator() {
+        let scenario_val = test_scenario::begin(@0x0);
+        let scenario = &mut scenario_val;
+
+        random::create_for_testing(test_scenario::ctx(scenario));
+        test_scenario::next_tx(scenario, @0x0);
+
+        let random_state = test_scenario::take_shared<Random>(scenario);
+        update_randomness_state_for_testing(
+            &mut random_state,
+            0,
+            x"1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F",
+            test_scenario::ctx(scenario)
+        );
+
+        // Generators of the same transaction derive different seeds.
+        let gen1 = new_generator(&random_state, test_scenario::ctx(scenario));
+        let gen2 = new_generator(&random_state, test_scenario::ctx(scenario));
+        assert!(generator_seed(&gen1) != generator_seed(&gen2), 0);
+        assert!(generator_counter(&gen1) == 0, 1);
+        assert!(vector::is_empty(generator_buffer(&gen1)), 2);
+
+        // Bytes are taken from full blocks first, and the rest from the buffer.
+        let bytes = generate_bytes(&mut gen1, 40);
+        assert!(vector::length(&bytes) == 40, 3);
+        assert!(generator_counter(&gen1) == 2, 4);
+        assert!(vector::length(generator_buffer(&gen1)) == 24, 5);
+        let bytes = generate_bytes(&mut gen1, 0);
+        assert!(vector::is_empty(&bytes), 6);
+
+        // Numbers consume their size from the buffer.
+        let _ = generate_u128(&mut gen1);
+        assert!(vector::length(generator_buffer(&gen1)) == 8, 7);
+        let _ = generate_u64(&mut gen1);
+        assert!(vector::length(generator_buffer(&gen1)) == 0, 8);
+        let _ = generate_u256(&mut gen1);
+        let _ = generate_u32(&mut gen1);
+        let _ = generate_u16(&mut gen1);
+        let _ = generate_u8(&mut gen1);
+        let _ = generate_bool(&mut gen1);
+
+        test_scenario::return_shared(random_state);
+        test_scenario::end(scenario_val);
+    }
+
+    #[test]
+    fun random_tests_in_range() {
+        let scenario_val = test_scenario::begin(@0x0);
+        let scenario = &mut scenario_val;
+
+        random::create_for_testing(test_scenario::ctx(scenario));
+        test_scenario::next_tx(scenario, @0x0);
+
+        let random_state = test_scenario::take_shared<Random>(scenario);
+        update_randomness_state_for_testing(
+            &mut random_state,
+            0,
+            x"1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F1F",
+            test_scenario::ctx(scenario)
+        );
+
+        let gen = new_generator(&random_state, test_scenario::ctx(scenario));
+        let i = 0;
+        while (i < 50) {
+            let n = generate_u128_in_range(&mut gen, 10, 20);
+            assert!(n >= 10 && n <= 20, 0);
+            let n = generate_u64_in_range(&mut gen, 0, 1);
+            assert!(n <= 1, 1);
+            let n = generate_u32_in_range(&mut gen, 7, 7);
+            assert!(n == 7, 2);
+            let n = generate_u16_in_range(&mut gen, 100, 0xFFFF);
+            assert!(n >= 100, 3);
+            let n = generate_u8_in_range(&mut gen, 0, 255);
+            assert!(n <= 255, 4);
+            i = i + 1;
+        };
+
+        // Shuffling keeps the elements of the vector.
+        let v = vector[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        shuffle(&mut gen, &mut v);
+        assert!(vector::length(&v) == 10, 5);
+        let i = 0;
+        while (i < 10) {
+            assert!(vector::contains(&v, &i), 6);
+            i = i + 1;
+        };
+        let empty = vector<u64>[];
+        shuffle(&mut gen, &mut empty);
+        assert!(vector::is_empty(&empty), 7);
+
+        test_scenario::return_shared(random_state);
+        test_scenario::end(scenario_val);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = random::EInvalidRange)]
+    fun random_tests_invalid_range() {
+        let scenario_val = test_scenario::begin(@0x0);
+        let scenario = &mut scenario_val;
+
+        random::create_for_testing(test_scenario::ctx(scenario));
+        test_scenario::next_tx(scenario, @0x0);
+
+        let random_state = test_scenario::take_shared<Random>(scenario);
+        update_randomness_state_for_testing(
+            &mut random_state,
+            0,
+            vector[0, 1, 2, 3],
+            test_scenario::ctx(scenario)
+        );
+
+        let gen = new_generator(&random_state, test_scenario::ctx(scenario));
+        generate_u64_in_range(&mut gen, 2, 1);
+
+        test_scenario::return_shared(random_state);
+        test_scenario::end(scenario_val);
+    }
 }
@@ -21,4 +21,10 @@ module sui::tx_context_tests {
         object::delete(id2);
     }
 
+    #[test]
+    fun test_gas_price() {
+        let ctx = tx_context::dummy();
+        // the gas price of Move unit tests
+        assert!(tx_context::gas_price(&ctx) == 1, 0);
+    }
 }
@@ -86,11 +86,12 @@ impl Checkpoint {
                 .map_err(|e| Error::Internal(format!("Error deserializing commitments: {e}")))
                 .extend()?
                 .into_iter()
-                .map(|commitment| {
-                    let C::ECMHLiveObjectSetDigest(digest) = commitment;
-                    Base58::encode(digest.digest.into_inner())
-                })
-                .next(),
+                .find_map(|commitment| match commitment {
+                    C::ECMHLiveObjectSetDigest(digest) => {
+                        Some(Base58::encode(digest.digest.into_inner()))
+                    }
+                    C::CheckpointContentsMerkleRoot(_) => None,
+                }),
         )
     }
 
@@ -0,0 +1,11 @@
+-- Balance of each coin type owned by an address, folded from the live objects table.
+CREATE VIEW balances AS
+SELECT
+    owner_id,
+    coin_type,
+    CAST(SUM(coin_balance) AS bigint)    AS balance,
+    CAST(COUNT(*) AS bigint)             AS coin_num
+FROM objects
+-- OwnerType: 1: Address, see types_v2.rs
+WHERE owner_type = 1 AND owner_id IS NOT NULL AND coin_type IS NOT NULL
+GROUP BY owner_id, coin_type;
@@ -0,0 +1,15 @@
+-- Single row table recording the last checkpoint committed by the writer.
+-- It is updated in the same DB transaction as the data of the checkpoint,
+-- so all rows of checkpoints up to the watermark are visible, and none after it.
+CREATE TABLE checkpoint_watermark (
+    id                          smallint      PRIMARY KEY CHECK (id = 0),
+    checkpoint_sequence_number  bigint        NOT NULL,
+    epoch                       bigint        NOT NULL,
+    network_total_transactions  bigint        NOT NULL,
+    timestamp_ms                bigint        NOT NULL
+);
+
+-- Databases written before the watermark existed resume from their last checkpoint.
+INSERT INTO checkpoint_watermark
+SELECT 0, sequence_number, epoch, network_total_transactions, timestamp_ms
+FROM checkpoints ORDER BY sequence_number DESC LIMIT 1;
@@ -0,0 +1,28 @@
+-- Subscribers notified of the events matching their filter, see webhooks.rs
+CREATE TABLE webhook_subscriptions (
+    id                          BIGSERIAL     PRIMARY KEY,
+    -- HTTPS endpoint the matching events are POSTed to
+    endpoint                    TEXT          NOT NULL,
+    -- JSON serialized EventFilter, same as the filter of suix_queryEvents
+    event_filter                TEXT          NOT NULL,
+    -- key the payloads are signed with, using HMAC-SHA256
+    secret                      TEXT          NOT NULL,
+    active                      BOOLEAN       NOT NULL DEFAULT TRUE,
+    -- position of the last event processed for this subscriber.
+    -- NULL until the delivery worker picks up the subscription,
+    -- after which only events indexed later are delivered.
+    last_tx_sequence_number     BIGINT,
+    last_event_sequence_number  BIGINT
+);
+
+-- Events that could not be delivered after all retries
+CREATE TABLE webhook_dead_letters (
+    subscription_id             BIGINT        NOT NULL,
+    tx_sequence_number          BIGINT        NOT NULL,
+    event_sequence_number       BIGINT        NOT NULL,
+    -- JSON serialized SuiEvent
+    payload                     TEXT          NOT NULL,
+    error                       TEXT          NOT NULL,
+    failed_at_ms                BIGINT        NOT NULL,
+    CONSTRAINT webhook_dead_letters_pk PRIMARY KEY (subscription_id, tx_sequence_number, event_sequence_number)
+);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS balance_history;
+DROP TABLE IF EXISTS address_activity;
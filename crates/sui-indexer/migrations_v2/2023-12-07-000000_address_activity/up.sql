@@ -0,0 +1,25 @@
+-- Transactions sent or received by each address, in the order they were executed.
+CREATE TABLE address_activity (
+    -- SuiAddress in bytes.
+    address                     bytea         NOT NULL,
+    tx_sequence_number          bigint        NOT NULL,
+    transaction_digest          bytea         NOT NULL,
+    checkpoint_sequence_number  bigint        NOT NULL,
+    timestamp_ms                bigint        NOT NULL,
+    is_sender                   boolean       NOT NULL,
+    is_recipient                boolean       NOT NULL,
+    PRIMARY KEY(address, tx_sequence_number)
+);
+CREATE INDEX address_activity_tx_sequence_number_index ON address_activity (tx_sequence_number ASC);
+
+-- Balance of each coin type owned by an address, at the end of every checkpoint in which it changed.
+CREATE TABLE balance_history (
+    -- SuiAddress in bytes.
+    owner_id                    bytea         NOT NULL,
+    coin_type                   text          NOT NULL,
+    checkpoint_sequence_number  bigint        NOT NULL,
+    timestamp_ms                bigint        NOT NULL,
+    balance                     bigint        NOT NULL,
+    PRIMARY KEY(owner_id, coin_type, checkpoint_sequence_number)
+);
+CREATE INDEX balance_history_checkpoint_sequence_number_index ON balance_history (checkpoint_sequence_number ASC);
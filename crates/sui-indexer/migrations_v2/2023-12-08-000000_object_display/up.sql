@@ -0,0 +1,18 @@
+-- Display fields of the address owned objects whose type has a Display, rendered with the
+-- Display template of the type when the object was last changed.
+CREATE TABLE object_display (
+    object_id                   bytea         PRIMARY KEY,
+    object_version              bigint        NOT NULL,
+    object_digest               bytea         NOT NULL,
+    checkpoint_sequence_number  bigint        NOT NULL,
+    -- SuiAddress in bytes.
+    owner_id                    bytea         NOT NULL,
+    -- e.g. `0x0000000000000000000000000000000000000000000000000000000000000002::kiosk::Kiosk`
+    object_type                 text          NOT NULL,
+    -- Null when the Display template has no such field, or it fails to render.
+    name                        text,
+    description                 text,
+    image_url                   text
+);
+CREATE INDEX object_display_owner ON object_display (owner_id, object_id);
+CREATE INDEX object_display_type ON object_display (object_type);
@@ -0,0 +1,10 @@
+-- The parent of every object owned by or wrapped in another object, e.g. a dynamic field and
+-- the object it belongs to, so that the object graph can be traversed in both directions.
+CREATE TABLE object_relations (
+    object_id                   bytea         PRIMARY KEY,
+    parent_id                   bytea         NOT NULL,
+    -- DynamicField/Wrapped, see types_v2.rs
+    relation                    smallint      NOT NULL,
+    checkpoint_sequence_number  bigint        NOT NULL
+);
+CREATE INDEX object_relations_parent ON object_relations (parent_id, object_id);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS package_functions;
+DROP TABLE IF EXISTS package_modules;
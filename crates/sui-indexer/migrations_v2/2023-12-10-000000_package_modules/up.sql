@@ -0,0 +1,20 @@
+-- The interface of every module of the published packages, so that on-chain APIs can be
+-- searched by module and function name.
+CREATE TABLE package_modules (
+    package_id                  bytea         NOT NULL,
+    module_name                 text          NOT NULL,
+    checkpoint_sequence_number  bigint        NOT NULL,
+    -- JSON of the SuiMoveNormalizedModule of the module
+    normalized_module           text          NOT NULL,
+    PRIMARY KEY (package_id, module_name)
+);
+CREATE INDEX package_modules_module_name ON package_modules (module_name text_pattern_ops);
+
+-- The public and entry functions of the modules of the published packages.
+CREATE TABLE package_functions (
+    package_id                  bytea         NOT NULL,
+    module_name                 text          NOT NULL,
+    function_name               text          NOT NULL,
+    PRIMARY KEY (package_id, module_name, function_name)
+);
+CREATE INDEX package_functions_function_name ON package_functions (function_name text_pattern_ops);
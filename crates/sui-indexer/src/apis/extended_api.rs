@@ -10,11 +10,13 @@ use sui_json_rpc_api::{
     validate_limit, ExtendedApiServer, QUERY_MAX_RESULT_LIMIT, QUERY_MAX_RESULT_LIMIT_CHECKPOINTS,
 };
 use sui_json_rpc_types::{
-    AddressMetrics, CheckpointedObjectID, EpochInfo, EpochMetricsPage, EpochPage, MoveCallMetrics,
-    NetworkMetrics, Page, QueryObjectsPage, SuiObjectDataFilter, SuiObjectResponse,
-    SuiObjectResponseQuery,
+    AddressActivityPage, AddressMetrics, BalanceHistoryPage, CheckpointedObjectID,
+    DisplayObjectPage, EpochInfo, EpochMetricsPage, EpochPage, MoveCallMetrics, NetworkMetrics,
+    ObjectRelation, ObjectRelationPage, PackageModuleCursor, PackageModulePage, Page,
+    QueryObjectsPage, SuiObjectDataFilter, SuiObjectResponse, SuiObjectResponseQuery,
 };
 use sui_open_rpc::Module;
+use sui_types::base_types::{ObjectID, SuiAddress};
 use sui_types::sui_serde::BigInt;
 
 use crate::errors::IndexerError;
@@ -179,6 +181,60 @@ impl<S: IndexerStore + Sync + Send + 'static> ExtendedApiServer for ExtendedApi<
         // rolling_total_successful_transaction_blocks.
         Ok((total_txes as u64).into())
     }
+
+    async fn get_address_activity(
+        &self,
+        _address: SuiAddress,
+        _cursor: Option<BigInt<u64>>,
+        _limit: Option<usize>,
+        _descending_order: Option<bool>,
+    ) -> RpcResult<AddressActivityPage> {
+        unimplemented!();
+    }
+
+    async fn get_balance_history(
+        &self,
+        _owner: SuiAddress,
+        _coin_type: Option<String>,
+        _cursor: Option<BigInt<u64>>,
+        _limit: Option<usize>,
+        _descending_order: Option<bool>,
+    ) -> RpcResult<BalanceHistoryPage> {
+        unimplemented!();
+    }
+
+    async fn get_owned_display_objects(
+        &self,
+        _owner: SuiAddress,
+        _type_filter: Option<String>,
+        _cursor: Option<ObjectID>,
+        _limit: Option<usize>,
+    ) -> RpcResult<DisplayObjectPage> {
+        unimplemented!();
+    }
+
+    async fn get_object_children(
+        &self,
+        _object_id: ObjectID,
+        _cursor: Option<ObjectID>,
+        _limit: Option<usize>,
+    ) -> RpcResult<ObjectRelationPage> {
+        unimplemented!();
+    }
+
+    async fn get_object_ancestors(&self, _object_id: ObjectID) -> RpcResult<Vec<ObjectRelation>> {
+        unimplemented!();
+    }
+
+    async fn search_package_modules(
+        &self,
+        _module_name: Option<String>,
+        _function_name: Option<String>,
+        _cursor: Option<PackageModuleCursor>,
+        _limit: Option<usize>,
+    ) -> RpcResult<PackageModulePage> {
+        unimplemented!();
+    }
 }
 
 impl<S> SuiRpcModule for ExtendedApi<S>
@@ -3,13 +3,19 @@
 
 use crate::indexer_reader::IndexerReader;
 use jsonrpsee::{core::RpcResult, RpcModule};
+use sui_json_rpc::coin_api::{parse_to_struct_tag, parse_to_type_tag};
 use sui_json_rpc::SuiRpcModule;
-use sui_json_rpc_api::{validate_limit, ExtendedApiServer, QUERY_MAX_RESULT_LIMIT_CHECKPOINTS};
+use sui_json_rpc_api::{
+    validate_limit, ExtendedApiServer, QUERY_MAX_RESULT_LIMIT, QUERY_MAX_RESULT_LIMIT_CHECKPOINTS,
+};
 use sui_json_rpc_types::{
-    AddressMetrics, CheckpointedObjectID, EpochInfo, EpochMetrics, EpochMetricsPage, EpochPage,
-    MoveCallMetrics, NetworkMetrics, Page, QueryObjectsPage, SuiObjectResponseQuery,
+    AddressActivity, AddressActivityPage, AddressMetrics, BalanceHistoryPage, CheckpointedObjectID,
+    DisplayObject, DisplayObjectPage, EpochInfo, EpochMetrics, EpochMetricsPage, EpochPage,
+    MoveCallMetrics, NetworkMetrics, ObjectRelation, ObjectRelationPage, PackageModule,
+    PackageModuleCursor, PackageModulePage, Page, QueryObjectsPage, SuiObjectResponseQuery,
 };
 use sui_open_rpc::Module;
+use sui_types::base_types::{ObjectID, SuiAddress};
 use sui_types::sui_serde::BigInt;
 
 pub(crate) struct ExtendedApiV2 {
@@ -161,6 +167,176 @@ impl ExtendedApiServer for ExtendedApiV2 {
             .await?;
         Ok(latest_checkpoint.network_total_transactions.into())
     }
+
+    async fn get_address_activity(
+        &self,
+        address: SuiAddress,
+        cursor: Option<BigInt<u64>>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<AddressActivityPage> {
+        let limit = validate_limit(limit, QUERY_MAX_RESULT_LIMIT_CHECKPOINTS)?;
+        let mut activities = self
+            .inner
+            .spawn_blocking(move |this| {
+                this.get_address_activity(
+                    address,
+                    cursor.map(|x| *x),
+                    limit + 1,
+                    descending_order.unwrap_or(false),
+                )
+            })
+            .await?;
+
+        let has_next_page = activities.len() > limit;
+        activities.truncate(limit);
+        let next_cursor = activities.last().map(|a| a.tx_sequence_number as u64);
+        let data = activities
+            .into_iter()
+            .map(AddressActivity::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Page {
+            data,
+            next_cursor: next_cursor.map(|seq| seq.into()),
+            has_next_page,
+        })
+    }
+
+    async fn get_balance_history(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<BigInt<u64>>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<BalanceHistoryPage> {
+        let limit = validate_limit(limit, QUERY_MAX_RESULT_LIMIT_CHECKPOINTS)?;
+        let coin_type =
+            parse_to_type_tag(coin_type)?.to_canonical_string(/* with_prefix */ true);
+        let mut history = self
+            .inner
+            .spawn_blocking(move |this| {
+                this.get_balance_history(
+                    owner,
+                    coin_type,
+                    cursor.map(|x| *x),
+                    limit + 1,
+                    descending_order.unwrap_or(false),
+                )
+            })
+            .await?;
+
+        let has_next_page = history.len() > limit;
+        history.truncate(limit);
+        let next_cursor = history.last().map(|h| h.checkpoint_sequence_number as u64);
+        Ok(Page {
+            data: history.into_iter().map(Into::into).collect(),
+            next_cursor: next_cursor.map(|cp| cp.into()),
+            has_next_page,
+        })
+    }
+
+    async fn get_owned_display_objects(
+        &self,
+        owner: SuiAddress,
+        type_filter: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<DisplayObjectPage> {
+        let limit = validate_limit(limit, *QUERY_MAX_RESULT_LIMIT)?;
+        let object_type = type_filter
+            .map(|t| parse_to_struct_tag(&t))
+            .transpose()?
+            .map(|t| t.to_canonical_string(/* with_prefix */ true));
+        let mut objects = self
+            .inner
+            .spawn_blocking(move |this| {
+                this.get_owned_display_objects(owner, object_type, cursor, limit + 1)
+            })
+            .await?;
+
+        let has_next_page = objects.len() > limit;
+        objects.truncate(limit);
+        let data = objects
+            .into_iter()
+            .map(DisplayObject::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_cursor = data.last().map(|o| o.object_id);
+        Ok(Page {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn get_object_children(
+        &self,
+        object_id: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectRelationPage> {
+        let limit = validate_limit(limit, *QUERY_MAX_RESULT_LIMIT)?;
+        let mut children = self
+            .inner
+            .spawn_blocking(move |this| this.get_object_children(object_id, cursor, limit + 1))
+            .await?;
+
+        let has_next_page = children.len() > limit;
+        children.truncate(limit);
+        let data = children
+            .into_iter()
+            .map(ObjectRelation::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_cursor = data.last().map(|r| r.object_id);
+        Ok(Page {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn get_object_ancestors(&self, object_id: ObjectID) -> RpcResult<Vec<ObjectRelation>> {
+        let ancestors = self
+            .inner
+            .spawn_blocking(move |this| this.get_object_ancestors(object_id))
+            .await?;
+        Ok(ancestors
+            .into_iter()
+            .map(ObjectRelation::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    async fn search_package_modules(
+        &self,
+        module_name: Option<String>,
+        function_name: Option<String>,
+        cursor: Option<PackageModuleCursor>,
+        limit: Option<usize>,
+    ) -> RpcResult<PackageModulePage> {
+        let limit = validate_limit(limit, *QUERY_MAX_RESULT_LIMIT)?;
+        let mut modules = self
+            .inner
+            .spawn_blocking(move |this| {
+                this.search_package_modules(module_name, function_name, cursor, limit + 1)
+            })
+            .await?;
+
+        let has_next_page = modules.len() > limit;
+        modules.truncate(limit);
+        let data = modules
+            .into_iter()
+            .map(PackageModule::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_cursor = data.last().map(|m| PackageModuleCursor {
+            package_id: m.package_id,
+            module_name: m.module_name.clone(),
+        });
+        Ok(Page {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
 }
 
 impl SuiRpcModule for ExtendedApiV2 {
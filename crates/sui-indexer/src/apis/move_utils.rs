@@ -12,7 +12,7 @@ use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_api::MoveUtilsClient;
 use sui_json_rpc_api::MoveUtilsServer;
 use sui_json_rpc_types::{
-    MoveFunctionArgType, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
+    MoveFunctionArgType, SuiMoveEventLayout, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
     SuiMoveNormalizedStruct,
 };
 use sui_open_rpc::Module;
@@ -93,4 +93,8 @@ impl MoveUtilsServer for MoveUtilsApi {
             .get_move_function_arg_types(package, module, function)
             .await
     }
+
+    async fn get_event_layout(&self, event_type: String) -> RpcResult<SuiMoveEventLayout> {
+        self.fullnode.get_event_layout(event_type).await
+    }
 }
@@ -7,13 +7,14 @@ use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::RpcModule;
 
+use sui_json_rpc::coin_api::parse_to_struct_tag;
 use sui_json_rpc::error::SuiRpcInputError;
 use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_api::MoveUtilsServer;
 use sui_json_rpc_types::ObjectValueKind;
 use sui_json_rpc_types::SuiMoveNormalizedType;
 use sui_json_rpc_types::{
-    MoveFunctionArgType, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
+    MoveFunctionArgType, SuiMoveEventLayout, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
     SuiMoveNormalizedStruct,
 };
 use sui_open_rpc::Module;
@@ -147,6 +148,19 @@ impl MoveUtilsServer for MoveUtilsApiV2 {
             .collect::<Vec<MoveFunctionArgType>>();
         Ok(args)
     }
+
+    async fn get_event_layout(&self, event_type: String) -> RpcResult<SuiMoveEventLayout> {
+        let event_type = parse_to_struct_tag(&event_type)?;
+        let (package_version, layout) = self
+            .inner
+            .get_event_layout_in_blocking_task(event_type)
+            .await
+            .map_err(|e| SuiRpcInputError::GenericNotFound(e.to_string()))?;
+        Ok(SuiMoveEventLayout {
+            package_version,
+            layout: layout.into(),
+        })
+    }
 }
 
 impl SuiRpcModule for MoveUtilsApiV2 {
@@ -17,6 +17,7 @@ use sui_json_rpc_types::{
 use sui_open_rpc::Module;
 use sui_types::base_types::{ObjectID, SequenceNumber};
 use sui_types::digests::{ChainIdentifier, TransactionDigest};
+use sui_types::messages_checkpoint::{CheckpointInclusionProof, EpochStateCommitment};
 use sui_types::sui_serde::BigInt;
 
 use crate::errors::IndexerError;
@@ -340,6 +341,20 @@ where
         events_guard.stop_and_record();
         events_resp
     }
+    async fn get_checkpoint_inclusion_proof(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<CheckpointInclusionProof> {
+        self.fullnode.get_checkpoint_inclusion_proof(digest).await
+    }
+
+    async fn get_epoch_state_commitment(
+        &self,
+        epoch: BigInt<u64>,
+    ) -> RpcResult<EpochStateCommitment> {
+        self.fullnode.get_epoch_state_commitment(epoch).await
+    }
+
     async fn get_loaded_child_objects(
         &self,
         digest: TransactionDigest,
@@ -21,6 +21,7 @@ use sui_open_rpc::Module;
 use sui_protocol_config::{ProtocolConfig, ProtocolVersion};
 use sui_types::base_types::{ObjectID, SequenceNumber};
 use sui_types::digests::{ChainIdentifier, TransactionDigest};
+use sui_types::messages_checkpoint::{CheckpointInclusionProof, EpochStateCommitment};
 use sui_types::sui_serde::BigInt;
 
 use sui_json_rpc_types::SuiLoadedChildObjectsResponse;
@@ -257,6 +258,26 @@ impl ReadApiServer for ReadApiV2 {
             .map_err(Into::into)
     }
 
+    async fn get_checkpoint_inclusion_proof(
+        &self,
+        _digest: TransactionDigest,
+    ) -> RpcResult<CheckpointInclusionProof> {
+        Err(jsonrpsee::types::error::CallError::Custom(
+            jsonrpsee::types::error::ErrorCode::MethodNotFound.into(),
+        )
+        .into())
+    }
+
+    async fn get_epoch_state_commitment(
+        &self,
+        _epoch: BigInt<u64>,
+    ) -> RpcResult<EpochStateCommitment> {
+        Err(jsonrpsee::types::error::CallError::Custom(
+            jsonrpsee::types::error::ErrorCode::MethodNotFound.into(),
+        )
+        .into())
+    }
+
     async fn get_loaded_child_objects(
         &self,
         _digest: TransactionDigest,
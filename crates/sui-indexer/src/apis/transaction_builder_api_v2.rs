@@ -4,10 +4,11 @@
 use super::governance_api_v2::GovernanceReadApiV2;
 use crate::indexer_reader::IndexerReader;
 use async_trait::async_trait;
-use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::{StructTag, TypeTag};
 use sui_json_rpc::transaction_builder_api::TransactionBuilderApi;
-use sui_json_rpc_types::{SuiObjectDataFilter, SuiObjectDataOptions, SuiObjectResponse};
-use sui_transaction_builder::DataReader;
+use sui_json_rpc_types::{CoinPage, SuiObjectDataFilter, SuiObjectDataOptions, SuiObjectResponse};
+use sui_protocol_config::ProtocolConfig;
+use sui_transaction_builder::{protocol_config_for_version, DataReader};
 use sui_types::base_types::{ObjectID, ObjectInfo, SuiAddress};
 use sui_types::object::Object;
 
@@ -62,6 +63,32 @@ impl DataReader for TransactionBuilderApiV2 {
         Ok((result, options).try_into()?)
     }
 
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: TypeTag,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<CoinPage, anyhow::Error> {
+        let mut data = self
+            .inner
+            .get_owned_coins_in_blocking_task(
+                owner,
+                Some(coin_type.to_canonical_string(/* with_prefix */ true)),
+                cursor.unwrap_or(ObjectID::ZERO),
+                limit + 1,
+            )
+            .await?;
+        let has_next_page = data.len() > limit;
+        data.truncate(limit);
+        let next_cursor = data.last().map(|coin| coin.coin_object_id);
+        Ok(CoinPage {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn get_reference_gas_price(&self) -> Result<u64, anyhow::Error> {
         let epoch_info = GovernanceReadApiV2::new(self.inner.clone())
             .get_epoch_info(None)
@@ -70,4 +97,14 @@ impl DataReader for TransactionBuilderApiV2 {
             .reference_gas_price
             .ok_or_else(|| anyhow::anyhow!("missing latest reference_gas_price"))?)
     }
+
+    async fn get_protocol_config(&self) -> Result<ProtocolConfig, anyhow::Error> {
+        let latest_epoch = self
+            .inner
+            .spawn_blocking(|this| this.get_latest_epoch_info_from_db())
+            .await?;
+        Ok(protocol_config_for_version(
+            (latest_epoch.protocol_version as u64).into(),
+        ))
+    }
 }
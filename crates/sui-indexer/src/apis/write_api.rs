@@ -73,6 +73,25 @@ impl WriteApiServer for WriteApi {
             .await
     }
 
+    async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender_address: SuiAddress,
+        tx_bytes: Base64,
+        checkpoint: BigInt<u64>,
+        gas_price: Option<BigInt<u64>>,
+        gas_budget: Option<BigInt<u64>>,
+    ) -> RpcResult<DevInspectResults> {
+        self.fullnode
+            .dev_inspect_transaction_block_at_checkpoint(
+                sender_address,
+                tx_bytes,
+                checkpoint,
+                gas_price,
+                gas_budget,
+            )
+            .await
+    }
+
     async fn dry_run_transaction_block(
         &self,
         tx_bytes: Base64,
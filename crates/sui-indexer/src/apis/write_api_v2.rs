@@ -55,6 +55,17 @@ impl WriteApiServer for WriteApiV2 {
         unimplemented!()
     }
 
+    async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender_address: SuiAddress,
+        tx_bytes: Base64,
+        checkpoint: BigInt<u64>,
+        gas_price: Option<BigInt<u64>>,
+        gas_budget: Option<BigInt<u64>>,
+    ) -> RpcResult<DevInspectResults> {
+        unimplemented!()
+    }
+
     async fn dry_run_transaction_block(
         &self,
         tx_bytes: Base64,
@@ -184,6 +184,9 @@ fn get_object_id_and_version(object_change: &ObjectChange) -> Option<(ObjectID,
         ObjectChange::Created {
             object_id, version, ..
         } => Some((*object_id, *version)),
+        ObjectChange::Unwrapped {
+            object_id, version, ..
+        } => Some((*object_id, *version)),
         // TODO(gegaowp): needs separate checks for packages and modules publishing
         // TODO(gegaowp): ?? needs separate checks for deleted and wrapped objects
         _ => None,
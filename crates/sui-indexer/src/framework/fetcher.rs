@@ -1,6 +1,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::ops::RangeInclusive;
+
 use anyhow::Result;
 use sui_rest_api::{CheckpointData, Client};
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
@@ -10,6 +12,7 @@ pub struct CheckpointFetcher {
     client: Client,
     last_downloaded_checkpoint: Option<CheckpointSequenceNumber>,
     highest_known_checkpoint: CheckpointSequenceNumber,
+    end_checkpoint: Option<CheckpointSequenceNumber>,
     sender: mysten_metrics::metered_channel::Sender<CheckpointData>,
 }
 
@@ -26,10 +29,17 @@ impl CheckpointFetcher {
             client,
             last_downloaded_checkpoint,
             highest_known_checkpoint: 0,
+            end_checkpoint: None,
             sender,
         }
     }
 
+    /// Stop fetching once `end_checkpoint` has been downloaded, e.g. when backfilling a range.
+    pub fn end_checkpoint(mut self, end_checkpoint: Option<CheckpointSequenceNumber>) -> Self {
+        self.end_checkpoint = end_checkpoint;
+        self
+    }
+
     pub async fn run(mut self) {
         let mut interval = tokio::time::interval(Self::INTERVAL_PERIOD);
         interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -48,6 +58,13 @@ impl CheckpointFetcher {
                 warn!("error downloading checkpoints: {e}");
                 continue;
             }
+
+            if let Some(end_checkpoint) = self.end_checkpoint {
+                if self.last_downloaded_checkpoint >= Some(end_checkpoint) {
+                    info!("CheckpointFetcher reached end checkpoint {end_checkpoint}");
+                    return;
+                }
+            }
         }
     }
 
@@ -62,10 +79,11 @@ impl CheckpointFetcher {
         use futures::StreamExt;
         use tap::Pipe;
 
-        let checkpoint_range = self
-            .last_downloaded_checkpoint
-            .map(|i| i.saturating_add(1))
-            .unwrap_or(0)..=self.highest_known_checkpoint;
+        let checkpoint_range = checkpoint_range(
+            self.last_downloaded_checkpoint,
+            self.highest_known_checkpoint,
+            self.end_checkpoint,
+        );
 
         if !checkpoint_range.is_empty() {
             info!("Starting download of checkpoints {checkpoint_range:?}");
@@ -95,3 +113,35 @@ impl CheckpointFetcher {
         Ok(())
     }
 }
+
+/// The checkpoints to download after `last_downloaded_checkpoint`, up to the highest known
+/// checkpoint or `end_checkpoint` if lower.
+fn checkpoint_range(
+    last_downloaded_checkpoint: Option<CheckpointSequenceNumber>,
+    highest_known_checkpoint: CheckpointSequenceNumber,
+    end_checkpoint: Option<CheckpointSequenceNumber>,
+) -> RangeInclusive<CheckpointSequenceNumber> {
+    let highest_checkpoint = end_checkpoint.map_or(highest_known_checkpoint, |end| {
+        std::cmp::min(end, highest_known_checkpoint)
+    });
+    last_downloaded_checkpoint
+        .map(|i| i.saturating_add(1))
+        .unwrap_or(0)..=highest_checkpoint
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_range() {
+        // From genesis, or resuming after the last downloaded checkpoint.
+        assert_eq!(checkpoint_range(None, 10, None), 0..=10);
+        assert_eq!(checkpoint_range(Some(4), 10, None), 5..=10);
+        // Backfilling stops at the end checkpoint, or at the highest known one if lower.
+        assert_eq!(checkpoint_range(Some(4), 10, Some(7)), 5..=7);
+        assert_eq!(checkpoint_range(Some(4), 10, Some(20)), 5..=10);
+        assert!(checkpoint_range(Some(7), 10, Some(7)).is_empty());
+        assert!(checkpoint_range(Some(10), 10, None).is_empty());
+    }
+}
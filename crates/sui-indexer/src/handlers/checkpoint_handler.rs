@@ -310,10 +310,12 @@ where
             let epoch_commitments = end_of_epoch_data
                 .epoch_commitments
                 .iter()
-                .map(|c| match c {
+                .filter_map(|c| match c {
                     CheckpointCommitment::ECMHLiveObjectSetDigest(d) => {
-                        Some(d.digest.into_inner().to_vec())
+                        Some(Some(d.digest.into_inner().to_vec()))
                     }
+                    // Only committed to by individual checkpoints.
+                    CheckpointCommitment::CheckpointContentsMerkleRoot(_) => None,
                 })
                 .collect();
 
@@ -4,9 +4,14 @@
 use crate::handlers::committer::start_tx_checkpoint_commit_task;
 use crate::handlers::tx_processor::IndexingPackageCache;
 use crate::models_v2::display::StoredDisplay;
+use crate::models_v2::object_display::StoredObjectDisplay;
+use crate::models_v2::object_relations::StoredObjectRelation;
+use crate::models_v2::package_modules::{StoredPackageFunction, StoredPackageModule};
 use async_trait::async_trait;
 use itertools::Itertools;
+use move_binary_format::file_format_common::VERSION_MAX;
 use move_bytecode_utils::module_cache::GetModule;
+use move_core_types::annotated_value::MoveValue;
 use mysten_metrics::{get_metrics, spawn_monitored_task};
 use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
@@ -17,18 +22,23 @@ use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::dynamic_field::DynamicFieldName;
 use sui_types::dynamic_field::DynamicFieldType;
 use sui_types::messages_checkpoint::{CertifiedCheckpointSummary, CheckpointContents};
+use sui_types::move_package::normalize_modules;
 use sui_types::object::Object;
 
 use tokio::sync::watch;
+use tokio::task::JoinHandle;
 
 use std::collections::hash_map::Entry;
 use std::collections::HashSet;
-use sui_json_rpc_types::SuiMoveValue;
+use sui_json_rpc::read_api::get_rendered_fields;
+use sui_json_rpc_types::{DisplayFieldsResponse, SuiMoveNormalizedModule, SuiMoveValue};
 use sui_types::base_types::SequenceNumber;
 use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
 use sui_types::event::SystemEpochInfoEvent;
+use sui_types::id::{OBJECT_MODULE_NAME, UID_STRUCT_NAME};
 use sui_types::object::Owner;
 use sui_types::transaction::TransactionDataAPI;
+use sui_types::SUI_FRAMEWORK_ADDRESS;
 use tap::tap::TapFallible;
 use tracing::{error, info, warn};
 
@@ -46,7 +56,7 @@ use crate::types_v2::IndexedEpochInfo;
 use crate::types_v2::{
     IndexedCheckpoint, IndexedEvent, IndexedTransaction, IndexerResult, TransactionKind, TxIndex,
 };
-use crate::types_v2::{IndexedDeletedObject, IndexedObject, IndexedPackage};
+use crate::types_v2::{IndexedDeletedObject, IndexedObject, IndexedPackage, ObjectRelationType};
 use crate::IndexerConfig;
 
 use super::tx_processor::EpochEndIndexingObjectStore;
@@ -61,7 +71,7 @@ pub async fn new_handlers<S>(
     state: S,
     metrics: IndexerMetrics,
     config: &IndexerConfig,
-) -> Result<CheckpointHandler<S>, IndexerError>
+) -> Result<(CheckpointHandler<S>, JoinHandle<()>), IndexerError>
 where
     S: IndexerStoreV2 + Clone + Sync + Send + 'static,
 {
@@ -82,7 +92,7 @@ where
     let metrics_clone = metrics.clone();
     let config_clone = config.clone();
     let (tx, rx) = watch::channel(None);
-    spawn_monitored_task!(start_tx_checkpoint_commit_task(
+    let commit_task = spawn_monitored_task!(start_tx_checkpoint_commit_task(
         state_clone,
         metrics_clone,
         config_clone,
@@ -97,7 +107,7 @@ where
         package_cache: IndexingPackageCache::start(rx),
     };
 
-    Ok(checkpoint_handler)
+    Ok((checkpoint_handler, commit_task))
 }
 
 pub struct CheckpointHandler<S> {
@@ -304,13 +314,15 @@ where
         info!(checkpoint_seq, "Indexing checkpoint data blob");
 
         // Index epoch
-        let epoch = Self::index_epoch(state, &data).await?;
+        let epoch = Self::index_epoch(state.clone(), &data).await?;
 
         // Index Objects
         let object_changes: TransactionObjectChangesToCommit =
             Self::index_objects(data.clone(), &metrics, &module_resolver);
         let object_history_changes: TransactionObjectChangesToCommit =
             Self::index_objects_history(data.clone(), &module_resolver);
+        let object_relations = Self::index_object_relations(&data, &module_resolver);
+        let (package_modules, package_functions) = Self::index_package_modules(&packages)?;
 
         let (checkpoint, db_transactions, db_events, db_indices, db_displays) = {
             let CheckpointData {
@@ -341,15 +353,24 @@ where
             )
         };
 
+        // Index Display of objects, after the display updates of the checkpoint
+        let object_displays =
+            Self::index_object_displays(state, &object_changes, &db_displays, &module_resolver)
+                .await?;
+
         Ok(CheckpointDataToCommit {
             checkpoint,
             transactions: db_transactions,
             events: db_events,
             tx_indices: db_indices,
             display_updates: db_displays,
+            object_displays,
+            object_relations,
             object_changes,
             object_history_changes,
             packages,
+            package_modules,
+            package_functions,
             epoch,
         })
     }
@@ -593,6 +614,108 @@ where
         }
     }
 
+    /// Render the Display of the changed objects owned by an address, so that wallets can list
+    /// them without fetching and rendering every object. Objects are rendered with the latest
+    /// Display template of their type, and are not rendered again when the template changes.
+    async fn index_object_displays(
+        state: Arc<S>,
+        object_changes: &TransactionObjectChangesToCommit,
+        display_updates: &BTreeMap<String, StoredDisplay>,
+        module_resolver: &impl GetModule,
+    ) -> IndexerResult<Vec<StoredObjectDisplay>> {
+        let address_owned_objects = object_changes
+            .changed_objects
+            .iter()
+            .filter_map(|object| {
+                match (&object.object.owner, object.object.data.try_as_move()) {
+                    (Owner::AddressOwner(owner), Some(move_object)) => Some((
+                        object,
+                        *owner,
+                        move_object,
+                        move_object
+                            .type_()
+                            .to_canonical_string(/* with_prefix */ true),
+                    )),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        // Display templates updated in this checkpoint take precedence over the stored ones.
+        let stored_display_types = address_owned_objects
+            .iter()
+            .map(|(_, _, _, object_type)| object_type)
+            .filter(|object_type| !display_updates.contains_key(*object_type))
+            .unique()
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut displays = state.get_displays(stored_display_types).await?;
+        displays.extend(display_updates.clone());
+
+        let mut object_displays = vec![];
+        for (object, owner, move_object, object_type) in address_owned_objects {
+            let Some(display) = displays.get(&object_type) else {
+                continue;
+            };
+            let fields = display.to_display_update_event()?.fields;
+            let rendered_fields = move_object
+                .to_move_struct_with_resolver(module_resolver)
+                .map_err(|e| IndexerError::ResolveMoveStructError(e.to_string()))
+                .and_then(|move_struct| {
+                    get_rendered_fields(fields, &move_struct)
+                        .map_err(|e| IndexerError::GenericError(e.to_string()))
+                });
+            match rendered_fields {
+                Ok(DisplayFieldsResponse {
+                    data: Some(fields), ..
+                }) => object_displays.push(StoredObjectDisplay::new(
+                    object,
+                    owner,
+                    object_type,
+                    &fields,
+                )),
+                Ok(_) => {}
+                Err(e) => warn!(
+                    "Failed to render Display of object {}:{}. Err: {e}",
+                    object.object_id, object.object_version
+                ),
+            }
+        }
+        Ok(object_displays)
+    }
+
+    /// The parent of every object changed, wrapped or deleted by the checkpoint, or None if it
+    /// no longer has one.
+    fn index_object_relations(
+        data: &CheckpointData,
+        module_resolver: &impl GetModule,
+    ) -> BTreeMap<ObjectID, Option<StoredObjectRelation>> {
+        let checkpoint_seq = data.checkpoint_summary.sequence_number;
+        let mut relations = BTreeMap::new();
+        for tx in &data.transactions {
+            // Objects created and wrapped by the transaction do not appear in its effects, so
+            // the wrapped objects are found in every object it writes. Coins wrap no objects.
+            let written = tx.output_objects.iter().filter_map(|object| {
+                let move_object = object.data.try_as_move()?;
+                if move_object.type_().is_coin() {
+                    return None;
+                }
+                match move_object.to_move_struct_with_resolver(module_resolver) {
+                    Ok(move_struct) => Some((object.id(), MoveValue::Struct(move_struct))),
+                    Err(e) => {
+                        warn!(
+                            "Failed to resolve wrapped objects of obj {}:{}. Err: {e}",
+                            object.id(),
+                            object.version()
+                        );
+                        None
+                    }
+                }
+            });
+            apply_object_relations(&tx.effects, written, checkpoint_seq, &mut relations);
+        }
+        relations
+    }
+
     // similar to index_objects, but objects_history keeps all versions of objects
     fn index_objects_history(
         data: CheckpointData,
@@ -689,6 +812,129 @@ where
             })
             .collect()
     }
+
+    /// The interfaces of the modules of the published packages, and their public and entry
+    /// functions.
+    fn index_package_modules(
+        packages: &[IndexedPackage],
+    ) -> IndexerResult<(Vec<StoredPackageModule>, Vec<StoredPackageFunction>)> {
+        let mut package_modules = vec![];
+        let mut package_functions = vec![];
+        for package in packages {
+            // Published packages went through the verifier, so they are in a supported binary
+            // format version.
+            let modules = normalize_modules(
+                package.move_package.serialized_module_map().values(),
+                /* max_binary_format_version */ VERSION_MAX,
+                /* no_extraneous_module_bytes */ false,
+            )?;
+            for module in modules.into_values() {
+                let (module, functions) = StoredPackageModule::new(
+                    package.package_id,
+                    &SuiMoveNormalizedModule::from(module),
+                    package.checkpoint_sequence_number,
+                )?;
+                package_modules.push(module);
+                package_functions.extend(functions);
+            }
+        }
+        Ok((package_modules, package_functions))
+    }
+}
+
+/// Update `relations` with the relations changed by a transaction, given its effects and the
+/// Move values of the objects it writes.
+fn apply_object_relations(
+    effects: &TransactionEffects,
+    written: impl IntoIterator<Item = (ObjectID, MoveValue)>,
+    checkpoint_seq: u64,
+    relations: &mut BTreeMap<ObjectID, Option<StoredObjectRelation>>,
+) {
+    for (oref, owner, _kind) in effects.all_changed_objects() {
+        let relation = match owner {
+            Owner::ObjectOwner(parent) => Some(StoredObjectRelation::new(
+                oref.0,
+                parent.into(),
+                ObjectRelationType::DynamicField,
+                checkpoint_seq,
+            )),
+            _ => None,
+        };
+        relations.insert(oref.0, relation);
+    }
+    for oref in effects
+        .deleted()
+        .into_iter()
+        .chain(effects.unwrapped_then_deleted())
+        .chain(effects.wrapped())
+    {
+        relations.insert(oref.0, None);
+    }
+    for (object_id, value) in written {
+        let mut wrapped = vec![];
+        collect_wrapped_objects(object_id, &value, &mut wrapped);
+        for (object_id, parent_id) in wrapped {
+            relations.insert(
+                object_id,
+                Some(StoredObjectRelation::new(
+                    object_id,
+                    parent_id,
+                    ObjectRelationType::Wrapped,
+                    checkpoint_seq,
+                )),
+            );
+        }
+    }
+}
+
+/// Collect the (object, parent) pairs of the objects wrapped in `value`, which is `parent` or
+/// one of its fields. A struct with a UID field is an object, and the parent of the objects
+/// wrapped in its own fields.
+fn collect_wrapped_objects(
+    parent: ObjectID,
+    value: &MoveValue,
+    wrapped: &mut Vec<(ObjectID, ObjectID)>,
+) {
+    match value {
+        MoveValue::Struct(move_struct) => {
+            let parent = match move_struct.fields.iter().find_map(|(_, v)| uid_value(v)) {
+                Some(object_id) if object_id != parent => {
+                    wrapped.push((object_id, parent));
+                    object_id
+                }
+                _ => parent,
+            };
+            for (_, field) in &move_struct.fields {
+                collect_wrapped_objects(parent, field, wrapped);
+            }
+        }
+        MoveValue::Vector(values) => {
+            for value in values {
+                collect_wrapped_objects(parent, value, wrapped);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The object ID of `value` if it is a `0x2::object::UID`.
+fn uid_value(value: &MoveValue) -> Option<ObjectID> {
+    let MoveValue::Struct(uid) = value else {
+        return None;
+    };
+    if uid.type_.address != SUI_FRAMEWORK_ADDRESS
+        || uid.type_.module.as_ident_str() != OBJECT_MODULE_NAME
+        || uid.type_.name.as_ident_str() != UID_STRUCT_NAME
+    {
+        return None;
+    }
+    match uid.fields.first() {
+        Some((_, MoveValue::Struct(id))) => match id.fields.first() {
+            Some((_, MoveValue::Address(address))) => Some((*address).into()),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 pub fn get_deleted_objects(effects: &TransactionEffects) -> Vec<ObjectRef> {
@@ -799,3 +1045,179 @@ fn try_create_dynamic_field_info(
         },
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use move_core_types::annotated_value::MoveStruct;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::StructTag;
+    use sui_types::base_types::SuiAddress;
+    use sui_types::digests::{ObjectDigest, TransactionDigest};
+    use sui_types::execution_status::ExecutionStatus;
+    use sui_types::gas::GasCostSummary;
+    use sui_types::id::{ID, UID};
+
+    use super::*;
+
+    fn object(id: ObjectID, fields: Vec<MoveValue>) -> MoveValue {
+        let uid = MoveValue::Struct(MoveStruct {
+            type_: UID::type_(),
+            fields: vec![(
+                Identifier::new("id").unwrap(),
+                MoveValue::Struct(MoveStruct {
+                    type_: ID::type_(),
+                    fields: vec![(
+                        Identifier::new("bytes").unwrap(),
+                        MoveValue::Address(id.into()),
+                    )],
+                }),
+            )],
+        });
+        let mut struct_fields = vec![(Identifier::new("id").unwrap(), uid)];
+        struct_fields.extend(
+            fields
+                .into_iter()
+                .enumerate()
+                .map(|(i, f)| (Identifier::new(format!("f{i}")).unwrap(), f)),
+        );
+        MoveValue::Struct(MoveStruct {
+            type_: StructTag {
+                address: SUI_FRAMEWORK_ADDRESS,
+                module: Identifier::new("test").unwrap(),
+                name: Identifier::new("Object").unwrap(),
+                type_params: vec![],
+            },
+            fields: struct_fields,
+        })
+    }
+
+    #[test]
+    fn test_collect_wrapped_objects() {
+        let (parent, a, b, c) = (
+            ObjectID::from_single_byte(1),
+            ObjectID::from_single_byte(2),
+            ObjectID::from_single_byte(3),
+            ObjectID::from_single_byte(4),
+        );
+        // parent wraps a, which wraps b, and a vector of c.
+        let value = object(
+            parent,
+            vec![
+                MoveValue::U64(1),
+                object(a, vec![object(b, vec![])]),
+                MoveValue::Vector(vec![object(c, vec![])]),
+            ],
+        );
+        let mut wrapped = vec![];
+        collect_wrapped_objects(parent, &value, &mut wrapped);
+        assert_eq!(wrapped, vec![(a, parent), (b, a), (c, parent)]);
+    }
+
+    fn effects(
+        created: Vec<(ObjectRef, Owner)>,
+        deleted: Vec<ObjectRef>,
+        wrapped: Vec<ObjectRef>,
+    ) -> TransactionEffects {
+        let gas = (
+            (
+                ObjectID::from_single_byte(0xff),
+                SequenceNumber::from(2),
+                ObjectDigest::MIN,
+            ),
+            Owner::AddressOwner(SuiAddress::ZERO),
+        );
+        TransactionEffects::new_from_execution_v1(
+            ExecutionStatus::Success,
+            0,
+            GasCostSummary::default(),
+            vec![],
+            vec![],
+            TransactionDigest::ZERO,
+            created,
+            vec![],
+            vec![],
+            deleted,
+            vec![],
+            wrapped,
+            gas,
+            None,
+            vec![],
+        )
+    }
+
+    fn relations_of(
+        relations: &BTreeMap<ObjectID, Option<StoredObjectRelation>>,
+    ) -> Vec<(ObjectID, Option<(ObjectID, i16)>)> {
+        relations
+            .iter()
+            .map(|(id, relation)| {
+                let relation = relation
+                    .as_ref()
+                    .map(|r| (ObjectID::from_bytes(&r.parent_id).unwrap(), r.relation));
+                (*id, relation)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_object_relations() {
+        let (parent, field, created, existing, deleted) = (
+            ObjectID::from_single_byte(1),
+            ObjectID::from_single_byte(2),
+            ObjectID::from_single_byte(3),
+            ObjectID::from_single_byte(4),
+            ObjectID::from_single_byte(5),
+        );
+        let oref = |id| (id, SequenceNumber::from(2), ObjectDigest::MIN);
+        let wrapped = ObjectRelationType::Wrapped as i16;
+        let dynamic_field = ObjectRelationType::DynamicField as i16;
+
+        // The transaction creates `parent` wrapping `created`, which is not in the effects, and
+        // `field` owned by `parent`, and deletes `deleted`.
+        let mut relations = BTreeMap::new();
+        apply_object_relations(
+            &effects(
+                vec![
+                    (oref(parent), Owner::AddressOwner(SuiAddress::ZERO)),
+                    (oref(field), Owner::ObjectOwner(parent.into())),
+                ],
+                vec![oref(deleted)],
+                vec![],
+            ),
+            [(parent, object(parent, vec![object(created, vec![])]))],
+            1,
+            &mut relations,
+        );
+        assert_eq!(
+            relations_of(&relations),
+            vec![
+                (parent, None),
+                (field, Some((parent, dynamic_field))),
+                (created, Some((parent, wrapped))),
+                (deleted, None),
+            ]
+        );
+
+        // A later transaction wraps the existing object `existing` in `parent` as well.
+        apply_object_relations(
+            &effects(vec![], vec![], vec![oref(existing)]),
+            [(
+                parent,
+                object(
+                    parent,
+                    vec![object(created, vec![]), object(existing, vec![])],
+                ),
+            )],
+            2,
+            &mut relations,
+        );
+        assert_eq!(relations[&existing].as_ref().unwrap().relation, wrapped);
+        assert_eq!(
+            relations[&created]
+                .as_ref()
+                .unwrap()
+                .checkpoint_sequence_number,
+            2
+        );
+    }
+}
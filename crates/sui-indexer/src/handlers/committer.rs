@@ -1,8 +1,6 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::BTreeMap;
-
 use tokio::sync::watch;
 use tracing::instrument;
 
@@ -14,7 +12,6 @@ use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use crate::metrics::IndexerMetrics;
 
 use crate::store::IndexerStoreV2;
-use crate::types_v2::IndexerResult;
 use crate::IndexerConfig;
 
 use super::{CheckpointDataToCommit, EpochToCommit};
@@ -97,75 +94,37 @@ async fn commit_checkpoints<S>(
 ) where
     S: IndexerStoreV2 + Clone + Sync + Send + 'static,
 {
-    let mut checkpoint_batch = vec![];
-    let mut tx_batch = vec![];
-    let mut events_batch = vec![];
-    let mut tx_indices_batch = vec![];
-    let mut display_updates_batch = BTreeMap::new();
-    let mut object_changes_batch = vec![];
-    let mut object_history_changes_batch = vec![];
-    let mut packages_batch = vec![];
-
-    for indexed_checkpoint in indexed_checkpoint_batch {
-        let CheckpointDataToCommit {
-            checkpoint,
-            transactions,
-            events,
-            tx_indices,
-            display_updates,
-            object_changes,
-            object_history_changes,
-            packages,
-            epoch: _,
-        } = indexed_checkpoint;
-        checkpoint_batch.push(checkpoint);
-        tx_batch.push(transactions);
-        events_batch.push(events);
-        tx_indices_batch.push(tx_indices);
-        display_updates_batch.extend(display_updates.into_iter());
-        object_changes_batch.push(object_changes);
-        object_history_changes_batch.push(object_history_changes);
-        packages_batch.push(packages);
-    }
-
-    let first_checkpoint_seq = checkpoint_batch.first().as_ref().unwrap().sequence_number;
-    let last_checkpoint_seq = checkpoint_batch.last().as_ref().unwrap().sequence_number;
+    let first_checkpoint_seq = indexed_checkpoint_batch
+        .first()
+        .as_ref()
+        .unwrap()
+        .checkpoint
+        .sequence_number;
+    let last_checkpoint_seq = indexed_checkpoint_batch
+        .last()
+        .as_ref()
+        .unwrap()
+        .checkpoint
+        .sequence_number;
+    let checkpoint_num = indexed_checkpoint_batch.len();
+    let tx_count = indexed_checkpoint_batch
+        .iter()
+        .map(|c| c.transactions.len())
+        .sum::<usize>();
 
     let guard = metrics.checkpoint_db_commit_latency.start_timer();
-    let tx_batch = tx_batch.into_iter().flatten().collect::<Vec<_>>();
-    let tx_indices_batch = tx_indices_batch.into_iter().flatten().collect::<Vec<_>>();
-    let events_batch = events_batch.into_iter().flatten().collect::<Vec<_>>();
-    let packages_batch = packages_batch.into_iter().flatten().collect::<Vec<_>>();
-    let checkpoint_num = checkpoint_batch.len();
-    let tx_count = tx_batch.len();
-
-    {
-        let _step_1_guard = metrics.checkpoint_db_commit_latency_step_1.start_timer();
-        let mut persist_tasks = vec![
-            state.persist_transactions(tx_batch),
-            state.persist_tx_indices(tx_indices_batch),
-            state.persist_events(events_batch),
-            state.persist_displays(display_updates_batch),
-            state.persist_packages(packages_batch),
-            state.persist_objects(object_changes_batch.clone()),
-            state.persist_object_history(object_history_changes_batch.clone()),
-            state.persist_object_snapshot(),
-        ];
-        if let Some(epoch_data) = epoch.clone() {
-            persist_tasks.push(state.persist_epoch(epoch_data));
-        }
-        futures::future::join_all(persist_tasks)
-            .await
-            .into_iter()
-            .map(|res| {
-                if res.is_err() {
-                    error!("Failed to persist data with error: {:?}", res);
-                }
-                res
-            })
-            .collect::<IndexerResult<Vec<_>>>()
-            .expect("Persisting data into DB should not fail.");
-    }
+    // All the rows of the batch are committed in a single DB transaction together with the
+    // checkpoint watermark, so that a checkpoint is either fully indexed or not at all.
+    state
+        .persist_checkpoint_data(indexed_checkpoint_batch, epoch.clone())
+        .await
+        .tap_err(|e| {
+            error!(
+                "Failed to persist checkpoint data with error: {}",
+                e.to_string()
+            );
+        })
+        .expect("Persisting data into DB should not fail.");
 
     // handle partitioning on epoch boundary
     if let Some(epoch_data) = epoch {
@@ -179,16 +138,18 @@ async fn commit_checkpoints<S>(
         metrics.total_epoch_committed.inc();
     }
 
+    // The objects snapshot lags behind the watermark, it is updated outside of the checkpoint
+    // DB transaction.
     state
-        .persist_checkpoints(checkpoint_batch)
+        .persist_object_snapshot()
         .await
         .tap_err(|e| {
             error!(
-                "Failed to persist checkpoint data with error: {}",
+                "Failed to update objects snapshot with error: {}",
                 e.to_string()
             );
         })
-        .expect("Persisting data into DB should not fail.");
+        .expect("Updating objects snapshot in DB should not fail.");
     let elapsed = guard.stop_and_record();
 
     commit_notifier
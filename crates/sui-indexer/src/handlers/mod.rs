@@ -8,8 +8,13 @@ pub mod tx_processor;
 
 use std::collections::BTreeMap;
 
+use sui_types::base_types::ObjectID;
+
 use crate::{
     models_v2::display::StoredDisplay,
+    models_v2::object_display::StoredObjectDisplay,
+    models_v2::object_relations::StoredObjectRelation,
+    models_v2::package_modules::{StoredPackageFunction, StoredPackageModule},
     types_v2::{
         IndexedCheckpoint, IndexedDeletedObject, IndexedEpochInfo, IndexedEvent, IndexedObject,
         IndexedPackage, IndexedTransaction, TxIndex,
@@ -23,9 +28,14 @@ pub struct CheckpointDataToCommit {
     pub events: Vec<IndexedEvent>,
     pub tx_indices: Vec<TxIndex>,
     pub display_updates: BTreeMap<String, StoredDisplay>,
+    pub object_displays: Vec<StoredObjectDisplay>,
+    /// The parent of the objects related to another object, or None if they no longer are.
+    pub object_relations: BTreeMap<ObjectID, Option<StoredObjectRelation>>,
     pub object_changes: TransactionObjectChangesToCommit,
     pub object_history_changes: TransactionObjectChangesToCommit,
     pub packages: Vec<IndexedPackage>,
+    pub package_modules: Vec<StoredPackageModule>,
+    pub package_functions: Vec<StoredPackageFunction>,
     pub epoch: Option<EpochToCommit>,
 }
 
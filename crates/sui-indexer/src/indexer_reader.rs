@@ -4,21 +4,27 @@
 use crate::{
     errors::IndexerError,
     models_v2::{
+        address_activity::StoredAddressActivity,
         address_metrics::StoredAddressMetrics,
+        balance_history::StoredBalanceHistory,
         checkpoints::StoredCheckpoint,
         display::StoredDisplay,
         epoch::StoredEpochInfo,
         events::StoredEvent,
         move_call_metrics::QueriedMoveCallMetrics,
         network_metrics::StoredNetworkMetrics,
+        object_display::StoredObjectDisplay,
+        object_relations::StoredObjectRelation,
         objects::{CoinBalance, ObjectRefColumn, StoredObject},
+        package_modules::StoredPackageModule,
         packages::StoredPackage,
         transactions::StoredTransaction,
         tx_indices::TxSequenceNumber,
     },
     schema_v2::{
-        address_metrics, checkpoints, display, epochs, events, move_call_metrics, objects,
-        objects_snapshot, packages, transactions,
+        address_activity, address_metrics, balance_history, checkpoints, display, epochs, events,
+        move_call_metrics, object_display, object_relations, objects, objects_snapshot,
+        package_functions, package_modules, packages, transactions,
     },
     types_v2::{IndexerResult, OwnerType},
     PgConnectionConfig, PgConnectionPoolConfig, PgPoolConnection,
@@ -27,14 +33,17 @@ use anyhow::{anyhow, Result};
 use cached::proc_macro::cached;
 use cached::SizedCache;
 use diesel::{
-    dsl::sql, r2d2::ConnectionManager, sql_types::Bool, ExpressionMethods, OptionalExtension,
-    PgConnection, QueryDsl, RunQueryDsl, TextExpressionMethods,
+    dsl::{exists, sql},
+    r2d2::ConnectionManager,
+    sql_types::Bool,
+    BoolExpressionMethods, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl,
+    RunQueryDsl, TextExpressionMethods,
 };
 use fastcrypto::encoding::Encoding;
 use fastcrypto::encoding::Hex;
 use itertools::{any, Itertools};
 use move_core_types::annotated_value::MoveStructLayout;
-use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::{StructTag, TypeTag};
 use std::{
     collections::{BTreeMap, HashMap},
     sync::{Arc, RwLock},
@@ -42,7 +51,8 @@ use std::{
 use sui_json_rpc_types::DisplayFieldsResponse;
 use sui_json_rpc_types::{
     AddressMetrics, CheckpointId, EpochInfo, EventFilter, MoveCallMetrics, MoveFunctionName,
-    NetworkMetrics, SuiEvent, SuiObjectDataFilter, SuiTransactionBlockResponse, TransactionFilter,
+    NetworkMetrics, PackageModuleCursor, SuiEvent, SuiObjectDataFilter,
+    SuiTransactionBlockResponse, TransactionFilter,
 };
 use sui_json_rpc_types::{
     Balance, Coin as SuiCoin, SuiCoinMetadata, SuiTransactionBlockEffects,
@@ -66,11 +76,14 @@ use sui_types::{coin::CoinMetadata, event::EventID};
 pub const TX_SEQUENCE_NUMBER_STR: &str = "tx_sequence_number";
 pub const TRANSACTION_DIGEST_STR: &str = "transaction_digest";
 pub const EVENT_SEQUENCE_NUMBER_STR: &str = "event_sequence_number";
+// Bounds the traversal of the object graph in case of a cycle in stale relations.
+const MAX_OBJECT_ANCESTORS: usize = 100;
 
 #[derive(Clone)]
 pub struct IndexerReader {
     pool: crate::PgConnectionPool,
     package_cache: PackageCache,
+    event_layout_cache: EventLayoutCache,
 }
 
 // Impl for common initialization and utilities
@@ -101,6 +114,7 @@ impl IndexerReader {
         Ok(Self {
             pool,
             package_cache: Default::default(),
+            event_layout_cache: Default::default(),
         })
     }
 
@@ -329,6 +343,41 @@ impl IndexerReader {
             .await
     }
 
+    /// Returns the layout of `event_type`, and the version of the package that defines it.
+    pub fn get_event_layout(
+        &self,
+        event_type: &StructTag,
+    ) -> Result<(SequenceNumber, MoveStructLayout), IndexerError> {
+        let package_id = ObjectID::from(event_type.address);
+        let package = self.get_package(&package_id)?.ok_or_else(|| {
+            IndexerError::InvalidArgumentError(format!(
+                "Package object does not exist with ID {package_id}"
+            ))
+        })?;
+        let key = EventLayoutKey {
+            package: package_id,
+            module: event_type.module.to_string(),
+            name: event_type.name.to_string(),
+            type_params: event_type.type_params.clone(),
+            package_version: package.version(),
+        };
+        if let Some(layout) = self.event_layout_cache.get(&key) {
+            return Ok((key.package_version, layout));
+        }
+
+        let layout = MoveObject::get_layout_from_struct_tag(event_type.clone(), self)?;
+        self.event_layout_cache.insert(key.clone(), layout.clone());
+        Ok((key.package_version, layout))
+    }
+
+    pub async fn get_event_layout_in_blocking_task(
+        &self,
+        event_type: StructTag,
+    ) -> Result<(SequenceNumber, MoveStructLayout), IndexerError> {
+        self.spawn_blocking(move |this| this.get_event_layout(&event_type))
+            .await
+    }
+
     pub fn get_epoch_info_from_db(
         &self,
         epoch: Option<EpochId>,
@@ -1613,6 +1662,192 @@ impl IndexerReader {
             .collect())
     }
 
+    pub fn get_address_activity(
+        &self,
+        address: SuiAddress,
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<StoredAddressActivity>> {
+        self.run_query(|conn| {
+            let mut boxed_query = address_activity::table
+                .filter(address_activity::address.eq(address.to_vec()))
+                .into_boxed();
+            if let Some(cursor) = cursor {
+                if descending_order {
+                    boxed_query =
+                        boxed_query.filter(address_activity::tx_sequence_number.lt(cursor as i64));
+                } else {
+                    boxed_query =
+                        boxed_query.filter(address_activity::tx_sequence_number.gt(cursor as i64));
+                }
+            }
+            if descending_order {
+                boxed_query = boxed_query.order_by(address_activity::tx_sequence_number.desc());
+            } else {
+                boxed_query = boxed_query.order_by(address_activity::tx_sequence_number.asc());
+            }
+
+            boxed_query.limit(limit as i64).load(conn)
+        })
+    }
+
+    pub fn get_balance_history(
+        &self,
+        owner: SuiAddress,
+        coin_type: String,
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<StoredBalanceHistory>> {
+        self.run_query(|conn| {
+            let mut boxed_query = balance_history::table
+                .filter(balance_history::owner_id.eq(owner.to_vec()))
+                .filter(balance_history::coin_type.eq(coin_type))
+                .into_boxed();
+            if let Some(cursor) = cursor {
+                if descending_order {
+                    boxed_query = boxed_query
+                        .filter(balance_history::checkpoint_sequence_number.lt(cursor as i64));
+                } else {
+                    boxed_query = boxed_query
+                        .filter(balance_history::checkpoint_sequence_number.gt(cursor as i64));
+                }
+            }
+            if descending_order {
+                boxed_query =
+                    boxed_query.order_by(balance_history::checkpoint_sequence_number.desc());
+            } else {
+                boxed_query =
+                    boxed_query.order_by(balance_history::checkpoint_sequence_number.asc());
+            }
+
+            boxed_query.limit(limit as i64).load(conn)
+        })
+    }
+
+    /// Objects owned by `owner` with a rendered Display, in the order of their IDs.
+    /// `object_type` is a canonical struct type, matching the objects of this type with any
+    /// type parameters if it has none.
+    pub fn get_owned_display_objects(
+        &self,
+        owner: SuiAddress,
+        object_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> IndexerResult<Vec<StoredObjectDisplay>> {
+        self.run_query(|conn| {
+            let mut boxed_query = object_display::table
+                .filter(object_display::owner_id.eq(owner.to_vec()))
+                .into_boxed();
+            if let Some(object_type) = object_type {
+                boxed_query = boxed_query
+                    .filter(object_display::object_type.like(format!("{}%", object_type)));
+            }
+            if let Some(cursor) = cursor {
+                boxed_query = boxed_query.filter(object_display::object_id.gt(cursor.to_vec()));
+            }
+
+            boxed_query
+                .order_by(object_display::object_id.asc())
+                .limit(limit as i64)
+                .load(conn)
+        })
+    }
+
+    /// Objects owned by or wrapped in `parent_id`, in the order of their IDs.
+    pub fn get_object_children(
+        &self,
+        parent_id: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> IndexerResult<Vec<StoredObjectRelation>> {
+        self.run_query(|conn| {
+            let mut boxed_query = object_relations::table
+                .filter(object_relations::parent_id.eq(parent_id.to_vec()))
+                .into_boxed();
+            if let Some(cursor) = cursor {
+                boxed_query = boxed_query.filter(object_relations::object_id.gt(cursor.to_vec()));
+            }
+
+            boxed_query
+                .order_by(object_relations::object_id.asc())
+                .limit(limit as i64)
+                .load(conn)
+        })
+    }
+
+    /// The chain of relations from `object_id` up to the first ancestor without a parent,
+    /// nearest first.
+    pub fn get_object_ancestors(
+        &self,
+        object_id: ObjectID,
+    ) -> IndexerResult<Vec<StoredObjectRelation>> {
+        self.run_query(|conn| {
+            let mut ancestors: Vec<StoredObjectRelation> = vec![];
+            let mut object_id = object_id.to_vec();
+            while ancestors.len() < MAX_OBJECT_ANCESTORS {
+                let Some(relation) = object_relations::table
+                    .filter(object_relations::object_id.eq(object_id))
+                    .first::<StoredObjectRelation>(conn)
+                    .optional()?
+                else {
+                    break;
+                };
+                object_id = relation.parent_id.clone();
+                ancestors.push(relation);
+            }
+            Ok::<_, diesel::result::Error>(ancestors)
+        })
+    }
+
+    /// Modules whose name starts with `module_name`, and with a public or entry function whose
+    /// name starts with `function_name`, in the order of their package IDs and names.
+    pub fn search_package_modules(
+        &self,
+        module_name: Option<String>,
+        function_name: Option<String>,
+        cursor: Option<PackageModuleCursor>,
+        limit: usize,
+    ) -> IndexerResult<Vec<StoredPackageModule>> {
+        self.run_query(|conn| {
+            let mut boxed_query = package_modules::table.into_boxed();
+            if let Some(module_name) = module_name {
+                boxed_query = boxed_query
+                    .filter(package_modules::module_name.like(like_prefix_pattern(&module_name)));
+            }
+            if let Some(function_name) = function_name {
+                boxed_query = boxed_query.filter(exists(
+                    package_functions::table
+                        .filter(package_functions::package_id.eq(package_modules::package_id))
+                        .filter(package_functions::module_name.eq(package_modules::module_name))
+                        .filter(
+                            package_functions::function_name
+                                .like(like_prefix_pattern(&function_name)),
+                        ),
+                ));
+            }
+            if let Some(cursor) = cursor {
+                let package_id = cursor.package_id.to_vec();
+                boxed_query = boxed_query.filter(
+                    package_modules::package_id.gt(package_id.clone()).or(
+                        package_modules::package_id
+                            .eq(package_id)
+                            .and(package_modules::module_name.gt(cursor.module_name)),
+                    ),
+                );
+            }
+
+            boxed_query
+                .order_by((
+                    package_modules::package_id.asc(),
+                    package_modules::module_name.asc(),
+                ))
+                .limit(limit as i64)
+                .load(conn)
+        })
+    }
+
     pub(crate) async fn get_display_fields(
         &self,
         original_object: &sui_types::object::Object,
@@ -1733,6 +1968,32 @@ impl PackageCache {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct EventLayoutKey {
+    package: ObjectID,
+    module: String,
+    name: String,
+    type_params: Vec<TypeTag>,
+    package_version: SequenceNumber,
+}
+
+/// Resolved layouts of event types, so that SDKs decoding the same events over and over don't
+/// rebuild the layout from the modules of the package each time.
+#[derive(Clone, Default)]
+struct EventLayoutCache {
+    inner: Arc<RwLock<BTreeMap<EventLayoutKey, MoveStructLayout>>>,
+}
+
+impl EventLayoutCache {
+    fn insert(&self, key: EventLayoutKey, layout: MoveStructLayout) {
+        self.inner.write().unwrap().insert(key, layout);
+    }
+
+    fn get(&self, key: &EventLayoutKey) -> Option<MoveStructLayout> {
+        self.inner.read().unwrap().get(key).cloned()
+    }
+}
+
 impl move_core_types::resolver::ModuleResolver for IndexerReader {
     type Error = IndexerError;
 
@@ -1836,3 +2097,9 @@ fn get_single_obj_id_from_package_publish(
         Ok(None)
     }
 }
+
+/// LIKE pattern matching the strings starting with `prefix`. Move identifiers may contain `_`,
+/// which is a wildcard in LIKE patterns, but neither `%` nor `\`.
+fn like_prefix_pattern(prefix: &str) -> String {
+    format!("{}%", prefix.replace('_', "\\_"))
+}
@@ -8,7 +8,7 @@ use crate::apis::{
 use crate::errors::IndexerError;
 use crate::indexer_reader::IndexerReader;
 use crate::metrics::IndexerMetrics;
-use crate::IndexerConfig;
+use crate::{start_network_stats_server, IndexerConfig};
 use anyhow::Result;
 use mysten_metrics::spawn_monitored_task;
 use prometheus::Registry;
@@ -21,8 +21,10 @@ use tracing::info;
 
 use crate::framework::fetcher::CheckpointFetcher;
 use crate::handlers::checkpoint_handler_v2::new_handlers;
+use crate::processors_v2::network_stats_processor::NetworkStatsCache;
 use crate::processors_v2::processor_orchestrator_v2::ProcessorOrchestratorV2;
-use crate::store::{IndexerStoreV2, PgIndexerAnalyticalStore};
+use crate::store::{IndexerStoreV2, PgIndexerAnalyticalStore, PgIndexerWebhookStore};
+use crate::webhooks::WebhookWorker;
 
 pub struct IndexerV2;
 
@@ -39,11 +41,19 @@ impl IndexerV2 {
             env!("CARGO_PKG_VERSION")
         );
 
-        // None will be returned when checkpoints table is empty.
+        // None will be returned when the checkpoint watermark is not set yet.
         let last_seq_from_db = store
             .get_latest_tx_checkpoint_sequence_number()
             .await
             .expect("Failed to get latest tx checkpoint sequence number from DB");
+        // When backfilling, the checkpoints before the start checkpoint are skipped.
+        let last_downloaded_checkpoint = match config.start_checkpoint {
+            Some(start_checkpoint) => {
+                info!("Backfilling from checkpoint {start_checkpoint}");
+                start_checkpoint.checked_sub(1)
+            }
+            None => last_seq_from_db,
+        };
         let (downloaded_checkpoint_data_sender, downloaded_checkpoint_data_receiver) =
             mysten_metrics::metered_channel::channel(
                 DOWNLOAD_QUEUE_SIZE,
@@ -57,12 +67,13 @@ impl IndexerV2 {
         let rest_client = sui_rest_api::Client::new(&rest_api_url);
         let fetcher = CheckpointFetcher::new(
             rest_client.clone(),
-            last_seq_from_db,
+            last_downloaded_checkpoint,
             downloaded_checkpoint_data_sender,
-        );
+        )
+        .end_checkpoint(config.end_checkpoint);
         spawn_monitored_task!(fetcher.run());
 
-        let checkpoint_handler = new_handlers(store, metrics, config).await?;
+        let (checkpoint_handler, commit_task) = new_handlers(store, metrics, config).await?;
 
         crate::framework::runner::run(
             mysten_metrics::metered_channel::ReceiverStream::new(
@@ -72,6 +83,13 @@ impl IndexerV2 {
         )
         .await;
 
+        // The fetcher stopped at the end checkpoint and the handler has been dropped, wait for
+        // the last checkpoints to be committed.
+        commit_task.await?;
+        if let Some(end_checkpoint) = config.end_checkpoint {
+            info!("Indexed checkpoints up to end checkpoint {end_checkpoint}");
+        }
+
         Ok(())
     }
 
@@ -96,6 +114,7 @@ impl IndexerV2 {
     }
 
     pub async fn start_analytical_worker(
+        config: &IndexerConfig,
         store: PgIndexerAnalyticalStore,
         metrics: IndexerMetrics,
     ) -> Result<(), IndexerError> {
@@ -103,10 +122,33 @@ impl IndexerV2 {
             "Sui indexerV2 Analytical Worker (version {:?}) started...",
             env!("CARGO_PKG_VERSION")
         );
-        let mut processor_orchestrator_v2 = ProcessorOrchestratorV2::new(store, metrics);
+        let network_stats = NetworkStatsCache::default();
+        let network_stats_addr: SocketAddr = format!(
+            "{}:{}",
+            config.client_metric_host, config.network_stats_port
+        )
+        .parse()
+        .map_err(|e| {
+            IndexerError::InvalidArgumentError(format!("Invalid network stats address: {e}"))
+        })?;
+        start_network_stats_server(network_stats_addr, network_stats.clone());
+        let mut processor_orchestrator_v2 =
+            ProcessorOrchestratorV2::new(store, metrics, network_stats);
         processor_orchestrator_v2.run_forever().await;
         Ok(())
     }
+
+    pub async fn start_webhook_worker(
+        store: PgIndexerWebhookStore,
+        metrics: IndexerMetrics,
+    ) -> Result<(), IndexerError> {
+        info!(
+            "Sui indexerV2 Webhook Worker (version {:?}) started...",
+            env!("CARGO_PKG_VERSION")
+        );
+        WebhookWorker::new(store, metrics)?.run_forever().await;
+        Ok(())
+    }
 }
 
 pub async fn build_json_rpc_server(
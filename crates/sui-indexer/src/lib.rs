@@ -7,7 +7,8 @@ use std::net::SocketAddr;
 use std::{collections::HashMap, time::Duration};
 
 use anyhow::{anyhow, Result};
-use axum::{extract::Extension, http::StatusCode, routing::get, Router};
+use axum::response::{IntoResponse, Response};
+use axum::{extract::Extension, http::StatusCode, routing::get, Json, Router};
 use clap::Parser;
 use diesel::pg::PgConnection;
 use diesel::r2d2::ConnectionManager;
@@ -26,6 +27,7 @@ use apis::{
 use errors::IndexerError;
 use mysten_metrics::{spawn_monitored_task, RegistryService};
 use processors::processor_orchestrator::ProcessorOrchestrator;
+use processors_v2::network_stats_processor::NetworkStatsCache;
 use store::IndexerStore;
 use sui_json_rpc::{JsonRpcServerBuilder, ServerHandle, ServerType};
 use sui_json_rpc_api::CLIENT_SDK_TYPE_HEADER;
@@ -48,16 +50,19 @@ pub mod processors;
 pub mod processors_v2;
 pub mod schema;
 pub mod schema_v2;
+pub mod schema_v2_views;
 pub mod store;
 pub mod test_utils;
 pub mod types;
 pub mod types_v2;
 pub mod utils;
+pub mod webhooks;
 
 pub type PgConnectionPool = diesel::r2d2::Pool<ConnectionManager<PgConnection>>;
 pub type PgPoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<PgConnection>>;
 
 const METRICS_ROUTE: &str = "/metrics";
+const NETWORK_STATS_ROUTE: &str = "/network-stats";
 /// Returns all endpoints for which we have implemented on the indexer,
 /// some of them are not validated yet.
 /// NOTE: we only use this for integration testing
@@ -114,11 +119,25 @@ pub struct IndexerConfig {
     pub rpc_server_worker: bool,
     #[clap(long)]
     pub analytical_worker: bool,
+    /// Port of the HTTP server of the analytical worker, serving the latest network stats as
+    /// JSON at `/network-stats`. The host is `--client-metric-host`.
+    #[clap(long, default_value = "9185", global = true)]
+    pub network_stats_port: u16,
+    /// Deliver the indexed events to the webhook subscribers. Only supported with `--use-v2`.
+    #[clap(long)]
+    pub webhook_worker: bool,
     // NOTE: experimental only, do not use in production.
     #[clap(long)]
     pub skip_db_commit: bool,
     #[clap(long)]
     pub use_v2: bool,
+    /// Index from this checkpoint instead of resuming after the last checkpoint in the DB,
+    /// e.g. to backfill a range of checkpoints. Only supported with `--use-v2`.
+    #[clap(long)]
+    pub start_checkpoint: Option<u64>,
+    /// Stop the writer once this checkpoint has been committed. Only supported with `--use-v2`.
+    #[clap(long)]
+    pub end_checkpoint: Option<u64>,
 }
 
 impl IndexerConfig {
@@ -173,8 +192,12 @@ impl Default for IndexerConfig {
             fullnode_sync_worker: true,
             rpc_server_worker: true,
             analytical_worker: false,
+            network_stats_port: 9185,
+            webhook_worker: false,
             skip_db_commit: false,
             use_v2: false,
+            start_checkpoint: None,
+            end_checkpoint: None,
         }
     }
 }
@@ -456,6 +479,31 @@ pub fn start_prometheus_server(
     Ok((registry_service, registry))
 }
 
+/// Serve the latest network stats computed by the analytical worker, for public dashboards.
+pub fn start_network_stats_server(addr: SocketAddr, network_stats: NetworkStatsCache) {
+    let app = Router::new()
+        .route(NETWORK_STATS_ROUTE, get(network_stats_handler))
+        .layer(Extension(network_stats));
+
+    tokio::spawn(async move {
+        axum::Server::bind(&addr)
+            .serve(app.into_make_service())
+            .await
+            .unwrap();
+    });
+}
+
+async fn network_stats_handler(Extension(network_stats): Extension<NetworkStatsCache>) -> Response {
+    match network_stats.read().unwrap().clone() {
+        Some(stats) => Json(stats).into_response(),
+        None => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Network stats are not computed yet",
+        )
+            .into_response(),
+    }
+}
+
 async fn metrics(Extension(registry_service): Extension<RegistryService>) -> (StatusCode, String) {
     let metrics_families = registry_service.gather_all();
     match TextEncoder.encode_to_string(&metrics_families) {
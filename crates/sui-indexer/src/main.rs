@@ -11,6 +11,7 @@ use sui_indexer::start_prometheus_server;
 use sui_indexer::store::PgIndexerAnalyticalStore;
 use sui_indexer::store::PgIndexerStore;
 use sui_indexer::store::PgIndexerStoreV2;
+use sui_indexer::store::PgIndexerWebhookStore;
 use sui_indexer::utils::reset_database;
 use sui_indexer::{get_pg_pool_connection, new_pg_connection_pool, Indexer, IndexerConfig};
 
@@ -98,7 +99,15 @@ async fn main() -> Result<(), IndexerError> {
             return IndexerV2::start_reader(&indexer_config, &registry, db_url).await;
         } else if indexer_config.analytical_worker {
             let store = PgIndexerAnalyticalStore::new(blocking_cp);
-            return IndexerV2::start_analytical_worker(store, indexer_metrics.clone()).await;
+            return IndexerV2::start_analytical_worker(
+                &indexer_config,
+                store,
+                indexer_metrics.clone(),
+            )
+            .await;
+        } else if indexer_config.webhook_worker {
+            let store = PgIndexerWebhookStore::new(blocking_cp);
+            return IndexerV2::start_webhook_worker(store, indexer_metrics.clone()).await;
         }
     }
 
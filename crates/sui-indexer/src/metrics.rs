@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use prometheus::{
-    register_histogram_with_registry, register_int_counter_with_registry,
-    register_int_gauge_with_registry, Histogram, IntCounter, IntGauge, Registry,
+    register_gauge_with_registry, register_histogram_with_registry,
+    register_int_counter_with_registry, register_int_gauge_vec_with_registry,
+    register_int_gauge_with_registry, Gauge, Histogram, IntCounter, IntGauge, IntGaugeVec,
+    Registry,
 };
 
 /// Prometheus metrics for sui-indexer.
@@ -35,6 +37,14 @@ pub struct IndexerMetrics {
     pub latest_move_call_metrics_tx_seq: IntGauge,
     pub latest_address_metrics_tx_seq: IntGauge,
     pub latest_network_metrics_cp_seq: IntGauge,
+    // network stats
+    pub network_rolling_tps: Gauge,
+    pub network_checkpoint_lag_ms: IntGauge,
+    pub network_gas_price_percentiles: IntGaugeVec,
+    pub network_package_gas_usage: IntGaugeVec,
+    // webhooks
+    pub total_webhook_deliveries: IntCounter,
+    pub total_webhook_dead_letters: IntCounter,
     // checkpoint E2E latency is:
     // fullnode_download_latency + checkpoint_index_latency + db_commit_latency
     pub fullnode_checkpoint_data_download_latency: Histogram,
@@ -191,6 +201,44 @@ impl IndexerMetrics {
                 "Latest network metrics cp seq",
                 registry,
             ).unwrap(),
+            network_rolling_tps: register_gauge_with_registry!(
+                "network_rolling_tps",
+                "Transaction blocks per second over the latest checkpoints",
+                registry,
+            )
+            .unwrap(),
+            network_checkpoint_lag_ms: register_int_gauge_with_registry!(
+                "network_checkpoint_lag_ms",
+                "Time elapsed since the timestamp of the latest indexed checkpoint, in milliseconds",
+                registry,
+            )
+            .unwrap(),
+            network_gas_price_percentiles: register_int_gauge_vec_with_registry!(
+                "network_gas_price_percentiles",
+                "Percentiles of the gas price of the transactions in the latest checkpoints",
+                &["percentile"],
+                registry,
+            )
+            .unwrap(),
+            network_package_gas_usage: register_int_gauge_vec_with_registry!(
+                "network_package_gas_usage",
+                "Net gas usage of the transactions calling the most used packages in the latest checkpoints",
+                &["package"],
+                registry,
+            )
+            .unwrap(),
+            total_webhook_deliveries: register_int_counter_with_registry!(
+                "total_webhook_deliveries",
+                "Total number of events delivered to webhook subscribers",
+                registry,
+            )
+            .unwrap(),
+            total_webhook_dead_letters: register_int_counter_with_registry!(
+                "total_webhook_dead_letters",
+                "Total number of events moved to the webhook dead letters after all delivery attempts failed",
+                registry,
+            )
+            .unwrap(),
             fullnode_checkpoint_data_download_latency: register_histogram_with_registry!(
                 "fullnode_checkpoint_data_download_latency",
                 "Time spent in downloading checkpoint and transation for a new checkpoint from the Full Node",
@@ -0,0 +1,75 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use diesel::prelude::*;
+use sui_json_rpc_types::AddressActivity;
+use sui_types::base_types::SuiAddress;
+use sui_types::digests::TransactionDigest;
+
+use crate::errors::IndexerError;
+use crate::schema_v2::address_activity;
+use crate::types_v2::TxIndex;
+
+#[derive(Queryable, Insertable, Debug, Clone, Default)]
+#[diesel(table_name = address_activity)]
+pub struct StoredAddressActivity {
+    pub address: Vec<u8>,
+    pub tx_sequence_number: i64,
+    pub transaction_digest: Vec<u8>,
+    pub checkpoint_sequence_number: i64,
+    pub timestamp_ms: i64,
+    pub is_sender: bool,
+    pub is_recipient: bool,
+}
+
+impl StoredAddressActivity {
+    /// One row for each address sending or receiving the transaction of `index`.
+    pub fn from_tx_index(index: &TxIndex, timestamp_ms: u64) -> Vec<Self> {
+        let mut activities: BTreeMap<SuiAddress, Self> = BTreeMap::new();
+        let addresses = index
+            .senders
+            .iter()
+            .map(|a| (a, true))
+            .chain(index.recipients.iter().map(|a| (a, false)));
+        for (address, is_sender) in addresses {
+            let activity = activities.entry(*address).or_insert_with(|| Self {
+                address: address.to_vec(),
+                tx_sequence_number: index.tx_sequence_number as i64,
+                transaction_digest: index.transaction_digest.into_inner().to_vec(),
+                checkpoint_sequence_number: index.checkpoint_sequence_number as i64,
+                timestamp_ms: timestamp_ms as i64,
+                is_sender: false,
+                is_recipient: false,
+            });
+            if is_sender {
+                activity.is_sender = true;
+            } else {
+                activity.is_recipient = true;
+            }
+        }
+        activities.into_values().collect()
+    }
+}
+
+impl TryFrom<StoredAddressActivity> for AddressActivity {
+    type Error = IndexerError;
+
+    fn try_from(activity: StoredAddressActivity) -> Result<Self, Self::Error> {
+        let digest =
+            TransactionDigest::try_from(activity.transaction_digest.as_slice()).map_err(|e| {
+                IndexerError::SerdeError(format!(
+                    "Failed to parse transaction digest: {:?}, error: {}",
+                    activity.transaction_digest, e
+                ))
+            })?;
+        Ok(Self {
+            digest,
+            checkpoint: activity.checkpoint_sequence_number as u64,
+            timestamp_ms: activity.timestamp_ms as u64,
+            is_sender: activity.is_sender,
+            is_recipient: activity.is_recipient,
+        })
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashMap};
+
+use diesel::prelude::*;
+use sui_json_rpc_types::BalanceHistory;
+use sui_types::base_types::SuiAddress;
+use sui_types::object::Owner;
+
+use crate::errors::IndexerError;
+use crate::schema_v2::balance_history;
+use crate::types_v2::IndexedTransaction;
+
+#[derive(Queryable, Insertable, Debug, Clone, Default)]
+#[diesel(table_name = balance_history)]
+pub struct StoredBalanceHistory {
+    pub owner_id: Vec<u8>,
+    pub coin_type: String,
+    pub checkpoint_sequence_number: i64,
+    pub timestamp_ms: i64,
+    pub balance: i64,
+}
+
+impl From<StoredBalanceHistory> for BalanceHistory {
+    fn from(history: StoredBalanceHistory) -> Self {
+        Self {
+            coin_type: history.coin_type,
+            checkpoint: history.checkpoint_sequence_number as u64,
+            timestamp_ms: history.timestamp_ms as u64,
+            total_balance: history.balance as u128,
+        }
+    }
+}
+
+/// Net change of the coin balances owned by addresses over a checkpoint.
+#[derive(Debug, Clone, Default)]
+pub struct CheckpointBalanceChanges {
+    pub checkpoint_sequence_number: u64,
+    pub timestamp_ms: u64,
+    /// Keyed by owner and canonical coin type, as in the `objects` table.
+    pub changes: BTreeMap<(SuiAddress, String), i128>,
+}
+
+impl CheckpointBalanceChanges {
+    pub fn new(
+        checkpoint_sequence_number: u64,
+        timestamp_ms: u64,
+        transactions: &[IndexedTransaction],
+    ) -> Self {
+        let mut changes = BTreeMap::new();
+        for change in transactions.iter().flat_map(|tx| tx.balance_change.iter()) {
+            // Coins owned by objects or shared are not part of any address portfolio.
+            let Owner::AddressOwner(owner) = change.owner else {
+                continue;
+            };
+            let coin_type = change.coin_type.to_canonical_string(/* with_prefix */ true);
+            *changes.entry((owner, coin_type)).or_insert(0) += change.amount;
+        }
+        changes.retain(|_, amount| *amount != 0);
+        Self {
+            checkpoint_sequence_number,
+            timestamp_ms,
+            changes,
+        }
+    }
+}
+
+/// Applies the balance changes of consecutive checkpoints, in order, to `balances`, the balances
+/// keyed by owner and coin type before the first of them, and returns the resulting balance of
+/// every owner and coin type changed in each checkpoint. Owners and coin types missing from
+/// `balances` start from zero.
+pub fn apply_balance_changes(
+    mut balances: HashMap<(Vec<u8>, String), i128>,
+    balance_changes: &[CheckpointBalanceChanges],
+) -> Result<Vec<StoredBalanceHistory>, IndexerError> {
+    let mut history = vec![];
+    let mut previous_checkpoint = None;
+    for changes in balance_changes {
+        if previous_checkpoint
+            .is_some_and(|previous| changes.checkpoint_sequence_number <= previous)
+        {
+            return Err(IndexerError::InvalidArgumentError(format!(
+                "Balance changes of checkpoint {} applied after checkpoint {:?}",
+                changes.checkpoint_sequence_number, previous_checkpoint
+            )));
+        }
+        previous_checkpoint = Some(changes.checkpoint_sequence_number);
+
+        for ((owner, coin_type), amount) in &changes.changes {
+            let owner_id = owner.to_vec();
+            let balance = balances
+                .entry((owner_id.clone(), coin_type.clone()))
+                .or_insert(0);
+            *balance += amount;
+            let stored_balance = i64::try_from(*balance)
+                .ok()
+                .filter(|balance| *balance >= 0)
+                .ok_or_else(|| {
+                    IndexerError::PersistentStorageDataCorruptionError(format!(
+                        "Balance {} of {} owned by {} at checkpoint {} is out of range",
+                        balance, coin_type, owner, changes.checkpoint_sequence_number
+                    ))
+                })?;
+            history.push(StoredBalanceHistory {
+                owner_id,
+                coin_type: coin_type.clone(),
+                checkpoint_sequence_number: changes.checkpoint_sequence_number as i64,
+                timestamp_ms: changes.timestamp_ms as i64,
+                balance: stored_balance,
+            });
+        }
+    }
+    Ok(history)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::ObjectID;
+
+    const SUI: &str = "0x2::sui::SUI";
+
+    fn changes(
+        checkpoint_sequence_number: u64,
+        changes: Vec<(SuiAddress, i128)>,
+    ) -> CheckpointBalanceChanges {
+        CheckpointBalanceChanges {
+            checkpoint_sequence_number,
+            timestamp_ms: checkpoint_sequence_number * 1000,
+            changes: changes
+                .into_iter()
+                .map(|(owner, amount)| ((owner, SUI.to_string()), amount))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_apply_balance_changes_from_seeded_balances() {
+        let alice = SuiAddress::from(ObjectID::from_single_byte(1));
+        let bob = SuiAddress::from(ObjectID::from_single_byte(2));
+        let seed = HashMap::from([((alice.to_vec(), SUI.to_string()), 100)]);
+
+        let history = apply_balance_changes(
+            seed,
+            &[
+                changes(5, vec![(alice, -30), (bob, 30)]),
+                changes(7, vec![(alice, 10)]),
+            ],
+        )
+        .unwrap();
+        let balances = history
+            .iter()
+            .map(|h| (h.owner_id.clone(), h.checkpoint_sequence_number, h.balance))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            balances,
+            vec![
+                (alice.to_vec(), 5, 70),
+                (bob.to_vec(), 5, 30),
+                (alice.to_vec(), 7, 80),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_balance_changes_rejects_out_of_range_balances() {
+        let alice = SuiAddress::from(ObjectID::from_single_byte(1));
+        let seed = HashMap::from([((alice.to_vec(), SUI.to_string()), i64::MAX as i128)]);
+        assert!(apply_balance_changes(seed, &[changes(1, vec![(alice, 1)])]).is_err());
+        assert!(apply_balance_changes(HashMap::new(), &[changes(1, vec![(alice, -1)])]).is_err());
+    }
+
+    #[test]
+    fn test_apply_balance_changes_rejects_out_of_order_checkpoints() {
+        let alice = SuiAddress::from(ObjectID::from_single_byte(1));
+        let result = apply_balance_changes(
+            HashMap::new(),
+            &[changes(2, vec![(alice, 1)]), changes(1, vec![(alice, 1)])],
+        );
+        assert!(result.is_err());
+    }
+}
@@ -1,15 +1,22 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod address_activity;
 pub mod address_metrics;
+pub mod balance_history;
 pub mod checkpoints;
 pub mod display;
 pub mod epoch;
 pub mod events;
 pub mod move_call_metrics;
 pub mod network_metrics;
+pub mod object_display;
+pub mod object_relations;
 pub mod objects;
+pub mod package_modules;
 pub mod packages;
 pub mod transactions;
 pub mod tx_count_metrics;
 pub mod tx_indices;
+pub mod watermark;
+pub mod webhooks;
@@ -0,0 +1,77 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use diesel::prelude::*;
+use sui_json_rpc_types::DisplayObject;
+use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+use sui_types::digests::ObjectDigest;
+
+use crate::errors::IndexerError;
+use crate::schema_v2::object_display;
+use crate::types_v2::IndexedObject;
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = object_display)]
+pub struct StoredObjectDisplay {
+    pub object_id: Vec<u8>,
+    pub object_version: i64,
+    pub object_digest: Vec<u8>,
+    pub checkpoint_sequence_number: i64,
+    pub owner_id: Vec<u8>,
+    pub object_type: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+}
+
+impl StoredObjectDisplay {
+    /// `fields` are the Display fields rendered for `object`, owned by `owner`.
+    pub fn new(
+        object: &IndexedObject,
+        owner: SuiAddress,
+        object_type: String,
+        fields: &BTreeMap<String, String>,
+    ) -> Self {
+        Self {
+            object_id: object.object_id.to_vec(),
+            object_version: object.object_version as i64,
+            object_digest: object.object_digest.into_inner().to_vec(),
+            checkpoint_sequence_number: object.checkpoint_sequence_number as i64,
+            owner_id: owner.to_vec(),
+            object_type,
+            name: fields.get("name").cloned(),
+            description: fields.get("description").cloned(),
+            image_url: fields.get("image_url").cloned(),
+        }
+    }
+}
+
+impl TryFrom<StoredObjectDisplay> for DisplayObject {
+    type Error = IndexerError;
+
+    fn try_from(display: StoredObjectDisplay) -> Result<Self, Self::Error> {
+        let object_id = ObjectID::from_bytes(&display.object_id).map_err(|e| {
+            IndexerError::SerdeError(format!(
+                "Failed to parse object id: {:?}, error: {}",
+                display.object_id, e
+            ))
+        })?;
+        let digest = ObjectDigest::try_from(display.object_digest.as_slice()).map_err(|e| {
+            IndexerError::SerdeError(format!(
+                "Failed to parse object digest: {:?}, error: {}",
+                display.object_digest, e
+            ))
+        })?;
+        Ok(Self {
+            object_id,
+            version: SequenceNumber::from_u64(display.object_version as u64),
+            digest,
+            type_: display.object_type,
+            name: display.name,
+            description: display.description,
+            image_url: display.image_url,
+        })
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use diesel::prelude::*;
+use sui_json_rpc_types::{ObjectRelation, ObjectRelationKind};
+use sui_types::base_types::ObjectID;
+
+use crate::errors::IndexerError;
+use crate::schema_v2::object_relations;
+use crate::types_v2::ObjectRelationType;
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = object_relations)]
+pub struct StoredObjectRelation {
+    pub object_id: Vec<u8>,
+    pub parent_id: Vec<u8>,
+    pub relation: i16,
+    pub checkpoint_sequence_number: i64,
+}
+
+impl StoredObjectRelation {
+    pub fn new(
+        object_id: ObjectID,
+        parent_id: ObjectID,
+        relation: ObjectRelationType,
+        checkpoint_sequence_number: u64,
+    ) -> Self {
+        Self {
+            object_id: object_id.to_vec(),
+            parent_id: parent_id.to_vec(),
+            relation: relation as i16,
+            checkpoint_sequence_number: checkpoint_sequence_number as i64,
+        }
+    }
+}
+
+impl TryFrom<StoredObjectRelation> for ObjectRelation {
+    type Error = IndexerError;
+
+    fn try_from(relation: StoredObjectRelation) -> Result<Self, Self::Error> {
+        let object_id = ObjectID::from_bytes(&relation.object_id).map_err(|e| {
+            IndexerError::SerdeError(format!(
+                "Failed to parse object id: {:?}, error: {}",
+                relation.object_id, e
+            ))
+        })?;
+        let parent_id = ObjectID::from_bytes(&relation.parent_id).map_err(|e| {
+            IndexerError::SerdeError(format!(
+                "Failed to parse parent object id: {:?}, error: {}",
+                relation.parent_id, e
+            ))
+        })?;
+        let kind = match ObjectRelationType::try_from(relation.relation)? {
+            ObjectRelationType::DynamicField => ObjectRelationKind::DynamicField,
+            ObjectRelationType::Wrapped => ObjectRelationKind::Wrapped,
+        };
+        Ok(Self {
+            object_id,
+            parent_id,
+            kind,
+            checkpoint: relation.checkpoint_sequence_number as u64,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use diesel::prelude::*;
+use sui_json_rpc_types::{PackageModule, SuiMoveNormalizedModule};
+use sui_types::base_types::ObjectID;
+
+use crate::errors::IndexerError;
+use crate::schema_v2::{package_functions, package_modules};
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = package_modules)]
+pub struct StoredPackageModule {
+    pub package_id: Vec<u8>,
+    pub module_name: String,
+    pub checkpoint_sequence_number: i64,
+    pub normalized_module: String,
+}
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = package_functions)]
+pub struct StoredPackageFunction {
+    pub package_id: Vec<u8>,
+    pub module_name: String,
+    pub function_name: String,
+}
+
+impl StoredPackageModule {
+    /// The module, and its public and entry functions.
+    pub fn new(
+        package_id: ObjectID,
+        module: &SuiMoveNormalizedModule,
+        checkpoint_sequence_number: u64,
+    ) -> Result<(Self, Vec<StoredPackageFunction>), IndexerError> {
+        let normalized_module = serde_json::to_string(module).map_err(|e| {
+            IndexerError::SerdeError(format!(
+                "Failed to serialize module {}::{}, error: {}",
+                package_id, module.name, e
+            ))
+        })?;
+        let functions = module
+            .exposed_functions
+            .keys()
+            .map(|function_name| StoredPackageFunction {
+                package_id: package_id.to_vec(),
+                module_name: module.name.clone(),
+                function_name: function_name.clone(),
+            })
+            .collect();
+        Ok((
+            Self {
+                package_id: package_id.to_vec(),
+                module_name: module.name.clone(),
+                checkpoint_sequence_number: checkpoint_sequence_number as i64,
+                normalized_module,
+            },
+            functions,
+        ))
+    }
+}
+
+impl TryFrom<StoredPackageModule> for PackageModule {
+    type Error = IndexerError;
+
+    fn try_from(module: StoredPackageModule) -> Result<Self, Self::Error> {
+        let package_id = ObjectID::from_bytes(&module.package_id).map_err(|e| {
+            IndexerError::SerdeError(format!(
+                "Failed to parse package id: {:?}, error: {}",
+                module.package_id, e
+            ))
+        })?;
+        let normalized_module = serde_json::from_str(&module.normalized_module).map_err(|e| {
+            IndexerError::SerdeError(format!(
+                "Failed to parse normalized module {}::{}, error: {}",
+                package_id, module.module_name, e
+            ))
+        })?;
+        Ok(Self {
+            package_id,
+            module_name: module.module_name,
+            checkpoint: module.checkpoint_sequence_number as u64,
+            normalized_module,
+        })
+    }
+}
@@ -69,6 +69,13 @@ pub struct StoredTransactionSuccessCommandCount {
     pub timestamp_ms: i64,
 }
 
+#[derive(Clone, Debug, Queryable)]
+pub struct StoredTransactionGas {
+    pub tx_sequence_number: i64,
+    pub raw_transaction: Vec<u8>,
+    pub raw_effects: Vec<u8>,
+}
+
 impl From<&IndexedTransaction> for StoredTransaction {
     fn from(tx: &IndexedTransaction) -> Self {
         StoredTransaction {
@@ -0,0 +1,32 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use diesel::prelude::*;
+
+use crate::schema_v2::checkpoint_watermark;
+use crate::types_v2::IndexedCheckpoint;
+
+/// The single row of the `checkpoint_watermark` table always has this ID.
+pub const CHECKPOINT_WATERMARK_ID: i16 = 0;
+
+#[derive(Queryable, Insertable, Debug, Clone, Default)]
+#[diesel(table_name = checkpoint_watermark)]
+pub struct StoredCheckpointWatermark {
+    pub id: i16,
+    pub checkpoint_sequence_number: i64,
+    pub epoch: i64,
+    pub network_total_transactions: i64,
+    pub timestamp_ms: i64,
+}
+
+impl From<&IndexedCheckpoint> for StoredCheckpointWatermark {
+    fn from(c: &IndexedCheckpoint) -> Self {
+        Self {
+            id: CHECKPOINT_WATERMARK_ID,
+            checkpoint_sequence_number: c.sequence_number as i64,
+            epoch: c.epoch as i64,
+            network_total_transactions: c.network_total_transactions as i64,
+            timestamp_ms: c.timestamp_ms as i64,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use diesel::prelude::*;
+
+use crate::schema_v2::{webhook_dead_letters, webhook_subscriptions};
+
+/// Position of an event in the `events` table, as (tx_sequence_number, event_sequence_number).
+pub type EventPosition = (i64, i64);
+
+#[derive(Queryable, Debug, Clone)]
+#[diesel(table_name = webhook_subscriptions)]
+pub struct StoredWebhookSubscription {
+    pub id: i64,
+    pub endpoint: String,
+    pub event_filter: String,
+    pub secret: String,
+    pub active: bool,
+    pub last_tx_sequence_number: Option<i64>,
+    pub last_event_sequence_number: Option<i64>,
+}
+
+impl StoredWebhookSubscription {
+    /// Position of the last event processed for this subscriber, if the delivery worker
+    /// picked up the subscription already.
+    pub fn cursor(&self) -> Option<EventPosition> {
+        Some((
+            self.last_tx_sequence_number?,
+            self.last_event_sequence_number?,
+        ))
+    }
+}
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = webhook_dead_letters)]
+pub struct StoredWebhookDeadLetter {
+    pub subscription_id: i64,
+    pub tx_sequence_number: i64,
+    pub event_sequence_number: i64,
+    pub payload: String,
+    pub error: String,
+    pub failed_at_ms: i64,
+}
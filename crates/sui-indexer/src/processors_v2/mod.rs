@@ -4,4 +4,5 @@
 pub mod address_metrics_processor;
 pub mod move_call_metrics_processor;
 pub mod network_metrics_processor;
+pub mod network_stats_processor;
 pub mod processor_orchestrator_v2;
@@ -0,0 +1,289 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use sui_types::base_types::ObjectID;
+use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
+use sui_types::transaction::{SenderSignedData, TransactionDataAPI};
+use tracing::info;
+
+use crate::errors::IndexerError;
+use crate::metrics::IndexerMetrics;
+use crate::models_v2::transactions::StoredTransactionGas;
+use crate::store::IndexerAnalyticalStore;
+use crate::types_v2::IndexerResult;
+
+const NETWORK_STATS_WINDOW_CHECKPOINTS: i64 = 100;
+const NETWORK_STATS_INTERVAL: Duration = Duration::from_secs(10);
+const GAS_PRICE_PERCENTILES: &[u8] = &[50, 90, 99];
+// Bounds the cardinality of the per package metrics.
+const TOP_GAS_PACKAGES: usize = 20;
+
+/// Network statistics over the latest `window_checkpoints` indexed checkpoints.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkStats {
+    pub checkpoint: u64,
+    pub timestamp_ms: u64,
+    pub window_checkpoints: u64,
+    /// Transaction blocks per second over the window.
+    pub rolling_tps: f64,
+    /// Time elapsed since the timestamp of the latest checkpoint, i.e. how far behind the
+    /// chain the indexed data is.
+    pub checkpoint_lag_ms: u64,
+    /// Gas price of the user transactions, keyed by percentile.
+    pub gas_price_percentiles: BTreeMap<u8, u64>,
+    /// The packages called by the transactions with the highest total net gas usage, highest
+    /// first.
+    pub package_gas_usage: Vec<PackageGasUsage>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageGasUsage {
+    pub package: ObjectID,
+    pub transactions: u64,
+    /// Net gas usage of the transactions calling the package. A transaction calling several
+    /// packages counts in full towards each of them.
+    pub gas_usage: i64,
+}
+
+/// Latest [NetworkStats], shared between the processor and the HTTP server.
+pub type NetworkStatsCache = Arc<RwLock<Option<NetworkStats>>>;
+
+pub struct NetworkStatsProcessor<S> {
+    pub store: S,
+    metrics: IndexerMetrics,
+    stats: NetworkStatsCache,
+    pub network_stats_window_checkpoints: i64,
+}
+
+impl<S> NetworkStatsProcessor<S>
+where
+    S: IndexerAnalyticalStore + Clone + Sync + Send + 'static,
+{
+    pub fn new(
+        store: S,
+        metrics: IndexerMetrics,
+        stats: NetworkStatsCache,
+    ) -> NetworkStatsProcessor<S> {
+        let network_stats_window_checkpoints = std::env::var("NETWORK_STATS_WINDOW_CHECKPOINTS")
+            .map(|s| s.parse::<i64>().unwrap_or(NETWORK_STATS_WINDOW_CHECKPOINTS))
+            .unwrap_or(NETWORK_STATS_WINDOW_CHECKPOINTS)
+            .max(1);
+        Self {
+            store,
+            metrics,
+            stats,
+            network_stats_window_checkpoints,
+        }
+    }
+
+    pub async fn start(&self) -> IndexerResult<()> {
+        info!("Indexer network stats async processor started...");
+        loop {
+            if let Some(stats) = self.compute_network_stats().await? {
+                self.update_metrics(&stats);
+                *self.stats.write().unwrap() = Some(stats);
+            }
+            tokio::time::sleep(NETWORK_STATS_INTERVAL).await;
+        }
+    }
+
+    async fn compute_network_stats(&self) -> IndexerResult<Option<NetworkStats>> {
+        let Some(latest_cp) = self.store.get_latest_stored_checkpoint().await? else {
+            return Ok(None);
+        };
+        let end_cp = latest_cp.sequence_number + 1;
+        let start_cp = (end_cp - self.network_stats_window_checkpoints).max(0);
+        let first_cp =
+            self.store
+                .get_cp(start_cp)
+                .await?
+                .ok_or(IndexerError::PostgresReadError(format!(
+                    "Cannot read checkpoint {start_cp} from PG for network stats"
+                )))?;
+        let txs = self
+            .store
+            .get_tx_gas_in_checkpoint_range(start_cp, end_cp)
+            .await?;
+
+        let elapsed_ms = latest_cp.timestamp_ms - first_cp.timestamp_ms;
+        let rolling_tps = if elapsed_ms > 0 {
+            (latest_cp.network_total_transactions - first_cp.network_total_transactions) as f64
+                * 1000.0
+                / elapsed_ms as f64
+        } else {
+            0.0
+        };
+        let gas_usages = txs
+            .iter()
+            .map(decode_tx_gas_usage)
+            .collect::<IndexerResult<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let mut gas_prices = gas_usages.iter().map(|tx| tx.gas_price).collect::<Vec<_>>();
+        gas_prices.sort_unstable();
+
+        Ok(Some(NetworkStats {
+            checkpoint: latest_cp.sequence_number as u64,
+            timestamp_ms: latest_cp.timestamp_ms as u64,
+            window_checkpoints: (end_cp - start_cp) as u64,
+            rolling_tps,
+            checkpoint_lag_ms: now_ms().saturating_sub(latest_cp.timestamp_ms as u64),
+            gas_price_percentiles: GAS_PRICE_PERCENTILES
+                .iter()
+                .filter_map(|p| percentile(&gas_prices, *p).map(|price| (*p, price)))
+                .collect(),
+            package_gas_usage: top_package_gas_usage(&gas_usages, TOP_GAS_PACKAGES),
+        }))
+    }
+
+    fn update_metrics(&self, stats: &NetworkStats) {
+        self.metrics.network_rolling_tps.set(stats.rolling_tps);
+        self.metrics
+            .network_checkpoint_lag_ms
+            .set(stats.checkpoint_lag_ms as i64);
+        for (p, price) in &stats.gas_price_percentiles {
+            self.metrics
+                .network_gas_price_percentiles
+                .with_label_values(&[&format!("p{p}")])
+                .set(*price as i64);
+        }
+        // Drop the packages which are no longer among the top ones.
+        self.metrics.network_package_gas_usage.reset();
+        for usage in &stats.package_gas_usage {
+            self.metrics
+                .network_package_gas_usage
+                .with_label_values(&[&usage.package.to_string()])
+                .set(usage.gas_usage);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TxGasUsage {
+    gas_price: u64,
+    net_gas_usage: i64,
+    packages: BTreeSet<ObjectID>,
+}
+
+/// Gas usage of a user transaction, or None for system transactions which do not pay gas.
+fn decode_tx_gas_usage(tx: &StoredTransactionGas) -> IndexerResult<Option<TxGasUsage>> {
+    let sender_signed_data: SenderSignedData =
+        bcs::from_bytes(&tx.raw_transaction).map_err(|e| {
+            IndexerError::PersistentStorageDataCorruptionError(format!(
+                "Can't convert raw_transaction of {} into SenderSignedData. Error: {e}",
+                tx.tx_sequence_number
+            ))
+        })?;
+    let tx_data = sender_signed_data.transaction_data();
+    if tx_data.is_system_tx() {
+        return Ok(None);
+    }
+    let effects: TransactionEffects = bcs::from_bytes(&tx.raw_effects).map_err(|e| {
+        IndexerError::PersistentStorageDataCorruptionError(format!(
+            "Can't convert raw_effects of {} into TransactionEffects. Error: {e}",
+            tx.tx_sequence_number
+        ))
+    })?;
+    Ok(Some(TxGasUsage {
+        gas_price: tx_data.gas_price(),
+        net_gas_usage: effects.gas_cost_summary().net_gas_usage(),
+        packages: tx_data
+            .move_calls()
+            .into_iter()
+            .map(|(package, _, _)| *package)
+            .collect(),
+    }))
+}
+
+/// Nearest-rank percentile of ascending `sorted_values`.
+fn percentile(sorted_values: &[u64], p: u8) -> Option<u64> {
+    if sorted_values.is_empty() {
+        return None;
+    }
+    let rank = (sorted_values.len() * p as usize).div_ceil(100).max(1);
+    sorted_values.get(rank - 1).copied()
+}
+
+fn top_package_gas_usage(txs: &[TxGasUsage], limit: usize) -> Vec<PackageGasUsage> {
+    let mut usages: HashMap<ObjectID, PackageGasUsage> = HashMap::new();
+    for tx in txs {
+        for package in &tx.packages {
+            let usage = usages.entry(*package).or_insert(PackageGasUsage {
+                package: *package,
+                transactions: 0,
+                gas_usage: 0,
+            });
+            usage.transactions += 1;
+            usage.gas_usage += tx.net_gas_usage;
+        }
+    }
+    let mut usages = usages.into_values().collect::<Vec<_>>();
+    usages.sort_by(|a, b| {
+        b.gas_usage
+            .cmp(&a.gas_usage)
+            .then(a.package.cmp(&b.package))
+    });
+    usages.truncate(limit);
+    usages
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile() {
+        let values = (1..=100).collect::<Vec<u64>>();
+        assert_eq!(percentile(&values, 50), Some(50));
+        assert_eq!(percentile(&values, 99), Some(99));
+        assert_eq!(percentile(&[750, 1000], 50), Some(750));
+        assert_eq!(percentile(&[750, 1000], 90), Some(1000));
+        assert_eq!(percentile(&[750], 0), Some(750));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn test_top_package_gas_usage() {
+        let (p1, p2, p3) = (
+            ObjectID::from_single_byte(1),
+            ObjectID::from_single_byte(2),
+            ObjectID::from_single_byte(3),
+        );
+        let tx = |net_gas_usage, packages: &[ObjectID]| TxGasUsage {
+            gas_price: 1000,
+            net_gas_usage,
+            packages: packages.iter().copied().collect(),
+        };
+        let txs = vec![tx(100, &[p1, p2]), tx(50, &[p2]), tx(10, &[p3]), tx(5, &[])];
+        assert_eq!(
+            top_package_gas_usage(&txs, 2),
+            vec![
+                PackageGasUsage {
+                    package: p2,
+                    transactions: 2,
+                    gas_usage: 150,
+                },
+                PackageGasUsage {
+                    package: p1,
+                    transactions: 1,
+                    gas_usage: 100,
+                },
+            ]
+        );
+    }
+}
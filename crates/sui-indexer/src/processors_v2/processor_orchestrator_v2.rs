@@ -10,18 +10,24 @@ use crate::store::IndexerAnalyticalStore;
 use super::address_metrics_processor::AddressMetricsProcessor;
 use super::move_call_metrics_processor::MoveCallMetricsProcessor;
 use super::network_metrics_processor::NetworkMetricsProcessor;
+use super::network_stats_processor::{NetworkStatsCache, NetworkStatsProcessor};
 
 pub struct ProcessorOrchestratorV2<S> {
     store: S,
     metrics: IndexerMetrics,
+    network_stats: NetworkStatsCache,
 }
 
 impl<S> ProcessorOrchestratorV2<S>
 where
     S: IndexerAnalyticalStore + Clone + Send + Sync + 'static,
 {
-    pub fn new(store: S, metrics: IndexerMetrics) -> Self {
-        Self { store, metrics }
+    pub fn new(store: S, metrics: IndexerMetrics, network_stats: NetworkStatsCache) -> Self {
+        Self {
+            store,
+            metrics,
+            network_stats,
+        }
     }
 
     pub async fn run_forever(&mut self) {
@@ -71,10 +77,29 @@ where
             }
         });
 
+        let network_stats_processor = NetworkStatsProcessor::new(
+            self.store.clone(),
+            self.metrics.clone(),
+            self.network_stats.clone(),
+        );
+        let network_stats_handle = tokio::task::spawn(async move {
+            loop {
+                let network_stats_res = network_stats_processor.start().await;
+                if let Err(e) = network_stats_res {
+                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                    error!(
+                        "Indexer network stats processor failed with error {:?}, retrying in 5s...",
+                        e
+                    );
+                }
+            }
+        });
+
         try_join_all(vec![
             network_metrics_handle,
             addr_metrics_handle,
             move_call_metrics_handle,
+            network_stats_handle,
         ])
         .await
         .expect("Processor orchestrator should not run into errors.");
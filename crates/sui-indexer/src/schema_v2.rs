@@ -12,6 +12,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    address_activity (address, tx_sequence_number) {
+        address -> Bytea,
+        tx_sequence_number -> Int8,
+        transaction_digest -> Bytea,
+        checkpoint_sequence_number -> Int8,
+        timestamp_ms -> Int8,
+        is_sender -> Bool,
+        is_recipient -> Bool,
+    }
+}
+
 diesel::table! {
     address_metrics (checkpoint) {
         checkpoint -> Int8,
@@ -33,6 +45,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    balance_history (owner_id, coin_type, checkpoint_sequence_number) {
+        owner_id -> Bytea,
+        coin_type -> Text,
+        checkpoint_sequence_number -> Int8,
+        timestamp_ms -> Int8,
+        balance -> Int8,
+    }
+}
+
+diesel::table! {
+    checkpoint_watermark (id) {
+        id -> Int2,
+        checkpoint_sequence_number -> Int8,
+        epoch -> Int8,
+        network_total_transactions -> Int8,
+        timestamp_ms -> Int8,
+    }
+}
+
 diesel::table! {
     checkpoints (sequence_number) {
         sequence_number -> Int8,
@@ -133,6 +165,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    object_display (object_id) {
+        object_id -> Bytea,
+        object_version -> Int8,
+        object_digest -> Bytea,
+        checkpoint_sequence_number -> Int8,
+        owner_id -> Bytea,
+        object_type -> Text,
+        name -> Nullable<Text>,
+        description -> Nullable<Text>,
+        image_url -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    object_relations (object_id) {
+        object_id -> Bytea,
+        parent_id -> Bytea,
+        relation -> Int2,
+        checkpoint_sequence_number -> Int8,
+    }
+}
+
 diesel::table! {
     objects (object_id) {
         object_id -> Bytea,
@@ -212,6 +267,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    package_functions (package_id, module_name, function_name) {
+        package_id -> Bytea,
+        module_name -> Text,
+        function_name -> Text,
+    }
+}
+
+diesel::table! {
+    package_modules (package_id, module_name) {
+        package_id -> Bytea,
+        module_name -> Text,
+        checkpoint_sequence_number -> Int8,
+        normalized_module -> Text,
+    }
+}
+
 diesel::table! {
     packages (package_id) {
         package_id -> Bytea,
@@ -299,10 +371,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook_dead_letters (subscription_id, tx_sequence_number, event_sequence_number) {
+        subscription_id -> Int8,
+        tx_sequence_number -> Int8,
+        event_sequence_number -> Int8,
+        payload -> Text,
+        error -> Text,
+        failed_at_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    webhook_subscriptions (id) {
+        id -> Int8,
+        endpoint -> Text,
+        event_filter -> Text,
+        secret -> Text,
+        active -> Bool,
+        last_tx_sequence_number -> Nullable<Int8>,
+        last_event_sequence_number -> Nullable<Int8>,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     active_addresses,
+    address_activity,
     address_metrics,
     addresses,
+    balance_history,
+    checkpoint_watermark,
     checkpoints,
     display,
     epoch_peak_tps,
@@ -310,10 +408,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     events,
     move_call_metrics,
     move_calls,
+    object_display,
+    object_relations,
     objects,
     objects_history,
     objects_history_partition_0,
     objects_snapshot,
+    package_functions,
+    package_modules,
     packages,
     transactions,
     transactions_partition_0,
@@ -323,4 +425,6 @@ diesel::allow_tables_to_appear_in_same_query!(
     tx_input_objects,
     tx_recipients,
     tx_senders,
+    webhook_dead_letters,
+    webhook_subscriptions,
 );
@@ -0,0 +1,14 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tables for the views created by `migrations_v2`. `diesel print-schema` only generates the
+//! tables of `schema_v2.rs`, so the views are declared here.
+
+diesel::table! {
+    balances (owner_id, coin_type) {
+        owner_id -> Bytea,
+        coin_type -> Text,
+        balance -> Int8,
+        coin_num -> Int8,
+    }
+}
@@ -7,8 +7,8 @@ use crate::models_v2::checkpoints::StoredCheckpoint;
 use crate::models_v2::move_call_metrics::StoredMoveCallMetrics;
 use crate::models_v2::network_metrics::StoredEpochPeakTps;
 use crate::models_v2::transactions::{
-    StoredTransaction, StoredTransactionCheckpoint, StoredTransactionSuccessCommandCount,
-    StoredTransactionTimestamp, TxSeq,
+    StoredTransaction, StoredTransactionCheckpoint, StoredTransactionGas,
+    StoredTransactionSuccessCommandCount, StoredTransactionTimestamp, TxSeq,
 };
 use crate::models_v2::tx_count_metrics::StoredTxCountMetrics;
 use crate::types_v2::IndexerResult;
@@ -37,6 +37,11 @@ pub trait IndexerAnalyticalStore {
         start_checkpoint: i64,
         end_checkpoint: i64,
     ) -> IndexerResult<Vec<StoredTransactionSuccessCommandCount>>;
+    async fn get_tx_gas_in_checkpoint_range(
+        &self,
+        start_checkpoint: i64,
+        end_checkpoint: i64,
+    ) -> IndexerResult<Vec<StoredTransactionGas>>;
     async fn get_tx(&self, tx_sequence_number: i64) -> IndexerResult<Option<StoredTransaction>>;
     async fn get_cp(&self, sequence_number: i64) -> IndexerResult<Option<StoredCheckpoint>>;
 
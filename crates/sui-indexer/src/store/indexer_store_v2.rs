@@ -11,12 +11,8 @@ use sui_types::base_types::{ObjectID, SequenceNumber};
 use sui_types::object::ObjectRead;
 
 use crate::errors::IndexerError;
-use crate::handlers::{EpochToCommit, TransactionObjectChangesToCommit};
-
+use crate::handlers::{CheckpointDataToCommit, EpochToCommit};
 use crate::models_v2::display::StoredDisplay;
-use crate::types_v2::{
-    IndexedCheckpoint, IndexedEvent, IndexedPackage, IndexedTransaction, TxIndex,
-};
 
 #[async_trait]
 pub trait IndexerStoreV2 {
@@ -37,40 +33,16 @@ pub trait IndexerStoreV2 {
         version: Option<SequenceNumber>,
     ) -> Result<ObjectRead, IndexerError>;
 
-    async fn persist_objects(
+    /// Persist the data of a batch of checkpoints, and of the epoch they end if any, atomically
+    /// with the checkpoint watermark. Readers never observe a partially committed checkpoint.
+    async fn persist_checkpoint_data(
         &self,
-        object_changes: Vec<TransactionObjectChangesToCommit>,
-    ) -> Result<(), IndexerError>;
-
-    async fn persist_object_history(
-        &self,
-        object_changes: Vec<TransactionObjectChangesToCommit>,
+        checkpoint_data: Vec<CheckpointDataToCommit>,
+        epoch: Option<EpochToCommit>,
     ) -> Result<(), IndexerError>;
 
     async fn persist_object_snapshot(&self) -> Result<(), IndexerError>;
 
-    async fn persist_checkpoints(
-        &self,
-        checkpoints: Vec<IndexedCheckpoint>,
-    ) -> Result<(), IndexerError>;
-
-    async fn persist_transactions(
-        &self,
-        transactions: Vec<IndexedTransaction>,
-    ) -> Result<(), IndexerError>;
-
-    async fn persist_tx_indices(&self, indices: Vec<TxIndex>) -> Result<(), IndexerError>;
-
-    async fn persist_events(&self, events: Vec<IndexedEvent>) -> Result<(), IndexerError>;
-    async fn persist_displays(
-        &self,
-        display_updates: BTreeMap<String, StoredDisplay>,
-    ) -> Result<(), IndexerError>;
-
-    async fn persist_packages(&self, packages: Vec<IndexedPackage>) -> Result<(), IndexerError>;
-
-    async fn persist_epoch(&self, epoch: EpochToCommit) -> Result<(), IndexerError>;
-
     async fn advance_epoch(&self, epoch: EpochToCommit) -> Result<(), IndexerError>;
 
     async fn get_network_total_transactions_by_end_of_epoch(
@@ -78,5 +50,12 @@ pub trait IndexerStoreV2 {
         epoch: u64,
     ) -> Result<u64, IndexerError>;
 
+    /// The latest Display templates of `object_types`, keyed by object type. Types without a
+    /// Display are left out.
+    async fn get_displays(
+        &self,
+        object_types: Vec<String>,
+    ) -> Result<BTreeMap<String, StoredDisplay>, IndexerError>;
+
     fn module_cache(&self) -> Arc<Self::ModuleCache>;
 }
@@ -7,6 +7,7 @@ pub(crate) use indexer_store_v2::*;
 pub use pg_indexer_analytical_store::PgIndexerAnalyticalStore;
 pub use pg_indexer_store::PgIndexerStore;
 pub use pg_indexer_store_v2::PgIndexerStoreV2;
+pub use pg_indexer_webhook_store::PgIndexerWebhookStore;
 
 mod indexer_analytical_store;
 mod indexer_store;
@@ -16,6 +17,7 @@ pub(crate) mod module_resolver_v2;
 mod pg_indexer_analytical_store;
 mod pg_indexer_store;
 mod pg_indexer_store_v2;
+mod pg_indexer_webhook_store;
 mod pg_partition_manager;
 mod query;
 
@@ -20,8 +20,8 @@ use crate::models_v2::move_call_metrics::{
 };
 use crate::models_v2::network_metrics::{StoredEpochPeakTps, Tps};
 use crate::models_v2::transactions::{
-    StoredTransaction, StoredTransactionCheckpoint, StoredTransactionSuccessCommandCount,
-    StoredTransactionTimestamp, TxSeq,
+    StoredTransaction, StoredTransactionCheckpoint, StoredTransactionGas,
+    StoredTransactionSuccessCommandCount, StoredTransactionTimestamp, TxSeq,
 };
 use crate::models_v2::tx_count_metrics::StoredTxCountMetrics;
 use crate::schema_v2::{
@@ -146,6 +146,27 @@ impl IndexerAnalyticalStore for PgIndexerAnalyticalStore {
         .context("Failed reading transaction success command counts from PostgresDB")?;
         Ok(tx_success_cmd_counts)
     }
+
+    async fn get_tx_gas_in_checkpoint_range(
+        &self,
+        start_checkpoint: i64,
+        end_checkpoint: i64,
+    ) -> IndexerResult<Vec<StoredTransactionGas>> {
+        let tx_gas = read_only_blocking!(&self.blocking_cp, |conn| {
+            transactions::dsl::transactions
+                .filter(transactions::dsl::checkpoint_sequence_number.ge(start_checkpoint))
+                .filter(transactions::dsl::checkpoint_sequence_number.lt(end_checkpoint))
+                .order(transactions::dsl::tx_sequence_number.asc())
+                .select((
+                    transactions::dsl::tx_sequence_number,
+                    transactions::dsl::raw_transaction,
+                    transactions::dsl::raw_effects,
+                ))
+                .load::<StoredTransactionGas>(conn)
+        })
+        .context("Failed reading transaction gas from PostgresDB")?;
+        Ok(tx_gas)
+    }
     async fn get_tx(&self, tx_sequence_number: i64) -> IndexerResult<Option<StoredTransaction>> {
         let tx = read_only_blocking!(&self.blocking_cp, |conn| {
             transactions::dsl::transactions
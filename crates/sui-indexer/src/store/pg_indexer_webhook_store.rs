@@ -0,0 +1,140 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use core::result::Result::Ok;
+use std::time::Duration;
+
+use diesel::{BoolExpressionMethods, ExpressionMethods, OptionalExtension};
+use diesel::{QueryDsl, RunQueryDsl};
+
+use crate::errors::{Context, IndexerError};
+use crate::models_v2::events::StoredEvent;
+use crate::models_v2::webhooks::{
+    EventPosition, StoredWebhookDeadLetter, StoredWebhookSubscription,
+};
+use crate::schema_v2::{checkpoint_watermark, events, webhook_dead_letters, webhook_subscriptions};
+use crate::store::diesel_macro::{read_only_blocking, transactional_blocking_with_retry};
+use crate::types_v2::IndexerResult;
+use crate::PgConnectionPool;
+
+/// Reads the events to deliver to webhook subscribers, and tracks the delivery progress of
+/// each subscriber.
+#[derive(Clone)]
+pub struct PgIndexerWebhookStore {
+    blocking_cp: PgConnectionPool,
+}
+
+impl PgIndexerWebhookStore {
+    pub fn new(blocking_cp: PgConnectionPool) -> Self {
+        Self { blocking_cp }
+    }
+
+    pub fn blocking_cp(&self) -> PgConnectionPool {
+        self.blocking_cp.clone()
+    }
+
+    pub async fn get_active_subscriptions(&self) -> IndexerResult<Vec<StoredWebhookSubscription>> {
+        read_only_blocking!(&self.blocking_cp, |conn| {
+            webhook_subscriptions::table
+                .filter(webhook_subscriptions::active.eq(true))
+                .order(webhook_subscriptions::id.asc())
+                .load::<StoredWebhookSubscription>(conn)
+        })
+        .context("Failed reading webhook subscriptions from PostgresDB")
+    }
+
+    /// The last checkpoint fully committed by the writer. Only events up to it are delivered.
+    pub async fn get_checkpoint_watermark(&self) -> IndexerResult<Option<i64>> {
+        read_only_blocking!(&self.blocking_cp, |conn| {
+            checkpoint_watermark::table
+                .select(checkpoint_watermark::checkpoint_sequence_number)
+                .first::<i64>(conn)
+                .optional()
+        })
+        .context("Failed reading checkpoint watermark from PostgresDB")
+    }
+
+    /// Position of the last event indexed up to `watermark`, or (-1, -1) if there is none.
+    pub async fn get_latest_event_position(&self, watermark: i64) -> IndexerResult<EventPosition> {
+        let position = read_only_blocking!(&self.blocking_cp, |conn| {
+            events::table
+                .filter(events::checkpoint_sequence_number.le(watermark))
+                .select((events::tx_sequence_number, events::event_sequence_number))
+                .order((
+                    events::tx_sequence_number.desc(),
+                    events::event_sequence_number.desc(),
+                ))
+                .first::<EventPosition>(conn)
+                .optional()
+        })
+        .context("Failed reading latest event from PostgresDB")?;
+        Ok(position.unwrap_or((-1, -1)))
+    }
+
+    /// Up to `limit` events after `position` and up to `watermark`, in order.
+    pub async fn get_events_after(
+        &self,
+        (tx_sequence_number, event_sequence_number): EventPosition,
+        watermark: i64,
+        limit: i64,
+    ) -> IndexerResult<Vec<StoredEvent>> {
+        read_only_blocking!(&self.blocking_cp, |conn| {
+            events::table
+                .filter(
+                    events::tx_sequence_number.gt(tx_sequence_number).or(
+                        events::tx_sequence_number
+                            .eq(tx_sequence_number)
+                            .and(events::event_sequence_number.gt(event_sequence_number)),
+                    ),
+                )
+                .filter(events::checkpoint_sequence_number.le(watermark))
+                .order((
+                    events::tx_sequence_number.asc(),
+                    events::event_sequence_number.asc(),
+                ))
+                .limit(limit)
+                .load::<StoredEvent>(conn)
+        })
+        .context("Failed reading events to deliver from PostgresDB")
+    }
+
+    pub async fn update_subscription_cursor(
+        &self,
+        subscription_id: i64,
+        (tx_sequence_number, event_sequence_number): EventPosition,
+    ) -> IndexerResult<()> {
+        transactional_blocking_with_retry!(
+            &self.blocking_cp,
+            |conn| {
+                diesel::update(webhook_subscriptions::table)
+                    .filter(webhook_subscriptions::id.eq(subscription_id))
+                    .set((
+                        webhook_subscriptions::last_tx_sequence_number.eq(tx_sequence_number),
+                        webhook_subscriptions::last_event_sequence_number.eq(event_sequence_number),
+                    ))
+                    .execute(conn)
+            },
+            Duration::from_secs(10)
+        )
+        .context("Failed updating webhook subscription cursor in PostgresDB")?;
+        Ok(())
+    }
+
+    pub async fn persist_dead_letter(
+        &self,
+        dead_letter: StoredWebhookDeadLetter,
+    ) -> IndexerResult<()> {
+        transactional_blocking_with_retry!(
+            &self.blocking_cp,
+            |conn| {
+                diesel::insert_into(webhook_dead_letters::table)
+                    .values(&dead_letter)
+                    .on_conflict_do_nothing()
+                    .execute(conn)
+            },
+            Duration::from_secs(10)
+        )
+        .context("Failed writing webhook dead letter to PostgresDB")?;
+        Ok(())
+    }
+}
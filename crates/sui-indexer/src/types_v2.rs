@@ -242,6 +242,30 @@ pub fn owner_to_owner_info(owner: &Owner) -> (OwnerType, Option<SuiAddress>) {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+pub enum ObjectRelationType {
+    /// The object is owned by its parent object, e.g. it is a dynamic field.
+    DynamicField = 0,
+    /// The object is wrapped in its parent object.
+    Wrapped = 1,
+}
+
+impl TryFrom<i16> for ObjectRelationType {
+    type Error = IndexerError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => ObjectRelationType::DynamicField,
+            1 => ObjectRelationType::Wrapped,
+            value => {
+                return Err(IndexerError::PersistentStorageDataCorruptionError(format!(
+                    "{value} as ObjectRelationType"
+                )))
+            }
+        })
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum DynamicFieldKind {
     DynamicField = 0,
@@ -397,6 +421,16 @@ pub enum IndexedObjectChange {
         version: SequenceNumber,
         digest: ObjectDigest,
     },
+    /// Object unwrapped from another object
+    Unwrapped {
+        sender: SuiAddress,
+        owner: Owner,
+        #[serde_as(as = "SuiStructTag")]
+        object_type: StructTag,
+        object_id: ObjectID,
+        version: SequenceNumber,
+        digest: ObjectDigest,
+    },
 }
 
 impl From<ObjectChange> for IndexedObjectChange {
@@ -482,6 +516,21 @@ impl From<ObjectChange> for IndexedObjectChange {
                 version,
                 digest,
             },
+            ObjectChange::Unwrapped {
+                sender,
+                owner,
+                object_type,
+                object_id,
+                version,
+                digest,
+            } => Self::Unwrapped {
+                sender,
+                owner,
+                object_type,
+                object_id,
+                version,
+                digest,
+            },
         }
     }
 }
@@ -569,6 +618,21 @@ impl From<IndexedObjectChange> for ObjectChange {
                 version,
                 digest,
             },
+            IndexedObjectChange::Unwrapped {
+                sender,
+                owner,
+                object_type,
+                object_id,
+                version,
+                digest,
+            } => ObjectChange::Unwrapped {
+                sender,
+                owner,
+                object_type,
+                object_id,
+                version,
+                digest,
+            },
         }
     }
 }
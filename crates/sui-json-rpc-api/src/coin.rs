@@ -70,4 +70,14 @@ pub trait CoinReadApi {
         /// type name for the coin (e.g., 0x168da5bf1f48dafc111b0a488fa454aca95e0b5e::usdc::USDC)
         coin_type: String,
     ) -> RpcResult<Supply>;
+
+    /// Return whether an address is denied from using a regulated coin
+    #[method(name = "isAddressDeniedForCoin")]
+    async fn is_address_denied_for_coin(
+        &self,
+        /// the Sui address to check
+        address: SuiAddress,
+        /// type name for the coin (e.g., 0x168da5bf1f48dafc111b0a488fa454aca95e0b5e::usdc::USDC)
+        coin_type: String,
+    ) -> RpcResult<bool>;
 }
@@ -5,10 +5,13 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 
 use sui_json_rpc_types::{
-    AddressMetrics, CheckpointedObjectID, EpochInfo, EpochMetricsPage, EpochPage, MoveCallMetrics,
-    NetworkMetrics, QueryObjectsPage, SuiObjectResponseQuery,
+    AddressActivityPage, AddressMetrics, BalanceHistoryPage, CheckpointedObjectID,
+    DisplayObjectPage, EpochInfo, EpochMetricsPage, EpochPage, MoveCallMetrics, NetworkMetrics,
+    ObjectRelation, ObjectRelationPage, PackageModuleCursor, PackageModulePage, QueryObjectsPage,
+    SuiObjectResponseQuery,
 };
 use sui_open_rpc_macros::open_rpc;
+use sui_types::base_types::{ObjectID, SuiAddress};
 use sui_types::sui_serde::BigInt;
 
 #[open_rpc(namespace = "suix", tag = "Extended API")]
@@ -75,4 +78,82 @@ pub trait ExtendedApi {
 
     #[method(name = "getTotalTransactions")]
     async fn get_total_transactions(&self) -> RpcResult<BigInt<u64>>;
+
+    /// Return the transactions sent or received by an address, with their checkpoint and timestamp
+    #[method(name = "getAddressActivity")]
+    async fn get_address_activity(
+        &self,
+        /// the address to query the activity for
+        address: SuiAddress,
+        /// optional paging cursor
+        cursor: Option<BigInt<u64>>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+        /// flag to return results in descending order
+        descending_order: Option<bool>,
+    ) -> RpcResult<AddressActivityPage>;
+
+    /// Return the balance of one coin type owned by an address at the end of every checkpoint in which it changed
+    #[method(name = "getBalanceHistory")]
+    async fn get_balance_history(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+        /// optional type name for the coin (e.g., 0x168da5bf1f48dafc111b0a488fa454aca95e0b5e::usdc::USDC), default to 0x2::sui::SUI if not specified.
+        coin_type: Option<String>,
+        /// optional paging cursor, the checkpoint of the last balance of the previous page
+        cursor: Option<BigInt<u64>>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+        /// flag to return results in descending order
+        descending_order: Option<bool>,
+    ) -> RpcResult<BalanceHistoryPage>;
+
+    /// Return the objects owned by an address whose type has a Display, with the Display fields rendered at indexing time
+    #[method(name = "getOwnedDisplayObjects")]
+    async fn get_owned_display_objects(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+        /// optional struct type of the objects (e.g., 0x2::kiosk::Kiosk), matching any type parameters if none are specified
+        type_filter: Option<String>,
+        /// optional paging cursor
+        cursor: Option<ObjectID>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+    ) -> RpcResult<DisplayObjectPage>;
+
+    /// Return the objects owned by an object, such as its dynamic fields, or wrapped in it
+    #[method(name = "getObjectChildren")]
+    async fn get_object_children(
+        &self,
+        /// the ID of the parent object
+        object_id: ObjectID,
+        /// optional paging cursor
+        cursor: Option<ObjectID>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectRelationPage>;
+
+    /// Return the chain of parents of an object, from its parent up to the first ancestor which is not owned by or wrapped in another object
+    #[method(name = "getObjectAncestors")]
+    async fn get_object_ancestors(
+        &self,
+        /// the ID of the object
+        object_id: ObjectID,
+    ) -> RpcResult<Vec<ObjectRelation>>;
+
+    /// Return the interfaces of the modules of the published packages, optionally filtered by module and function name, in the order of their package IDs and names
+    #[method(name = "searchPackageModules")]
+    async fn search_package_modules(
+        &self,
+        /// optional prefix of the module names (e.g., coin)
+        module_name: Option<String>,
+        /// optional prefix of the name of one of the public or entry functions of the modules (e.g., transfer)
+        function_name: Option<String>,
+        /// optional paging cursor
+        cursor: Option<PackageModuleCursor>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+    ) -> RpcResult<PackageModulePage>;
 }
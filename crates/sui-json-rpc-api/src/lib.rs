@@ -52,6 +52,9 @@ pub static QUERY_MAX_RESULT_LIMIT: Lazy<usize> = Lazy::new(|| {
 // TODOD(chris): make this configurable
 pub const QUERY_MAX_RESULT_LIMIT_CHECKPOINTS: usize = 100;
 
+/// The maximum number of transactions submitted in one `sui_subscribeExecuteTransactionBlocks`.
+pub const MAX_TRANSACTIONS_PER_SUBMISSION: usize = 1000;
+
 pub fn cap_page_limit(limit: Option<usize>) -> usize {
     let limit = limit.unwrap_or_default();
     if limit > *QUERY_MAX_RESULT_LIMIT || limit == 0 {
@@ -7,7 +7,7 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 
 use sui_json_rpc_types::{
-    MoveFunctionArgType, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
+    MoveFunctionArgType, SuiMoveEventLayout, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
     SuiMoveNormalizedStruct,
 };
 use sui_open_rpc_macros::open_rpc;
@@ -58,4 +58,13 @@ pub trait MoveUtils {
         module_name: String,
         function_name: String,
     ) -> RpcResult<SuiMoveNormalizedFunction>;
+
+    /// Return the layout of a Move event type, with which the BCS contents of its events can be
+    /// decoded without the source of the package that defines it
+    #[method(name = "getEventLayout")]
+    async fn get_event_layout(
+        &self,
+        /// the type of the event, e.g. `0x2::coin::CoinEvent<0x2::sui::SUI>`
+        event_type: String,
+    ) -> RpcResult<SuiMoveEventLayout>;
 }
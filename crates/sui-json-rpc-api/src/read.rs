@@ -12,6 +12,7 @@ use sui_json_rpc_types::{
 use sui_json_rpc_types::{ProtocolConfigResponse, SuiLoadedChildObjectsResponse};
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::{ObjectID, SequenceNumber, TransactionDigest};
+use sui_types::messages_checkpoint::{CheckpointInclusionProof, EpochStateCommitment};
 use sui_types::sui_serde::BigInt;
 
 #[open_rpc(namespace = "sui", tag = "Read API")]
@@ -101,6 +102,24 @@ pub trait ReadApi {
         id: CheckpointId,
     ) -> RpcResult<Checkpoint>;
 
+    /// Return a proof that the transaction is included in its checkpoint, which can be verified
+    /// against the certified summary of that checkpoint alone.
+    #[method(name = "getCheckpointInclusionProof")]
+    async fn get_checkpoint_inclusion_proof(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<CheckpointInclusionProof>;
+
+    /// Return the commitment of the committee to the live object set at the end of an epoch, which
+    /// can be verified against the certified summary of the last checkpoint of that epoch.
+    #[method(name = "getEpochStateCommitment")]
+    async fn get_epoch_state_commitment(
+        &self,
+        /// the epoch, which must have ended
+        epoch: BigInt<u64>,
+    ) -> RpcResult<EpochStateCommitment>;
+
     /// Return paginated list of checkpoints
     #[method(name = "getCheckpoints")]
     async fn get_checkpoints(
@@ -6,8 +6,8 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 
 use sui_json_rpc_types::{
-    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiTransactionBlockResponse,
-    SuiTransactionBlockResponseOptions,
+    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SignedTransactionBlockBytes,
+    SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions, TransactionBlockStatusUpdate,
 };
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::SuiAddress;
@@ -40,6 +40,23 @@ pub trait WriteApi {
         request_type: Option<ExecuteTransactionRequestType>,
     ) -> RpcResult<SuiTransactionBlockResponse>;
 
+    /// Submit a batch of signed transactions over a single connection, and subscribe to the
+    /// updates of their status, each with the index of the transaction in the batch.
+    /// A transaction is `accepted` once its signatures are verified and it is submitted,
+    /// `certified` once a quorum of validators certified its effects, and `executed` once it is
+    /// final and executed by this node, or `failed` with the reason. The transactions are
+    /// executed concurrently, so the updates of different transactions are interleaved. The
+    /// subscription ends once every transaction is executed or failed.
+    #[subscription(
+        name = "subscribeExecuteTransactionBlocks",
+        item = TransactionBlockStatusUpdate
+    )]
+    fn subscribe_execute_transaction_blocks(
+        &self,
+        /// The signed transactions, at most 1000 of them.
+        transactions: Vec<SignedTransactionBlockBytes>,
+    );
+
     /// Runs the transaction in dev-inspect mode. Which allows for nearly any
     /// transaction (or Move call) with any arguments. Detailed results are
     /// provided, including both the transaction effects and any return values.
@@ -57,6 +74,24 @@ pub trait WriteApi {
         additional_args: Option<DevInspectArgs>,
     ) -> RpcResult<DevInspectResults>;
 
+    /// Runs the transaction in dev-inspect mode against the objects as of the end of a past
+    /// checkpoint, to query what a view function returned at that checkpoint. Objects are read
+    /// at their version at the checkpoint, regardless of the versions in the transaction. Only
+    /// checkpoints whose objects have not been pruned by the node can be queried.
+    #[method(name = "devInspectTransactionBlockAtCheckpoint")]
+    async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender_address: SuiAddress,
+        /// BCS encoded TransactionKind(as opposed to TransactionData, which include gasBudget and gasPrice)
+        tx_bytes: Base64,
+        /// The checkpoint at the end of which the transaction is run
+        checkpoint: BigInt<u64>,
+        /// Gas is not charged, but gas usage is still calculated. Default to use reference gas price
+        gas_price: Option<BigInt<u64>>,
+        /// Default to the maximum gas budget of the epoch of the checkpoint
+        gas_budget: Option<BigInt<u64>>,
+    ) -> RpcResult<DevInspectResults>;
+
     /// Return transaction execution effects including the gas cost summary,
     /// while the effects are not committed to the chain.
     #[method(name = "dryRunTransactionBlock")]
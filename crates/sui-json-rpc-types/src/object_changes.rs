@@ -95,6 +95,20 @@ pub enum ObjectChange {
         version: SequenceNumber,
         digest: ObjectDigest,
     },
+    /// Object unwrapped from another object
+    #[serde(rename_all = "camelCase")]
+    Unwrapped {
+        sender: SuiAddress,
+        owner: Owner,
+        #[schemars(with = "String")]
+        #[serde_as(as = "SuiStructTag")]
+        object_type: StructTag,
+        object_id: ObjectID,
+        #[schemars(with = "AsSequenceNumber")]
+        #[serde_as(as = "AsSequenceNumber")]
+        version: SequenceNumber,
+        digest: ObjectDigest,
+    },
 }
 
 impl ObjectChange {
@@ -105,7 +119,8 @@ impl ObjectChange {
             | ObjectChange::Mutated { object_id, .. }
             | ObjectChange::Deleted { object_id, .. }
             | ObjectChange::Wrapped { object_id, .. }
-            | ObjectChange::Created { object_id, .. } => *object_id,
+            | ObjectChange::Created { object_id, .. }
+            | ObjectChange::Unwrapped { object_id, .. } => *object_id,
         }
     }
 
@@ -134,6 +149,12 @@ impl ObjectChange {
                 version,
                 digest,
                 ..
+            }
+            | ObjectChange::Unwrapped {
+                object_id,
+                version,
+                digest,
+                ..
             } => (*object_id, *version, *digest),
             ObjectChange::Deleted {
                 object_id, version, ..
@@ -157,6 +178,9 @@ impl ObjectChange {
             }
             | ObjectChange::Created {
                 version, digest, ..
+            }
+            | ObjectChange::Unwrapped {
+                version, digest, ..
             } => {
                 *version = new_version;
                 *digest = new_digest
@@ -246,6 +270,14 @@ impl Display for ObjectChange {
                 object_id,
                 version,
                 digest,
+            }
+            | ObjectChange::Unwrapped {
+                sender,
+                owner,
+                object_type,
+                object_id,
+                version,
+                digest,
             } => {
                 write!(
                     f,
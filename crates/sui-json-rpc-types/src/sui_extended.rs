@@ -12,16 +12,24 @@ use serde_with::serde_as;
 use serde_with::DisplayFromStr;
 
 use sui_types::base_types::AuthorityName;
-use sui_types::base_types::{EpochId, ObjectID};
+use sui_types::base_types::{EpochId, ObjectID, SequenceNumber};
 use sui_types::committee::Committee;
+use sui_types::digests::{ObjectDigest, TransactionDigest};
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::sui_serde::BigInt;
+use sui_types::sui_serde::SequenceNumber as AsSequenceNumber;
 use sui_types::sui_system_state::sui_system_state_summary::SuiValidatorSummary;
 
 use crate::Page;
+use crate::SuiMoveNormalizedModule;
 
 pub type EpochPage = Page<EpochInfo, BigInt<u64>>;
 pub type EpochMetricsPage = Page<EpochMetrics, BigInt<u64>>;
+pub type AddressActivityPage = Page<AddressActivity, BigInt<u64>>;
+pub type BalanceHistoryPage = Page<BalanceHistory, BigInt<u64>>;
+pub type DisplayObjectPage = Page<DisplayObject, ObjectID>;
+pub type ObjectRelationPage = Page<ObjectRelation, ObjectID>;
+pub type PackageModulePage = Page<PackageModule, PackageModuleCursor>;
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -194,3 +202,100 @@ pub struct AddressMetrics {
     pub cumulative_active_addresses: u64,
     pub daily_active_addresses: u64,
 }
+
+/// A transaction sent or received by an address
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressActivity {
+    pub digest: TransactionDigest,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub checkpoint: CheckpointSequenceNumber,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub timestamp_ms: u64,
+    /// the address is the sender of the transaction
+    pub is_sender: bool,
+    /// the address received objects or coins from the transaction
+    pub is_recipient: bool,
+}
+
+/// Balance of a coin type owned by an address at the end of a checkpoint
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceHistory {
+    pub coin_type: String,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub checkpoint: CheckpointSequenceNumber,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub timestamp_ms: u64,
+    #[schemars(with = "BigInt<u128>")]
+    #[serde_as(as = "BigInt<u128>")]
+    pub total_balance: u128,
+}
+
+/// An object owned by an address, with the Display fields of its type rendered for it
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayObject {
+    pub object_id: ObjectID,
+    #[schemars(with = "AsSequenceNumber")]
+    #[serde_as(as = "AsSequenceNumber")]
+    pub version: SequenceNumber,
+    pub digest: ObjectDigest,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// How an object is related to its parent object
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectRelationKind {
+    /// the object is owned by its parent, e.g. it is a dynamic field of the parent
+    DynamicField,
+    /// the object is wrapped in its parent
+    Wrapped,
+}
+
+/// An edge of the object graph, from an object to its parent object
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectRelation {
+    pub object_id: ObjectID,
+    pub parent_id: ObjectID,
+    pub kind: ObjectRelationKind,
+    /// checkpoint at which the object became related to its parent
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub checkpoint: CheckpointSequenceNumber,
+}
+
+/// The interface of a module of a published package
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageModule {
+    pub package_id: ObjectID,
+    pub module_name: String,
+    /// checkpoint at which the package was published
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub checkpoint: CheckpointSequenceNumber,
+    /// the structs and the public and entry functions of the module
+    pub normalized_module: SuiMoveNormalizedModule,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageModuleCursor {
+    pub package_id: ObjectID,
+    pub module_name: String,
+}
@@ -8,7 +8,9 @@ use move_binary_format::normalized::{
     Field as NormalizedField, Function as SuiNormalizedFunction, Module as NormalizedModule,
     Struct as NormalizedStruct, Type as NormalizedType,
 };
-use move_core_types::annotated_value::{MoveStruct, MoveValue};
+use move_core_types::annotated_value::{
+    MoveFieldLayout, MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue,
+};
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
 use schemars::JsonSchema;
@@ -21,7 +23,8 @@ use std::fmt::{Display, Formatter, Write};
 use sui_macros::EnumVariantOrder;
 use tracing::warn;
 
-use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+use sui_types::sui_serde::SequenceNumber as AsSequenceNumber;
 use sui_types::sui_serde::SuiStructTag;
 
 pub type SuiMoveTypeParameterIndex = u16;
@@ -283,6 +286,136 @@ impl From<AbilitySet> for SuiMoveAbilitySet {
     }
 }
 
+/// The layout of a Move struct with all its type parameters instantiated, which is what is needed
+/// to decode a BCS value of the struct, e.g. the `bcs` of an event.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+pub struct SuiMoveStructLayout {
+    #[schemars(with = "String")]
+    #[serde(rename = "type")]
+    #[serde_as(as = "SuiStructTag")]
+    pub type_: StructTag,
+    pub fields: Vec<SuiMoveFieldLayout>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+pub struct SuiMoveFieldLayout {
+    pub name: String,
+    pub layout: SuiMoveTypeLayout,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+pub enum SuiMoveTypeLayout {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<SuiMoveTypeLayout>),
+    Struct(SuiMoveStructLayout),
+}
+
+/// The layout of a Move event type, and the version of the package that defines it.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiMoveEventLayout {
+    #[schemars(with = "AsSequenceNumber")]
+    #[serde_as(as = "AsSequenceNumber")]
+    pub package_version: SequenceNumber,
+    pub layout: SuiMoveStructLayout,
+}
+
+impl From<MoveStructLayout> for SuiMoveStructLayout {
+    fn from(layout: MoveStructLayout) -> Self {
+        Self {
+            type_: layout.type_,
+            fields: layout
+                .fields
+                .into_iter()
+                .map(|field| SuiMoveFieldLayout {
+                    name: field.name.to_string(),
+                    layout: field.layout.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<MoveTypeLayout> for SuiMoveTypeLayout {
+    fn from(layout: MoveTypeLayout) -> Self {
+        match layout {
+            MoveTypeLayout::Bool => SuiMoveTypeLayout::Bool,
+            MoveTypeLayout::U8 => SuiMoveTypeLayout::U8,
+            MoveTypeLayout::U16 => SuiMoveTypeLayout::U16,
+            MoveTypeLayout::U32 => SuiMoveTypeLayout::U32,
+            MoveTypeLayout::U64 => SuiMoveTypeLayout::U64,
+            MoveTypeLayout::U128 => SuiMoveTypeLayout::U128,
+            MoveTypeLayout::U256 => SuiMoveTypeLayout::U256,
+            MoveTypeLayout::Address => SuiMoveTypeLayout::Address,
+            MoveTypeLayout::Signer => SuiMoveTypeLayout::Signer,
+            MoveTypeLayout::Vector(layout) => {
+                SuiMoveTypeLayout::Vector(Box::new(SuiMoveTypeLayout::from(*layout)))
+            }
+            MoveTypeLayout::Struct(layout) => SuiMoveTypeLayout::Struct(layout.into()),
+        }
+    }
+}
+
+impl TryFrom<SuiMoveStructLayout> for MoveStructLayout {
+    type Error = anyhow::Error;
+
+    fn try_from(layout: SuiMoveStructLayout) -> Result<Self, Self::Error> {
+        Ok(Self {
+            type_: layout.type_,
+            fields: layout
+                .fields
+                .into_iter()
+                .map(|field| {
+                    Ok(MoveFieldLayout::new(
+                        Identifier::new(field.name)?,
+                        field.layout.try_into()?,
+                    ))
+                })
+                .collect::<Result<_, anyhow::Error>>()?,
+        })
+    }
+}
+
+impl TryFrom<SuiMoveTypeLayout> for MoveTypeLayout {
+    type Error = anyhow::Error;
+
+    fn try_from(layout: SuiMoveTypeLayout) -> Result<Self, Self::Error> {
+        Ok(match layout {
+            SuiMoveTypeLayout::Bool => MoveTypeLayout::Bool,
+            SuiMoveTypeLayout::U8 => MoveTypeLayout::U8,
+            SuiMoveTypeLayout::U16 => MoveTypeLayout::U16,
+            SuiMoveTypeLayout::U32 => MoveTypeLayout::U32,
+            SuiMoveTypeLayout::U64 => MoveTypeLayout::U64,
+            SuiMoveTypeLayout::U128 => MoveTypeLayout::U128,
+            SuiMoveTypeLayout::U256 => MoveTypeLayout::U256,
+            SuiMoveTypeLayout::Address => MoveTypeLayout::Address,
+            SuiMoveTypeLayout::Signer => MoveTypeLayout::Signer,
+            SuiMoveTypeLayout::Vector(layout) => {
+                MoveTypeLayout::Vector(Box::new(MoveTypeLayout::try_from(*layout)?))
+            }
+            SuiMoveTypeLayout::Struct(layout) => MoveTypeLayout::Struct(layout.try_into()?),
+        })
+    }
+}
+
+impl SuiMoveStructLayout {
+    /// Decodes the BCS `bytes` of a value of the struct, e.g. the `bcs` of an event.
+    pub fn decode(&self, bytes: &[u8]) -> Result<SuiMoveStruct, anyhow::Error> {
+        let layout = MoveStructLayout::try_from(self.clone())?;
+        Ok(MoveStruct::simple_deserialize(bytes, &layout)?.into())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum ObjectValueKind {
     ByImmutableReference,
@@ -321,7 +321,7 @@ impl TryFrom<&SuiMoveStruct> for GasCoin {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Hash, Default)]
 #[serde(rename_all = "camelCase", rename = "ObjectDataOptions", default)]
 pub struct SuiObjectDataOptions {
     /// Whether to show the type of the object. Default to be False
@@ -25,7 +25,9 @@ use sui_types::base_types::{
     EpochId, ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
 };
 use sui_types::crypto::SuiSignature;
-use sui_types::digests::{ConsensusCommitDigest, ObjectDigest, TransactionEventsDigest};
+use sui_types::digests::{
+    ConsensusCommitDigest, ObjectDigest, TransactionEffectsDigest, TransactionEventsDigest,
+};
 use sui_types::effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents};
 use sui_types::error::{ExecutionError, SuiError, SuiResult};
 use sui_types::execution_status::ExecutionStatus;
@@ -284,7 +286,8 @@ impl Display for SuiTransactionBlockResponse {
                 mut published,
                 mut transferred,
                 mut wrapped,
-            ) = (vec![], vec![], vec![], vec![], vec![], vec![]);
+                mut unwrapped,
+            ) = (vec![], vec![], vec![], vec![], vec![], vec![], vec![]);
 
             for obj in object_changes {
                 match obj {
@@ -294,6 +297,7 @@ impl Display for SuiTransactionBlockResponse {
                     ObjectChange::Published { .. } => published.push(obj),
                     ObjectChange::Transferred { .. } => transferred.push(obj),
                     ObjectChange::Wrapped { .. } => wrapped.push(obj),
+                    ObjectChange::Unwrapped { .. } => unwrapped.push(obj),
                 };
             }
 
@@ -303,6 +307,7 @@ impl Display for SuiTransactionBlockResponse {
             write_obj_changes(published, "Published", &mut builder)?;
             write_obj_changes(transferred, "Transferred", &mut builder)?;
             write_obj_changes(wrapped, "Wrapped", &mut builder)?;
+            write_obj_changes(unwrapped, "Unwrapped", &mut builder)?;
 
             let mut table = builder.build();
             table.with(TablePanel::header("Object Changes"));
@@ -1037,6 +1042,53 @@ impl Display for SuiTransactionBlockEvents {
     }
 }
 
+/// A signed transaction submitted in a batch to `sui_subscribeExecuteTransactionBlocks`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "SignedTransactionBlockBytes", rename_all = "camelCase")]
+pub struct SignedTransactionBlockBytes {
+    /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
+    pub tx_bytes: Base64,
+    /// A list of signatures (`flag || signature || pubkey` bytes, as base-64 encoded string).
+    pub signatures: Vec<Base64>,
+}
+
+/// An update of the status of a transaction submitted in a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename = "TransactionBlockStatusUpdate", rename_all = "camelCase")]
+pub struct TransactionBlockStatusUpdate {
+    /// The index of the transaction in the submitted batch.
+    pub index: usize,
+    /// The digest of the transaction, if its bytes could be deserialized.
+    pub digest: Option<TransactionDigest>,
+    pub status: TransactionBlockStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(
+    rename = "TransactionBlockStatus",
+    rename_all = "camelCase",
+    tag = "status"
+)]
+pub enum TransactionBlockStatus {
+    /// The signatures are valid and the transaction is submitted to the validators.
+    Accepted,
+    /// A quorum of validators certified the effects of the transaction. The effects may still
+    /// be those of a failed execution, see the `executed` update.
+    #[serde(rename_all = "camelCase")]
+    Certified {
+        effects_digest: TransactionEffectsDigest,
+    },
+    /// The transaction is final, with the execution status of its effects.
+    #[serde(rename_all = "camelCase")]
+    Executed {
+        execution_status: SuiExecutionStatus,
+        /// Whether the fullnode executed the transaction before returning the update.
+        confirmed_local_execution: bool,
+    },
+    /// The transaction could not be submitted or certified.
+    Failed { error: String },
+}
+
 // TODO: this file might not be the best place for this struct.
 /// Additional rguments supplied to dev inspect beyond what is allowed in today's API.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
@@ -16,7 +16,7 @@ use sui_types::gas_coin::GasCoin;
 use sui_types::object::{MoveObject, Owner};
 use sui_types::{parse_sui_struct_tag, MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS};
 
-use crate::{ObjectChange, SuiMoveStruct, SuiMoveValue};
+use crate::{ObjectChange, SuiMoveStruct, SuiMoveStructLayout, SuiMoveValue};
 
 #[test]
 fn test_move_value_to_sui_coin() {
@@ -196,3 +196,35 @@ fn test_type_tag_struct_tag_devnet_inc_222() {
         assert_eq!(oc, deser);
     }
 }
+
+#[test]
+fn test_unwrapped_object_change() {
+    let digest = ObjectDigest::random();
+    let oc = ObjectChange::Unwrapped {
+        sender: Default::default(),
+        owner: Owner::Immutable,
+        object_type: parse_sui_struct_tag("0x2::coin::Coin<0x2::sui::SUI>").unwrap(),
+        object_id: ObjectID::random(),
+        version: Default::default(),
+        digest,
+    };
+    assert_eq!(oc.object_ref().2, digest);
+
+    let serde_json = serde_json::to_value(&oc).unwrap();
+    assert_eq!(serde_json["type"], "unwrapped");
+    let deser: ObjectChange = serde_json::from_value(serde_json).unwrap();
+    assert_eq!(oc, deser);
+}
+
+#[test]
+fn test_struct_layout_decode() {
+    let id = ObjectID::random();
+    let move_object = MoveObject::new_gas_coin(SequenceNumber::new(), id, 10000);
+    let layout = SuiMoveStructLayout::from(GasCoin::layout());
+
+    // The layout is served as JSON, and must decode the value the same after a round trip.
+    let json = serde_json::to_string(&layout).unwrap();
+    let layout: SuiMoveStructLayout = serde_json::from_str(&json).unwrap();
+    let expected = SuiMoveStruct::from(move_object.to_move_struct(&GasCoin::layout()).unwrap());
+    assert_eq!(layout.decode(move_object.contents()).unwrap(), expected);
+}
@@ -34,6 +34,7 @@ use sui_types::messages_checkpoint::{
     CheckpointContents, CheckpointContentsDigest, CheckpointDigest, CheckpointSequenceNumber,
     VerifiedCheckpoint,
 };
+use sui_types::messages_grpc::{EpochStateCommitmentRequest, EpochStateCommitmentResponse};
 use sui_types::object::{Object, ObjectRead, PastObjectRead};
 use sui_types::storage::WriteKind;
 use sui_types::sui_serde::BigInt;
@@ -131,6 +132,15 @@ pub trait StateRead: Send + Sync {
         skip_checks: Option<bool>,
     ) -> StateReadResult<DevInspectResults>;
 
+    async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender: SuiAddress,
+        transaction_kind: TransactionKind,
+        checkpoint: CheckpointSequenceNumber,
+        gas_price: Option<u64>,
+        gas_budget: Option<u64>,
+    ) -> StateReadResult<DevInspectResults>;
+
     // indexer_api
     fn get_subscription_handler(&self) -> Arc<SubscriptionHandler>;
 
@@ -233,6 +243,13 @@ pub trait StateRead: Send + Sync {
     ) -> StateReadResult<Option<Vec<(ObjectID, SequenceNumber)>>>;
 
     fn get_chain_identifier(&self) -> StateReadResult<ChainIdentifier>;
+
+    fn is_address_denied_for_coin(&self, address: SuiAddress, coin_type: String) -> bool;
+
+    fn get_epoch_state_commitment(
+        &self,
+        epoch: EpochId,
+    ) -> StateReadResult<EpochStateCommitmentResponse>;
 }
 
 #[async_trait]
@@ -372,6 +389,25 @@ impl StateRead for AuthorityState {
             .await?)
     }
 
+    async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender: SuiAddress,
+        transaction_kind: TransactionKind,
+        checkpoint: CheckpointSequenceNumber,
+        gas_price: Option<u64>,
+        gas_budget: Option<u64>,
+    ) -> StateReadResult<DevInspectResults> {
+        Ok(self
+            .dev_inspect_transaction_block_at_checkpoint(
+                sender,
+                transaction_kind,
+                checkpoint,
+                gas_price,
+                gas_budget,
+            )
+            .await?)
+    }
+
     fn get_subscription_handler(&self) -> Arc<SubscriptionHandler> {
         self.subscription_handler.clone()
     }
@@ -554,6 +590,17 @@ impl StateRead for AuthorityState {
             .get_chain_identifier()
             .ok_or(anyhow!("Chain identifier not found"))?)
     }
+
+    fn is_address_denied_for_coin(&self, address: SuiAddress, coin_type: String) -> bool {
+        self.is_address_denied_for_coin(address, coin_type)
+    }
+
+    fn get_epoch_state_commitment(
+        &self,
+        epoch: EpochId,
+    ) -> StateReadResult<EpochStateCommitmentResponse> {
+        Ok(self.handle_epoch_state_commitment_request(EpochStateCommitmentRequest { epoch })?)
+    }
 }
 
 /// This implementation allows `S` to be a dynamically sized type (DST) that implements ObjectProvider
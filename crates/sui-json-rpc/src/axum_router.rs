@@ -23,6 +23,18 @@ use sui_json_rpc_api::CLIENT_TARGET_API_VERSION_HEADER;
 
 pub const MAX_RESPONSE_SIZE: u32 = 2 << 30;
 
+/// Extends [`Logger`] with a callback that sees the method name together with the request
+/// headers and the payload sizes, none of which the `Logger` callbacks are given.
+pub trait MethodLogger: Logger {
+    fn on_method_response(
+        &self,
+        method_name: &str,
+        headers: Option<&HeaderMap>,
+        request_size: usize,
+        response_size: usize,
+    );
+}
+
 #[derive(Clone, Debug)]
 pub struct JsonRpcService<L> {
     logger: L,
@@ -97,27 +109,23 @@ pub(crate) fn ok_response(body: String) -> hyper::Response<hyper::Body> {
     from_template(hyper::StatusCode::OK, body, JSON)
 }
 
-pub async fn json_rpc_handler<L: Logger>(
+pub async fn json_rpc_handler<L: MethodLogger>(
     State(service): State<JsonRpcService<L>>,
     headers: HeaderMap,
     Json(raw_request): Json<Box<RawValue>>,
 ) -> impl axum::response::IntoResponse {
-    // Get version from header.
-    let api_version = headers
-        .get(CLIENT_TARGET_API_VERSION_HEADER)
-        .and_then(|h| h.to_str().ok());
-    let response = process_raw_request(&service, api_version, raw_request.get()).await;
+    let response = process_raw_request(&service, &headers, raw_request.get()).await;
 
     ok_response(response.result)
 }
 
-async fn process_raw_request<L: Logger>(
+async fn process_raw_request<L: MethodLogger>(
     service: &JsonRpcService<L>,
-    api_version: Option<&str>,
+    headers: &HeaderMap,
     raw_request: &str,
 ) -> MethodResponse {
     if let Ok(request) = serde_json::from_str::<Request>(raw_request) {
-        process_request(request, api_version, service.call_data()).await
+        process_request(request, headers, service.call_data()).await
     } else if let Ok(_batch) = serde_json::from_str::<Vec<&RawValue>>(raw_request) {
         MethodResponse::error(
             Id::Null,
@@ -129,9 +137,9 @@ async fn process_raw_request<L: Logger>(
     }
 }
 
-async fn process_request<L: Logger>(
+async fn process_request<L: MethodLogger>(
     req: Request<'_>,
-    api_version: Option<&str>,
+    headers: &HeaderMap,
     call: CallData<'_, L>,
 ) -> MethodResponse {
     let CallData {
@@ -143,7 +151,12 @@ async fn process_request<L: Logger>(
     } = call;
     let conn_id = 0; // unused
 
+    // Get version from header.
+    let api_version = headers
+        .get(CLIENT_TARGET_API_VERSION_HEADER)
+        .and_then(|h| h.to_str().ok());
     let params = Params::new(req.params.map(|params| params.get()));
+    let request_size = params.as_str().map_or(0, str::len);
     let name = rpc_router.route(&req.method, api_version);
     let id = req.id;
 
@@ -200,6 +213,7 @@ async fn process_request<L: Logger>(
         request_start,
         TransportProtocol::Http,
     );
+    logger.on_method_response(name, Some(headers), request_size, response.result.len());
     response
 }
 
@@ -255,14 +269,17 @@ pub mod ws {
     // A WebSocket handler that echos any message it receives.
     //
     // This one we'll be integration testing so it can be written in the regular way.
-    pub async fn ws_json_rpc_upgrade<L: Logger>(
+    pub async fn ws_json_rpc_upgrade<L: MethodLogger>(
         ws: WebSocketUpgrade,
         State(service): State<JsonRpcService<L>>,
     ) -> Response {
         ws.on_upgrade(|ws| ws_json_rpc_handler(ws, service))
     }
 
-    async fn ws_json_rpc_handler<L: Logger>(mut socket: WebSocket, service: JsonRpcService<L>) {
+    async fn ws_json_rpc_handler<L: MethodLogger>(
+        mut socket: WebSocket,
+        service: JsonRpcService<L>,
+    ) {
         #[allow(clippy::disallowed_methods)]
         let (tx, mut rx) = mpsc::unbounded::<String>();
         let sink = MethodSink::new_with_limit(tx, MAX_RESPONSE_SIZE, MAX_RESPONSE_SIZE);
@@ -292,7 +309,7 @@ pub mod ws {
         }
     }
 
-    async fn process_raw_request<L: Logger>(
+    async fn process_raw_request<L: MethodLogger>(
         service: &JsonRpcService<L>,
         raw_request: &str,
         bounded_subscriptions: BoundedSubscriptions,
@@ -311,7 +328,7 @@ pub mod ws {
         }
     }
 
-    async fn process_request<L: Logger>(
+    async fn process_request<L: MethodLogger>(
         req: Request<'_>,
         call: WsCallData<'_, L>,
     ) -> Option<MethodResponse> {
@@ -327,6 +344,7 @@ pub mod ws {
         let conn_id = 0; // unused
 
         let params = Params::new(req.params.map(|params| params.get()));
+        let request_size = params.as_str().map_or(0, str::len);
         let name = &req.method;
         let id = req.id;
 
@@ -419,6 +437,7 @@ pub mod ws {
                 request_start,
                 TransportProtocol::WebSocket,
             );
+            logger.on_method_response(name, None, request_size, response.result.len());
         }
         response
     }
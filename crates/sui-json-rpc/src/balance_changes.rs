@@ -264,3 +264,122 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::SuiAddress;
+    use sui_types::digests::TransactionDigest;
+    use sui_types::object::MoveObject;
+
+    #[derive(Default)]
+    struct InMemoryObjectProvider(BTreeMap<(ObjectID, SequenceNumber), Object>);
+
+    impl InMemoryObjectProvider {
+        fn insert_coin(
+            &mut self,
+            id: ObjectID,
+            version: u64,
+            owner: SuiAddress,
+            value: u64,
+        ) -> (ObjectID, SequenceNumber, Option<ObjectDigest>) {
+            let version = SequenceNumber::from_u64(version);
+            let object = Object::new_move(
+                MoveObject::new_gas_coin(version, id, value),
+                Owner::AddressOwner(owner),
+                TransactionDigest::genesis_marker(),
+            );
+            let digest = object.digest();
+            self.0.insert((id, version), object);
+            (id, version, Some(digest))
+        }
+    }
+
+    #[async_trait]
+    impl ObjectProvider for InMemoryObjectProvider {
+        type Error = ObjectID;
+
+        async fn get_object(
+            &self,
+            id: &ObjectID,
+            version: &SequenceNumber,
+        ) -> Result<Object, Self::Error> {
+            self.0.get(&(*id, *version)).cloned().ok_or(*id)
+        }
+
+        async fn find_object_lt_or_eq_version(
+            &self,
+            id: &ObjectID,
+            version: &SequenceNumber,
+        ) -> Result<Option<Object>, Self::Error> {
+            Ok(self
+                .0
+                .range((*id, SequenceNumber::MIN)..=(*id, *version))
+                .next_back()
+                .map(|(_, object)| object.clone()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_balance_changes_of_split_and_transfer() {
+        let (alice, bob) = (
+            SuiAddress::random_for_testing_only(),
+            SuiAddress::random_for_testing_only(),
+        );
+        let (coin, gas, new_coin, touched_coin) = (
+            ObjectID::random(),
+            ObjectID::random(),
+            ObjectID::random(),
+            ObjectID::random(),
+        );
+        let mut provider = InMemoryObjectProvider::default();
+
+        // Alice splits 30 off her coin to Bob and pays 5 for gas. Another coin of hers is
+        // mutated without its balance or owner changing, which contributes nothing.
+        let modified_at_version = vec![
+            provider.insert_coin(coin, 1, alice, 100),
+            provider.insert_coin(gas, 1, alice, 50),
+            provider.insert_coin(touched_coin, 1, alice, 20),
+        ];
+        let all_mutated = vec![
+            provider.insert_coin(coin, 2, alice, 70),
+            provider.insert_coin(gas, 2, alice, 45),
+            provider.insert_coin(new_coin, 2, bob, 30),
+            provider.insert_coin(touched_coin, 2, alice, 20),
+        ];
+
+        let mut balance_changes =
+            get_balance_changes(&provider, &modified_at_version, &all_mutated)
+                .await
+                .unwrap();
+        balance_changes.sort_by_key(|change| change.amount);
+        assert_eq!(
+            balance_changes,
+            vec![
+                BalanceChange {
+                    owner: Owner::AddressOwner(alice),
+                    coin_type: GAS::type_tag(),
+                    amount: -35,
+                },
+                BalanceChange {
+                    owner: Owner::AddressOwner(bob),
+                    coin_type: GAS::type_tag(),
+                    amount: 30,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_balance_changes_of_missing_object() {
+        let provider = InMemoryObjectProvider::default();
+        let missing = ObjectID::random();
+        let result = get_balance_changes(
+            &provider,
+            &[(missing, SequenceNumber::from_u64(1), None)],
+            &[],
+        )
+        .await;
+        assert_eq!(result, Err(missing));
+    }
+}
@@ -34,6 +34,7 @@ use mockall::automock;
 
 use crate::authority_state::StateRead;
 use crate::error::{Error, RpcInterimResult, SuiRpcInputError};
+use crate::read_cache::{ImmutableCache, READ_CACHE_SIZE};
 use crate::{with_tracing, SuiRpcModule};
 
 pub fn parse_to_struct_tag(coin_type: &str) -> Result<StructTag, SuiRpcInputError> {
@@ -238,6 +239,22 @@ impl CoinReadApiServer for CoinReadApi {
             })
         })
     }
+
+    #[instrument(skip(self))]
+    async fn is_address_denied_for_coin(
+        &self,
+        address: SuiAddress,
+        coin_type: String,
+    ) -> RpcResult<bool> {
+        with_tracing!(async move {
+            let coin_type = parse_to_type_tag(Some(coin_type))?.to_canonical_string(false);
+            let state = self.internal.get_state();
+            Ok(spawn_monitored_task!(async move {
+                state.is_address_denied_for_coin(address, coin_type)
+            })
+            .await?)
+        })
+    }
 }
 
 #[cached(
@@ -312,6 +329,8 @@ pub struct CoinReadInternalImpl {
     state: Arc<dyn StateRead>,
     transaction_kv_store: Arc<TransactionKeyValueStore>,
     pub metrics: Arc<JsonRpcMetrics>,
+    /// Frozen coin metadata objects, which are read often and never change.
+    coin_metadata_cache: ImmutableCache<(ObjectID, StructTag), Object>,
 }
 
 impl CoinReadInternalImpl {
@@ -324,6 +343,7 @@ impl CoinReadInternalImpl {
             state,
             transaction_kv_store,
             metrics,
+            coin_metadata_cache: ImmutableCache::new(*READ_CACHE_SIZE),
         }
     }
 }
@@ -358,11 +378,26 @@ impl CoinReadInternal for CoinReadInternalImpl {
         package_id: &ObjectID,
         object_struct_tag: StructTag,
     ) -> RpcInterimResult<Object> {
+        // Only frozen coin metadata is cached. Owned or shared metadata can be updated, e.g. by a
+        // transaction this node executed ahead of its checkpoint.
+        let cache_key = CoinMetadata::is_coin_metadata(&object_struct_tag)
+            .then(|| (*package_id, object_struct_tag.clone()));
+        if let Some(object) = cache_key
+            .as_ref()
+            .and_then(|k| self.coin_metadata_cache.get(k))
+        {
+            return Ok(object);
+        }
+
         let state = self.get_state();
         let kv_store = self.transaction_kv_store.clone();
         let object_id =
             find_package_object_id(state, *package_id, object_struct_tag, kv_store).await?;
-        Ok(self.state.get_object_read(&object_id)?.into_object()?)
+        let object = self.state.get_object_read(&object_id)?.into_object()?;
+        if let Some(cache_key) = cache_key.filter(|_| object.is_immutable()) {
+            self.coin_metadata_cache.insert(cache_key, object.clone());
+        }
+        Ok(object)
     }
 
     async fn get_coins_iterator(
@@ -1399,4 +1434,45 @@ mod tests {
             expected.assert_eq(error_object.message());
         }
     }
+
+    mod is_address_denied_for_coin_tests {
+        use super::super::*;
+        use super::*;
+        use mockall::predicate;
+
+        #[tokio::test]
+        async fn test_denied_address() {
+            let owner = get_test_owner();
+            let coin_type = get_test_coin_type(get_test_package_id());
+            let canonical_coin_type =
+                get_test_coin_type_tag(coin_type.clone()).to_canonical_string(false);
+            let mut mock_state = MockStateRead::new();
+            mock_state
+                .expect_is_address_denied_for_coin()
+                .with(predicate::eq(owner), predicate::eq(canonical_coin_type))
+                .return_once(|_, _| true);
+            let coin_read_api = CoinReadApi::new_for_tests(Arc::new(mock_state), None);
+
+            let response = coin_read_api
+                .is_address_denied_for_coin(owner, coin_type)
+                .await;
+
+            assert!(response.unwrap());
+        }
+
+        #[tokio::test]
+        async fn test_invalid_coin_type() {
+            let coin_read_api = CoinReadApi::new_for_tests(Arc::new(MockStateRead::new()), None);
+
+            let response = coin_read_api
+                .is_address_denied_for_coin(get_test_owner(), "0x2::invalid".to_string())
+                .await;
+
+            let error_object: ErrorObjectOwned = response.unwrap_err().into();
+            assert_eq!(
+                error_object.code(),
+                jsonrpsee::types::error::INVALID_PARAMS_CODE
+            );
+        }
+    }
 }
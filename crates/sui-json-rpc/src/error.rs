@@ -5,11 +5,13 @@ use fastcrypto::error::FastCryptoError;
 use hyper::header::InvalidHeaderValue;
 use itertools::Itertools;
 use jsonrpsee::core::Error as RpcError;
-use jsonrpsee::types::error::{CallError, INTERNAL_ERROR_CODE};
+use jsonrpsee::types::error::{
+    CallError, CALL_EXECUTION_FAILED_CODE, INTERNAL_ERROR_CODE, INVALID_PARAMS_CODE,
+};
 use jsonrpsee::types::ErrorObject;
 use std::collections::BTreeMap;
 use sui_json_rpc_api::{TRANSACTION_EXECUTION_CLIENT_ERROR_CODE, TRANSIENT_ERROR_CODE};
-use sui_types::error::{SuiError, SuiObjectResponseError, UserInputError};
+use sui_types::error::{SuiError, SuiErrorData, SuiObjectResponseError, UserInputError};
 use sui_types::quorum_driver_types::QuorumDriverError;
 use thiserror::Error;
 use tokio::task::JoinError;
@@ -80,30 +82,40 @@ impl From<SuiError> for Error {
     }
 }
 
+/// An error with the given code and message, and the [SuiErrorData] of `error` as data.
+fn error_with_data(code: i32, message: String, error: SuiError) -> RpcError {
+    let error_object = ErrorObject::owned(code, message, Some(error.error_data()));
+    RpcError::Call(CallError::Custom(error_object))
+}
+
 impl From<Error> for RpcError {
     /// `InvalidParams`/`INVALID_PARAMS_CODE` for client errors.
     fn from(e: Error) -> RpcError {
         match e {
-            Error::UserInputError(_) => RpcError::Call(CallError::InvalidParams(e.into())),
+            Error::UserInputError(err) => {
+                error_with_data(INVALID_PARAMS_CODE, err.to_string(), err.into())
+            }
             Error::UnsupportedFeature(_) => RpcError::Call(CallError::InvalidParams(e.into())),
-            Error::SuiObjectResponseError(err) => match err {
-                SuiObjectResponseError::NotExists { .. }
-                | SuiObjectResponseError::DynamicFieldNotFound { .. }
-                | SuiObjectResponseError::Deleted { .. }
-                | SuiObjectResponseError::DisplayError { .. } => {
-                    RpcError::Call(CallError::InvalidParams(err.into()))
-                }
-                _ => RpcError::Call(CallError::Failed(err.into())),
-            },
+            Error::SuiObjectResponseError(err) => {
+                let code = match err {
+                    SuiObjectResponseError::NotExists { .. }
+                    | SuiObjectResponseError::DynamicFieldNotFound { .. }
+                    | SuiObjectResponseError::Deleted { .. }
+                    | SuiObjectResponseError::DisplayError { .. } => INVALID_PARAMS_CODE,
+                    _ => CALL_EXECUTION_FAILED_CODE,
+                };
+                error_with_data(code, err.to_string(), err.into())
+            }
             Error::SuiRpcInputError(err) => RpcError::Call(CallError::InvalidParams(err.into())),
-            Error::SuiError(sui_error) => match sui_error {
-                SuiError::TransactionNotFound { .. }
-                | SuiError::TransactionsNotFound { .. }
-                | SuiError::TransactionEventsNotFound { .. } => {
-                    RpcError::Call(CallError::InvalidParams(sui_error.into()))
-                }
-                _ => RpcError::Call(CallError::Failed(sui_error.into())),
-            },
+            Error::SuiError(sui_error) => {
+                let code = match sui_error {
+                    SuiError::TransactionNotFound { .. }
+                    | SuiError::TransactionsNotFound { .. }
+                    | SuiError::TransactionEventsNotFound { .. } => INVALID_PARAMS_CODE,
+                    _ => CALL_EXECUTION_FAILED_CODE,
+                };
+                error_with_data(code, sui_error.to_string(), sui_error)
+            }
             Error::StateReadError(err) => match err {
                 StateReadError::Client(_) => RpcError::Call(CallError::InvalidParams(err.into())),
                 _ => {
@@ -118,7 +130,7 @@ impl From<Error> for RpcError {
             Error::QuorumDriverError(err) => {
                 match err {
                     QuorumDriverError::InvalidUserSignature(err) => {
-                        let inner_error_str = match err {
+                        let inner_error_str = match &err {
                             // TODO(wlmyng): update SuiError display trait to render UserInputError with display
                             SuiError::UserInputError { error } => error.to_string(),
                             _ => err.to_string(),
@@ -126,12 +138,7 @@ impl From<Error> for RpcError {
 
                         let error_message = format!("Invalid user signature: {inner_error_str}");
 
-                        let error_object = ErrorObject::owned(
-                            TRANSACTION_EXECUTION_CLIENT_ERROR_CODE,
-                            error_message,
-                            None::<()>,
-                        );
-                        RpcError::Call(CallError::Custom(error_object))
+                        error_with_data(TRANSACTION_EXECUTION_CLIENT_ERROR_CODE, error_message, err)
                     }
                     QuorumDriverError::TxAlreadyFinalizedWithDifferentUserSignatures => {
                         let error_object = ErrorObject::owned(
@@ -176,7 +183,7 @@ impl From<Error> for RpcError {
                         RpcError::Call(CallError::Custom(error_object))
                     }
                     QuorumDriverError::NonRecoverableTransactionError { errors } => {
-                        let new_errors: Vec<String> = errors
+                        let (new_errors, error_data): (Vec<String>, Vec<SuiErrorData>) = errors
                             .into_iter()
                             // sort by total stake, descending, so users see the most prominent one first
                             .sorted_by(|(_, a, _), (_, b, _)| b.cmp(a))
@@ -192,17 +199,19 @@ impl From<Error> for RpcError {
                                     // So, we take an easier route and consider them non-retryable
                                     // at all. Combining this with the sorting above, clients will
                                     // see the dominant error first.
-                                    SuiError::UserInputError { error } => Some(error.to_string()),
+                                    SuiError::UserInputError { error } => {
+                                        Some((error.to_string(), err.error_data()))
+                                    }
                                     _ => {
                                         if err.is_retryable().0 {
                                             None
                                         } else {
-                                            Some(err.to_string())
+                                            Some((err.to_string(), err.error_data()))
                                         }
                                     }
                                 }
                             })
-                            .collect();
+                            .unzip();
 
                         assert!(
                             !new_errors.is_empty(),
@@ -215,7 +224,7 @@ impl From<Error> for RpcError {
                         let error_object = ErrorObject::owned(
                             TRANSACTION_EXECUTION_CLIENT_ERROR_CODE,
                             error_msg,
-                            None::<()>,
+                            Some(error_data),
                         );
                         RpcError::Call(CallError::Custom(error_object))
                     }
@@ -227,9 +236,15 @@ impl From<Error> for RpcError {
                         );
                         RpcError::Call(CallError::Custom(error_object))
                     }
-                    QuorumDriverError::SystemOverload { .. } => {
-                        let error_object =
-                            ErrorObject::owned(TRANSIENT_ERROR_CODE, err.to_string(), None::<()>);
+                    QuorumDriverError::SystemOverload { ref errors, .. } => {
+                        // The overload errors of the validators carry their retry-after hints.
+                        let error_data: Vec<SuiErrorData> =
+                            errors.iter().map(|(err, _, _)| err.error_data()).collect();
+                        let error_object = ErrorObject::owned(
+                            TRANSIENT_ERROR_CODE,
+                            err.to_string(),
+                            Some(error_data),
+                        );
                         RpcError::Call(CallError::Custom(error_object))
                     }
                 }
@@ -298,6 +313,7 @@ mod tests {
     use sui_types::crypto::AuthorityPublicKeyBytes;
     use sui_types::digests::ObjectDigest;
     use sui_types::digests::TransactionDigest;
+    use sui_types::error::QueueDepthClass;
 
     fn test_object_ref() -> ObjectRef {
         (
@@ -307,6 +323,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_sui_error_data() {
+        let rpc_error: RpcError = Error::from(SuiError::TransactionNotFound {
+            digest: TransactionDigest::default(),
+        })
+        .into();
+
+        let error_object: ErrorObjectOwned = rpc_error.into();
+        let expected_code = expect!["-32602"];
+        expected_code.assert_eq(&error_object.code().to_string());
+        let expected_message = expect!["Could not find the referenced transaction [TransactionDigest(11111111111111111111111111111111)]."];
+        expected_message.assert_eq(error_object.message());
+        let expected_data = expect![[
+            r#"{"kind":"notFound","code":2000,"retriable":false,"context":{"digest":"11111111111111111111111111111111"}}"#
+        ]];
+        let actual_data = error_object.data().unwrap().to_string();
+        expected_data.assert_eq(&actual_data);
+    }
+
     mod match_quorum_driver_error_tests {
         use super::*;
 
@@ -479,7 +514,15 @@ mod tests {
         fn test_system_overload() {
             let quorum_driver_error = QuorumDriverError::SystemOverload {
                 overloaded_stake: 10,
-                errors: vec![(SuiError::UnexpectedMessage, 0, vec![])],
+                errors: vec![(
+                    SuiError::ValidatorOverloaded {
+                        error: Box::new(SuiError::TooManyTransactionsPendingConsensus),
+                        queue_depth: QueueDepthClass::High,
+                        retry_after_ms: 1000,
+                    },
+                    10,
+                    vec![],
+                )],
             };
 
             let rpc_error: RpcError = Error::QuorumDriverError(quorum_driver_error).into();
@@ -489,6 +532,11 @@ mod tests {
             expected_code.assert_eq(&error_object.code().to_string());
             let expected_message = expect!["Transaction is not processed because 10 of validators by stake are overloaded with certificates pending execution."];
             expected_message.assert_eq(error_object.message());
+            let expected_data = expect![[
+                r#"[{"kind":"overload","code":3007,"retriable":true,"context":{"error":"TooManyTransactionsPendingConsensus","queue_depth":"High","retry_after_ms":1000}}]"#
+            ]];
+            let actual_data = error_object.data().unwrap().to_string();
+            expected_data.assert_eq(&actual_data);
         }
     }
 }
@@ -14,7 +14,7 @@ use move_core_types::language_storage::TypeTag;
 use mysten_metrics::spawn_monitored_task;
 use serde::Serialize;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use sui_core::authority::AuthorityState;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_api::{
@@ -73,13 +73,49 @@ pub fn spawn_subscription<S, T>(
 }
 const DEFAULT_MAX_SUBSCRIPTIONS: usize = 100;
 
+/// Changes the maximum number of concurrent subscriptions of an [IndexerApi] while it is running.
+#[derive(Clone)]
+pub struct SubscriptionLimit {
+    semaphore: Arc<Semaphore>,
+    max_subscriptions: Arc<Mutex<usize>>,
+}
+
+impl SubscriptionLimit {
+    fn new(max_subscriptions: Option<usize>) -> Self {
+        let max_subscriptions = max_subscriptions.unwrap_or(DEFAULT_MAX_SUBSCRIPTIONS);
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_subscriptions)),
+            max_subscriptions: Arc::new(Mutex::new(max_subscriptions)),
+        }
+    }
+
+    /// Lowering the limit does not close active subscriptions, new subscriptions are refused
+    /// until enough of them have ended.
+    pub fn set(&self, max_subscriptions: Option<usize>) {
+        let max_subscriptions = max_subscriptions.unwrap_or(DEFAULT_MAX_SUBSCRIPTIONS);
+        let mut current = self.max_subscriptions.lock().unwrap();
+        if max_subscriptions > *current {
+            self.semaphore.add_permits(max_subscriptions - *current);
+        } else if max_subscriptions < *current {
+            let removed = (*current - max_subscriptions) as u32;
+            let semaphore = self.semaphore.clone();
+            spawn_monitored_task!(async move {
+                if let Ok(permits) = semaphore.acquire_many_owned(removed).await {
+                    permits.forget();
+                }
+            });
+        }
+        *current = max_subscriptions;
+    }
+}
+
 pub struct IndexerApi<R> {
     state: Arc<dyn StateRead>,
     read_api: R,
     transaction_kv_store: Arc<TransactionKeyValueStore>,
     name_service_config: NameServiceConfig,
     pub metrics: Arc<JsonRpcMetrics>,
-    subscription_semaphore: Arc<Semaphore>,
+    subscription_limit: SubscriptionLimit,
 }
 
 impl<R: ReadApiServer> IndexerApi<R> {
@@ -91,17 +127,20 @@ impl<R: ReadApiServer> IndexerApi<R> {
         metrics: Arc<JsonRpcMetrics>,
         max_subscriptions: Option<usize>,
     ) -> Self {
-        let max_subscriptions = max_subscriptions.unwrap_or(DEFAULT_MAX_SUBSCRIPTIONS);
         Self {
             state,
             transaction_kv_store,
             read_api,
             name_service_config,
             metrics,
-            subscription_semaphore: Arc::new(Semaphore::new(max_subscriptions)),
+            subscription_limit: SubscriptionLimit::new(max_subscriptions),
         }
     }
 
+    pub fn subscription_limit(&self) -> SubscriptionLimit {
+        self.subscription_limit.clone()
+    }
+
     fn extract_values_from_dynamic_field_name(
         &self,
         name: DynamicFieldName,
@@ -118,7 +157,12 @@ impl<R: ReadApiServer> IndexerApi<R> {
     }
 
     fn acquire_subscribe_permit(&self) -> anyhow::Result<OwnedSemaphorePermit> {
-        match self.subscription_semaphore.clone().try_acquire_owned() {
+        match self
+            .subscription_limit
+            .semaphore
+            .clone()
+            .try_acquire_owned()
+        {
             Ok(p) => Ok(p),
             Err(_) => bail!("Resources exhausted"),
         }
@@ -443,3 +487,27 @@ impl<R: ReadApiServer> SuiRpcModule for IndexerApi<R> {
         IndexerApiOpenRpc::module_doc()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscription_limit() {
+        let limit = SubscriptionLimit::new(Some(2));
+        let acquire = || limit.semaphore.clone().try_acquire_owned();
+        let first = acquire().unwrap();
+        let _second = acquire().unwrap();
+        assert!(acquire().is_err());
+
+        limit.set(Some(3));
+        let _third = acquire().unwrap();
+        assert!(acquire().is_err());
+
+        // Active subscriptions are kept, and new ones refused until enough of them end.
+        limit.set(Some(1));
+        drop(first);
+        tokio::task::yield_now().await;
+        assert!(acquire().is_err());
+    }
+}
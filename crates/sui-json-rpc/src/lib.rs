@@ -25,7 +25,7 @@ use sui_json_rpc_api::{
 use sui_open_rpc::{Module, Project};
 
 use crate::error::Error;
-use crate::metrics::MetricsLogger;
+use crate::metrics::{MetricsLogger, DEFAULT_MAX_CLIENT_LABELS};
 use crate::routing_layer::RpcRouter;
 
 pub mod authority_state;
@@ -41,6 +41,7 @@ pub mod move_utils;
 pub mod name_service;
 mod object_changes;
 pub mod read_api;
+mod read_cache;
 mod routing_layer;
 pub mod transaction_builder_api;
 pub mod transaction_execution_api;
@@ -163,7 +164,11 @@ impl JsonRpcServerBuilder {
         module.register_method("rpc.discover", move |_, _| Ok(rpc_docs.clone()))?;
         let methods_names = module.method_names().collect::<Vec<_>>();
 
-        let metrics_logger = MetricsLogger::new(&self.registry, &methods_names);
+        let max_client_labels = env::var("RPC_METRICS_MAX_CLIENT_LABELS")
+            .ok()
+            .and_then(|v| usize::from_str(&v).ok())
+            .unwrap_or(DEFAULT_MAX_CLIENT_LABELS);
+        let metrics_logger = MetricsLogger::new(&self.registry, &methods_names, max_client_labels);
 
         let middleware = tower::ServiceBuilder::new()
             .layer(Self::trace_layer())
@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use hyper::body::HttpBody;
+use hyper::HeaderMap;
 use std::collections::HashSet;
 use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
 
 use jsonrpsee::server::logger::{HttpRequest, Logger, MethodKind, TransportProtocol};
 use jsonrpsee::types::Params;
@@ -15,7 +17,15 @@ use sui_json_rpc_api::TRANSIENT_ERROR_CODE;
 use sui_json_rpc_api::{CLIENT_SDK_TYPE_HEADER, CLIENT_TARGET_API_VERSION_HEADER};
 use tokio::time::Instant;
 
+use crate::axum_router::MethodLogger;
+use crate::APP_NAME_HEADER;
+
 const SPAM_LABEL: &str = "SPAM";
+const UNKNOWN_LABEL: &str = "Unknown";
+const OTHER_LABEL: &str = "Other";
+/// Default cap on the number of distinct clients reported in client labels, see
+/// [`MetricsLogger::new`].
+pub const DEFAULT_MAX_CLIENT_LABELS: usize = 100;
 const LATENCY_SEC_BUCKETS: &[f64] = &[
     0.001, 0.005, 0.01, 0.05, 0.1, 0.25, 0.5, 1., 2.5, 5., 10., 20., 30., 60., 90.,
 ];
@@ -41,12 +51,21 @@ pub struct Metrics {
     rpc_request_size: HistogramVec,
     /// Response size
     rpc_response_size: HistogramVec,
+    /// Request params size, route is a label
+    request_size_by_route: HistogramVec,
+    /// Response size, route is a label
+    response_size_by_route: HistogramVec,
+    /// Counter of requests, route and client are labels
+    requests_by_route_and_client: IntCounterVec,
 }
 
 #[derive(Clone)]
 pub struct MetricsLogger {
     metrics: Metrics,
     method_whitelist: HashSet<String>,
+    /// Clients given their own label value so far, at most `max_client_labels` of them.
+    client_labels: Arc<Mutex<HashSet<(String, String)>>>,
+    max_client_labels: usize,
 }
 
 impl MetricsLogger {
@@ -58,7 +77,28 @@ impl MetricsLogger {
         }
     }
 
-    pub fn new(registry: &prometheus::Registry, method_whitelist: &[&str]) -> Self {
+    /// Clients are identified by their own headers, so to bound the cardinality of the client
+    /// labels, clients beyond the first `max_client_labels` seen are reported as "Other".
+    fn check_client<'a>(&self, client_type: &'a str, app_name: &'a str) -> (&'a str, &'a str) {
+        let mut client_labels = self.client_labels.lock().unwrap();
+        let client = (client_type.to_owned(), app_name.to_owned());
+        if client_labels.contains(&client) {
+            (client_type, app_name)
+        } else if client_labels.len() < self.max_client_labels {
+            client_labels.insert(client);
+            (client_type, app_name)
+        } else {
+            (OTHER_LABEL, OTHER_LABEL)
+        }
+    }
+
+    /// `max_client_labels` caps the number of distinct clients, identified by their SDK type
+    /// and app name headers, that get their own label value.
+    pub fn new(
+        registry: &prometheus::Registry,
+        method_whitelist: &[&str],
+        max_client_labels: usize,
+    ) -> Self {
         let metrics = Metrics {
             requests_by_route: register_int_counter_vec_with_registry!(
                 "rpc_requests_by_route",
@@ -144,11 +184,40 @@ impl MetricsLogger {
                 registry,
             )
             .unwrap(),
+            request_size_by_route: register_histogram_vec_with_registry!(
+                "rpc_request_size_by_route",
+                "Size of the params of rpc requests by route",
+                &["route"],
+                prometheus::exponential_buckets(32.0, 2.0, 19)
+                    .unwrap()
+                    .to_vec(),
+                registry,
+            )
+            .unwrap(),
+            response_size_by_route: register_histogram_vec_with_registry!(
+                "rpc_response_size_by_route",
+                "Response size of rpc requests by route",
+                &["route"],
+                prometheus::exponential_buckets(1024.0, 2.0, 20)
+                    .unwrap()
+                    .to_vec(),
+                registry,
+            )
+            .unwrap(),
+            requests_by_route_and_client: register_int_counter_vec_with_registry!(
+                "rpc_requests_by_route_and_client",
+                "Number of requests by route and client",
+                &["route", "client_type", "app_name"],
+                registry,
+            )
+            .unwrap(),
         };
 
         Self {
             metrics,
             method_whitelist: method_whitelist.iter().map(|s| (*s).into()).collect(),
+            client_labels: Arc::new(Mutex::new(HashSet::new())),
+            max_client_labels,
         }
     }
 }
@@ -269,3 +338,53 @@ impl Logger for MetricsLogger {
             .dec();
     }
 }
+
+impl MethodLogger for MetricsLogger {
+    fn on_method_response(
+        &self,
+        method_name: &str,
+        headers: Option<&HeaderMap>,
+        request_size: usize,
+        response_size: usize,
+    ) {
+        let method_name = self.check_spam(method_name);
+        self.metrics
+            .request_size_by_route
+            .with_label_values(&[method_name])
+            .observe(request_size as f64);
+        self.metrics
+            .response_size_by_route
+            .with_label_values(&[method_name])
+            .observe(response_size as f64);
+
+        let header = |name: &'static str| {
+            headers
+                .and_then(|headers| headers.get(name))
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or(UNKNOWN_LABEL)
+        };
+        let (client_type, app_name) =
+            self.check_client(header(CLIENT_SDK_TYPE_HEADER), header(APP_NAME_HEADER));
+        self.metrics
+            .requests_by_route_and_client
+            .with_label_values(&[method_name, client_type, app_name])
+            .inc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_labels_are_capped() {
+        let registry = prometheus::Registry::new();
+        let logger = MetricsLogger::new(&registry, &["sui_getObject"], 2);
+
+        assert_eq!(logger.check_client("rust", "a"), ("rust", "a"));
+        assert_eq!(logger.check_client("ts", "b"), ("ts", "b"));
+        assert_eq!(logger.check_client("ts", "c"), (OTHER_LABEL, OTHER_LABEL));
+        // Clients seen before the cap was reached keep their labels.
+        assert_eq!(logger.check_client("rust", "a"), ("rust", "a"));
+    }
+}
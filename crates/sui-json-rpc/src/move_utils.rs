@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::authority_state::StateRead;
+use crate::coin_api::parse_to_struct_tag;
 use crate::error::{Error, SuiRpcInputError};
+use crate::read_cache::{ImmutableCache, READ_CACHE_SIZE};
 use crate::{with_tracing, SuiRpcModule};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
@@ -13,17 +15,19 @@ use move_binary_format::{
     file_format_common::VERSION_MAX,
     normalized::{Module as NormalizedModule, Type},
 };
+use move_core_types::annotated_value::MoveStructLayout;
 use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 use sui_core::authority::AuthorityState;
 use sui_json_rpc_api::{MoveUtilsOpenRpc, MoveUtilsServer};
 use sui_json_rpc_types::{
-    MoveFunctionArgType, ObjectValueKind, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
-    SuiMoveNormalizedStruct,
+    MoveFunctionArgType, ObjectValueKind, SuiMoveEventLayout, SuiMoveNormalizedFunction,
+    SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
 };
 use sui_open_rpc::Module;
-use sui_types::base_types::ObjectID;
+use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber};
 use sui_types::move_package::normalize_modules;
 use sui_types::object::{Data, ObjectRead};
 use tap::TapFallible;
@@ -46,15 +50,27 @@ pub trait MoveUtilsInternalTrait {
     ) -> Result<BTreeMap<String, NormalizedModule>, Error>;
 
     fn get_object_read(&self, package: ObjectID) -> Result<ObjectRead, Error>;
+
+    /// Returns the layout of `event_type`, and the version of the package that defines it.
+    fn get_event_layout(
+        &self,
+        event_type: &StructTag,
+    ) -> Result<(SequenceNumber, MoveStructLayout), Error>;
 }
 
 pub struct MoveUtilsInternal {
     state: Arc<dyn StateRead>,
+    /// Normalized modules by package version. System packages are upgraded in place, so they
+    /// are keyed by object ref rather than by ID.
+    package_cache: ImmutableCache<ObjectRef, BTreeMap<String, NormalizedModule>>,
 }
 
 impl MoveUtilsInternal {
     pub fn new(state: Arc<AuthorityState>) -> Self {
-        Self { state }
+        Self {
+            state,
+            package_cache: ImmutableCache::new(*READ_CACHE_SIZE),
+        }
     }
 }
 
@@ -88,20 +104,25 @@ impl MoveUtilsInternalTrait for MoveUtilsInternal {
         })?;
 
         match object_read {
-            ObjectRead::Exists(_obj_ref, object, _layout) => {
+            ObjectRead::Exists(obj_ref, object, _layout) => {
                 match object.into_inner().data {
                     Data::Package(p) => {
+                        if let Some(modules) = self.package_cache.get(&obj_ref) {
+                            return Ok(modules);
+                        }
                         // we are on the read path - it's OK to use VERSION_MAX of the supported Move
                         // binary format
-                        normalize_modules(
-                        p.serialized_module_map().values(),
-                        /* max_binary_format_version */ VERSION_MAX,
-                        /* no_extraneous_module_bytes */ false,
-                    )
-                    .map_err(|e| {
-                        error!("Failed to call get_move_modules_by_package for package: {package:?}");
-                        Error::from(e)
-                    })
+                        let modules = normalize_modules(
+                            p.serialized_module_map().values(),
+                            /* max_binary_format_version */ VERSION_MAX,
+                            /* no_extraneous_module_bytes */ false,
+                        )
+                        .map_err(|e| {
+                            error!("Failed to call get_move_modules_by_package for package: {package:?}");
+                            Error::from(e)
+                        })?;
+                        self.package_cache.insert(obj_ref, modules.clone());
+                        Ok(modules)
                     }
                     _ => Err(SuiRpcInputError::GenericInvalid(format!(
                         "Object is not a package with ID {}",
@@ -119,6 +140,30 @@ impl MoveUtilsInternalTrait for MoveUtilsInternal {
     fn get_object_read(&self, package: ObjectID) -> Result<ObjectRead, Error> {
         self.state.get_object_read(&package).map_err(Error::from)
     }
+
+    fn get_event_layout(
+        &self,
+        event_type: &StructTag,
+    ) -> Result<(SequenceNumber, MoveStructLayout), Error> {
+        let package = ObjectID::from(event_type.address);
+        let version = match self.get_object_read(package)? {
+            ObjectRead::Exists(obj_ref, object, _layout) if object.is_package() => obj_ref.1,
+            ObjectRead::Exists(..) => Err(SuiRpcInputError::GenericInvalid(format!(
+                "Object is not a package with ID {}",
+                package
+            )))?,
+            _ => Err(SuiRpcInputError::GenericNotFound(format!(
+                "Package object does not exist with ID {}",
+                package
+            )))?,
+        };
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        let layout = epoch_store
+            .executor()
+            .type_layout_resolver(Box::new(self.state.get_db()))
+            .get_annotated_layout(event_type)?;
+        Ok((version, layout))
+    }
 }
 
 pub struct MoveUtils {
@@ -281,6 +326,18 @@ impl MoveUtilsServer for MoveUtils {
             }
         })
     }
+
+    #[instrument(skip(self))]
+    async fn get_event_layout(&self, event_type: String) -> RpcResult<SuiMoveEventLayout> {
+        with_tracing!(async move {
+            let event_type = parse_to_struct_tag(&event_type)?;
+            let (package_version, layout) = self.internal.get_event_layout(&event_type)?;
+            Ok(SuiMoveEventLayout {
+                package_version,
+                layout: layout.into(),
+            })
+        })
+    }
 }
 
 #[cfg(test)]
@@ -345,4 +402,37 @@ mod tests {
             assert_eq!(error_object.message(), &error_string);
         }
     }
+
+    mod get_event_layout_tests {
+        use super::super::*;
+        use sui_types::gas_coin::GasCoin;
+
+        #[tokio::test]
+        async fn test_success_response() {
+            let mut mock_internal = MockMoveUtilsInternalTrait::new();
+            mock_internal
+                .expect_get_event_layout()
+                .return_once(|_event_type| Ok((SequenceNumber::from_u64(1), GasCoin::layout())));
+            let move_utils = MoveUtils {
+                internal: Arc::new(mock_internal),
+            };
+
+            let response = move_utils
+                .get_event_layout("0x2::coin::Coin<0x2::sui::SUI>".to_string())
+                .await
+                .unwrap();
+            assert_eq!(response.package_version, SequenceNumber::from_u64(1));
+            assert_eq!(response.layout, GasCoin::layout().into());
+        }
+
+        #[tokio::test]
+        async fn test_invalid_event_type() {
+            let move_utils = MoveUtils {
+                internal: Arc::new(MockMoveUtilsInternalTrait::new()),
+            };
+
+            let response = move_utils.get_event_layout("not a type".to_string()).await;
+            assert!(response.is_err());
+        }
+    }
 }
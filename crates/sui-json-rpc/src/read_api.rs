@@ -36,15 +36,17 @@ use sui_protocol_config::{ProtocolConfig, ProtocolVersion};
 use sui_storage::key_value_store::TransactionKeyValueStore;
 use sui_types::base_types::{ObjectID, SequenceNumber, TransactionDigest};
 use sui_types::collection_types::VecMap;
+use sui_types::committee::EpochId;
 use sui_types::crypto::AggregateAuthoritySignature;
 use sui_types::digests::TransactionEventsDigest;
 use sui_types::display::DisplayVersionUpdatedEvent;
 use sui_types::effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents};
 use sui_types::error::{SuiError, SuiObjectResponseError};
 use sui_types::messages_checkpoint::{
-    CheckpointContents, CheckpointContentsDigest, CheckpointSequenceNumber, CheckpointSummary,
-    CheckpointTimestamp,
+    CheckpointContents, CheckpointContentsDigest, CheckpointInclusionProof,
+    CheckpointSequenceNumber, CheckpointSummary, CheckpointTimestamp, EpochStateCommitment,
 };
+use sui_types::messages_grpc::EpochStateCommitmentResponse;
 use sui_types::object::{Object, ObjectRead, PastObjectRead};
 use sui_types::sui_serde::BigInt;
 use sui_types::transaction::Transaction;
@@ -52,6 +54,7 @@ use sui_types::transaction::TransactionDataAPI;
 
 use crate::authority_state::{StateRead, StateReadError, StateReadResult};
 use crate::error::{Error, RpcInterimResult, SuiRpcInputError};
+use crate::read_cache::{ImmutableCache, READ_CACHE_SIZE};
 use crate::with_tracing;
 use crate::{
     get_balance_changes_from_effect, get_object_changes, ObjectProviderCache, SuiRpcModule,
@@ -66,6 +69,10 @@ pub struct ReadApi {
     pub state: Arc<dyn StateRead>,
     pub transaction_kv_store: Arc<TransactionKeyValueStore>,
     pub metrics: Arc<JsonRpcMetrics>,
+    /// Responses for frozen Move objects, which never change. Responses with display fields are
+    /// not cached, as the display of a type can be updated.
+    object_cache: Arc<ImmutableCache<(ObjectID, SuiObjectDataOptions), SuiObjectResponse>>,
+    protocol_config_cache: Arc<ImmutableCache<ProtocolVersion, ProtocolConfigResponse>>,
 }
 
 // Internal data structure to make it easy to work with data returned from
@@ -107,6 +114,8 @@ impl ReadApi {
             state,
             transaction_kv_store,
             metrics,
+            object_cache: Arc::new(ImmutableCache::new(*READ_CACHE_SIZE)),
+            protocol_config_cache: Arc::new(ImmutableCache::new(*READ_CACHE_SIZE)),
         }
     }
 
@@ -139,6 +148,81 @@ impl ReadApi {
         })
     }
 
+    async fn get_checkpoint_inclusion_proof_internal(
+        &self,
+        digest: TransactionDigest,
+    ) -> Result<CheckpointInclusionProof, Error> {
+        let sequence_number = self
+            .transaction_kv_store
+            .multi_get_transaction_checkpoint(&[digest])
+            .await?
+            .into_iter()
+            .next()
+            .flatten()
+            .ok_or_else(|| {
+                SuiRpcInputError::GenericNotFound(format!(
+                    "Transaction {digest:?} is not included in a checkpoint yet"
+                ))
+            })?;
+        let summary = self
+            .transaction_kv_store
+            .get_checkpoint_summary(sequence_number)
+            .await?;
+        if summary.contents_merkle_root().is_none() {
+            return Err(SuiRpcInputError::GenericNotFound(format!(
+                "Checkpoint {sequence_number} does not commit to a Merkle root of its contents"
+            ))
+            .into());
+        }
+        let contents = self
+            .transaction_kv_store
+            .get_checkpoint_contents_by_digest(summary.content_digest)
+            .await?;
+        contents
+            .inclusion_proof(sequence_number, &digest)
+            .ok_or_else(|| {
+                Error::UnexpectedError(format!(
+                    "Transaction {digest:?} is not in the contents of checkpoint {sequence_number}"
+                ))
+            })
+    }
+
+    fn get_epoch_state_commitment_internal(
+        &self,
+        epoch: EpochId,
+    ) -> Result<EpochStateCommitment, Error> {
+        let EpochStateCommitmentResponse {
+            checkpoint,
+            root_state_digest,
+        } = self.state.get_epoch_state_commitment(epoch)?;
+        let checkpoint = checkpoint.ok_or_else(|| {
+            SuiRpcInputError::GenericNotFound(format!("Epoch {epoch} has not ended yet"))
+        })?;
+        let live_object_set_digest =
+            checkpoint
+                .live_object_set_digest()
+                .cloned()
+                .ok_or_else(|| {
+                    SuiRpcInputError::GenericNotFound(format!(
+                        "Epoch {epoch} does not commit to its live object set"
+                    ))
+                })?;
+        // Don't serve a commitment that the state of this node disagrees with.
+        if let Some(root_state_digest) = root_state_digest {
+            if root_state_digest != live_object_set_digest {
+                return Err(Error::UnexpectedError(format!(
+                    "Live object set digest {:?} of this node at the end of epoch {epoch} differs from the committed {:?}",
+                    root_state_digest.digest, live_object_set_digest.digest
+                )));
+            }
+        }
+        Ok(EpochStateCommitment {
+            epoch,
+            sequence_number: checkpoint.sequence_number,
+            live_object_set_digest,
+        })
+    }
+
     pub async fn get_checkpoints_internal(
         state: Arc<dyn StateRead>,
         transaction_kv_store: Arc<TransactionKeyValueStore>,
@@ -486,6 +570,12 @@ impl ReadApiServer for ReadApi {
         options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiObjectResponse> {
         with_tracing!(async move {
+            let options = options.unwrap_or_default();
+            let cache_key = (!options.show_display).then(|| (object_id, options.clone()));
+            if let Some(response) = cache_key.as_ref().and_then(|k| self.object_cache.get(k)) {
+                return Ok(response);
+            }
+
             let state = self.state.clone();
             let object_read = spawn_monitored_task!(async move {
                 state.get_object_read(&object_id).map_err(|e| {
@@ -495,7 +585,6 @@ impl ReadApiServer for ReadApi {
             })
             .await
             .map_err(Error::from)??;
-            let options = options.unwrap_or_default();
 
             match object_read {
                 ObjectRead::NotExists(id) => Ok(SuiObjectResponse::new_with_error(
@@ -518,9 +607,14 @@ impl ReadApiServer for ReadApi {
                             }
                         }
                     }
-                    Ok(SuiObjectResponse::new_with_data(
+                    let frozen = o.is_immutable() && !o.is_package();
+                    let response = SuiObjectResponse::new_with_data(
                         (object_ref, o, layout, options, display_fields).try_into()?,
-                    ))
+                    );
+                    if let Some(cache_key) = cache_key.filter(|_| frozen) {
+                        self.object_cache.insert(cache_key, response.clone());
+                    }
+                    Ok(response)
                 }
                 ObjectRead::Deleted((object_id, version, digest)) => Ok(
                     SuiObjectResponse::new_with_error(SuiObjectResponseError::Deleted {
@@ -926,6 +1020,22 @@ impl ReadApiServer for ReadApi {
         with_tracing!(self.get_checkpoint_internal(id))
     }
 
+    #[instrument(skip(self))]
+    async fn get_checkpoint_inclusion_proof(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<CheckpointInclusionProof> {
+        with_tracing!(self.get_checkpoint_inclusion_proof_internal(digest))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_epoch_state_commitment(
+        &self,
+        epoch: BigInt<u64>,
+    ) -> RpcResult<EpochStateCommitment> {
+        with_tracing!(async move { self.get_epoch_state_commitment_internal(*epoch) })
+    }
+
     #[instrument(skip(self))]
     async fn get_checkpoints(
         &self,
@@ -1024,7 +1134,13 @@ impl ReadApiServer for ReadApi {
         version: Option<BigInt<u64>>,
     ) -> RpcResult<ProtocolConfigResponse> {
         with_tracing!(async move {
-            version
+            let epoch_store = self.state.load_epoch_store_one_call_per_task();
+            let cache_key = version.map_or_else(|| epoch_store.protocol_version(), |v| (*v).into());
+            if let Some(response) = self.protocol_config_cache.get(&cache_key) {
+                return Ok(response);
+            }
+
+            let response = version
                 .map(|v| {
                     ProtocolConfig::get_for_version_if_supported(
                         (*v).into(),
@@ -1036,12 +1152,11 @@ impl ReadApiServer for ReadApi {
                     ))
                     .map_err(Error::from)
                 })
-                .unwrap_or(Ok(self
-                    .state
-                    .load_epoch_store_one_call_per_task()
-                    .protocol_config()
-                    .clone()))
-                .map(ProtocolConfigResponse::from)
+                .unwrap_or(Ok(epoch_store.protocol_config().clone()))
+                .map(ProtocolConfigResponse::from)?;
+            self.protocol_config_cache
+                .insert(cache_key, response.clone());
+            Ok(response)
         })
     }
 
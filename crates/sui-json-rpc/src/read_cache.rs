@@ -0,0 +1,66 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-memory caches for hot read endpoints.
+//!
+//! Only data that never changes is cached, such as a package at a given version, a frozen object
+//! or the protocol config of a given version, in an [`ImmutableCache`] bounded by size alone.
+//!
+//! Owned and shared objects are deliberately not cached: a fullnode applies the transactions it
+//! executes locally before their checkpoint, and a client must see its own writes.
+
+use std::hash::Hash;
+use std::sync::Mutex;
+
+use cached::{Cached, SizedCache};
+use once_cell::sync::Lazy;
+use sui_json_rpc_api::read_size_from_env;
+
+const RPC_READ_CACHE_SIZE: &str = "RPC_READ_CACHE_SIZE";
+const DEFAULT_RPC_READ_CACHE_SIZE: usize = 10000;
+
+/// Maximum number of entries of each read cache.
+pub static READ_CACHE_SIZE: Lazy<usize> = Lazy::new(|| {
+    read_size_from_env(RPC_READ_CACHE_SIZE)
+        .unwrap_or(DEFAULT_RPC_READ_CACHE_SIZE)
+        .max(1)
+});
+
+/// A cache of values that never change once read, evicting the least recently used entries.
+pub(crate) struct ImmutableCache<K, V> {
+    inner: Mutex<SizedCache<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> ImmutableCache<K, V> {
+    pub fn new(size: usize) -> Self {
+        Self {
+            inner: Mutex::new(SizedCache::with_size(size)),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.inner.lock().unwrap().cache_get(key).cloned()
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.inner.lock().unwrap().cache_set(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_immutable_cache_evicts_least_recently_used() {
+        let cache = ImmutableCache::new(2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        assert_eq!(cache.get(&1), Some("a"));
+        cache.insert(3, "c");
+
+        assert_eq!(cache.get(&1), Some("a"));
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&3), Some("c"));
+    }
+}
@@ -7,17 +7,18 @@ use async_trait::async_trait;
 use fastcrypto::encoding::Base64;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::RpcModule;
-use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::{StructTag, TypeTag};
 
 use sui_core::authority::AuthorityState;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_api::{TransactionBuilderOpenRpc, TransactionBuilderServer};
-use sui_json_rpc_types::{RPCTransactionRequestParams, SuiObjectDataFilter};
+use sui_json_rpc_types::{CoinPage, RPCTransactionRequestParams, SuiObjectDataFilter};
 use sui_json_rpc_types::{
     SuiObjectDataOptions, SuiObjectResponse, SuiTransactionBlockBuilderMode, SuiTypeTag,
     TransactionBlockBytes,
 };
 use sui_open_rpc::Module;
+use sui_protocol_config::ProtocolConfig;
 use sui_transaction_builder::{DataReader, TransactionBuilder};
 use sui_types::base_types::ObjectInfo;
 use sui_types::base_types::{ObjectID, SuiAddress};
@@ -73,10 +74,36 @@ impl DataReader for AuthorityStateDataReader {
         Ok((result, options).try_into()?)
     }
 
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: TypeTag,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<CoinPage, anyhow::Error> {
+        let cursor = (coin_type.to_string(), cursor.unwrap_or(ObjectID::ZERO));
+        let mut data =
+            self.0
+                .get_owned_coins(owner, cursor, limit + 1, /* one_coin_type_only */ true)?;
+        let has_next_page = data.len() > limit;
+        data.truncate(limit);
+        let next_cursor = data.last().map(|coin| coin.coin_object_id);
+        Ok(CoinPage {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn get_reference_gas_price(&self) -> Result<u64, anyhow::Error> {
         let epoch_store = self.0.load_epoch_store_one_call_per_task();
         Ok(epoch_store.reference_gas_price())
     }
+
+    async fn get_protocol_config(&self) -> Result<ProtocolConfig, anyhow::Error> {
+        let epoch_store = self.0.load_epoch_store_one_call_per_task();
+        Ok(epoch_store.protocol_config().clone())
+    }
 }
 
 #[async_trait]
@@ -4,21 +4,27 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use anyhow::anyhow;
 use async_trait::async_trait;
 use fastcrypto::encoding::Base64;
 use fastcrypto::traits::ToFromBytes;
+use futures::stream;
 use jsonrpsee::core::RpcResult;
-use jsonrpsee::RpcModule;
+use jsonrpsee::types::SubscriptionResult;
+use jsonrpsee::{RpcModule, SubscriptionSink};
 
 use mysten_metrics::spawn_monitored_task;
 use shared_crypto::intent::{AppId, Intent, IntentMessage, IntentScope, IntentVersion};
 use sui_core::authority::AuthorityState;
 use sui_core::authority_client::NetworkAuthorityClient;
-use sui_core::transaction_orchestrator::TransactiondOrchestrator;
-use sui_json_rpc_api::{JsonRpcMetrics, WriteApiOpenRpc, WriteApiServer};
+use sui_core::transaction_orchestrator::{TransactionExecutionStage, TransactiondOrchestrator};
+use sui_json_rpc_api::{
+    JsonRpcMetrics, WriteApiOpenRpc, WriteApiServer, MAX_TRANSACTIONS_PER_SUBMISSION,
+};
 use sui_json_rpc_types::{
-    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SuiTransactionBlock,
-    SuiTransactionBlockEvents, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+    DevInspectArgs, DevInspectResults, DryRunTransactionBlockResponse, SignedTransactionBlockBytes,
+    SuiTransactionBlock, SuiTransactionBlockEvents, SuiTransactionBlockResponse,
+    SuiTransactionBlockResponseOptions, TransactionBlockStatus, TransactionBlockStatusUpdate,
 };
 use sui_open_rpc::Module;
 use sui_types::base_types::SuiAddress;
@@ -33,19 +39,25 @@ use sui_types::sui_serde::BigInt;
 use sui_types::transaction::{
     InputObjectKind, Transaction, TransactionData, TransactionDataAPI, TransactionKind,
 };
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
 use tracing::instrument;
 
 use crate::authority_state::StateRead;
 use crate::error::{Error, SuiRpcInputError};
+use crate::indexer_api::spawn_subscription;
 use crate::{
     get_balance_changes_from_effect, get_object_changes, with_tracing, ObjectProviderCache,
     SuiRpcModule,
 };
 
+/// The maximum number of batches of transactions being executed at once.
+const MAX_CONCURRENT_SUBMISSIONS: usize = 100;
+
 pub struct TransactionExecutionApi {
     state: Arc<dyn StateRead>,
     transaction_orchestrator: Arc<TransactiondOrchestrator<NetworkAuthorityClient>>,
     metrics: Arc<JsonRpcMetrics>,
+    submission_semaphore: Arc<Semaphore>,
 }
 
 impl TransactionExecutionApi {
@@ -58,6 +70,7 @@ impl TransactionExecutionApi {
             state,
             transaction_orchestrator,
             metrics,
+            submission_semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_SUBMISSIONS)),
         }
     }
 
@@ -69,6 +82,19 @@ impl TransactionExecutionApi {
         Ok(data)
     }
 
+    fn convert_transaction(
+        &self,
+        tx_bytes: Base64,
+        signatures: Vec<Base64>,
+    ) -> Result<Transaction, SuiRpcInputError> {
+        let tx_data: TransactionData = self.convert_bytes(tx_bytes)?;
+        let mut sigs = Vec::new();
+        for sig in signatures {
+            sigs.push(GenericSignature::from_bytes(&sig.to_vec()?)?);
+        }
+        Ok(Transaction::from_generic_sig_data(tx_data, sigs))
+    }
+
     #[allow(clippy::type_complexity)]
     fn prepare_execute_transaction_block(
         &self,
@@ -95,15 +121,11 @@ impl TransactionExecutionApi {
             }
             (t, _) => t.unwrap_or_else(|| opts.default_execution_request_type()),
         };
-        let tx_data: TransactionData = self.convert_bytes(tx_bytes)?;
+        let txn = self.convert_transaction(tx_bytes, signatures)?;
+        let tx_data = txn.data().transaction_data();
         let sender = tx_data.sender();
         let input_objs = tx_data.input_objects().unwrap_or_default();
 
-        let mut sigs = Vec::new();
-        for sig in signatures {
-            sigs.push(GenericSignature::from_bytes(&sig.to_vec()?)?);
-        }
-        let txn = Transaction::from_generic_sig_data(tx_data, sigs);
         let raw_transaction = if opts.show_raw_input {
             bcs::to_bytes(txn.data())?
         } else {
@@ -215,6 +237,13 @@ impl TransactionExecutionApi {
         })
     }
 
+    fn acquire_submission_permit(&self) -> anyhow::Result<OwnedSemaphorePermit> {
+        self.submission_semaphore
+            .clone()
+            .try_acquire_owned()
+            .map_err(|_| anyhow!("Resources exhausted"))
+    }
+
     pub fn prepare_dry_run_transaction_block(
         &self,
         tx_bytes: Base64,
@@ -287,6 +316,41 @@ impl WriteApiServer for TransactionExecutionApi {
         })
     }
 
+    fn subscribe_execute_transaction_blocks(
+        &self,
+        sink: SubscriptionSink,
+        transactions: Vec<SignedTransactionBlockBytes>,
+    ) -> SubscriptionResult {
+        if transactions.len() > MAX_TRANSACTIONS_PER_SUBMISSION {
+            return Err(anyhow!(
+                "At most {MAX_TRANSACTIONS_PER_SUBMISSION} transactions can be submitted at once, \
+                 got {}",
+                transactions.len()
+            )
+            .into());
+        }
+        let permit = self.acquire_submission_permit()?;
+        let (updates, mut receiver) = mpsc::unbounded_channel();
+        for (index, transaction) in transactions.into_iter().enumerate() {
+            let transaction =
+                self.convert_transaction(transaction.tx_bytes, transaction.signatures);
+            spawn_monitored_task!(execute_with_status_updates(
+                self.transaction_orchestrator.clone(),
+                index,
+                transaction,
+                updates.clone(),
+            ));
+        }
+        // The stream ends once every transaction has sent its last update.
+        drop(updates);
+        spawn_subscription(
+            sink,
+            stream::poll_fn(move |cx| receiver.poll_recv(cx)),
+            Some(permit),
+        );
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     async fn dev_inspect_transaction_block(
         &self,
@@ -320,6 +384,30 @@ impl WriteApiServer for TransactionExecutionApi {
         })
     }
 
+    #[instrument(skip(self))]
+    async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender_address: SuiAddress,
+        tx_bytes: Base64,
+        checkpoint: BigInt<u64>,
+        gas_price: Option<BigInt<u64>>,
+        gas_budget: Option<BigInt<u64>>,
+    ) -> RpcResult<DevInspectResults> {
+        with_tracing!(async move {
+            let tx_kind: TransactionKind = self.convert_bytes(tx_bytes)?;
+            self.state
+                .dev_inspect_transaction_block_at_checkpoint(
+                    sender_address,
+                    tx_kind,
+                    *checkpoint,
+                    gas_price.map(|i| *i),
+                    gas_budget.map(|i| *i),
+                )
+                .await
+                .map_err(Error::from)
+        })
+    }
+
     #[instrument(skip(self))]
     async fn dry_run_transaction_block(
         &self,
@@ -329,6 +417,84 @@ impl WriteApiServer for TransactionExecutionApi {
     }
 }
 
+/// Executes the `index`th transaction of a batch until it is executed locally, and sends the
+/// updates of its status to `updates`.
+async fn execute_with_status_updates(
+    transaction_orchestrator: Arc<TransactiondOrchestrator<NetworkAuthorityClient>>,
+    index: usize,
+    transaction: Result<Transaction, SuiRpcInputError>,
+    updates: mpsc::UnboundedSender<TransactionBlockStatusUpdate>,
+) {
+    let send_update = |digest, status| {
+        // The subscriber may have disconnected, the transaction is executed regardless.
+        let _ = updates.send(TransactionBlockStatusUpdate {
+            index,
+            digest,
+            status,
+        });
+    };
+    let transaction = match transaction {
+        Ok(transaction) => transaction,
+        Err(err) => {
+            send_update(
+                None,
+                TransactionBlockStatus::Failed {
+                    error: err.to_string(),
+                },
+            );
+            return;
+        }
+    };
+    let digest = Some(*transaction.digest());
+
+    let (stages_sender, mut stages) = mpsc::unbounded_channel();
+    let execution = transaction_orchestrator.execute_transaction_block_with_stages(
+        ExecuteTransactionRequest {
+            transaction,
+            request_type: ExecuteTransactionRequestType::WaitForLocalExecution,
+        },
+        Some(stages_sender),
+    );
+    tokio::pin!(execution);
+    let send_stage = |stage| {
+        send_update(
+            digest,
+            match stage {
+                TransactionExecutionStage::Accepted => TransactionBlockStatus::Accepted,
+                TransactionExecutionStage::Certified(effects_digest) => {
+                    TransactionBlockStatus::Certified { effects_digest }
+                }
+            },
+        )
+    };
+    let result = loop {
+        tokio::select! {
+            result = &mut execution => break result,
+            Some(stage) = stages.recv() => send_stage(stage),
+        }
+    };
+    // The stages reached just before the execution finished.
+    while let Ok(stage) = stages.try_recv() {
+        send_stage(stage);
+    }
+
+    send_update(
+        digest,
+        match result {
+            Ok(ExecuteTransactionResponse::EffectsCert(cert)) => {
+                let (effects, _, is_executed_locally) = *cert;
+                TransactionBlockStatus::Executed {
+                    execution_status: effects.effects.status().clone().into(),
+                    confirmed_local_execution: is_executed_locally,
+                }
+            }
+            Err(err) => TransactionBlockStatus::Failed {
+                error: err.to_string(),
+            },
+        },
+    );
+}
+
 impl SuiRpcModule for TransactionExecutionApi {
     fn rpc(self) -> RpcModule<Self> {
         self.into_rpc()
@@ -821,20 +821,6 @@ pub fn is_receiving_argument(view: &BinaryIndexedView, arg_type: &SignatureToken
     )
 }
 
-fn resolve_call_args(
-    view: &BinaryIndexedView,
-    type_args: &[TypeTag],
-    json_args: &[SuiJsonValue],
-    parameter_types: &[SignatureToken],
-) -> Result<Vec<ResolvedCallArg>, anyhow::Error> {
-    json_args
-        .iter()
-        .zip(parameter_types)
-        .enumerate()
-        .map(|(idx, (arg, param))| resolve_call_arg(view, type_args, idx, arg, param))
-        .collect()
-}
-
 /// Resolve the JSON args of a function into the expected formats to make them usable by Move call
 /// This is because we have special types which we need to specify in other formats
 pub fn resolve_move_function_args(
@@ -844,6 +830,25 @@ pub fn resolve_move_function_args(
     type_args: &[TypeTag],
     combined_args_json: Vec<SuiJsonValue>,
 ) -> Result<Vec<(ResolvedCallArg, SignatureToken)>, anyhow::Error> {
+    let args = combined_args_json.into_iter().map(Some).collect();
+    Ok(
+        resolve_partial_move_function_args(package, module_ident, function, type_args, args)?
+            .into_iter()
+            .map(|(arg, expected_type)| (arg.expect("all args are provided"), expected_type))
+            .collect(),
+    )
+}
+
+/// Like [resolve_move_function_args], for calls where some of the args are not JSON values, e.g.
+/// the results of previous commands of a programmable transaction. Those are passed as `None`
+/// and only their position is checked.
+pub fn resolve_partial_move_function_args(
+    package: &MovePackage,
+    module_ident: Identifier,
+    function: Identifier,
+    type_args: &[TypeTag],
+    combined_args_json: Vec<Option<SuiJsonValue>>,
+) -> Result<Vec<(Option<ResolvedCallArg>, SignatureToken)>, anyhow::Error> {
     // Extract the expected function signature
     let module = package.deserialize_module(&module_ident, VERSION_MAX, true)?;
     let function_str = function.as_ident_str();
@@ -878,13 +883,18 @@ pub fn resolve_move_function_args(
         );
     }
     // Check that the args are valid and convert to the correct format
-    let call_args = resolve_call_args(&view, type_args, &combined_args_json, parameters)?;
-    let tupled_call_args = call_args
-        .into_iter()
-        .zip(parameters.iter())
-        .map(|(arg, expected_type)| (arg, expected_type.clone()))
-        .collect::<Vec<_>>();
-    Ok(tupled_call_args)
+    combined_args_json
+        .iter()
+        .zip(parameters)
+        .enumerate()
+        .map(|(idx, (arg, param))| {
+            let arg = arg
+                .as_ref()
+                .map(|arg| resolve_call_arg(&view, type_args, idx, arg, param))
+                .transpose()?;
+            Ok((arg, param.clone()))
+        })
+        .collect()
 }
 
 fn convert_string_to_u256(s: &str) -> Result<U256, anyhow::Error> {
@@ -0,0 +1,100 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context};
+use serde::{Deserialize, Serialize};
+use sui_types::base_types::SuiAddress;
+
+use crate::keystore::validate_alias;
+
+const ADDRESS_BOOK_EXTENSION: &str = "address_book";
+
+/// Named addresses the wallet does not hold keys for, e.g. frequently used counterparties.
+/// The address book of a file based keystore is stored next to it, with the `address_book`
+/// extension.
+#[derive(Default, Serialize, Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct AddressBook {
+    entries: BTreeMap<String, SuiAddress>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl AddressBook {
+    /// Load the address book of the keystore at `keystore_path`, or an empty one if it does
+    /// not exist yet.
+    pub fn load(keystore_path: &Path) -> Result<Self, anyhow::Error> {
+        let path = keystore_path.with_extension(ADDRESS_BOOK_EXTENSION);
+        let entries = if path.exists() {
+            let content = fs::read_to_string(&path).with_context(|| {
+                format!("Cannot open the address book file: {}", path.display())
+            })?;
+            serde_json::from_str(&content).with_context(|| {
+                format!(
+                    "Cannot deserialize the address book file: {}",
+                    path.display()
+                )
+            })?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            entries,
+            path: Some(path),
+        })
+    }
+
+    pub(crate) fn set_keystore_path(&mut self, keystore_path: &Path) {
+        self.path = Some(keystore_path.with_extension(ADDRESS_BOOK_EXTENSION));
+    }
+
+    pub fn save(&self) -> Result<(), anyhow::Error> {
+        if let Some(path) = &self.path {
+            let content = serde_json::to_string_pretty(&self.entries).with_context(|| {
+                format!(
+                    "Cannot serialize the address book to file: {}",
+                    path.display()
+                )
+            })?;
+            fs::write(path, content)?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SuiAddress> {
+        self.entries.get(name)
+    }
+
+    pub fn entries(&self) -> &BTreeMap<String, SuiAddress> {
+        &self.entries
+    }
+
+    pub fn add(&mut self, name: &str, address: SuiAddress) -> Result<(), anyhow::Error> {
+        let name = validate_alias(name)?;
+        if self.entries.contains_key(&name) {
+            bail!("Address book entry {name} already exists");
+        }
+        self.entries.insert(name, address);
+        Ok(())
+    }
+
+    pub fn rename(&mut self, old_name: &str, new_name: &str) -> Result<(), anyhow::Error> {
+        let new_name = validate_alias(new_name)?;
+        if self.entries.contains_key(&new_name) {
+            bail!("Address book entry {new_name} already exists");
+        }
+        let address = self.remove(old_name)?;
+        self.entries.insert(new_name, address);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, name: &str) -> Result<SuiAddress, anyhow::Error> {
+        self.entries
+            .remove(name)
+            .ok_or_else(|| anyhow!("Address book entry {name} does not exist"))
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, bail};
+use bech32::{FromBase32, ToBase32, Variant};
+use sui_types::crypto::SuiKeyPair;
+
+/// The human readable part of a Bech32 encoded private key.
+pub const SUI_PRIV_KEY_PREFIX: &str = "suiprivkey";
+
+/// Encode a keypair as `flag || privkey` in Bech32, with the human readable part
+/// [SUI_PRIV_KEY_PREFIX].
+pub fn encode_private_key(keypair: &SuiKeyPair) -> anyhow::Result<String> {
+    bech32::encode(
+        SUI_PRIV_KEY_PREFIX,
+        keypair.to_bytes().to_base32(),
+        Variant::Bech32,
+    )
+    .map_err(|e| anyhow!("Cannot encode private key: {e}"))
+}
+
+/// Decode a keypair from a Bech32 string produced by [encode_private_key].
+pub fn decode_private_key(value: &str) -> anyhow::Result<SuiKeyPair> {
+    let (hrp, data, variant) =
+        bech32::decode(value).map_err(|e| anyhow!("Invalid Bech32 private key: {e}"))?;
+    if hrp != SUI_PRIV_KEY_PREFIX || variant != Variant::Bech32 {
+        bail!("Invalid private key prefix or Bech32 variant");
+    }
+    let bytes =
+        Vec::<u8>::from_base32(&data).map_err(|e| anyhow!("Invalid Bech32 private key: {e}"))?;
+    SuiKeyPair::from_bytes(&bytes).map_err(|e| anyhow!("{e}"))
+}
@@ -1,7 +1,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::key_derive::{derive_key_pair_from_path, generate_new_key};
+use crate::address_book::AddressBook;
+use crate::key_derive::{derive_key_pair_from_path, generate_new_key, validate_path};
 use crate::random_names::{random_name, random_names};
 use anyhow::{anyhow, bail, ensure, Context};
 use bip32::DerivationPath;
@@ -31,7 +32,16 @@ pub enum Keystore {
 }
 #[enum_dispatch]
 pub trait AccountKeystore: Send + Sync {
-    fn add_key(&mut self, alias: Option<String>, keypair: SuiKeyPair) -> Result<(), anyhow::Error>;
+    fn add_key(&mut self, alias: Option<String>, keypair: SuiKeyPair) -> Result<(), anyhow::Error> {
+        self.add_derived_key(alias, keypair, None)
+    }
+    /// Adds a key, recording the path it was derived at from its mnemonic if known.
+    fn add_derived_key(
+        &mut self,
+        alias: Option<String>,
+        keypair: SuiKeyPair,
+        derivation_path: Option<DerivationPath>,
+    ) -> Result<(), anyhow::Error>;
     fn keys(&self) -> Vec<PublicKey>;
     fn get_key(&self, address: &SuiAddress) -> Result<&SuiKeyPair, anyhow::Error>;
 
@@ -51,6 +61,9 @@ pub trait AccountKeystore: Send + Sync {
     fn addresses_with_alias(&self) -> Vec<(&SuiAddress, &Alias)>;
     fn aliases(&self) -> Vec<&Alias>;
     fn aliases_mut(&mut self) -> Vec<&mut Alias>;
+    /// Named addresses of counterparties, which can be used like the aliases of the keys.
+    fn address_book(&self) -> &AddressBook;
+    fn address_book_mut(&mut self) -> &mut AddressBook;
     fn alias_names(&self) -> Vec<&str> {
         self.aliases()
             .into_iter()
@@ -60,6 +73,13 @@ pub trait AccountKeystore: Send + Sync {
     /// Get alias of address
     fn get_alias_by_address(&self, address: &SuiAddress) -> Result<String, anyhow::Error>;
     fn get_address_by_alias(&self, alias: String) -> Result<&SuiAddress, anyhow::Error>;
+    /// Get the derivation path of the key of an address, if it was derived from a mnemonic
+    fn get_derivation_path(&self, address: &SuiAddress) -> Option<String> {
+        self.addresses_with_alias()
+            .into_iter()
+            .find(|(a, _)| *a == address)
+            .and_then(|(_, alias)| alias.derivation_path.clone())
+    }
     /// Check if an alias exists by its name
     fn alias_exists(&self, alias: &str) -> bool {
         self.alias_names().contains(&alias)
@@ -94,11 +114,7 @@ pub trait AccountKeystore: Send + Sync {
         };
         for a in self.aliases_mut() {
             if a.alias == old_alias {
-                let pk = &a.public_key_base64;
-                *a = Alias {
-                    alias: new_alias_name.clone(),
-                    public_key_base64: pk.clone(),
-                };
+                a.alias = new_alias_name.clone();
             }
         }
         Ok(new_alias_name)
@@ -111,9 +127,11 @@ pub trait AccountKeystore: Send + Sync {
         derivation_path: Option<DerivationPath>,
         word_length: Option<String>,
     ) -> Result<(SuiAddress, String, SignatureScheme), anyhow::Error> {
+        let path = validate_path(&key_scheme, derivation_path)
+            .map_err(|e| anyhow!("Failed to generate keypair: {:?}", e))?;
         let (address, kp, scheme, phrase) =
-            generate_new_key(key_scheme, derivation_path, word_length)?;
-        self.add_key(alias, kp)?;
+            generate_new_key(key_scheme, Some(path.clone()), word_length)?;
+        self.add_derived_key(alias, kp, Some(path))?;
         Ok((address, phrase, scheme))
     }
 
@@ -122,13 +140,16 @@ pub trait AccountKeystore: Send + Sync {
         phrase: &str,
         key_scheme: SignatureScheme,
         derivation_path: Option<DerivationPath>,
+        alias: Option<String>,
     ) -> Result<SuiAddress, anyhow::Error> {
         let mnemonic = Mnemonic::from_phrase(phrase, Language::English)
             .map_err(|e| anyhow::anyhow!("Invalid mnemonic phrase: {:?}", e))?;
         let seed = Seed::new(&mnemonic, "");
-        match derive_key_pair_from_path(seed.as_bytes(), derivation_path, &key_scheme) {
+        let path = validate_path(&key_scheme, derivation_path)
+            .map_err(|e| anyhow!("error getting keypair {:?}", e))?;
+        match derive_key_pair_from_path(seed.as_bytes(), Some(path.clone()), &key_scheme) {
             Ok((address, kp)) => {
-                self.add_key(None, kp)?;
+                self.add_derived_key(alias, kp, Some(path))?;
                 Ok(address)
             }
             Err(e) => Err(anyhow!("error getting keypair {:?}", e)),
@@ -157,12 +178,15 @@ impl Display for Keystore {
 pub struct Alias {
     pub alias: String,
     pub public_key_base64: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub derivation_path: Option<String>,
 }
 
 #[derive(Default)]
 pub struct FileBasedKeystore {
     keys: BTreeMap<SuiAddress, SuiKeyPair>,
     aliases: BTreeMap<SuiAddress, Alias>,
+    address_book: AddressBook,
     path: Option<PathBuf>,
 }
 
@@ -218,7 +242,12 @@ impl AccountKeystore for FileBasedKeystore {
         ))
     }
 
-    fn add_key(&mut self, alias: Option<String>, keypair: SuiKeyPair) -> Result<(), anyhow::Error> {
+    fn add_derived_key(
+        &mut self,
+        alias: Option<String>,
+        keypair: SuiKeyPair,
+        derivation_path: Option<DerivationPath>,
+    ) -> Result<(), anyhow::Error> {
         let address: SuiAddress = (&keypair.public()).into();
         let alias = self.create_alias(alias)?;
         self.aliases.insert(
@@ -226,6 +255,7 @@ impl AccountKeystore for FileBasedKeystore {
             Alias {
                 alias,
                 public_key_base64: EncodeDecodeBase64::encode_base64(&keypair.public()),
+                derivation_path: derivation_path.map(|p| p.to_string()),
             },
         );
         self.keys.insert(address, keypair);
@@ -247,6 +277,14 @@ impl AccountKeystore for FileBasedKeystore {
         self.aliases.values_mut().collect()
     }
 
+    fn address_book(&self) -> &AddressBook {
+        &self.address_book
+    }
+
+    fn address_book_mut(&mut self) -> &mut AddressBook {
+        &mut self.address_book
+    }
+
     fn keys(&self) -> Vec<PublicKey> {
         self.keys.values().map(|key| key.public()).collect()
     }
@@ -377,6 +415,7 @@ impl FileBasedKeystore {
                         Alias {
                             alias,
                             public_key_base64,
+                            derivation_path: None,
                         },
                     )
                 })
@@ -395,12 +434,18 @@ impl FileBasedKeystore {
         Ok(Self {
             keys,
             aliases,
+            address_book: AddressBook::load(path)?,
             path: Some(path.to_path_buf()),
         })
     }
 
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         self.path = Some(path.to_path_buf());
+        self.address_book.set_keystore_path(path);
     }
 
     pub fn save_aliases(&self) -> Result<(), anyhow::Error> {
@@ -451,6 +496,8 @@ impl FileBasedKeystore {
 pub struct InMemKeystore {
     aliases: BTreeMap<SuiAddress, Alias>,
     keys: BTreeMap<SuiAddress, SuiKeyPair>,
+    #[serde(default)]
+    address_book: AddressBook,
 }
 
 impl AccountKeystore for InMemKeystore {
@@ -479,7 +526,12 @@ impl AccountKeystore for InMemKeystore {
         ))
     }
 
-    fn add_key(&mut self, alias: Option<String>, keypair: SuiKeyPair) -> Result<(), anyhow::Error> {
+    fn add_derived_key(
+        &mut self,
+        alias: Option<String>,
+        keypair: SuiKeyPair,
+        derivation_path: Option<DerivationPath>,
+    ) -> Result<(), anyhow::Error> {
         let address: SuiAddress = (&keypair.public()).into();
         let alias = alias.unwrap_or_else(|| {
             random_name(
@@ -495,6 +547,7 @@ impl AccountKeystore for InMemKeystore {
         let alias = Alias {
             alias,
             public_key_base64,
+            derivation_path: derivation_path.map(|p| p.to_string()),
         };
         self.aliases.insert(address, alias);
         self.keys.insert(address, keypair);
@@ -561,6 +614,14 @@ impl AccountKeystore for InMemKeystore {
         self.aliases.values_mut().collect()
     }
 
+    fn address_book(&self) -> &AddressBook {
+        &self.address_book
+    }
+
+    fn address_book_mut(&mut self) -> &mut AddressBook {
+        &mut self.address_book
+    }
+
     /// Updates an old alias to the new alias. If the new_alias is None,
     /// it will generate a new random alias.
     fn update_alias(
@@ -590,16 +651,21 @@ impl InMemKeystore {
                     Alias {
                         alias,
                         public_key_base64,
+                        derivation_path: None,
                     },
                 )
             })
             .collect::<BTreeMap<_, _>>();
 
-        Self { aliases, keys }
+        Self {
+            aliases,
+            keys,
+            address_book: AddressBook::default(),
+        }
     }
 }
 
-fn validate_alias(alias: &str) -> Result<String, anyhow::Error> {
+pub(crate) fn validate_alias(alias: &str) -> Result<String, anyhow::Error> {
     let re = Regex::new(r"^[A-Za-z][A-Za-z0-9-_\.]*$")
         .map_err(|_| anyhow!("Cannot build the regex needed to validate the alias naming"))?;
     let alias = alias.trim();
@@ -4,9 +4,11 @@
 use std::fs;
 use std::str::FromStr;
 
+use bech32::ToBase32;
 use fastcrypto::hash::HashFunction;
 use fastcrypto::traits::EncodeDecodeBase64;
 use sui_keys::key_derive::generate_new_key;
+use sui_keys::key_encoding::{decode_private_key, encode_private_key, SUI_PRIV_KEY_PREFIX};
 use tempfile::TempDir;
 
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, InMemKeystore, Keystore};
@@ -204,7 +206,7 @@ fn mnemonic_test() {
     let keystore_path_2 = temp_dir.path().join("sui2.keystore");
     let mut keystore2 = Keystore::from(FileBasedKeystore::new(&keystore_path_2).unwrap());
     let imported_address = keystore2
-        .import_from_mnemonic(&phrase, SignatureScheme::ED25519, None)
+        .import_from_mnemonic(&phrase, SignatureScheme::ED25519, None, None)
         .unwrap();
     assert_eq!(scheme.flag(), Ed25519SuiSignature::SCHEME.flag());
     assert_eq!(address, imported_address);
@@ -222,7 +224,7 @@ fn sui_wallet_address_mnemonic_test() -> Result<(), anyhow::Error> {
     let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
 
     keystore
-        .import_from_mnemonic(phrase, SignatureScheme::ED25519, None)
+        .import_from_mnemonic(phrase, SignatureScheme::ED25519, None, None)
         .unwrap();
 
     let pubkey = keystore.keys()[0].clone();
@@ -266,3 +268,53 @@ fn get_alias_by_address_test() {
     let address = generate_new_key(SignatureScheme::ED25519, None, None).unwrap();
     assert!(keystore.get_alias_by_address(&address.0).is_err())
 }
+
+#[test]
+fn derivation_path_persisted_test() {
+    let temp_dir = TempDir::new().unwrap();
+    let keystore_path = temp_dir.path().join("sui.keystore");
+    let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
+    let (derived, _, _) = keystore
+        .generate_and_add_new_key(
+            SignatureScheme::Secp256k1,
+            None,
+            Some("m/54'/784'/1'/0/0".parse().unwrap()),
+            None,
+        )
+        .unwrap();
+    let (_, imported, _, _) = generate_new_key(SignatureScheme::ED25519, None, None).unwrap();
+    let imported_address: SuiAddress = (&imported.public()).into();
+    keystore.add_key(None, imported).unwrap();
+
+    // The derivation path survives reloading the keystore from disk.
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
+    assert_eq!(
+        keystore.get_derivation_path(&derived).as_deref(),
+        Some("m/54'/784'/1'/0/0")
+    );
+    assert_eq!(keystore.get_derivation_path(&imported_address), None);
+}
+
+#[test]
+fn bech32_private_key_round_trip_test() {
+    for scheme in [
+        SignatureScheme::ED25519,
+        SignatureScheme::Secp256k1,
+        SignatureScheme::Secp256r1,
+    ] {
+        let (_, skp, _, _) = generate_new_key(scheme, None, None).unwrap();
+        let encoded = encode_private_key(&skp).unwrap();
+        assert!(encoded.starts_with(SUI_PRIV_KEY_PREFIX));
+        assert_eq!(decode_private_key(&encoded).unwrap(), skp);
+    }
+
+    // Wrong human readable part.
+    let (_, skp, _, _) = generate_new_key(SignatureScheme::ED25519, None, None).unwrap();
+    let encoded = bech32::encode(
+        "suipubkey",
+        skp.to_bytes().to_base32(),
+        bech32::Variant::Bech32,
+    )
+    .unwrap();
+    assert!(decode_private_key(&encoded).is_err());
+}
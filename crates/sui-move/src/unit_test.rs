@@ -136,6 +136,7 @@ fn new_testing_object_and_natives_cost_runtime(ext: &mut NativeContextExtensions
         Box::leak(Box::new(ProtocolConfig::get_for_max_version_UNSAFE())), // leak for testing
         metrics,
         0, // epoch id
+        1, // gas price
     ));
     ext.add(NativesCostTable::from_protocol_config(
         &ProtocolConfig::get_for_max_version_UNSAFE(),
@@ -94,6 +94,42 @@ fn main() -> Result<()> {
                 .codec_path(codec_path)
                 .build(),
         )
+        .method(
+            Method::builder()
+                .name("get_conflicting_transactions")
+                .route_name("GetConflictingTransactions")
+                .input_type("sui_types::messages_grpc::ConflictingTransactionsRequest")
+                .output_type("sui_types::messages_grpc::ConflictingTransactionsResponse")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("get_inclusion_gas_price")
+                .route_name("GetInclusionGasPrice")
+                .input_type("sui_types::messages_grpc::InclusionGasPriceRequest")
+                .output_type("sui_types::messages_grpc::InclusionGasPriceResponse")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("get_object_ownership_proof")
+                .route_name("GetObjectOwnershipProof")
+                .input_type("sui_types::messages_grpc::ObjectOwnershipProofRequest")
+                .output_type("sui_types::messages_grpc::ObjectOwnershipProofResponse")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("get_epoch_state_commitment")
+                .route_name("GetEpochStateCommitment")
+                .input_type("sui_types::messages_grpc::EpochStateCommitmentRequest")
+                .output_type("sui_types::messages_grpc::EpochStateCommitmentResponse")
+                .codec_path(codec_path)
+                .build(),
+        )
         .build();
 
     Builder::new()
@@ -11,6 +11,7 @@ use axum::{
 use humantime::parse_duration;
 use serde::Deserialize;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
 use sui_types::error::SuiError;
 use telemetry_subscribers::TracingHandle;
@@ -39,6 +40,12 @@ use tracing::info;
 //
 //   $ curl 'http://127.0.0.1:1337/node-config'
 //
+// View the frontier of the consensus DAG and the last committed rounds, to diagnose stalls. The
+// metrics `header_to_certificate_latency` (certificate creation latency), `leader_commits` (rate
+// of leader commits), `proposer_rounds_skipped` and `worker_batch_fetch_failures` complement it:
+//
+//   $ curl 'http://127.0.0.1:1337/consensus/status'
+//
 // Set a time-limited tracing config. After the duration expires, tracing will be disabled
 // automatically.
 //
@@ -47,6 +54,11 @@ use tracing::info;
 // Reset tracing to the TRACE_FILTER env var.
 //
 //   $ curl -X POST 'http://127.0.0.1:1337/reset-tracing'
+//
+// Reload the node config file, and report the changes that were applied and the ones that need
+// a restart:
+//
+//   $ curl -X POST 'http://127.0.0.1:1337/reload-config'
 
 const LOGGING_ROUTE: &str = "/logging";
 const TRACING_ROUTE: &str = "/enable-tracing";
@@ -56,24 +68,34 @@ const CLEAR_BUFFER_STAKE_ROUTE: &str = "/clear-override-buffer-stake";
 const FORCE_CLOSE_EPOCH: &str = "/force-close-epoch";
 const CAPABILITIES: &str = "/capabilities";
 const NODE_CONFIG: &str = "/node-config";
+const CONSENSUS_STATUS: &str = "/consensus/status";
+const RELOAD_CONFIG: &str = "/reload-config";
 
 struct AppState {
     node: Arc<SuiNode>,
     tracing_handle: TracingHandle,
+    config_path: PathBuf,
 }
 
-pub async fn run_admin_server(node: Arc<SuiNode>, port: u16, tracing_handle: TracingHandle) {
+pub async fn run_admin_server(
+    node: Arc<SuiNode>,
+    port: u16,
+    tracing_handle: TracingHandle,
+    config_path: PathBuf,
+) {
     let filter = tracing_handle.get_log().unwrap();
 
     let app_state = AppState {
         node,
         tracing_handle,
+        config_path,
     };
 
     let app = Router::new()
         .route(LOGGING_ROUTE, get(get_filter))
         .route(CAPABILITIES, get(capabilities))
         .route(NODE_CONFIG, get(node_config))
+        .route(CONSENSUS_STATUS, get(consensus_status))
         .route(LOGGING_ROUTE, post(set_filter))
         .route(
             SET_BUFFER_STAKE_ROUTE,
@@ -86,6 +108,7 @@ pub async fn run_admin_server(node: Arc<SuiNode>, port: u16, tracing_handle: Tra
         .route(FORCE_CLOSE_EPOCH, post(force_close_epoch))
         .route(TRACING_ROUTE, post(enable_tracing))
         .route(TRACING_RESET_ROUTE, post(reset_tracing))
+        .route(RELOAD_CONFIG, post(reload_config))
         .with_state(Arc::new(app_state));
 
     let socket_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
@@ -216,6 +239,27 @@ async fn node_config(State(state): State<Arc<AppState>>) -> (StatusCode, String)
     (StatusCode::OK, format!("{:#?}\n", node_config))
 }
 
+async fn reload_config(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    match state.node.reload_config(&state.config_path).await {
+        Ok(report) => (StatusCode::OK, report.to_string()),
+        Err(err) => (
+            StatusCode::BAD_REQUEST,
+            format!("config not reloaded: {:#}\n", err),
+        ),
+    }
+}
+
+async fn consensus_status(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    match state.node.consensus_status().await {
+        Ok(Some(status)) => (StatusCode::OK, format!("{:#?}\n", status)),
+        Ok(None) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "consensus status is not available\n".to_string(),
+        ),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
 #[derive(Deserialize)]
 struct Epoch {
     epoch: u64,
@@ -0,0 +1,189 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reloading of the node config while the node is running, on SIGHUP or from the admin server:
+//!
+//!   $ kill -HUP <pid>
+//!   $ curl -X POST 'http://127.0.0.1:1337/reload-config'
+//!
+//! The config file is read and validated again. Changes to `RELOADABLE_FIELDS` take effect
+//! immediately, while other changes are only reported and take effect at the next restart.
+//!
+//! Only the config file is reloaded. Settings outside of it are not, in particular:
+//! - the log filter, which is changed with the `/logging` admin route instead,
+//! - settings read from the environment, like `RPC_METRICS_MAX_CLIENT_LABELS`, as the
+//!   environment of a running process can't be changed.
+
+use crate::SuiNode;
+use anyhow::{ensure, Result};
+use std::path::Path;
+use sui_config::node::{AuthorityStorePruningConfig, OverloadThresholdConfig};
+use sui_config::{Config, NodeConfig};
+use tracing::info;
+
+/// Serialized paths of the fields that can be changed without a restart, including the fields
+/// nested in them.
+const RELOADABLE_FIELDS: &[&str] = &[
+    "authority-store-pruning-config.num-epochs-to-retain",
+    "authority-store-pruning-config.num-epochs-to-retain-for-checkpoints",
+    "authority-store-pruning-config.max-checkpoints-in-batch",
+    "authority-store-pruning-config.max-transactions-in-batch",
+    "authority-store-pruning-config.killswitch-tombstone-pruning",
+    "overload-threshold-config",
+    "indexer-max-subscriptions",
+];
+
+#[derive(Debug, Default)]
+pub struct ConfigReloadReport {
+    /// Changed fields that are now in effect.
+    pub applied: Vec<String>,
+    /// Changed fields that only take effect at the next restart.
+    pub requires_restart: Vec<String>,
+}
+
+impl std::fmt::Display for ConfigReloadReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.applied.is_empty() && self.requires_restart.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for field in &self.applied {
+            writeln!(f, "applied: {field}")?;
+        }
+        for field in &self.requires_restart {
+            writeln!(f, "requires restart: {field}")?;
+        }
+        Ok(())
+    }
+}
+
+fn is_reloadable(field: &str) -> bool {
+    RELOADABLE_FIELDS.iter().any(|reloadable| {
+        field
+            .strip_prefix(reloadable)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+fn validate_pruning_config(config: &AuthorityStorePruningConfig) -> Result<()> {
+    ensure!(
+        config.max_checkpoints_in_batch > 0,
+        "max-checkpoints-in-batch must be positive"
+    );
+    ensure!(
+        config.max_transactions_in_batch > 0,
+        "max-transactions-in-batch must be positive"
+    );
+    Ok(())
+}
+
+fn validate_overload_threshold_config(config: &OverloadThresholdConfig) -> Result<()> {
+    ensure!(
+        config.max_transactions_in_flight_per_sender > 0,
+        "max_transactions_in_flight_per_sender must be positive"
+    );
+    ensure!(
+        config.max_transactions_in_flight_per_client > 0,
+        "max_transactions_in_flight_per_client must be positive"
+    );
+    ensure!(
+        config.max_transaction_manager_queue_length_for_signing > 0,
+        "max_transaction_manager_queue_length_for_signing must be positive"
+    );
+    ensure!(
+        config.min_transaction_manager_queue_length_for_gas_price_priority
+            <= config.max_transaction_manager_queue_length_for_signing,
+        "min_transaction_manager_queue_length_for_gas_price_priority must not exceed \
+         max_transaction_manager_queue_length_for_signing"
+    );
+    ensure!(
+        config.max_gas_price_multiplier_under_load > 0,
+        "max_gas_price_multiplier_under_load must be positive"
+    );
+    Ok(())
+}
+
+impl SuiNode {
+    /// Reads the config file at `path` again, and applies the changes that don't need a restart
+    /// if the new config is valid. Nothing is applied otherwise.
+    pub async fn reload_config(&self, path: &Path) -> Result<ConfigReloadReport> {
+        let mut new_config = NodeConfig::load(path)?;
+        validate_pruning_config(&new_config.authority_store_pruning_config)?;
+        validate_overload_threshold_config(&new_config.overload_threshold_config)?;
+
+        let mut config = self.reloaded_config.lock().await;
+        // These are set on the command line rather than in the config file.
+        new_config.network_address = config.network_address.clone();
+        new_config.run_with_range = config.run_with_range;
+        new_config.supported_protocol_versions = config.supported_protocol_versions;
+
+        let mut report = ConfigReloadReport::default();
+        for field in config.changed_fields(&new_config) {
+            if is_reloadable(&field) {
+                report.applied.push(field);
+            } else {
+                report.requires_restart.push(field);
+            }
+        }
+
+        let mut pruning_config = config.authority_store_pruning_config;
+        let new_pruning_config = &new_config.authority_store_pruning_config;
+        pruning_config.num_epochs_to_retain = new_pruning_config.num_epochs_to_retain;
+        pruning_config.num_epochs_to_retain_for_checkpoints =
+            new_pruning_config.num_epochs_to_retain_for_checkpoints;
+        pruning_config.max_checkpoints_in_batch = new_pruning_config.max_checkpoints_in_batch;
+        pruning_config.max_transactions_in_batch = new_pruning_config.max_transactions_in_batch;
+        pruning_config.killswitch_tombstone_pruning =
+            new_pruning_config.killswitch_tombstone_pruning;
+        self.state.update_pruning_config(pruning_config);
+        self.state
+            .update_overload_threshold_config(new_config.overload_threshold_config.clone());
+
+        if let Some(subscription_limit) = &self.subscription_limit {
+            subscription_limit.set(new_config.indexer_max_subscriptions);
+        }
+
+        config.authority_store_pruning_config = pruning_config;
+        config.overload_threshold_config = new_config.overload_threshold_config;
+        config.indexer_max_subscriptions = new_config.indexer_max_subscriptions;
+
+        info!(
+            applied = ?report.applied,
+            requires_restart = ?report.requires_restart,
+            "Reloaded config from {}",
+            path.display()
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reloadable_fields() {
+        assert!(is_reloadable(
+            "authority-store-pruning-config.num-epochs-to-retain"
+        ));
+        assert!(is_reloadable(
+            "overload-threshold-config.max_transactions_in_flight_per_sender"
+        ));
+        assert!(!is_reloadable(
+            "authority-store-pruning-config.pruning-run-delay-seconds"
+        ));
+        assert!(!is_reloadable(
+            "authority-store-pruning-config.num-epochs-to-retain-for-objects"
+        ));
+        assert!(is_reloadable("indexer-max-subscriptions"));
+        assert!(!is_reloadable("db-path"));
+    }
+
+    #[test]
+    fn invalid_overload_threshold_config() {
+        let mut config = OverloadThresholdConfig::default();
+        validate_overload_threshold_config(&config).unwrap();
+        config.min_transaction_manager_queue_length_for_gas_price_priority =
+            config.max_transaction_manager_queue_length_for_signing + 1;
+        assert!(validate_overload_threshold_config(&config).is_err());
+    }
+}
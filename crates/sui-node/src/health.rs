@@ -0,0 +1,217 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Health and readiness endpoints of the fullnode HTTP server, for load balancers and k8s probes.
+//!
+//!   $ curl 'http://127.0.0.1:9000/health'
+//!   $ curl 'http://127.0.0.1:9000/readiness?max_checkpoint_lag=10'
+//!
+//! `/health` fails only if the node can't read its database, while `/readiness` also fails if
+//! the node is behind the network by more than `max_checkpoint_lag` checkpoints, or if its latest
+//! executed checkpoint is older than `max_checkpoint_age_ms`.
+//!
+//! There is no consensus connectivity check: the endpoints are only served by fullnodes, which
+//! don't connect to consensus. A fullnode learns of new checkpoints from its state sync peers,
+//! so losing connectivity to the network shows up as a growing checkpoint age instead, which
+//! probes check with `max_checkpoint_age_ms`.
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use sui_core::authority::AuthorityState;
+use sui_types::committee::EpochId;
+use sui_types::error::SuiResult;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+
+pub const HEALTH_ROUTE: &str = "/health";
+pub const READINESS_ROUTE: &str = "/readiness";
+
+const DEFAULT_MAX_CHECKPOINT_LAG: u64 = 100;
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealth {
+    pub version: &'static str,
+    /// The error reading the database, if any.
+    pub db_error: Option<String>,
+    pub epoch: Option<EpochId>,
+    pub highest_executed_checkpoint: Option<CheckpointSequenceNumber>,
+    /// The highest checkpoint known to be certified by the network.
+    pub highest_verified_checkpoint: Option<CheckpointSequenceNumber>,
+    pub checkpoint_lag: Option<u64>,
+    /// The time since the latest executed checkpoint was created.
+    pub checkpoint_age_ms: Option<u64>,
+}
+
+impl NodeHealth {
+    fn new(state: &AuthorityState) -> Self {
+        let mut health = Self {
+            version: env!("CARGO_PKG_VERSION"),
+            db_error: None,
+            epoch: None,
+            highest_executed_checkpoint: None,
+            highest_verified_checkpoint: None,
+            checkpoint_lag: None,
+            checkpoint_age_ms: None,
+        };
+        if let Err(err) = health.read_checkpoints(state) {
+            health.db_error = Some(err.to_string());
+        }
+        health
+    }
+
+    fn read_checkpoints(&mut self, state: &AuthorityState) -> SuiResult {
+        let checkpoint_store = state.get_checkpoint_store();
+        let highest_executed = checkpoint_store.get_highest_executed_checkpoint()?;
+        let highest_verified = checkpoint_store.get_highest_verified_checkpoint()?;
+
+        self.epoch = Some(state.load_epoch_store_one_call_per_task().epoch());
+        self.highest_executed_checkpoint = highest_executed.as_ref().map(|c| c.sequence_number);
+        self.highest_verified_checkpoint = highest_verified.as_ref().map(|c| c.sequence_number);
+        self.checkpoint_lag = Some(
+            self.highest_verified_checkpoint
+                .unwrap_or_default()
+                .saturating_sub(self.highest_executed_checkpoint.unwrap_or_default()),
+        );
+        self.checkpoint_age_ms = highest_executed.map(|checkpoint| {
+            SystemTime::now()
+                .duration_since(checkpoint.timestamp())
+                .unwrap_or(Duration::ZERO)
+                .as_millis() as u64
+        });
+        Ok(())
+    }
+
+    fn is_ready(&self, params: &ReadinessParams) -> bool {
+        self.db_error.is_none()
+            && self.checkpoint_lag.is_some_and(|lag| {
+                lag <= params
+                    .max_checkpoint_lag
+                    .unwrap_or(DEFAULT_MAX_CHECKPOINT_LAG)
+            })
+            && params.max_checkpoint_age_ms.map_or(true, |max_age| {
+                self.checkpoint_age_ms.is_some_and(|age| age <= max_age)
+            })
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct ReadinessParams {
+    max_checkpoint_lag: Option<u64>,
+    max_checkpoint_age_ms: Option<u64>,
+}
+
+pub fn health_router(state: Arc<AuthorityState>) -> Router {
+    Router::new()
+        .route(HEALTH_ROUTE, get(health))
+        .route(READINESS_ROUTE, get(readiness))
+        .with_state(state)
+}
+
+async fn health(State(state): State<Arc<AuthorityState>>) -> (StatusCode, Json<NodeHealth>) {
+    let health = NodeHealth::new(&state);
+    let status = if health.db_error.is_none() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(health))
+}
+
+async fn readiness(
+    State(state): State<Arc<AuthorityState>>,
+    Query(params): Query<ReadinessParams>,
+) -> (StatusCode, Json<NodeHealth>) {
+    let health = NodeHealth::new(&state);
+    let status = if health.is_ready(&params) {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(health))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn healthy() -> NodeHealth {
+        NodeHealth {
+            version: "test",
+            db_error: None,
+            epoch: Some(1),
+            highest_executed_checkpoint: Some(90),
+            highest_verified_checkpoint: Some(100),
+            checkpoint_lag: Some(10),
+            checkpoint_age_ms: Some(1_000),
+        }
+    }
+
+    fn params(
+        max_checkpoint_lag: Option<u64>,
+        max_checkpoint_age_ms: Option<u64>,
+    ) -> ReadinessParams {
+        ReadinessParams {
+            max_checkpoint_lag,
+            max_checkpoint_age_ms,
+        }
+    }
+
+    #[test]
+    fn test_ready() {
+        assert!(healthy().is_ready(&ReadinessParams::default()));
+        assert!(healthy().is_ready(&params(Some(10), Some(1_000))));
+    }
+
+    #[test]
+    fn test_not_ready_on_db_error() {
+        let health = NodeHealth {
+            db_error: Some("error".to_string()),
+            ..healthy()
+        };
+        assert!(!health.is_ready(&ReadinessParams::default()));
+    }
+
+    #[test]
+    fn test_not_ready_when_lagging() {
+        let health = NodeHealth {
+            checkpoint_lag: Some(DEFAULT_MAX_CHECKPOINT_LAG),
+            ..healthy()
+        };
+        assert!(health.is_ready(&ReadinessParams::default()));
+
+        let health = NodeHealth {
+            checkpoint_lag: Some(DEFAULT_MAX_CHECKPOINT_LAG + 1),
+            ..healthy()
+        };
+        assert!(!health.is_ready(&ReadinessParams::default()));
+        assert!(!healthy().is_ready(&params(Some(9), None)));
+
+        // The lag is unknown when the checkpoints can't be read.
+        let health = NodeHealth {
+            checkpoint_lag: None,
+            ..healthy()
+        };
+        assert!(!health.is_ready(&ReadinessParams::default()));
+    }
+
+    #[test]
+    fn test_not_ready_when_checkpoint_is_old() {
+        assert!(!healthy().is_ready(&params(None, Some(999))));
+
+        // The age is only checked when a maximum is given, and is unknown before the first
+        // checkpoint is executed.
+        let health = NodeHealth {
+            checkpoint_age_ms: None,
+            ..healthy()
+        };
+        assert!(health.is_ready(&ReadinessParams::default()));
+        assert!(!health.is_ready(&params(None, Some(1_000))));
+    }
+}
@@ -63,7 +63,7 @@ use sui_core::checkpoints::{
 use sui_core::consensus_adapter::{
     CheckConnection, ConnectionMonitorStatus, ConsensusAdapter, ConsensusAdapterMetrics,
 };
-use sui_core::consensus_manager::{ConsensusManager, ConsensusManagerTrait};
+use sui_core::consensus_manager::{ConsensusManager, ConsensusManagerTrait, ConsensusStatus};
 use sui_core::consensus_throughput_calculator::{
     ConsensusThroughputCalculator, ConsensusThroughputProfiler, ThroughputProfileRanges,
 };
@@ -80,11 +80,11 @@ use sui_core::storage::RocksDbStore;
 use sui_core::transaction_orchestrator::TransactiondOrchestrator;
 use sui_core::{
     authority::{AuthorityState, AuthorityStore},
-    authority_client::NetworkAuthorityClient,
+    authority_client::{NetworkAuthorityClient, ValidatorClientTlsConfig},
 };
 use sui_json_rpc::coin_api::CoinReadApi;
 use sui_json_rpc::governance_api::GovernanceReadApi;
-use sui_json_rpc::indexer_api::IndexerApi;
+use sui_json_rpc::indexer_api::{IndexerApi, SubscriptionLimit};
 use sui_json_rpc::move_utils::MoveUtils;
 use sui_json_rpc::read_api::ReadApi;
 use sui_json_rpc::transaction_builder_api::TransactionBuilderApi;
@@ -106,7 +106,8 @@ use sui_storage::{
 use sui_storage::{FileCompression, IndexStore, StorageFormat};
 use sui_types::base_types::{AuthorityName, EpochId};
 use sui_types::committee::Committee;
-use sui_types::crypto::KeypairTraits;
+use sui_types::crypto::{KeypairTraits, NetworkPublicKey};
+use sui_types::deserialization_limits::DeserializationLimits;
 use sui_types::error::{SuiError, SuiResult};
 use sui_types::messages_consensus::{
     check_total_jwk_size, AuthorityCapabilities, ConsensusTransaction,
@@ -121,7 +122,9 @@ use typed_store::DBMetrics;
 use crate::metrics::{GrpcMetrics, SuiNodeMetrics};
 
 pub mod admin;
+pub mod config_reload;
 mod handle;
+pub mod health;
 pub mod metrics;
 
 pub struct ValidatorComponents {
@@ -137,6 +140,41 @@ pub struct ValidatorComponents {
     sui_tx_validator_metrics: Arc<SuiTxValidatorMetrics>,
 }
 
+/// Allows the network keys of the validators of the current epoch, so that clients authenticated
+/// on the validator gRPC service follow the committee across reconfigurations, and the network
+/// keys of the other nodes allowed in the config.
+struct CommitteeNetworkKeys {
+    state: Arc<AuthorityState>,
+    allowed_client_network_keys: Vec<NetworkPublicKey>,
+}
+
+impl sui_tls::Allower for CommitteeNetworkKeys {
+    fn allowed(&self, key: &NetworkPublicKey) -> bool {
+        if self.allowed_client_network_keys.contains(key) {
+            return true;
+        }
+        let peer_id = anemo::PeerId(key.0.to_bytes());
+        self.state
+            .load_epoch_store_one_call_per_task()
+            .epoch_start_state()
+            .get_authority_names_to_peer_ids()
+            .values()
+            .any(|committee_peer_id| *committee_peer_id == peer_id)
+    }
+}
+
+/// The cipher suites allowed on TLS connections between validators.
+fn validator_tls_cipher_suites(
+    config: &NodeConfig,
+) -> Result<Vec<sui_tls::rustls::SupportedCipherSuite>> {
+    match &config.validator_tls_config {
+        Some(tls_config) if !tls_config.allowed_cipher_suites.is_empty() => Ok(
+            sui_tls::cipher_suites_from_names(&tls_config.allowed_cipher_suites)?,
+        ),
+        _ => Ok(sui_tls::rustls::DEFAULT_CIPHER_SUITES.to_vec()),
+    }
+}
+
 #[cfg(msim)]
 mod simulator {
     use super::*;
@@ -198,9 +236,13 @@ use sui_core::mysticeti_adapter::LazyMysticetiClient;
 
 pub struct SuiNode {
     config: NodeConfig,
+    /// The config with the changes applied by `reload_config` since startup.
+    reloaded_config: Mutex<NodeConfig>,
     validator_components: Mutex<Option<ValidatorComponents>>,
     /// The http server responsible for serving JSON-RPC as well as the experimental rest service
     _http_server: Option<tokio::task::JoinHandle<()>>,
+    /// The subscription limit of the JSON-RPC server, changed by `reload_config`.
+    subscription_limit: Option<SubscriptionLimit>,
     state: Arc<AuthorityState>,
     transaction_orchestrator: Option<Arc<TransactiondOrchestrator<NetworkAuthorityClient>>>,
     registry_service: RegistryService,
@@ -589,6 +631,10 @@ impl SuiNode {
             config.state_debug_dump_config.clone(),
             config.overload_threshold_config.clone(),
             archive_readers,
+            Some(ValidatorClientTlsConfig::new(
+                config.network_key_pair(),
+                validator_tls_cipher_suites(&config)?,
+            )),
         )
         .await;
         // ensure genesis txn was executed
@@ -635,13 +681,14 @@ impl SuiNode {
             None
         };
 
-        let http_server = build_http_server(
+        let (http_server, subscription_limit) = build_http_server(
             state.clone(),
             &transaction_orchestrator.clone(),
             &config,
             &prometheus_registry,
             custom_rpc_runtime,
-        )?;
+        )?
+        .unzip();
 
         let accumulator = Arc::new(StateAccumulator::new(store));
 
@@ -696,9 +743,11 @@ impl SuiNode {
         let (shutdown_channel, _) = broadcast::channel::<Option<RunWithRange>>(1);
 
         let node = Self {
+            reloaded_config: Mutex::new(config.clone()),
             config,
             validator_components: Mutex::new(validator_components),
             _http_server: http_server,
+            subscription_limit,
             state,
             transaction_orchestrator,
             registry_service,
@@ -759,6 +808,17 @@ impl SuiNode {
         Ok(())
     }
 
+    pub async fn consensus_status(&self) -> SuiResult<Option<ConsensusStatus>> {
+        self.validator_components
+            .lock()
+            .await
+            .as_ref()
+            .ok_or_else(|| SuiError::from("Node is not a validator"))?
+            .consensus_manager
+            .consensus_status()
+            .await
+    }
+
     pub fn clear_override_protocol_upgrade_buffer_stake(&self, epoch: EpochId) -> SuiResult {
         self.state
             .clear_override_protocol_upgrade_buffer_stake(epoch)
@@ -1017,6 +1077,7 @@ impl SuiNode {
 
         let (consensus_adapter, consensus_manager) = match consensus_config.protocol {
             ConsensusProtocol::Narwhal => {
+                consensus_config.narwhal_config().validate()?;
                 let consensus_adapter = Arc::new(Self::construct_consensus_adapter(
                     &committee,
                     consensus_config,
@@ -1272,7 +1333,29 @@ impl SuiNode {
         let mut server_builder =
             ServerBuilder::from_config(&server_conf, GrpcMetrics::new(prometheus_registry));
 
-        server_builder = server_builder.add_service(ValidatorServer::new(validator_service));
+        if let Some(tls_config) = &config.validator_tls_config {
+            let cipher_suites = validator_tls_cipher_suites(config)?;
+            let certificate = sui_tls::SelfSignedCertificate::new(
+                config.network_key_pair().copy().private(),
+                sui_tls::SUI_VALIDATOR_SERVER_NAME,
+            );
+            let tls_config = sui_tls::CertVerifier::new(CommitteeNetworkKeys {
+                state: state.clone(),
+                allowed_client_network_keys: tls_config.allowed_client_network_keys.clone(),
+            })
+            .rustls_server_config_with_cipher_suites(
+                &cipher_suites,
+                vec![certificate.rustls_certificate()],
+                certificate.rustls_private_key(),
+            )?;
+            server_builder = server_builder.with_tls_config(tls_config);
+        }
+
+        server_builder = server_builder.add_service(
+            ValidatorServer::new(validator_service).max_decoding_message_size(
+                DeserializationLimits::get().max_validator_request_size_bytes(),
+            ),
+        );
 
         let server = server_builder
             .bind(config.network_address())
@@ -1467,6 +1550,14 @@ impl SuiNode {
                 // Stop the old checkpoint service.
                 drop(checkpoint_service_exit);
 
+                // Let the consensus handler catch up with the output of consensus, so that the
+                // last commits of the epoch are persisted before consensus is torn down.
+                if let Err(err) = consensus_manager
+                    .wait_for_output_processed(&cur_epoch_store)
+                    .await
+                {
+                    warn!("Failed to wait for the consensus output to be processed: {err:?}");
+                }
                 consensus_manager.shutdown().await;
 
                 let new_epoch_store = self
@@ -1718,15 +1809,15 @@ pub fn build_http_server(
     config: &NodeConfig,
     prometheus_registry: &Registry,
     _custom_runtime: Option<Handle>,
-) -> Result<Option<tokio::task::JoinHandle<()>>> {
+) -> Result<Option<(tokio::task::JoinHandle<()>, SubscriptionLimit)>> {
     // Validators do not expose these APIs
     if config.consensus_config().is_some() {
         return Ok(None);
     }
 
-    let mut router = axum::Router::new();
+    let mut router = health::health_router(state.clone());
 
-    let json_rpc_router = {
+    let (json_rpc_router, subscription_limit) = {
         let mut server = JsonRpcServerBuilder::new(env!("CARGO_PKG_VERSION"), prometheus_registry);
 
         let kv_store = build_kv_store(&state, config, prometheus_registry)?;
@@ -1773,17 +1864,19 @@ pub fn build_http_server(
                 sui_json_rpc::name_service::NameServiceConfig::default()
             };
 
-        server.register_module(IndexerApi::new(
+        let indexer_api = IndexerApi::new(
             state.clone(),
             ReadApi::new(state.clone(), kv_store.clone(), metrics.clone()),
             kv_store,
             name_service_config,
             metrics,
             config.indexer_max_subscriptions,
-        ))?;
+        );
+        let subscription_limit = indexer_api.subscription_limit();
+        server.register_module(indexer_api)?;
         server.register_module(MoveUtils::new(state.clone()))?;
 
-        server.to_router(None)?
+        (server.to_router(None)?, subscription_limit)
     };
 
     router = router.merge(json_rpc_router);
@@ -1800,7 +1893,7 @@ pub fn build_http_server(
 
     info!(local_addr =? addr, "Sui JSON-RPC server listening on {addr}");
 
-    Ok(Some(handle))
+    Ok(Some((handle, subscription_limit)))
 }
 
 #[cfg(not(test))]
@@ -117,6 +117,7 @@ fn main() {
     let is_validator = config.consensus_config().is_some();
 
     let admin_interface_port = config.admin_interface_port;
+    let config_path = args.config_path.clone();
 
     // Run node in a separate runtime so that admin/monitoring functions continue to work
     // if it deadlocks.
@@ -177,9 +178,16 @@ fn main() {
             ))
             .unwrap();
 
-        sui_node::admin::run_admin_server(node, admin_interface_port, filter_handle).await
+        sui_node::admin::run_admin_server(node, admin_interface_port, filter_handle, config_path)
+            .await
     });
 
+    #[cfg(unix)]
+    runtimes.metrics.spawn(reload_config_on_sighup(
+        node_once_cell.clone(),
+        args.config_path,
+    ));
+
     runtimes.metrics.spawn(async move {
         let node = node_once_cell.get().await;
         let state = node.state();
@@ -200,6 +208,26 @@ fn main() {
     drop(runtimes);
 }
 
+#[cfg(unix)]
+async fn reload_config_on_sighup(
+    node_once_cell: Arc<AsyncOnceCell<Arc<sui_node::SuiNode>>>,
+    config_path: PathBuf,
+) {
+    use tokio::signal::unix::*;
+
+    // Handle SIGHUP from the start, so that it doesn't terminate the node while it starts.
+    let mut sighup = signal(SignalKind::hangup()).unwrap();
+    let node = node_once_cell.get().await;
+    while sighup.recv().await.is_some() {
+        if let Err(err) = node.reload_config(&config_path).await {
+            error!(
+                "Failed to reload config from {}: {err:#}",
+                config_path.display()
+            );
+        }
+    }
+}
+
 #[cfg(not(unix))]
 async fn wait_termination(mut shutdown_rx: tokio::sync::broadcast::Receiver<()>) {
     tokio::select! {
@@ -143,6 +143,7 @@ impl RpcExampleProvider {
             self.suix_resolve_name_service_address(),
             self.suix_resolve_name_service_names(),
             self.sui_try_multi_get_past_objects(),
+            self.suix_is_address_denied_for_coin(),
         ]
         .into_iter()
         .map(|example| (example.function_name, example.examples))
@@ -999,6 +1000,25 @@ impl RpcExampleProvider {
         )
     }
 
+    fn suix_is_address_denied_for_coin(&mut self) -> Examples {
+        // Fixed values, so that adding this example does not change the ones generated before it.
+        let address = SuiAddress::from_str(
+            "0x2d6ba7e2e2fd5b8a3d1e1e1fc19e4e7b63f2e9f2a2f4c07e7d6e3b1f85b4d3e1",
+        )
+        .unwrap();
+        let coin_type =
+            "0x0a52124e2d53af3bef7959609efa51761ad155441a1b73bdaeecce7c56488b13::regulated_coin::REGULATED_COIN";
+
+        Examples::new(
+            "suix_isAddressDeniedForCoin",
+            vec![ExamplePairing::new(
+                "Checks whether the address provided is denied from using the regulated coin type provided.",
+                vec![("address", json!(address)), ("coin_type", json!(coin_type))],
+                json!(true),
+            )],
+        )
+    }
+
     fn sui_get_loaded_child_objects(&mut self) -> Examples {
         let mut sequence = SequenceNumber::from_u64(self.rng.gen_range(24506..6450624));
         let seqs = (0..6)
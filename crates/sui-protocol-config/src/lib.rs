@@ -12,7 +12,7 @@ use tracing::{info, warn};
 
 /// The minimum and maximum protocol versions supported by this build.
 const MIN_PROTOCOL_VERSION: u64 = 1;
-const MAX_PROTOCOL_VERSION: u64 = 35;
+const MAX_PROTOCOL_VERSION: u64 = 36;
 
 // Record history of protocol version allocations here:
 //
@@ -105,6 +105,13 @@ const MAX_PROTOCOL_VERSION: u64 = 35;
 // Version 34: Framework changes for random beacon.
 // Version 35: Add poseidon hash function.
 //             Enable coin deny list.
+// Version 36: Add `tx_context::gas_price`.
+//             Bound the size of messages deserialized from the network.
+//             Commit to the transactions of checkpoints with a Merkle root in devnet.
+//             Enforce monotonic checkpoint timestamps in devnet.
+//             Check that the dependencies of published packages link their imports in devnet.
+//             Bound the number of bytecode instructions executed by a transaction in devnet.
+//             Restrict how entry functions and programmable transactions use Random in devnet.
 
 #[derive(Copy, Clone, Debug, Hash, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ProtocolVersion(u64);
@@ -210,7 +217,8 @@ impl Default for Chain {
 pub struct Error(pub String);
 
 /// Records on/off feature flags that may vary at each protocol version.
-#[derive(Default, Clone, Serialize, Debug, ProtocolConfigFeatureFlagsGetters)]
+#[derive(Default, Clone, Serialize, Deserialize, Debug, ProtocolConfigFeatureFlagsGetters)]
+#[serde(default)]
 struct FeatureFlags {
     // Add feature flags here, e.g.:
     // new_protocol_feature: bool,
@@ -376,6 +384,30 @@ struct FeatureFlags {
     // If true, enable the coin deny list.
     #[serde(skip_serializing_if = "is_false")]
     enable_coin_deny_list: bool,
+
+    // If true, checkpoints commit to the root of a Merkle tree over their transactions and
+    // effects, so that the inclusion of a transaction can be proven without the full contents.
+    #[serde(skip_serializing_if = "is_false")]
+    checkpoint_contents_merkle_root: bool,
+
+    // If true, the timestamp of a consensus commit is raised to the timestamp of the previous
+    // commit if it is older, both in the Clock and in the checkpoints built from the commit.
+    #[serde(skip_serializing_if = "is_false")]
+    enforce_checkpoint_timestamp_monotonicity: bool,
+
+    // If true, publish and upgrade fail if two dependencies are versions of the same package, or
+    // a module is imported from a dependency that doesn't contain it.
+    #[serde(skip_serializing_if = "is_false")]
+    check_package_dependencies: bool,
+
+    // If true, entry functions cannot take `sui::random::Random` by value or by mutable reference.
+    #[serde(skip_serializing_if = "is_false")]
+    reject_mutable_random_on_entry_functions: bool,
+
+    // If true, a programmable transaction can only follow a command using `sui::random::Random`
+    // with TransferObjects or MergeCoins commands.
+    #[serde(skip_serializing_if = "is_false")]
+    enable_randomness_ptb_restrictions: bool,
 }
 
 fn is_false(b: &bool) -> bool {
@@ -387,7 +419,7 @@ fn is_empty(b: &BTreeSet<String>) -> bool {
 }
 
 /// Ordering mechanism for transactions in one Narwhal consensus output.
-#[derive(Default, Copy, Clone, PartialEq, Eq, Serialize, Debug)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum ConsensusTransactionOrdering {
     /// No ordering. Transactions are processed in the order they appear in the consensus output.
     #[default]
@@ -433,8 +465,11 @@ impl ConsensusTransactionOrdering {
 /// `pub fn new_constant_as_option(&self) -> Option<u64>` getter, which will
 /// return `None` if the field is not defined at that version.
 /// - If you want a customized getter, you can add a method in the impl.
+///
+/// Constants that are not set in a protocol version are omitted when serializing, so the config
+/// can only be deserialized from self-describing formats such as JSON, not from BCS.
 #[skip_serializing_none]
-#[derive(Clone, Serialize, Debug, ProtocolConfigAccessors)]
+#[derive(Clone, Serialize, Deserialize, Debug, ProtocolConfigAccessors)]
 pub struct ProtocolConfig {
     pub version: ProtocolVersion,
 
@@ -444,6 +479,14 @@ pub struct ProtocolConfig {
     /// Maximum serialized size of a transaction (in bytes).
     max_tx_size_bytes: Option<u64>,
 
+    /// Maximum number of signatures of a transaction. Enforced when deserializing transactions
+    /// received from the network.
+    max_tx_signatures: Option<u64>,
+
+    /// Maximum serialized size of the bitmap of signers of a certificate (in bytes). Enforced when
+    /// deserializing certificates received from the network.
+    max_signers_map_bytes: Option<u64>,
+
     /// Maximum number of input objects to a transaction. Enforced by the transaction input checker
     max_input_objects: Option<u64>,
 
@@ -545,6 +588,10 @@ pub struct ProtocolConfig {
     /// Maximum number of Move events that a single transaction can emit. Enforced by the VM during execution.
     max_num_event_emit: Option<u64>,
 
+    /// Maximum number of events of a single transaction, including system transactions. Enforced
+    /// when deserializing transaction events received from the network.
+    max_num_events_per_tx: Option<u64>,
+
     /// Maximum number of new IDs that a single transaction can create. Enforced by the VM during execution.
     max_num_new_move_object_ids: Option<u64>,
 
@@ -578,6 +625,10 @@ pub struct ProtocolConfig {
     /// Maximum depth of a Move value within the VM.
     max_move_value_depth: Option<u64>,
 
+    /// Maximum number of bytecode instructions executed by a transaction, regardless of its gas
+    /// budget. Enforced by the VM during execution.
+    max_instructions_per_tx: Option<u64>,
+
     /// Maximum number of back edges in Move function. Enforced by the bytecode verifier at signing.
     max_back_edges_per_function: Option<u64>,
 
@@ -755,6 +806,8 @@ pub struct ProtocolConfig {
     // TxContext
     // Cost params for the Move native function `transfer_impl<T: key>(obj: T, recipient: address)`
     tx_context_derive_id_cost_base: Option<u64>,
+    // Cost params for the Move native function `native_gas_price(): u64`
+    tx_context_gas_price_cost_base: Option<u64>,
 
     // Types
     // Cost params for the Move native function `is_one_time_witness<T: drop>(_: &T): bool`
@@ -1102,6 +1155,26 @@ impl ProtocolConfig {
     pub fn enable_coin_deny_list(&self) -> bool {
         self.feature_flags.enable_coin_deny_list
     }
+
+    pub fn checkpoint_contents_merkle_root(&self) -> bool {
+        self.feature_flags.checkpoint_contents_merkle_root
+    }
+
+    pub fn enforce_checkpoint_timestamp_monotonicity(&self) -> bool {
+        self.feature_flags.enforce_checkpoint_timestamp_monotonicity
+    }
+
+    pub fn check_package_dependencies(&self) -> bool {
+        self.feature_flags.check_package_dependencies
+    }
+
+    pub fn reject_mutable_random_on_entry_functions(&self) -> bool {
+        self.feature_flags.reject_mutable_random_on_entry_functions
+    }
+
+    pub fn enable_randomness_ptb_restrictions(&self) -> bool {
+        self.feature_flags.enable_randomness_ptb_restrictions
+    }
 }
 
 #[cfg(not(msim))]
@@ -1215,6 +1288,8 @@ impl ProtocolConfig {
             feature_flags: Default::default(),
 
             max_tx_size_bytes: Some(128 * 1024),
+            max_tx_signatures: None,
+            max_signers_map_bytes: None,
             // We need this number to be at least 100x less than `max_serialized_tx_effects_size_bytes`otherwise effects can be huge
             max_input_objects: Some(2048),
             max_serialized_tx_effects_size_bytes: Some(512 * 1024),
@@ -1245,6 +1320,7 @@ impl ProtocolConfig {
             max_fields_in_struct: Some(32),
             max_dependency_depth: Some(100),
             max_num_event_emit: Some(256),
+            max_num_events_per_tx: None,
             max_num_new_move_object_ids: Some(2048),
             max_num_new_move_object_ids_system_tx: Some(2048 * 16),
             max_num_deleted_move_object_ids: Some(2048),
@@ -1354,6 +1430,7 @@ impl ProtocolConfig {
             // `tx_context` module
             // Cost params for the Move native function `transfer_impl<T: key>(obj: T, recipient: address)`
             tx_context_derive_id_cost_base: Some(52),
+            tx_context_gas_price_cost_base: None,
 
             // `types` module
             // Cost params for the Move native function `is_one_time_witness<T: drop>(_: &T): bool`
@@ -1466,6 +1543,7 @@ impl ProtocolConfig {
             // Limits the length of a Move identifier
             max_move_identifier_len: None,
             max_move_value_depth: None,
+            max_instructions_per_tx: None,
 
             gas_rounding_step: None,
 
@@ -1759,6 +1837,24 @@ impl ProtocolConfig {
 
                     cfg.feature_flags.enable_coin_deny_list = true;
                 }
+                36 => {
+                    // Add cost for tx_context::native_gas_price
+                    cfg.tx_context_gas_price_cost_base = Some(52);
+
+                    // Bound the size of messages deserialized from the network.
+                    cfg.max_tx_signatures = Some(8);
+                    cfg.max_signers_map_bytes = Some(4 * 1024);
+                    cfg.max_num_events_per_tx = Some(2048);
+
+                    if chain != Chain::Mainnet && chain != Chain::Testnet {
+                        cfg.feature_flags.checkpoint_contents_merkle_root = true;
+                        cfg.feature_flags.enforce_checkpoint_timestamp_monotonicity = true;
+                        cfg.feature_flags.check_package_dependencies = true;
+                        cfg.max_instructions_per_tx = Some(1_000_000_000);
+                        cfg.feature_flags.reject_mutable_random_on_entry_functions = true;
+                        cfg.feature_flags.enable_randomness_ptb_restrictions = true;
+                    }
+                }
                 // Use this template when making changes:
                 //
                 //     // modify an existing constant.
@@ -1842,6 +1938,9 @@ impl ProtocolConfig {
     pub fn set_enable_effects_v2(&mut self, val: bool) {
         self.feature_flags.enable_effects_v2 = val;
     }
+    pub fn set_enable_randomness_ptb_restrictions_for_testing(&mut self, val: bool) {
+        self.feature_flags.enable_randomness_ptb_restrictions = val;
+    }
 }
 
 type OverrideFn = dyn Fn(ProtocolVersion, ProtocolConfig) -> ProtocolConfig + Send;
@@ -1937,6 +2036,18 @@ mod test {
     use super::*;
     use insta::assert_yaml_snapshot;
 
+    #[test]
+    fn test_serde_round_trip() {
+        for chain_id in [Chain::Unknown, Chain::Mainnet, Chain::Testnet] {
+            for i in MIN_PROTOCOL_VERSION..=MAX_PROTOCOL_VERSION {
+                let config = ProtocolConfig::get_for_version(ProtocolVersion::new(i), chain_id);
+                let json = serde_json::to_string(&config).unwrap();
+                let deserialized: ProtocolConfig = serde_json::from_str(&json).unwrap();
+                assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+            }
+        }
+    }
+
     #[test]
     fn snapshot_tests() {
         println!("\n============================================================================");
@@ -13,9 +13,10 @@ use sui_types::message_envelope::Message;
 use tracing::warn;
 use transaction_provider::{FuzzStartPoint, TransactionSource};
 
-use crate::replay::ExecutionSandboxState;
 use crate::replay::LocalExec;
 use crate::replay::ProtocolVersionSummary;
+use crate::replay::{DivergenceKind, EffectsDivergence, ExecutionSandboxState};
+use std::collections::BTreeMap;
 use std::env;
 use std::io::BufRead;
 use std::path::PathBuf;
@@ -88,6 +89,19 @@ pub enum ReplayToolCommand {
         batch_size: u64,
     },
 
+    /// Execute each transaction listed in a file twice, with independently constructed
+    /// executors, and check that both executions produce the same effects
+    #[command(name = "cc")]
+    CrossCheckBatch {
+        #[arg(long, short)]
+        path: PathBuf,
+        #[arg(long, short)]
+        terminate_early: bool,
+        /// Execution version used for the second execution of each transaction
+        #[arg(long, short, allow_hyphen_values = true)]
+        executor_version_override: Option<i64>,
+    },
+
     /// Replay a transaction from a node state dump
     #[command(name = "rd")]
     ReplayDump {
@@ -108,6 +122,10 @@ pub enum ReplayToolCommand {
         terminate_early: bool,
         #[arg(long, short, default_value = "16")]
         max_tasks: u64,
+        /// Write the transactions whose effects diverged, grouped by feature area, to this file
+        /// as JSON
+        #[arg(long, short)]
+        report: Option<PathBuf>,
     },
 
     /// Replay all transactions in an epoch
@@ -343,6 +361,50 @@ pub async fn execute_replay_command(
             // TODO: clean this up
             Some((0u64, 0u64))
         }
+        ReplayToolCommand::CrossCheckBatch {
+            path,
+            terminate_early,
+            executor_version_override,
+        } => {
+            let rpc_url = rpc_url.expect("Url must be provided");
+            let file = std::fs::File::open(path)?;
+            let reader = std::io::BufReader::new(file);
+
+            let mut total = 0u64;
+            let mut succeeded = 0u64;
+            for tx_digest in reader.lines() {
+                let tx_digest = TransactionDigest::from_str(&tx_digest?)?;
+                total += 1;
+                info!("Cross checking tx: {}", tx_digest);
+                let result = async {
+                    LocalExec::new_from_fn_url(&rpc_url)
+                        .await?
+                        .init_for_execution()
+                        .await?
+                        .cross_check_execution(
+                            &tx_digest,
+                            safety.clone(),
+                            executor_version_override,
+                        )
+                        .await
+                }
+                .await;
+                match result {
+                    Ok(_) => {
+                        info!("Executions of {} produced the same effects", tx_digest);
+                        succeeded += 1;
+                    }
+                    Err(e) => {
+                        error!("Cross check of {} failed: {:?}", tx_digest, e);
+                        if terminate_early {
+                            return Err(e.into());
+                        }
+                    }
+                }
+            }
+            info!("Cross checked {}/{} transactions", succeeded, total);
+            Some((succeeded, total))
+        }
         ReplayToolCommand::ReplayTransaction {
             tx_digest,
             show_effects,
@@ -425,6 +487,7 @@ pub async fn execute_replay_command(
             end,
             terminate_early,
             max_tasks,
+            report,
         } => {
             assert!(start <= end, "Start checkpoint must be <= end checkpoint");
             assert!(max_tasks > 0, "Max tasks must be > 0");
@@ -443,7 +506,7 @@ pub async fn execute_replay_command(
                 handles.push(tokio::spawn(async move {
                     info!("Spawning task {task_count} for checkpoints {checkpoints:?}");
                     let time = std::time::Instant::now();
-                    let (succeeded, total) = LocalExec::new_from_fn_url(&rpc_url.expect("Url must be provided"))
+                    let (succeeded, total, divergences) = LocalExec::new_from_fn_url(&rpc_url.expect("Url must be provided"))
                         .await
                         .unwrap()
                         .init_for_execution()
@@ -457,21 +520,28 @@ pub async fn execute_replay_command(
                         "Task {task_count}: executed checkpoints {:?} @ {} total transactions, {} succeeded",
                         checkpoints, total, succeeded
                     );
-                    (succeeded, total, time)
+                    (succeeded, total, divergences, time)
                 }));
             }
 
             let mut total_tx = 0;
             let mut total_time_ms = 0;
             let mut total_succeeded = 0;
+            let mut divergences_by_area: BTreeMap<String, Vec<EffectsDivergence>> = BTreeMap::new();
             futures::future::join_all(handles)
                 .await
                 .into_iter()
                 .for_each(|x| match x {
-                    Ok((suceeded, total, time)) => {
+                    Ok((suceeded, total, divergences, time)) => {
                         total_tx += total;
                         total_time_ms += time.as_millis() as u64;
                         total_succeeded += suceeded;
+                        for divergence in divergences {
+                            divergences_by_area
+                                .entry(divergence.feature_area.clone())
+                                .or_default()
+                                .push(divergence);
+                        }
                     }
                     Err(e) => {
                         error!("Task failed: {:?}", e);
@@ -485,6 +555,21 @@ pub async fn execute_replay_command(
                 total_time_ms,
                 (total_tx as f64) / (total_time_ms as f64 / 1000.0)
             );
+            for (area, divergences) in &divergences_by_area {
+                let mut kinds: BTreeMap<DivergenceKind, usize> = BTreeMap::new();
+                for kind in divergences.iter().flat_map(|d| &d.kinds) {
+                    *kinds.entry(*kind).or_default() += 1;
+                }
+                error!(
+                    "{} TXs diverged in {}: {:?}",
+                    divergences.len(),
+                    area,
+                    kinds
+                );
+            }
+            if let Some(report) = report {
+                std::fs::write(report, serde_json::to_string_pretty(&divergences_by_area)?)?;
+            }
             Some((total_succeeded, total_tx))
         }
         ReplayToolCommand::ReplayEpoch {
@@ -511,6 +596,7 @@ pub async fn execute_replay_command(
                     end,
                     terminate_early,
                     max_tasks,
+                    report: None,
                 },
             )
             .await;
@@ -58,9 +58,9 @@ use sui_types::{
     storage::{BackingPackageStore, ChildObjectResolver, ObjectStore, ParentSync},
     sui_system_state::epoch_start_sui_system_state::EpochStartSystemState,
     transaction::{
-        CertifiedTransaction, CheckedInputObjects, InputObjectKind, InputObjects, ObjectReadResult,
-        ObjectReadResultKind, SenderSignedData, Transaction, TransactionData, TransactionDataAPI,
-        TransactionKind, VerifiedCertificate, VerifiedTransaction,
+        CertifiedTransaction, CheckedInputObjects, Command, InputObjectKind, InputObjects,
+        ObjectReadResult, ObjectReadResultKind, SenderSignedData, Transaction, TransactionData,
+        TransactionDataAPI, TransactionKind, VerifiedCertificate, VerifiedTransaction,
     },
     DEEPBOOK_PACKAGE_ID,
 };
@@ -102,25 +102,101 @@ impl ExecutionSandboxState {
 
     /// Utility to diff effects in a human readable format
     pub fn diff_effects(&self) -> String {
-        let eff1 = &self.transaction_info.effects;
-        let eff2 = &self.local_exec_effects;
-        let on_chain_str = format!("{:#?}", eff1);
-        let local_chain_str = format!("{:#?}", eff2);
-        let mut res = vec![];
-
-        let diff = TextDiff::from_lines(&on_chain_str, &local_chain_str);
         println!("On-chain vs local diff");
-        for change in diff.iter_all_changes() {
-            let sign = match change.tag() {
-                ChangeTag::Delete => "---",
-                ChangeTag::Insert => "+++",
-                ChangeTag::Equal => "   ",
-            };
-            res.push(format!("{}{}", sign, change));
+        diff_effects_lines(&self.transaction_info.effects, &self.local_exec_effects)
+    }
+
+    /// Describe how the local effects diverged from the on-chain effects, if they did
+    pub fn divergence(&self) -> Option<EffectsDivergence> {
+        let on_chain = &self.transaction_info.effects;
+        let local = &self.local_exec_effects;
+        if on_chain == local {
+            return None;
+        }
+
+        let mut kinds = vec![];
+        if on_chain.status() != local.status() {
+            kinds.push(DivergenceKind::Status);
         }
+        if on_chain.gas_cost_summary() != local.gas_cost_summary() {
+            kinds.push(DivergenceKind::Gas);
+        }
+        if on_chain.created() != local.created()
+            || on_chain.mutated() != local.mutated()
+            || on_chain.unwrapped() != local.unwrapped()
+            || on_chain.deleted() != local.deleted()
+            || on_chain.unwrapped_then_deleted() != local.unwrapped_then_deleted()
+            || on_chain.wrapped() != local.wrapped()
+        {
+            kinds.push(DivergenceKind::Objects);
+        }
+        if on_chain.events_digest() != local.events_digest() {
+            kinds.push(DivergenceKind::Events);
+        }
+        if kinds.is_empty() {
+            kinds.push(DivergenceKind::Other);
+        }
+
+        Some(EffectsDivergence {
+            tx_digest: self.transaction_info.tx_digest,
+            feature_area: feature_area(&self.transaction_info.kind),
+            kinds,
+        })
+    }
+}
+
+/// What differs between the on-chain and the local effects of a transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DivergenceKind {
+    Status,
+    Gas,
+    Objects,
+    Events,
+    Other,
+}
+
+/// A transaction whose local effects diverged from its on-chain effects
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EffectsDivergence {
+    pub tx_digest: TransactionDigest,
+    /// The module of the first Move call of a programmable transaction, or the transaction kind
+    pub feature_area: String,
+    pub kinds: Vec<DivergenceKind>,
+}
+
+fn feature_area(kind: &TransactionKind) -> String {
+    let TransactionKind::ProgrammableTransaction(pt) = kind else {
+        return kind.name().to_string();
+    };
+    pt.commands
+        .iter()
+        .find_map(|command| match command {
+            Command::MoveCall(call) => Some(format!("{}::{}", call.package, call.module)),
+            _ => None,
+        })
+        .unwrap_or_else(|| kind.name().to_string())
+}
 
-        res.join("")
+/// Diff two effects line by line in a human readable format
+fn diff_effects_lines(
+    eff1: &SuiTransactionBlockEffects,
+    eff2: &SuiTransactionBlockEffects,
+) -> String {
+    let eff1_str = format!("{:#?}", eff1);
+    let eff2_str = format!("{:#?}", eff2);
+    let mut res = vec![];
+
+    let diff = TextDiff::from_lines(&eff1_str, &eff2_str);
+    for change in diff.iter_all_changes() {
+        let sign = match change.tag() {
+            ChangeTag::Delete => "---",
+            ChangeTag::Insert => "+++",
+            ChangeTag::Equal => "   ",
+        };
+        res.push(format!("{}{}", sign, change));
     }
+
+    res.join("")
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -643,7 +719,7 @@ impl LocalExec {
         expensive_safety_check_config: &ExpensiveSafetyCheckConfig,
         terminate_early: bool,
         use_authority: bool,
-    ) -> Result<(u64, u64), ReplayEngineError> {
+    ) -> Result<(u64, u64, Vec<EffectsDivergence>), ReplayEngineError> {
         // Get all the TXs at this checkpoint
         let mut txs = Vec::new();
         for checkpoint_id in checkpoint_ids {
@@ -651,6 +727,7 @@ impl LocalExec {
         }
         let num = txs.len();
         let mut succeeded = 0;
+        let mut divergences = vec![];
         for tx in txs {
             match self
                 .execute_transaction(
@@ -661,8 +738,10 @@ impl LocalExec {
                     None,
                 )
                 .await
-                .map(|q| q.check_effects())
-            {
+                .map(|q| {
+                    divergences.extend(q.divergence());
+                    q.check_effects()
+                }) {
                 Err(e) | Ok(Err(e)) => {
                     if terminate_early {
                         return Err(e);
@@ -675,7 +754,7 @@ impl LocalExec {
 
             succeeded += 1;
         }
-        Ok((succeeded, num as u64))
+        Ok((succeeded, num as u64, divergences))
     }
 
     pub async fn execution_engine_execute_with_tx_info_impl(
@@ -718,8 +797,7 @@ impl LocalExec {
         // At this point we have all the objects needed for replay
 
         // This assumes we already initialized the protocol version table `protocol_version_epoch_table`
-        let protocol_config =
-            &ProtocolConfig::get_for_version(tx_info.protocol_version, tx_info.chain);
+        let protocol_config = &tx_info.protocol_config();
 
         let metrics = self.metrics.clone();
 
@@ -818,10 +896,7 @@ impl LocalExec {
         let executed_epoch = pre_run_sandbox.transaction_info.executed_epoch;
         let reference_gas_price = pre_run_sandbox.transaction_info.reference_gas_price;
         let epoch_start_timestamp = pre_run_sandbox.transaction_info.epoch_start_timestamp;
-        let protocol_config = ProtocolConfig::get_for_version(
-            pre_run_sandbox.transaction_info.protocol_version,
-            pre_run_sandbox.transaction_info.chain,
-        );
+        let protocol_config = pre_run_sandbox.transaction_info.protocol_config();
         let required_objects = pre_run_sandbox.required_objects.clone();
         let shared_object_refs = pre_run_sandbox.transaction_info.shared_object_refs.clone();
 
@@ -955,6 +1030,51 @@ impl LocalExec {
         Ok(sandbox_state)
     }
 
+    /// Must be called after `init_for_execution`
+    /// Executes the transaction twice, each time with a newly constructed executor, and checks
+    /// that both executions produce the same effects. This catches nondeterminism in execution
+    /// before it can fork the network.
+    /// If `executor_version_override` is provided, the second execution uses that execution
+    /// version instead, which checks that both execution versions agree on the transaction.
+    pub async fn cross_check_execution(
+        &mut self,
+        tx_digest: &TransactionDigest,
+        expensive_safety_check_config: ExpensiveSafetyCheckConfig,
+        executor_version_override: Option<i64>,
+    ) -> Result<ExecutionSandboxState, ReplayEngineError> {
+        let first = self
+            .execution_engine_execute_impl(tx_digest, expensive_safety_check_config.clone())
+            .await?;
+
+        let first_executor_version_override = self.executor_version_override;
+        if executor_version_override.is_some() {
+            self.executor_version_override = executor_version_override;
+        }
+        let second = self
+            .execution_engine_execute_with_tx_info_impl(
+                &first.transaction_info,
+                None,
+                expensive_safety_check_config,
+            )
+            .await;
+        self.executor_version_override = first_executor_version_override;
+        let second = second?;
+
+        if first.local_exec_effects != second.local_exec_effects {
+            error!("Executions of {} are not deterministic", tx_digest);
+            return Err(ReplayEngineError::EffectsNondeterministic {
+                digest: *tx_digest,
+                diff: format!(
+                    "\n{}",
+                    diff_effects_lines(&first.local_exec_effects, &second.local_exec_effects)
+                ),
+                first: Box::new(first.local_exec_effects),
+                second: Box::new(second.local_exec_effects),
+            });
+        }
+        Ok(second)
+    }
+
     pub async fn execute_state_dump(
         &mut self,
         expensive_safety_check_config: ExpensiveSafetyCheckConfig,
@@ -1465,6 +1585,7 @@ impl LocalExec {
             sender_signed_data: orig_tx.clone(),
             reference_gas_price,
             chain,
+            protocol_config: None,
         })
     }
 
@@ -1517,8 +1638,14 @@ impl LocalExec {
 
         let chain = chain_from_chain_id(self.fetcher.get_chain_id().await?.as_str());
 
-        let protocol_config =
-            ProtocolConfig::get_for_version(dp.node_state_dump.protocol_version.into(), chain);
+        // Dumps of newer nodes record the config the transaction was executed with.
+        let protocol_config = dp
+            .node_state_dump
+            .protocol_config
+            .clone()
+            .unwrap_or_else(|| {
+                ProtocolConfig::get_for_version(dp.node_state_dump.protocol_version.into(), chain)
+            });
         // Extract the epoch start timestamp
         let (epoch_start_timestamp, reference_gas_price) =
             self.get_epoch_start_timestamp_and_rgp(epoch_id).await?;
@@ -1541,6 +1668,7 @@ impl LocalExec {
             sender_signed_data: orig_tx.clone(),
             reference_gas_price,
             chain,
+            protocol_config: Some(protocol_config),
         })
     }
 
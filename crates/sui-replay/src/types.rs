@@ -10,7 +10,7 @@ use serde::Serialize;
 use std::fmt::Debug;
 use sui_json_rpc_types::SuiEvent;
 use sui_json_rpc_types::SuiTransactionBlockEffects;
-use sui_protocol_config::{Chain, ProtocolVersion};
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
 use sui_sdk::error::Error as SuiRpcError;
 use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber, SuiAddress, VersionNumber};
 use sui_types::digests::{ObjectDigest, TransactionDigest};
@@ -54,6 +54,18 @@ pub struct OnChainTransactionInfo {
     pub reference_gas_price: u64,
     #[serde(default = "unspecified_chain")]
     pub chain: Chain,
+    /// The exact config the transaction was executed with, when known, e.g. from a node state
+    /// dump. Otherwise it is derived from `protocol_version` and `chain`.
+    #[serde(default)]
+    pub protocol_config: Option<ProtocolConfig>,
+}
+
+impl OnChainTransactionInfo {
+    pub fn protocol_config(&self) -> ProtocolConfig {
+        self.protocol_config
+            .clone()
+            .unwrap_or_else(|| ProtocolConfig::get_for_version(self.protocol_version, self.chain))
+    }
 }
 
 fn unspecified_chain() -> Chain {
@@ -132,6 +144,18 @@ pub enum ReplayEngineError {
         local: Box<SuiTransactionBlockEffects>,
     },
 
+    #[error(
+        "EffectsNondeterministic: Effects for digest {} differ between executions with diff {}",
+        digest,
+        diff
+    )]
+    EffectsNondeterministic {
+        digest: TransactionDigest,
+        diff: String,
+        first: Box<SuiTransactionBlockEffects>,
+        second: Box<SuiTransactionBlockEffects>,
+    },
+
     #[error("Genesis replay not supported digest {:#?}", digest)]
     GenesisReplayNotSupported { digest: TransactionDigest },
 
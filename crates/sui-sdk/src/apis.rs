@@ -11,8 +11,11 @@ use std::future;
 use std::sync::Arc;
 use std::time::Instant;
 use sui_json_rpc_types::DevInspectArgs;
+use tracing::debug;
 
 use crate::error::{Error, SuiRpcResult};
+use crate::event_subscription::EventSubscription;
+use crate::failover::{overload_retry_after, BackoffPolicy};
 use crate::RpcClient;
 use sui_json_rpc_api::{
     CoinReadApiClient, GovernanceReadApiClient, IndexerApiClient, MoveUtilsClient, ReadApiClient,
@@ -21,18 +24,20 @@ use sui_json_rpc_api::{
 use sui_json_rpc_types::{
     Balance, Checkpoint, CheckpointId, Coin, CoinPage, DelegatedStake, DevInspectResults,
     DryRunTransactionBlockResponse, DynamicFieldPage, EventFilter, EventPage, ObjectsPage,
-    ProtocolConfigResponse, SuiCoinMetadata, SuiCommittee, SuiEvent, SuiGetPastObjectRequest,
-    SuiMoveNormalizedModule, SuiObjectDataOptions, SuiObjectResponse, SuiObjectResponseQuery,
-    SuiPastObjectResponse, SuiTransactionBlockEffects, SuiTransactionBlockResponse,
-    SuiTransactionBlockResponseOptions, SuiTransactionBlockResponseQuery, TransactionBlocksPage,
+    ProtocolConfigResponse, SignedTransactionBlockBytes, SuiCoinMetadata, SuiCommittee, SuiEvent,
+    SuiGetPastObjectRequest, SuiMoveEventLayout, SuiMoveNormalizedModule, SuiObjectDataOptions,
+    SuiObjectResponse, SuiObjectResponseQuery, SuiPastObjectResponse, SuiTransactionBlockEffects,
+    SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+    SuiTransactionBlockResponseQuery, TransactionBlockStatusUpdate, TransactionBlocksPage,
     TransactionFilter,
 };
 use sui_json_rpc_types::{CheckpointPage, SuiLoadedChildObjectsResponse};
 use sui_types::balance::Supply;
 use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, TransactionDigest};
+use sui_types::committee::{CommitteeWithNetworkMetadata, EpochId};
 use sui_types::dynamic_field::DynamicFieldName;
 use sui_types::event::EventID;
-use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::messages_checkpoint::{CheckpointSequenceNumber, EpochStateCommitment};
 use sui_types::quorum_driver_types::ExecuteTransactionRequestType;
 use sui_types::sui_serde::BigInt;
 use sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
@@ -515,6 +520,22 @@ impl ReadApi {
         Ok(self.api.http.get_checkpoint(id).await?)
     }
 
+    /// Return the commitment of the committee to the live object set at the end of `epoch`, or an
+    /// error upon failure, e.g. if the epoch has not ended yet.
+    ///
+    /// The commitment can be checked with [EpochStateCommitment::verify], against the certified
+    /// summary of the last checkpoint of the epoch and the committee of the epoch.
+    pub async fn get_epoch_state_commitment(
+        &self,
+        epoch: EpochId,
+    ) -> SuiRpcResult<EpochStateCommitment> {
+        Ok(self
+            .api
+            .http
+            .get_epoch_state_commitment(BigInt::from(epoch))
+            .await?)
+    }
+
     /// Return a paginated list of checkpoints, or an error upon failure.
     pub async fn get_checkpoints(
         &self,
@@ -667,6 +688,33 @@ impl ReadApi {
             .await?)
     }
 
+    /// Run a transaction in dev inspect mode against the objects as of the end of `checkpoint`,
+    /// to query what a view function returned at that checkpoint.
+    ///
+    /// Objects are read at their version at the checkpoint, regardless of the versions in the
+    /// transaction. Only checkpoints whose objects have not been pruned by the fullnode can be
+    /// queried.
+    pub async fn dev_inspect_transaction_block_at_checkpoint(
+        &self,
+        sender_address: SuiAddress,
+        tx: TransactionKind,
+        checkpoint: CheckpointSequenceNumber,
+        gas_price: Option<BigInt<u64>>,
+        gas_budget: Option<BigInt<u64>>,
+    ) -> SuiRpcResult<DevInspectResults> {
+        Ok(self
+            .api
+            .http
+            .dev_inspect_transaction_block_at_checkpoint(
+                sender_address,
+                Base64::from_bytes(&bcs::to_bytes(&tx)?),
+                checkpoint.into(),
+                gas_price,
+                gas_budget,
+            )
+            .await?)
+    }
+
     /// Return the loaded child objects response for the the provided digest, or an error upon failure.
     ///
     /// Loaded child objects ([SuiLoadedChildObject](sui_json_rpc_types::SuiLoadedChildObject)) are the non-input objects that the transaction at the digest loaded
@@ -976,6 +1024,39 @@ impl CoinReadApi {
     pub async fn get_total_supply(&self, coin_type: String) -> SuiRpcResult<Supply> {
         Ok(self.api.http.get_total_supply(coin_type).await?)
     }
+
+    /// Return whether an address is denied from using a regulated coin type, or an error upon
+    /// failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use sui_sdk::SuiClientBuilder;
+    /// use sui_types::base_types::SuiAddress;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anyhow::Error> {
+    ///     let sui = SuiClientBuilder::default().build_localnet().await?;
+    ///     let address = SuiAddress::from_str("0x0000....0000")?;
+    ///     let denied = sui
+    ///         .coin_read_api()
+    ///         .is_address_denied_for_coin(address, "0x2::sui::SUI".to_string())
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn is_address_denied_for_coin(
+        &self,
+        address: SuiAddress,
+        coin_type: String,
+    ) -> SuiRpcResult<bool> {
+        Ok(self
+            .api
+            .http
+            .is_address_denied_for_coin(address, coin_type)
+            .await?)
+    }
 }
 
 /// Event API provides the functionality to fetch, query, or subscribe to events on the Sui network.
@@ -1033,11 +1114,60 @@ impl EventApi {
         }
     }
 
+    /// Return a stream of events that reconnects automatically, or an error if the initial
+    /// subscription fails or if the events matching `filter` cannot be queried, which is needed
+    /// to backfill them after a reconnection.
+    ///
+    /// Unlike [EventApi::subscribe_event], the returned [EventSubscription] resubscribes after
+    /// the WebSocket connection drops, backfills the events it missed in the meantime and skips
+    /// events it has already returned. Pass the value of [EventSubscription::cursor] from a
+    /// previous subscription as `cursor` to resume from where it stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// use futures::StreamExt;
+    /// use sui_json_rpc_types::EventFilter;
+    /// use sui_sdk::SuiClientBuilder;
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anyhow::Error> {
+    ///     let sui = SuiClientBuilder::default()
+    ///         .ws_url("wss://rpc.mainnet.sui.io:443")
+    ///         .build("https://fullnode.mainnet.sui.io:443")
+    ///         .await?;
+    ///     let mut events = sui
+    ///         .event_api()
+    ///         .subscribe_events(EventFilter::All(vec![]), None)
+    ///         .await?;
+    ///     while let Some(event) = events.next().await {
+    ///         println!("{:?}", event?);
+    ///     }
+    ///     println!("stopped at {:?}", events.cursor());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn subscribe_events(
+        &self,
+        filter: EventFilter,
+        cursor: Option<EventID>,
+    ) -> SuiRpcResult<EventSubscription> {
+        EventSubscription::new(self.api.clone(), filter, cursor, BackoffPolicy::default()).await
+    }
+
     /// Return a list of events for the given transaction digest, or an error upon failure.
     pub async fn get_events(&self, digest: TransactionDigest) -> SuiRpcResult<Vec<SuiEvent>> {
         Ok(self.api.http.get_events(digest).await?)
     }
 
+    /// Return the layout of the given event type, or an error upon failure.
+    ///
+    /// The `bcs` of events of the type can be decoded with the layout, see
+    /// [sui_json_rpc_types::SuiMoveStructLayout::decode], without the source of the package that
+    /// defines it.
+    pub async fn get_event_layout(&self, event_type: String) -> SuiRpcResult<SuiMoveEventLayout> {
+        Ok(self.api.http.get_event_layout(event_type).await?)
+    }
+
     /// Return a paginated response with events for the given event filter, or an error upon failure.
     ///
     /// The ordering of the events can be set with the `descending_order` argument.
@@ -1105,6 +1235,8 @@ impl QuorumDriverApi {
     /// but returned `confirmed_local_execution` is false, the client will
     /// keep retry for WAIT_FOR_LOCAL_EXECUTION_RETRY_COUNT times. If it
     /// still fails, it will return an error.
+    /// When the validators are overloaded, the transaction is resubmitted with exponential
+    /// backoff, waiting at least as long as the validators asked.
     pub async fn execute_transaction_block(
         &self,
         tx: Transaction,
@@ -1116,15 +1248,8 @@ impl QuorumDriverApi {
         let mut retry_count = 0;
         let start = Instant::now();
         while retry_count < WAIT_FOR_LOCAL_EXECUTION_RETRY_COUNT {
-            let response: SuiTransactionBlockResponse = self
-                .api
-                .http
-                .execute_transaction_block(
-                    tx_bytes.clone(),
-                    signatures.clone(),
-                    Some(options.clone()),
-                    Some(request_type.clone()),
-                )
+            let response = self
+                .submit_transaction_block(&tx_bytes, &signatures, &options, &request_type)
                 .await?;
 
             match request_type {
@@ -1147,6 +1272,70 @@ impl QuorumDriverApi {
             start.elapsed().as_secs(),
         ))
     }
+
+    async fn submit_transaction_block(
+        &self,
+        tx_bytes: &Base64,
+        signatures: &[Base64],
+        options: &SuiTransactionBlockResponseOptions,
+        request_type: &ExecuteTransactionRequestType,
+    ) -> SuiRpcResult<SuiTransactionBlockResponse> {
+        let backoff = BackoffPolicy::default();
+        let mut attempt = 0;
+        loop {
+            let result = self
+                .api
+                .http
+                .execute_transaction_block(
+                    tx_bytes.clone(),
+                    signatures.to_vec(),
+                    Some(options.clone()),
+                    Some(request_type.clone()),
+                )
+                .await;
+            match result {
+                Err(e) if attempt < backoff.max_retries => {
+                    let Some(retry_after) = overload_retry_after(&e) else {
+                        return Err(e.into());
+                    };
+                    let delay = backoff.delay_for_attempt(attempt).max(retry_after);
+                    debug!("Validators are overloaded, resubmitting transaction in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return Ok(result?),
+            }
+        }
+    }
+
+    /// Submit a batch of transactions over the WebSocket connection, and return a stream of
+    /// the updates of their status, each with the index of its transaction in `transactions`.
+    /// The stream ends once every transaction is executed or failed.
+    pub async fn subscribe_execute_transaction_blocks(
+        &self,
+        transactions: &[Transaction],
+    ) -> SuiRpcResult<impl Stream<Item = SuiRpcResult<TransactionBlockStatusUpdate>>> {
+        let transactions = transactions
+            .iter()
+            .map(|tx| {
+                let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+                SignedTransactionBlockBytes {
+                    tx_bytes,
+                    signatures,
+                }
+            })
+            .collect();
+        match &self.api.ws {
+            Some(c) => {
+                let subscription: Subscription<TransactionBlockStatusUpdate> =
+                    c.subscribe_execute_transaction_blocks(transactions).await?;
+                Ok(subscription.map(|item| Ok(item?)))
+            }
+            _ => Err(Error::Subscription(
+                "Subscription only supported by WebSocket client.".to_string(),
+            )),
+        }
+    }
 }
 
 /// Governance API provides the staking functionality.
@@ -1200,6 +1389,36 @@ impl GovernanceApi {
         Ok(self.api.http.get_latest_sui_system_state().await?)
     }
 
+    /// Return the committee of the current epoch together with the network addresses and keys
+    /// the validators registered on-chain, or an error upon failure.
+    ///
+    /// Use this method to discover the validator set with a single query to a full node, for
+    /// example to submit transactions to the validators directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use sui_sdk::SuiClientBuilder;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anyhow::Error> {
+    ///     let sui = SuiClientBuilder::default().build_localnet().await?;
+    ///     let committee = sui
+    ///         .governance_api()
+    ///         .get_committee_with_network_metadata()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_committee_with_network_metadata(
+        &self,
+    ) -> SuiRpcResult<CommitteeWithNetworkMetadata> {
+        self.get_latest_sui_system_state()
+            .await?
+            .get_sui_committee_with_network_metadata()
+            .map_err(|e| Error::DataError(e.to_string()))
+    }
+
     /// Return the reference gas price for the network, or an error upon failure.
     pub async fn get_reference_gas_price(&self) -> SuiRpcResult<u64> {
         Ok(*self.api.http.get_reference_gas_price().await?)
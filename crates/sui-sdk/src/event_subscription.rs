@@ -0,0 +1,584 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A long lived event subscription that survives WebSocket disconnects.
+//!
+//! [EventSubscription] resubscribes after the connection drops, backfills the events emitted
+//! while it was disconnected through `suix_queryEvents`, and drops events it has already
+//! delivered. Before the first event is delivered, the backfill starts from the latest event
+//! matching the filter when the subscription was opened. The last delivered [EventID] is
+//! exposed as a cursor, so that a consumer can persist it and resume from it later with
+//! [crate::apis::EventApi::subscribe_events].
+
+use std::collections::{HashSet, VecDeque};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use futures::{stream, StreamExt};
+use futures_core::Stream;
+use jsonrpsee::ws_client::WsClient;
+use sui_json_rpc_api::IndexerApiClient;
+use sui_json_rpc_types::{EventFilter, EventPage, SuiEvent};
+use sui_types::event::EventID;
+use tracing::{debug, warn};
+
+use crate::error::{Error, SuiRpcResult};
+use crate::failover::BackoffPolicy;
+use crate::RpcClient;
+
+/// Number of delivered event IDs remembered to filter out duplicates.
+const DEDUP_WINDOW: usize = 10_000;
+/// Page size used when backfilling events after a reconnection. Pages are fetched one at a
+/// time as the previous one is consumed, so this also bounds the backfilled events held in
+/// memory.
+const BACKFILL_PAGE_SIZE: usize = 100;
+
+type EventStream = Pin<Box<dyn Stream<Item = SuiRpcResult<SuiEvent>> + Send>>;
+
+/// A stream of [SuiEvent]s that reconnects automatically.
+///
+/// The stream only ends after it failed to reconnect `max_retries` times in a row, in which
+/// case the last error is returned as the final item.
+pub struct EventSubscription {
+    cursor: Arc<Mutex<Option<EventID>>>,
+    inner: EventStream,
+}
+
+impl EventSubscription {
+    /// Fails if `filter` cannot be used to backfill events with `suix_queryEvents`, as events
+    /// could then be silently missed after a reconnection.
+    pub(crate) async fn new(
+        api: Arc<RpcClient>,
+        filter: EventFilter,
+        cursor: Option<EventID>,
+        backoff: BackoffPolicy,
+    ) -> SuiRpcResult<Self> {
+        let source = RpcEventSource { api, client: None };
+        Self::with_source(source, filter, cursor, backoff).await
+    }
+
+    async fn with_source<S: EventSource + 'static>(
+        source: S,
+        filter: EventFilter,
+        cursor: Option<EventID>,
+        backoff: BackoffPolicy,
+    ) -> SuiRpcResult<Self> {
+        if !supports_backfill(&filter) {
+            return Err(Error::Subscription(format!(
+                "Events matching {filter:?} cannot be backfilled after a reconnection, use \
+                 subscribe_event instead."
+            )));
+        }
+        let resume = cursor.is_some();
+        let cursor = Arc::new(Mutex::new(cursor));
+        let mut state = State {
+            source,
+            filter,
+            cursor: cursor.clone(),
+            start: None,
+            seen: SeenEvents::default(),
+            backlog: VecDeque::new(),
+            backfill_cursor: None,
+            backfilling: false,
+            subscription: None,
+            backoff,
+            done: false,
+        };
+        // Connect eagerly so that configuration errors surface to the caller.
+        state.connect(false).await?;
+        if resume {
+            state.start_backfill();
+        } else {
+            // Events emitted after the subscription was opened are delivered by it, so a
+            // reconnection before the first event only needs to backfill from here.
+            state.start = state.source.latest(&state.filter).await?;
+        }
+
+        let inner = stream::unfold(state, |mut state| async move {
+            let item = state.next_event().await?;
+            Some((item, state))
+        });
+        Ok(Self {
+            cursor,
+            inner: Box::pin(inner),
+        })
+    }
+
+    /// The ID of the last event returned by this stream, if any.
+    pub fn cursor(&self) -> Option<EventID> {
+        *self.cursor.lock().unwrap()
+    }
+}
+
+impl Stream for EventSubscription {
+    type Item = SuiRpcResult<SuiEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// Whether `suix_queryEvents` supports `filter`, mirroring the queries a fullnode can serve.
+fn supports_backfill(filter: &EventFilter) -> bool {
+    match filter {
+        EventFilter::All(filters) => filters.is_empty(),
+        EventFilter::Sender(_)
+        | EventFilter::Transaction(_)
+        | EventFilter::MoveModule { .. }
+        | EventFilter::MoveEventType(_)
+        | EventFilter::MoveEventModule { .. }
+        | EventFilter::TimeRange { .. } => true,
+        // not using "_ =>" because we want to make sure we remember to add new variants here
+        EventFilter::Package(_)
+        | EventFilter::MoveEventField { .. }
+        | EventFilter::Any(_)
+        | EventFilter::And(_, _)
+        | EventFilter::Or(_, _) => false,
+    }
+}
+
+/// Where an [EventSubscription] reads its events from.
+#[async_trait]
+trait EventSource: Send + Sync {
+    /// Subscribe to the events matching `filter`, over a new connection if `reconnect` is set.
+    async fn subscribe(
+        &mut self,
+        filter: &EventFilter,
+        reconnect: bool,
+    ) -> SuiRpcResult<EventStream>;
+
+    /// Query the events matching `filter` emitted after `cursor`, in ascending order.
+    async fn query(
+        &self,
+        filter: &EventFilter,
+        cursor: Option<EventID>,
+        limit: usize,
+    ) -> SuiRpcResult<EventPage>;
+
+    /// The ID of the latest event matching `filter`, if any.
+    async fn latest(&self, filter: &EventFilter) -> SuiRpcResult<Option<EventID>>;
+}
+
+struct RpcEventSource {
+    api: Arc<RpcClient>,
+    /// Connection opened by this subscription after a reconnect. The initial subscription uses
+    /// the client's own WebSocket connection.
+    client: Option<WsClient>,
+}
+
+#[async_trait]
+impl EventSource for RpcEventSource {
+    async fn subscribe(
+        &mut self,
+        filter: &EventFilter,
+        reconnect: bool,
+    ) -> SuiRpcResult<EventStream> {
+        let subscription = match (&self.api.ws, reconnect) {
+            (Some(ws), false) => ws.subscribe_event(filter.clone()).await?,
+            (None, _) => {
+                return Err(Error::Subscription(
+                    "Subscription only supported by WebSocket client.".to_string(),
+                ))
+            }
+            (Some(_), true) => {
+                // Drop the previous connection before opening a new one.
+                self.client = None;
+                let client = self.api.reconnect_ws().await?;
+                let subscription = client.subscribe_event(filter.clone()).await?;
+                self.client = Some(client);
+                subscription
+            }
+        };
+        Ok(Box::pin(
+            subscription.map(|event| event.map_err(Error::from)),
+        ))
+    }
+
+    async fn query(
+        &self,
+        filter: &EventFilter,
+        cursor: Option<EventID>,
+        limit: usize,
+    ) -> SuiRpcResult<EventPage> {
+        Ok(self
+            .api
+            .http
+            .query_events(filter.clone(), cursor, Some(limit), Some(false))
+            .await?)
+    }
+
+    async fn latest(&self, filter: &EventFilter) -> SuiRpcResult<Option<EventID>> {
+        let page = self
+            .api
+            .http
+            .query_events(filter.clone(), None, Some(1), Some(true))
+            .await?;
+        Ok(page.data.first().map(|event| event.id))
+    }
+}
+
+struct State<S> {
+    source: S,
+    filter: EventFilter,
+    cursor: Arc<Mutex<Option<EventID>>>,
+    /// The latest event when the subscription was opened without a cursor. Backfills start
+    /// from it until an event is delivered.
+    start: Option<EventID>,
+    seen: SeenEvents,
+    /// Backfilled events to deliver before those of the subscription.
+    backlog: VecDeque<SuiEvent>,
+    /// The cursor of the next backfill page, while `backfilling` is set.
+    backfill_cursor: Option<EventID>,
+    backfilling: bool,
+    subscription: Option<EventStream>,
+    backoff: BackoffPolicy,
+    done: bool,
+}
+
+impl<S: EventSource> State<S> {
+    async fn next_event(&mut self) -> Option<SuiRpcResult<SuiEvent>> {
+        if self.done {
+            return None;
+        }
+        loop {
+            if let Some(event) = self.backlog.pop_front() {
+                if let Some(event) = self.deliver(event) {
+                    return Some(Ok(event));
+                }
+                continue;
+            }
+            let Some(subscription) = self.subscription.as_mut() else {
+                if let Err(e) = self.reconnect().await {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+                continue;
+            };
+            if self.backfilling {
+                if let Err(e) = self.backfill_page().await {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+                continue;
+            }
+            match subscription.next().await {
+                Some(Ok(event)) => {
+                    if let Some(event) = self.deliver(event) {
+                        return Some(Ok(event));
+                    }
+                }
+                Some(Err(e)) => {
+                    warn!("Event subscription error, reconnecting: {e}");
+                    self.subscription = None;
+                }
+                None => {
+                    debug!("Event subscription closed, reconnecting");
+                    self.subscription = None;
+                }
+            }
+        }
+    }
+
+    /// Record the event as delivered, returning `None` if it was already delivered.
+    fn deliver(&mut self, event: SuiEvent) -> Option<SuiEvent> {
+        if !self.seen.insert(event.id) {
+            return None;
+        }
+        *self.cursor.lock().unwrap() = Some(event.id);
+        Some(event)
+    }
+
+    async fn reconnect(&mut self) -> SuiRpcResult<()> {
+        let mut attempt = 0;
+        loop {
+            match self.connect(true).await {
+                Ok(()) => {
+                    self.start_backfill();
+                    return Ok(());
+                }
+                Err(e) if attempt >= self.backoff.max_retries => return Err(e),
+                Err(e) => {
+                    let delay = self.backoff.delay_for_attempt(attempt);
+                    warn!("Failed to resubscribe to events: {e}, retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Subscribe, dropping the backfill of the previous subscription.
+    async fn connect(&mut self, reconnect: bool) -> SuiRpcResult<()> {
+        self.subscription = None;
+        self.backlog.clear();
+        self.backfilling = false;
+        self.subscription = Some(self.source.subscribe(&self.filter, reconnect).await?);
+        Ok(())
+    }
+
+    /// Backfill the events emitted after the last delivered event, or after the start of the
+    /// subscription if none was delivered yet, before the events of the subscription. The
+    /// subscription is opened first so that no event falls between the end of the backfill and
+    /// the subscription.
+    fn start_backfill(&mut self) {
+        self.backfill_cursor = self.cursor.lock().unwrap().or(self.start);
+        self.backfilling = true;
+    }
+
+    /// Fetch the next page of the backfill, retrying with backoff.
+    async fn backfill_page(&mut self) -> SuiRpcResult<()> {
+        let mut attempt = 0;
+        let page = loop {
+            match self
+                .source
+                .query(&self.filter, self.backfill_cursor, BACKFILL_PAGE_SIZE)
+                .await
+            {
+                Ok(page) => break page,
+                Err(e) if attempt >= self.backoff.max_retries => return Err(e),
+                Err(e) => {
+                    let delay = self.backoff.delay_for_attempt(attempt);
+                    warn!("Failed to backfill events: {e}, retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        };
+        self.backfilling = page.has_next_page && page.next_cursor.is_some();
+        if page.next_cursor.is_some() {
+            self.backfill_cursor = page.next_cursor;
+        }
+        self.backlog.extend(page.data);
+        Ok(())
+    }
+}
+
+/// Bounded set of the most recently delivered event IDs.
+#[derive(Default)]
+struct SeenEvents {
+    ids: HashSet<EventID>,
+    order: VecDeque<EventID>,
+}
+
+impl SeenEvents {
+    fn insert(&mut self, id: EventID) -> bool {
+        if !self.ids.insert(id) {
+            return false;
+        }
+        self.order.push_back(id);
+        if self.order.len() > DEDUP_WINDOW {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use sui_types::base_types::ObjectID;
+    use sui_types::digests::TransactionDigest;
+
+    /// Serves the events of `emitted` to queries, and each subscription from the next of
+    /// `connections`, failing to subscribe once they run out. `latest` is the latest event
+    /// emitted before the first subscription.
+    struct FakeSource {
+        emitted: Vec<SuiEvent>,
+        latest: Option<EventID>,
+        connections: VecDeque<Vec<SuiEvent>>,
+        subscriptions: Arc<Mutex<Vec<bool>>>,
+        queries: Arc<Mutex<Vec<Option<EventID>>>>,
+    }
+
+    impl FakeSource {
+        fn new(emitted: Vec<SuiEvent>, connections: Vec<Vec<SuiEvent>>) -> Self {
+            Self {
+                emitted,
+                latest: None,
+                connections: connections.into(),
+                subscriptions: Arc::default(),
+                queries: Arc::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EventSource for FakeSource {
+        async fn subscribe(
+            &mut self,
+            _filter: &EventFilter,
+            reconnect: bool,
+        ) -> SuiRpcResult<EventStream> {
+            self.subscriptions.lock().unwrap().push(reconnect);
+            let events = self
+                .connections
+                .pop_front()
+                .ok_or_else(|| Error::Subscription("connection refused".to_string()))?;
+            Ok(Box::pin(stream::iter(events.into_iter().map(Ok))))
+        }
+
+        async fn query(
+            &self,
+            _filter: &EventFilter,
+            cursor: Option<EventID>,
+            limit: usize,
+        ) -> SuiRpcResult<EventPage> {
+            self.queries.lock().unwrap().push(cursor);
+            let start = cursor
+                .and_then(|cursor| self.emitted.iter().position(|e| e.id == cursor))
+                .map_or(0, |i| i + 1);
+            let data: Vec<_> = self.emitted[start..].iter().take(limit).cloned().collect();
+            Ok(EventPage {
+                next_cursor: data.last().map(|e| e.id),
+                has_next_page: start + data.len() < self.emitted.len(),
+                data,
+            })
+        }
+
+        async fn latest(&self, _filter: &EventFilter) -> SuiRpcResult<Option<EventID>> {
+            Ok(self.latest)
+        }
+    }
+
+    fn event(seq: u64) -> SuiEvent {
+        SuiEvent {
+            id: EventID {
+                tx_digest: TransactionDigest::default(),
+                event_seq: seq,
+            },
+            ..SuiEvent::random_for_testing()
+        }
+    }
+
+    fn no_retries() -> BackoffPolicy {
+        BackoffPolicy {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            multiplier: 1,
+            max_retries: 0,
+        }
+    }
+
+    /// Returns the sequence numbers of the events delivered before the stream failed.
+    async fn drain(subscription: EventSubscription) -> Vec<u64> {
+        let items: Vec<_> = subscription.collect().await;
+        let (last, events) = items.split_last().unwrap();
+        assert!(matches!(last, Err(Error::Subscription(_))));
+        events
+            .iter()
+            .map(|event| event.as_ref().unwrap().id.event_seq)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_backfills_missed_events() {
+        let emitted: Vec<_> = (0..250).map(event).collect();
+        // Events 1 to 248 are emitted while the subscription is down.
+        let source = FakeSource::new(emitted, vec![vec![event(0)], vec![event(249)]]);
+        let subscriptions = source.subscriptions.clone();
+        let subscription =
+            EventSubscription::with_source(source, EventFilter::All(vec![]), None, no_retries())
+                .await
+                .unwrap();
+
+        assert_eq!(drain(subscription).await, (0..250).collect::<Vec<_>>());
+        assert_eq!(*subscriptions.lock().unwrap(), vec![false, true, true]);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_before_first_event_backfills_from_start() {
+        let emitted: Vec<_> = (0..10).map(event).collect();
+        // Events 0 to 2 were emitted before subscribing, and events 3 to 8 while the
+        // subscription is down, before it delivered any event.
+        let mut source = FakeSource::new(emitted, vec![vec![], vec![event(9)]]);
+        source.latest = Some(event(2).id);
+        let queries = source.queries.clone();
+        let subscription =
+            EventSubscription::with_source(source, EventFilter::All(vec![]), None, no_retries())
+                .await
+                .unwrap();
+
+        assert_eq!(drain(subscription).await, (3..10).collect::<Vec<_>>());
+        assert_eq!(*queries.lock().unwrap(), vec![Some(event(2).id)]);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_is_fetched_page_by_page() {
+        let emitted: Vec<_> = (0..250).map(event).collect();
+        let source = FakeSource::new(emitted, vec![vec![event(249)]]);
+        let queries = source.queries.clone();
+        let mut subscription = EventSubscription::with_source(
+            source,
+            EventFilter::All(vec![]),
+            Some(event(0).id),
+            no_retries(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(subscription.next().await.unwrap().unwrap().id, event(1).id);
+        // Only the first page is held until it is consumed.
+        assert_eq!(*queries.lock().unwrap(), vec![Some(event(0).id)]);
+        assert_eq!(drain(subscription).await, (2..250).collect::<Vec<_>>());
+        assert_eq!(
+            *queries.lock().unwrap(),
+            vec![Some(event(0).id), Some(event(100).id), Some(event(200).id)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_events_are_skipped() {
+        let source = FakeSource::new(
+            vec![],
+            vec![vec![event(0), event(1), event(0), event(1), event(2)]],
+        );
+        let mut subscription =
+            EventSubscription::with_source(source, EventFilter::All(vec![]), None, no_retries())
+                .await
+                .unwrap();
+
+        for seq in 0..3 {
+            assert_eq!(
+                subscription.next().await.unwrap().unwrap().id.event_seq,
+                seq
+            );
+        }
+        assert_eq!(subscription.cursor(), Some(event(2).id));
+        assert!(subscription.next().await.unwrap().is_err());
+        assert!(subscription.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_cursor() {
+        let emitted: Vec<_> = (0..5).map(event).collect();
+        let source = FakeSource::new(emitted, vec![vec![event(3), event(5)]]);
+        let subscription = EventSubscription::with_source(
+            source,
+            EventFilter::All(vec![]),
+            Some(event(1).id),
+            no_retries(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(drain(subscription).await, vec![2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_backfill_filter() {
+        let source = FakeSource::new(vec![], vec![vec![]]);
+        let result = EventSubscription::with_source(
+            source,
+            EventFilter::Package(ObjectID::ZERO),
+            None,
+            no_retries(),
+        )
+        .await;
+        assert!(matches!(result, Err(Error::Subscription(_))));
+    }
+}
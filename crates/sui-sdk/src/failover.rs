@@ -0,0 +1,574 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A client layer that spreads requests over several fullnode endpoints.
+//!
+//! [FailoverClient] keeps one [SuiClient] per endpoint, tracks the health of each one and
+//! retries failed requests on the next endpoint with exponential backoff and jitter. Whether a
+//! request may be retried at all is decided by [is_retryable], based on the [Idempotency] of the
+//! request and the error it failed with.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+use tracing::{debug, warn};
+
+use sui_types::error::{SuiErrorData, SuiErrorKind};
+
+use sui_json_rpc_types::{SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions};
+use sui_types::quorum_driver_types::ExecuteTransactionRequestType;
+use sui_types::transaction::Transaction;
+
+use crate::error::{Error, SuiRpcResult};
+use crate::json_rpc_error::TRANSIENT_ERROR_CODE;
+use crate::{SuiClient, SuiClientBuilder};
+
+/// Number of consecutive failures after which an endpoint is marked unhealthy.
+const DEFAULT_UNHEALTHY_THRESHOLD: u32 = 3;
+
+/// Whether a request can be sent again once it may have reached a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Idempotency {
+    /// Reads, which can be sent any number of times.
+    Idempotent,
+    /// Requests with side effects, like transaction execution, which are only sent again when
+    /// the previous attempt certainly had no effect.
+    NonIdempotent,
+}
+
+/// Return true if a request that failed with `error` can be sent again, to any endpoint.
+///
+/// Idempotent requests are retried after transport failures and errors reported by the node as
+/// transient or internal. Any other error is the node's final answer to the request.
+/// Non idempotent requests are only retried when they were never sent, or when the node
+/// reported that it did not process them because the validators are overloaded. A timeout or
+/// a dropped connection leaves them in an unknown state, and is returned to the caller.
+pub fn is_retryable(error: &Error, idempotency: Idempotency) -> bool {
+    match idempotency {
+        Idempotency::Idempotent => is_transient(error) || is_server_error(error),
+        Idempotency::NonIdempotent => is_not_processed(error),
+    }
+}
+
+/// Transport level failures, where the request may never have reached the node.
+fn is_transient(error: &Error) -> bool {
+    use jsonrpsee::core::Error as RpcError;
+    matches!(
+        error,
+        Error::RpcError(
+            RpcError::Transport(_)
+                | RpcError::RequestTimeout
+                | RpcError::RestartNeeded(_)
+                | RpcError::MaxSlotsExceeded
+        )
+    )
+}
+
+/// Failures where the request certainly had no effect.
+fn is_not_processed(error: &Error) -> bool {
+    match error {
+        Error::RpcError(jsonrpsee::core::Error::MaxSlotsExceeded) => true,
+        Error::RpcError(e) => overload_retry_after(e).is_some(),
+        _ => false,
+    }
+}
+
+/// Errors reported by the node as transient or internal, which are usually a sign of an
+/// overloaded or unhealthy node.
+fn is_server_error(error: &Error) -> bool {
+    use jsonrpsee::types::error::{CallError, INTERNAL_ERROR_CODE};
+    match error {
+        Error::RpcError(jsonrpsee::core::Error::Call(CallError::Custom(e))) => {
+            e.code() == INTERNAL_ERROR_CODE || e.code() == TRANSIENT_ERROR_CODE
+        }
+        _ => false,
+    }
+}
+
+/// If the node reported that the transaction was not processed because the validators are
+/// overloaded, returns the longest delay the validators asked clients to wait before retrying,
+/// or zero if they did not give one.
+pub(crate) fn overload_retry_after(error: &jsonrpsee::core::Error) -> Option<Duration> {
+    use jsonrpsee::types::error::CallError;
+    let jsonrpsee::core::Error::Call(CallError::Custom(e)) = error else {
+        return None;
+    };
+    if e.code() != TRANSIENT_ERROR_CODE {
+        return None;
+    }
+    let errors: Vec<SuiErrorData> = serde_json::from_str(e.data()?.get()).ok()?;
+    errors
+        .iter()
+        .filter(|error| error.kind == SuiErrorKind::Overload)
+        .map(|error| error.retry_after().unwrap_or_default())
+        .max()
+}
+
+/// Exponential backoff with full jitter.
+#[derive(Debug, Clone)]
+pub struct BackoffPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: u32,
+    pub max_retries: u32,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            multiplier: 2,
+            max_retries: 5,
+        }
+    }
+}
+
+impl BackoffPolicy {
+    /// Upper bound of the delay before the given (zero based) retry attempt.
+    pub fn max_delay_for_attempt(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(attempt);
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+
+    /// Delay before the given (zero based) retry attempt, picked uniformly in
+    /// `[0, max_delay_for_attempt(attempt)]`.
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let max = self.max_delay_for_attempt(attempt).as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(0..=max))
+    }
+}
+
+struct Endpoint<C> {
+    url: String,
+    client: C,
+    healthy: AtomicBool,
+    consecutive_failures: AtomicU32,
+}
+
+impl<C> Endpoint<C> {
+    fn new(url: String, client: C) -> Self {
+        Self {
+            url,
+            client,
+            healthy: AtomicBool::new(true),
+            consecutive_failures: AtomicU32::new(0),
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+        if !self.healthy.swap(true, Ordering::Relaxed) {
+            debug!("Endpoint {} is healthy again", self.url);
+        }
+    }
+
+    fn record_failure(&self, threshold: u32) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if failures >= threshold && self.healthy.swap(false, Ordering::Relaxed) {
+            warn!(
+                "Endpoint {} marked unhealthy after {failures} consecutive failures",
+                self.url
+            );
+        }
+    }
+}
+
+/// Builder for [FailoverClient].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sui_sdk::failover::FailoverClientBuilder;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), anyhow::Error> {
+///     let client = FailoverClientBuilder::default()
+///         .add_url("https://fullnode.testnet.sui.io:443")
+///         .add_url("http://127.0.0.1:9000")
+///         .build()
+///         .await?;
+///     let gas_price = client
+///         .call(|sui| async move { sui.read_api().get_reference_gas_price().await })
+///         .await?;
+///     println!("{gas_price}");
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct FailoverClientBuilder {
+    urls: Vec<String>,
+    client_builder: SuiClientBuilder,
+    backoff: Option<BackoffPolicy>,
+    unhealthy_threshold: Option<u32>,
+}
+
+impl FailoverClientBuilder {
+    /// Add a fullnode URL. Endpoints are tried in the order they were added.
+    pub fn add_url(mut self, url: impl AsRef<str>) -> Self {
+        self.urls.push(url.as_ref().to_string());
+        self
+    }
+
+    /// Set the builder used to create the client of every endpoint.
+    pub fn client_builder(mut self, client_builder: SuiClientBuilder) -> Self {
+        self.client_builder = client_builder;
+        self
+    }
+
+    /// Set the backoff policy applied between retries. By default, requests are retried until
+    /// every endpoint was tried at least once.
+    pub fn backoff(mut self, backoff: BackoffPolicy) -> Self {
+        self.backoff = Some(backoff);
+        self
+    }
+
+    /// Set the number of consecutive failures after which an endpoint is skipped.
+    pub fn unhealthy_threshold(mut self, threshold: u32) -> Self {
+        self.unhealthy_threshold = Some(threshold);
+        self
+    }
+
+    /// Connect to all endpoints. Endpoints that cannot be reached are skipped, but at least
+    /// one endpoint has to be available.
+    pub async fn build(self) -> SuiRpcResult<FailoverClient> {
+        if self.urls.is_empty() {
+            return Err(Error::DataError(
+                "At least one RPC URL is required to build a failover client.".into(),
+            ));
+        }
+        let mut endpoints = Vec::with_capacity(self.urls.len());
+        let mut last_error = None;
+        for url in self.urls {
+            match self.client_builder.clone().build(&url).await {
+                Ok(client) => endpoints.push(Endpoint::new(url, client)),
+                Err(e) => {
+                    warn!("Cannot connect to RPC endpoint {url}: {e}");
+                    last_error = Some(e);
+                }
+            }
+        }
+        if endpoints.is_empty() {
+            return Err(last_error.expect("at least one connection error"));
+        }
+        let backoff = self
+            .backoff
+            .unwrap_or_else(|| default_backoff(endpoints.len()));
+        Ok(FailoverClient {
+            inner: Arc::new(Inner {
+                endpoints,
+                backoff,
+                unhealthy_threshold: self
+                    .unhealthy_threshold
+                    .unwrap_or(DEFAULT_UNHEALTHY_THRESHOLD),
+            }),
+        })
+    }
+}
+
+/// The default [BackoffPolicy], with enough retries to try every endpoint at least once.
+fn default_backoff(num_endpoints: usize) -> BackoffPolicy {
+    let backoff = BackoffPolicy::default();
+    BackoffPolicy {
+        max_retries: backoff
+            .max_retries
+            .max(num_endpoints.saturating_sub(1) as u32),
+        ..backoff
+    }
+}
+
+struct Inner<C> {
+    endpoints: Vec<Endpoint<C>>,
+    backoff: BackoffPolicy,
+    unhealthy_threshold: u32,
+}
+
+/// A client that sends requests to the first healthy endpoint, failing over to the next one
+/// when a request fails with a retryable error.
+#[derive(Clone)]
+pub struct FailoverClient {
+    inner: Arc<Inner<SuiClient>>,
+}
+
+impl FailoverClient {
+    /// Run `f` against a healthy endpoint, and again against the next endpoints as long as it
+    /// fails with an error for which [is_retryable] holds. `f` must be idempotent, use
+    /// [FailoverClient::call_non_idempotent] for requests with side effects.
+    pub async fn call<T, F, Fut>(&self, f: F) -> SuiRpcResult<T>
+    where
+        F: Fn(SuiClient) -> Fut,
+        Fut: Future<Output = SuiRpcResult<T>>,
+    {
+        self.inner.call(Idempotency::Idempotent, f).await
+    }
+
+    /// Like [FailoverClient::call], but for requests with side effects: `f` is only run again
+    /// when the previous attempt certainly had no effect.
+    pub async fn call_non_idempotent<T, F, Fut>(&self, f: F) -> SuiRpcResult<T>
+    where
+        F: Fn(SuiClient) -> Fut,
+        Fut: Future<Output = SuiRpcResult<T>>,
+    {
+        self.inner.call(Idempotency::NonIdempotent, f).await
+    }
+
+    /// Execute a transaction, see [crate::apis::QuorumDriverApi::execute_transaction_block].
+    /// The transaction is only submitted to another endpoint when the previous one certainly
+    /// did not process it.
+    pub async fn execute_transaction_block(
+        &self,
+        tx: Transaction,
+        options: SuiTransactionBlockResponseOptions,
+        request_type: Option<ExecuteTransactionRequestType>,
+    ) -> SuiRpcResult<SuiTransactionBlockResponse> {
+        self.call_non_idempotent(|sui| {
+            let (tx, options, request_type) = (tx.clone(), options.clone(), request_type.clone());
+            async move {
+                sui.quorum_driver_api()
+                    .execute_transaction_block(tx, options, request_type)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// Probe every endpoint and update its health status.
+    pub async fn check_health(&self) {
+        for endpoint in &self.inner.endpoints {
+            match endpoint
+                .client
+                .read_api()
+                .get_latest_checkpoint_sequence_number()
+                .await
+            {
+                Ok(_) => endpoint.record_success(),
+                Err(e) => {
+                    debug!("Health check failed for {}: {e}", endpoint.url);
+                    endpoint.record_failure(self.inner.unhealthy_threshold);
+                }
+            }
+        }
+    }
+
+    /// Spawn a background task probing all endpoints every `interval`.
+    /// The task stops when the returned handle is aborted.
+    pub fn spawn_health_checker(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let client = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+            loop {
+                interval.tick().await;
+                client.check_health().await;
+            }
+        })
+    }
+
+    /// Returns the URLs of the endpoints currently considered healthy.
+    pub fn healthy_urls(&self) -> Vec<&str> {
+        self.inner
+            .endpoints
+            .iter()
+            .filter(|e| e.is_healthy())
+            .map(|e| e.url.as_str())
+            .collect()
+    }
+
+    /// Returns the client of the first healthy endpoint.
+    pub fn client(&self) -> &SuiClient {
+        &self.inner.endpoint_order()[0].client
+    }
+}
+
+impl<C: Clone> Inner<C> {
+    async fn call<T, F, Fut>(&self, idempotency: Idempotency, f: F) -> SuiRpcResult<T>
+    where
+        F: Fn(C) -> Fut,
+        Fut: Future<Output = SuiRpcResult<T>>,
+    {
+        // Endpoints marked unhealthy by this call keep their place, so that every endpoint is
+        // tried once before any is tried again.
+        let endpoints = self.endpoint_order();
+        let mut attempt = 0;
+        loop {
+            let endpoint = endpoints[attempt as usize % endpoints.len()];
+            match f(endpoint.client.clone()).await {
+                Ok(result) => {
+                    endpoint.record_success();
+                    return Ok(result);
+                }
+                Err(e) => {
+                    if !is_retryable(&e, idempotency) {
+                        return Err(e);
+                    }
+                    endpoint.record_failure(self.unhealthy_threshold);
+                    if attempt >= self.backoff.max_retries {
+                        return Err(e);
+                    }
+                    let delay = self.backoff.delay_for_attempt(attempt);
+                    debug!(
+                        "Request failed on {}: {e}, retrying in {delay:?}",
+                        endpoint.url
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// All endpoints, healthy ones first, each group in the order the endpoints were added.
+    /// Unhealthy endpoints are only tried once all healthy ones failed, so that requests still
+    /// have a chance to go through when no endpoint is healthy.
+    fn endpoint_order(&self) -> Vec<&Endpoint<C>> {
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) =
+            self.endpoints.iter().partition(|e| e.is_healthy());
+        healthy.extend(unhealthy);
+        healthy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn inner(urls: &[&'static str], unhealthy_threshold: u32) -> Inner<&'static str> {
+        Inner {
+            endpoints: urls
+                .iter()
+                .map(|url| Endpoint::new(url.to_string(), *url))
+                .collect(),
+            backoff: BackoffPolicy {
+                initial_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(1),
+                multiplier: 1,
+                max_retries: 5,
+            },
+            unhealthy_threshold,
+        }
+    }
+
+    /// Calls `inner`, failing with `error` on the endpoints in `failing`, and returns the
+    /// result along with the endpoints called, in order.
+    async fn call(
+        inner: &Inner<&'static str>,
+        failing: &[&str],
+        error: fn() -> Error,
+    ) -> (SuiRpcResult<&'static str>, Vec<&'static str>) {
+        call_with(inner, Idempotency::Idempotent, failing, error).await
+    }
+
+    async fn call_with(
+        inner: &Inner<&'static str>,
+        idempotency: Idempotency,
+        failing: &[&str],
+        error: fn() -> Error,
+    ) -> (SuiRpcResult<&'static str>, Vec<&'static str>) {
+        let calls = Mutex::new(vec![]);
+        let result = inner
+            .call(idempotency, |url| {
+                calls.lock().unwrap().push(url);
+                let result = if failing.contains(&url) {
+                    Err(error())
+                } else {
+                    Ok(url)
+                };
+                async move { result }
+            })
+            .await;
+        (result, calls.into_inner().unwrap())
+    }
+
+    fn timeout() -> Error {
+        Error::RpcError(jsonrpsee::core::Error::RequestTimeout)
+    }
+
+    #[tokio::test]
+    async fn test_fails_over_to_next_endpoint() {
+        let inner = inner(&["a", "b", "c"], 1);
+
+        let (result, calls) = call(&inner, &["a", "b"], timeout).await;
+        assert_eq!(result.unwrap(), "c");
+        assert_eq!(calls, vec!["a", "b", "c"]);
+
+        // Endpoints that failed are now tried last.
+        let (result, calls) = call(&inner, &[], timeout).await;
+        assert_eq!(result.unwrap(), "c");
+        assert_eq!(calls, vec!["c"]);
+        assert_eq!(
+            inner
+                .endpoint_order()
+                .iter()
+                .map(|e| e.client)
+                .collect::<Vec<_>>(),
+            vec!["c", "a", "b"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tries_each_endpoint_in_turn() {
+        let inner = inner(&["a", "b", "c"], 1);
+
+        // Marking endpoints unhealthy during the call neither skips nor repeats any endpoint.
+        let (result, calls) = call(&inner, &["a", "b", "c"], timeout).await;
+        assert!(result.is_err());
+        assert_eq!(calls, vec!["a", "b", "c", "a", "b", "c"]);
+        assert!(inner.endpoints.iter().all(|e| !e.is_healthy()));
+
+        // A success restores the health of the endpoint.
+        let (result, calls) = call(&inner, &["a"], timeout).await;
+        assert_eq!(result.unwrap(), "b");
+        assert_eq!(calls, vec!["a", "b"]);
+        assert!(inner.endpoints[1].is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_final_errors_are_not_retried() {
+        let inner = inner(&["a", "b"], 1);
+
+        let (result, calls) = call(&inner, &["a"], || {
+            Error::DataError("object not found".to_string())
+        })
+        .await;
+        assert!(matches!(result, Err(Error::DataError(_))));
+        assert_eq!(calls, vec!["a"]);
+        assert!(inner.endpoints[0].is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_requests_are_not_replayed() {
+        let inner = inner(&["a", "b"], 1);
+
+        // The request may have been processed before it timed out.
+        let (result, calls) = call_with(&inner, Idempotency::NonIdempotent, &["a"], timeout).await;
+        assert!(matches!(result, Err(Error::RpcError(_))));
+        assert_eq!(calls, vec!["a"]);
+
+        // The request was never sent.
+        let (result, calls) = call_with(&inner, Idempotency::NonIdempotent, &["a"], || {
+            Error::RpcError(jsonrpsee::core::Error::MaxSlotsExceeded)
+        })
+        .await;
+        assert_eq!(result.unwrap(), "b");
+        assert_eq!(calls, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_default_backoff_covers_every_endpoint() {
+        let default_retries = BackoffPolicy::default().max_retries;
+        assert_eq!(default_backoff(1).max_retries, default_retries);
+        assert_eq!(
+            default_backoff(default_retries as usize + 3).max_retries,
+            default_retries + 2
+        );
+    }
+}
@@ -19,6 +19,9 @@
 //! objects and transactions
 //! * [TransactionBuilder] - provides functions for building transactions
 //!
+//! For services that need to survive a fullnode outage, [failover::FailoverClient] spreads
+//! requests over several endpoints with health checking and retries.
+//!
 //! # Usage
 //! The main way to interact with the API is through the [SuiClientBuilder],
 //! which returns a [SuiClient] object from which the user can access the
@@ -82,17 +85,20 @@ use jsonrpsee::rpc_params;
 use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
 use serde_json::Value;
 
-use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::{StructTag, TypeTag};
 pub use sui_json as json;
 use sui_json_rpc_api::{
-    CLIENT_SDK_TYPE_HEADER, CLIENT_SDK_VERSION_HEADER, CLIENT_TARGET_API_VERSION_HEADER,
+    CoinReadApiClient, CLIENT_SDK_TYPE_HEADER, CLIENT_SDK_VERSION_HEADER,
+    CLIENT_TARGET_API_VERSION_HEADER,
 };
 pub use sui_json_rpc_types as rpc_types;
 use sui_json_rpc_types::{
-    ObjectsPage, SuiObjectDataFilter, SuiObjectDataOptions, SuiObjectResponse,
+    CoinPage, ObjectsPage, SuiObjectDataFilter, SuiObjectDataOptions, SuiObjectResponse,
     SuiObjectResponseQuery,
 };
-use sui_transaction_builder::{DataReader, TransactionBuilder};
+use sui_protocol_config::ProtocolConfig;
+use sui_transaction_builder::coin_selection::CoinSelector;
+use sui_transaction_builder::{protocol_config_for_version, DataReader, TransactionBuilder};
 pub use sui_types as types;
 use sui_types::base_types::{ObjectID, ObjectInfo, SuiAddress};
 
@@ -101,6 +107,8 @@ use crate::error::{Error, SuiRpcResult};
 
 pub mod apis;
 pub mod error;
+pub mod event_subscription;
+pub mod failover;
 pub mod json_rpc_error;
 pub mod sui_client_config;
 pub mod wallet_context;
@@ -134,11 +142,13 @@ pub const SUI_TESTNET_URL: &str = "https://fullnode.testnet.sui.io:443";
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct SuiClientBuilder {
     request_timeout: Duration,
     max_concurrent_requests: usize,
     ws_url: Option<String>,
     ws_ping_interval: Option<Duration>,
+    coin_selector: Option<Arc<dyn CoinSelector>>,
 }
 
 impl Default for SuiClientBuilder {
@@ -148,6 +158,7 @@ impl Default for SuiClientBuilder {
             max_concurrent_requests: 256,
             ws_url: None,
             ws_ping_interval: None,
+            coin_selector: None,
         }
     }
 }
@@ -177,6 +188,12 @@ impl SuiClientBuilder {
         self
     }
 
+    /// Set the policy the transaction builder uses to choose gas and payment coins
+    pub fn coin_selector(mut self, coin_selector: Arc<dyn CoinSelector>) -> Self {
+        self.coin_selector = Some(coin_selector);
+        self
+    }
+
     /// Returns a [SuiClient] object connected to the Sui network running at the URI provided.
     ///
     /// # Examples
@@ -208,7 +225,7 @@ impl SuiClientBuilder {
         );
         headers.insert(CLIENT_SDK_TYPE_HEADER, HeaderValue::from_static("rust"));
 
-        let ws = if let Some(url) = self.ws_url {
+        let (ws, ws_builder) = if let Some(url) = self.ws_url {
             let mut builder = WsClientBuilder::default()
                 .max_request_body_size(2 << 30)
                 .max_concurrent_requests(self.max_concurrent_requests)
@@ -219,9 +236,10 @@ impl SuiClientBuilder {
                 builder = builder.ping_interval(duration)
             }
 
-            Some(builder.build(url).await?)
+            let ws = builder.clone().build(&url).await?;
+            (Some(ws), Some((builder, url)))
         } else {
-            None
+            (None, None)
         };
 
         let http = HttpClientBuilder::default()
@@ -233,12 +251,20 @@ impl SuiClientBuilder {
 
         let info = Self::get_server_info(&http, &ws).await?;
 
-        let rpc = RpcClient { http, ws, info };
+        let rpc = RpcClient {
+            http,
+            ws,
+            ws_builder,
+            info,
+        };
         let api = Arc::new(rpc);
         let read_api = Arc::new(ReadApi::new(api.clone()));
         let quorum_driver_api = QuorumDriverApi::new(api.clone());
         let event_api = EventApi::new(api.clone());
-        let transaction_builder = TransactionBuilder::new(read_api.clone());
+        let mut transaction_builder = TransactionBuilder::new(read_api.clone());
+        if let Some(coin_selector) = self.coin_selector {
+            transaction_builder = transaction_builder.with_coin_selector(coin_selector);
+        }
         let coin_read_api = CoinReadApi::new(api.clone());
         let governance_api = GovernanceApi::new(api.clone());
 
@@ -419,9 +445,23 @@ pub struct SuiClient {
 pub(crate) struct RpcClient {
     http: HttpClient,
     ws: Option<WsClient>,
+    /// Builder and URL used to create `ws`, kept around to reconnect subscriptions.
+    ws_builder: Option<(WsClientBuilder, String)>,
     info: ServerInfo,
 }
 
+impl RpcClient {
+    /// Open a new WebSocket connection to the same endpoint as `ws`.
+    pub(crate) async fn reconnect_ws(&self) -> SuiRpcResult<WsClient> {
+        match &self.ws_builder {
+            Some((builder, url)) => Ok(builder.clone().build(url).await?),
+            None => Err(Error::Subscription(
+                "Subscription only supported by WebSocket client.".to_string(),
+            )),
+        }
+    }
+}
+
 impl Debug for RpcClient {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -560,8 +600,27 @@ impl DataReader for ReadApi {
         Ok(self.get_object_with_options(object_id, options).await?)
     }
 
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: TypeTag,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<CoinPage, anyhow::Error> {
+        Ok(self
+            .api
+            .http
+            .get_coins(owner, Some(coin_type.to_string()), cursor, Some(limit))
+            .await?)
+    }
+
     /// Returns the reference gas price as a u64 or an error otherwise
     async fn get_reference_gas_price(&self) -> Result<u64, anyhow::Error> {
         Ok(self.get_reference_gas_price().await?)
     }
+
+    async fn get_protocol_config(&self) -> Result<ProtocolConfig, anyhow::Error> {
+        let response = self.get_protocol_config(None).await?;
+        Ok(protocol_config_for_version(response.protocol_version))
+    }
 }
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::fmt::{Display, Formatter, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use anyhow::anyhow;
 use serde::{Deserialize, Serialize};
@@ -9,7 +11,8 @@ use serde_with::serde_as;
 
 use crate::{SuiClient, SuiClientBuilder, SUI_DEVNET_URL, SUI_LOCAL_NETWORK_URL, SUI_TESTNET_URL};
 use sui_config::Config;
-use sui_keys::keystore::{AccountKeystore, Keystore};
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_transaction_builder::coin_selection::CoinSelector;
 use sui_types::base_types::*;
 
 #[serde_as]
@@ -48,6 +51,51 @@ impl SuiClientConfig {
         })
     }
 
+    /// Make the environment with the given alias the active one.
+    ///
+    /// The active address is remembered by the environment being left, and the one remembered
+    /// by the new environment is restored. The keystore configured for the new environment, if
+    /// any, replaces the one in use. The active address falls back to the current one, or to
+    /// the first address of the keystore, if the environment has none or it is not managed by
+    /// the keystore.
+    pub fn switch_env(&mut self, alias: &str) -> Result<(), anyhow::Error> {
+        let new_env = self
+            .envs
+            .iter()
+            .position(|env| env.alias == alias)
+            .ok_or_else(|| {
+                anyhow!("Environment config not found for [{alias}], add new environment config using the `sui client new-env` command.")
+            })?;
+        let current_keystore = match &self.keystore {
+            Keystore::File(keystore) => keystore.path().cloned(),
+            Keystore::InMem(_) => None,
+        };
+        let new_keystore = self.envs[new_env].keystore.clone();
+
+        if let Some(current_env) = self
+            .active_env
+            .as_ref()
+            .and_then(|active| self.envs.iter_mut().find(|env| &env.alias == active))
+        {
+            current_env.active_address = self.active_address;
+        }
+
+        if let Some(path) = new_keystore {
+            if Some(&path) != current_keystore.as_ref() {
+                self.keystore = Keystore::from(FileBasedKeystore::new(&path)?);
+            }
+        }
+
+        let addresses = self.keystore.addresses();
+        self.active_address = [self.envs[new_env].active_address, self.active_address]
+            .into_iter()
+            .flatten()
+            .find(|address| addresses.contains(address))
+            .or_else(|| addresses.first().copied());
+        self.active_env = Some(alias.to_string());
+        Ok(())
+    }
+
     pub fn add_env(&mut self, env: SuiEnv) {
         if !self
             .envs
@@ -64,6 +112,13 @@ pub struct SuiEnv {
     pub alias: String,
     pub rpc: String,
     pub ws: Option<String>,
+    /// The address made active when switching to this environment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_address: Option<SuiAddress>,
+    /// The keystore used in this environment. If not set, the keystore in use is kept when
+    /// switching to the environment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keystore: Option<PathBuf>,
 }
 
 impl SuiEnv {
@@ -71,6 +126,16 @@ impl SuiEnv {
         &self,
         request_timeout: Option<std::time::Duration>,
         max_concurrent_requests: Option<u64>,
+    ) -> Result<SuiClient, anyhow::Error> {
+        self.create_rpc_client_with_coin_selector(request_timeout, max_concurrent_requests, None)
+            .await
+    }
+
+    pub async fn create_rpc_client_with_coin_selector(
+        &self,
+        request_timeout: Option<std::time::Duration>,
+        max_concurrent_requests: Option<u64>,
+        coin_selector: Option<Arc<dyn CoinSelector>>,
     ) -> Result<SuiClient, anyhow::Error> {
         let mut builder = SuiClientBuilder::default();
         if let Some(request_timeout) = request_timeout {
@@ -83,6 +148,9 @@ impl SuiEnv {
         if let Some(max_concurrent_requests) = max_concurrent_requests {
             builder = builder.max_concurrent_requests(max_concurrent_requests as usize);
         }
+        if let Some(coin_selector) = coin_selector {
+            builder = builder.coin_selector(coin_selector);
+        }
         Ok(builder.build(&self.rpc).await?)
     }
 
@@ -91,6 +159,8 @@ impl SuiEnv {
             alias: "devnet".to_string(),
             rpc: SUI_DEVNET_URL.into(),
             ws: None,
+            active_address: None,
+            keystore: None,
         }
     }
     pub fn testnet() -> Self {
@@ -98,6 +168,8 @@ impl SuiEnv {
             alias: "testnet".to_string(),
             rpc: SUI_TESTNET_URL.into(),
             ws: None,
+            active_address: None,
+            keystore: None,
         }
     }
 
@@ -106,6 +178,8 @@ impl SuiEnv {
             alias: "local".to_string(),
             rpc: SUI_LOCAL_NETWORK_URL.into(),
             ws: None,
+            active_address: None,
+            keystore: None,
         }
     }
 }
@@ -119,6 +193,10 @@ impl Display for SuiEnv {
             writeln!(writer)?;
             write!(writer, "Websocket URL: {ws}")?;
         }
+        if let Some(keystore) = &self.keystore {
+            writeln!(writer)?;
+            write!(writer, "Keystore: {}", keystore.display())?;
+        }
         write!(f, "{}", writer)
     }
 }
@@ -15,6 +15,7 @@ use sui_json_rpc_types::{
     SuiObjectResponseQuery, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
 };
 use sui_keys::keystore::AccountKeystore;
+use sui_transaction_builder::coin_selection::CoinSelector;
 use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
 use sui_types::gas_coin::GasCoin;
 use sui_types::transaction::{Transaction, TransactionData, TransactionDataAPI};
@@ -26,6 +27,7 @@ pub struct WalletContext {
     request_timeout: Option<std::time::Duration>,
     client: Arc<RwLock<Option<SuiClient>>>,
     max_concurrent_requests: Option<u64>,
+    coin_selector: Option<Arc<dyn CoinSelector>>,
 }
 
 impl WalletContext {
@@ -47,10 +49,17 @@ impl WalletContext {
             request_timeout,
             client: Default::default(),
             max_concurrent_requests,
+            coin_selector: None,
         };
         Ok(context)
     }
 
+    /// Set the policy the transaction builder of the client uses to choose gas and payment
+    /// coins. Only applies to a client that has not been created yet.
+    pub fn set_coin_selector(&mut self, coin_selector: Arc<dyn CoinSelector>) {
+        self.coin_selector = Some(coin_selector);
+    }
+
     pub fn get_addresses(&self) -> Vec<SuiAddress> {
         self.config.keystore.addresses()
     }
@@ -65,7 +74,11 @@ impl WalletContext {
             let client = self
                 .config
                 .get_active_env()?
-                .create_rpc_client(self.request_timeout, self.max_concurrent_requests)
+                .create_rpc_client_with_coin_selector(
+                    self.request_timeout,
+                    self.max_concurrent_requests,
+                    self.coin_selector.clone(),
+                )
                 .await?;
             if let Err(e) = client.check_api_version() {
                 warn!("{e}");
@@ -1,8 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
+use std::time::Duration;
 use tempfile::TempDir;
 
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_sdk::error::Error;
+use sui_sdk::failover::{is_retryable, BackoffPolicy};
+use sui_sdk::json_rpc_error::TRANSIENT_ERROR_CODE;
 use sui_types::crypto::Ed25519SuiSignature;
 use sui_types::crypto::{SignatureScheme, SuiSignatureInner};
 #[test]
@@ -17,7 +21,7 @@ fn mnemonic_test() {
     let keystore_path_2 = temp_dir.path().join("sui2.keystore");
     let mut keystore2 = Keystore::from(FileBasedKeystore::new(&keystore_path_2).unwrap());
     let imported_address = keystore2
-        .import_from_mnemonic(&phrase, SignatureScheme::ED25519, None)
+        .import_from_mnemonic(&phrase, SignatureScheme::ED25519, None, None)
         .unwrap();
     assert_eq!(scheme.flag(), Ed25519SuiSignature::SCHEME.flag());
     assert_eq!(address, imported_address);
@@ -32,3 +36,36 @@ fn keystore_display_test() -> Result<(), anyhow::Error> {
     assert!(!keystore.to_string().contains("keys:"));
     Ok(())
 }
+
+#[test]
+fn is_retryable_test() {
+    use jsonrpsee::core::Error as RpcError;
+    use jsonrpsee::types::error::{CallError, ErrorObject, INTERNAL_ERROR_CODE};
+
+    let call_error = |code| {
+        Error::RpcError(RpcError::Call(CallError::Custom(ErrorObject::owned(
+            code, "error", None::<()>,
+        ))))
+    };
+    assert!(is_retryable(&Error::RpcError(RpcError::RequestTimeout)));
+    assert!(is_retryable(&call_error(INTERNAL_ERROR_CODE)));
+    assert!(is_retryable(&call_error(TRANSIENT_ERROR_CODE)));
+    assert!(!is_retryable(&call_error(-32602)));
+    assert!(!is_retryable(&Error::Subscription("closed".to_string())));
+}
+
+#[test]
+fn backoff_policy_test() {
+    let policy = BackoffPolicy {
+        initial_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(1),
+        multiplier: 2,
+        max_retries: 10,
+    };
+    assert_eq!(policy.max_delay_for_attempt(0), Duration::from_millis(100));
+    assert_eq!(policy.max_delay_for_attempt(2), Duration::from_millis(400));
+    assert_eq!(policy.max_delay_for_attempt(8), Duration::from_secs(1));
+    for attempt in 0..10 {
+        assert!(policy.delay_for_attempt(attempt) <= policy.max_delay_for_attempt(attempt));
+    }
+}
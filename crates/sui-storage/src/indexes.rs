@@ -10,7 +10,8 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
+use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
 use prometheus::{register_int_counter_with_registry, IntCounter, Registry};
@@ -26,7 +27,7 @@ use sui_json_rpc_types::{SuiObjectDataFilter, TransactionFilter};
 use sui_types::base_types::{
     ObjectDigest, ObjectID, SequenceNumber, SuiAddress, TransactionDigest, TxSequenceNumber,
 };
-use sui_types::base_types::{ObjectInfo, ObjectRef};
+use sui_types::base_types::{ObjectInfo, ObjectRef, ObjectType};
 use sui_types::digests::TransactionEventsDigest;
 use sui_types::dynamic_field::{self, DynamicFieldInfo};
 use sui_types::effects::TransactionEvents;
@@ -35,7 +36,7 @@ use sui_types::inner_temporary_store::TxCoins;
 use sui_types::object::{Object, Owner};
 use sui_types::parse_sui_struct_tag;
 use tokio::task::spawn_blocking;
-use tracing::{debug, trace};
+use tracing::{debug, info, trace};
 use typed_store::rocks::{
     default_db_options, read_size_from_env, DBBatch, DBMap, DBOptions, MetricConf,
 };
@@ -44,6 +45,12 @@ use typed_store::traits::{TableSummary, TypedStoreDebug};
 use typed_store_derive::DBMapUtils;
 
 type OwnerIndexKey = (SuiAddress, ObjectID);
+/// The type of an object split into its package, module, name and type params in canonical form.
+/// Keeping each part in its own field keeps the keys of the types in a package, a module or of a
+/// struct with any type params next to each other, which a single string of variable length
+/// does not.
+type OwnerType = (AccountAddress, String, String, String);
+type OwnerTypeIndexKey = (SuiAddress, OwnerType, ObjectID);
 type CoinIndexKey = (SuiAddress, String, ObjectID);
 type DynamicFieldKey = (ObjectID, ObjectID);
 type EventId = (TxSequenceNumber, usize);
@@ -189,6 +196,12 @@ pub struct IndexStoreTables {
     #[default_options_override_fn = "owner_index_table_default_config"]
     owner_index: DBMap<OwnerIndexKey, ObjectInfo>,
 
+    /// The entries of `owner_index` for Move objects, indexed by the composite key of their
+    /// owner, their type and their object ID, so that the objects of an owner can be filtered by
+    /// type with a range scan.
+    #[default_options_override_fn = "owner_index_table_default_config"]
+    owner_type_index: DBMap<OwnerTypeIndexKey, ObjectInfo>,
+
     #[default_options_override_fn = "coin_index_table_default_config"]
     coin_index: DBMap<CoinIndexKey, CoinInfo>,
 
@@ -221,6 +234,10 @@ impl IndexStoreTables {
         &self.owner_index
     }
 
+    pub fn owner_type_index(&self) -> &DBMap<OwnerTypeIndexKey, ObjectInfo> {
+        &self.owner_type_index
+    }
+
     pub fn coin_index(&self) -> &DBMap<CoinIndexKey, CoinInfo> {
         &self.coin_index
     }
@@ -276,6 +293,119 @@ fn coin_index_table_default_config() -> DBOptions {
         )
 }
 
+/// The key of an object in the owner type index, None for packages.
+pub fn owner_type_index_key(owner: SuiAddress, info: &ObjectInfo) -> Option<OwnerTypeIndexKey> {
+    match &info.type_ {
+        ObjectType::Struct(type_) => Some((
+            owner,
+            (
+                type_.address(),
+                type_.module().to_string(),
+                type_.name().to_string(),
+                type_params_key(&type_.type_params()),
+            ),
+            info.object_id,
+        )),
+        ObjectType::Package => None,
+    }
+}
+
+fn type_params_key(type_params: &[TypeTag]) -> String {
+    type_params
+        .iter()
+        .map(|type_param| type_param.to_canonical_string(true))
+        .join(",")
+}
+
+fn owner_type_index_entries(
+    new_owners: &[(OwnerIndexKey, ObjectInfo)],
+) -> Vec<(OwnerTypeIndexKey, ObjectInfo)> {
+    new_owners
+        .iter()
+        .filter_map(|((owner, _), info)| Some((owner_type_index_key(*owner, info)?, info.clone())))
+        .collect()
+}
+
+/// The leading parts of the type shared by all the objects that match a filter: the package,
+/// then optionally the module, the name and the type params.
+struct OwnerTypePrefix {
+    package: AccountAddress,
+    module: Option<String>,
+    name: Option<String>,
+    type_params: Option<String>,
+}
+
+impl OwnerTypePrefix {
+    /// Returns the prefix shared by the types of all the objects that match `filter`, if the
+    /// filter can be answered by a range scan of the owner type index.
+    fn from_filter(filter: &SuiObjectDataFilter) -> Option<Self> {
+        match filter {
+            SuiObjectDataFilter::StructType(type_) => Some(Self {
+                package: type_.address,
+                module: Some(type_.module.to_string()),
+                name: Some(type_.name.to_string()),
+                // A struct type without type params matches all of its instantiations.
+                type_params: (!type_.type_params.is_empty())
+                    .then(|| type_params_key(&type_.type_params)),
+            }),
+            SuiObjectDataFilter::MoveModule { package, module } => Some(Self {
+                package: (*package).into(),
+                module: Some(module.to_string()),
+                name: None,
+                type_params: None,
+            }),
+            SuiObjectDataFilter::Package(package) => Some(Self {
+                package: (*package).into(),
+                module: None,
+                name: None,
+                type_params: None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The smallest type with this prefix, the empty string sorting before any other.
+    fn first_type(&self) -> OwnerType {
+        (
+            self.package,
+            self.module.clone().unwrap_or_default(),
+            self.name.clone().unwrap_or_default(),
+            self.type_params.clone().unwrap_or_default(),
+        )
+    }
+
+    fn matches(&self, (package, module, name, type_params): &OwnerType) -> bool {
+        fn part_matches(prefix: &Option<String>, part: &str) -> bool {
+            prefix.as_ref().map_or(true, |prefix| prefix == part)
+        }
+        &self.package == package
+            && part_matches(&self.module, module)
+            && part_matches(&self.name, name)
+            && part_matches(&self.type_params, type_params)
+    }
+}
+
+/// Populates the owner type index from the owner index, for a database created before the owner
+/// type index was added.
+fn backfill_owner_type_index(tables: &IndexStoreTables) -> SuiResult {
+    if !tables.owner_type_index.is_empty() || tables.owner_index.is_empty() {
+        return Ok(());
+    }
+    info!("Backfilling the owner type index from the owner index");
+    let mut num_entries = 0;
+    for chunk in &tables.owner_index.unbounded_iter().chunks(10_000) {
+        let entries = chunk
+            .filter_map(|((owner, _), info)| Some((owner_type_index_key(owner, &info)?, info)))
+            .collect::<Vec<_>>();
+        num_entries += entries.len();
+        let mut batch = tables.owner_type_index.batch();
+        batch.insert_batch(&tables.owner_type_index, entries)?;
+        batch.write()?;
+    }
+    info!("Backfilled {num_entries} entries of the owner type index");
+    Ok(())
+}
+
 impl IndexStore {
     pub fn new(path: PathBuf, registry: &Registry, max_type_length: Option<u64>) -> Self {
         let tables =
@@ -286,6 +416,7 @@ impl IndexStore {
             all_balances: ShardedLruCache::new(1_000_000, 1000),
             locks: MutexTable::new(128),
         };
+        backfill_owner_type_index(&tables).expect("Failed to backfill the owner type index");
         let next_sequence_number = tables
             .transaction_order
             .unbounded_iter()
@@ -518,7 +649,17 @@ impl IndexStore {
             .index_coin(digest, &mut batch, &object_index_changes, tx_coins)
             .await?;
 
-        // Owner index
+        // Owner index. The type of a deleted entry is read from the index before the
+        // transaction is committed.
+        let deleted_owner_types = self
+            .tables
+            .owner_index
+            .multi_get(&object_index_changes.deleted_owners)?
+            .into_iter()
+            .zip(&object_index_changes.deleted_owners)
+            .filter_map(|(info, (owner, _))| owner_type_index_key(*owner, &info?))
+            .collect::<Vec<_>>();
+        batch.delete_batch(&self.tables.owner_type_index, deleted_owner_types)?;
         batch.delete_batch(
             &self.tables.owner_index,
             object_index_changes.deleted_owners.into_iter(),
@@ -528,6 +669,10 @@ impl IndexStore {
             object_index_changes.deleted_dynamic_fields.into_iter(),
         )?;
 
+        batch.insert_batch(
+            &self.tables.owner_type_index,
+            owner_type_index_entries(&object_index_changes.new_owners),
+        )?;
         batch.insert_batch(
             &self.tables.owner_index,
             object_index_changes.new_owners.into_iter(),
@@ -1275,32 +1420,68 @@ impl IndexStore {
 
     /// starting_object_id can be used to implement pagination, where a client remembers the last
     /// object id of each page, and use it to query the next page.
+    ///
+    /// Filters by type are answered by a range scan of the owner type index, and the objects are
+    /// then ordered by type first.
     pub fn get_owner_objects_iterator(
         &self,
         owner: SuiAddress,
         starting_object_id: ObjectID,
         filter: Option<SuiObjectDataFilter>,
     ) -> SuiResult<impl Iterator<Item = ObjectInfo> + '_> {
-        Ok(self
-            .tables
-            .owner_index
-            .unbounded_iter()
-            // The object id 0 is the smallest possible
-            .skip_to(&(owner, starting_object_id))?
-            .skip(usize::from(starting_object_id != ObjectID::ZERO))
-            .take_while(move |((address_owner, _), _)| address_owner == &owner)
-            .filter(move |(_, o)| {
-                if let Some(filter) = filter.as_ref() {
-                    filter.matches(o)
-                } else {
-                    true
-                }
-            })
-            .map(|(_, object_info)| object_info))
+        if let Some(type_prefix) = filter.as_ref().and_then(OwnerTypePrefix::from_filter) {
+            // The cursor is resumed from its type. If it is no longer owned by `owner`, its type
+            // is unknown, and all the objects of `owner` are scanned instead.
+            let starting_type = if starting_object_id == ObjectID::ZERO {
+                Some(type_prefix.first_type())
+            } else {
+                self.tables
+                    .owner_index
+                    .get(&(owner, starting_object_id))?
+                    .and_then(|info| owner_type_index_key(owner, &info))
+                    .map(|(_, type_, _)| type_)
+            };
+            if let Some(starting_type) = starting_type {
+                return Ok(Either::Left(
+                    self.tables
+                        .owner_type_index
+                        .unbounded_iter()
+                        .skip_to(&(owner, starting_type, starting_object_id))?
+                        .skip(usize::from(starting_object_id != ObjectID::ZERO))
+                        .take_while(move |((address_owner, type_, _), _)| {
+                            address_owner == &owner && type_prefix.matches(type_)
+                        })
+                        .filter(move |(_, o)| filter.as_ref().map_or(true, |f| f.matches(o)))
+                        .map(|(_, object_info)| object_info),
+                ));
+            }
+        }
+
+        Ok(Either::Right(
+            self.tables
+                .owner_index
+                .unbounded_iter()
+                // The object id 0 is the smallest possible
+                .skip_to(&(owner, starting_object_id))?
+                .skip(usize::from(starting_object_id != ObjectID::ZERO))
+                .take_while(move |((address_owner, _), _)| address_owner == &owner)
+                .filter(move |(_, o)| {
+                    if let Some(filter) = filter.as_ref() {
+                        filter.matches(o)
+                    } else {
+                        true
+                    }
+                })
+                .map(|(_, object_info)| object_info),
+        ))
     }
 
     pub fn insert_genesis_objects(&self, object_index_changes: ObjectIndexChanges) -> SuiResult {
         let mut batch = self.tables.owner_index.batch();
+        batch.insert_batch(
+            &self.tables.owner_type_index,
+            owner_type_index_entries(&object_index_changes.new_owners),
+        )?;
         batch.insert_batch(
             &self.tables.owner_index,
             object_index_changes.new_owners.into_iter(),
@@ -1587,15 +1768,21 @@ mod tests {
     use crate::indexes::ObjectIndexChanges;
     use crate::IndexStore;
     use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
     use prometheus::Registry;
     use std::collections::BTreeMap;
     use std::env::temp_dir;
-    use sui_types::base_types::{ObjectInfo, ObjectType, SuiAddress};
+    use sui_json_rpc_types::SuiObjectDataFilter;
+    use sui_types::base_types::{
+        ObjectDigest, ObjectID, ObjectInfo, ObjectType, SequenceNumber, SuiAddress,
+    };
     use sui_types::digests::TransactionDigest;
     use sui_types::effects::TransactionEvents;
-    use sui_types::gas_coin::GAS;
+    use sui_types::error::SuiResult;
+    use sui_types::gas_coin::{GasCoin, GAS};
     use sui_types::object;
     use sui_types::object::Owner;
+    use sui_types::parse_sui_struct_tag;
 
     #[tokio::test]
     async fn test_index_cache() -> anyhow::Result<()> {
@@ -1721,6 +1908,111 @@ mod tests {
         assert_eq!(balance.balance, 700);
         assert_eq!(balance.num_coins, 7);
 
+        Ok(())
+    }
+    async fn index_object_changes(
+        index_store: &IndexStore,
+        sender: SuiAddress,
+        object_index_changes: ObjectIndexChanges,
+    ) -> SuiResult<u64> {
+        index_store
+            .index_tx(
+                sender,
+                vec![].into_iter(),
+                vec![].into_iter(),
+                vec![].into_iter(),
+                &TransactionEvents { data: vec![] },
+                object_index_changes,
+                &TransactionDigest::random(),
+                1234,
+                None,
+                &BTreeMap::new(),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_owner_objects_by_type() -> anyhow::Result<()> {
+        let index_store = IndexStore::new(
+            tempfile::tempdir()?.into_path(),
+            &Registry::default(),
+            Some(128),
+        );
+        let address: SuiAddress = AccountAddress::random().into();
+        let other_coin = parse_sui_struct_tag("0x2::coin::Coin<0xa::x::X>")?;
+        let nft = parse_sui_struct_tag("0xa::nft::Nft")?;
+        // The length of this type falls between the lengths of the coin types, which must not end
+        // the scan of the coins.
+        let unrelated = parse_sui_struct_tag("0x2::coin::Wrap<0xa::x::XYZ>")?;
+        let types = [GasCoin::type_(), other_coin, nft.clone(), unrelated];
+
+        let mut new_owners = vec![];
+        for type_ in types.iter().cycle().take(12) {
+            let object_id = ObjectID::random();
+            new_owners.push((
+                (address, object_id),
+                ObjectInfo {
+                    object_id,
+                    version: SequenceNumber::new(),
+                    digest: ObjectDigest::random(),
+                    type_: ObjectType::Struct(type_.clone().into()),
+                    owner: Owner::AddressOwner(address),
+                    previous_transaction: TransactionDigest::random(),
+                },
+            ));
+        }
+        index_object_changes(
+            &index_store,
+            address,
+            ObjectIndexChanges {
+                deleted_owners: vec![],
+                deleted_dynamic_fields: vec![],
+                new_owners,
+                new_dynamic_fields: vec![],
+            },
+        )
+        .await?;
+
+        let owned = |cursor, filter| index_store.get_owner_objects(address, cursor, 100, filter);
+        assert_eq!(owned(None, Some(SuiObjectDataFilter::gas_coin()))?.len(), 3);
+        let coins = SuiObjectDataFilter::StructType(parse_sui_struct_tag("0x2::coin::Coin")?);
+        assert_eq!(owned(None, Some(coins.clone()))?.len(), 6);
+        let coin_module = SuiObjectDataFilter::MoveModule {
+            package: ObjectID::from_hex_literal("0x2")?,
+            module: Identifier::new("coin")?,
+        };
+        assert_eq!(owned(None, Some(coin_module))?.len(), 9);
+        let framework = SuiObjectDataFilter::Package(ObjectID::from_hex_literal("0x2")?);
+        assert_eq!(owned(None, Some(framework))?.len(), 9);
+        let package = SuiObjectDataFilter::Package(ObjectID::from_hex_literal("0xa")?);
+        let nfts = owned(None, Some(package.clone()))?;
+        assert_eq!(nfts.len(), 3);
+        assert_eq!(owned(None, None)?.len(), 12);
+
+        // Pages resume after the cursor.
+        let first_page = index_store.get_owner_objects(address, None, 4, Some(coins.clone()))?;
+        let next_page = owned(Some(first_page[3].object_id), Some(coins.clone()))?;
+        assert_eq!(next_page.len(), 2);
+        assert!(next_page.iter().all(|o| !first_page.contains(o)));
+
+        index_object_changes(
+            &index_store,
+            address,
+            ObjectIndexChanges {
+                deleted_owners: vec![(address, nfts[0].object_id)],
+                deleted_dynamic_fields: vec![],
+                new_owners: vec![],
+                new_dynamic_fields: vec![],
+            },
+        )
+        .await?;
+        assert_eq!(owned(None, Some(package))?.len(), 2);
+        assert!(index_store
+            .tables
+            .owner_type_index
+            .unbounded_iter()
+            .all(|(_, o)| o.object_id != nfts[0].object_id));
+
         Ok(())
     }
 }
@@ -9,7 +9,10 @@ pub const SUI_VALIDATOR_SERVER_NAME: &str = "sui";
 
 pub use acceptor::{TlsAcceptor, TlsConnectionInfo};
 pub use certgen::SelfSignedCertificate;
-pub use verifier::{AllowAll, Allower, CertVerifier, HashSetAllow, ValidatorAllowlist};
+pub use verifier::{
+    cipher_suites_from_names, AllowAll, Allower, CertVerifier, HashSetAllow, ServerCertVerifier,
+    ValidatorAllowlist,
+};
 
 pub use rustls;
 
@@ -125,6 +128,89 @@ mod tests {
             .unwrap_err();
     }
 
+    #[test]
+    fn optional_client_auth() {
+        let verifier = CertVerifier::new(AllowAll);
+        assert!(verifier.client_auth_mandatory());
+
+        let verifier = verifier.with_optional_client_auth();
+        assert!(verifier.offer_client_auth());
+        assert!(!verifier.client_auth_mandatory());
+    }
+
+    #[test]
+    fn verify_server_cert() {
+        use rustls::client::ServerCertVerifier as _;
+
+        let mut rng = rand::thread_rng();
+        let expected = Ed25519KeyPair::generate(&mut rng);
+        let unexpected = Ed25519KeyPair::generate(&mut rng);
+
+        let expected_public_key = expected.public().to_owned();
+        let expected_cert =
+            SelfSignedCertificate::new(expected.private(), SUI_VALIDATOR_SERVER_NAME);
+        let unexpected_cert =
+            SelfSignedCertificate::new(unexpected.private(), SUI_VALIDATOR_SERVER_NAME);
+
+        let verifier = ServerCertVerifier::new(
+            expected_public_key.clone(),
+            SUI_VALIDATOR_SERVER_NAME.to_string(),
+        );
+        // The server name the client connects to doesn't matter, only the key does.
+        let server_name = rustls::ServerName::try_from("127.0.0.1").unwrap();
+
+        // The certificate of the expected key passes validation
+        verifier
+            .verify_server_cert(
+                &expected_cert.rustls_certificate(),
+                &[],
+                &server_name,
+                &mut std::iter::empty(),
+                &[],
+                std::time::SystemTime::now(),
+            )
+            .unwrap();
+
+        // The certificate of any other key fails validation
+        verifier
+            .verify_server_cert(
+                &unexpected_cert.rustls_certificate(),
+                &[],
+                &server_name,
+                &mut std::iter::empty(),
+                &[],
+                std::time::SystemTime::now(),
+            )
+            .unwrap_err();
+
+        // The expected key with a certificate for another name fails validation
+        let mut rng = rand::thread_rng();
+        let keypair = Ed25519KeyPair::generate(&mut rng);
+        let verifier = ServerCertVerifier::new(
+            keypair.public().to_owned(),
+            SUI_VALIDATOR_SERVER_NAME.into(),
+        );
+        let cert = SelfSignedCertificate::new(keypair.private(), "not-sui");
+        verifier
+            .verify_server_cert(
+                &cert.rustls_certificate(),
+                &[],
+                &server_name,
+                &mut std::iter::empty(),
+                &[],
+                std::time::SystemTime::now(),
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    fn cipher_suites_by_name() {
+        let suites = cipher_suites_from_names(&["TLS13_AES_256_GCM_SHA384".to_string()]).unwrap();
+        assert_eq!(suites, vec![rustls::cipher_suite::TLS13_AES_256_GCM_SHA384]);
+
+        cipher_suites_from_names(&["TLS_NULL_WITH_NULL_NULL".to_string()]).unwrap_err();
+    }
+
     #[tokio::test]
     async fn axum_acceptor() {
         use fastcrypto::ed25519::Ed25519KeyPair;
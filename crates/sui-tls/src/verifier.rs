@@ -59,16 +59,43 @@ impl Allower for HashSetAllow {
     }
 }
 
+/// Looks up the rustls cipher suites with the given names, e.g. `TLS13_AES_256_GCM_SHA384`.
+pub fn cipher_suites_from_names(
+    names: &[String],
+) -> Result<Vec<rustls::SupportedCipherSuite>, rustls::Error> {
+    names
+        .iter()
+        .map(|name| {
+            rustls::ALL_CIPHER_SUITES
+                .iter()
+                .find(|suite| format!("{:?}", suite.suite()) == *name)
+                .copied()
+                .ok_or_else(|| rustls::Error::General(format!("unknown cipher suite: {name}")))
+        })
+        .collect()
+}
+
 /// A `rustls::server::ClientCertVerifier` that will ensure that every client provides a valid,
 /// expected certificate and that the client's public key is in the validator set.
 #[derive(Clone, Debug)]
 pub struct CertVerifier<A> {
     allower: A,
+    client_auth_mandatory: bool,
 }
 
 impl<A> CertVerifier<A> {
     pub fn new(allower: A) -> Self {
-        Self { allower }
+        Self {
+            allower,
+            client_auth_mandatory: true,
+        }
+    }
+
+    /// Also accept clients that don't provide a certificate. Certificates that are provided are
+    /// still verified.
+    pub fn with_optional_client_auth(mut self) -> Self {
+        self.client_auth_mandatory = false;
+        self
     }
 }
 
@@ -77,9 +104,24 @@ impl<A: Allower + 'static> CertVerifier<A> {
         self,
         certificates: Vec<rustls::Certificate>,
         private_key: rustls::PrivateKey,
+    ) -> Result<rustls::ServerConfig, rustls::Error> {
+        self.rustls_server_config_with_cipher_suites(
+            rustls::DEFAULT_CIPHER_SUITES,
+            certificates,
+            private_key,
+        )
+    }
+
+    pub fn rustls_server_config_with_cipher_suites(
+        self,
+        cipher_suites: &[rustls::SupportedCipherSuite],
+        certificates: Vec<rustls::Certificate>,
+        private_key: rustls::PrivateKey,
     ) -> Result<rustls::ServerConfig, rustls::Error> {
         let mut config = rustls::ServerConfig::builder()
-            .with_safe_defaults()
+            .with_cipher_suites(cipher_suites)
+            .with_safe_default_kx_groups()
+            .with_safe_default_protocol_versions()?
             .with_client_cert_verifier(std::sync::Arc::new(self))
             .with_single_cert(certificates, private_key)?;
         config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
@@ -94,7 +136,7 @@ impl<A: Allower> rustls::server::ClientCertVerifier for CertVerifier<A> {
     }
 
     fn client_auth_mandatory(&self) -> bool {
-        true
+        self.client_auth_mandatory
     }
 
     fn client_auth_root_subjects(&self) -> &[rustls::DistinguishedName] {
@@ -151,6 +193,100 @@ impl<A: Allower> rustls::server::ClientCertVerifier for CertVerifier<A> {
     }
 }
 
+/// A `rustls::client::ServerCertVerifier` that will ensure that the server provides a valid,
+/// self-signed certificate for the public key the client expects, e.g. the one a validator has
+/// registered on chain.
+#[derive(Clone, Debug)]
+pub struct ServerCertVerifier {
+    public_key: Ed25519PublicKey,
+    name: String,
+}
+
+impl ServerCertVerifier {
+    pub fn new(public_key: Ed25519PublicKey, name: String) -> Self {
+        Self { public_key, name }
+    }
+
+    /// A client config that doesn't authenticate the client.
+    pub fn rustls_client_config_with_no_client_auth(
+        self,
+        cipher_suites: &[rustls::SupportedCipherSuite],
+    ) -> Result<rustls::ClientConfig, rustls::Error> {
+        let mut config = rustls::ClientConfig::builder()
+            .with_cipher_suites(cipher_suites)
+            .with_safe_default_kx_groups()
+            .with_safe_default_protocol_versions()?
+            .with_custom_certificate_verifier(std::sync::Arc::new(self))
+            .with_no_client_auth();
+        config.alpn_protocols = vec![b"h2".to_vec()];
+
+        Ok(config)
+    }
+
+    /// A client config that authenticates the client with the given certificate.
+    pub fn rustls_client_config(
+        self,
+        cipher_suites: &[rustls::SupportedCipherSuite],
+        certificates: Vec<rustls::Certificate>,
+        private_key: rustls::PrivateKey,
+    ) -> Result<rustls::ClientConfig, rustls::Error> {
+        let mut config = rustls::ClientConfig::builder()
+            .with_cipher_suites(cipher_suites)
+            .with_safe_default_kx_groups()
+            .with_safe_default_protocol_versions()?
+            .with_custom_certificate_verifier(std::sync::Arc::new(self))
+            .with_client_auth_cert(certificates, private_key)?;
+        config.alpn_protocols = vec![b"h2".to_vec()];
+
+        Ok(config)
+    }
+}
+
+impl rustls::client::ServerCertVerifier for ServerCertVerifier {
+    // Verifies this is a valid ed25519 self-signed certificate for the expected public key, the
+    // same way `CertVerifier` verifies client certificates. The server name the client connected
+    // to is ignored, the certificate must be valid for `self.name` instead.
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls::Certificate,
+        intermediates: &[rustls::Certificate],
+        _server_name: &rustls::ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        now: std::time::SystemTime,
+    ) -> Result<rustls::client::ServerCertVerified, rustls::Error> {
+        let public_key = public_key_from_certificate(end_entity)?;
+
+        if public_key != self.public_key {
+            return Err(rustls::Error::General(format!(
+                "invalid certificate: {:?} is not the expected key {:?}",
+                public_key, self.public_key,
+            )));
+        }
+
+        let (cert, chain, trustroots) = prepare_for_self_signed(end_entity, intermediates)?;
+        let now = webpki::Time::try_from(now).map_err(|_| rustls::Error::FailedToGetCurrentTime)?;
+
+        let cert = cert
+            .verify_for_usage(
+                SUPPORTED_SIG_ALGS,
+                &trustroots,
+                &chain,
+                now,
+                webpki::KeyUsage::server_auth(),
+                &[],
+            )
+            .map_err(pki_error)
+            .map(|_| cert)?;
+
+        let dns_nameref = webpki::SubjectNameRef::try_from_ascii_str(&self.name)
+            .map_err(|_| rustls::Error::UnsupportedNameType)?;
+        cert.verify_is_valid_for_subject_name(dns_nameref)
+            .map_err(pki_error)
+            .map(|_| rustls::client::ServerCertVerified::assertion())
+    }
+}
+
 type CertChainAndRoots<'a> = (
     webpki::EndEntityCert<'a>,
     Vec<&'a [u8]>,
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    check_completed_snapshot,
+    check_completed_snapshot, consensus_replay,
     db_tool::{execute_db_tool_command, print_db_all_tables, DbToolCommand},
+    diff_effects::{checkpoint_transactions, diff_effects_at_nodes},
     download_db_snapshot, download_formal_snapshot, dump_checkpoints_from_archive, get_object,
-    get_transaction_block, make_clients, pkg_dump, restore_from_db_checkpoint,
-    state_sync_from_archive, verify_archive, verify_archive_by_checksum, ConciseObjectOutput,
-    GroupedObjectOutput, VerboseObjectOutput,
+    get_transaction_block,
+    grep_digest::grep_digest,
+    make_clients, pkg_dump, restore_from_db_checkpoint, state_sync_from_archive, verify_archive,
+    verify_archive_by_checksum, ConciseObjectOutput, GroupedObjectOutput, VerboseObjectOutput,
 };
 use anyhow::Result;
 use std::env;
@@ -129,6 +131,43 @@ pub enum ToolCommand {
         show_input_tx: bool,
     },
 
+    /// Compare the effects of a transaction, or of the transactions of a range of checkpoints,
+    /// executed by different fullnodes, and report the fields that diverge
+    #[command(name = "diff-effects")]
+    DiffEffects {
+        /// Comma-separated RPC URLs of the fullnodes to compare. The effects of each node are
+        /// compared with those of the first one.
+        #[arg(long, value_delimiter = ',', num_args = 2.., required = true)]
+        nodes: Vec<String>,
+
+        #[arg(
+            long,
+            help = "The transaction to compare",
+            required_unless_present = "start_checkpoint",
+            conflicts_with = "start_checkpoint"
+        )]
+        digest: Option<TransactionDigest>,
+
+        /// The first checkpoint whose transactions are compared
+        #[arg(long, requires = "end_checkpoint")]
+        start_checkpoint: Option<CheckpointSequenceNumber>,
+
+        /// The last checkpoint whose transactions are compared, inclusive
+        #[arg(long, requires = "start_checkpoint")]
+        end_checkpoint: Option<CheckpointSequenceNumber>,
+    },
+
+    /// Print the log records of transaction `digest` from node log files
+    #[command(name = "grep-digest")]
+    GrepDigest {
+        #[arg(long, help = "The digest of the transaction")]
+        digest: TransactionDigest,
+
+        /// Log files, or directories of log files, to search
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+
     /// Tool to read validator & node db.
     #[command(name = "db-tool")]
     DbTool {
@@ -139,6 +178,24 @@ pub enum ToolCommand {
         cmd: Option<DbToolCommand>,
     },
 
+    /// Re-run the consensus ordering over the certificates persisted by a validator in the
+    /// current epoch, and diff the resulting commits against the ones it committed and executed.
+    #[command(name = "consensus-replay")]
+    ConsensusReplay {
+        /// Path of the authority DB of the validator
+        #[arg(long = "db-path")]
+        db_path: PathBuf,
+        /// Path of the consensus DB of the validator for the current epoch
+        #[arg(long = "consensus-db-path")]
+        consensus_db_path: PathBuf,
+        /// Network of the validator, which determines its protocol config
+        #[arg(long = "network", default_value = "mainnet")]
+        network: Chain,
+        /// GC depth of the Narwhal parameters of the validator
+        #[arg(long = "gc-depth", default_value_t = 50)]
+        gc_depth: u64,
+    },
+
     /// Tool to sync the node from archive store
     #[command(name = "sync-from-archive")]
     SyncFromArchive {
@@ -490,6 +547,33 @@ impl ToolCommand {
                     get_transaction_block(digest, genesis, show_input_tx, fullnode_rpc_url).await?
                 );
             }
+            ToolCommand::DiffEffects {
+                nodes,
+                digest,
+                start_checkpoint,
+                end_checkpoint,
+            } => {
+                let digests = match (digest, start_checkpoint, end_checkpoint) {
+                    (Some(digest), _, _) => vec![digest],
+                    (None, Some(start), Some(end)) => {
+                        checkpoint_transactions(&nodes[0], start, end).await?
+                    }
+                    _ => unreachable!("clap requires a digest or a checkpoint range"),
+                };
+                let num_diverging =
+                    diff_effects_at_nodes(&nodes, &digests, &mut std::io::stdout()).await?;
+                println!(
+                    "{} of {} transactions have diverging effects",
+                    num_diverging,
+                    digests.len()
+                );
+            }
+            ToolCommand::GrepDigest { digest, paths } => {
+                let num_records = grep_digest(&digest, &paths, &mut std::io::stdout().lock())?;
+                if num_records == 0 {
+                    eprintln!("No log records found for transaction {}", digest);
+                }
+            }
             ToolCommand::DbTool { db_path, cmd } => {
                 let path = PathBuf::from(db_path);
                 match cmd {
@@ -497,6 +581,14 @@ impl ToolCommand {
                     None => print_db_all_tables(path)?,
                 }
             }
+            ToolCommand::ConsensusReplay {
+                db_path,
+                consensus_db_path,
+                network,
+                gc_depth,
+            } => {
+                consensus_replay::replay(&db_path, &consensus_db_path, network, gc_depth)?;
+            }
             ToolCommand::DumpPackages {
                 db_url,
                 output_dir,
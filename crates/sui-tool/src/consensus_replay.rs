@@ -0,0 +1,172 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, ensure, Result};
+use fastcrypto::hash::Hash;
+use narwhal_node::primary_node::CONSENSUS_SCHEDULE_CHANGE_SUB_DAGS;
+use narwhal_primary::consensus::{
+    Bullshark, ConsensusMetrics, ConsensusState, LeaderSchedule, LeaderSwapTable,
+};
+use narwhal_storage::NodeStorage;
+use narwhal_types::{CertificateDigest, CommittedSubDag, ConsensusCommit, Round};
+use prometheus::Registry;
+use sui_core::authority::authority_per_epoch_store::AuthorityEpochTables;
+use sui_core::authority::authority_store_tables::AuthorityPerpetualTables;
+use sui_core::authority::epoch_start_configuration::EpochStartConfigTrait;
+use sui_protocol_config::{Chain, ProtocolConfig};
+use sui_types::sui_system_state::epoch_start_sui_system_state::EpochStartSystemStateTrait;
+use tracing::info;
+
+/// Maximum number of divergent commits printed.
+const MAX_PRINTED_DIFFS: usize = 10;
+
+/// Re-runs the ordering of the certificates persisted in the consensus database of the current
+/// epoch of a validator, from the start of the epoch, and diffs the resulting commits against the
+/// ones the validator committed and executed.
+///
+/// `db_path` is the authority database of the validator, from which the committee and the
+/// protocol config of the epoch are read, and `consensus_db_path` its consensus database for the
+/// epoch. `gc_depth` must match the Narwhal parameters of the validator.
+pub(crate) fn replay(
+    db_path: &Path,
+    consensus_db_path: &Path,
+    chain: Chain,
+    gc_depth: Round,
+) -> Result<()> {
+    let store_path = db_path.join("store");
+    let perpetual_db = AuthorityPerpetualTables::open(&store_path, None);
+    let epoch_start_configuration = perpetual_db
+        .get_epoch_start_configuration()?
+        .ok_or_else(|| anyhow!("Epoch start configuration not found in {:?}", db_path))?;
+    let epoch_start_state = epoch_start_configuration.epoch_start_state();
+    let epoch = epoch_start_state.epoch();
+    let committee = epoch_start_state.get_narwhal_committee();
+    let protocol_config =
+        ProtocolConfig::get_for_version(epoch_start_state.protocol_version(), chain);
+
+    let epoch_tables = AuthorityEpochTables::open(epoch, &store_path, None);
+    let last_executed_index = epoch_tables
+        .get_last_consensus_index()?
+        .map(|index| index.index.sub_dag_index);
+
+    let node_storage = NodeStorage::reopen(consensus_db_path, None);
+    let replay_dir = tempfile::tempdir()?;
+    let replay_storage = NodeStorage::reopen(replay_dir.path(), None);
+    let metrics = Arc::new(ConsensusMetrics::new(&Registry::new()));
+    let mut state = ConsensusState::new(metrics.clone(), gc_depth);
+    let mut bullshark = Bullshark::new(
+        committee.clone(),
+        replay_storage.consensus_store.clone(),
+        protocol_config,
+        metrics,
+        CONSENSUS_SCHEDULE_CHANGE_SUB_DAGS,
+        LeaderSchedule::new(committee, LeaderSwapTable::default()),
+    );
+
+    let highest_round = node_storage.certificate_store.highest_round_number();
+    info!("Replaying the certificates of epoch {epoch} up to round {highest_round}");
+
+    let mut replayed_commits = 0;
+    let mut diffs = 0;
+    for round in 1..=highest_round {
+        for certificate in node_storage.certificate_store.at_round(round)? {
+            ensure!(
+                certificate.epoch() == epoch,
+                "Certificate {} is of epoch {}, but the authority database is at epoch {epoch}",
+                certificate.digest(),
+                certificate.epoch(),
+            );
+            let (_, sub_dags) = bullshark.process_certificate(&mut state, certificate)?;
+            for sub_dag in sub_dags {
+                replayed_commits += 1;
+                let index = sub_dag.sub_dag_index;
+                let node_commit = node_storage.consensus_store.read_consensus_commit(&index)?;
+                if let Some(diff) = diff_commit(&sub_dag, node_commit.as_ref()) {
+                    diffs += 1;
+                    if diffs <= MAX_PRINTED_DIFFS {
+                        println!("Commit {index} diverges: {diff}");
+                    }
+                }
+            }
+        }
+    }
+
+    let node_commits = node_storage.consensus_store.get_latest_sub_dag_index();
+    let node_executed = last_executed_index.map_or("none".to_string(), |index| index.to_string());
+    println!(
+        "Replayed {replayed_commits} commits of epoch {epoch}, the node committed {node_commits} \
+        and executed {node_executed}"
+    );
+    if node_commits > replayed_commits {
+        println!(
+            "The node committed {} commits which were not replayed",
+            node_commits - replayed_commits
+        );
+    }
+    if let Some(executed) = last_executed_index {
+        if executed > replayed_commits {
+            println!(
+                "The node executed {} commits which were not replayed",
+                executed - replayed_commits
+            );
+        }
+    }
+    if diffs == 0 {
+        println!("No divergence found");
+    } else {
+        println!("Found {diffs} divergent commits");
+    }
+    Ok(())
+}
+
+/// Describes the differences between a replayed commit and the commit of the node with the same
+/// index, if any.
+fn diff_commit(replayed: &CommittedSubDag, node: Option<&ConsensusCommit>) -> Option<String> {
+    let Some(node) = node else {
+        return Some(format!(
+            "replayed leader {} of round {}, which the node did not commit",
+            replayed.leader.digest(),
+            replayed.leader.round()
+        ));
+    };
+    let mut diffs = vec![];
+    if replayed.leader.digest() != node.leader() {
+        diffs.push(format!(
+            "replayed leader {} of round {}, node leader {} of round {}",
+            replayed.leader.digest(),
+            replayed.leader.round(),
+            node.leader(),
+            node.leader_round()
+        ));
+    }
+    let replayed_certificates = replayed
+        .certificates
+        .iter()
+        .map(|certificate| certificate.digest())
+        .collect::<Vec<CertificateDigest>>();
+    let node_certificates = node.certificates();
+    if replayed_certificates != node_certificates {
+        let position = replayed_certificates
+            .iter()
+            .zip(&node_certificates)
+            .position(|(replayed, node)| replayed != node)
+            .unwrap_or(replayed_certificates.len().min(node_certificates.len()));
+        diffs.push(format!(
+            "replayed {} certificates, node committed {}, first difference at position {position}",
+            replayed_certificates.len(),
+            node_certificates.len()
+        ));
+    }
+    // Commits persisted before the commit timestamps were stored have a timestamp of 0.
+    if node.commit_timestamp() != 0 && replayed.commit_timestamp() != node.commit_timestamp() {
+        diffs.push(format!(
+            "replayed timestamp {}, node timestamp {}",
+            replayed.commit_timestamp(),
+            node.commit_timestamp()
+        ));
+    }
+    (!diffs.is_empty()).then(|| diffs.join("; "))
+}
@@ -43,6 +43,7 @@ pub enum DbToolCommand {
     Compact,
     PruneObjects,
     PruneCheckpoints,
+    Migrate(MigrateOptions),
 }
 
 #[derive(Parser)]
@@ -160,6 +161,14 @@ pub struct RewindCheckpointExecutionOptions {
     checkpoint_sequence_number: u64,
 }
 
+#[derive(Parser)]
+#[command(rename_all = "kebab-case")]
+pub struct MigrateOptions {
+    /// Print the migrations that would run without changing the database
+    #[arg(long)]
+    dry_run: bool,
+}
+
 pub async fn execute_db_tool_command(db_path: PathBuf, cmd: DbToolCommand) -> anyhow::Result<()> {
     match cmd {
         DbToolCommand::ListTables => print_db_all_tables(db_path),
@@ -192,6 +201,7 @@ pub async fn execute_db_tool_command(db_path: PathBuf, cmd: DbToolCommand) -> an
         DbToolCommand::Compact => compact(db_path),
         DbToolCommand::PruneObjects => prune_objects(db_path).await,
         DbToolCommand::PruneCheckpoints => prune_checkpoints(db_path).await,
+        DbToolCommand::Migrate(d) => migrate(&db_path, d),
         DbToolCommand::IndexSearchKeyRange(rg) => {
             let res = search_index(
                 db_path,
@@ -395,6 +405,13 @@ pub fn remove_object_lock(path: &Path, opt: RemoveObjectLockOptions) -> anyhow::
     Ok(())
 }
 
+pub fn migrate(path: &Path, opt: MigrateOptions) -> anyhow::Result<()> {
+    let perpetual_db = AuthorityPerpetualTables::open(&path.join("store"), None);
+    let report = perpetual_db.migrate_schema(opt.dry_run)?;
+    print!("{}", report);
+    Ok(())
+}
+
 pub fn reset_db_to_genesis(path: &Path) -> anyhow::Result<()> {
     // Follow the below steps to test:
     //
@@ -0,0 +1,319 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compares the effects that different nodes computed for the same transactions, to triage forks.
+//!
+//!   $ sui-tool diff-effects --nodes http://node-a:9000,http://node-b:9000 --digest <tx>
+//!   $ sui-tool diff-effects --nodes http://node-a:9000,http://node-b:9000 \
+//!       --start-checkpoint 1000 --end-checkpoint 1010
+//!
+//! The effects of each node are compared with those of the first node, and for each transaction
+//! with diverging effects the fields that differ are reported.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use sui_sdk::rpc_types::{CheckpointId, SuiTransactionBlockResponseOptions};
+use sui_sdk::{SuiClient, SuiClientBuilder};
+use sui_types::digests::{TransactionDigest, TransactionEffectsDigest};
+use sui_types::effects::TransactionEffects;
+use sui_types::message_envelope::Message;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+
+/// The maximum number of transactions fetched in one request.
+const MAX_TRANSACTIONS_PER_REQUEST: usize = 50;
+
+/// A field whose value differs between the effects of the first node and another node.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Path of the field in the JSON form of the effects, e.g. `V2.changed_objects[3]`.
+    pub path: String,
+    pub expected: Option<Value>,
+    pub actual: Option<Value>,
+}
+
+#[derive(Debug)]
+pub struct NodeEffects {
+    pub node: String,
+    /// The effects of the transaction, or `None` if the node has not executed it.
+    pub effects_digest: Option<TransactionEffectsDigest>,
+    /// The fields that differ from the effects of the first node.
+    pub diffs: Vec<FieldDiff>,
+}
+
+#[derive(Debug)]
+pub struct EffectsDivergence {
+    pub digest: TransactionDigest,
+    pub nodes: Vec<NodeEffects>,
+}
+
+impl fmt::Display for EffectsDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Transaction {} has diverging effects:", self.digest)?;
+        for node in &self.nodes {
+            match &node.effects_digest {
+                Some(digest) => writeln!(f, "  {}: effects {}", node.node, digest)?,
+                None => writeln!(f, "  {}: not executed", node.node)?,
+            }
+            for diff in &node.diffs {
+                writeln!(
+                    f,
+                    "    {}: {} != {}",
+                    diff.path,
+                    display_value(&diff.expected),
+                    display_value(&diff.actual)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn display_value(value: &Option<Value>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "<missing>".to_string(), |value| value.to_string())
+}
+
+/// Compares the effects of the transaction at each node with those at the first node, and
+/// returns the divergence if they are not all the same.
+pub fn diff_effects(
+    digest: TransactionDigest,
+    node_effects: &[(String, Option<&TransactionEffects>)],
+) -> Result<Option<EffectsDivergence>> {
+    let Some((_, expected)) = node_effects.first() else {
+        return Ok(None);
+    };
+    let expected_digest = expected.map(|effects| effects.digest());
+    if node_effects
+        .iter()
+        .all(|(_, effects)| effects.map(|effects| effects.digest()) == expected_digest)
+    {
+        return Ok(None);
+    }
+
+    let expected_value = expected.map(serde_json::to_value).transpose()?;
+    let mut nodes = Vec::new();
+    for (node, effects) in node_effects {
+        let mut diffs = Vec::new();
+        if let (Some(expected), Some(effects)) = (&expected_value, effects) {
+            diff_values(
+                String::new(),
+                expected,
+                &serde_json::to_value(effects)?,
+                &mut diffs,
+            );
+        }
+        nodes.push(NodeEffects {
+            node: node.clone(),
+            effects_digest: effects.map(|effects| effects.digest()),
+            diffs,
+        });
+    }
+    Ok(Some(EffectsDivergence { digest, nodes }))
+}
+
+fn diff_values(path: String, expected: &Value, actual: &Value, diffs: &mut Vec<FieldDiff>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected_value) in expected {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match actual.get(key) {
+                    Some(actual_value) => {
+                        diff_values(field_path, expected_value, actual_value, diffs)
+                    }
+                    None => diffs.push(FieldDiff {
+                        path: field_path,
+                        expected: Some(expected_value.clone()),
+                        actual: None,
+                    }),
+                }
+            }
+            for (key, actual_value) in actual {
+                if !expected.contains_key(key) {
+                    diffs.push(FieldDiff {
+                        path: if path.is_empty() {
+                            key.clone()
+                        } else {
+                            format!("{path}.{key}")
+                        },
+                        expected: None,
+                        actual: Some(actual_value.clone()),
+                    });
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for i in 0..expected.len().max(actual.len()) {
+                let element_path = format!("{path}[{i}]");
+                match (expected.get(i), actual.get(i)) {
+                    (Some(expected), Some(actual)) => {
+                        diff_values(element_path, expected, actual, diffs)
+                    }
+                    (expected, actual) => diffs.push(FieldDiff {
+                        path: element_path,
+                        expected: expected.cloned(),
+                        actual: actual.cloned(),
+                    }),
+                }
+            }
+        }
+        (expected, actual) if expected != actual => diffs.push(FieldDiff {
+            path,
+            expected: Some(expected.clone()),
+            actual: Some(actual.clone()),
+        }),
+        _ => {}
+    }
+}
+
+async fn fetch_effects(
+    client: &SuiClient,
+    digests: &[TransactionDigest],
+) -> Result<BTreeMap<TransactionDigest, TransactionEffects>> {
+    let mut effects = BTreeMap::new();
+    for chunk in digests.chunks(MAX_TRANSACTIONS_PER_REQUEST) {
+        let responses = client
+            .read_api()
+            .multi_get_transactions_with_options(
+                chunk.to_vec(),
+                SuiTransactionBlockResponseOptions::new().with_raw_effects(),
+            )
+            .await?;
+        for response in responses {
+            if response.raw_effects.is_empty() {
+                continue;
+            }
+            effects.insert(response.digest, bcs::from_bytes(&response.raw_effects)?);
+        }
+    }
+    Ok(effects)
+}
+
+/// Compares the effects of the transactions with `digests` at the fullnodes with RPC URLs
+/// `nodes`, writes a report of each transaction with diverging effects to `output`, and returns
+/// how many there are.
+pub async fn diff_effects_at_nodes(
+    nodes: &[String],
+    digests: &[TransactionDigest],
+    output: &mut impl Write,
+) -> Result<usize> {
+    if nodes.len() < 2 {
+        return Err(anyhow!("At least two nodes are needed to compare effects"));
+    }
+    let mut node_effects = Vec::new();
+    for node in nodes {
+        let client = SuiClientBuilder::default().build(node).await?;
+        node_effects.push(fetch_effects(&client, digests).await?);
+    }
+
+    let mut num_diverging = 0;
+    for digest in digests {
+        let effects: Vec<_> = nodes
+            .iter()
+            .cloned()
+            .zip(node_effects.iter().map(|effects| effects.get(digest)))
+            .collect();
+        if let Some(divergence) = diff_effects(*digest, &effects)? {
+            num_diverging += 1;
+            writeln!(output, "{divergence}")?;
+        }
+    }
+    Ok(num_diverging)
+}
+
+/// Returns the transactions of checkpoints `start..=end`, as known by the fullnode at `node`.
+pub async fn checkpoint_transactions(
+    node: &str,
+    start: CheckpointSequenceNumber,
+    end: CheckpointSequenceNumber,
+) -> Result<Vec<TransactionDigest>> {
+    let client = SuiClientBuilder::default().build(node).await?;
+    let mut digests = Vec::new();
+    for sequence_number in start..=end {
+        let checkpoint = client
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(sequence_number))
+            .await?;
+        digests.extend(checkpoint.transactions);
+    }
+    Ok(digests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_values() {
+        let expected = json!({
+            "status": "Success",
+            "gas_used": { "computation_cost": 1000, "storage_cost": 10 },
+            "changed_objects": [[1, "a"], [2, "b"]],
+            "events_digest": null,
+        });
+        let actual = json!({
+            "status": "Success",
+            "gas_used": { "computation_cost": 1200, "storage_cost": 10 },
+            "changed_objects": [[1, "a"], [2, "c"], [3, "d"]],
+            "dependencies": [],
+        });
+        let mut diffs = Vec::new();
+        diff_values(String::new(), &expected, &actual, &mut diffs);
+        assert_eq!(
+            diffs,
+            vec![
+                FieldDiff {
+                    path: "gas_used.computation_cost".to_string(),
+                    expected: Some(json!(1000)),
+                    actual: Some(json!(1200)),
+                },
+                FieldDiff {
+                    path: "changed_objects[1][1]".to_string(),
+                    expected: Some(json!("b")),
+                    actual: Some(json!("c")),
+                },
+                FieldDiff {
+                    path: "changed_objects[2]".to_string(),
+                    expected: None,
+                    actual: Some(json!([3, "d"])),
+                },
+                FieldDiff {
+                    path: "events_digest".to_string(),
+                    expected: Some(Value::Null),
+                    actual: None,
+                },
+                FieldDiff {
+                    path: "dependencies".to_string(),
+                    expected: None,
+                    actual: Some(json!([])),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_effects() {
+        let digest = TransactionDigest::random();
+        let effects = TransactionEffects::default();
+        let same = vec![
+            ("a".to_string(), Some(&effects)),
+            ("b".to_string(), Some(&effects)),
+        ];
+        assert!(diff_effects(digest, &same).unwrap().is_none());
+
+        let missing = vec![("a".to_string(), Some(&effects)), ("b".to_string(), None)];
+        let divergence = diff_effects(digest, &missing).unwrap().unwrap();
+        assert_eq!(divergence.nodes[0].effects_digest, Some(effects.digest()));
+        assert_eq!(divergence.nodes[1].effects_digest, None);
+        assert!(divergence.nodes[1].diffs.is_empty());
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pulls the log records of one transaction out of node logs. The spans of the authority
+//! pipeline carry the digest of the transaction they process as their `tx_digest` field, so the
+//! records of a transaction are the ones that mention its digest, in both text and JSON logs.
+
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use sui_types::digests::TransactionDigest;
+
+/// Writes the records of the transaction with `digest` in the log files at `paths` to `output`,
+/// and returns how many there are. Directories are searched for log files recursively.
+pub fn grep_digest(
+    digest: &TransactionDigest,
+    paths: &[PathBuf],
+    output: &mut impl Write,
+) -> Result<usize> {
+    let pattern = digest.base58_encode();
+    let mut files = Vec::new();
+    for path in paths {
+        collect_files(path, &mut files)?;
+    }
+    let show_path = files.len() > 1;
+
+    let mut num_records = 0;
+    for file in files {
+        let reader = BufReader::new(
+            File::open(&file).with_context(|| format!("Failed to open {}", file.display()))?,
+        );
+        for line in reader.split(b'\n') {
+            // Lines are matched as bytes, so that a record that is not valid UTF-8 doesn't stop
+            // the search.
+            let line = line.with_context(|| format!("Failed to read {}", file.display()))?;
+            let line = String::from_utf8_lossy(&line);
+            if !line.contains(&pattern) {
+                continue;
+            }
+            num_records += 1;
+            if show_path {
+                writeln!(output, "{}: {}", file.display(), line)?;
+            } else {
+                writeln!(output, "{}", line)?;
+            }
+        }
+    }
+    Ok(num_records)
+}
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    let mut entries = fs::read_dir(path)
+        .with_context(|| format!("Failed to read directory {}", path.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Rotated log files are named in chronological order.
+    entries.sort();
+    for entry in entries {
+        collect_files(&entry, files)?;
+    }
+    Ok(())
+}
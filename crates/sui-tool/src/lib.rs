@@ -63,7 +63,10 @@ use tracing::info;
 use typed_store::rocks::MetricConf;
 
 pub mod commands;
+mod consensus_replay;
 pub mod db_tool;
+mod diff_effects;
+mod grep_digest;
 pub mod pkg_dump;
 
 // This functions requires at least one of genesis or fullnode_rpc to be `Some`.
@@ -944,6 +947,9 @@ pub async fn download_formal_snapshot(
                 );
                 eprintln!("Formal snapshot state verification completed successfully!");
             }
+            CheckpointCommitment::CheckpointContentsMerkleRoot(_) => {
+                panic!("Expected the last end of epoch commitment to be a root state digest")
+            }
         };
     } else {
         eprintln!(
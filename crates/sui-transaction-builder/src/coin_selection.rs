@@ -0,0 +1,233 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Policies choosing which coins pay for a transaction, used by the [crate::TransactionBuilder]
+//! for gas coins and for payment coins. Integrators can supply their own by implementing
+//! [CoinSelector].
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use sui_types::base_types::ObjectRef;
+
+/// Chooses coins out of the candidate `(coin, balance)` pairs.
+pub trait CoinSelector: Send + Sync {
+    /// Returns coins with a total balance of at least `amount`, at most `max_coins` of them, or
+    /// `None` if the candidates can't cover the amount. When several coins are returned, the
+    /// first one is the one the others are merged into.
+    fn select_coins(
+        &self,
+        coins: Vec<(ObjectRef, u64)>,
+        amount: u64,
+        max_coins: usize,
+    ) -> Option<Vec<ObjectRef>>;
+}
+
+/// Uses as few coins as possible: the largest coin alone if it covers the amount, else coins in
+/// decreasing order of balance until they cover it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinimizeInputs;
+
+impl CoinSelector for MinimizeInputs {
+    fn select_coins(
+        &self,
+        mut coins: Vec<(ObjectRef, u64)>,
+        amount: u64,
+        max_coins: usize,
+    ) -> Option<Vec<ObjectRef>> {
+        coins.sort_by(|(_, a), (_, b)| b.cmp(a));
+        let mut selected = vec![];
+        let mut total: u64 = 0;
+        for (coin, value) in coins.into_iter().take(max_coins) {
+            selected.push(coin);
+            total = total.saturating_add(value);
+            if total >= amount {
+                return Some(selected);
+            }
+        }
+        None
+    }
+}
+
+/// Covers the amount like [MinimizeInputs], then adds the smallest remaining coins up to
+/// `max_coins`, so that dust gets merged into the selected coins as a side effect.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConsolidateDust;
+
+impl CoinSelector for ConsolidateDust {
+    fn select_coins(
+        &self,
+        coins: Vec<(ObjectRef, u64)>,
+        amount: u64,
+        max_coins: usize,
+    ) -> Option<Vec<ObjectRef>> {
+        let mut selected = MinimizeInputs.select_coins(coins.clone(), amount, max_coins)?;
+        let mut dust: Vec<_> = coins
+            .into_iter()
+            .filter(|(coin, _)| !selected.contains(coin))
+            .collect();
+        dust.sort_by_key(|(_, value)| *value);
+        let remaining = max_coins.saturating_sub(selected.len());
+        selected.extend(dust.into_iter().take(remaining).map(|(coin, _)| coin));
+        Some(selected)
+    }
+}
+
+/// Uses a single coin with exactly the amount if there is one, else the smallest single coin
+/// covering it, so that as little change as possible is left over. Falls back to
+/// [MinimizeInputs] if no single coin covers the amount.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExactMatch;
+
+impl CoinSelector for ExactMatch {
+    fn select_coins(
+        &self,
+        coins: Vec<(ObjectRef, u64)>,
+        amount: u64,
+        max_coins: usize,
+    ) -> Option<Vec<ObjectRef>> {
+        if max_coins == 0 {
+            return None;
+        }
+        let single = coins
+            .iter()
+            .filter(|(_, value)| *value >= amount)
+            .min_by_key(|(_, value)| *value);
+        match single {
+            Some((coin, _)) => Some(vec![*coin]),
+            None => MinimizeInputs.select_coins(coins, amount, max_coins),
+        }
+    }
+}
+
+/// The built-in coin selection policies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    #[default]
+    MinimizeInputs,
+    ConsolidateDust,
+    ExactMatch,
+}
+
+impl CoinSelectionStrategy {
+    pub fn selector(&self) -> Arc<dyn CoinSelector> {
+        match self {
+            CoinSelectionStrategy::MinimizeInputs => Arc::new(MinimizeInputs),
+            CoinSelectionStrategy::ConsolidateDust => Arc::new(ConsolidateDust),
+            CoinSelectionStrategy::ExactMatch => Arc::new(ExactMatch),
+        }
+    }
+}
+
+impl FromStr for CoinSelectionStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minimize-inputs" => Ok(CoinSelectionStrategy::MinimizeInputs),
+            "consolidate-dust" => Ok(CoinSelectionStrategy::ConsolidateDust),
+            "exact-match" => Ok(CoinSelectionStrategy::ExactMatch),
+            _ => Err(anyhow!(
+                "Unknown coin selection strategy {s}, expected one of minimize-inputs, \
+                 consolidate-dust or exact-match"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for CoinSelectionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CoinSelectionStrategy::MinimizeInputs => "minimize-inputs",
+            CoinSelectionStrategy::ConsolidateDust => "consolidate-dust",
+            CoinSelectionStrategy::ExactMatch => "exact-match",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::random_object_ref;
+
+    fn coins(values: &[u64]) -> Vec<(ObjectRef, u64)> {
+        values
+            .iter()
+            .map(|value| (random_object_ref(), *value))
+            .collect()
+    }
+
+    fn values(coins: &[(ObjectRef, u64)], selected: Option<Vec<ObjectRef>>) -> Option<Vec<u64>> {
+        selected.map(|selected| {
+            selected
+                .iter()
+                .map(|coin| coins.iter().find(|(c, _)| c == coin).unwrap().1)
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_minimize_inputs() {
+        let coins = coins(&[5, 40, 10, 30]);
+        let select = |amount, max_coins| {
+            values(
+                &coins,
+                MinimizeInputs.select_coins(coins.clone(), amount, max_coins),
+            )
+        };
+        assert_eq!(select(20, 10), Some(vec![40]));
+        assert_eq!(select(60, 10), Some(vec![40, 30]));
+        assert_eq!(select(80, 10), Some(vec![40, 30, 10]));
+        assert_eq!(select(80, 2), None);
+        assert_eq!(select(100, 10), None);
+    }
+
+    #[test]
+    fn test_consolidate_dust() {
+        let coins = coins(&[5, 40, 10, 30, 1]);
+        let select = |amount, max_coins| {
+            values(
+                &coins,
+                ConsolidateDust.select_coins(coins.clone(), amount, max_coins),
+            )
+        };
+        assert_eq!(select(20, 3), Some(vec![40, 1, 5]));
+        assert_eq!(select(60, 10), Some(vec![40, 30, 1, 5, 10]));
+        assert_eq!(select(100, 10), None);
+    }
+
+    #[test]
+    fn test_exact_match() {
+        let coins = coins(&[5, 40, 10, 30]);
+        let select = |amount, max_coins| {
+            values(
+                &coins,
+                ExactMatch.select_coins(coins.clone(), amount, max_coins),
+            )
+        };
+        assert_eq!(select(30, 10), Some(vec![30]));
+        assert_eq!(select(20, 10), Some(vec![30]));
+        assert_eq!(select(45, 10), Some(vec![40, 30]));
+        assert_eq!(select(20, 0), None);
+    }
+
+    #[test]
+    fn test_strategy_from_str() {
+        for strategy in [
+            CoinSelectionStrategy::MinimizeInputs,
+            CoinSelectionStrategy::ConsolidateDust,
+            CoinSelectionStrategy::ExactMatch,
+        ] {
+            assert_eq!(
+                strategy
+                    .to_string()
+                    .parse::<CoinSelectionStrategy>()
+                    .unwrap(),
+                strategy
+            );
+        }
+        assert!("largest-first".parse::<CoinSelectionStrategy>().is_err());
+    }
+}
@@ -1,6 +1,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod coin_selection;
+
 use std::collections::BTreeMap;
 use std::result::Result;
 use std::str::FromStr;
@@ -15,25 +17,30 @@ use move_binary_format::file_format_common::VERSION_MAX;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
 
-use sui_json::{is_receiving_argument, resolve_move_function_args, ResolvedCallArg, SuiJsonValue};
+use sui_json::{
+    is_receiving_argument, resolve_partial_move_function_args, ResolvedCallArg, SuiJsonValue,
+};
 use sui_json_rpc_types::{
-    RPCTransactionRequestParams, SuiData, SuiObjectDataOptions, SuiObjectResponse, SuiRawData,
-    SuiTypeTag,
+    CoinPage, RPCTransactionRequestParams, SuiData, SuiObjectDataOptions, SuiObjectResponse,
+    SuiRawData, SuiTypeTag,
 };
-use sui_protocol_config::ProtocolConfig;
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
 use sui_types::base_types::{ObjectID, ObjectInfo, ObjectRef, ObjectType, SuiAddress};
 use sui_types::error::UserInputError;
-use sui_types::gas_coin::GasCoin;
+use sui_types::gas_coin::GAS;
 use sui_types::governance::{ADD_STAKE_MUL_COIN_FUN_NAME, WITHDRAW_STAKE_FUN_NAME};
 use sui_types::move_package::MovePackage;
 use sui_types::object::{Object, Owner};
 use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::sui_system_state::SUI_SYSTEM_MODULE_NAME;
 use sui_types::transaction::{
-    Argument, CallArg, Command, InputObjectKind, ObjectArg, TransactionData, TransactionKind,
+    Argument, CallArg, Command, InputObjectKind, ObjectArg, ProgrammableTransaction,
+    TransactionData, TransactionKind,
 };
 use sui_types::{coin, fp_ensure, SUI_FRAMEWORK_PACKAGE_ID, SUI_SYSTEM_PACKAGE_ID};
 
+use crate::coin_selection::{CoinSelector, MinimizeInputs};
+
 #[async_trait]
 pub trait DataReader {
     async fn get_owned_objects(
@@ -48,17 +55,60 @@ pub trait DataReader {
         options: SuiObjectDataOptions,
     ) -> Result<SuiObjectResponse, anyhow::Error>;
 
+    /// A page of at most `limit` coins of type `coin_type` owned by `owner`, starting after
+    /// `cursor`.
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: TypeTag,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<CoinPage, anyhow::Error>;
+
     async fn get_reference_gas_price(&self) -> Result<u64, anyhow::Error>;
+
+    /// The protocol config of the current epoch, whose limits the transactions must respect.
+    async fn get_protocol_config(&self) -> Result<ProtocolConfig, anyhow::Error>;
+}
+
+/// Number of coins read at a time when selecting coins.
+const COIN_PAGE_SIZE: usize = 50;
+
+/// The protocol config of `version` for readers that do not know the chain they read from, the
+/// limits the builder reads being the same on all chains. Falls back to the latest version known
+/// to this binary if the network is ahead of it.
+pub fn protocol_config_for_version(version: ProtocolVersion) -> ProtocolConfig {
+    ProtocolConfig::get_for_version(version.min(ProtocolVersion::MAX), Chain::Unknown)
+}
+
+/// An argument of a Move call in a programmable transaction.
+#[derive(Clone, Debug)]
+pub enum MoveCallArg {
+    /// A value resolved against the parameter types of the called function.
+    Json(SuiJsonValue),
+    /// An argument already available in the transaction, e.g. the result of a previous command.
+    Argument(Argument),
 }
 
 #[derive(Clone)]
-pub struct TransactionBuilder(Arc<dyn DataReader + Sync + Send>);
+pub struct TransactionBuilder(Arc<dyn DataReader + Sync + Send>, Arc<dyn CoinSelector>);
 
 impl TransactionBuilder {
     pub fn new(data_reader: Arc<dyn DataReader + Sync + Send>) -> Self {
-        Self(data_reader)
+        Self(data_reader, Arc::new(MinimizeInputs))
+    }
+
+    /// Use `coin_selector` to choose gas and payment coins, instead of [MinimizeInputs].
+    pub fn with_coin_selector(mut self, coin_selector: Arc<dyn CoinSelector>) -> Self {
+        self.1 = coin_selector;
+        self
     }
 
+    /// Select the gas coins paying for a transaction.
+    ///
+    /// If `input_gas` is provided it is used as the only gas coin. Otherwise the signer's SUI
+    /// coins that are not part of `input_objects` are chosen by the coin selector of the
+    /// builder, and they get smashed into a single gas coin at execution.
     async fn select_gas(
         &self,
         signer: SuiAddress,
@@ -66,34 +116,81 @@ impl TransactionBuilder {
         budget: u64,
         input_objects: Vec<ObjectID>,
         gas_price: u64,
-    ) -> Result<ObjectRef, anyhow::Error> {
+    ) -> Result<Vec<ObjectRef>, anyhow::Error> {
         if budget < gas_price {
             bail!("Gas budget {budget} is less than the reference gas price {gas_price}. The gas budget must be at least the current reference gas price of {gas_price}.")
         }
         if let Some(gas) = input_gas {
-            self.get_object_ref(gas).await
-        } else {
-            let gas_objs = self.0.get_owned_objects(signer, GasCoin::type_()).await?;
-
-            for obj in gas_objs {
-                let response = self
-                    .0
-                    .get_object_with_options(obj.object_id, SuiObjectDataOptions::new().with_bcs())
-                    .await?;
-                let obj = response.object()?;
-                let gas: GasCoin = bcs::from_bytes(
-                    &obj.bcs
-                        .as_ref()
-                        .ok_or_else(|| anyhow!("bcs field is unexpectedly empty"))?
-                        .try_as_move()
-                        .ok_or_else(|| anyhow!("Cannot parse move object to gas object"))?
-                        .bcs_bytes,
-                )?;
-                if !input_objects.contains(&obj.object_id) && gas.value() >= budget {
-                    return Ok(obj.object_ref());
-                }
+            return Ok(vec![self.get_object_ref(gas).await?]);
+        }
+        let max_coins = self.max_gas_coins().await?;
+        self.select_from_coin_pages(signer, GAS::type_tag(), budget, max_coins, &input_objects)
+            .await?
+            .ok_or_else(|| anyhow!("Cannot find gas coins for signer address [{signer}] with amount sufficient for the required gas amount [{budget}]."))
+    }
+
+    /// The maximum number of coins a transaction can pay for gas with, in the current protocol
+    /// config.
+    pub async fn max_gas_coins(&self) -> anyhow::Result<usize> {
+        // The gas payment must have strictly less than `max_gas_payment_objects` coins.
+        Ok(self
+            .0
+            .get_protocol_config()
+            .await?
+            .max_gas_payment_objects() as usize
+            - 1)
+    }
+
+    /// Select coins of type `coin_type` owned by `signer`, with a total balance of at least
+    /// `amount`, to pay for a transaction. The coins are chosen by the coin selector of the
+    /// builder, out of those not in `exclude`.
+    pub async fn select_coins(
+        &self,
+        signer: SuiAddress,
+        coin_type: TypeTag,
+        amount: u64,
+        exclude: Vec<ObjectID>,
+    ) -> anyhow::Result<Vec<ObjectRef>> {
+        let max_coins = self.0.get_protocol_config().await?.max_input_objects() as usize;
+        self.select_from_coin_pages(signer, coin_type.clone(), amount, max_coins, &exclude)
+            .await?
+            .ok_or_else(|| {
+                anyhow!("Cannot find coins of type {coin_type} for signer address [{signer}] with amount sufficient for [{amount}].")
+            })
+    }
+
+    /// Select coins of type `coin_type` owned by `owner` and not in `exclude` with the coin
+    /// selector of the builder. The coins are read a page at a time, until the coins read so far
+    /// cover `amount` with at most `max_coins` of them, so that the coins of an address owning
+    /// many are not all read. Returns `None` if all the coins can't cover the amount.
+    async fn select_from_coin_pages(
+        &self,
+        owner: SuiAddress,
+        coin_type: TypeTag,
+        amount: u64,
+        max_coins: usize,
+        exclude: &[ObjectID],
+    ) -> anyhow::Result<Option<Vec<ObjectRef>>> {
+        let mut coins = vec![];
+        let mut cursor = None;
+        loop {
+            let page = self
+                .0
+                .get_coins(owner, coin_type.clone(), cursor, COIN_PAGE_SIZE)
+                .await?;
+            coins.extend(
+                page.data
+                    .iter()
+                    .filter(|coin| !exclude.contains(&coin.coin_object_id))
+                    .map(|coin| (coin.object_ref(), coin.balance)),
+            );
+            if let Some(selected) = self.1.select_coins(coins.clone(), amount, max_coins) {
+                return Ok(Some(selected));
             }
-            Err(anyhow!("Cannot find gas coin for signer address [{signer}] with amount sufficient for the required gas amount [{budget}]."))
+            if !page.has_next_page || page.next_cursor.is_none() {
+                return Ok(None);
+            }
+            cursor = page.next_cursor;
         }
     }
 
@@ -113,7 +210,7 @@ impl TransactionBuilder {
             .select_gas(signer, gas, gas_budget, vec![object_id], gas_price)
             .await?;
 
-        Ok(TransactionData::new(
+        Ok(TransactionData::new_with_gas_coins(
             TransactionKind::programmable(builder.finish()),
             signer,
             gas,
@@ -175,7 +272,7 @@ impl TransactionBuilder {
             .select_gas(signer, gas, gas_budget, input_coins, gas_price)
             .await?;
 
-        TransactionData::new_pay(
+        TransactionData::new_pay_with_gas_coins(
             signer, coin_refs, recipients, amounts, gas, gas_budget, gas_price,
         )
     }
@@ -226,6 +323,16 @@ impl TransactionBuilder {
             !input_coins.is_empty(),
             UserInputError::EmptyInputCoins.into()
         );
+        // All the coins pay for gas.
+        let max_coins = self.max_gas_coins().await?;
+        fp_ensure!(
+            input_coins.len() <= max_coins,
+            UserInputError::SizeLimitExceeded {
+                limit: "maximum number of gas payment objects".to_string(),
+                value: max_coins.to_string()
+            }
+            .into()
+        );
 
         let handles: Vec<_> = input_coins
             .into_iter()
@@ -270,7 +377,19 @@ impl TransactionBuilder {
             call_args,
         )
         .await?;
-        let pt = builder.finish();
+        self.programmable_transaction(signer, builder.finish(), gas, gas_budget)
+            .await
+    }
+
+    /// Create the transaction data of a programmable transaction, selecting gas coins that are
+    /// not inputs of the transaction if `gas` is not provided.
+    pub async fn programmable_transaction(
+        &self,
+        signer: SuiAddress,
+        pt: ProgrammableTransaction,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> anyhow::Result<TransactionData> {
         let input_objects = pt
             .input_objects()?
             .iter()
@@ -284,7 +403,7 @@ impl TransactionBuilder {
             .select_gas(signer, gas, gas_budget, input_objects, gas_price)
             .await?;
 
-        Ok(TransactionData::new(
+        Ok(TransactionData::new_with_gas_coins(
             TransactionKind::programmable(pt),
             signer,
             gas,
@@ -310,6 +429,7 @@ impl TransactionBuilder {
             .map(|ty| ty.try_into())
             .collect::<Result<Vec<_>, _>>()?;
 
+        let call_args = call_args.into_iter().map(MoveCallArg::Json).collect();
         let call_args = self
             .resolve_and_checks_json_args(
                 builder, package, &module, &function, &type_args, call_args,
@@ -322,6 +442,61 @@ impl TransactionBuilder {
         Ok(())
     }
 
+    /// Add a Move call to `builder`, returning its result so that it can be used by the next
+    /// commands.
+    pub async fn programmable_move_call(
+        &self,
+        builder: &mut ProgrammableTransactionBuilder,
+        package: ObjectID,
+        module: &str,
+        function: &str,
+        type_args: Vec<SuiTypeTag>,
+        call_args: Vec<MoveCallArg>,
+    ) -> anyhow::Result<Argument> {
+        let module = Identifier::from_str(module)?;
+        let function = Identifier::from_str(function)?;
+
+        let type_args = type_args
+            .into_iter()
+            .map(|ty| ty.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let call_args = self
+            .resolve_and_checks_json_args(
+                builder, package, &module, &function, &type_args, call_args,
+            )
+            .await?;
+
+        Ok(builder.programmable_move_call(package, module, function, type_args, call_args))
+    }
+
+    /// Add an object to the inputs of `builder`, as a shared object if it is shared and as an
+    /// owned or immutable object otherwise. `mutable` tells whether a shared object is used
+    /// mutably (or by value) by the transaction, it is ignored for other objects.
+    pub async fn object_input(
+        &self,
+        builder: &mut ProgrammableTransactionBuilder,
+        object_id: ObjectID,
+        mutable: bool,
+    ) -> anyhow::Result<Argument> {
+        let object = self
+            .0
+            .get_object_with_options(object_id, SuiObjectDataOptions::new().with_owner())
+            .await?
+            .into_object()?;
+        let object_arg = match object.owner {
+            Some(Owner::Shared {
+                initial_shared_version,
+            }) => ObjectArg::SharedObject {
+                id: object_id,
+                initial_shared_version,
+                mutable,
+            },
+            _ => ObjectArg::ImmOrOwnedObject(object.object_ref()),
+        };
+        builder.obj(object_arg)
+    }
+
     async fn get_object_arg(
         &self,
         id: ObjectID,
@@ -363,7 +538,7 @@ impl TransactionBuilder {
         module: &Identifier,
         function: &Identifier,
         type_args: &[TypeTag],
-        json_args: Vec<SuiJsonValue>,
+        args: Vec<MoveCallArg>,
     ) -> Result<Vec<Argument>, anyhow::Error> {
         let object = self
             .0
@@ -385,7 +560,21 @@ impl TransactionBuilder {
             package.linkage_table,
         )?;
 
-        let json_args_and_tokens = resolve_move_function_args(
+        let mut json_args = Vec::with_capacity(args.len());
+        let mut arguments = Vec::with_capacity(args.len());
+        for arg in args {
+            match arg {
+                MoveCallArg::Json(value) => {
+                    json_args.push(Some(value));
+                    arguments.push(None);
+                }
+                MoveCallArg::Argument(argument) => {
+                    json_args.push(None);
+                    arguments.push(Some(argument));
+                }
+            }
+        }
+        let json_args_and_tokens = resolve_partial_move_function_args(
             &package,
             module.clone(),
             function.clone(),
@@ -397,7 +586,11 @@ impl TransactionBuilder {
         let mut objects = BTreeMap::new();
         let module = package.deserialize_module(module, VERSION_MAX, true)?;
         let view = BinaryIndexedView::Module(&module);
-        for (arg, expected_type) in json_args_and_tokens {
+        for ((arg, expected_type), argument) in json_args_and_tokens.into_iter().zip(arguments) {
+            let Some(arg) = arg else {
+                args.push(argument.expect("arguments without a JSON value are provided"));
+                continue;
+            };
             args.push(match arg {
                 ResolvedCallArg::Pure(p) => builder.input(CallArg::Pure(p)),
 
@@ -448,7 +641,7 @@ impl TransactionBuilder {
         let gas = self
             .select_gas(sender, gas, gas_budget, vec![], gas_price)
             .await?;
-        Ok(TransactionData::new_module(
+        Ok(TransactionData::new_module_with_gas_coins(
             sender,
             gas,
             compiled_modules,
@@ -482,7 +675,7 @@ impl TransactionBuilder {
         let cap_owner = upgrade_cap
             .owner
             .ok_or_else(|| anyhow!("Unable to determine ownership of upgrade capability"))?;
-        TransactionData::new_upgrade(
+        TransactionData::new_upgrade_with_gas_coins(
             sender,
             gas,
             package_id,
@@ -518,7 +711,7 @@ impl TransactionBuilder {
             .select_gas(signer, gas, gas_budget, vec![coin_object_id], gas_price)
             .await?;
 
-        TransactionData::new_move_call(
+        TransactionData::new_move_call_with_gas_coins(
             signer,
             SUI_FRAMEWORK_PACKAGE_ID,
             coin::PAY_MODULE_NAME.to_owned(),
@@ -556,7 +749,7 @@ impl TransactionBuilder {
             .select_gas(signer, gas, gas_budget, vec![coin_object_id], gas_price)
             .await?;
 
-        TransactionData::new_move_call(
+        TransactionData::new_move_call_with_gas_coins(
             signer,
             SUI_FRAMEWORK_PACKAGE_ID,
             coin::PAY_MODULE_NAME.to_owned(),
@@ -601,7 +794,7 @@ impl TransactionBuilder {
             )
             .await?;
 
-        TransactionData::new_move_call(
+        TransactionData::new_move_call_with_gas_coins(
             signer,
             SUI_FRAMEWORK_PACKAGE_ID,
             coin::PAY_MODULE_NAME.to_owned(),
@@ -651,27 +844,8 @@ impl TransactionBuilder {
                 }
             };
         }
-        let pt = builder.finish();
-        let all_inputs = pt.input_objects()?;
-        let inputs = all_inputs
-            .iter()
-            .flat_map(|obj| match obj {
-                InputObjectKind::ImmOrOwnedMoveObject((id, _, _)) => Some(*id),
-                _ => None,
-            })
-            .collect();
-        let gas_price = self.0.get_reference_gas_price().await?;
-        let gas = self
-            .select_gas(signer, gas, gas_budget, inputs, gas_price)
-            .await?;
-
-        Ok(TransactionData::new(
-            TransactionKind::programmable(pt),
-            signer,
-            gas,
-            gas_budget,
-            gas_price,
-        ))
+        self.programmable_transaction(signer, builder.finish(), gas, gas_budget)
+            .await
     }
 
     pub async fn request_add_stake(
@@ -734,11 +908,7 @@ impl TransactionBuilder {
             builder.finish()
         };
         Ok(TransactionData::new_programmable(
-            signer,
-            vec![gas],
-            pt,
-            gas_budget,
-            gas_price,
+            signer, gas, pt, gas_budget, gas_price,
         ))
     }
 
@@ -754,7 +924,7 @@ impl TransactionBuilder {
         let gas = self
             .select_gas(signer, gas, gas_budget, vec![], gas_price)
             .await?;
-        TransactionData::new_move_call(
+        TransactionData::new_move_call_with_gas_coins(
             signer,
             SUI_SYSTEM_PACKAGE_ID,
             SUI_SYSTEM_MODULE_NAME.to_owned(),
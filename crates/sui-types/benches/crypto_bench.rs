@@ -0,0 +1,173 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use fastcrypto::traits::AggregateAuthenticator;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use shared_crypto::intent::{Intent, IntentMessage, IntentScope};
+use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+use sui_types::committee::EpochId;
+use sui_types::crypto::{
+    default_hash, get_key_pair_from_rng, random_committee_key_pairs_of_size, AccountKeyPair,
+    AggregateAuthoritySignature, AuthoritySignature, KeypairTraits, Signer, SuiAuthoritySignature,
+    VerificationObligation, VerifyingKey,
+};
+use sui_types::digests::ObjectDigest;
+use sui_types::transaction::{CallArg, ProgrammableTransaction, TransactionData};
+
+use criterion::*;
+
+const EPOCH: EpochId = 0;
+const COMMITTEE_SIZE: usize = 4;
+
+fn verify_all_benchmark(c: &mut Criterion) {
+    let key_pairs = random_committee_key_pairs_of_size(COMMITTEE_SIZE);
+
+    let mut group = c.benchmark_group("verification_obligation");
+    for batch_size in [1, 10, 100, 1000] {
+        let messages: Vec<_> = (0..batch_size as u64).collect();
+        let signatures: Vec<Vec<AuthoritySignature>> = messages
+            .iter()
+            .map(|message| {
+                let intent_msg =
+                    IntentMessage::new(Intent::sui_app(IntentScope::CheckpointSummary), message);
+                key_pairs
+                    .iter()
+                    .map(|kp| AuthoritySignature::new_secure(&intent_msg, &EPOCH, kp))
+                    .collect()
+            })
+            .collect();
+
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("verify_all", batch_size),
+            &batch_size,
+            |b, _| {
+                b.iter_batched(
+                    || {
+                        let mut obligation = VerificationObligation::default();
+                        for (message, signatures) in messages.iter().zip(&signatures) {
+                            let idx = obligation.add_message(
+                                message,
+                                EPOCH,
+                                Intent::sui_app(IntentScope::CheckpointSummary),
+                            );
+                            for (signature, kp) in signatures.iter().zip(&key_pairs) {
+                                obligation
+                                    .add_signature_and_public_key(signature, kp.public(), idx)
+                                    .unwrap();
+                            }
+                        }
+                        obligation
+                    },
+                    |obligation| obligation.verify_all().unwrap(),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+}
+
+fn ed25519_benchmark(c: &mut Criterion) {
+    let mut rng = StdRng::from_seed([0; 32]);
+    let mut group = c.benchmark_group("ed25519");
+    for batch_size in [1, 10, 100, 1000] {
+        let key_pairs: Vec<AccountKeyPair> = (0..batch_size)
+            .map(|_| get_key_pair_from_rng(&mut rng).1)
+            .collect();
+        let messages: Vec<Vec<u8>> = (0..batch_size as u64)
+            .map(|i| i.to_le_bytes().to_vec())
+            .collect();
+        let signatures: Vec<Ed25519Signature> = key_pairs
+            .iter()
+            .zip(&messages)
+            .map(|(kp, message)| kp.sign(message))
+            .collect();
+        let public_keys: Vec<Ed25519PublicKey> =
+            key_pairs.iter().map(|kp| kp.public().clone()).collect();
+
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("verify_single", batch_size),
+            &batch_size,
+            |b, _| {
+                b.iter(|| {
+                    for ((pk, message), signature) in
+                        public_keys.iter().zip(&messages).zip(&signatures)
+                    {
+                        pk.verify(message, signature).unwrap();
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("verify_batch", batch_size),
+            &batch_size,
+            |b, _| {
+                b.iter(|| {
+                    Ed25519PublicKey::verify_batch_empty_fail_different_msg(
+                        &messages,
+                        &public_keys,
+                        &signatures,
+                    )
+                    .unwrap()
+                })
+            },
+        );
+    }
+}
+
+fn bls_aggregation_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bls12381");
+    for committee_size in [4, 10, 100] {
+        let key_pairs = random_committee_key_pairs_of_size(committee_size);
+        let intent_msg = IntentMessage::new(Intent::sui_app(IntentScope::CheckpointSummary), 0u64);
+        let signatures: Vec<AuthoritySignature> = key_pairs
+            .iter()
+            .map(|kp| AuthoritySignature::new_secure(&intent_msg, &EPOCH, kp))
+            .collect();
+
+        group.throughput(Throughput::Elements(committee_size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("aggregate", committee_size),
+            &committee_size,
+            |b, _| b.iter(|| AggregateAuthoritySignature::aggregate(&signatures).unwrap()),
+        );
+    }
+}
+
+fn transaction_digest_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transaction_digest");
+    // From a simple transfer up to a transaction carrying a large pure argument.
+    for pure_size in [32, 1024, 16 * 1024, 128 * 1024] {
+        let pt = ProgrammableTransaction {
+            inputs: vec![CallArg::Pure(vec![0; pure_size])],
+            commands: vec![],
+        };
+        let tx_data = TransactionData::new_programmable(
+            SuiAddress::ZERO,
+            vec![(ObjectID::ZERO, SequenceNumber::new(), ObjectDigest::MIN)],
+            pt,
+            0,
+            0,
+        );
+        let size = bcs::serialized_size(&tx_data).unwrap();
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("default_hash", size),
+            &tx_data,
+            |b, tx_data| b.iter(|| default_hash(tx_data)),
+        );
+    }
+}
+
+criterion_group!(
+    benches,
+    verify_all_benchmark,
+    ed25519_benchmark,
+    bls_aggregation_benchmark,
+    transaction_digest_benchmark
+);
+criterion_main!(benches);
@@ -1133,6 +1133,18 @@ impl ObjectID {
         ObjectID::try_from(&hash.as_ref()[0..ObjectID::LENGTH]).unwrap()
     }
 
+    /// Find the `creation_num` under which the transaction `digest` derived this ID, trying the
+    /// numbers below `max_creation_num`. A transaction derives the IDs of the objects it creates
+    /// with consecutive creation numbers starting from zero, so this recovers, and thereby
+    /// proves, which transaction created an object and in which order.
+    pub fn find_creation_num(
+        &self,
+        digest: TransactionDigest,
+        max_creation_num: u64,
+    ) -> Option<u64> {
+        (0..max_creation_num).find(|creation_num| Self::derive_id(digest, *creation_num) == *self)
+    }
+
     /// Incremenent the ObjectID by usize IDs, assuming the ObjectID hex is a number represented as an array of bytes
     pub fn advance(&self, step: usize) -> Result<ObjectID, anyhow::Error> {
         let mut curr_vec = self.to_vec();
@@ -58,6 +58,10 @@ use tracing::{instrument, warn};
 #[path = "unit_tests/crypto_tests.rs"]
 mod crypto_tests;
 
+#[cfg(test)]
+#[path = "unit_tests/bcs_signable_tests.rs"]
+mod bcs_signable_tests;
+
 #[cfg(test)]
 #[cfg(feature = "test-utils")]
 #[path = "unit_tests/intent_tests.rs"]
@@ -149,30 +153,9 @@ impl SuiKeyPair {
             SuiKeyPair::Secp256r1(kp) => PublicKey::Secp256r1(kp.public().into()),
         }
     }
-}
-
-impl Signer<Signature> for SuiKeyPair {
-    fn sign(&self, msg: &[u8]) -> Signature {
-        match self {
-            SuiKeyPair::Ed25519(kp) => kp.sign(msg),
-            SuiKeyPair::Secp256k1(kp) => kp.sign(msg),
-            SuiKeyPair::Secp256r1(kp) => kp.sign(msg),
-        }
-    }
-}
-
-impl FromStr for SuiKeyPair {
-    type Err = eyre::Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let kp = Self::decode_base64(s).map_err(|e| eyre!("{}", e.to_string()))?;
-        Ok(kp)
-    }
-}
 
-impl EncodeDecodeBase64 for SuiKeyPair {
-    /// Encode a SuiKeyPair as `flag || privkey` in Base64. Note that the pubkey is not encoded.
-    fn encode_base64(&self) -> String {
+    /// Returns the `flag || privkey` bytes of the keypair.
+    pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes: Vec<u8> = Vec::new();
         bytes.push(self.public().flag());
 
@@ -187,12 +170,11 @@ impl EncodeDecodeBase64 for SuiKeyPair {
                 bytes.extend_from_slice(kp.as_bytes());
             }
         }
-        Base64::encode(&bytes[..])
+        bytes
     }
 
-    /// Decode a SuiKeyPair from `flag || privkey` in Base64. The public key is computed directly from the private key bytes.
-    fn decode_base64(value: &str) -> Result<Self, eyre::Report> {
-        let bytes = Base64::decode(value).map_err(|e| eyre!("{}", e.to_string()))?;
+    /// Parse a keypair from `flag || privkey` bytes. The public key is computed from the private key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, eyre::Report> {
         match SignatureScheme::from_flag_byte(bytes.first().ok_or_else(|| eyre!("Invalid length"))?)
         {
             Ok(x) => match x {
@@ -216,6 +198,38 @@ impl EncodeDecodeBase64 for SuiKeyPair {
     }
 }
 
+impl Signer<Signature> for SuiKeyPair {
+    fn sign(&self, msg: &[u8]) -> Signature {
+        match self {
+            SuiKeyPair::Ed25519(kp) => kp.sign(msg),
+            SuiKeyPair::Secp256k1(kp) => kp.sign(msg),
+            SuiKeyPair::Secp256r1(kp) => kp.sign(msg),
+        }
+    }
+}
+
+impl FromStr for SuiKeyPair {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kp = Self::decode_base64(s).map_err(|e| eyre!("{}", e.to_string()))?;
+        Ok(kp)
+    }
+}
+
+impl EncodeDecodeBase64 for SuiKeyPair {
+    /// Encode a SuiKeyPair as `flag || privkey` in Base64. Note that the pubkey is not encoded.
+    fn encode_base64(&self) -> String {
+        Base64::encode(self.to_bytes())
+    }
+
+    /// Decode a SuiKeyPair from `flag || privkey` in Base64. The public key is computed directly from the private key bytes.
+    fn decode_base64(value: &str) -> Result<Self, eyre::Report> {
+        let bytes = Base64::decode(value).map_err(|e| eyre!("{}", e.to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
 impl Serialize for SuiKeyPair {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -0,0 +1,178 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Limits enforced while deserializing messages received from the network, so that a single
+//! message can't make a node allocate unbounded memory.
+//!
+//! Messages are deserialized before the protocol version they are checked against is known, so
+//! each limit is the largest value it takes in any supported protocol version. Messages that are
+//! within the limits are still subject to the checks of their protocol version afterwards.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use once_cell::sync::Lazy;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
+
+/// Size of a certificate in addition to its transaction, excluding the signers bitmap: the
+/// epoch, the aggregate signature and the length prefixes.
+const CERTIFICATE_OVERHEAD_BYTES: usize = 128;
+
+#[derive(Debug)]
+pub struct DeserializationLimits {
+    pub max_tx_size_bytes: usize,
+    pub max_tx_signatures: usize,
+    pub max_signers_map_bytes: usize,
+    pub max_num_events_per_tx: usize,
+}
+
+static DESERIALIZATION_LIMITS: Lazy<DeserializationLimits> = Lazy::new(|| {
+    let configs: Vec<_> = (ProtocolVersion::MIN.as_u64()..=ProtocolVersion::MAX.as_u64())
+        .flat_map(|version| {
+            [Chain::Mainnet, Chain::Testnet, Chain::Unknown]
+                .map(|chain| ProtocolConfig::get_for_version(version.into(), chain))
+        })
+        .collect();
+    // Versions that predate a limit don't enforce it, but their messages are no different.
+    let max_limit = |limit: fn(&ProtocolConfig) -> Option<u64>| {
+        configs
+            .iter()
+            .filter_map(limit)
+            .max()
+            .map_or(usize::MAX, |limit| limit as usize)
+    };
+    DeserializationLimits {
+        max_tx_size_bytes: max_limit(|config| config.max_tx_size_bytes_as_option()),
+        max_tx_signatures: max_limit(|config| config.max_tx_signatures_as_option()),
+        max_signers_map_bytes: max_limit(|config| config.max_signers_map_bytes_as_option()),
+        max_num_events_per_tx: max_limit(|config| config.max_num_events_per_tx_as_option()),
+    }
+});
+
+impl DeserializationLimits {
+    pub fn get() -> &'static Self {
+        &DESERIALIZATION_LIMITS
+    }
+
+    /// The largest request accepted by the validator gRPC service, i.e. a certificate for a
+    /// transaction of the maximum size.
+    pub fn max_validator_request_size_bytes(&self) -> usize {
+        self.max_tx_size_bytes
+            .saturating_add(self.max_signers_map_bytes)
+            .saturating_add(CERTIFICATE_OVERHEAD_BYTES)
+    }
+}
+
+/// Deserializes a sequence of at most `max_len` elements. The length is checked before any
+/// element is deserialized when the format provides it, as BCS does.
+pub(crate) fn deserialize_bounded_vec<'de, D, T>(
+    deserializer: D,
+    max_len: usize,
+) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct BoundedVecVisitor<T> {
+        max_len: usize,
+        marker: PhantomData<T>,
+    }
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for BoundedVecVisitor<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a sequence of at most {} elements", self.max_len)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let len = seq.size_hint().unwrap_or(0);
+            if len > self.max_len {
+                return Err(A::Error::invalid_length(len, &self));
+            }
+            let mut values = Vec::with_capacity(len);
+            while let Some(value) = seq.next_element()? {
+                if values.len() == self.max_len {
+                    return Err(A::Error::invalid_length(self.max_len + 1, &self));
+                }
+                values.push(value);
+            }
+            Ok(values)
+        }
+    }
+
+    deserializer.deserialize_seq(BoundedVecVisitor {
+        max_len,
+        marker: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sui_serde::SuiBitmap;
+    use roaring::RoaringBitmap;
+    use serde::Serialize;
+    use serde_with::serde_as;
+
+    #[derive(Debug, Deserialize)]
+    struct Bounded(#[serde(deserialize_with = "deserialize_three")] Vec<u64>);
+
+    fn deserialize_three<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u64>, D::Error> {
+        deserialize_bounded_vec(deserializer, 3)
+    }
+
+    #[test]
+    fn test_deserialize_bounded_vec() {
+        let bytes = bcs::to_bytes(&vec![1u64, 2, 3]).unwrap();
+        assert_eq!(bcs::from_bytes::<Bounded>(&bytes).unwrap().0, vec![1, 2, 3]);
+
+        let bytes = bcs::to_bytes(&vec![1u64, 2, 3, 4]).unwrap();
+        assert!(bcs::from_bytes::<Bounded>(&bytes).is_err());
+
+        // The length prefix alone is rejected, without the elements.
+        let bytes = bcs::to_bytes(&vec![(); 1_000_000]).unwrap();
+        assert!(bcs::from_bytes::<Bounded>(&bytes).is_err());
+
+        assert_eq!(
+            serde_json::from_str::<Bounded>("[1,2]").unwrap().0,
+            vec![1, 2]
+        );
+        assert!(serde_json::from_str::<Bounded>("[1,2,3,4]").is_err());
+    }
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize)]
+    struct SignersMap(#[serde_as(as = "SuiBitmap")] RoaringBitmap);
+
+    #[test]
+    fn test_signers_map_size_limit() {
+        let max_bytes = DeserializationLimits::get().max_signers_map_bytes;
+
+        let bitmap = RoaringBitmap::from_iter(0..100);
+        let bytes = bcs::to_bytes(&SignersMap(bitmap.clone())).unwrap();
+        assert_eq!(bcs::from_bytes::<SignersMap>(&bytes).unwrap().0, bitmap);
+
+        // Every other index, so that the bitmap can't be stored as a run.
+        let bitmap = RoaringBitmap::from_iter((0..max_bytes as u32).map(|i| i * 2));
+        assert!(bitmap.serialized_size() > max_bytes);
+        let bytes = bcs::to_bytes(&SignersMap(bitmap)).unwrap();
+        assert!(bcs::from_bytes::<SignersMap>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_limits_cover_all_protocol_versions() {
+        let limits = DeserializationLimits::get();
+        let config = ProtocolConfig::get_for_max_version_UNSAFE();
+        assert!(limits.max_tx_size_bytes >= config.max_tx_size_bytes() as usize);
+        assert!(limits.max_tx_signatures >= config.max_tx_signatures() as usize);
+        assert!(limits.max_signers_map_bytes >= config.max_signers_map_bytes() as usize);
+        assert!(limits.max_num_events_per_tx >= config.max_num_events_per_tx() as usize);
+        assert!(limits.max_validator_request_size_bytes() > limits.max_tx_size_bytes);
+    }
+}
@@ -7,6 +7,7 @@ use crate::committee::EpochId;
 use crate::crypto::{
     default_hash, AuthoritySignInfo, AuthorityStrongQuorumSignInfo, EmptySignInfo,
 };
+use crate::deserialization_limits::{deserialize_bounded_vec, DeserializationLimits};
 use crate::digests::{
     ObjectDigest, TransactionDigest, TransactionEffectsDigest, TransactionEventsDigest,
 };
@@ -25,7 +26,7 @@ use effects_v1::TransactionEffectsV1;
 pub use effects_v2::UnchangedSharedKind;
 use enum_dispatch::enum_dispatch;
 pub use object_change::{EffectsObjectChange, ObjectIn, ObjectOut};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use shared_crypto::intent::IntentScope;
 use std::collections::BTreeMap;
 use sui_protocol_config::ProtocolConfig;
@@ -444,9 +445,20 @@ pub enum IDOperation {
 
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, Default)]
 pub struct TransactionEvents {
+    #[serde(deserialize_with = "deserialize_events")]
     pub data: Vec<Event>,
 }
 
+fn deserialize_events<'de, D>(deserializer: D) -> Result<Vec<Event>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_bounded_vec(
+        deserializer,
+        DeserializationLimits::get().max_num_events_per_tx,
+    )
+}
+
 impl TransactionEvents {
     pub fn digest(&self) -> TransactionEventsDigest {
         TransactionEventsDigest::new(default_hash(self))
@@ -13,7 +13,7 @@ use crate::{
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, fmt::Debug};
+use std::{collections::BTreeMap, fmt::Debug, time::Duration};
 use strum_macros::{AsRefStr, IntoStaticStr};
 use thiserror::Error;
 use tonic::Status;
@@ -41,6 +41,10 @@ use crate::digests::TransactionEventsDigest;
 use crate::execution_status::{CommandIndex, ExecutionFailureStatus};
 pub(crate) use fp_ensure;
 
+#[cfg(test)]
+#[path = "unit_tests/error_tests.rs"]
+mod error_tests;
+
 #[macro_export]
 macro_rules! exit_main {
     ($result:expr) => {
@@ -254,6 +258,9 @@ pub enum UserInputError {
         address: SuiAddress,
         coin_type: String,
     },
+
+    #[error("Commands following a command using Random can only be TransferObjects or MergeCoins")]
+    PostRandomCommandRestrictions,
 }
 
 #[derive(
@@ -295,6 +302,160 @@ pub enum SuiObjectResponseError {
     // TODO: also integrate SuiPastObjectResponse (VersionNotFound,  VersionTooHigh)
 }
 
+impl UserInputError {
+    /// The stable code of the error, see [SuiError::code].
+    pub fn code(&self) -> u32 {
+        match self {
+            UserInputError::MutableObjectUsedMoreThanOnce { .. } => 1500,
+            UserInputError::ObjectInputArityViolation => 1501,
+            UserInputError::ObjectNotFound { .. } => 1502,
+            UserInputError::ObjectVersionUnavailableForConsumption { .. } => 1503,
+            UserInputError::PackageVerificationTimedout { .. } => 1504,
+            UserInputError::DependentPackageNotFound { .. } => 1505,
+            UserInputError::ImmutableParameterExpectedError { .. } => 1506,
+            UserInputError::SizeLimitExceeded { .. } => 1507,
+            UserInputError::InvalidChildObjectArgument { .. } => 1508,
+            UserInputError::InvalidObjectDigest { .. } => 1509,
+            UserInputError::InvalidSequenceNumber => 1510,
+            UserInputError::MovePackageAsObject { .. } => 1511,
+            UserInputError::MoveObjectAsPackage { .. } => 1512,
+            UserInputError::IncorrectUserSignature { .. } => 1513,
+            UserInputError::NotSharedObjectError => 1514,
+            UserInputError::DuplicateObjectRefInput => 1515,
+            UserInputError::MissingGasPayment => 1516,
+            UserInputError::GasObjectNotOwnedObject { .. } => 1517,
+            UserInputError::GasBudgetTooHigh { .. } => 1518,
+            UserInputError::GasBudgetTooLow { .. } => 1519,
+            UserInputError::GasBalanceTooLow { .. } => 1520,
+            UserInputError::UnsupportedSponsoredTransactionKind => 1521,
+            UserInputError::GasPriceUnderRGP { .. } => 1522,
+            UserInputError::GasPriceTooHigh { .. } => 1523,
+            UserInputError::InvalidGasObject { .. } => 1524,
+            UserInputError::InsufficientBalanceToCoverMinimalGas => 1525,
+            UserInputError::ObjectSequenceNumberTooHigh { .. } => 1526,
+            UserInputError::ObjectDeleted { .. } => 1527,
+            UserInputError::InvalidBatchTransaction { .. } => 1528,
+            UserInputError::BlockedMoveFunction => 1529,
+            UserInputError::EmptyInputCoins => 1530,
+            UserInputError::UnexpectedGasPaymentObject => 1531,
+            UserInputError::SharedObjectStartingVersionMismatch => 1532,
+            UserInputError::TransferObjectWithoutPublicTransferError { .. } => 1533,
+            UserInputError::EmptyCommandInput => 1534,
+            UserInputError::TransactionDenied { .. } => 1535,
+            UserInputError::Unsupported(..) => 1536,
+            UserInputError::MoveFunctionInputError(..) => 1537,
+            UserInputError::VerifiedCheckpointNotFound(..) => 1538,
+            UserInputError::VerifiedCheckpointDigestNotFound(..) => 1539,
+            UserInputError::LatestCheckpointSequenceNumberNotFound => 1540,
+            UserInputError::CheckpointContentsNotFound(..) => 1541,
+            UserInputError::GenesisTransactionNotFound => 1542,
+            UserInputError::TransactionCursorNotFound(..) => 1543,
+            UserInputError::InaccessibleSystemObject { .. } => 1544,
+            UserInputError::MaxPublishCountExceeded { .. } => 1545,
+            UserInputError::MutableParameterExpected { .. } => 1546,
+            UserInputError::AddressDeniedForCoin { .. } => 1547,
+            UserInputError::PostRandomCommandRestrictions => 1548,
+        }
+    }
+}
+
+impl SuiObjectResponseError {
+    /// The stable code of the error, see [SuiError::code].
+    pub fn code(&self) -> u32 {
+        match self {
+            SuiObjectResponseError::NotExists { .. } => 2500,
+            SuiObjectResponseError::DynamicFieldNotFound { .. } => 2501,
+            SuiObjectResponseError::Deleted { .. } => 2502,
+            SuiObjectResponseError::Unknown => 15500,
+            SuiObjectResponseError::DisplayError { .. } => 14500,
+        }
+    }
+}
+
+/// The category of a [SuiError]. The kind of an error is given by its [SuiError::code], which
+/// is in the range `kind * 1000..kind * 1000 + 1000`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum SuiErrorKind {
+    /// The transaction or request is malformed or its inputs are invalid.
+    InvalidInput,
+    /// The requested object, transaction or events do not exist.
+    NotFound,
+    /// The validator is overloaded, the request can be retried later.
+    Overload,
+    /// A user or authority signature is missing or invalid.
+    Signature,
+    /// A certificate or the effects of a quorum could not be formed or verified.
+    Certificate,
+    /// The request is for the wrong epoch, or the epoch is changing.
+    Epoch,
+    /// A Move package could not be built, verified, published or called.
+    Move,
+    /// An owned object is already locked by another transaction.
+    ObjectLock,
+    /// The node failed to read or write its storage.
+    Storage,
+    /// The node failed to submit to or process the output of consensus.
+    Consensus,
+    /// A key could not be generated, converted or fetched.
+    Crypto,
+    /// The request failed or timed out in the network.
+    Network,
+    /// The node does not support the requested feature.
+    Unsupported,
+    /// A value could not be serialized or deserialized.
+    Serialization,
+    /// An unexpected internal error.
+    Internal,
+}
+
+impl SuiErrorKind {
+    pub fn from_code(code: u32) -> Self {
+        match code / 1000 {
+            1 => SuiErrorKind::InvalidInput,
+            2 => SuiErrorKind::NotFound,
+            3 => SuiErrorKind::Overload,
+            4 => SuiErrorKind::Signature,
+            5 => SuiErrorKind::Certificate,
+            6 => SuiErrorKind::Epoch,
+            7 => SuiErrorKind::Move,
+            8 => SuiErrorKind::ObjectLock,
+            9 => SuiErrorKind::Storage,
+            10 => SuiErrorKind::Consensus,
+            11 => SuiErrorKind::Crypto,
+            12 => SuiErrorKind::Network,
+            13 => SuiErrorKind::Unsupported,
+            14 => SuiErrorKind::Serialization,
+            _ => SuiErrorKind::Internal,
+        }
+    }
+}
+
+/// The machine-readable form of a [SuiError], attached to the errors of the JSON-RPC API so
+/// that clients can branch on them instead of on error messages.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiErrorData {
+    pub kind: SuiErrorKind,
+    /// The stable code of the error, see [SuiError::code].
+    pub code: u32,
+    /// Whether the same request may succeed if retried.
+    pub retriable: bool,
+    /// The fields of the error, e.g. the object id of an object that was not found.
+    pub context: BTreeMap<String, serde_json::Value>,
+}
+
+impl SuiErrorData {
+    /// The delay an overloaded validator asked clients to wait before retrying, see
+    /// [SuiError::retry_after].
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.context
+            .get("retry_after_ms")?
+            .as_u64()
+            .map(Duration::from_millis)
+    }
+}
+
 /// Custom error type for Sui.
 #[derive(
     Eq, PartialEq, Clone, Debug, Serialize, Deserialize, Error, Hash, AsRefStr, IntoStaticStr,
@@ -315,6 +476,23 @@ pub enum SuiError {
     #[error("There are too many transactions pending in consensus")]
     TooManyTransactionsPendingConsensus,
 
+    #[error("Sender {sender} already has {in_flight} transactions being signed, above threshold of {threshold}")]
+    TooManyTransactionsInFlightFromSender {
+        sender: SuiAddress,
+        in_flight: usize,
+        threshold: usize,
+    },
+
+    #[error("Client {client} already has {in_flight} transactions being signed, above threshold of {threshold}")]
+    TooManyTransactionsInFlightFromClient {
+        client: String,
+        in_flight: usize,
+        threshold: usize,
+    },
+
+    #[error("Gas price {gas_price} is below the inclusion threshold of {threshold} while the validator is under load")]
+    GasPriceTooLowUnderLoad { gas_price: u64, threshold: u64 },
+
     #[error("Input {object_id} already has {queue_len} transactions pending, above threshold of {threshold}")]
     TooManyTransactionsPendingOnObject {
         object_id: ObjectID,
@@ -601,6 +779,42 @@ pub enum SuiError {
 
     #[error("Failed to get JWK")]
     JWKRetrievalError,
+
+    #[error("Validator is overloaded with a {queue_depth:?} queue of transactions pending execution, retry after {retry_after_ms}ms: {error}")]
+    ValidatorOverloaded {
+        error: Box<SuiError>,
+        queue_depth: QueueDepthClass,
+        retry_after_ms: u64,
+    },
+}
+
+/// How full the queue of transactions pending execution of an overloaded validator is, relative
+/// to the length at which it stops signing new transactions.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug, Serialize, Deserialize, Hash)]
+pub enum QueueDepthClass {
+    /// Less than a quarter full. The validator sheds load for another reason, e.g. a sender or
+    /// client with too many transactions in flight.
+    Low,
+    /// Less than half full.
+    Medium,
+    /// At least half full.
+    High,
+    /// Full, the validator does not sign new transactions.
+    Full,
+}
+
+impl QueueDepthClass {
+    pub fn new(queue_len: usize, capacity: usize) -> Self {
+        if queue_len >= capacity {
+            QueueDepthClass::Full
+        } else if queue_len.saturating_mul(2) >= capacity {
+            QueueDepthClass::High
+        } else if queue_len.saturating_mul(4) >= capacity {
+            QueueDepthClass::Medium
+        } else {
+            QueueDepthClass::Low
+        }
+    }
 }
 
 #[repr(u64)]
@@ -626,6 +840,7 @@ pub enum VMMemoryLimitExceededSubStatusCode {
     OBJECT_RUNTIME_CACHE_LIMIT_EXCEEDED = 5,
     OBJECT_RUNTIME_STORE_LIMIT_EXCEEDED = 6,
     TOTAL_EVENT_SIZE_LIMIT_EXCEEDED = 7,
+    INSTRUCTION_LIMIT_EXCEEDED = 8,
 }
 
 pub type SuiResult<T = ()> = Result<T, SuiError>;
@@ -750,6 +965,10 @@ impl SuiError {
             SuiError::TooManyTransactionsPendingOnObject { .. } => (true, true),
             SuiError::TooOldTransactionPendingOnObject { .. } => (true, true),
             SuiError::TooManyTransactionsPendingConsensus => (true, true),
+            SuiError::TooManyTransactionsInFlightFromSender { .. } => (true, true),
+            SuiError::TooManyTransactionsInFlightFromClient { .. } => (true, true),
+            SuiError::GasPriceTooLowUnderLoad { .. } => (true, true),
+            SuiError::ValidatorOverloaded { error, .. } => error.is_retryable(),
 
             // Non retryable error
             SuiError::ExecutionError(..) => (false, true),
@@ -785,8 +1004,176 @@ impl SuiError {
                 | SuiError::TooManyTransactionsPendingOnObject { .. }
                 | SuiError::TooOldTransactionPendingOnObject { .. }
                 | SuiError::TooManyTransactionsPendingConsensus
+                | SuiError::TooManyTransactionsInFlightFromSender { .. }
+                | SuiError::TooManyTransactionsInFlightFromClient { .. }
+                | SuiError::GasPriceTooLowUnderLoad { .. }
+                | SuiError::ValidatorOverloaded { .. }
         )
     }
+
+    /// The delay an overloaded validator asked clients to wait before sending it new requests.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SuiError::ValidatorOverloaded { retry_after_ms, .. } => {
+                Some(Duration::from_millis(*retry_after_ms))
+            }
+            _ => None,
+        }
+    }
+
+    /// The stable code of the error, which never changes or gets reused across releases. A
+    /// new variant gets the next unused code of its [SuiErrorKind]. The codes of [UserInputError]
+    /// and [SuiObjectResponseError] start at `kind * 1000 + 500`.
+    pub fn code(&self) -> u32 {
+        match self {
+            SuiError::UserInputError { error } => error.code(),
+            SuiError::SuiObjectResponseError { error } => error.code(),
+            SuiError::UnexpectedOwnerType => 1000,
+            SuiError::TooManyTransactionsPendingExecution { .. } => 3000,
+            SuiError::TooManyTransactionsPendingConsensus => 3001,
+            SuiError::TooManyTransactionsInFlightFromSender { .. } => 3002,
+            SuiError::TooManyTransactionsInFlightFromClient { .. } => 3003,
+            SuiError::GasPriceTooLowUnderLoad { .. } => 3004,
+            SuiError::TooManyTransactionsPendingOnObject { .. } => 3005,
+            SuiError::TooOldTransactionPendingOnObject { .. } => 3006,
+            SuiError::ValidatorOverloaded { .. } => 3007,
+            SuiError::InvalidSignature { .. } => 4000,
+            SuiError::SignerSignatureAbsent { .. } => 4001,
+            SuiError::SignerSignatureNumberMismatch { .. } => 4002,
+            SuiError::IncorrectSigner { .. } => 4003,
+            SuiError::UnknownSigner { .. } => 4004,
+            SuiError::StakeAggregatorRepeatedSigner { .. } => 4005,
+            SuiError::PotentiallyTemporarilyInvalidSignature { .. } => 4006,
+            SuiError::WrongEpoch { .. } => 6000,
+            SuiError::CertificateRequiresQuorum => 5000,
+            SuiError::ErrorWhileProcessingCertificate { .. } => 5001,
+            SuiError::QuorumFailedToGetEffectsQuorumWhenProcessingTransaction { .. } => 5002,
+            SuiError::FailedToVerifyTxCertWithExecutedEffects { .. } => 5003,
+            SuiError::TxAlreadyFinalizedWithDifferentUserSigs => 5004,
+            SuiError::InvalidSystemTransaction => 1001,
+            SuiError::InvalidAuthenticator => 1002,
+            SuiError::InvalidAddress => 1003,
+            SuiError::InvalidTransactionDigest => 1004,
+            SuiError::InvalidDigestLength { .. } => 1005,
+            SuiError::UnexpectedMessage => 1006,
+            SuiError::ModuleVerificationFailure { .. } => 7000,
+            SuiError::ModuleDeserializationFailure { .. } => 7001,
+            SuiError::ModulePublishFailure { .. } => 7002,
+            SuiError::ModuleBuildFailure { .. } => 7003,
+            SuiError::FunctionNotFound { .. } => 7004,
+            SuiError::ModuleNotFound { .. } => 7005,
+            SuiError::TypeError { .. } => 7006,
+            SuiError::CircularObjectOwnership => 1007,
+            SuiError::ObjectLockAlreadyInitialized { .. } => 8000,
+            SuiError::ObjectLockConflict { .. } => 8001,
+            SuiError::ObjectLockedAtFutureEpoch { .. } => 8002,
+            SuiError::TransactionNotFound { .. } => 2000,
+            SuiError::TransactionsNotFound { .. } => 2001,
+            SuiError::TransactionEventsNotFound { .. } => 2002,
+            SuiError::TransactionAlreadyExecuted { .. } => 5005,
+            SuiError::BadObjectType { .. } => 1008,
+            SuiError::FailObjectLayout { .. } => 7007,
+            SuiError::ExecutionInvariantViolation => 15000,
+            SuiError::ByzantineAuthoritySuspicion { .. } => 5006,
+            SuiError::StorageError(..) => 9000,
+            SuiError::GenericStorageError(..) => 9001,
+            SuiError::InvalidChildObjectAccess { .. } => 1009,
+            SuiError::StorageMissingFieldError(..) => 9002,
+            SuiError::StorageCorruptedFieldError(..) => 9003,
+            SuiError::GenericAuthorityError { .. } => 15001,
+            SuiError::FailedToDispatchSubscription { .. } => 12000,
+            SuiError::OwnerFailedToSerialize { .. } => 14000,
+            SuiError::ExtraFieldFailedToDeserialize { .. } => 14001,
+            SuiError::TransactionOrchestratorLocalExecutionError { .. } => 15002,
+            SuiError::TransactionSerializationError { .. } => 14002,
+            SuiError::ObjectSerializationError { .. } => 14003,
+            SuiError::ObjectDeserializationError { .. } => 14004,
+            SuiError::NoEventStore => 13000,
+            SuiError::TooManyIncorrectAuthorities { .. } => 5007,
+            SuiError::FullNodeInvalidTxRangeQuery { .. } => 1010,
+            SuiError::FailedToSubmitToConsensus(..) => 10000,
+            SuiError::ConsensusConnectionBroken(..) => 10001,
+            SuiError::HandleConsensusTransactionFailure(..) => 10002,
+            SuiError::SignatureSeedInvalidLength(..) => 11000,
+            SuiError::HkdfError(..) => 11001,
+            SuiError::SignatureKeyGenError(..) => 11002,
+            SuiError::KeyConversionError(..) => 11003,
+            SuiError::InvalidPrivateKey => 11004,
+            SuiError::FullNodeCantHandleCertificate => 13001,
+            SuiError::ValidatorHaltedAtEpochEnd => 6001,
+            SuiError::EpochEnded => 6002,
+            SuiError::AdvanceEpochError { .. } => 6003,
+            SuiError::TransactionExpired => 6004,
+            SuiError::RpcError(..) => 12001,
+            SuiError::UnsupportedFeatureError { .. } => 13002,
+            SuiError::QuorumDriverCommunicationError { .. } => 12002,
+            SuiError::TimeoutError => 12003,
+            SuiError::ExecutionError(..) => 7008,
+            SuiError::InvalidCommittee(..) => 6005,
+            SuiError::MissingCommitteeAtEpoch(..) => 6006,
+            SuiError::IndexStoreNotAvailable => 13003,
+            SuiError::DynamicFieldReadError(..) => 9004,
+            SuiError::SuiSystemStateReadError(..) => 9005,
+            SuiError::UnexpectedVersion(..) => 13004,
+            SuiError::WrongMessageVersion { .. } => 13005,
+            SuiError::Unknown(..) => 15003,
+            SuiError::FileIOError(..) => 9006,
+            SuiError::JWKRetrievalError => 11005,
+        }
+    }
+
+    pub fn kind(&self) -> SuiErrorKind {
+        SuiErrorKind::from_code(self.code())
+    }
+
+    pub fn error_data(&self) -> SuiErrorData {
+        SuiErrorData {
+            kind: self.kind(),
+            code: self.code(),
+            retriable: self.is_retryable().0,
+            context: self.context(),
+        }
+    }
+
+    /// The fields of the error variant, or of the wrapped [UserInputError] or
+    /// [SuiObjectResponseError] variant.
+    fn context(&self) -> BTreeMap<String, serde_json::Value> {
+        match self {
+            SuiError::UserInputError { error } => variant_fields(serde_json::to_value(error)),
+            SuiError::SuiObjectResponseError { error } => match serde_json::to_value(error) {
+                // Internally tagged, with the variant name in the `code` field.
+                Ok(serde_json::Value::Object(mut fields)) => {
+                    fields.remove("code");
+                    fields.into_iter().collect()
+                }
+                _ => BTreeMap::new(),
+            },
+            _ => variant_fields(serde_json::to_value(self)),
+        }
+    }
+}
+
+/// Returns the fields of an externally tagged enum variant serialized to JSON, keyed by their
+/// index for a tuple variant.
+fn variant_fields(
+    value: Result<serde_json::Value, serde_json::Error>,
+) -> BTreeMap<String, serde_json::Value> {
+    let fields = match value {
+        Ok(serde_json::Value::Object(variant)) if variant.len() == 1 => {
+            variant.into_iter().next().unwrap().1
+        }
+        // A unit variant, serialized as its name.
+        _ => return BTreeMap::new(),
+    };
+    match fields {
+        serde_json::Value::Object(fields) => fields.into_iter().collect(),
+        serde_json::Value::Array(fields) => fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, field)| (i.to_string(), field))
+            .collect(),
+        field => BTreeMap::from([("0".to_string(), field)]),
+    }
 }
 
 impl Ord for SuiError {
@@ -188,6 +188,12 @@ pub enum ExecutionFailureStatus {
 
     #[error("Certificate cannot be executed due to a dependency on a deleted shared object")]
     InputObjectDeleted,
+
+    #[error(
+        "Transaction executed more bytecode instructions than the limit of the protocol, \
+         regardless of its gas budget"
+    )]
+    InstructionLimitExceeded,
     // NOTE: if you want to add a new enum,
     // please add it at the end for Rust SDK backward compatibility.
 }
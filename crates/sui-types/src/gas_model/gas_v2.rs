@@ -241,13 +241,15 @@ mod checked {
             };
             let sui_cost_table = SuiCostTable::new(config, gas_price);
             let gas_rounding_step = config.gas_rounding_step_as_option();
+            let mut gas_status = GasStatus::new(
+                sui_cost_table.execution_cost_table.clone(),
+                computation_budget,
+                gas_price,
+                config.gas_model_version(),
+            );
+            gas_status.set_max_instructions(config.max_instructions_per_tx_as_option());
             Self::new(
-                GasStatus::new(
-                    sui_cost_table.execution_cost_table.clone(),
-                    computation_budget,
-                    gas_price,
-                    config.gas_model_version(),
-                ),
+                gas_status,
                 gas_budget,
                 true,
                 gas_price,
@@ -393,6 +395,10 @@ mod checked {
             self.gas_budget
         }
 
+        fn gas_price(&self) -> u64 {
+            self.gas_price
+        }
+
         fn storage_gas_units(&self) -> u64 {
             self.storage_cost
         }
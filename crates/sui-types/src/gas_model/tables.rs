@@ -17,6 +17,7 @@ use move_vm_types::views::{TypeView, ValueView};
 use once_cell::sync::Lazy;
 use tracing::trace;
 
+use crate::error::VMMemoryLimitExceededSubStatusCode;
 use crate::gas_model::units_types::{CostTable, Gas, GasCost};
 
 use super::gas_predicates::charge_input_as_memory;
@@ -76,6 +77,9 @@ pub struct GasStatus {
     instructions_executed: u64,
     instructions_next_tier_start: Option<u64>,
     instructions_current_tier_mult: u64,
+    // The maximum number of bytecode instructions the transaction can execute, regardless of the
+    // gas left.
+    max_instructions: Option<u64>,
 
     #[cfg(debug_assertions)]
     profiler: Option<GasProfiler>,
@@ -115,6 +119,7 @@ impl GasStatus {
             stack_height_next_tier_start,
             stack_size_next_tier_start,
             instructions_next_tier_start,
+            max_instructions: None,
             #[cfg(debug_assertions)]
             profiler: None,
         }
@@ -143,6 +148,7 @@ impl GasStatus {
             stack_height_next_tier_start: None,
             stack_size_next_tier_start: None,
             instructions_next_tier_start: None,
+            max_instructions: None,
             #[cfg(debug_assertions)]
             profiler: None,
         }
@@ -196,6 +202,15 @@ impl GasStatus {
             }
         }
 
+        if let Some(max_instructions) = self.max_instructions {
+            if self.charge && self.instructions_executed > max_instructions {
+                return Err(PartialVMError::new(StatusCode::MEMORY_LIMIT_EXCEEDED)
+                    .with_sub_status(
+                        VMMemoryLimitExceededSubStatusCode::INSTRUCTION_LIMIT_EXCEEDED as u64,
+                    ));
+            }
+        }
+
         if let Some(instr_tier_next) = self.instructions_next_tier_start {
             if self.instructions_executed > instr_tier_next {
                 let (instr_cost, next_tier) =
@@ -310,6 +325,12 @@ impl GasStatus {
         self.charge = enabled
     }
 
+    /// Fail execution once more than `max_instructions` bytecode instructions have been executed,
+    /// even if there is gas left.
+    pub fn set_max_instructions(&mut self, max_instructions: Option<u64>) {
+        self.max_instructions = max_instructions
+    }
+
     // The amount of gas used, it does not include the multiplication for the gas price
     pub fn gas_used_pre_gas_price(&self) -> u64 {
         let gas: Gas = match self.initial_budget.checked_sub(self.gas_left) {
@@ -920,3 +941,46 @@ pub fn initial_cost_schedule_for_unit_tests() -> move_vm_test_utils::gas_schedul
         stack_size_tiers: table.stack_size_tiers.into_iter().collect(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gas_model::gas_v2::SuiGasStatus;
+    use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
+
+    const GAS_PRICE: u64 = 1_000;
+    const GAS_BUDGET: u64 = 50_000_000_000;
+
+    fn gas_status_for_version(version: u64) -> GasStatus {
+        let config = ProtocolConfig::get_for_version(ProtocolVersion::new(version), Chain::Unknown);
+        SuiGasStatus::new_with_budget(GAS_BUDGET, GAS_PRICE, GAS_PRICE, &config).gas_status
+    }
+
+    #[test]
+    fn test_instruction_limit_exceeded_with_gas_left() {
+        let mut gas_status = gas_status_for_version(36);
+        let max_instructions = gas_status.max_instructions.unwrap();
+
+        gas_status
+            .increase_instruction_count(max_instructions)
+            .unwrap();
+        let error = gas_status.increase_instruction_count(1).unwrap_err();
+        assert_eq!(error.major_status(), StatusCode::MEMORY_LIMIT_EXCEEDED);
+        assert_eq!(
+            error.sub_status(),
+            Some(VMMemoryLimitExceededSubStatusCode::INSTRUCTION_LIMIT_EXCEEDED as u64)
+        );
+        assert!(gas_status.remaining_gas() > Gas::new(0));
+    }
+
+    #[test]
+    fn test_no_instruction_limit_before_version_36() {
+        let mut gas_status = gas_status_for_version(35);
+        assert_eq!(gas_status.max_instructions, None);
+
+        gas_status
+            .increase_instruction_count(1_000_000_000)
+            .unwrap();
+        gas_status.increase_instruction_count(1).unwrap();
+    }
+}
@@ -36,6 +36,7 @@ pub mod collection_types;
 pub mod committee;
 pub mod crypto;
 pub mod deny_list;
+pub mod deserialization_limits;
 pub mod digests;
 pub mod display;
 pub mod dynamic_field;
@@ -54,6 +55,7 @@ pub mod governance;
 pub mod id;
 pub mod in_memory_storage;
 pub mod inner_temporary_store;
+pub mod merkle;
 pub mod message_envelope;
 pub mod messages_checkpoint;
 pub mod messages_consensus;
@@ -65,6 +67,7 @@ pub mod move_package;
 pub mod multisig;
 pub mod multisig_legacy;
 pub mod object;
+pub mod object_proof;
 pub mod programmable_transaction_builder;
 pub mod quorum_driver_types;
 pub mod randomness_state;
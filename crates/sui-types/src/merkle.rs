@@ -0,0 +1,195 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Binary Merkle trees, to prove that an item is part of a list committed to by a single digest
+//! without the rest of the list.
+//!
+//! Leaves and inner nodes are hashed with different prefixes, so that an inner node can't be
+//! passed off as a leaf. A node without a sibling is promoted to the next level as is, rather
+//! than hashed with itself, so that each list has a single tree.
+
+use crate::crypto::DefaultHash;
+use crate::digests::Digest;
+use crate::error::{SuiError, SuiResult};
+use crate::sui_serde::{BigInt, Readable};
+use fastcrypto::hash::HashFunction;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+const LEAF_PREFIX: u8 = 0;
+const INNER_NODE_PREFIX: u8 = 1;
+
+pub fn leaf_digest<T: Serialize>(leaf: &T) -> Digest {
+    let mut hasher = DefaultHash::default();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(bcs::to_bytes(leaf).expect("Serialization should not fail"));
+    Digest::new(hasher.finalize().digest)
+}
+
+fn inner_node_digest(left: &Digest, right: &Digest) -> Digest {
+    let mut hasher = DefaultHash::default();
+    hasher.update([INNER_NODE_PREFIX]);
+    hasher.update(left.inner());
+    hasher.update(right.inner());
+    Digest::new(hasher.finalize().digest)
+}
+
+fn next_level(level: &[Digest]) -> Vec<Digest> {
+    level
+        .chunks(2)
+        .map(|nodes| match nodes {
+            [left, right] => inner_node_digest(left, right),
+            [node] => *node,
+            _ => unreachable!("chunks of at most 2 nodes"),
+        })
+        .collect()
+}
+
+/// The root of the tree over the digests of `leaves`. The root of an empty tree is the zero
+/// digest.
+pub fn merkle_root(leaves: &[Digest]) -> Digest {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied().unwrap_or_default()
+}
+
+/// Proof that a leaf is at `leaf_index` in a tree of `num_leaves` leaves.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleProof {
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "Readable<BigInt<u64>, _>")]
+    pub leaf_index: u64,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "Readable<BigInt<u64>, _>")]
+    pub num_leaves: u64,
+    /// The siblings of the nodes on the path from the leaf to the root, bottom up. Nodes without
+    /// a sibling have no entry.
+    pub siblings: Vec<Digest>,
+}
+
+impl MerkleProof {
+    /// Proof for the leaf at `leaf_index` in the tree over the digests of `leaves`, or None if
+    /// there is no such leaf.
+    pub fn new(leaves: &[Digest], leaf_index: usize) -> Option<Self> {
+        if leaf_index >= leaves.len() {
+            return None;
+        }
+        let mut siblings = Vec::new();
+        let mut level = leaves.to_vec();
+        let mut index = leaf_index;
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(index ^ 1) {
+                siblings.push(*sibling);
+            }
+            level = next_level(&level);
+            index /= 2;
+        }
+        Some(Self {
+            leaf_index: leaf_index as u64,
+            num_leaves: leaves.len() as u64,
+            siblings,
+        })
+    }
+
+    /// The root of the tree in which `leaf` is at the position of this proof, or None if the
+    /// proof is malformed.
+    pub fn compute_root(&self, leaf: &Digest) -> Option<Digest> {
+        if self.leaf_index >= self.num_leaves {
+            return None;
+        }
+        let mut siblings = self.siblings.iter();
+        let mut digest = *leaf;
+        let mut index = self.leaf_index;
+        let mut level_len = self.num_leaves;
+        while level_len > 1 {
+            if (index ^ 1) < level_len {
+                let sibling = siblings.next()?;
+                digest = if index % 2 == 0 {
+                    inner_node_digest(&digest, sibling)
+                } else {
+                    inner_node_digest(sibling, &digest)
+                };
+            }
+            index /= 2;
+            level_len = (level_len + 1) / 2;
+        }
+        if siblings.next().is_some() {
+            return None;
+        }
+        Some(digest)
+    }
+
+    pub fn verify(&self, leaf: &Digest, root: &Digest) -> SuiResult {
+        fp_ensure!(
+            self.compute_root(leaf).as_ref() == Some(root),
+            SuiError::GenericAuthorityError {
+                error: format!("Invalid Merkle proof {:?} for root {}", self, root)
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: u64) -> Vec<Digest> {
+        (0..n).map(|i| leaf_digest(&i)).collect()
+    }
+
+    #[test]
+    fn test_merkle_proofs() {
+        assert_eq!(merkle_root(&[]), Digest::default());
+        assert!(MerkleProof::new(&[], 0).is_none());
+
+        for n in 1..=17 {
+            let leaves = leaves(n);
+            let root = merkle_root(&leaves);
+            for (i, leaf) in leaves.iter().enumerate() {
+                let proof = MerkleProof::new(&leaves, i).unwrap();
+                proof.verify(leaf, &root).unwrap();
+
+                // The proof doesn't hold for any other leaf.
+                assert!(proof.verify(&leaf_digest(&n), &root).is_err());
+                if n > 1 {
+                    let other = &leaves[(i + 1) % leaves.len()];
+                    assert!(proof.verify(other, &root).is_err());
+                }
+            }
+            assert!(MerkleProof::new(&leaves, leaves.len()).is_none());
+        }
+    }
+
+    #[test]
+    fn test_malformed_merkle_proofs() {
+        let leaves = leaves(5);
+        let root = merkle_root(&leaves);
+        let proof = MerkleProof::new(&leaves, 4).unwrap();
+
+        let mut extra_sibling = proof.clone();
+        extra_sibling.siblings.push(leaves[0]);
+        assert!(extra_sibling.verify(&leaves[4], &root).is_err());
+
+        let mut out_of_range = proof.clone();
+        out_of_range.leaf_index = 5;
+        assert!(out_of_range.verify(&leaves[4], &root).is_err());
+
+        // An inner node is not a leaf.
+        let inner = inner_node_digest(&leaves[0], &leaves[1]);
+        let proof = MerkleProof::new(&leaves, 0).unwrap();
+        let truncated = MerkleProof {
+            leaf_index: 0,
+            num_leaves: 3,
+            siblings: proof.siblings[1..].to_vec(),
+        };
+        assert!(truncated.verify(&leaf_digest(&0u64), &root).is_err());
+        assert_eq!(truncated.compute_root(&inner), Some(root));
+        assert_ne!(inner, leaf_digest(&0u64));
+    }
+}
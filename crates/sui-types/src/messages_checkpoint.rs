@@ -11,9 +11,11 @@ use crate::crypto::{
     AuthorityStrongQuorumSignInfo,
 };
 use crate::digests::Digest;
+use crate::digests::TransactionDigest;
 use crate::effects::{TransactionEffects, TransactionEffectsAPI};
 use crate::error::SuiResult;
 use crate::gas::GasCostSummary;
+use crate::merkle::{self, MerkleProof};
 use crate::message_envelope::{
     Envelope, Message, TrustedEnvelope, UnauthenticatedMessage, VerifiedEnvelope,
 };
@@ -121,10 +123,19 @@ impl Default for ECMHLiveObjectSetDigest {
     }
 }
 
+/// The root of a Merkle tree over the execution digests of the transactions in a checkpoint, in
+/// the order of the checkpoint contents.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct CheckpointContentsMerkleRoot {
+    #[schemars(with = "[u8; 32]")]
+    pub digest: Digest,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum CheckpointCommitment {
     ECMHLiveObjectSetDigest(ECMHLiveObjectSetDigest),
-    // Other commitment types (e.g. merkle roots) go here.
+    CheckpointContentsMerkleRoot(CheckpointContentsMerkleRoot),
+    // Other commitment types go here.
 }
 
 impl From<ECMHLiveObjectSetDigest> for CheckpointCommitment {
@@ -133,6 +144,12 @@ impl From<ECMHLiveObjectSetDigest> for CheckpointCommitment {
     }
 }
 
+impl From<CheckpointContentsMerkleRoot> for CheckpointCommitment {
+    fn from(d: CheckpointContentsMerkleRoot) -> Self {
+        Self::CheckpointContentsMerkleRoot(d)
+    }
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -251,6 +268,29 @@ impl CheckpointSummary {
         UNIX_EPOCH + Duration::from_millis(self.timestamp_ms)
     }
 
+    /// Present if the checkpoint was created with `checkpoint_contents_merkle_root` enabled.
+    pub fn contents_merkle_root(&self) -> Option<&CheckpointContentsMerkleRoot> {
+        self.checkpoint_commitments
+            .iter()
+            .find_map(|commitment| match commitment {
+                CheckpointCommitment::CheckpointContentsMerkleRoot(root) => Some(root),
+                _ => None,
+            })
+    }
+
+    /// The commitment to the live object set at the end of the epoch. Present on the last
+    /// checkpoint of an epoch created with `commit_root_state_digest` enabled.
+    pub fn live_object_set_digest(&self) -> Option<&ECMHLiveObjectSetDigest> {
+        self.end_of_epoch_data
+            .as_ref()?
+            .epoch_commitments
+            .iter()
+            .find_map(|commitment| match commitment {
+                CheckpointCommitment::ECMHLiveObjectSetDigest(digest) => Some(digest),
+                _ => None,
+            })
+    }
+
     pub fn next_epoch_committee(&self) -> Option<&[(AuthorityName, StakeUnit)]> {
         self.end_of_epoch_data
             .as_ref()
@@ -314,11 +354,50 @@ impl CertifiedCheckpointSummary {
                 content_digest == self.data().content_digest,
                 SuiError::GenericAuthorityError{error:format!("Checkpoint contents digest mismatch: summary={:?}, received content digest {:?}, received {} transactions", self.data(), content_digest, contents.size())}
             );
+            if let Some(root) = self.data().contents_merkle_root() {
+                fp_ensure!(
+                    *root == contents.merkle_root(),
+                    SuiError::GenericAuthorityError {
+                        error: format!(
+                            "Checkpoint contents Merkle root mismatch: summary={:?}",
+                            self.data()
+                        )
+                    }
+                );
+            }
         }
 
         Ok(())
     }
 
+    /// Verifies that the committee committed to `digest` as the live object set at the end of the
+    /// epoch of this checkpoint, e.g. to check a restored snapshot or the state of a validator.
+    pub fn verify_live_object_set_digest(
+        &self,
+        committee: &Committee,
+        digest: &ECMHLiveObjectSetDigest,
+    ) -> SuiResult {
+        self.verify_authority_signatures(committee)?;
+        let committed = self.data().live_object_set_digest().ok_or_else(|| {
+            SuiError::GenericAuthorityError {
+                error: format!(
+                    "Checkpoint {} does not commit to the live object set of epoch {}",
+                    self.sequence_number, self.epoch
+                ),
+            }
+        })?;
+        fp_ensure!(
+            committed == digest,
+            SuiError::GenericAuthorityError {
+                error: format!(
+                    "Live object set digest mismatch at the end of epoch {}: committed {:?}, got {:?}",
+                    self.epoch, committed.digest, digest.digest
+                )
+            }
+        );
+        Ok(())
+    }
+
     pub fn into_summary_and_sequence(self) -> (CheckpointSequenceNumber, CheckpointSummary) {
         let summary = self.into_data();
         (summary.sequence_number, summary)
@@ -476,6 +555,110 @@ impl CheckpointContents {
             .digest
             .get_or_init(|| CheckpointContentsDigest::new(default_hash(self)))
     }
+
+    fn merkle_leaves(&self) -> Vec<Digest> {
+        self.iter().map(merkle::leaf_digest).collect()
+    }
+
+    pub fn merkle_root(&self) -> CheckpointContentsMerkleRoot {
+        CheckpointContentsMerkleRoot {
+            digest: merkle::merkle_root(&self.merkle_leaves()),
+        }
+    }
+
+    /// Proof that the transaction is in these contents, to be checked against the Merkle root
+    /// committed to by the checkpoint. None if the transaction is not in these contents.
+    pub fn inclusion_proof(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+        transaction: &TransactionDigest,
+    ) -> Option<CheckpointInclusionProof> {
+        let (index, execution_digests) = self
+            .iter()
+            .enumerate()
+            .find(|(_, digests)| digests.transaction == *transaction)?;
+        Some(CheckpointInclusionProof {
+            sequence_number,
+            execution_digests: *execution_digests,
+            proof: MerkleProof::new(&self.merkle_leaves(), index)?,
+        })
+    }
+}
+
+/// Proof that a transaction, with the given effects, is included in a checkpoint.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointInclusionProof {
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "Readable<BigInt<u64>, _>")]
+    pub sequence_number: CheckpointSequenceNumber,
+    pub execution_digests: ExecutionDigests,
+    pub proof: MerkleProof,
+}
+
+impl CheckpointInclusionProof {
+    /// Verifies the proof against the summary of its checkpoint. The summary is trusted, i.e. it
+    /// must have been verified against the committee of its epoch.
+    pub fn verify(&self, summary: &CheckpointSummary) -> SuiResult {
+        fp_ensure!(
+            summary.sequence_number == self.sequence_number,
+            SuiError::GenericAuthorityError {
+                error: format!(
+                    "Inclusion proof is for checkpoint {}, got summary of checkpoint {}",
+                    self.sequence_number, summary.sequence_number
+                )
+            }
+        );
+        let root =
+            summary
+                .contents_merkle_root()
+                .ok_or_else(|| SuiError::GenericAuthorityError {
+                    error: format!(
+                        "Checkpoint {} does not commit to a contents Merkle root",
+                        summary.sequence_number
+                    ),
+                })?;
+        self.proof
+            .verify(&merkle::leaf_digest(&self.execution_digests), &root.digest)
+    }
+}
+
+/// The commitment of the committee to the live object set at the end of an epoch, carried by the
+/// last checkpoint of the epoch.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochStateCommitment {
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "Readable<BigInt<u64>, _>")]
+    pub epoch: EpochId,
+    /// The last checkpoint of the epoch.
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "Readable<BigInt<u64>, _>")]
+    pub sequence_number: CheckpointSequenceNumber,
+    pub live_object_set_digest: ECMHLiveObjectSetDigest,
+}
+
+impl EpochStateCommitment {
+    /// Verifies the commitment against the certified summary of the last checkpoint of its epoch,
+    /// signed by the committee of that epoch.
+    pub fn verify(
+        &self,
+        checkpoint: &CertifiedCheckpointSummary,
+        committee: &Committee,
+    ) -> SuiResult {
+        fp_ensure!(
+            checkpoint.epoch == self.epoch && checkpoint.sequence_number == self.sequence_number,
+            SuiError::GenericAuthorityError {
+                error: format!(
+                    "Commitment is for checkpoint {} of epoch {}, got checkpoint {} of epoch {}",
+                    self.sequence_number, self.epoch, checkpoint.sequence_number, checkpoint.epoch
+                )
+            }
+        );
+        checkpoint.verify_live_object_set_digest(committee, &self.live_object_set_digest)
+    }
 }
 
 /// Same as CheckpointContents, but contains full contents of all Transactions and
@@ -875,4 +1058,124 @@ mod tests {
             assert_ne!(c1.digest(), c2.digest());
         }
     }
+
+    #[test]
+    fn test_checkpoint_inclusion_proof() {
+        let mut rng = StdRng::from_seed(RNG_SEED);
+        let (keys, committee) = make_committee_key(&mut rng);
+
+        let digests: Vec<_> = (0..5).map(|_| ExecutionDigests::random()).collect();
+        let contents = CheckpointContents::new_with_digests_only_for_tests(digests.clone());
+        let mut summary = CheckpointSummary::new(
+            committee.epoch,
+            1,
+            5,
+            &contents,
+            None,
+            GasCostSummary::default(),
+            None,
+            0,
+        );
+        assert!(summary.contents_merkle_root().is_none());
+        summary
+            .checkpoint_commitments
+            .push(contents.merkle_root().into());
+
+        let sign_infos: Vec<_> = keys
+            .iter()
+            .map(|k| SignedCheckpointSummary::sign(committee.epoch, &summary, k, k.public().into()))
+            .collect();
+        let checkpoint_cert =
+            CertifiedCheckpointSummary::new(summary, sign_infos, &committee).expect("Cert is OK");
+        assert!(checkpoint_cert
+            .verify_with_contents(&committee, Some(&contents))
+            .is_ok());
+
+        for digest in &digests {
+            let proof = contents.inclusion_proof(1, &digest.transaction).unwrap();
+            assert_eq!(proof.execution_digests, *digest);
+            proof.verify(checkpoint_cert.data()).unwrap();
+
+            // The proof doesn't hold for other effects.
+            let mut tampered = proof.clone();
+            tampered.execution_digests.effects = TransactionEffectsDigest::random();
+            assert!(tampered.verify(checkpoint_cert.data()).is_err());
+        }
+        assert!(contents
+            .inclusion_proof(1, &TransactionDigest::random())
+            .is_none());
+
+        // The proof doesn't hold for another checkpoint.
+        let proof = contents
+            .inclusion_proof(2, &digests[0].transaction)
+            .unwrap();
+        assert!(proof.verify(checkpoint_cert.data()).is_err());
+    }
+
+    #[test]
+    fn test_verify_live_object_set_digest() {
+        let mut rng = StdRng::from_seed(RNG_SEED);
+        let (keys, committee) = make_committee_key(&mut rng);
+        let digest = ECMHLiveObjectSetDigest {
+            digest: Digest::random(),
+        };
+
+        let certify = |end_of_epoch_data| {
+            let summary = CheckpointSummary::new(
+                committee.epoch,
+                1,
+                0,
+                &CheckpointContents::new_with_digests_only_for_tests([ExecutionDigests::random()]),
+                None,
+                GasCostSummary::default(),
+                end_of_epoch_data,
+                0,
+            );
+            let sign_infos: Vec<_> = keys
+                .iter()
+                .map(|k| {
+                    SignedCheckpointSummary::sign(committee.epoch, &summary, k, k.public().into())
+                })
+                .collect();
+            CertifiedCheckpointSummary::new(summary, sign_infos, &committee).expect("Cert is OK")
+        };
+
+        let checkpoint = certify(Some(EndOfEpochData {
+            next_epoch_committee: committee.voting_rights.clone(),
+            next_epoch_protocol_version: ProtocolVersion::MAX,
+            epoch_commitments: vec![digest.clone().into()],
+        }));
+        assert_eq!(checkpoint.live_object_set_digest(), Some(&digest));
+        checkpoint
+            .verify_live_object_set_digest(&committee, &digest)
+            .unwrap();
+        let commitment = EpochStateCommitment {
+            epoch: committee.epoch,
+            sequence_number: 1,
+            live_object_set_digest: digest.clone(),
+        };
+        commitment.verify(&checkpoint, &committee).unwrap();
+        assert!(EpochStateCommitment {
+            sequence_number: 2,
+            ..commitment
+        }
+        .verify(&checkpoint, &committee)
+        .is_err());
+        assert!(checkpoint
+            .verify_live_object_set_digest(&committee, &ECMHLiveObjectSetDigest::default())
+            .is_err());
+
+        // A different committee didn't sign the checkpoint.
+        let (_, other_committee) = make_committee_key(&mut rng);
+        assert!(checkpoint
+            .verify_live_object_set_digest(&other_committee, &digest)
+            .is_err());
+
+        // Checkpoints that are not the last of their epoch commit to no live object set.
+        let checkpoint = certify(None);
+        assert!(checkpoint.live_object_set_digest().is_none());
+        assert!(checkpoint
+            .verify_live_object_set_digest(&committee, &digest)
+            .is_err());
+    }
 }
@@ -1,12 +1,15 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::base_types::{ObjectID, SequenceNumber, TransactionDigest};
+use crate::base_types::{ObjectID, ObjectRef, SequenceNumber, TransactionDigest};
+use crate::committee::EpochId;
 use crate::crypto::{AuthoritySignInfo, AuthorityStrongQuorumSignInfo};
 use crate::effects::{
     SignedTransactionEffects, TransactionEvents, VerifiedSignedTransactionEffects,
 };
+use crate::messages_checkpoint::{CertifiedCheckpointSummary, ECMHLiveObjectSetDigest};
 use crate::object::Object;
+use crate::object_proof::ObjectOwnershipProof;
 use crate::transaction::{SenderSignedData, SignedTransaction};
 use move_core_types::annotated_value::MoveStructLayout;
 use serde::{Deserialize, Serialize};
@@ -181,3 +184,70 @@ pub struct SystemStateRequest {
     // This is needed to make gRPC happy.
     pub _unused: bool,
 }
+
+/// A request for the attempted double spends of an object seen by an authority.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ConflictingTransactionsRequest {
+    pub object_id: ObjectID,
+}
+
+/// Two different transactions that tried to lock the same owned object version in the same epoch.
+/// The authority signed `locked_by_tx` and rejected `conflicting_tx`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ConflictingTransactions {
+    pub obj_ref: ObjectRef,
+    pub epoch: EpochId,
+    pub locked_by_tx: TransactionDigest,
+    pub conflicting_tx: TransactionDigest,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ConflictingTransactionsResponse {
+    /// Sorted by object version.
+    pub conflicts: Vec<ConflictingTransactions>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InclusionGasPriceRequest {
+    // This is needed to make gRPC happy.
+    pub _unused: bool,
+}
+
+/// The lowest gas price of transactions an authority currently signs. It is the reference gas
+/// price, unless the authority is under load.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InclusionGasPriceResponse {
+    pub epoch: EpochId,
+    pub reference_gas_price: u64,
+    pub inclusion_gas_price: u64,
+}
+
+/// A request for a proof of the latest state of an object, for light clients.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ObjectOwnershipProofRequest {
+    pub object_id: ObjectID,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ObjectOwnershipProofResponse {
+    /// None if the object doesn't exist, or the transaction that last wrote it is not
+    /// checkpointed yet or was certified in an earlier epoch.
+    pub proof: Option<ObjectOwnershipProof>,
+}
+
+/// A request for the commitment to the live object set at the end of an epoch, to check the state
+/// of the authority against the committee.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EpochStateCommitmentRequest {
+    pub epoch: EpochId,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EpochStateCommitmentResponse {
+    /// The last checkpoint of the epoch, which commits to the live object set at the end of the
+    /// epoch. None if the epoch has not ended yet, or the authority doesn't have the checkpoint.
+    pub checkpoint: Option<CertifiedCheckpointSummary>,
+    /// The digest of the live object set at the end of the epoch, as accumulated by the
+    /// authority. None if the authority has not accumulated the epoch.
+    pub root_state_digest: Option<ECMHLiveObjectSetDigest>,
+}
@@ -241,9 +241,9 @@ impl MultiSig {
         }
     }
     /// This combines a list of [enum Signature] `flag || signature || pk` to a MultiSig.
-    /// The order of full_sigs must be the same as the order of public keys in
-    /// [enum MultiSigPublicKey]. e.g. for [pk1, pk2, pk3, pk4, pk5],
-    /// [sig1, sig2, sig5] is valid, but [sig2, sig1, sig5] is invalid.
+    /// full_sigs can be passed in any order, they are sorted to follow the order of public
+    /// keys in [enum MultiSigPublicKey]. e.g. for [pk1, pk2, pk3, pk4, pk5], both
+    /// [sig1, sig2, sig5] and [sig2, sig1, sig5] are combined as [sig1, sig2, sig5].
     pub fn combine(
         full_sigs: Vec<GenericSignature>,
         multisig_pk: MultiSigPublicKey,
@@ -260,7 +260,7 @@ impl MultiSig {
             });
         }
         let mut bitmap = 0;
-        let mut sigs = Vec::with_capacity(full_sigs.len());
+        let mut indexed_sigs = Vec::with_capacity(full_sigs.len());
         for s in full_sigs {
             let pk = s.to_public_key()?;
             let index = multisig_pk
@@ -274,8 +274,10 @@ impl MultiSig {
                 });
             }
             bitmap |= 1 << index;
-            sigs.push(s.to_compressed()?);
+            indexed_sigs.push((index, s.to_compressed()?));
         }
+        indexed_sigs.sort_by_key(|(index, _)| *index);
+        let sigs = indexed_sigs.into_iter().map(|(_, sig)| sig).collect();
 
         Ok(MultiSig {
             sigs,
@@ -0,0 +1,186 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Proofs of the latest state of an object, which a light client can check against the committee
+//! alone, without trusting the full node or validator that served them.
+
+use crate::base_types::{ExecutionDigests, ObjectRef};
+use crate::committee::Committee;
+use crate::effects::{TransactionEffects, TransactionEffectsAPI};
+use crate::error::{SuiError, SuiResult};
+use crate::message_envelope::Message;
+use crate::messages_checkpoint::{CertifiedCheckpointSummary, CheckpointContents};
+use crate::object::Object;
+use crate::transaction::CertifiedTransaction;
+use serde::{Deserialize, Serialize};
+
+/// The latest version of an object, with the certificate and effects of the transaction that
+/// wrote it and the checkpoint that includes that transaction.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ObjectOwnershipProof {
+    pub object: Object,
+    pub certificate: CertifiedTransaction,
+    pub effects: TransactionEffects,
+    pub checkpoint: CertifiedCheckpointSummary,
+    pub checkpoint_contents: CheckpointContents,
+}
+
+impl ObjectOwnershipProof {
+    /// Verifies the proof against the committee of the epoch of the checkpoint, and returns the
+    /// reference of the object. This shows that the object, including its owner, was written by
+    /// the certified transaction, and that the effects of the transaction are final.
+    ///
+    /// It does not show that the object has not been modified since: a proof is only as recent
+    /// as its checkpoint.
+    pub fn verify(&self, committee: &Committee) -> SuiResult<ObjectRef> {
+        self.checkpoint
+            .verify_with_contents(committee, Some(&self.checkpoint_contents))?;
+        self.certificate.verify_committee_sigs_only(committee)?;
+
+        let tx_digest = *self.certificate.digest();
+        let execution_digests = ExecutionDigests::new(tx_digest, self.effects.digest());
+        fp_ensure!(
+            self.checkpoint_contents
+                .iter()
+                .any(|digests| *digests == execution_digests),
+            SuiError::GenericAuthorityError {
+                error: format!(
+                    "Transaction {:?} with effects {:?} is not included in checkpoint {}",
+                    tx_digest, execution_digests.effects, self.checkpoint.sequence_number
+                )
+            }
+        );
+        fp_ensure!(
+            *self.effects.transaction_digest() == tx_digest,
+            SuiError::GenericAuthorityError {
+                error: format!(
+                    "Effects are of transaction {:?}, expected {:?}",
+                    self.effects.transaction_digest(),
+                    tx_digest
+                )
+            }
+        );
+
+        let object_ref = self.object.compute_object_reference();
+        fp_ensure!(
+            self.object.previous_transaction == tx_digest
+                && self
+                    .effects
+                    .all_changed_objects()
+                    .into_iter()
+                    .any(|(changed_ref, owner, _)| {
+                        changed_ref == object_ref && owner == self.object.owner
+                    }),
+            SuiError::GenericAuthorityError {
+                error: format!(
+                    "Object {:?} is not written by transaction {:?}",
+                    object_ref, tx_digest
+                )
+            }
+        );
+        Ok(object_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base_types::{dbg_addr, ObjectID};
+    use crate::crypto::{get_key_pair, AccountKeyPair, AuthorityKeyPair, SuiKeyPair};
+    use crate::gas::GasCostSummary;
+    use crate::messages_checkpoint::{CheckpointSummary, SignedCheckpointSummary};
+    use crate::object::Owner;
+    use crate::transaction::SignedTransaction;
+    use crate::utils::{make_committee_key, make_transaction};
+    use fastcrypto::traits::KeyPair;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn make_proof(keys: &[AuthorityKeyPair], committee: &Committee) -> ObjectOwnershipProof {
+        let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+        let transaction = make_transaction(sender, &SuiKeyPair::Ed25519(sender_key));
+        let signatures = keys
+            .iter()
+            .map(|key| {
+                SignedTransaction::new(
+                    committee.epoch,
+                    transaction.clone().into_message(),
+                    key,
+                    key.public().into(),
+                )
+                .auth_sig()
+                .clone()
+            })
+            .collect();
+        let certificate =
+            CertifiedTransaction::new(transaction.into_message(), signatures, committee).unwrap();
+
+        let mut object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+        object.previous_transaction = *certificate.digest();
+        let effects = TransactionEffects::new_with_tx_and_gas(
+            certificate.data(),
+            (object.compute_object_reference(), object.owner),
+        );
+
+        let checkpoint_contents = CheckpointContents::new_with_digests_only_for_tests([
+            ExecutionDigests::random(),
+            ExecutionDigests::new(*certificate.digest(), effects.digest()),
+        ]);
+        let summary = CheckpointSummary::new(
+            committee.epoch,
+            1,
+            0,
+            &checkpoint_contents,
+            None,
+            GasCostSummary::default(),
+            None,
+            0,
+        );
+        let signatures = keys
+            .iter()
+            .map(|key| {
+                SignedCheckpointSummary::sign(committee.epoch, &summary, key, key.public().into())
+            })
+            .collect();
+        let checkpoint = CertifiedCheckpointSummary::new(summary, signatures, committee).unwrap();
+
+        ObjectOwnershipProof {
+            object,
+            certificate,
+            effects,
+            checkpoint,
+            checkpoint_contents,
+        }
+    }
+
+    #[test]
+    fn test_verify_object_ownership_proof() {
+        let mut rng = StdRng::from_seed([0; 32]);
+        let (keys, committee) = make_committee_key(&mut rng);
+        let proof = make_proof(&keys, &committee);
+        assert_eq!(
+            proof.verify(&committee).unwrap(),
+            proof.object.compute_object_reference()
+        );
+
+        // A different committee didn't sign the checkpoint.
+        let (_, other_committee) = make_committee_key(&mut rng);
+        assert!(proof.verify(&other_committee).is_err());
+
+        // The owner of the object is not the one written by the transaction.
+        let mut tampered = proof.clone();
+        tampered.object.owner = Owner::AddressOwner(dbg_addr(1));
+        assert!(tampered.verify(&committee).is_err());
+
+        // The effects are not the ones included in the checkpoint.
+        let mut tampered = proof.clone();
+        tampered.effects = TransactionEffects::new_with_tx(proof.certificate.data());
+        assert!(tampered.verify(&committee).is_err());
+
+        // The object is not written by the certified transaction.
+        let other = make_proof(&keys, &committee);
+        let mut tampered = proof;
+        tampered.object = other.object;
+        assert!(tampered.verify(&committee).is_err());
+    }
+}
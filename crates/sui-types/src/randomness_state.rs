@@ -1,6 +1,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use move_binary_format::{binary_views::BinaryIndexedView, file_format::SignatureToken};
+use move_bytecode_utils::resolve_struct;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
 
 use crate::base_types::SequenceNumber;
@@ -32,3 +34,13 @@ pub fn get_randomness_state_obj_initial_shared_version(
             _ => unreachable!("Randomness state object must be shared"),
         }))
 }
+
+/// Detects a `&mut sui::random::Random` or `sui::random::Random` in the signature.
+pub fn is_mutable_random(view: &BinaryIndexedView<'_>, s: &SignatureToken) -> bool {
+    use SignatureToken as S;
+    match s {
+        S::MutableReference(inner) => is_mutable_random(view, inner),
+        S::Struct(idx) => resolve_struct(view, *idx) == RESOLVED_SUI_RANDOMNESS_STATE,
+        _ => false,
+    }
+}
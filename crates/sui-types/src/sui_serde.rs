@@ -22,6 +22,7 @@ use serde_with::{Bytes, DeserializeAs, SerializeAs};
 
 use sui_protocol_config::ProtocolVersion;
 
+use crate::deserialization_limits::DeserializationLimits;
 use crate::{
     parse_sui_struct_tag, parse_sui_type_tag, DEEPBOOK_ADDRESS, SUI_CLOCK_ADDRESS,
     SUI_FRAMEWORK_ADDRESS, SUI_SYSTEM_ADDRESS, SUI_SYSTEM_STATE_ADDRESS,
@@ -149,6 +150,13 @@ impl<'de> DeserializeAs<'de, roaring::RoaringBitmap> for SuiBitmap {
         D: Deserializer<'de>,
     {
         let bytes: Vec<u8> = Bytes::deserialize_as(deserializer)?;
+        let max_bytes = DeserializationLimits::get().max_signers_map_bytes;
+        if bytes.len() > max_bytes {
+            return Err(Error::custom(format!(
+                "bitmap of {} bytes exceeds the maximum of {max_bytes} bytes",
+                bytes.len()
+            )));
+        }
         roaring::RoaringBitmap::deserialize_from(&bytes[..]).map_err(to_custom_error::<'de, D, _>)
     }
 }
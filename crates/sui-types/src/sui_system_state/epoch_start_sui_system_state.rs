@@ -144,6 +144,7 @@ impl EpochStartSystemStateTrait for EpochStartSystemStateV1 {
                         NetworkMetadata {
                             network_address: validator.sui_net_address.clone(),
                             narwhal_primary_address: validator.narwhal_primary_address.clone(),
+                            network_pubkey: validator.narwhal_network_pubkey.clone(),
                         },
                     ),
                 )
@@ -175,6 +175,7 @@ impl SuiSystemStateTrait for SimTestSuiSystemStateInnerV1 {
                 NetworkMetadata {
                     network_address: verified_metadata.net_address.clone(),
                     narwhal_primary_address: verified_metadata.primary_address.clone(),
+                    network_pubkey: verified_metadata.network_pubkey.clone(),
                 },
             );
         }
@@ -289,6 +290,7 @@ impl SuiSystemStateTrait for SimTestSuiSystemStateInnerShallowV2 {
                 NetworkMetadata {
                     network_address: verified_metadata.net_address.clone(),
                     narwhal_primary_address: verified_metadata.primary_address.clone(),
+                    network_pubkey: verified_metadata.network_pubkey.clone(),
                 },
             );
         }
@@ -432,6 +434,7 @@ impl SuiSystemStateTrait for SimTestSuiSystemStateInnerDeepV2 {
                 NetworkMetadata {
                     network_address: verified_metadata.net_address.clone(),
                     narwhal_primary_address: verified_metadata.primary_address.clone(),
+                    network_pubkey: verified_metadata.network_pubkey.clone(),
                 },
             );
         }
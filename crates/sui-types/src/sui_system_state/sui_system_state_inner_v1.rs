@@ -554,6 +554,7 @@ impl SuiSystemStateTrait for SuiSystemStateInnerV1 {
                 NetworkMetadata {
                     network_address: verified_metadata.net_address.clone(),
                     narwhal_primary_address: verified_metadata.primary_address.clone(),
+                    network_pubkey: verified_metadata.network_pubkey.clone(),
                 },
             );
         }
@@ -3,6 +3,7 @@
 
 use crate::base_types::{AuthorityName, ObjectID, SuiAddress};
 use crate::committee::{Committee, CommitteeWithNetworkMetadata, NetworkMetadata};
+use crate::crypto::NetworkPublicKey;
 use crate::dynamic_field::get_dynamic_field_from_store;
 use crate::error::SuiError;
 use crate::id::ID;
@@ -187,25 +188,44 @@ pub struct SuiSystemStateSummary {
 }
 
 impl SuiSystemStateSummary {
-    pub fn get_sui_committee_for_benchmarking(&self) -> CommitteeWithNetworkMetadata {
+    /// The committee of the current epoch, together with the network metadata the validators
+    /// registered on-chain. This is all a client needs to submit transactions to the validators
+    /// directly.
+    pub fn get_sui_committee_with_network_metadata(
+        &self,
+    ) -> Result<CommitteeWithNetworkMetadata, SuiError> {
         let mut voting_rights = BTreeMap::new();
         let mut network_metadata = BTreeMap::new();
         for validator in &self.active_validators {
-            let name = AuthorityName::from_bytes(&validator.protocol_pubkey_bytes).unwrap();
+            let invalid = |field: &str, e: &dyn std::fmt::Display| {
+                SuiError::InvalidCommittee(format!(
+                    "Invalid {field} of validator {}: {e}",
+                    validator.sui_address
+                ))
+            };
+            let name = AuthorityName::from_bytes(&validator.protocol_pubkey_bytes)
+                .map_err(|e| invalid("protocol key", &e))?;
             voting_rights.insert(name, validator.voting_power);
             network_metadata.insert(
                 name,
                 NetworkMetadata {
-                    network_address: Multiaddr::try_from(validator.net_address.clone()).unwrap(),
+                    network_address: Multiaddr::try_from(validator.net_address.clone())
+                        .map_err(|e| invalid("network address", &e))?,
                     narwhal_primary_address: Multiaddr::try_from(validator.primary_address.clone())
-                        .unwrap(),
+                        .map_err(|e| invalid("primary address", &e))?,
+                    network_pubkey: NetworkPublicKey::from_bytes(&validator.network_pubkey_bytes)
+                        .map_err(|e| invalid("network key", &e))?,
                 },
             );
         }
-        CommitteeWithNetworkMetadata {
+        Ok(CommitteeWithNetworkMetadata {
             committee: Committee::new(self.epoch, voting_rights),
             network_metadata,
-        }
+        })
+    }
+
+    pub fn get_sui_committee_for_benchmarking(&self) -> CommitteeWithNetworkMetadata {
+        self.get_sui_committee_with_network_metadata().unwrap()
     }
 }
 
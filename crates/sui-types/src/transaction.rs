@@ -10,6 +10,7 @@ use crate::crypto::{
     DefaultHash, Ed25519SuiSignature, EmptySignInfo, Signature, Signer, SuiSignatureInner,
     ToFromBytes,
 };
+use crate::deserialization_limits::{deserialize_bounded_vec, DeserializationLimits};
 use crate::digests::ConsensusCommitDigest;
 use crate::digests::{CertificateDigest, SenderSignedDataDigest};
 use crate::execution::SharedInput;
@@ -34,7 +35,7 @@ use move_core_types::ident_str;
 use move_core_types::identifier::IdentStr;
 use move_core_types::{identifier::Identifier, language_storage::TypeTag};
 use nonempty::{nonempty, NonEmpty};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use shared_crypto::intent::{Intent, IntentMessage, IntentScope};
 use std::fmt::Write;
 use std::fmt::{Debug, Display, Formatter};
@@ -812,6 +813,19 @@ impl Command {
         }
     }
 
+    fn is_input_arg_used(&self, input_arg: u16) -> bool {
+        let is_input = |arg: &Argument| matches!(arg, Argument::Input(i) if *i == input_arg);
+        match self {
+            Command::MoveCall(c) => c.arguments.iter().any(is_input),
+            Command::TransferObjects(args, arg)
+            | Command::MergeCoins(arg, args)
+            | Command::SplitCoins(arg, args) => is_input(arg) || args.iter().any(is_input),
+            Command::MakeMoveVec(_, args) => args.iter().any(is_input),
+            Command::Upgrade(_, _, _, arg) => is_input(arg),
+            Command::Publish(_, _) => false,
+        }
+    }
+
     fn validity_check(&self, config: &ProtocolConfig) -> UserInputResult {
         match self {
             Command::MoveCall(call) => call.validity_check(config)?,
@@ -946,6 +960,35 @@ impl ProgrammableTransaction {
             command.validity_check(config)?;
         }
 
+        // A command using Random can only be followed by commands that cannot abort depending on
+        // its output, so that the outcome of the transaction cannot be rejected after the fact.
+        let random_index = inputs.iter().position(|input| {
+            matches!(
+                input,
+                CallArg::Object(ObjectArg::SharedObject { id, .. })
+                    if *id == SUI_RANDOMNESS_STATE_OBJECT_ID
+            )
+        });
+        if config.enable_randomness_ptb_restrictions() {
+            if let Some(random_index) = random_index {
+                let random_index = random_index as u16;
+                let mut used_random = false;
+                for command in commands {
+                    if used_random {
+                        fp_ensure!(
+                            matches!(
+                                command,
+                                Command::TransferObjects(_, _) | Command::MergeCoins(_, _)
+                            ),
+                            UserInputError::PostRandomCommandRestrictions
+                        );
+                    } else {
+                        used_random = command.is_input_arg_used(random_index);
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -1466,6 +1509,11 @@ impl TransactionData {
         })
     }
 
+    /// The digest of the transaction once signed, which identifies it on chain.
+    pub fn digest(&self) -> TransactionDigest {
+        TransactionDigest::new(default_hash(self))
+    }
+
     pub fn new(
         kind: TransactionKind,
         sender: SuiAddress,
@@ -1649,6 +1697,26 @@ impl TransactionData {
         gas_payment: ObjectRef,
         gas_budget: u64,
         gas_price: u64,
+    ) -> anyhow::Result<Self> {
+        Self::new_pay_with_gas_coins(
+            sender,
+            coins,
+            recipients,
+            amounts,
+            vec![gas_payment],
+            gas_budget,
+            gas_price,
+        )
+    }
+
+    pub fn new_pay_with_gas_coins(
+        sender: SuiAddress,
+        coins: Vec<ObjectRef>,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<u64>,
+        gas_payment: Vec<ObjectRef>,
+        gas_budget: u64,
+        gas_price: u64,
     ) -> anyhow::Result<Self> {
         let pt = {
             let mut builder = ProgrammableTransactionBuilder::new();
@@ -1657,7 +1725,7 @@ impl TransactionData {
         };
         Ok(Self::new_programmable(
             sender,
-            vec![gas_payment],
+            gas_payment,
             pt,
             gas_budget,
             gas_price,
@@ -1708,6 +1776,24 @@ impl TransactionData {
         dep_ids: Vec<ObjectID>,
         gas_budget: u64,
         gas_price: u64,
+    ) -> Self {
+        Self::new_module_with_gas_coins(
+            sender,
+            vec![gas_payment],
+            modules,
+            dep_ids,
+            gas_budget,
+            gas_price,
+        )
+    }
+
+    pub fn new_module_with_gas_coins(
+        sender: SuiAddress,
+        gas_payment: Vec<ObjectRef>,
+        modules: Vec<Vec<u8>>,
+        dep_ids: Vec<ObjectID>,
+        gas_budget: u64,
+        gas_price: u64,
     ) -> Self {
         let pt = {
             let mut builder = ProgrammableTransactionBuilder::new();
@@ -1715,7 +1801,7 @@ impl TransactionData {
             builder.transfer_arg(sender, upgrade_cap);
             builder.finish()
         };
-        Self::new_programmable(sender, vec![gas_payment], pt, gas_budget, gas_price)
+        Self::new_programmable(sender, gas_payment, pt, gas_budget, gas_price)
     }
 
     pub fn new_upgrade(
@@ -1729,6 +1815,32 @@ impl TransactionData {
         digest: Vec<u8>,
         gas_budget: u64,
         gas_price: u64,
+    ) -> anyhow::Result<Self> {
+        Self::new_upgrade_with_gas_coins(
+            sender,
+            vec![gas_payment],
+            package_id,
+            modules,
+            dep_ids,
+            (upgrade_capability, capability_owner),
+            upgrade_policy,
+            digest,
+            gas_budget,
+            gas_price,
+        )
+    }
+
+    pub fn new_upgrade_with_gas_coins(
+        sender: SuiAddress,
+        gas_payment: Vec<ObjectRef>,
+        package_id: ObjectID,
+        modules: Vec<Vec<u8>>,
+        dep_ids: Vec<ObjectID>,
+        (upgrade_capability, capability_owner): (ObjectRef, Owner),
+        upgrade_policy: u8,
+        digest: Vec<u8>,
+        gas_budget: u64,
+        gas_price: u64,
     ) -> anyhow::Result<Self> {
         let pt = {
             let mut builder = ProgrammableTransactionBuilder::new();
@@ -1776,7 +1888,7 @@ impl TransactionData {
         };
         Ok(Self::new_programmable(
             sender,
-            vec![gas_payment],
+            gas_payment,
             pt,
             gas_budget,
             gas_price,
@@ -2031,7 +2143,10 @@ impl TransactionDataAPI for TransactionDataV1 {
 impl TransactionDataV1 {}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
-pub struct SenderSignedData(Vec<SenderSignedTransaction>);
+pub struct SenderSignedData(
+    #[serde(deserialize_with = "deserialize_sender_signed_transactions")]
+    Vec<SenderSignedTransaction>,
+);
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct SenderSignedTransaction {
@@ -2039,9 +2154,27 @@ pub struct SenderSignedTransaction {
     /// A list of signatures signed by all transaction participants.
     /// 1. non participant signature must not be present.
     /// 2. signature order does not matter.
+    #[serde(deserialize_with = "deserialize_tx_signatures")]
     pub tx_signatures: Vec<GenericSignature>,
 }
 
+fn deserialize_sender_signed_transactions<'de, D>(
+    deserializer: D,
+) -> Result<Vec<SenderSignedTransaction>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // SenderSignedData must contain exactly one transaction, see `verify_user_input`.
+    deserialize_bounded_vec(deserializer, 1)
+}
+
+fn deserialize_tx_signatures<'de, D>(deserializer: D) -> Result<Vec<GenericSignature>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_bounded_vec(deserializer, DeserializationLimits::get().max_tx_signatures)
+}
+
 impl SenderSignedData {
     pub fn new(
         tx_data: TransactionData,
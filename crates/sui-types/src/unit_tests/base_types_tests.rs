@@ -130,6 +130,16 @@ fn test_object_id_str_lossless() {
     assert_eq!(id_one.short_str_lossless(), "1",);
 }
 
+#[test]
+fn test_object_id_find_creation_num() {
+    let digest = TransactionDigest::random();
+    let id = ObjectID::derive_id(digest, 3);
+
+    assert_eq!(id.find_creation_num(digest, 10), Some(3));
+    assert_eq!(id.find_creation_num(digest, 3), None);
+    assert_eq!(id.find_creation_num(TransactionDigest::random(), 10), None);
+}
+
 #[test]
 fn test_object_id_from_hex_literal() {
     let hex_literal = "0x1";
@@ -0,0 +1,592 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The digests of `BcsSignable` types are computed over their BCS bytes, so any change to their
+//! layout (e.g. reordering fields) changes digests and invalidates signatures. These tests pin the
+//! bytes and digests of fixed values, and check that arbitrary values round trip.
+
+use super::*;
+use crate::accumulator::Accumulator;
+use crate::base_types::{ExecutionDigests, ObjectID, ObjectRef, SequenceNumber, SuiAddress};
+use crate::committee::{Committee, TOTAL_VOTING_POWER};
+use crate::digests::{
+    CheckpointContentsDigest, CheckpointDigest, Digest, ObjectDigest, TransactionDigest,
+    TransactionEffectsDigest, TransactionEventsDigest,
+};
+use crate::effects::{TransactionEffects, TransactionEvents};
+use crate::event::Event;
+use crate::execution_status::ExecutionStatus;
+use crate::gas::GasCostSummary;
+use crate::message_envelope::Message;
+use crate::messages_checkpoint::{CheckpointContents, CheckpointSummary, ECMHLiveObjectSetDigest};
+use crate::object::{Data, MoveObject, ObjectInner, Owner};
+use crate::signature::GenericSignature;
+use crate::transaction::{
+    Argument, CallArg, Command, ProgrammableTransaction, SenderSignedData, TransactionData,
+};
+use expect_test::expect;
+use fastcrypto::hash::MultisetHash;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
+use proptest::collection;
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// The generator of G2 in compressed form, a valid BLS12-381 public key.
+const G2_GENERATOR: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+/// An object reference, as (ID, version, digest) bytes.
+type RefSeed = ([u8; 32], u64, [u8; 32]);
+
+fn signable_bytes<T: Signable<Vec<u8>>>(value: &T) -> Vec<u8> {
+    let mut bytes = vec![];
+    value.write(&mut bytes);
+    bytes
+}
+
+fn assert_round_trip<T>(value: &T)
+where
+    T: Signable<Vec<u8>> + SignableBytes + Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let bytes = bcs::to_bytes(value).unwrap();
+    assert_eq!(&bcs::from_bytes::<T>(&bytes).unwrap(), value);
+    assert_eq!(
+        bcs::to_bytes(&bcs::from_bytes::<T>(&bytes).unwrap()).unwrap(),
+        bytes
+    );
+
+    let signable = signable_bytes(value);
+    assert!(signable.ends_with(&bytes));
+    assert_eq!(&T::from_signable_bytes(&signable).unwrap(), value);
+}
+
+fn transfer_sui_transaction_data(
+    sender: SuiAddress,
+    gas: (ObjectID, SequenceNumber, ObjectDigest),
+    recipient: SuiAddress,
+    amount: u64,
+    gas_budget: u64,
+    gas_price: u64,
+) -> TransactionData {
+    let pt = ProgrammableTransaction {
+        inputs: vec![
+            CallArg::Pure(bcs::to_bytes(&recipient).unwrap()),
+            CallArg::Pure(bcs::to_bytes(&amount).unwrap()),
+        ],
+        commands: vec![
+            Command::SplitCoins(Argument::GasCoin, vec![Argument::Input(1)]),
+            Command::TransferObjects(vec![Argument::NestedResult(0, 0)], Argument::Input(0)),
+        ],
+    };
+    TransactionData::new_programmable(sender, vec![gas], pt, gas_budget, gas_price)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn checkpoint_summary(
+    epoch: u64,
+    sequence_number: u64,
+    network_total_transactions: u64,
+    content_digest: [u8; 32],
+    previous_digest: Option<[u8; 32]>,
+    gas: (u64, u64, u64, u64),
+    timestamp_ms: u64,
+    commitment: [u8; 32],
+    version_specific_data: Vec<u8>,
+) -> CheckpointSummary {
+    CheckpointSummary {
+        epoch,
+        sequence_number,
+        network_total_transactions,
+        content_digest: CheckpointContentsDigest::new(content_digest),
+        previous_digest: previous_digest.map(CheckpointDigest::new),
+        epoch_rolling_gas_cost_summary: GasCostSummary::new(gas.0, gas.1, gas.2, gas.3),
+        timestamp_ms,
+        checkpoint_commitments: vec![ECMHLiveObjectSetDigest {
+            digest: Digest::new(commitment),
+        }
+        .into()],
+        end_of_epoch_data: None,
+        version_specific_data,
+    }
+}
+
+fn transaction_effects(
+    epoch: u64,
+    gas: (u64, u64, u64, u64),
+    transaction_digest: [u8; 32],
+    events_digest: Option<[u8; 32]>,
+    dependencies: Vec<[u8; 32]>,
+    lamport_version: u64,
+) -> TransactionEffects {
+    TransactionEffects::new_from_execution_v2(
+        ExecutionStatus::Success,
+        epoch,
+        GasCostSummary::new(gas.0, gas.1, gas.2, gas.3),
+        vec![],
+        TransactionDigest::new(transaction_digest),
+        SequenceNumber::from_u64(lamport_version),
+        BTreeMap::new(),
+        None,
+        events_digest.map(TransactionEventsDigest::new),
+        dependencies
+            .into_iter()
+            .map(TransactionDigest::new)
+            .collect(),
+    )
+}
+
+fn sender_signed_data(
+    tx_data: TransactionData,
+    signature: [u8; 64],
+    public_key: [u8; 32],
+) -> SenderSignedData {
+    let mut bytes = vec![SignatureScheme::ED25519.flag()];
+    bytes.extend(signature);
+    bytes.extend(public_key);
+    SenderSignedData::new(
+        tx_data,
+        Intent::sui_transaction(),
+        vec![GenericSignature::Signature(
+            Signature::from_bytes(&bytes).unwrap(),
+        )],
+    )
+}
+
+fn event(
+    package_id: [u8; 32],
+    sender: [u8; 32],
+    type_address: [u8; 32],
+    contents: Vec<u8>,
+) -> Event {
+    Event {
+        package_id: ObjectID::new(package_id),
+        transaction_module: Identifier::new("m").unwrap(),
+        sender: SuiAddress::from(ObjectID::new(sender)),
+        type_: StructTag {
+            address: AccountAddress::new(type_address),
+            module: Identifier::new("m").unwrap(),
+            name: Identifier::new("E").unwrap(),
+            type_params: vec![],
+        },
+        contents,
+    }
+}
+
+fn gas_object(
+    (id, version, value): ([u8; 32], u64, u64),
+    owner: [u8; 32],
+    previous_transaction: [u8; 32],
+    storage_rebate: u64,
+) -> ObjectInner {
+    ObjectInner {
+        data: Data::Move(MoveObject::new_gas_coin(
+            SequenceNumber::from_u64(version),
+            ObjectID::new(id),
+            value,
+        )),
+        owner: address_owner(owner),
+        previous_transaction: TransactionDigest::new(previous_transaction),
+        storage_rebate,
+    }
+}
+
+fn object_ref((id, version, digest): RefSeed) -> ObjectRef {
+    (
+        ObjectID::new(id),
+        SequenceNumber::from_u64(version),
+        ObjectDigest::new(digest),
+    )
+}
+
+fn address_owner(owner: [u8; 32]) -> Owner {
+    Owner::AddressOwner(SuiAddress::from(ObjectID::new(owner)))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn transaction_effects_v1(
+    epoch: u64,
+    gas: (u64, u64, u64, u64),
+    transaction_digest: [u8; 32],
+    modified_at_versions: Vec<([u8; 32], u64)>,
+    created: Vec<(RefSeed, [u8; 32])>,
+    deleted: Vec<RefSeed>,
+    gas_object: (RefSeed, [u8; 32]),
+    events_digest: Option<[u8; 32]>,
+    dependencies: Vec<[u8; 32]>,
+) -> TransactionEffects {
+    TransactionEffects::new_from_execution_v1(
+        ExecutionStatus::Success,
+        epoch,
+        GasCostSummary::new(gas.0, gas.1, gas.2, gas.3),
+        modified_at_versions
+            .into_iter()
+            .map(|(id, version)| (ObjectID::new(id), SequenceNumber::from_u64(version)))
+            .collect(),
+        vec![],
+        TransactionDigest::new(transaction_digest),
+        created
+            .into_iter()
+            .map(|(object, owner)| (object_ref(object), address_owner(owner)))
+            .collect(),
+        vec![],
+        vec![],
+        deleted.into_iter().map(object_ref).collect(),
+        vec![],
+        vec![],
+        (object_ref(gas_object.0), address_owner(gas_object.1)),
+        events_digest.map(TransactionEventsDigest::new),
+        dependencies
+            .into_iter()
+            .map(TransactionDigest::new)
+            .collect(),
+    )
+}
+
+fn committee(epoch: u64, seed: [u8; 32], weights: Vec<u64>) -> Committee {
+    let mut rng = StdRng::from_seed(seed);
+    let voting_weights = weights
+        .into_iter()
+        .map(|weight| {
+            let key_pair = AuthorityKeyPair::generate(&mut rng);
+            (AuthorityPublicKeyBytes::from(key_pair.public()), weight)
+        })
+        .collect();
+    Committee::new_for_testing_with_normalized_voting_power(epoch, voting_weights)
+}
+
+fn accumulator(inserted: &[[u8; 32]], removed: &[[u8; 32]]) -> Accumulator {
+    let mut accumulator = Accumulator::default();
+    for item in inserted {
+        accumulator.insert(ObjectDigest::new(*item));
+    }
+    for item in removed {
+        accumulator.remove(ObjectDigest::new(*item));
+    }
+    accumulator
+}
+
+fn digest_of<T: Signable<DefaultHash>>(value: &T) -> String {
+    Digest::new(default_hash(value)).to_string()
+}
+
+#[test]
+fn transaction_data_golden() {
+    let tx_data = transfer_sui_transaction_data(
+        SuiAddress::from(ObjectID::new([1; 32])),
+        (
+            ObjectID::new([2; 32]),
+            SequenceNumber::from_u64(3),
+            ObjectDigest::new([4; 32]),
+        ),
+        SuiAddress::from(ObjectID::new([5; 32])),
+        6,
+        7,
+        8,
+    );
+    expect!["000002002005050505050505050505050505050505050505050505050505050505050505050008060000000000000002020001010100010103000000000100000101010101010101010101010101010101010101010101010101010101010101010202020202020202020202020202020202020202020202020202020202020202030000000000000020040404040404040404040404040404040404040404040404040404040404040401010101010101010101010101010101010101010101010101010101010101010800000000000000070000000000000000"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&tx_data).unwrap()));
+    expect!["EGA62bqbC9dU2aVhtUZdLqcPbMkem5efZxZpk9wk6NnP"]
+        .assert_eq(&TransactionDigest::new(default_hash(&tx_data)).to_string());
+}
+
+#[test]
+fn checkpoint_summary_golden() {
+    let summary = checkpoint_summary(
+        1,
+        2,
+        3,
+        [4; 32],
+        Some([5; 32]),
+        (6, 7, 8, 9),
+        10,
+        [11; 32],
+        vec![12],
+    );
+    expect!["0100000000000000020000000000000003000000000000002004040404040404040404040404040404040404040404040404040404040404040120050505050505050505050505050505050505050505050505050505050505050506000000000000000700000000000000080000000000000009000000000000000a000000000000000100200b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b00010c"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&summary).unwrap()));
+    expect!["8SniXZFborzoFk8c19a17H2C9wB7tXN52AeGL17JMGGv"]
+        .assert_eq(&summary.digest().to_string());
+}
+
+#[test]
+fn checkpoint_contents_golden() {
+    let contents = CheckpointContents::new_with_digests_only_for_tests([ExecutionDigests::new(
+        TransactionDigest::new([1; 32]),
+        TransactionEffectsDigest::new([2; 32]),
+    )]);
+    expect!["00012001010101010101010101010101010101010101010101010101010101010101012002020202020202020202020202020202020202020202020202020202020202020100"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&contents).unwrap()));
+    expect!["6dNn2eRTMS4xamQg7R8t6nhqcHxZiVQqtpaLq7RVdeGG"]
+        .assert_eq(&contents.digest().to_string());
+}
+
+#[test]
+fn transaction_effects_golden() {
+    let effects = transaction_effects(5, (1, 2, 3, 4), [6; 32], Some([7; 32]), vec![[8; 32]], 9);
+    expect!["0100050000000000000001000000000000000200000000000000030000000000000004000000000000002006060606060606060606060606060606060606060606060606060606060606060001200707070707070707070707070707070707070707070707070707070707070707012008080808080808080808080808080808080808080808080808080808080808080900000000000000000000"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&effects).unwrap()));
+    expect!["2PZd95mrQJhVpH47LppmJU6SYVK8mqMtdHcPR9APhjyi"]
+        .assert_eq(&effects.digest().to_string());
+}
+
+#[test]
+fn sender_signed_data_golden() {
+    let tx_data = transfer_sui_transaction_data(
+        SuiAddress::from(ObjectID::new([1; 32])),
+        (
+            ObjectID::new([2; 32]),
+            SequenceNumber::from_u64(3),
+            ObjectDigest::new([4; 32]),
+        ),
+        SuiAddress::from(ObjectID::new([5; 32])),
+        6,
+        7,
+        8,
+    );
+    let data = sender_signed_data(tx_data, [9; 64], [10; 32]);
+    expect!["01000000000002002005050505050505050505050505050505050505050505050505050505050505050008060000000000000002020001010100010103000000000100000101010101010101010101010101010101010101010101010101010101010101010202020202020202020202020202020202020202020202020202020202020202030000000000000020040404040404040404040404040404040404040404040404040404040404040401010101010101010101010101010101010101010101010101010101010101010800000000000000070000000000000000016100090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&data).unwrap()));
+    expect!["BQzVPhzMP1HEopJ59EfYJfmidhq92bwizSfcAL1h9cvt"].assert_eq(&digest_of(&data));
+}
+
+#[test]
+fn transaction_events_golden() {
+    let events = TransactionEvents {
+        data: vec![event([1; 32], [2; 32], [3; 32], vec![4, 5])],
+    };
+    expect!["010101010101010101010101010101010101010101010101010101010101010101016d02020202020202020202020202020202020202020202020202020202020202020303030303030303030303030303030303030303030303030303030303030303016d014500020405"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&events).unwrap()));
+    expect!["Dn1TNuEcocvsFcRLzWRoPruC2CyeMhYe2YTWXEQXd3LJ"].assert_eq(&events.digest().to_string());
+}
+
+#[test]
+fn object_golden() {
+    let object = gas_object(([3; 32], 2, 100), [4; 32], [5; 32], 6);
+    expect!["000101020000000000000028030303030303030303030303030303030303030303030303030303030303030364000000000000000004040404040404040404040404040404040404040404040404040404040404042005050505050505050505050505050505050505050505050505050505050505050600000000000000"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&object).unwrap()));
+    expect!["HoorAqix93XLiqGxnp2Zw8nArCvckPV5SwXKkYPPXWqd"].assert_eq(&digest_of(&object));
+}
+
+#[test]
+fn committee_golden() {
+    let name = AuthorityPublicKeyBytes::new(Hex::decode(G2_GENERATOR).unwrap().try_into().unwrap());
+    let committee = Committee::new(1, BTreeMap::from([(name, TOTAL_VOTING_POWER)]));
+    expect!["0100000000000000016093e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb81027000000000000016093e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb893e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8016093e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&committee).unwrap()));
+    expect!["5DdM6JEaCgqdGbDAmk8CHLT6j92YoB69qstogMRYMjrZ"].assert_eq(&digest_of(&committee));
+}
+
+#[test]
+fn accumulator_golden() {
+    let accumulator = accumulator(&[[1; 32], [2; 32]], &[]);
+    expect!["1eb0199829479e3cf0e572ee989807634975c5f03087e642e5bc1903fc746118"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&accumulator).unwrap()));
+    expect!["9absMWtxUdGZ1QRnujbjf91SWVESHB4Sp6EjgV7aCYq4"].assert_eq(&digest_of(&accumulator));
+}
+
+#[test]
+fn transaction_effects_v1_golden() {
+    let effects = transaction_effects_v1(
+        1,
+        (2, 3, 4, 5),
+        [8; 32],
+        vec![([6; 32], 7)],
+        vec![(([9; 32], 10, [11; 32]), [12; 32])],
+        vec![([13; 32], 14, [15; 32])],
+        (([16; 32], 17, [18; 32]), [19; 32]),
+        None,
+        vec![[20; 32]],
+    );
+    expect!["0000010000000000000002000000000000000300000000000000040000000000000005000000000000000106060606060606060606060606060606060606060606060606060606060606060700000000000000002008080808080808080808080808080808080808080808080808080808080808080109090909090909090909090909090909090909090909090909090909090909090a00000000000000200b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b000c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0000010d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0e00000000000000200f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0000101010101010101010101010101010101010101010101010101010101010101011000000000000002012121212121212121212121212121212121212121212121212121212121212120013131313131313131313131313131313131313131313131313131313131313130001201414141414141414141414141414141414141414141414141414141414141414"]
+        .assert_eq(&Hex::encode(bcs::to_bytes(&effects).unwrap()));
+    expect!["GnkvNVZiushdAUJQFP6EpWeQW2p5S33VUMaRYaGygy5f"]
+        .assert_eq(&effects.digest().to_string());
+}
+
+proptest! {
+    #[test]
+    fn transaction_data_round_trip(
+        sender in any::<[u8; 32]>(),
+        gas in (any::<[u8; 32]>(), any::<u64>(), any::<[u8; 32]>()),
+        recipient in any::<[u8; 32]>(),
+        amount in any::<u64>(),
+        gas_budget in any::<u64>(),
+        gas_price in any::<u64>(),
+    ) {
+        let tx_data = transfer_sui_transaction_data(
+            SuiAddress::from(ObjectID::new(sender)),
+            (
+                ObjectID::new(gas.0),
+                SequenceNumber::from_u64(gas.1),
+                ObjectDigest::new(gas.2),
+            ),
+            SuiAddress::from(ObjectID::new(recipient)),
+            amount,
+            gas_budget,
+            gas_price,
+        );
+        assert_round_trip(&tx_data);
+    }
+
+    #[test]
+    fn checkpoint_summary_round_trip(
+        numbers in (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>()),
+        content_digest in any::<[u8; 32]>(),
+        previous_digest in any::<Option<[u8; 32]>>(),
+        gas in (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>()),
+        commitment in any::<[u8; 32]>(),
+        version_specific_data in collection::vec(any::<u8>(), 0..64),
+    ) {
+        let summary = checkpoint_summary(
+            numbers.0,
+            numbers.1,
+            numbers.2,
+            content_digest,
+            previous_digest,
+            gas,
+            numbers.3,
+            commitment,
+            version_specific_data,
+        );
+        assert_round_trip(&summary);
+    }
+
+    #[test]
+    fn checkpoint_contents_round_trip(
+        digests in collection::vec((any::<[u8; 32]>(), any::<[u8; 32]>()), 0..16),
+    ) {
+        let contents = CheckpointContents::new_with_digests_only_for_tests(
+            digests.into_iter().map(|(transaction, effects)| {
+                ExecutionDigests::new(
+                    TransactionDigest::new(transaction),
+                    TransactionEffectsDigest::new(effects),
+                )
+            }),
+        );
+        assert_round_trip(&contents);
+    }
+
+    #[test]
+    fn transaction_effects_round_trip(
+        epoch in any::<u64>(),
+        gas in (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>()),
+        transaction_digest in any::<[u8; 32]>(),
+        events_digest in any::<Option<[u8; 32]>>(),
+        dependencies in collection::vec(any::<[u8; 32]>(), 0..16),
+        lamport_version in any::<u64>(),
+    ) {
+        let effects = transaction_effects(
+            epoch,
+            gas,
+            transaction_digest,
+            events_digest,
+            dependencies,
+            lamport_version,
+        );
+        assert_round_trip(&effects);
+    }
+    #[test]
+    fn sender_signed_data_round_trip(
+        sender in any::<[u8; 32]>(),
+        gas in (any::<[u8; 32]>(), any::<u64>(), any::<[u8; 32]>()),
+        amount in any::<u64>(),
+        signature in collection::vec(any::<u8>(), 64),
+        public_key in any::<[u8; 32]>(),
+    ) {
+        let tx_data = transfer_sui_transaction_data(
+            SuiAddress::from(ObjectID::new(sender)),
+            (
+                ObjectID::new(gas.0),
+                SequenceNumber::from_u64(gas.1),
+                ObjectDigest::new(gas.2),
+            ),
+            SuiAddress::from(ObjectID::new(public_key)),
+            amount,
+            1_000_000,
+            1_000,
+        );
+        let data = sender_signed_data(tx_data, signature.try_into().unwrap(), public_key);
+        assert_round_trip(&data);
+    }
+
+    #[test]
+    fn transaction_events_round_trip(
+        events in collection::vec(
+            (
+                any::<[u8; 32]>(),
+                any::<[u8; 32]>(),
+                any::<[u8; 32]>(),
+                collection::vec(any::<u8>(), 0..64),
+            ),
+            0..8,
+        ),
+    ) {
+        let events = TransactionEvents {
+            data: events
+                .into_iter()
+                .map(|(package_id, sender, type_address, contents)| {
+                    event(package_id, sender, type_address, contents)
+                })
+                .collect(),
+        };
+        assert_round_trip(&events);
+    }
+
+    #[test]
+    fn object_round_trip(
+        coin in (any::<[u8; 32]>(), any::<u64>(), any::<u64>()),
+        owner in any::<[u8; 32]>(),
+        previous_transaction in any::<[u8; 32]>(),
+        storage_rebate in any::<u64>(),
+    ) {
+        let object = gas_object(coin, owner, previous_transaction, storage_rebate);
+        assert_round_trip(&object);
+    }
+
+    #[test]
+    fn committee_round_trip(
+        epoch in any::<u64>(),
+        seed in any::<[u8; 32]>(),
+        weights in collection::vec(1..1_000u64, 1..5),
+    ) {
+        assert_round_trip(&committee(epoch, seed, weights));
+    }
+
+    #[test]
+    fn accumulator_round_trip(
+        inserted in collection::vec(any::<[u8; 32]>(), 0..16),
+        removed in collection::vec(any::<[u8; 32]>(), 0..4),
+    ) {
+        assert_round_trip(&accumulator(&inserted, &removed));
+    }
+
+    #[test]
+    fn transaction_effects_v1_round_trip(
+        epoch in any::<u64>(),
+        gas in (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>()),
+        transaction_digest in any::<[u8; 32]>(),
+        modified_at_versions in collection::vec((any::<[u8; 32]>(), any::<u64>()), 0..8),
+        created in collection::vec(
+            ((any::<[u8; 32]>(), any::<u64>(), any::<[u8; 32]>()), any::<[u8; 32]>()),
+            0..8,
+        ),
+        deleted in collection::vec((any::<[u8; 32]>(), any::<u64>(), any::<[u8; 32]>()), 0..8),
+        gas_object in ((any::<[u8; 32]>(), any::<u64>(), any::<[u8; 32]>()), any::<[u8; 32]>()),
+        digests in (any::<Option<[u8; 32]>>(), collection::vec(any::<[u8; 32]>(), 0..8)),
+    ) {
+        let effects = transaction_effects_v1(
+            epoch,
+            gas,
+            transaction_digest,
+            modified_at_versions,
+            created,
+            deleted,
+            gas_object,
+            digests.0,
+            digests.1,
+        );
+        assert_round_trip(&effects);
+    }
+}
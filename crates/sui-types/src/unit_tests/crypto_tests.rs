@@ -18,6 +18,18 @@ fn serde_pubkey() {
     );
 }
 
+#[test]
+fn keypair_bytes_round_trip() {
+    for skp in [
+        SuiKeyPair::Ed25519(get_key_pair().1),
+        SuiKeyPair::Secp256k1(get_key_pair().1),
+        SuiKeyPair::Secp256r1(get_key_pair().1),
+    ] {
+        assert_eq!(SuiKeyPair::from_bytes(&skp.to_bytes()).unwrap(), skp);
+    }
+    assert!(SuiKeyPair::from_bytes(&[]).is_err());
+}
+
 #[test]
 fn serde_round_trip_authority_quorum_sign_info() {
     let info = AuthorityQuorumSignInfo::<true> {
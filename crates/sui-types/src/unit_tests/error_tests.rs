@@ -0,0 +1,353 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::base_types::{ObjectID, SequenceNumber};
+use crate::digests::{ObjectDigest, TransactionDigest};
+use crate::error::{
+    QueueDepthClass, SuiError, SuiErrorKind, SuiObjectResponseError, UserInputError,
+};
+use expect_test::expect;
+use serde_json::json;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Clients branch on error codes, so a code must never change once released.
+#[test]
+fn test_error_codes_are_stable() {
+    let object_ref = (ObjectID::ZERO, SequenceNumber::new(), ObjectDigest::MIN);
+    let errors: Vec<SuiError> = vec![
+        UserInputError::ObjectNotFound {
+            object_id: ObjectID::ZERO,
+            version: None,
+        }
+        .into(),
+        UserInputError::GasBalanceTooLow {
+            gas_balance: 10,
+            needed_gas_amount: 100,
+        }
+        .into(),
+        SuiObjectResponseError::NotExists {
+            object_id: ObjectID::ZERO,
+        }
+        .into(),
+        SuiError::TransactionNotFound {
+            digest: TransactionDigest::ZERO,
+        },
+        SuiError::TooManyTransactionsPendingExecution {
+            queue_len: 10,
+            threshold: 5,
+        },
+        SuiError::InvalidSignature {
+            error: String::new(),
+        },
+        SuiError::TxAlreadyFinalizedWithDifferentUserSigs,
+        SuiError::WrongEpoch {
+            expected_epoch: 1,
+            actual_epoch: 0,
+        },
+        SuiError::ModulePublishFailure {
+            error: String::new(),
+        },
+        SuiError::ObjectLockConflict {
+            obj_ref: object_ref,
+            pending_transaction: TransactionDigest::ZERO,
+        },
+        SuiError::GenericStorageError(String::new()),
+        SuiError::FailedToSubmitToConsensus(String::new()),
+        SuiError::InvalidPrivateKey,
+        SuiError::RpcError(String::new(), String::new()),
+        SuiError::UnsupportedFeatureError {
+            error: String::new(),
+        },
+        SuiError::TransactionSerializationError {
+            error: String::new(),
+        },
+        SuiError::ExecutionInvariantViolation,
+    ];
+    let codes: String = errors
+        .iter()
+        .map(|error| {
+            let data = error.error_data();
+            format!(
+                "{} {:?} {} {}\n",
+                error.as_ref(),
+                data.kind,
+                data.code,
+                data.retriable
+            )
+        })
+        .collect();
+    expect![[r#"
+        UserInputError InvalidInput 1502 true
+        UserInputError InvalidInput 1520 false
+        SuiObjectResponseError NotFound 2500 false
+        TransactionNotFound NotFound 2000 false
+        TooManyTransactionsPendingExecution Overload 3000 true
+        InvalidSignature Signature 4000 false
+        TxAlreadyFinalizedWithDifferentUserSigs Certificate 5004 false
+        WrongEpoch Epoch 6000 true
+        ModulePublishFailure Move 7002 false
+        ObjectLockConflict ObjectLock 8001 false
+        GenericStorageError Storage 9001 false
+        FailedToSubmitToConsensus Consensus 10000 false
+        InvalidPrivateKey Crypto 11004 false
+        RpcError Network 12001 true
+        UnsupportedFeatureError Unsupported 13002 false
+        TransactionSerializationError Serialization 14002 false
+        ExecutionInvariantViolation Internal 15000 false
+    "#]]
+    .assert_eq(&codes);
+}
+
+/// Pins the code of every variant. The `code` functions match without a wildcard, so a new
+/// variant can't compile without a code, and this table then has to be updated with it.
+#[test]
+fn test_every_error_code_is_pinned() {
+    let mut table = String::new();
+    let mut codes = HashSet::new();
+    let mut in_code_fn = false;
+    for line in include_str!("../error.rs").lines() {
+        let line = line.trim();
+        if line == "pub fn code(&self) -> u32 {" {
+            in_code_fn = true;
+        } else if in_code_fn && line == "}" {
+            in_code_fn = false;
+        } else if in_code_fn {
+            assert!(!line.starts_with("_ =>"), "code must not match a wildcard");
+            let Some((pattern, code)) = line.split_once(" => ") else {
+                continue;
+            };
+            let Ok(code) = code.trim_end_matches(',').parse::<u32>() else {
+                continue;
+            };
+            let variant = pattern.split([' ', '(']).next().unwrap();
+            assert!(codes.insert(code), "code {code} of {variant} is reused");
+            table.push_str(&format!(
+                "{variant} {:?} {code}\n",
+                SuiErrorKind::from_code(code)
+            ));
+        }
+    }
+    expect![[r#"
+        UserInputError::MutableObjectUsedMoreThanOnce InvalidInput 1500
+        UserInputError::ObjectInputArityViolation InvalidInput 1501
+        UserInputError::ObjectNotFound InvalidInput 1502
+        UserInputError::ObjectVersionUnavailableForConsumption InvalidInput 1503
+        UserInputError::PackageVerificationTimedout InvalidInput 1504
+        UserInputError::DependentPackageNotFound InvalidInput 1505
+        UserInputError::ImmutableParameterExpectedError InvalidInput 1506
+        UserInputError::SizeLimitExceeded InvalidInput 1507
+        UserInputError::InvalidChildObjectArgument InvalidInput 1508
+        UserInputError::InvalidObjectDigest InvalidInput 1509
+        UserInputError::InvalidSequenceNumber InvalidInput 1510
+        UserInputError::MovePackageAsObject InvalidInput 1511
+        UserInputError::MoveObjectAsPackage InvalidInput 1512
+        UserInputError::IncorrectUserSignature InvalidInput 1513
+        UserInputError::NotSharedObjectError InvalidInput 1514
+        UserInputError::DuplicateObjectRefInput InvalidInput 1515
+        UserInputError::MissingGasPayment InvalidInput 1516
+        UserInputError::GasObjectNotOwnedObject InvalidInput 1517
+        UserInputError::GasBudgetTooHigh InvalidInput 1518
+        UserInputError::GasBudgetTooLow InvalidInput 1519
+        UserInputError::GasBalanceTooLow InvalidInput 1520
+        UserInputError::UnsupportedSponsoredTransactionKind InvalidInput 1521
+        UserInputError::GasPriceUnderRGP InvalidInput 1522
+        UserInputError::GasPriceTooHigh InvalidInput 1523
+        UserInputError::InvalidGasObject InvalidInput 1524
+        UserInputError::InsufficientBalanceToCoverMinimalGas InvalidInput 1525
+        UserInputError::ObjectSequenceNumberTooHigh InvalidInput 1526
+        UserInputError::ObjectDeleted InvalidInput 1527
+        UserInputError::InvalidBatchTransaction InvalidInput 1528
+        UserInputError::BlockedMoveFunction InvalidInput 1529
+        UserInputError::EmptyInputCoins InvalidInput 1530
+        UserInputError::UnexpectedGasPaymentObject InvalidInput 1531
+        UserInputError::SharedObjectStartingVersionMismatch InvalidInput 1532
+        UserInputError::TransferObjectWithoutPublicTransferError InvalidInput 1533
+        UserInputError::EmptyCommandInput InvalidInput 1534
+        UserInputError::TransactionDenied InvalidInput 1535
+        UserInputError::Unsupported InvalidInput 1536
+        UserInputError::MoveFunctionInputError InvalidInput 1537
+        UserInputError::VerifiedCheckpointNotFound InvalidInput 1538
+        UserInputError::VerifiedCheckpointDigestNotFound InvalidInput 1539
+        UserInputError::LatestCheckpointSequenceNumberNotFound InvalidInput 1540
+        UserInputError::CheckpointContentsNotFound InvalidInput 1541
+        UserInputError::GenesisTransactionNotFound InvalidInput 1542
+        UserInputError::TransactionCursorNotFound InvalidInput 1543
+        UserInputError::InaccessibleSystemObject InvalidInput 1544
+        UserInputError::MaxPublishCountExceeded InvalidInput 1545
+        UserInputError::MutableParameterExpected InvalidInput 1546
+        UserInputError::AddressDeniedForCoin InvalidInput 1547
+        UserInputError::PostRandomCommandRestrictions InvalidInput 1548
+        SuiObjectResponseError::NotExists NotFound 2500
+        SuiObjectResponseError::DynamicFieldNotFound NotFound 2501
+        SuiObjectResponseError::Deleted NotFound 2502
+        SuiObjectResponseError::Unknown Internal 15500
+        SuiObjectResponseError::DisplayError Serialization 14500
+        SuiError::UnexpectedOwnerType InvalidInput 1000
+        SuiError::TooManyTransactionsPendingExecution Overload 3000
+        SuiError::TooManyTransactionsPendingConsensus Overload 3001
+        SuiError::TooManyTransactionsInFlightFromSender Overload 3002
+        SuiError::TooManyTransactionsInFlightFromClient Overload 3003
+        SuiError::GasPriceTooLowUnderLoad Overload 3004
+        SuiError::TooManyTransactionsPendingOnObject Overload 3005
+        SuiError::TooOldTransactionPendingOnObject Overload 3006
+        SuiError::ValidatorOverloaded Overload 3007
+        SuiError::InvalidSignature Signature 4000
+        SuiError::SignerSignatureAbsent Signature 4001
+        SuiError::SignerSignatureNumberMismatch Signature 4002
+        SuiError::IncorrectSigner Signature 4003
+        SuiError::UnknownSigner Signature 4004
+        SuiError::StakeAggregatorRepeatedSigner Signature 4005
+        SuiError::PotentiallyTemporarilyInvalidSignature Signature 4006
+        SuiError::WrongEpoch Epoch 6000
+        SuiError::CertificateRequiresQuorum Certificate 5000
+        SuiError::ErrorWhileProcessingCertificate Certificate 5001
+        SuiError::QuorumFailedToGetEffectsQuorumWhenProcessingTransaction Certificate 5002
+        SuiError::FailedToVerifyTxCertWithExecutedEffects Certificate 5003
+        SuiError::TxAlreadyFinalizedWithDifferentUserSigs Certificate 5004
+        SuiError::InvalidSystemTransaction InvalidInput 1001
+        SuiError::InvalidAuthenticator InvalidInput 1002
+        SuiError::InvalidAddress InvalidInput 1003
+        SuiError::InvalidTransactionDigest InvalidInput 1004
+        SuiError::InvalidDigestLength InvalidInput 1005
+        SuiError::UnexpectedMessage InvalidInput 1006
+        SuiError::ModuleVerificationFailure Move 7000
+        SuiError::ModuleDeserializationFailure Move 7001
+        SuiError::ModulePublishFailure Move 7002
+        SuiError::ModuleBuildFailure Move 7003
+        SuiError::FunctionNotFound Move 7004
+        SuiError::ModuleNotFound Move 7005
+        SuiError::TypeError Move 7006
+        SuiError::CircularObjectOwnership InvalidInput 1007
+        SuiError::ObjectLockAlreadyInitialized ObjectLock 8000
+        SuiError::ObjectLockConflict ObjectLock 8001
+        SuiError::ObjectLockedAtFutureEpoch ObjectLock 8002
+        SuiError::TransactionNotFound NotFound 2000
+        SuiError::TransactionsNotFound NotFound 2001
+        SuiError::TransactionEventsNotFound NotFound 2002
+        SuiError::TransactionAlreadyExecuted Certificate 5005
+        SuiError::BadObjectType InvalidInput 1008
+        SuiError::FailObjectLayout Move 7007
+        SuiError::ExecutionInvariantViolation Internal 15000
+        SuiError::ByzantineAuthoritySuspicion Certificate 5006
+        SuiError::StorageError Storage 9000
+        SuiError::GenericStorageError Storage 9001
+        SuiError::InvalidChildObjectAccess InvalidInput 1009
+        SuiError::StorageMissingFieldError Storage 9002
+        SuiError::StorageCorruptedFieldError Storage 9003
+        SuiError::GenericAuthorityError Internal 15001
+        SuiError::FailedToDispatchSubscription Network 12000
+        SuiError::OwnerFailedToSerialize Serialization 14000
+        SuiError::ExtraFieldFailedToDeserialize Serialization 14001
+        SuiError::TransactionOrchestratorLocalExecutionError Internal 15002
+        SuiError::TransactionSerializationError Serialization 14002
+        SuiError::ObjectSerializationError Serialization 14003
+        SuiError::ObjectDeserializationError Serialization 14004
+        SuiError::NoEventStore Unsupported 13000
+        SuiError::TooManyIncorrectAuthorities Certificate 5007
+        SuiError::FullNodeInvalidTxRangeQuery InvalidInput 1010
+        SuiError::FailedToSubmitToConsensus Consensus 10000
+        SuiError::ConsensusConnectionBroken Consensus 10001
+        SuiError::HandleConsensusTransactionFailure Consensus 10002
+        SuiError::SignatureSeedInvalidLength Crypto 11000
+        SuiError::HkdfError Crypto 11001
+        SuiError::SignatureKeyGenError Crypto 11002
+        SuiError::KeyConversionError Crypto 11003
+        SuiError::InvalidPrivateKey Crypto 11004
+        SuiError::FullNodeCantHandleCertificate Unsupported 13001
+        SuiError::ValidatorHaltedAtEpochEnd Epoch 6001
+        SuiError::EpochEnded Epoch 6002
+        SuiError::AdvanceEpochError Epoch 6003
+        SuiError::TransactionExpired Epoch 6004
+        SuiError::RpcError Network 12001
+        SuiError::UnsupportedFeatureError Unsupported 13002
+        SuiError::QuorumDriverCommunicationError Network 12002
+        SuiError::TimeoutError Network 12003
+        SuiError::ExecutionError Move 7008
+        SuiError::InvalidCommittee Epoch 6005
+        SuiError::MissingCommitteeAtEpoch Epoch 6006
+        SuiError::IndexStoreNotAvailable Unsupported 13003
+        SuiError::DynamicFieldReadError Storage 9004
+        SuiError::SuiSystemStateReadError Storage 9005
+        SuiError::UnexpectedVersion Unsupported 13004
+        SuiError::WrongMessageVersion Unsupported 13005
+        SuiError::Unknown Internal 15003
+        SuiError::FileIOError Storage 9006
+        SuiError::JWKRetrievalError Crypto 11005
+    "#]]
+    .assert_eq(&table);
+}
+
+#[test]
+fn test_error_kind_from_code() {
+    assert_eq!(SuiErrorKind::from_code(1000), SuiErrorKind::InvalidInput);
+    assert_eq!(SuiErrorKind::from_code(1999), SuiErrorKind::InvalidInput);
+    assert_eq!(SuiErrorKind::from_code(15000), SuiErrorKind::Internal);
+    assert_eq!(SuiErrorKind::from_code(0), SuiErrorKind::Internal);
+}
+
+#[test]
+fn test_error_context() {
+    let error: SuiError = UserInputError::ObjectNotFound {
+        object_id: ObjectID::ZERO,
+        version: None,
+    }
+    .into();
+    assert_eq!(
+        json!(error.error_data()),
+        json!({
+            "kind": "invalidInput",
+            "code": 1502,
+            "retriable": true,
+            "context": { "object_id": ObjectID::ZERO, "version": null },
+        })
+    );
+
+    let error: SuiError = SuiObjectResponseError::NotExists {
+        object_id: ObjectID::ZERO,
+    }
+    .into();
+    assert_eq!(
+        json!(error.error_data().context),
+        json!({ "object_id": ObjectID::ZERO })
+    );
+
+    let error = SuiError::RpcError("message".to_string(), "code".to_string());
+    assert_eq!(
+        json!(error.error_data().context),
+        json!({ "0": "message", "1": "code" })
+    );
+
+    assert!(SuiError::InvalidPrivateKey.error_data().context.is_empty());
+}
+
+#[test]
+fn test_validator_overloaded() {
+    let error = SuiError::ValidatorOverloaded {
+        error: Box::new(SuiError::TooManyTransactionsPendingConsensus),
+        queue_depth: QueueDepthClass::High,
+        retry_after_ms: 1000,
+    };
+    assert!(error.is_overload());
+    assert_eq!(error.is_retryable(), (true, true));
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(1)));
+
+    let data = error.error_data();
+    assert_eq!((data.kind, data.code), (SuiErrorKind::Overload, 3007));
+    assert_eq!(data.retry_after(), Some(Duration::from_secs(1)));
+    assert_eq!(
+        SuiError::TooManyTransactionsPendingConsensus
+            .error_data()
+            .retry_after(),
+        None
+    );
+}
+
+#[test]
+fn test_queue_depth_class() {
+    assert_eq!(QueueDepthClass::new(0, 100), QueueDepthClass::Low);
+    assert_eq!(QueueDepthClass::new(25, 100), QueueDepthClass::Medium);
+    assert_eq!(QueueDepthClass::new(50, 100), QueueDepthClass::High);
+    assert_eq!(QueueDepthClass::new(100, 100), QueueDepthClass::Full);
+    assert_eq!(QueueDepthClass::new(0, 0), QueueDepthClass::Full);
+}
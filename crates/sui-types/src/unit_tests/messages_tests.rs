@@ -571,6 +571,8 @@ fn test_user_signature_committed_in_transactions() {
 
     // The digest is the same for the same TransactionData even though the signature is different.
     assert_eq!(tx_digest_a, tx_digest_b);
+    // The digest only depends on the TransactionData.
+    assert_eq!(*tx_digest_a, transaction_a.transaction_data().digest());
 
     // The digest is the different for different TransactionData even though the signer is the same.
     assert_ne!(tx_digest_a, tx_digest_c);
@@ -1343,6 +1345,51 @@ fn test_certificate_digest() {
     assert_ne!(digest, cert.certificate_digest());
 }
 
+#[test]
+fn test_randomness_ptb_restrictions() {
+    let config = ProtocolConfig::get_for_max_version_UNSAFE();
+    let random = ObjectArg::SharedObject {
+        id: SUI_RANDOMNESS_STATE_OBJECT_ID,
+        initial_shared_version: SequenceNumber::from_u64(1),
+        mutable: false,
+    };
+    let ptb_after_random = |command: fn(Argument) -> Command| {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let random = builder.obj(random).unwrap();
+        let coin = builder
+            .obj(ObjectArg::ImmOrOwnedObject(random_object_ref()))
+            .unwrap();
+        builder.programmable_move_call(
+            ObjectID::random(),
+            Identifier::new("lottery").unwrap(),
+            Identifier::new("play").unwrap(),
+            vec![],
+            vec![random, coin],
+        );
+        builder.command(command(coin));
+        builder.finish()
+    };
+
+    // Commands that cannot abort depending on the random output are allowed.
+    ptb_after_random(|coin| Command::TransferObjects(vec![coin], coin))
+        .validity_check(&config)
+        .unwrap();
+    ptb_after_random(|coin| Command::MergeCoins(coin, vec![coin]))
+        .validity_check(&config)
+        .unwrap();
+
+    let pt = ptb_after_random(|coin| Command::SplitCoins(coin, vec![coin]));
+    assert_eq!(
+        pt.validity_check(&config).unwrap_err(),
+        UserInputError::PostRandomCommandRestrictions
+    );
+
+    // Restrictions only apply once the feature is enabled.
+    let mut config = config;
+    config.set_enable_randomness_ptb_restrictions_for_testing(false);
+    pt.validity_check(&config).unwrap();
+}
+
 // Use this to ensure that our approximation for components used in effects size are not smaller than expected
 // If this test fails, the value of the constant must be increased
 #[test]
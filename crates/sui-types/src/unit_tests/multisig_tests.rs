@@ -125,12 +125,13 @@ fn multisig_scenarios() {
     // providing the same sig twice fails.
     assert!(MultiSig::combine(vec![sig1.clone(), sig1.clone()], multisig_pk_2.clone()).is_err());
 
-    // Change position for sig2 and sig1 is not ok with plain bitmap.
+    // Change position for sig2 and sig1 is ok with plain bitmap, combine sorts them by pk index.
     let multi_sig_7 =
         MultiSig::combine(vec![sig2.clone(), sig1.clone()], multisig_pk_2.clone()).unwrap();
+    assert_eq!(multi_sig_7, multi_sig_6);
     assert!(multi_sig_7
         .verify_authenticator(&msg, addr_2, None, &VerifyParams::default())
-        .is_err());
+        .is_ok());
 
     // Change position for sig2 and sig1 is not ok with legacy using roaring bitmap.
     let multi_sig_legacy_7 =
@@ -5,9 +5,10 @@ use std::{
     fmt::{Debug, Display, Formatter, Write},
     path::PathBuf,
     sync::Arc,
+    time::Duration,
 };
 
-use anyhow::{anyhow, ensure};
+use anyhow::{anyhow, bail, ensure};
 use bip32::DerivationPath;
 use clap::*;
 use colored::Colorize;
@@ -15,6 +16,7 @@ use fastcrypto::{
     encoding::{Base64, Encoding},
     traits::ToFromBytes,
 };
+use futures::StreamExt;
 
 use json_to_table::json_to_table;
 use move_core_types::language_storage::TypeTag;
@@ -30,12 +32,12 @@ use shared_crypto::intent::Intent;
 use sui_execution::verifier::VerifierOverrides;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    DynamicFieldPage, SuiData, SuiObjectData, SuiObjectResponse, SuiObjectResponseQuery,
-    SuiParsedData, SuiRawData, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse,
-    SuiTransactionBlockResponseOptions,
+    DynamicFieldPage, EventFilter, SuiData, SuiObjectData, SuiObjectResponse,
+    SuiObjectResponseQuery, SuiParsedData, SuiRawData, SuiTransactionBlockEffectsAPI,
+    SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions, TransactionFilter,
 };
 use sui_json_rpc_types::{SuiExecutionStatus, SuiObjectDataOptions};
-use sui_keys::keystore::AccountKeystore;
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
 use sui_move_build::{
     build_from_resolution_graph, check_invalid_dependencies, check_unpublished_dependencies,
     gather_published_ids, BuildConfig, CompiledPackage, PackageDependencies, PublishedAtError,
@@ -45,11 +47,11 @@ use sui_sdk::sui_client_config::{SuiClientConfig, SuiEnv};
 use sui_sdk::wallet_context::WalletContext;
 use sui_sdk::SuiClient;
 use sui_types::{
-    base_types::{ObjectID, SequenceNumber, SuiAddress},
+    base_types::{ObjectDigest, ObjectID, SequenceNumber, SuiAddress},
     crypto::SignatureScheme,
     digests::TransactionDigest,
     dynamic_field::DynamicFieldInfo,
-    error::SuiError,
+    error::{SuiError, SuiObjectResponseError},
     gas_coin::GasCoin,
     metrics::BytecodeVerifierMetrics,
     move_package::UpgradeCap,
@@ -68,8 +70,13 @@ use tabled::{
 };
 use tracing::info;
 
+use crate::client_ptb::Ptb;
 use crate::key_identity::{get_identity_address, KeyIdentity};
 
+#[cfg(test)]
+#[path = "unit_tests/client_commands_tests.rs"]
+mod client_commands_tests;
+
 macro_rules! serialize_or_execute {
     ($tx_data:expr, $serialize_unsigned:expr, $serialize_signed:expr, $context:expr, $result_variant:ident) => {{
         assert!(
@@ -201,14 +208,36 @@ pub enum SuiClientCommands {
         signatures: Vec<String>,
     },
 
+    /// Execute a combined serialized SenderSignedData string, as produced by
+    /// `--serialize-signed-transaction`. This is useful when the transaction is signed on a
+    /// different (e.g. air-gapped) machine and only needs to be broadcast from this one.
+    ExecuteCombinedSignedTx {
+        /// BCS serialized sender signed data, as base-64 encoded string.
+        #[clap(long)]
+        signed_tx_bytes: String,
+    },
+
     /// Obtain all gas objects owned by the address.
     /// An address' alias can be used instead of the address.
+    ///
+    /// With `--split` or `--merge`, first reorganize the address' gas coins and then list them.
     #[clap(name = "gas")]
     Gas {
         /// Address (or its alias) owning the objects
         #[clap(name = "owner_address")]
         #[arg(value_parser)]
         address: Option<KeyIdentity>,
+        /// Split the largest gas coin into this many coins of (roughly) equal value.
+        /// The gas for the transaction is paid from the split coin.
+        #[clap(long, conflicts_with = "merge", requires = "gas_budget")]
+        split: Option<u64>,
+        /// Merge all gas coins into a single coin. The gas for the transaction is paid from
+        /// the merged coin.
+        #[clap(long, requires = "gas_budget")]
+        merge: bool,
+        /// Gas budget for the split or merge transaction
+        #[clap(long)]
+        gas_budget: Option<u64>,
     },
 
     /// Merge two coin objects into one coin
@@ -260,6 +289,10 @@ pub enum SuiClientCommands {
         rpc: String,
         #[clap(long, value_hint = ValueHint::Url)]
         ws: Option<String>,
+        /// Path of the keystore to use in this environment. If not set, the keystore in use
+        /// when switching to the environment is kept.
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        keystore: Option<PathBuf>,
     },
 
     /// Get object info
@@ -376,6 +409,24 @@ pub enum SuiClientCommands {
         serialize_signed_transaction: bool,
     },
 
+    /// Build and execute a programmable transaction made of several commands, where each
+    /// command can use the results of the previous ones.
+    ///
+    /// Commands are `--move-call PACKAGE::MODULE::FUNCTION ["<TYPE_ARGS>"] ARGS...`,
+    /// `--split-coins COIN "[AMOUNTS]"` and `--transfer-objects "[OBJECTS]" RECIPIENT`.
+    /// `--assign NAME` names the result of the previous command, and `--assign NAME VALUE` names
+    /// a value, so that they can be used by the next commands as `NAME`, or `NAME.N` for one of
+    /// several results. `gas` refers to the gas coin.
+    ///
+    /// The transaction also accepts `--gas-budget` (required), `--gas-coin`,
+    /// `--serialize-unsigned-transaction` and `--serialize-signed-transaction`.
+    #[clap(name = "ptb")]
+    Ptb {
+        /// The commands and options of the transaction
+        #[clap(trailing_var_arg = true, allow_hyphen_values = true, required = true)]
+        args: Vec<String>,
+    },
+
     /// Publish Move modules
     #[clap(name = "publish")]
     Publish {
@@ -448,13 +499,17 @@ pub enum SuiClientCommands {
     },
 
     /// Switch active address and network(e.g., devnet, local rpc server).
+    ///
+    /// Each environment remembers its own active address: switching back to an environment
+    /// restores the address that was active when it was left. Switching to an environment
+    /// created with a keystore makes that keystore the one in use.
     #[clap(name = "switch")]
     Switch {
         /// An address to be used as the active address for subsequent
         /// commands. It accepts also the alias of the address.
         #[clap(long)]
         address: Option<KeyIdentity>,
-        /// The RPC server URL (e.g., local rpc server, devnet rpc server, etc) to be
+        /// The alias of the environment (e.g., local rpc server, devnet rpc server, etc) to be
         /// used for subsequent commands.
         #[clap(long)]
         env: Option<String>,
@@ -612,6 +667,28 @@ pub enum SuiClientCommands {
         address_override: Option<ObjectID>,
     },
 
+    /// Watch an object or events and print a JSON record on every change, until interrupted.
+    ///
+    /// Changes are received through the WebSocket endpoint of the active environment when one is
+    /// configured, and polled from the RPC endpoint otherwise.
+    #[clap(name = "watch")]
+    Watch {
+        /// Object to watch. A record is printed with its current state, then each time its
+        /// version changes.
+        #[clap(long, required_unless_present = "events", conflicts_with = "events")]
+        object: Option<ObjectID>,
+        /// Event filter, in the JSON format of `suix_queryEvents`, e.g.
+        /// '{"MoveModule": {"package": "0x2", "module": "coin"}}'.
+        #[clap(long)]
+        events: Option<String>,
+        /// Polling interval in milliseconds, when WebSocket is not available.
+        #[clap(long, default_value_t = 1000)]
+        poll_interval_ms: u64,
+        /// Stop after printing this many records.
+        #[clap(long)]
+        limit: Option<usize>,
+    },
+
     /// Replay a given transaction to view transaction effects. Set environment variable MOVE_VM_STEP=1 to debug.
     #[clap(name = "replay-transaction")]
     ReplayTransaction {
@@ -700,6 +777,7 @@ impl SuiClientCommands {
                     end,
                     terminate_early,
                     max_tasks: 16,
+                    report: None,
                 };
                 let rpc = context.config.get_active_env()?.rpc.clone();
                 let _command_result =
@@ -822,6 +900,24 @@ impl SuiClientCommands {
                     Upgrade
                 )
             }
+            SuiClientCommands::Ptb { args } => {
+                let ptb = Ptb::parse(&args)?;
+                let gas_owner = context.try_get_object_owner(&ptb.gas).await?;
+                let sender = gas_owner.unwrap_or(context.active_address()?);
+                let client = context.get_client().await?;
+                let builder = client.transaction_builder();
+                let pt = ptb.build(builder, &context.config.keystore).await?;
+                let tx_data = builder
+                    .programmable_transaction(sender, pt, ptb.gas, ptb.gas_budget)
+                    .await?;
+                serialize_or_execute!(
+                    tx_data,
+                    ptb.serialize_unsigned_transaction,
+                    ptb.serialize_signed_transaction,
+                    context,
+                    Ptb
+                )
+            }
             SuiClientCommands::Publish {
                 package_path,
                 gas,
@@ -1191,8 +1287,79 @@ impl SuiClientCommands {
                     recovery_phrase: phrase,
                 })
             }
-            SuiClientCommands::Gas { address } => {
+            SuiClientCommands::Gas {
+                address,
+                split,
+                merge,
+                gas_budget,
+            } => {
                 let address = get_identity_address(address, context)?;
+                if split.is_some() || merge {
+                    let gas_budget = gas_budget
+                        .ok_or_else(|| anyhow!("--gas-budget is required to split or merge"))?;
+                    let mut gas_objects = context.gas_objects(address).await?;
+                    // Largest coin first.
+                    gas_objects.sort_by(|(a, _), (b, _)| b.cmp(a));
+                    let client = context.get_client().await?;
+                    let data = match split {
+                        Some(count) => {
+                            ensure!(count > 1, "Gas coin split count must be greater than 1");
+                            let (value, coin) = gas_objects
+                                .first()
+                                .ok_or_else(|| anyhow!("No gas coins are owned by {address}"))?;
+                            let amount = value.saturating_sub(gas_budget) / count;
+                            ensure!(
+                                amount > 0,
+                                "Largest gas coin {} is too small to be split into {count} coins",
+                                coin.object_id
+                            );
+                            // The split coin pays for gas and keeps the remainder.
+                            client
+                                .transaction_builder()
+                                .pay_sui(
+                                    address,
+                                    vec![coin.object_id],
+                                    vec![address; count as usize - 1],
+                                    vec![amount; count as usize - 1],
+                                    gas_budget,
+                                )
+                                .await?
+                        }
+                        None => {
+                            ensure!(
+                                gas_objects.len() > 1,
+                                "At least two gas coins are needed to merge"
+                            );
+                            // Merge as many coins as a transaction can pay for gas with, largest
+                            // first.
+                            let max_coins = client.transaction_builder().max_gas_coins().await?;
+                            client
+                                .transaction_builder()
+                                .pay_all_sui(
+                                    address,
+                                    gas_objects
+                                        .iter()
+                                        .take(max_coins)
+                                        .map(|(_, o)| o.object_id)
+                                        .collect(),
+                                    address,
+                                    gas_budget,
+                                )
+                                .await?
+                        }
+                    };
+                    let transaction = context.sign_transaction(&data);
+                    let response = context.execute_transaction_may_fail(transaction).await?;
+                    let effects = response.effects.as_ref().ok_or_else(|| {
+                        anyhow!("Effects from SuiTransactionBlockResult should not be empty")
+                    })?;
+                    if matches!(effects.status(), SuiExecutionStatus::Failure { .. }) {
+                        return Err(anyhow!(
+                            "Error executing transaction: {:#?}",
+                            effects.status()
+                        ));
+                    }
+                }
                 let coins = context
                     .gas_objects(address)
                     .await?
@@ -1281,6 +1448,11 @@ impl SuiClientCommands {
                     ));
                 }
 
+                // Switch env first, as it may change the keystore and the active address.
+                if let Some(ref env) = env {
+                    Self::switch_env(&mut context.config, env)?;
+                }
+
                 if let Some(address) = address.clone() {
                     let address = get_identity_address(Some(address), context)?;
                     if !context.config.keystore.addresses().contains(&address) {
@@ -1289,10 +1461,6 @@ impl SuiClientCommands {
                     context.config.active_address = Some(address);
                     addr = Some(address.to_string());
                 }
-
-                if let Some(ref env) = env {
-                    Self::switch_env(&mut context.config, env)?;
-                }
                 context.config.save()?;
                 SuiClientCommandResult::Switch(SwitchResponse { address: addr, env })
             }
@@ -1328,13 +1496,39 @@ impl SuiClientCommands {
                 let response = context.execute_transaction_may_fail(transaction).await?;
                 SuiClientCommandResult::ExecuteSignedTx(response)
             }
-            SuiClientCommands::NewEnv { alias, rpc, ws } => {
+            SuiClientCommands::ExecuteCombinedSignedTx { signed_tx_bytes } => {
+                let data: SenderSignedData = bcs::from_bytes(
+                    &Base64::try_from(signed_tx_bytes)
+                        .map_err(|e| anyhow!(e))?
+                        .to_vec()
+                        .map_err(|e| anyhow!(e))?,
+                )?;
+                let transaction = Transaction::new(data);
+                let response = context.execute_transaction_may_fail(transaction).await?;
+                SuiClientCommandResult::ExecuteSignedTx(response)
+            }
+            SuiClientCommands::NewEnv {
+                alias,
+                rpc,
+                ws,
+                keystore,
+            } => {
                 if context.config.envs.iter().any(|env| env.alias == alias) {
                     return Err(anyhow!(
                         "Environment config with name [{alias}] already exists."
                     ));
                 }
-                let env = SuiEnv { alias, rpc, ws };
+                if let Some(keystore) = &keystore {
+                    // Check the keystore can be loaded
+                    FileBasedKeystore::new(keystore)?;
+                }
+                let env = SuiEnv {
+                    alias,
+                    rpc,
+                    ws,
+                    active_address: None,
+                    keystore,
+                };
 
                 // Check urls are valid and server is reachable
                 env.create_rpc_client(None, None).await?;
@@ -1349,6 +1543,28 @@ impl SuiClientCommands {
                 context.config.envs.clone(),
                 context.config.active_env.clone(),
             ),
+            SuiClientCommands::Watch {
+                object,
+                events,
+                poll_interval_ms,
+                limit,
+            } => {
+                let client = context.get_client().await?;
+                let poll_interval = Duration::from_millis(poll_interval_ms);
+                let limit = limit.unwrap_or(usize::MAX);
+                match (object, events) {
+                    (Some(object_id), _) => {
+                        watch_object(&client, object_id, poll_interval, limit).await?
+                    }
+                    (None, Some(filter)) => {
+                        let filter: EventFilter = serde_json::from_str(&filter)
+                            .map_err(|e| anyhow!("Invalid event filter: {e}"))?;
+                        watch_events(&client, filter, poll_interval, limit).await?
+                    }
+                    (None, None) => bail!("Either --object or --events must be provided"),
+                }
+                SuiClientCommandResult::Watch
+            }
             SuiClientCommands::VerifySource {
                 package_path,
                 build_config,
@@ -1392,10 +1608,7 @@ impl SuiClientCommands {
     }
 
     pub fn switch_env(config: &mut SuiClientConfig, env: &str) -> Result<(), anyhow::Error> {
-        let env = Some(env.into());
-        ensure!(config.get_env(&env).is_some(), "Environment config not found for [{env:?}], add new environment config using the `sui client new-env` command.");
-        config.active_env = env;
-        Ok(())
+        config.switch_env(env)
     }
 }
 
@@ -1658,7 +1871,7 @@ impl Display for SuiClientCommandResult {
                 };
                 writeln!(writer, "{}", raw_object)?;
             }
-            SuiClientCommandResult::Call(response) => {
+            SuiClientCommandResult::Call(response) | SuiClientCommandResult::Ptb(response) => {
                 write!(writer, "{}", response)?;
             }
             SuiClientCommandResult::SerializedUnsignedTransaction(tx_data) => {
@@ -1667,6 +1880,7 @@ impl Display for SuiClientCommandResult {
                     "{}",
                     fastcrypto::encoding::Base64::encode(bcs::to_bytes(tx_data).unwrap())
                 )?;
+                writeln!(writer, "Transaction Digest: {}", tx_data.digest())?;
             }
             SuiClientCommandResult::SerializedSignedTransaction(sender_signed_tx) => {
                 writeln!(
@@ -1674,6 +1888,11 @@ impl Display for SuiClientCommandResult {
                     "{}",
                     fastcrypto::encoding::Base64::encode(bcs::to_bytes(sender_signed_tx).unwrap())
                 )?;
+                writeln!(
+                    writer,
+                    "Transaction Digest: {}",
+                    sender_signed_tx.transaction_data().digest()
+                )?;
             }
             SuiClientCommandResult::Transfer(response) => {
                 write!(writer, "{}", response)?;
@@ -1772,6 +1991,7 @@ impl Display for SuiClientCommandResult {
             SuiClientCommandResult::ReplayTransaction => {}
             SuiClientCommandResult::ReplayBatch => {}
             SuiClientCommandResult::ReplayCheckpoints => {}
+            SuiClientCommandResult::Watch => {}
         }
         write!(f, "{}", writer.trim_end_matches('\n'))
     }
@@ -1809,7 +2029,7 @@ async fn construct_move_call_transaction(
         .await
 }
 
-fn convert_number_to_string(value: Value) -> Value {
+pub(crate) fn convert_number_to_string(value: Value) -> Value {
     match value {
         Value::Number(n) => Value::String(n.to_string()),
         Value::Array(a) => Value::Array(a.into_iter().map(convert_number_to_string).collect()),
@@ -1840,6 +2060,20 @@ impl Debug for SuiClientCommandResult {
                 let raw_object = raw_object_read.object()?;
                 Ok(serde_json::to_string_pretty(&raw_object)?)
             }
+            SuiClientCommandResult::SerializedUnsignedTransaction(tx_data) => {
+                let output = SerializedTransactionOutput {
+                    tx_bytes: Base64::encode(bcs::to_bytes(tx_data)?),
+                    digest: tx_data.digest(),
+                };
+                Ok(serde_json::to_string_pretty(&output)?)
+            }
+            SuiClientCommandResult::SerializedSignedTransaction(sender_signed_tx) => {
+                let output = SerializedTransactionOutput {
+                    tx_bytes: Base64::encode(bcs::to_bytes(sender_signed_tx)?),
+                    digest: sender_signed_tx.transaction_data().digest(),
+                };
+                Ok(serde_json::to_string_pretty(&output)?)
+            }
             _ => Ok(serde_json::to_string_pretty(self)?),
         });
         write!(f, "{}", s)
@@ -1880,7 +2114,7 @@ impl SuiClientCommandResult {
     pub fn tx_block_response(&self) -> Option<&SuiTransactionBlockResponse> {
         use SuiClientCommandResult::*;
         match self {
-            Upgrade(b) | Publish(b) | TransactionBlock(b) | Call(b) | Transfer(b)
+            Upgrade(b) | Publish(b) | TransactionBlock(b) | Call(b) | Ptb(b) | Transfer(b)
             | TransferSui(b) | Pay(b) | PaySui(b) | PayAllSui(b) | SplitCoin(b) | MergeCoin(b)
             | ExecuteSignedTx(b) => Some(b),
             _ => None,
@@ -1912,6 +2146,16 @@ pub struct NewAddressOutput {
     pub recovery_phrase: String,
 }
 
+/// JSON output of `--serialize-unsigned-transaction` and `--serialize-signed-transaction`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerializedTransactionOutput {
+    /// Base64 encoded BCS bytes of the transaction.
+    pub tx_bytes: String,
+    /// Digest of the transaction data, i.e. the digest the transaction will be executed under.
+    pub digest: TransactionDigest,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ObjectOutput {
@@ -2033,6 +2277,7 @@ pub enum SuiClientCommandResult {
     Pay(SuiTransactionBlockResponse),
     PayAllSui(SuiTransactionBlockResponse),
     PaySui(SuiTransactionBlockResponse),
+    Ptb(SuiTransactionBlockResponse),
     Publish(SuiTransactionBlockResponse),
     RawObject(SuiObjectResponse),
     SerializedSignedTransaction(SenderSignedData),
@@ -2051,6 +2296,7 @@ pub enum SuiClientCommandResult {
         used_module_ticks: u128,
     },
     VerifySource,
+    Watch,
     ReplayTransaction,
     ReplayBatch,
     ReplayCheckpoints,
@@ -2076,3 +2322,163 @@ impl Display for SwitchResponse {
         write!(f, "{}", writer)
     }
 }
+
+/// A change of a watched object, printed by `sui client watch --object`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ObjectChangeRecord {
+    object_id: ObjectID,
+    version: SequenceNumber,
+    digest: ObjectDigest,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<Owner>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_transaction: Option<TransactionDigest>,
+    deleted: bool,
+}
+
+impl ObjectChangeRecord {
+    fn from_response(response: SuiObjectResponse) -> Option<Self> {
+        match (response.data, response.error) {
+            (Some(data), _) => Some(Self {
+                object_id: data.object_id,
+                version: data.version,
+                digest: data.digest,
+                owner: data.owner,
+                previous_transaction: data.previous_transaction,
+                deleted: false,
+            }),
+            (
+                None,
+                Some(SuiObjectResponseError::Deleted {
+                    object_id,
+                    version,
+                    digest,
+                }),
+            ) => Some(Self {
+                object_id,
+                version,
+                digest,
+                owner: None,
+                previous_transaction: None,
+                deleted: true,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Print a record as a single line of JSON.
+fn print_record<T: Serialize>(record: &T) -> Result<(), anyhow::Error> {
+    println!("{}", serde_json::to_string(record)?);
+    Ok(())
+}
+
+async fn watch_object(
+    client: &SuiClient,
+    object_id: ObjectID,
+    poll_interval: Duration,
+    limit: usize,
+) -> Result<(), anyhow::Error> {
+    let mut changes = match client
+        .read_api()
+        .subscribe_transaction(TransactionFilter::ChangedObject(object_id))
+        .await
+    {
+        Ok(stream) => Some(Box::pin(stream)),
+        Err(e) => {
+            info!("Cannot subscribe to object changes, falling back to polling: {e}");
+            None
+        }
+    };
+    let options = SuiObjectDataOptions::new()
+        .with_owner()
+        .with_previous_transaction();
+    let mut last_version = None;
+    let mut printed = 0;
+    while printed < limit {
+        let response = client
+            .read_api()
+            .get_object_with_options(object_id, options.clone())
+            .await?;
+        let Some(record) = ObjectChangeRecord::from_response(response) else {
+            bail!("Object {object_id} does not exist");
+        };
+        if last_version != Some(record.version) {
+            last_version = Some(record.version);
+            print_record(&record)?;
+            printed += 1;
+            if record.deleted {
+                break;
+            }
+        }
+        match &mut changes {
+            Some(stream) => match stream.next().await {
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    info!("Object subscription failed, falling back to polling: {e}");
+                    changes = None;
+                }
+                None => {
+                    info!("Object subscription closed, falling back to polling");
+                    changes = None;
+                }
+            },
+            None => tokio::time::sleep(poll_interval).await,
+        }
+    }
+    Ok(())
+}
+
+async fn watch_events(
+    client: &SuiClient,
+    filter: EventFilter,
+    poll_interval: Duration,
+    limit: usize,
+) -> Result<(), anyhow::Error> {
+    match client
+        .event_api()
+        .subscribe_events(filter.clone(), None)
+        .await
+    {
+        Ok(mut subscription) => {
+            let mut printed = 0;
+            while printed < limit {
+                match subscription.next().await {
+                    Some(event) => {
+                        print_record(&event?)?;
+                        printed += 1;
+                    }
+                    None => break,
+                }
+            }
+            return Ok(());
+        }
+        Err(e) => info!("Cannot subscribe to events, falling back to polling: {e}"),
+    }
+
+    // Only print the events emitted from now on.
+    let mut cursor = client
+        .event_api()
+        .query_events(filter.clone(), None, Some(1), true)
+        .await?
+        .data
+        .first()
+        .map(|event| event.id);
+    let mut printed = 0;
+    while printed < limit {
+        let page = client
+            .event_api()
+            .query_events(filter.clone(), cursor, None, false)
+            .await?;
+        for event in page.data.iter().take(limit - printed) {
+            print_record(event)?;
+            printed += 1;
+            cursor = Some(event.id);
+        }
+        if !page.has_next_page {
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,370 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Programmable transactions written on the command line, for `sui client ptb`.
+//!
+//! The arguments are a sequence of commands, each one being a `--command` flag followed by its
+//! operands, e.g.
+//!
+//! ```text
+//! sui client ptb \
+//!     --split-coins gas "[1000, 2000]" \
+//!     --assign coins \
+//!     --transfer-objects "[coins.0, coins.1]" 0x42 \
+//!     --gas-budget 10000000
+//! ```
+//!
+//! Operands are either the gas coin (`gas`), a variable bound with `--assign` (`name`, or
+//! `name.N` to select one of several results), or a value. Values are object IDs, addresses,
+//! numbers or any JSON value accepted by `sui client call`.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, ensure};
+use move_core_types::language_storage::TypeTag;
+use sui_json::SuiJsonValue;
+use sui_keys::keystore::Keystore;
+use sui_transaction_builder::{MoveCallArg, TransactionBuilder};
+use sui_types::base_types::ObjectID;
+use sui_types::parse_sui_type_tag;
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use sui_types::transaction::{Argument, Command, ProgrammableTransaction};
+
+use crate::client_commands::convert_number_to_string;
+use crate::key_identity::{get_identity_address_from_keystore, KeyIdentity};
+
+#[cfg(test)]
+#[path = "unit_tests/client_ptb_tests.rs"]
+mod client_ptb_tests;
+
+const GAS_COIN: &str = "gas";
+
+/// A programmable transaction parsed from the arguments of `sui client ptb`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Ptb {
+    pub commands: Vec<PtbCommand>,
+    pub gas: Option<ObjectID>,
+    pub gas_budget: u64,
+    pub serialize_unsigned_transaction: bool,
+    pub serialize_signed_transaction: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PtbCommand {
+    MoveCall {
+        package: ObjectID,
+        module: String,
+        function: String,
+        type_args: Vec<TypeTag>,
+        args: Vec<PtbArg>,
+    },
+    SplitCoins {
+        coin: PtbArg,
+        amounts: Vec<PtbArg>,
+    },
+    TransferObjects {
+        objects: Vec<PtbArg>,
+        recipient: PtbArg,
+    },
+}
+
+/// An operand of a command, with variables already substituted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PtbArg {
+    Gas,
+    /// The result of the command at this index.
+    Result(u16),
+    /// One of the results of the command at this index.
+    NestedResult(u16, u16),
+    /// A value, interpreted according to where it is used.
+    Value(String),
+}
+
+impl Ptb {
+    /// Parse the arguments of `sui client ptb`.
+    pub fn parse(args: &[String]) -> Result<Self, anyhow::Error> {
+        let mut ptb = Ptb::default();
+        let mut gas_budget = None;
+        let mut variables = BTreeMap::new();
+        // Whether the last command can be assigned to a variable.
+        let mut assignable = false;
+
+        let mut args = args.iter().peekable();
+        while let Some(flag) = args.next() {
+            let mut operands = vec![];
+            while let Some(operand) = args.next_if(|arg| !arg.starts_with("--")) {
+                operands.push(operand.as_str());
+            }
+            let index = u16::try_from(ptb.commands.len())?;
+            match flag.as_str() {
+                "--move-call" => {
+                    let Some((target, rest)) = operands.split_first() else {
+                        bail!("--move-call expects a PACKAGE::MODULE::FUNCTION target");
+                    };
+                    let (package, module, function) = parse_target(target)?;
+                    let (type_args, rest) = match rest.split_first() {
+                        Some((first, rest)) if first.starts_with('<') => {
+                            (parse_type_args(first)?, rest)
+                        }
+                        _ => (vec![], rest),
+                    };
+                    let args = rest
+                        .iter()
+                        .map(|arg| resolve_arg(arg, &variables))
+                        .collect::<Result<_, _>>()?;
+                    ptb.commands.push(PtbCommand::MoveCall {
+                        package,
+                        module,
+                        function,
+                        type_args,
+                        args,
+                    });
+                    assignable = true;
+                }
+                "--split-coins" => {
+                    let [coin, amounts] = operands[..] else {
+                        bail!("--split-coins expects a coin and a list of amounts");
+                    };
+                    ptb.commands.push(PtbCommand::SplitCoins {
+                        coin: resolve_arg(coin, &variables)?,
+                        amounts: resolve_list(amounts, &variables)?,
+                    });
+                    assignable = true;
+                }
+                "--transfer-objects" => {
+                    let [objects, recipient] = operands[..] else {
+                        bail!("--transfer-objects expects a list of objects and a recipient");
+                    };
+                    ptb.commands.push(PtbCommand::TransferObjects {
+                        objects: resolve_list(objects, &variables)?,
+                        recipient: resolve_arg(recipient, &variables)?,
+                    });
+                    assignable = false;
+                }
+                "--assign" => {
+                    let value = match operands[..] {
+                        [_] if assignable => PtbArg::Result(index - 1),
+                        [_] => {
+                            bail!("--assign without a value must follow a command with a result")
+                        }
+                        [_, value] => resolve_arg(value, &variables)?,
+                        _ => bail!("--assign expects a name and an optional value"),
+                    };
+                    let name = operands[0];
+                    ensure!(
+                        is_identifier(name) && name != GAS_COIN,
+                        "Invalid variable name: {name}"
+                    );
+                    variables.insert(name.to_string(), value);
+                    assignable = false;
+                }
+                "--gas-coin" => {
+                    let [gas] = operands[..] else {
+                        bail!("--gas-coin expects an object ID");
+                    };
+                    ptb.gas = Some(ObjectID::from_str(gas)?);
+                }
+                "--gas-budget" => {
+                    let [budget] = operands[..] else {
+                        bail!("--gas-budget expects an amount");
+                    };
+                    gas_budget = Some(budget.parse()?);
+                }
+                "--serialize-unsigned-transaction" => {
+                    ensure!(operands.is_empty(), "Unexpected operands: {operands:?}");
+                    ptb.serialize_unsigned_transaction = true;
+                }
+                "--serialize-signed-transaction" => {
+                    ensure!(operands.is_empty(), "Unexpected operands: {operands:?}");
+                    ptb.serialize_signed_transaction = true;
+                }
+                _ => bail!("Unknown command {flag}"),
+            }
+        }
+
+        ensure!(!ptb.commands.is_empty(), "The transaction has no commands");
+        ensure!(
+            !ptb.serialize_unsigned_transaction || !ptb.serialize_signed_transaction,
+            "Cannot specify both --serialize-unsigned-transaction and --serialize-signed-transaction"
+        );
+        ptb.gas_budget = gas_budget.ok_or_else(|| anyhow!("--gas-budget is required"))?;
+        Ok(ptb)
+    }
+
+    /// Build the programmable transaction, fetching the objects it uses.
+    pub async fn build(
+        &self,
+        builder: &TransactionBuilder,
+        keystore: &Keystore,
+    ) -> Result<ProgrammableTransaction, anyhow::Error> {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        for command in &self.commands {
+            match command {
+                PtbCommand::MoveCall {
+                    package,
+                    module,
+                    function,
+                    type_args,
+                    args,
+                } => {
+                    let args = args
+                        .iter()
+                        .map(|arg| {
+                            Ok(match arg {
+                                PtbArg::Value(value) => {
+                                    MoveCallArg::Json(SuiJsonValue::new(convert_number_to_string(
+                                        SuiJsonValue::from_str(value)?.to_json_value(),
+                                    ))?)
+                                }
+                                arg => MoveCallArg::Argument(to_argument(arg)),
+                            })
+                        })
+                        .collect::<Result<_, anyhow::Error>>()?;
+                    let type_args = type_args.iter().cloned().map(Into::into).collect();
+                    builder
+                        .programmable_move_call(
+                            &mut ptb, *package, module, function, type_args, args,
+                        )
+                        .await?;
+                }
+                PtbCommand::SplitCoins { coin, amounts } => {
+                    // The coin is split by mutable reference.
+                    let coin = object_arg(builder, &mut ptb, coin, true).await?;
+                    let amounts = amounts
+                        .iter()
+                        .map(|amount| match amount {
+                            PtbArg::Value(value) => ptb.pure(value.parse::<u64>()?),
+                            arg => Ok(to_argument(arg)),
+                        })
+                        .collect::<Result<_, anyhow::Error>>()?;
+                    ptb.command(Command::SplitCoins(coin, amounts));
+                }
+                PtbCommand::TransferObjects { objects, recipient } => {
+                    let mut args = vec![];
+                    for object in objects {
+                        // Objects are transferred by value.
+                        args.push(object_arg(builder, &mut ptb, object, true).await?);
+                    }
+                    let recipient = match recipient {
+                        PtbArg::Value(value) => ptb.pure(get_identity_address_from_keystore(
+                            KeyIdentity::from_str(value)?,
+                            keystore,
+                        )?)?,
+                        arg => to_argument(arg),
+                    };
+                    ptb.command(Command::TransferObjects(args, recipient));
+                }
+            }
+        }
+        Ok(ptb.finish())
+    }
+}
+
+fn to_argument(arg: &PtbArg) -> Argument {
+    match arg {
+        PtbArg::Gas => Argument::GasCoin,
+        PtbArg::Result(i) => Argument::Result(*i),
+        PtbArg::NestedResult(i, j) => Argument::NestedResult(*i, *j),
+        PtbArg::Value(_) => unreachable!("values are converted by the commands using them"),
+    }
+}
+
+async fn object_arg(
+    builder: &TransactionBuilder,
+    ptb: &mut ProgrammableTransactionBuilder,
+    arg: &PtbArg,
+    mutable: bool,
+) -> Result<Argument, anyhow::Error> {
+    match arg {
+        PtbArg::Value(value) => {
+            let id = ObjectID::from_str(value)
+                .map_err(|e| anyhow!("Expected an object ID, found {value}: {e}"))?;
+            builder.object_input(ptb, id, mutable).await
+        }
+        arg => Ok(to_argument(arg)),
+    }
+}
+
+/// Substitute variables and the gas coin, any other operand is a value.
+fn resolve_arg(arg: &str, variables: &BTreeMap<String, PtbArg>) -> Result<PtbArg, anyhow::Error> {
+    if arg == GAS_COIN {
+        return Ok(PtbArg::Gas);
+    }
+    let (name, index) = match arg.split_once('.') {
+        Some((name, index)) if index.chars().all(|c| c.is_ascii_digit()) => {
+            (name, Some(index.parse::<u16>()?))
+        }
+        _ => (arg, None),
+    };
+    match (variables.get(name), index) {
+        (Some(value), None) => Ok(value.clone()),
+        (Some(PtbArg::Result(i)), Some(j)) => Ok(PtbArg::NestedResult(*i, j)),
+        (Some(_), Some(_)) => bail!("Variable {name} is not the result of a command"),
+        (None, _) => Ok(PtbArg::Value(arg.to_string())),
+    }
+}
+
+/// Parse a `[a, b, ...]` list of operands.
+fn resolve_list(
+    list: &str,
+    variables: &BTreeMap<String, PtbArg>,
+) -> Result<Vec<PtbArg>, anyhow::Error> {
+    let inner = list
+        .trim()
+        .strip_prefix('[')
+        .and_then(|l| l.strip_suffix(']'))
+        .ok_or_else(|| anyhow!("Expected a list in brackets, found {list}"))?;
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| resolve_arg(arg, variables))
+        .collect()
+}
+
+fn parse_target(target: &str) -> Result<(ObjectID, String, String), anyhow::Error> {
+    let parts: Vec<_> = target.split("::").collect();
+    let [package, module, function] = parts[..] else {
+        bail!("Expected a PACKAGE::MODULE::FUNCTION target, found {target}");
+    };
+    Ok((
+        ObjectID::from_str(package)?,
+        module.to_string(),
+        function.to_string(),
+    ))
+}
+
+/// Parse `<T1, T2, ...>`, splitting on the commas that are not nested in a type argument.
+fn parse_type_args(type_args: &str) -> Result<Vec<TypeTag>, anyhow::Error> {
+    let inner = type_args
+        .strip_prefix('<')
+        .and_then(|t| t.strip_suffix('>'))
+        .ok_or_else(|| anyhow!("Expected type arguments in angle brackets, found {type_args}"))?;
+    let mut tags = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                tags.push(parse_sui_type_tag(inner[start..i].trim())?);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !inner[start..].trim().is_empty() {
+        tags.push(parse_sui_type_tag(inner[start..].trim())?);
+    }
+    Ok(tags)
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
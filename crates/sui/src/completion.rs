@@ -0,0 +1,174 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shell completion scripts for the `sui` binary.
+//!
+//! The scripts generated by clap only complete commands and flags. For the flags taking an
+//! address or an object ID, the scripts call back `sui completion --values <kind>`, which lists
+//! the addresses, key aliases and address book entries of the local keystore, and the object
+//! IDs cached from the last `sui client objects` or `sui client gas` outputs.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use clap::{Command, ValueEnum};
+use clap_complete::Shell;
+use sui_config::PersistedConfig;
+use sui_keys::keystore::{AccountKeystore, Keystore};
+use sui_sdk::sui_client_config::SuiClientConfig;
+
+use crate::client_commands::SuiClientCommandResult;
+
+/// File next to the client config caching the object IDs listed by the client.
+const OBJECT_CACHE_FILE: &str = "completion_objects.json";
+
+/// Flags completed with addresses.
+pub const ADDRESS_FLAGS: &[&str] = &[
+    "--address",
+    "--to",
+    "--recipient",
+    "--recipients",
+    "--sender",
+];
+/// Flags completed with object IDs.
+pub const OBJECT_FLAGS: &[&str] = &[
+    "--gas",
+    "--object-id",
+    "--coin-id",
+    "--coin-object-id",
+    "--sui-coin-object-id",
+    "--input-coins",
+    "--primary-coin",
+    "--coin-to-merge",
+    "--object",
+    "--id",
+];
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum CompletionValues {
+    Addresses,
+    Objects,
+}
+
+/// Write the completion script of `cmd` for `shell`.
+pub fn print_completions(shell: Shell, cmd: &mut Command, out: &mut dyn Write) {
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, cmd, &name, out);
+    let addresses = ADDRESS_FLAGS.join(" ");
+    let objects = OBJECT_FLAGS.join(" ");
+    let script = match shell {
+        Shell::Bash => format!(
+            r#"
+_{name}_dynamic() {{
+    local prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+    local kind=""
+    case " {addresses} " in *" ${{prev}} "*) kind="addresses" ;; esac
+    case " {objects} " in *" ${{prev}} "*) kind="objects" ;; esac
+    if [[ -n "${{kind}}" ]]; then
+        COMPREPLY=($(compgen -W "$({name} completion --values ${{kind}} 2>/dev/null)" -- "${{COMP_WORDS[COMP_CWORD]}}"))
+        return 0
+    fi
+    _{name} "$@"
+}}
+complete -F _{name}_dynamic -o bashdefault -o default {name}
+"#
+        ),
+        Shell::Zsh => format!(
+            r#"
+_{name}_dynamic() {{
+    local -a address_flags object_flags
+    address_flags=({addresses})
+    object_flags=({objects})
+    local prev="${{words[CURRENT-1]}}"
+    if (( ${{address_flags[(Ie)$prev]}} )); then
+        compadd -- ${{(f)"$({name} completion --values addresses 2>/dev/null)"}}
+    elif (( ${{object_flags[(Ie)$prev]}} )); then
+        compadd -- ${{(f)"$({name} completion --values objects 2>/dev/null)"}}
+    else
+        _{name} "$@"
+    fi
+}}
+compdef _{name}_dynamic {name}
+"#
+        ),
+        Shell::Fish => {
+            let flags = |flags: &[&str]| {
+                flags
+                    .iter()
+                    .map(|flag| format!("-l {}", flag.trim_start_matches("--")))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!(
+                "\ncomplete -c {name} {} -x -a '({name} completion --values addresses 2>/dev/null)'\
+                 \ncomplete -c {name} {} -x -a '({name} completion --values objects 2>/dev/null)'\n",
+                flags(ADDRESS_FLAGS),
+                flags(OBJECT_FLAGS),
+            )
+        }
+        _ => String::new(),
+    };
+    let _ = out.write_all(script.as_bytes());
+}
+
+/// The values completed for `kind`, read from the client config at `config_path`. Errors are
+/// ignored, so that a missing or broken config does not break completion.
+pub fn completion_values(kind: CompletionValues, config_path: &Path) -> Vec<String> {
+    match kind {
+        CompletionValues::Addresses => PersistedConfig::<SuiClientConfig>::read(config_path)
+            .map(|config| address_values(&config.keystore))
+            .unwrap_or_default(),
+        CompletionValues::Objects => object_values(config_path),
+    }
+}
+
+/// The addresses of the keystore, followed by the aliases of its keys and its address book
+/// entries.
+pub fn address_values(keystore: &Keystore) -> Vec<String> {
+    let mut values = keystore
+        .addresses()
+        .iter()
+        .map(|address| address.to_string())
+        .collect::<Vec<_>>();
+    values.extend(
+        keystore
+            .alias_names()
+            .iter()
+            .map(|alias| format!("@{alias}")),
+    );
+    values.extend(
+        keystore
+            .address_book()
+            .entries()
+            .keys()
+            .map(|name| format!("@{name}")),
+    );
+    values
+}
+
+/// The object IDs cached by [cache_objects] for the client config at `config_path`.
+pub fn object_values(config_path: &Path) -> Vec<String> {
+    fs::read_to_string(config_path.with_file_name(OBJECT_CACHE_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Remember the object IDs listed by `result`, for the completion of the next commands.
+pub fn cache_objects(config_path: &Path, result: &SuiClientCommandResult) {
+    let objects = match result {
+        SuiClientCommandResult::Objects(objects) => objects
+            .iter()
+            .filter_map(|object| object.object_id().ok())
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>(),
+        SuiClientCommandResult::Gas(coins) => {
+            coins.iter().map(|coin| coin.id().to_string()).collect()
+        }
+        _ => return,
+    };
+    if let Ok(content) = serde_json::to_string(&objects) {
+        let _ = fs::write(config_path.with_file_name(OBJECT_CACHE_FILE), content);
+    }
+}
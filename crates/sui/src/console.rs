@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
 use std::io::{stderr, Write};
 use std::ops::Deref;
 
@@ -14,6 +15,9 @@ use sui_sdk::wallet_context::WalletContext;
 
 use crate::client_commands::SwitchResponse;
 use crate::client_commands::{SuiClientCommandResult, SuiClientCommands};
+use crate::completion::{
+    address_values, cache_objects, object_values, ADDRESS_FLAGS, OBJECT_FLAGS,
+};
 use crate::shell::{
     install_shell_plugins, AsyncHandler, CacheKey, CommandStructure, CompletionCache, Shell,
 };
@@ -24,6 +28,9 @@ const SUI: &str = "   _____       _    ______                       __
  ___/ / /_/ / /  / /___/ /_/ / / / (__  ) /_/ / /  __/
 /____/\\__,_/_/   \\____/\\____/_/ /_/____/\\____/_/\\___/";
 
+/// File next to the client config storing the history of the console.
+const HISTORY_FILE: &str = "console_history";
+
 #[derive(Parser)]
 #[clap(name = "", rename_all = "kebab-case", no_binary_name = true)]
 pub struct ConsoleOpts {
@@ -78,12 +85,25 @@ pub async fn start_console(
     writeln!(out, "Welcome to the Sui interactive console.")?;
     writeln!(out)?;
 
+    let config_path = context.config.path().to_path_buf();
+    let mut completions = BTreeMap::new();
+    let addresses = address_values(&context.config.keystore);
+    for flag in ADDRESS_FLAGS {
+        completions.insert(CacheKey::flag(flag), addresses.clone());
+    }
+    let objects = object_values(&config_path);
+    for flag in OBJECT_FLAGS {
+        completions.insert(CacheKey::flag(flag), objects.clone());
+    }
+
     let mut shell = Shell::new(
         "sui>-$ ",
         context,
         ClientCommandHandler,
         CommandStructure::from_clap(&install_shell_plugins(app)),
-    );
+    )
+    .with_history_file(config_path.with_file_name(HISTORY_FILE))
+    .with_completions(completions);
 
     shell.run_async(out, err).await
 }
@@ -122,6 +142,7 @@ async fn handle_command(
 ) -> Result<bool, anyhow::Error> {
     let wallet_opts = wallet_opts?;
     let result = wallet_opts.command.execute(context).await?;
+    cache_objects(context.config.path(), &result);
 
     // Update completion cache
     // TODO: Completion data are keyed by strings, are there ways to make it more error proof?
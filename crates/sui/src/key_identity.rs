@@ -9,9 +9,10 @@ use sui_keys::keystore::{AccountKeystore, Keystore};
 use sui_sdk::wallet_context::WalletContext;
 use sui_types::base_types::SuiAddress;
 
-/// An address or an alias associated with a key in the wallet
+/// An address or an alias associated with a key in the wallet or with an address book entry.
 /// This is used to distinguish between an address or an alias,
 /// enabling a user to use an alias for any command that requires an address.
+/// Aliases can be written with a leading `@`, e.g. `@treasury`.
 #[derive(Serialize, Clone)]
 pub enum KeyIdentity {
     Address(SuiAddress),
@@ -24,7 +25,9 @@ impl FromStr for KeyIdentity {
         if s.starts_with("0x") {
             Ok(KeyIdentity::Address(SuiAddress::from_str(s)?))
         } else {
-            Ok(KeyIdentity::Alias(s.to_string()))
+            Ok(KeyIdentity::Alias(
+                s.strip_prefix('@').unwrap_or(s).to_string(),
+            ))
         }
     }
 }
@@ -58,6 +61,9 @@ pub fn get_identity_address_from_keystore(
 ) -> Result<SuiAddress, Error> {
     match input {
         KeyIdentity::Address(x) => Ok(x),
-        KeyIdentity::Alias(x) => Ok(*keystore.get_address_by_alias(x)?),
+        KeyIdentity::Alias(x) => match keystore.address_book().get(&x) {
+            Some(address) if !keystore.alias_exists(&x) => Ok(*address),
+            _ => Ok(*keystore.get_address_by_alias(x)?),
+        },
     }
 }
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::key_identity::{get_identity_address_from_keystore, KeyIdentity};
 use crate::zklogin_commands_util::{perform_zk_login_test_tx, read_cli_line};
-use anyhow::anyhow;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, ensure};
 use bip32::DerivationPath;
 use clap::*;
 use fastcrypto::ed25519::Ed25519KeyPair;
@@ -30,7 +32,8 @@ use shared_crypto::intent::{Intent, IntentMessage, IntentScope, PersonalMessage}
 use std::fmt::{Debug, Display, Formatter};
 use std::fs;
 use std::path::{Path, PathBuf};
-use sui_keys::key_derive::generate_new_key;
+use sui_keys::key_derive::{generate_new_key, validate_path};
+use sui_keys::key_encoding::{decode_private_key, encode_private_key, SUI_PRIV_KEY_PREFIX};
 use sui_keys::keypair_file::{
     read_authority_keypair_from_file, read_keypair_from_file, write_authority_keypair_to_file,
     write_keypair_to_file,
@@ -57,10 +60,22 @@ use tracing::info;
 #[path = "unit_tests/keytool_tests.rs"]
 mod keytool_tests;
 
+/// Prefix of a private key encrypted by `sui keytool export --encrypt`.
+const ENCRYPTED_KEY_PREFIX: &str = "suienc:";
+const ENCRYPTION_SALT_LENGTH: usize = 16;
+const ENCRYPTION_NONCE_LENGTH: usize = 12;
+/// Environment variable holding the password of an encrypted private key.
+const PASSWORD_ENV_VAR: &str = "SUI_KEYTOOL_PASSWORD";
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 #[clap(rename_all = "kebab-case")]
 pub enum KeyToolCommand {
+    /// Manage the address book, which names addresses the wallet does not hold keys for.
+    /// Address book entries can be used wherever an address or a key alias is accepted, e.g.
+    /// `sui client transfer --to @treasury`.
+    #[clap(subcommand)]
+    AddressBook(AddressBookCommand),
     /// Update an old alias to a new one.
     /// If a new alias is not provided, a random one will be generated.
     #[clap(name = "update-alias")]
@@ -69,9 +84,16 @@ pub enum KeyToolCommand {
         /// The alias must start with a letter and can contain only letters, digits, dots, hyphens (-), or underscores (_).
         new_alias: Option<String>,
     },
-    /// Convert private key from wallet format (hex of 32 byte private key) to sui.keystore format
-    /// (base64 of 33 byte flag || private key) or vice versa.
-    Convert { value: String },
+    /// Convert a private key between its supported encodings: Bech32 starting with `suiprivkey`,
+    /// sui.keystore format (Base64 of 33 byte `flag || privkey`) and wallet format (Hex of the
+    /// 32 byte ed25519 private key). By default, Base64 is converted to Hex, and Hex or Bech32
+    /// to Base64.
+    Convert {
+        value: String,
+        /// The encoding to convert to.
+        #[clap(long, value_enum)]
+        to: Option<KeyEncoding>,
+    },
     /// Given a Base64 encoded transaction bytes, decode its components.
     DecodeTxBytes {
         #[clap(long)]
@@ -85,6 +107,16 @@ pub enum KeyToolCommand {
         #[clap(long)]
         tx_bytes: Option<String>,
     },
+    /// Export the private key of an address (or its alias) in sui.keystore, Bech32 encoded
+    /// with the `suiprivkey` prefix. With `--encrypt`, the private key is instead encrypted
+    /// with a password (read from the `SUI_KEYTOOL_PASSWORD` environment variable if set,
+    /// or prompted for otherwise). Both outputs can be imported back with `sui keytool import`.
+    Export {
+        #[clap(long)]
+        key_identity: KeyIdentity,
+        #[clap(long)]
+        encrypt: bool,
+    },
     /// Generate a new keypair with key scheme flag {ed25519 | secp256k1 | secp256r1}
     /// with optional derivation path, default to m/44'/784'/0'/0'/0' for ed25519 or
     /// m/54'/784'/0'/0/0 for secp256k1 or m/74'/784'/0'/0/0 for secp256r1. Word
@@ -100,10 +132,11 @@ pub enum KeyToolCommand {
         derivation_path: Option<DerivationPath>,
     },
 
-    /// Add a new key to sui.keystore using either the input mnemonic phrase or a private key (from the Wallet),
-    /// the key scheme flag {ed25519 | secp256k1 | secp256r1} and an optional derivation path,
-    /// default to m/44'/784'/0'/0'/0' for ed25519 or m/54'/784'/0'/0/0 for secp256k1
-    /// or m/74'/784'/0'/0/0 for secp256r1. Supports mnemonic phrase of word length 12, 15, 18`, 21, 24.
+    /// Add a new key to sui.keystore using either the input mnemonic phrase, a Hex encoded private key
+    /// (from the Wallet), a Bech32 encoded private key starting with `suiprivkey` or a private key
+    /// encrypted by `sui keytool export --encrypt`, the key scheme flag {ed25519 | secp256k1 | secp256r1}
+    /// and an optional derivation path, default to m/44'/784'/0'/0'/0' for ed25519 or m/54'/784'/0'/0/0
+    /// for secp256k1 or m/74'/784'/0'/0/0 for secp256r1. Supports mnemonic phrase of word length 12, 15, 18`, 21, 24.
     /// Set an alias for the key with the --alias flag. If no alias is provided,
     /// the tool will automatically generate one.
     Import {
@@ -114,8 +147,8 @@ pub enum KeyToolCommand {
         key_scheme: SignatureScheme,
         derivation_path: Option<DerivationPath>,
     },
-    /// List all keys by its Sui address, Base64 encoded public key, key scheme name in
-    /// sui.keystore.
+    /// List all keys by its Sui address, Base64 encoded public key, key scheme name and the
+    /// derivation path of keys derived from a mnemonic in sui.keystore.
     List {
         /// Sort by alias
         #[clap(long, short = 's')]
@@ -142,9 +175,8 @@ pub enum KeyToolCommand {
     /// result can be used as signature field for `sui client execute-signed-tx`. The sum
     /// of weights of all signatures must be >= the threshold.
     ///
-    /// The order of `sigs` must be the same as the order of `pks`.
-    /// e.g. for [pk1, pk2, pk3, pk4, pk5], [sig1, sig2, sig5] is valid, but
-    /// [sig2, sig1, sig5] is invalid.
+    /// The signatures can be passed in any order, they are reordered to follow the order
+    /// of `pks`.
     MultiSigCombinePartialSig {
         #[clap(long, num_args(1..))]
         sigs: Vec<GenericSignature>,
@@ -269,7 +301,31 @@ pub enum KeyToolCommand {
     },
 }
 
+#[derive(Subcommand)]
+#[clap(rename_all = "kebab-case")]
+pub enum AddressBookCommand {
+    /// Add a named address to the address book.
+    Add {
+        /// The name must start with a letter and can contain only letters, digits, dots, hyphens (-), or underscores (_).
+        name: String,
+        address: SuiAddress,
+    },
+    /// List the address book entries.
+    List,
+    /// Rename an address book entry.
+    Rename { old_name: String, new_name: String },
+    /// Remove an entry from the address book.
+    Remove { name: String },
+}
+
 // Command Output types
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookEntry {
+    name: String,
+    address: SuiAddress,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AliasUpdate {
@@ -305,6 +361,8 @@ pub struct Key {
     flag: u8,
     #[serde(skip_serializing_if = "Option::is_none")]
     mnemonic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    derivation_path: Option<String>,
     peer_id: Option<String>,
 }
 
@@ -353,6 +411,26 @@ pub struct MultiSigOutput {
 pub enum ConvertOutput {
     Base64(String),
     Hex(String),
+    Bech32(String),
+}
+
+/// An encoding of a private key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum KeyEncoding {
+    /// Base64 of 33 byte `flag || privkey`, as in sui.keystore.
+    Base64,
+    /// Hex of the 32 byte `privkey`, without the flag.
+    Hex,
+    /// Bech32 of `flag || privkey`, starting with `suiprivkey`.
+    Bech32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedKey {
+    exported_private_key: String,
+    encrypted: bool,
+    key: Key,
 }
 
 #[derive(Serialize)]
@@ -410,11 +488,13 @@ pub struct ZkLoginInsecureSignPersonalMessage {
 #[derive(Serialize)]
 #[serde(untagged)]
 pub enum CommandOutput {
+    AddressBook(Vec<AddressBookEntry>),
     Alias(AliasUpdate),
     Convert(ConvertOutput),
     DecodeMultiSig(DecodedMultiSigOutput),
     DecodeTxBytes(TransactionData),
     Error(String),
+    Export(ExportedKey),
     Generate(Key),
     Import(Key),
     List(Vec<Key>),
@@ -434,6 +514,40 @@ pub enum CommandOutput {
 impl KeyToolCommand {
     pub async fn execute(self, keystore: &mut Keystore) -> Result<CommandOutput, anyhow::Error> {
         let cmd_result = Ok(match self {
+            KeyToolCommand::AddressBook(command) => {
+                match command {
+                    AddressBookCommand::Add { name, address } => {
+                        ensure!(
+                            !keystore.alias_exists(&name),
+                            "{name} is already the alias of a key"
+                        );
+                        keystore.address_book_mut().add(&name, address)?;
+                    }
+                    AddressBookCommand::List => {}
+                    AddressBookCommand::Rename { old_name, new_name } => {
+                        ensure!(
+                            !keystore.alias_exists(&new_name),
+                            "{new_name} is already the alias of a key"
+                        );
+                        keystore.address_book_mut().rename(&old_name, &new_name)?;
+                    }
+                    AddressBookCommand::Remove { name } => {
+                        keystore.address_book_mut().remove(&name)?;
+                    }
+                }
+                let address_book = keystore.address_book();
+                address_book.save()?;
+                CommandOutput::AddressBook(
+                    address_book
+                        .entries()
+                        .iter()
+                        .map(|(name, address)| AddressBookEntry {
+                            name: name.clone(),
+                            address: *address,
+                        })
+                        .collect(),
+                )
+            }
             KeyToolCommand::Alias {
                 old_alias,
                 new_alias,
@@ -444,8 +558,8 @@ impl KeyToolCommand {
                     new_alias,
                 })
             }
-            KeyToolCommand::Convert { value } => {
-                let result = convert_private_key_to_base64(value)?;
+            KeyToolCommand::Convert { value, to } => {
+                let result = convert_private_key(value, to)?;
                 CommandOutput::Convert(result)
             }
 
@@ -509,6 +623,26 @@ impl KeyToolCommand {
                 CommandOutput::DecodeTxBytes(tx_data)
             }
 
+            KeyToolCommand::Export {
+                key_identity,
+                encrypt,
+            } => {
+                let address = get_identity_address_from_keystore(key_identity, keystore)?;
+                let skp = keystore.get_key(&address)?;
+                let exported_private_key = if encrypt {
+                    encrypt_private_key(skp, &read_password(PASSWORD_ENV_VAR, true)?)?
+                } else {
+                    encode_private_key(skp)?
+                };
+                let mut key = Key::from(skp);
+                key.alias = keystore.get_alias_by_address(&address).ok();
+                CommandOutput::Export(ExportedKey {
+                    exported_private_key,
+                    encrypted: encrypt,
+                    key,
+                })
+            }
+
             KeyToolCommand::Generate {
                 key_scheme,
                 derivation_path,
@@ -525,16 +659,19 @@ impl KeyToolCommand {
                         key_scheme: key_scheme.to_string(),
                         flag: SignatureScheme::BLS12381.flag(),
                         mnemonic: None,
+                        derivation_path: None,
                         peer_id: None,
                     })
                 }
                 _ => {
+                    let path = validate_path(&key_scheme, derivation_path)?;
                     let (sui_address, skp, _scheme, phrase) =
-                        generate_new_key(key_scheme, derivation_path, word_length)?;
+                        generate_new_key(key_scheme, Some(path.clone()), word_length)?;
                     let file = format!("{sui_address}.key");
                     write_keypair_to_file(&skp, file)?;
                     let mut key = Key::from(&skp);
                     key.mnemonic = Some(phrase);
+                    key.derivation_path = Some(path.to_string());
                     CommandOutput::Generate(key)
                 }
             },
@@ -568,14 +705,33 @@ impl KeyToolCommand {
                     let key = Key::from(&skp);
                     keystore.add_key(alias, skp)?;
                     CommandOutput::Import(key)
+                } else if input_string.starts_with(SUI_PRIV_KEY_PREFIX) {
+                    let skp = decode_private_key(&input_string).map_err(|_| {
+                        anyhow!("Private key is malformed. Importing private key failed.")
+                    })?;
+                    let key = Key::from(&skp);
+                    keystore.add_key(alias, skp)?;
+                    CommandOutput::Import(key)
+                } else if input_string.starts_with(ENCRYPTED_KEY_PREFIX) {
+                    let skp = decrypt_private_key(
+                        &input_string,
+                        &read_password(PASSWORD_ENV_VAR, false)?,
+                    )?;
+                    let key = Key::from(&skp);
+                    keystore.add_key(alias, skp)?;
+                    CommandOutput::Import(key)
                 } else {
+                    let path = validate_path(&key_scheme, derivation_path)?;
                     let sui_address = keystore.import_from_mnemonic(
                         &input_string,
                         key_scheme,
-                        derivation_path,
+                        Some(path.clone()),
+                        alias,
                     )?;
                     let skp = keystore.get_key(&sui_address)?;
-                    let key = Key::from(skp);
+                    let mut key = Key::from(skp);
+                    key.alias = keystore.get_alias_by_address(&sui_address).ok();
+                    key.derivation_path = Some(path.to_string());
                     CommandOutput::Import(key)
                 }
             }
@@ -587,6 +743,7 @@ impl KeyToolCommand {
                     .map(|pk| {
                         let mut key = Key::from(pk);
                         key.alias = keystore.get_alias_by_address(&key.sui_address).ok();
+                        key.derivation_path = keystore.get_derivation_path(&key.sui_address);
                         key
                     })
                     .collect::<Vec<Key>>();
@@ -715,6 +872,7 @@ impl KeyToolCommand {
                                 flag: SignatureScheme::BLS12381.flag(),
                                 peer_id: None,
                                 mnemonic: None,
+                                derivation_path: None,
                             })
                         }
                         Err(e) => CommandOutput::Error(format!(
@@ -1094,6 +1252,7 @@ impl From<PublicKey> for Key {
             public_base64_key: key.encode_base64(),
             key_scheme: key.scheme().to_string(),
             mnemonic: None,
+            derivation_path: None,
             flag: key.flag(),
             peer_id: anemo_styling(&key),
         }
@@ -1180,29 +1339,141 @@ impl Debug for CommandOutput {
     }
 }
 
-fn convert_private_key_to_base64(value: String) -> Result<ConvertOutput, anyhow::Error> {
-    match Base64::decode(&value) {
-        Ok(decoded) => {
-            if decoded.len() != 33 {
-                return Err(anyhow!(format!("Private key is malformed and cannot base64 decode it. Fed 33 length but got {}", decoded.len())));
-            }
-            info!("Hex encode");
-            Ok(ConvertOutput::Hex(Hex::encode(&decoded[1..])))
-        }
-        Err(_) => match Hex::decode(&value) {
+/// Parse a private key encoded in Bech32 (`suiprivkey`), Base64 (`flag || privkey`) or Hex (ed25519
+/// `privkey`) and encode it as `to`. By default, Base64 is converted to Hex, and Hex or Bech32 to
+/// Base64.
+fn convert_private_key(
+    value: String,
+    to: Option<KeyEncoding>,
+) -> Result<ConvertOutput, anyhow::Error> {
+    let (skp, from) = if value.starts_with(SUI_PRIV_KEY_PREFIX) {
+        (decode_private_key(&value)?, KeyEncoding::Bech32)
+    } else {
+        match Base64::decode(&value) {
             Ok(decoded) => {
-                if decoded.len() != 32 {
-                    return Err(anyhow!(format!("Private key is malformed and cannot hex decode it. Expected 32 length but got {}", decoded.len())));
+                if decoded.len() != 33 {
+                    return Err(anyhow!(format!("Private key is malformed and cannot base64 decode it. Fed 33 length but got {}", decoded.len())));
                 }
-                let mut res = Vec::new();
-                res.extend_from_slice(&[SignatureScheme::ED25519.flag()]);
-                res.extend_from_slice(&decoded);
-                info!("Base64 encode");
-                Ok(ConvertOutput::Base64(Base64::encode(&res)))
+                let skp = SuiKeyPair::from_bytes(&decoded).map_err(|e| anyhow!("{e}"))?;
+                (skp, KeyEncoding::Base64)
             }
-            Err(_) => Err(anyhow!("Invalid private key format".to_string())),
-        },
+            Err(_) => match Hex::decode(&value) {
+                Ok(decoded) => {
+                    if decoded.len() != 32 {
+                        return Err(anyhow!(format!("Private key is malformed and cannot hex decode it. Expected 32 length but got {}", decoded.len())));
+                    }
+                    let skp = SuiKeyPair::Ed25519(Ed25519KeyPair::from_bytes(&decoded)?);
+                    (skp, KeyEncoding::Hex)
+                }
+                Err(_) => return Err(anyhow!("Invalid private key format".to_string())),
+            },
+        }
+    };
+    let to = to.unwrap_or(match from {
+        KeyEncoding::Base64 => KeyEncoding::Hex,
+        KeyEncoding::Hex | KeyEncoding::Bech32 => KeyEncoding::Base64,
+    });
+    let bytes = skp.to_bytes();
+    Ok(match to {
+        KeyEncoding::Base64 => {
+            info!("Base64 encode");
+            ConvertOutput::Base64(Base64::encode(&bytes))
+        }
+        KeyEncoding::Hex => {
+            info!("Hex encode");
+            ConvertOutput::Hex(Hex::encode(&bytes[1..]))
+        }
+        KeyEncoding::Bech32 => {
+            info!("Bech32 encode");
+            ConvertOutput::Bech32(encode_private_key(&skp)?)
+        }
+    })
+}
+
+/// Read a password used for encryption, from the environment variable `env_var` if set or from
+/// the terminal otherwise.
+pub(crate) fn read_password(env_var: &str, confirm: bool) -> Result<String, anyhow::Error> {
+    if let Ok(password) = std::env::var(env_var) {
+        return Ok(password);
+    }
+    let prompt = inquire::Password::new("Password:");
+    let prompt = if confirm {
+        prompt
+    } else {
+        prompt.without_confirmation()
+    };
+    let password = prompt.prompt()?;
+    if password.is_empty() {
+        return Err(anyhow!("Password cannot be empty"));
+    }
+    Ok(password)
+}
+
+fn derive_encryption_key(password: &str, salt: &[u8]) -> Result<[u8; 32], anyhow::Error> {
+    let mut key = [0u8; 32];
+    scrypt::scrypt(
+        password.as_bytes(),
+        salt,
+        &scrypt::Params::recommended(),
+        &mut key,
+    )
+    .map_err(|e| anyhow!("Cannot derive encryption key: {e}"))?;
+    Ok(key)
+}
+
+/// Encrypt `plaintext` with AES-256-GCM, using a key derived from the password with scrypt. The
+/// result is `salt || nonce || ciphertext`.
+pub(crate) fn encrypt_with_password(
+    plaintext: &[u8],
+    password: &str,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let mut rng = rand::thread_rng();
+    let salt: [u8; ENCRYPTION_SALT_LENGTH] = rng.gen();
+    let nonce: [u8; ENCRYPTION_NONCE_LENGTH] = rng.gen();
+    let key = derive_encryption_key(password, &salt)?;
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| anyhow!("{e}"))?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| anyhow!("Cannot encrypt"))?;
+    let mut bytes = Vec::with_capacity(salt.len() + nonce.len() + ciphertext.len());
+    bytes.extend_from_slice(&salt);
+    bytes.extend_from_slice(&nonce);
+    bytes.extend_from_slice(&ciphertext);
+    Ok(bytes)
+}
+
+/// Decrypt bytes encrypted by [encrypt_with_password].
+pub(crate) fn decrypt_with_password(
+    bytes: &[u8],
+    password: &str,
+) -> Result<Vec<u8>, anyhow::Error> {
+    if bytes.len() <= ENCRYPTION_SALT_LENGTH + ENCRYPTION_NONCE_LENGTH {
+        return Err(anyhow!("Encrypted bytes are malformed"));
     }
+    let (salt, rest) = bytes.split_at(ENCRYPTION_SALT_LENGTH);
+    let (nonce, ciphertext) = rest.split_at(ENCRYPTION_NONCE_LENGTH);
+    let key = derive_encryption_key(password, salt)?;
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| anyhow!("{e}"))?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Cannot decrypt, the password may be wrong"))
+}
+
+/// Encrypt a keypair with [encrypt_with_password]. The result is [ENCRYPTED_KEY_PREFIX] followed
+/// by the Base64 encoding of the encrypted bytes.
+fn encrypt_private_key(skp: &SuiKeyPair, password: &str) -> Result<String, anyhow::Error> {
+    let bytes = encrypt_with_password(&skp.to_bytes(), password)?;
+    Ok(format!("{ENCRYPTED_KEY_PREFIX}{}", Base64::encode(bytes)))
+}
+
+/// Decrypt a keypair encrypted by [encrypt_private_key].
+fn decrypt_private_key(value: &str, password: &str) -> Result<SuiKeyPair, anyhow::Error> {
+    let bytes = value
+        .strip_prefix(ENCRYPTED_KEY_PREFIX)
+        .and_then(|v| Base64::decode(v).ok())
+        .ok_or_else(|| anyhow!("Encrypted private key is malformed"))?;
+    let plaintext = decrypt_with_password(&bytes, password)?;
+    SuiKeyPair::from_bytes(&plaintext).map_err(|e| anyhow!("{e}"))
 }
 
 fn anemo_styling(pk: &PublicKey) -> Option<String> {
@@ -3,6 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod client_commands;
+pub mod client_ptb;
+pub mod completion;
 pub mod console;
 pub mod fire_drill;
 pub mod genesis_ceremony;
@@ -12,4 +14,5 @@ pub mod keytool;
 pub mod shell;
 pub mod sui_commands;
 pub mod validator_commands;
+pub mod vault;
 pub mod zklogin_commands_util;
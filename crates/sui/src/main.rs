@@ -4,7 +4,7 @@
 use clap::*;
 use colored::Colorize;
 use sui::client_commands::SuiClientCommands::ReplayTransaction;
-use sui::sui_commands::SuiCommand;
+use sui::sui_commands::{JsonErrorOutput, SuiCommand};
 use sui_types::exit_main;
 use tracing::debug;
 
@@ -80,5 +80,16 @@ async fn main() {
 
     debug!("Sui CLI version: {VERSION}");
 
+    if args.command.json_output() {
+        if let Err(err) = args.command.execute().await {
+            let err = JsonErrorOutput::from(&err);
+            match serde_json::to_string_pretty(&err) {
+                Ok(json) => println!("{json}"),
+                Err(_) => println!("{}", err.error),
+            }
+            std::process::exit(1);
+        }
+        return;
+    }
     exit_main!(args.command.execute().await);
 }
@@ -9,6 +9,7 @@ use std::collections::BTreeMap;
 use std::env;
 use std::fmt::Display;
 use std::io::Write;
+use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
@@ -33,6 +34,8 @@ pub struct Shell<P: Display, S, H> {
     state: S,
     handler: H,
     command: CommandStructure,
+    history_file: Option<PathBuf>,
+    completions: BTreeMap<CacheKey, Vec<String>>,
 }
 
 impl<P: Display, S: Send, H: AsyncHandler<S>> Shell<P, S, H> {
@@ -51,9 +54,23 @@ impl<P: Display, S: Send, H: AsyncHandler<S>> Shell<P, S, H> {
             state,
             handler,
             command,
+            history_file: None,
+            completions: BTreeMap::new(),
         }
     }
 
+    /// Load the history from `path` when the shell starts, and save it there when it exits.
+    pub fn with_history_file(mut self, path: PathBuf) -> Self {
+        self.history_file = Some(path);
+        self
+    }
+
+    /// Values completed before any command updated the completion cache.
+    pub fn with_completions(mut self, completions: BTreeMap<CacheKey, Vec<String>>) -> Self {
+        self.completions = completions;
+        self
+    }
+
     pub async fn run_async(
         &mut self,
         out: &mut (dyn Write + Send),
@@ -66,8 +83,12 @@ impl<P: Display, S: Send, H: AsyncHandler<S>> Shell<P, S, H> {
             .build();
 
         let mut rl = Editor::with_config(config);
+        if let Some(history_file) = &self.history_file {
+            // The history file does not exist on the first run.
+            let _ = rl.load_history(history_file);
+        }
 
-        let completion_cache = Arc::new(RwLock::new(BTreeMap::new()));
+        let completion_cache = Arc::new(RwLock::new(std::mem::take(&mut self.completions)));
 
         rl.set_helper(Some(ShellHelper {
             command: self.command.clone(),
@@ -135,6 +156,11 @@ impl<P: Display, S: Send, H: AsyncHandler<S>> Shell<P, S, H> {
                 Err(e) => writeln!(err, "{}", e.to_string().red())?,
             }
         }
+        if let Some(history_file) = &self.history_file {
+            if let Err(e) = rl.save_history(history_file) {
+                writeln!(err, "Cannot save history: {}", e.to_string().red())?;
+            }
+        }
         Ok(())
     }
 }
@@ -2,16 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::client_commands::SuiClientCommands;
+use crate::completion::{cache_objects, completion_values, print_completions, CompletionValues};
 use crate::console::start_console;
 use crate::fire_drill::{run_fire_drill, FireDrill};
 use crate::genesis_ceremony::{run, Ceremony};
 use crate::keytool::KeyToolCommand;
 use crate::validator_commands::SuiValidatorCommand;
+use crate::vault::{execute_vault_command, VaultCommand, SUI_VAULT_FILENAME};
 use anyhow::{anyhow, bail};
 use clap::*;
 use fastcrypto::traits::KeyPair;
 use move_package::BuildConfig;
 use rand::rngs::OsRng;
+use serde::Serialize;
 use std::io::{stderr, stdout, Write};
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
@@ -35,6 +38,7 @@ use sui_swarm_config::genesis_config::{GenesisConfig, DEFAULT_NUMBER_OF_AUTHORIT
 use sui_swarm_config::network_config::NetworkConfig;
 use sui_swarm_config::network_config_builder::ConfigBuilder;
 use sui_swarm_config::node_config_builder::FullnodeConfigBuilder;
+use sui_transaction_builder::coin_selection::CoinSelectionStrategy;
 use sui_types::crypto::{SignatureScheme, SuiKeyPair};
 use tracing::info;
 
@@ -49,6 +53,13 @@ pub enum SuiCommand {
         config: Option<PathBuf>,
         #[clap(long = "no-full-node")]
         no_full_node: bool,
+        /// Run genesis again before starting, discarding the state of the network. The keystore
+        /// and client config are kept if they are valid.
+        #[clap(long)]
+        force_regenesis: bool,
+        /// The duration of epochs of the network, if genesis is run.
+        #[clap(long = "epoch-duration-ms")]
+        epoch_duration_ms: Option<u64>,
     },
     #[clap(name = "network")]
     Network {
@@ -120,6 +131,10 @@ pub enum SuiCommand {
         json: bool,
         #[clap(short = 'y', long = "yes")]
         accept_defaults: bool,
+        /// How gas coins are chosen when none is given: minimize-inputs (the default),
+        /// consolidate-dust or exact-match.
+        #[clap(long, global = true)]
+        coin_selection: Option<CoinSelectionStrategy>,
     },
     /// A tool for validators and validator candidates.
     #[clap(name = "validator")]
@@ -150,24 +165,104 @@ pub enum SuiCommand {
         cmd: sui_move::Command,
     },
 
+    /// Store signed transactions encrypted, and broadcast them when a trigger fires: a time, an
+    /// epoch, or an on-chain event.
+    #[clap(name = "vault")]
+    Vault {
+        /// Sets the file storing the state of our user accounts (an empty one will be created if missing)
+        #[clap(long = "client.config")]
+        config: Option<PathBuf>,
+        /// Sets the file storing the transactions of the vault.
+        #[clap(long = "vault.path")]
+        vault_path: Option<PathBuf>,
+        #[clap(subcommand)]
+        cmd: VaultCommand,
+    },
+
     /// Tool for Fire Drill
     FireDrill {
         #[clap(subcommand)]
         fire_drill: FireDrill,
     },
+
+    /// Generate the completion script of a shell, e.g. `source <(sui completion bash)`.
+    /// Addresses and object IDs are completed from the local keystore and from the objects
+    /// listed by the last `sui client objects` or `sui client gas`.
+    #[clap(name = "completion")]
+    Completion {
+        #[clap(required_unless_present = "values")]
+        shell: Option<clap_complete::Shell>,
+        /// Print the values completed for an address or an object ID flag, used by the
+        /// completion scripts.
+        #[clap(long, hide = true)]
+        values: Option<CompletionValues>,
+        /// Sets the file storing the state of our user accounts
+        #[clap(long = "client.config")]
+        config: Option<PathBuf>,
+    },
+}
+
+/// The error printed to stdout when a command run with `--json` fails.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonErrorOutput {
+    pub error: String,
+    /// The chain of underlying errors, outermost first.
+    pub causes: Vec<String>,
+}
+
+impl From<&anyhow::Error> for JsonErrorOutput {
+    fn from(err: &anyhow::Error) -> Self {
+        Self {
+            error: err.to_string(),
+            causes: err.chain().skip(1).map(|e| e.to_string()).collect(),
+        }
+    }
 }
 
 impl SuiCommand {
+    /// Returns true if the command was invoked with `--json`, in which case both its output and
+    /// its errors are printed as JSON.
+    pub fn json_output(&self) -> bool {
+        match self {
+            SuiCommand::KeyTool { json, .. }
+            | SuiCommand::Client { json, .. }
+            | SuiCommand::Validator { json, .. } => *json,
+            _ => false,
+        }
+    }
+
     pub async fn execute(self) -> Result<(), anyhow::Error> {
         move_package::package_hooks::register_package_hooks(Box::new(SuiPackageHooks));
         match self {
             SuiCommand::Start {
                 config,
                 no_full_node,
+                force_regenesis,
+                epoch_duration_ms,
             } => {
+                if force_regenesis && config.is_some() {
+                    bail!("--force-regenesis cannot be used with --network.config");
+                }
                 // Auto genesis if path is none and sui directory doesn't exists.
-                if config.is_none() && !sui_config_dir()?.join(SUI_NETWORK_CONFIG).exists() {
-                    genesis(None, None, None, false, None, None, false).await?;
+                if force_regenesis
+                    || (config.is_none() && !sui_config_dir()?.join(SUI_NETWORK_CONFIG).exists())
+                {
+                    genesis(
+                        None,
+                        None,
+                        None,
+                        force_regenesis,
+                        epoch_duration_ms,
+                        None,
+                        false,
+                    )
+                    .await?;
+                } else if epoch_duration_ms.is_some() {
+                    bail!(
+                        "--epoch-duration-ms only applies to a new network, use --force-regenesis \
+                        to replace the existing one"
+                    );
                 }
 
                 // Load the config of the Sui authority.
@@ -281,12 +376,18 @@ impl SuiCommand {
                 cmd,
                 json,
                 accept_defaults,
+                coin_selection,
             } => {
                 let config_path = config.unwrap_or(sui_config_dir()?.join(SUI_CLIENT_CONFIG));
                 prompt_if_no_config(&config_path, accept_defaults).await?;
                 let mut context = WalletContext::new(&config_path, None, None).await?;
+                if let Some(coin_selection) = coin_selection {
+                    context.set_coin_selector(coin_selection.selector());
+                }
                 if let Some(cmd) = cmd {
-                    cmd.execute(&mut context).await?.print(!json);
+                    let result = cmd.execute(&mut context).await?;
+                    cache_objects(&config_path, &result);
+                    result.print(!json);
                 } else {
                     // Print help
                     let mut app: Command = SuiCommand::command();
@@ -319,7 +420,33 @@ impl SuiCommand {
                 build_config,
                 cmd,
             } => execute_move_command(package_path, build_config, cmd),
+            SuiCommand::Vault {
+                config,
+                vault_path,
+                cmd,
+            } => {
+                let config_path = config.unwrap_or(sui_config_dir()?.join(SUI_CLIENT_CONFIG));
+                prompt_if_no_config(&config_path, false).await?;
+                let context = WalletContext::new(&config_path, None, None).await?;
+                let vault_path = vault_path.unwrap_or(sui_config_dir()?.join(SUI_VAULT_FILENAME));
+                execute_vault_command(cmd, &vault_path, &context).await
+            }
             SuiCommand::FireDrill { fire_drill } => run_fire_drill(fire_drill).await,
+            SuiCommand::Completion {
+                shell,
+                values,
+                config,
+            } => {
+                if let Some(values) = values {
+                    let config_path = config.unwrap_or(sui_config_dir()?.join(SUI_CLIENT_CONFIG));
+                    for value in completion_values(values, &config_path) {
+                        println!("{value}");
+                    }
+                } else if let Some(shell) = shell {
+                    print_completions(shell, &mut SuiCommand::command(), &mut stdout());
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -546,6 +673,8 @@ async fn genesis(
         alias: "localnet".to_string(),
         rpc: format!("http://{}", fullnode_config.json_rpc_address),
         ws: None,
+        active_address: None,
+        keystore: None,
     });
     client_config.add_env(SuiEnv::devnet());
 
@@ -570,6 +699,8 @@ async fn prompt_if_no_config(
                 alias: "custom".to_string(),
                 rpc: v.into_string().unwrap(),
                 ws: None,
+                active_address: None,
+                keystore: None,
             }),
             None => {
                 if accept_defaults {
@@ -605,6 +736,8 @@ async fn prompt_if_no_config(
                             alias,
                             rpc: url,
                             ws: None,
+                            active_address: None,
+                            keystore: None,
                         }
                     })
                 } else {
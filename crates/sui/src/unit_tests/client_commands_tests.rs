@@ -0,0 +1,85 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::json;
+use sui_json_rpc_types::{SuiObjectData, SuiObjectResponse};
+use sui_types::base_types::{ObjectDigest, ObjectID, SequenceNumber, SuiAddress};
+use sui_types::digests::TransactionDigest;
+use sui_types::error::SuiObjectResponseError;
+use sui_types::object::Owner;
+
+use super::ObjectChangeRecord;
+
+fn object_data(
+    object_id: ObjectID,
+    owner: Owner,
+    previous_transaction: TransactionDigest,
+) -> SuiObjectData {
+    SuiObjectData {
+        object_id,
+        version: SequenceNumber::from_u64(3),
+        digest: ObjectDigest::new([4; 32]),
+        type_: None,
+        owner: Some(owner),
+        previous_transaction: Some(previous_transaction),
+        storage_rebate: None,
+        display: None,
+        content: None,
+        bcs: None,
+    }
+}
+
+#[test]
+fn test_object_change_record_from_live_object() {
+    let object_id = ObjectID::random();
+    let owner = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+    let previous_transaction = TransactionDigest::random();
+    let response =
+        SuiObjectResponse::new_with_data(object_data(object_id, owner, previous_transaction));
+
+    let record = ObjectChangeRecord::from_response(response).unwrap();
+    assert_eq!(
+        serde_json::to_value(&record).unwrap(),
+        json!({
+            "objectId": object_id,
+            "version": SequenceNumber::from_u64(3),
+            "digest": ObjectDigest::new([4; 32]),
+            "owner": owner,
+            "previousTransaction": previous_transaction,
+            "deleted": false,
+        })
+    );
+}
+
+#[test]
+fn test_object_change_record_from_deleted_object() {
+    let object_id = ObjectID::random();
+    let response = SuiObjectResponse::new_with_error(SuiObjectResponseError::Deleted {
+        object_id,
+        version: SequenceNumber::from_u64(5),
+        digest: ObjectDigest::OBJECT_DIGEST_DELETED,
+    });
+
+    let record = ObjectChangeRecord::from_response(response).unwrap();
+    // The owner and previous transaction of a deleted object are unknown, and left out.
+    assert_eq!(
+        serde_json::to_value(&record).unwrap(),
+        json!({
+            "objectId": object_id,
+            "version": SequenceNumber::from_u64(5),
+            "digest": ObjectDigest::OBJECT_DIGEST_DELETED,
+            "deleted": true,
+        })
+    );
+}
+
+#[test]
+fn test_object_change_record_from_missing_object() {
+    let object_id = ObjectID::random();
+    let response =
+        SuiObjectResponse::new_with_error(SuiObjectResponseError::NotExists { object_id });
+    assert!(ObjectChangeRecord::from_response(response).is_none());
+
+    let response = SuiObjectResponse::new_with_error(SuiObjectResponseError::Unknown);
+    assert!(ObjectChangeRecord::from_response(response).is_none());
+}
@@ -0,0 +1,134 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use sui_types::base_types::ObjectID;
+use sui_types::parse_sui_type_tag;
+
+use crate::client_ptb::{Ptb, PtbArg, PtbCommand};
+
+fn parse(args: &[&str]) -> Result<Ptb, anyhow::Error> {
+    Ptb::parse(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>())
+}
+
+#[test]
+fn test_parse_results_piping() {
+    let ptb = parse(&[
+        "--split-coins",
+        "gas",
+        "[1000, 2000]",
+        "--assign",
+        "coins",
+        "--move-call",
+        "0x2::coin::join",
+        "<0x2::sui::SUI>",
+        "coins.0",
+        "coins.1",
+        "--transfer-objects",
+        "[coins.0]",
+        "0x42",
+        "--gas-budget",
+        "5000000",
+    ])
+    .unwrap();
+
+    assert_eq!(ptb.gas_budget, 5000000);
+    assert_eq!(ptb.gas, None);
+    assert_eq!(
+        ptb.commands,
+        vec![
+            PtbCommand::SplitCoins {
+                coin: PtbArg::Gas,
+                amounts: vec![
+                    PtbArg::Value("1000".to_string()),
+                    PtbArg::Value("2000".to_string())
+                ],
+            },
+            PtbCommand::MoveCall {
+                package: ObjectID::from_str("0x2").unwrap(),
+                module: "coin".to_string(),
+                function: "join".to_string(),
+                type_args: vec![parse_sui_type_tag("0x2::sui::SUI").unwrap()],
+                args: vec![PtbArg::NestedResult(0, 0), PtbArg::NestedResult(0, 1)],
+            },
+            PtbCommand::TransferObjects {
+                objects: vec![PtbArg::NestedResult(0, 0)],
+                recipient: PtbArg::Value("0x42".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_assign_value() {
+    let ptb = parse(&[
+        "--assign",
+        "coin",
+        "0x5",
+        "--split-coins",
+        "coin",
+        "[10]",
+        "--assign",
+        "split",
+        "--transfer-objects",
+        "[split]",
+        "0x42",
+        "--gas-coin",
+        "0x6",
+        "--gas-budget",
+        "100",
+        "--serialize-unsigned-transaction",
+    ])
+    .unwrap();
+
+    assert_eq!(ptb.gas, Some(ObjectID::from_str("0x6").unwrap()));
+    assert!(ptb.serialize_unsigned_transaction);
+    assert_eq!(
+        ptb.commands,
+        vec![
+            PtbCommand::SplitCoins {
+                coin: PtbArg::Value("0x5".to_string()),
+                amounts: vec![PtbArg::Value("10".to_string())],
+            },
+            PtbCommand::TransferObjects {
+                objects: vec![PtbArg::Result(0)],
+                recipient: PtbArg::Value("0x42".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    // Missing gas budget.
+    assert!(parse(&["--split-coins", "gas", "[1]"]).is_err());
+    // No commands.
+    assert!(parse(&["--gas-budget", "100"]).is_err());
+    // Assigning the result of a command without result.
+    assert!(parse(&[
+        "--transfer-objects",
+        "[gas]",
+        "0x42",
+        "--assign",
+        "x",
+        "--gas-budget",
+        "100"
+    ])
+    .is_err());
+    // Selecting a result of a value.
+    assert!(parse(&[
+        "--assign",
+        "x",
+        "0x5",
+        "--transfer-objects",
+        "[x.0]",
+        "0x42",
+        "--gas-budget",
+        "100"
+    ])
+    .is_err());
+    // Invalid target and unknown command.
+    assert!(parse(&["--move-call", "0x2::coin", "--gas-budget", "100"]).is_err());
+    assert!(parse(&["--merge", "gas", "--gas-budget", "100"]).is_err());
+}
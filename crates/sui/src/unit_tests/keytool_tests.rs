@@ -3,11 +3,15 @@
 
 use std::str::FromStr;
 
-use crate::key_identity::KeyIdentity;
+use crate::key_identity::{get_identity_address_from_keystore, KeyIdentity};
 use crate::keytool::read_authority_keypair_from_file;
 use crate::keytool::read_keypair_from_file;
 
 use super::write_keypair_to_file;
+use super::AddressBookCommand;
+use super::CommandOutput;
+use super::ConvertOutput;
+use super::KeyEncoding;
 use super::KeyToolCommand;
 use anyhow::Ok;
 use fastcrypto::encoding::Base64;
@@ -15,7 +19,9 @@ use fastcrypto::encoding::Encoding;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 use shared_crypto::intent::Intent;
+use shared_crypto::intent::IntentMessage;
 use shared_crypto::intent::IntentScope;
+use sui_keys::key_encoding::SUI_PRIV_KEY_PREFIX;
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, InMemKeystore, Keystore};
 use sui_types::base_types::ObjectDigest;
 use sui_types::base_types::ObjectID;
@@ -32,6 +38,8 @@ use sui_types::crypto::Signature;
 use sui_types::crypto::SignatureScheme;
 use sui_types::crypto::SuiKeyPair;
 use sui_types::crypto::SuiSignatureInner;
+use sui_types::multisig::MultiSigPublicKey;
+use sui_types::signature::{AuthenticatorTrait, GenericSignature, VerifyParams};
 use sui_types::transaction::TransactionData;
 use sui_types::transaction::TEST_ONLY_GAS_UNIT_FOR_TRANSFER;
 use tempfile::TempDir;
@@ -526,3 +534,280 @@ async fn test_sign_command() -> Result<(), anyhow::Error> {
     .await?;
     Ok(())
 }
+
+#[test]
+async fn test_multisig_combine_partial_sig_any_order() -> Result<(), anyhow::Error> {
+    let mut keystore = Keystore::from(InMemKeystore::new_insecure_for_tests(3));
+    let pks = keystore.keys();
+    let addresses: Vec<SuiAddress> = pks.iter().map(|pk| pk.into()).collect();
+    let multisig_pk = MultiSigPublicKey::new(pks.clone(), vec![1, 1, 1], 2)?;
+    let multisig_address = SuiAddress::from(&multisig_pk);
+
+    let gas = (
+        ObjectID::random(),
+        SequenceNumber::new(),
+        ObjectDigest::random(),
+    );
+    let tx_data = TransactionData::new_transfer_sui(
+        SuiAddress::random_for_testing_only(),
+        multisig_address,
+        None,
+        gas,
+        TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
+        1,
+    );
+    let sig_0: GenericSignature = keystore
+        .sign_secure(&addresses[0], &tx_data, Intent::sui_transaction())?
+        .into();
+    let sig_2: GenericSignature = keystore
+        .sign_secure(&addresses[2], &tx_data, Intent::sui_transaction())?
+        .into();
+
+    // Signatures passed out of order are sorted by public key index before combining.
+    let output = KeyToolCommand::MultiSigCombinePartialSig {
+        sigs: vec![sig_2, sig_0],
+        pks,
+        weights: vec![1, 1, 1],
+        threshold: 2,
+    }
+    .execute(&mut keystore)
+    .await?;
+    let CommandOutput::MultiSigCombinePartialSig(combined) = output else {
+        panic!("unexpected output");
+    };
+    assert_eq!(combined.multisig_address, multisig_address);
+    combined.multisig_parsed.verify_authenticator(
+        &IntentMessage::new(Intent::sui_transaction(), tx_data),
+        multisig_address,
+        None,
+        &VerifyParams::default(),
+    )?;
+    Ok(())
+}
+
+#[test]
+async fn test_convert_private_key() -> Result<(), anyhow::Error> {
+    let mut keystore = Keystore::from(InMemKeystore::new_insecure_for_tests(0));
+    let hex = "0x9dd9ae36ee51b912a0364c58c1f21333bcdad2d91911aa127226c512be285102";
+    let base64 = "AJ3ZrjbuUbkSoDZMWMHyEzO82tLZGRGqEnImxRK+KFEC";
+
+    // Hex converts to Base64 and back by default, in the original output shape.
+    let output = KeyToolCommand::Convert {
+        value: hex.to_string(),
+        to: None,
+    }
+    .execute(&mut keystore)
+    .await?;
+    assert_eq!(
+        serde_json::to_value(&output)?,
+        serde_json::json!({ "base64": base64 })
+    );
+    let output = KeyToolCommand::Convert {
+        value: base64.to_string(),
+        to: None,
+    }
+    .execute(&mut keystore)
+    .await?;
+    assert_eq!(
+        serde_json::to_value(&output)?,
+        serde_json::json!({ "hex": &hex[2..] })
+    );
+
+    let CommandOutput::Convert(ConvertOutput::Bech32(bech32)) = KeyToolCommand::Convert {
+        value: hex.to_string(),
+        to: Some(KeyEncoding::Bech32),
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    assert!(bech32.starts_with(SUI_PRIV_KEY_PREFIX));
+
+    // Bech32 converts to Base64 by default, and to any encoding on request.
+    let CommandOutput::Convert(ConvertOutput::Base64(from_bech32)) = KeyToolCommand::Convert {
+        value: bech32.clone(),
+        to: None,
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    assert_eq!(from_bech32, base64);
+    let CommandOutput::Convert(ConvertOutput::Hex(from_bech32)) = KeyToolCommand::Convert {
+        value: bech32.clone(),
+        to: Some(KeyEncoding::Hex),
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    assert_eq!(format!("0x{from_bech32}"), hex);
+    let CommandOutput::Convert(ConvertOutput::Bech32(from_base64)) = KeyToolCommand::Convert {
+        value: base64.to_string(),
+        to: Some(KeyEncoding::Bech32),
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    assert_eq!(from_base64, bech32);
+    Ok(())
+}
+
+#[test]
+async fn test_export_and_import_private_key() -> Result<(), anyhow::Error> {
+    let mut keystore = Keystore::from(InMemKeystore::new_insecure_for_tests(1));
+    let address = keystore.addresses()[0];
+
+    let CommandOutput::Export(exported) = KeyToolCommand::Export {
+        key_identity: KeyIdentity::Address(address),
+        encrypt: false,
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    assert!(!exported.encrypted);
+    assert_eq!(exported.key.sui_address, address);
+
+    let mut restored = Keystore::from(InMemKeystore::new_insecure_for_tests(0));
+    KeyToolCommand::Import {
+        alias: Some("restored".to_string()),
+        input_string: exported.exported_private_key,
+        key_scheme: SignatureScheme::ED25519,
+        derivation_path: None,
+    }
+    .execute(&mut restored)
+    .await?;
+    assert_eq!(restored.addresses(), vec![address]);
+    assert_eq!(restored.get_alias_by_address(&address)?, "restored");
+    Ok(())
+}
+
+#[test]
+async fn test_encrypted_private_key() -> Result<(), anyhow::Error> {
+    let skp = SuiKeyPair::Secp256k1(get_key_pair().1);
+    let encrypted = super::encrypt_private_key(&skp, "correct horse")?;
+    assert!(encrypted.starts_with(super::ENCRYPTED_KEY_PREFIX));
+    assert_eq!(
+        super::decrypt_private_key(&encrypted, "correct horse")?,
+        skp
+    );
+    assert!(super::decrypt_private_key(&encrypted, "wrong password").is_err());
+    assert!(super::decrypt_private_key(&encrypted[..20], "correct horse").is_err());
+    Ok(())
+}
+
+#[test]
+async fn test_import_mnemonic_with_alias_and_path() -> Result<(), anyhow::Error> {
+    let mut keystore = Keystore::from(InMemKeystore::new_insecure_for_tests(0));
+    let CommandOutput::Import(key) = KeyToolCommand::Import {
+        alias: Some("account1".to_string()),
+        input_string: TEST_MNEMONIC.to_string(),
+        key_scheme: SignatureScheme::ED25519,
+        derivation_path: Some("m/44'/784'/1'/0'/0'".parse().unwrap()),
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    assert_eq!(key.alias.as_deref(), Some("account1"));
+    assert_eq!(key.derivation_path.as_deref(), Some("m/44'/784'/1'/0'/0'"));
+    assert_eq!(keystore.get_alias_by_address(&key.sui_address)?, "account1");
+
+    // The default derivation path is reported when none is given.
+    let CommandOutput::Import(key) = KeyToolCommand::Import {
+        alias: None,
+        input_string: TEST_MNEMONIC.to_string(),
+        key_scheme: SignatureScheme::Secp256k1,
+        derivation_path: None,
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    assert_eq!(key.derivation_path.as_deref(), Some("m/54'/784'/0'/0/0"));
+
+    // The derivation paths are listed with the keys, and omitted for keys imported as such.
+    keystore.add_key(None, SuiKeyPair::Ed25519(get_key_pair().1))?;
+    let CommandOutput::List(keys) = KeyToolCommand::List {
+        sort_by_alias: false,
+    }
+    .execute(&mut keystore)
+    .await?
+    else {
+        panic!("Unexpected output")
+    };
+    let mut paths = keys
+        .iter()
+        .map(|key| key.derivation_path.as_deref())
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![None, Some("m/44'/784'/1'/0'/0'"), Some("m/54'/784'/0'/0/0")]
+    );
+    Ok(())
+}
+
+#[test]
+async fn test_address_book() -> Result<(), anyhow::Error> {
+    let temp_dir = TempDir::new().unwrap();
+    let keystore_path = temp_dir.path().join("sui.keystore");
+    let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    keystore.add_key(
+        Some("my_key".to_string()),
+        SuiKeyPair::Ed25519(get_key_pair().1),
+    )?;
+    let treasury = SuiAddress::random_for_testing_only();
+
+    KeyToolCommand::AddressBook(AddressBookCommand::Add {
+        name: "treasury".to_string(),
+        address: treasury,
+    })
+    .execute(&mut keystore)
+    .await?;
+    assert_eq!(
+        get_identity_address_from_keystore(KeyIdentity::from_str("@treasury")?, &keystore)?,
+        treasury
+    );
+
+    // Names of address book entries cannot collide with key aliases or other entries.
+    for name in ["my_key", "treasury"] {
+        assert!(KeyToolCommand::AddressBook(AddressBookCommand::Add {
+            name: name.to_string(),
+            address: SuiAddress::random_for_testing_only(),
+        })
+        .execute(&mut keystore)
+        .await
+        .is_err());
+    }
+
+    // The address book is persisted next to the keystore.
+    KeyToolCommand::AddressBook(AddressBookCommand::Rename {
+        old_name: "treasury".to_string(),
+        new_name: "vault".to_string(),
+    })
+    .execute(&mut keystore)
+    .await?;
+    let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    assert_eq!(keystore.address_book().get("vault"), Some(&treasury));
+    assert!(
+        get_identity_address_from_keystore(KeyIdentity::from_str("@treasury")?, &keystore).is_err()
+    );
+
+    KeyToolCommand::AddressBook(AddressBookCommand::Remove {
+        name: "vault".to_string(),
+    })
+    .execute(&mut keystore)
+    .await?;
+    assert!(keystore.address_book().entries().is_empty());
+    Ok(())
+}
@@ -0,0 +1,78 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{Trigger, Vault};
+use sui_json_rpc_types::EventFilter;
+use sui_types::base_types::{random_object_ref, SuiAddress};
+use sui_types::crypto::{get_key_pair, AccountKeyPair, SuiKeyPair};
+use sui_types::parse_sui_struct_tag;
+use sui_types::transaction::{Transaction, TransactionData, TransactionExpiration};
+
+fn signed_transaction(expiration: TransactionExpiration) -> Transaction {
+    let (sender, key): (_, AccountKeyPair) = get_key_pair();
+    let mut data = TransactionData::new_transfer_sui(
+        SuiAddress::random_for_testing_only(),
+        sender,
+        None,
+        random_object_ref(),
+        1_000_000,
+        1000,
+    );
+    if let TransactionData::V1(data) = &mut data {
+        data.expiration = expiration;
+    }
+    Transaction::from_data_and_signer(data, vec![&SuiKeyPair::Ed25519(key)])
+}
+
+#[test]
+fn test_vault_roundtrip() -> Result<(), anyhow::Error> {
+    let path = tempfile::tempdir()?.into_path().join("vault.json");
+    let transaction = signed_transaction(TransactionExpiration::Epoch(7));
+    let trigger = Trigger::Event {
+        filter: EventFilter::MoveEventType(parse_sui_struct_tag("0x42::escrow::Expired")?),
+    };
+
+    let mut vault = Vault::load(&path)?;
+    assert!(vault.entries().is_empty());
+    vault.add("payout".to_string(), &transaction, trigger, "password")?;
+    assert!(vault
+        .add(
+            "payout".to_string(),
+            &transaction,
+            Trigger::Epoch { epoch: 1 },
+            "password"
+        )
+        .is_err());
+    vault.save(&path)?;
+
+    let mut vault = Vault::load(&path)?;
+    let entry = &vault.entries()[0];
+    assert_eq!(entry.name, "payout");
+    assert_eq!(entry.digest, *transaction.digest());
+    assert!(matches!(entry.trigger, Trigger::Event { .. }));
+    assert_eq!(entry.expiration_epoch, Some(7));
+    assert!(!entry.is_expired(7));
+    assert!(entry.is_expired(8));
+    assert_eq!(entry.decrypt("password")?, transaction);
+    assert!(entry.decrypt("wrong password").is_err());
+
+    assert!(vault.remove("payout").is_some());
+    assert!(vault.remove("payout").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_trigger_has_fired() {
+    let time = Trigger::Time { timestamp_ms: 1000 };
+    assert!(!time.has_fired(999, 0));
+    assert!(time.has_fired(1000, 0));
+
+    let epoch = Trigger::Epoch { epoch: 5 };
+    assert!(!epoch.has_fired(u64::MAX, 4));
+    assert!(epoch.has_fired(0, 5));
+
+    let event = Trigger::Event {
+        filter: EventFilter::All(vec![]),
+    };
+    assert!(!event.has_fired(u64::MAX, u64::MAX));
+}
@@ -0,0 +1,449 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A vault of fully signed transactions that are broadcast when a trigger fires, so that
+//! operations like scheduled payouts can be signed ahead of time, e.g. on an air-gapped machine,
+//! and broadcast later by a process that holds no keys.
+//!
+//!   $ sui vault add --name payout --signed-tx-bytes <BASE64> --epoch 120
+//!   $ sui vault add --name refund --signed-tx-bytes <BASE64> --event-type 0x42::escrow::Expired
+//!   $ sui vault run
+//!
+//! The transactions are encrypted at rest with a password, read from `SUI_VAULT_PASSWORD` if set
+//! or from the terminal otherwise. A transaction whose expiration epoch has passed is dropped
+//! from the vault without being broadcast.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, ensure};
+use clap::*;
+use fastcrypto::encoding::{Base64, Encoding};
+use futures::StreamExt;
+use move_core_types::language_storage::StructTag;
+use serde::{Deserialize, Serialize};
+use sui_json_rpc_types::EventFilter;
+use sui_sdk::failover::BackoffPolicy;
+use sui_sdk::wallet_context::WalletContext;
+use sui_sdk::SuiClient;
+use sui_types::base_types::EpochId;
+use sui_types::digests::TransactionDigest;
+use sui_types::event::EventID;
+use sui_types::parse_sui_struct_tag;
+use sui_types::transaction::{
+    SenderSignedData, Transaction, TransactionDataAPI, TransactionExpiration,
+};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::keytool::{decrypt_with_password, encrypt_with_password, read_password};
+
+#[cfg(test)]
+#[path = "unit_tests/vault_tests.rs"]
+mod vault_tests;
+
+pub const SUI_VAULT_FILENAME: &str = "vault.json";
+/// Environment variable holding the password of the vault.
+const PASSWORD_ENV_VAR: &str = "SUI_VAULT_PASSWORD";
+
+#[derive(Subcommand)]
+#[clap(rename_all = "kebab-case")]
+pub enum VaultCommand {
+    /// Add a signed transaction to the vault, to be broadcast when its trigger fires.
+    #[clap(group(ArgGroup::new("trigger").required(true).args(&["at_ms", "epoch", "event_type"])))]
+    Add {
+        /// A unique name for the transaction in the vault.
+        #[clap(long)]
+        name: String,
+        /// BCS serialized sender signed data, as base-64 encoded string, as produced by
+        /// `--serialize-signed-transaction`.
+        #[clap(long)]
+        signed_tx_bytes: String,
+        /// Broadcast the transaction once the local clock reaches this Unix timestamp in
+        /// milliseconds.
+        #[clap(long)]
+        at_ms: Option<u64>,
+        /// Broadcast the transaction once the network reaches this epoch.
+        #[clap(long)]
+        epoch: Option<EpochId>,
+        /// Broadcast the transaction when an event of this Move type is emitted.
+        #[clap(long, value_parser = parse_sui_struct_tag)]
+        event_type: Option<StructTag>,
+    },
+    /// List the transactions in the vault and their triggers.
+    List,
+    /// Remove a transaction from the vault without broadcasting it.
+    Remove { name: String },
+    /// Broadcast the transactions of the vault as their triggers fire, until the vault is empty.
+    /// Transactions added while running are picked up.
+    Run {
+        /// How often the time and epoch triggers are checked, in seconds.
+        #[clap(long, default_value_t = 10)]
+        poll_interval_secs: u64,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Trigger {
+    /// Fires once the local clock reaches a Unix timestamp in milliseconds.
+    Time { timestamp_ms: u64 },
+    /// Fires once the network reaches an epoch.
+    Epoch { epoch: EpochId },
+    /// Fires when an event matching the filter is emitted, observed through an event
+    /// subscription.
+    Event { filter: EventFilter },
+}
+
+impl Trigger {
+    /// Whether a time or an epoch trigger has fired. Event triggers are fired by their
+    /// subscriptions instead.
+    pub fn has_fired(&self, now_ms: u64, epoch: EpochId) -> bool {
+        match self {
+            Trigger::Time { timestamp_ms } => now_ms >= *timestamp_ms,
+            Trigger::Epoch {
+                epoch: trigger_epoch,
+            } => epoch >= *trigger_epoch,
+            Trigger::Event { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for Trigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Trigger::Time { timestamp_ms } => write!(f, "at {timestamp_ms} ms"),
+            Trigger::Epoch { epoch } => write!(f, "at epoch {epoch}"),
+            Trigger::Event { filter } => write!(f, "on event {filter:?}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultEntry {
+    pub name: String,
+    pub digest: TransactionDigest,
+    pub trigger: Trigger,
+    /// The last epoch in which the transaction can be executed, if it expires.
+    pub expiration_epoch: Option<EpochId>,
+    /// The Base64 encoding of the BCS serialized sender signed data, encrypted with the password
+    /// of the vault.
+    encrypted_tx: String,
+}
+
+impl VaultEntry {
+    pub fn is_expired(&self, epoch: EpochId) -> bool {
+        self.expiration_epoch
+            .is_some_and(|expiration_epoch| epoch > expiration_epoch)
+    }
+
+    pub fn decrypt(&self, password: &str) -> Result<Transaction, anyhow::Error> {
+        let encrypted = Base64::decode(&self.encrypted_tx)
+            .map_err(|e| anyhow!("Transaction {} is malformed: {e}", self.name))?;
+        let data: SenderSignedData =
+            bcs::from_bytes(&decrypt_with_password(&encrypted, password)?)?;
+        let transaction = Transaction::new(data);
+        ensure!(
+            *transaction.digest() == self.digest,
+            "Transaction {} does not match its digest {}",
+            self.name,
+            self.digest
+        );
+        Ok(transaction)
+    }
+}
+
+/// The transactions of the vault, persisted as JSON.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Vault {
+    entries: Vec<VaultEntry>,
+}
+
+impl Vault {
+    /// Loads the vault at `path`, which is empty if the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Cannot read vault {}: {e}", path.display()))?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), anyhow::Error> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|e| anyhow!("Cannot write vault {}: {e}", path.display()))
+    }
+
+    pub fn entries(&self) -> &[VaultEntry] {
+        &self.entries
+    }
+
+    pub fn add(
+        &mut self,
+        name: String,
+        transaction: &Transaction,
+        trigger: Trigger,
+        password: &str,
+    ) -> Result<&VaultEntry, anyhow::Error> {
+        if self.entries.iter().any(|entry| entry.name == name) {
+            bail!("The vault already has a transaction named {name}");
+        }
+        let expiration_epoch = match transaction.transaction_data().expiration() {
+            TransactionExpiration::None => None,
+            TransactionExpiration::Epoch(epoch) => Some(*epoch),
+        };
+        let encrypted = encrypt_with_password(&bcs::to_bytes(transaction.data())?, password)?;
+        self.entries.push(VaultEntry {
+            name,
+            digest: *transaction.digest(),
+            trigger,
+            expiration_epoch,
+            encrypted_tx: Base64::encode(encrypted),
+        });
+        Ok(self.entries.last().unwrap())
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<VaultEntry> {
+        let index = self.entries.iter().position(|entry| entry.name == name)?;
+        Some(self.entries.remove(index))
+    }
+}
+
+pub async fn execute_vault_command(
+    cmd: VaultCommand,
+    vault_path: &Path,
+    context: &WalletContext,
+) -> Result<(), anyhow::Error> {
+    match cmd {
+        VaultCommand::Add {
+            name,
+            signed_tx_bytes,
+            at_ms,
+            epoch,
+            event_type,
+        } => {
+            let data: SenderSignedData = bcs::from_bytes(
+                &Base64::decode(&signed_tx_bytes)
+                    .map_err(|e| anyhow!("Cannot decode signed transaction: {e}"))?,
+            )?;
+            let transaction = Transaction::new(data);
+            let trigger = match (at_ms, epoch, event_type) {
+                (Some(timestamp_ms), _, _) => Trigger::Time { timestamp_ms },
+                (_, Some(epoch), _) => Trigger::Epoch { epoch },
+                (_, _, Some(event_type)) => Trigger::Event {
+                    filter: EventFilter::MoveEventType(event_type),
+                },
+                _ => unreachable!("a trigger is required by the argument group"),
+            };
+            let mut vault = Vault::load(vault_path)?;
+            let password = read_password(PASSWORD_ENV_VAR, vault.entries.is_empty())?;
+            // All the transactions of a vault are decrypted by `run` with the same password.
+            if let Some(entry) = vault.entries.first() {
+                entry.decrypt(&password)?;
+            }
+            let entry = vault.add(name, &transaction, trigger, &password)?;
+            println!(
+                "Added transaction {} ({}) to be broadcast {}",
+                entry.name, entry.digest, entry.trigger
+            );
+            vault.save(vault_path)
+        }
+        VaultCommand::List => {
+            let vault = Vault::load(vault_path)?;
+            if vault.entries.is_empty() {
+                println!("The vault is empty");
+            }
+            for entry in &vault.entries {
+                let expiration = entry.expiration_epoch.map_or_else(String::new, |epoch| {
+                    format!(", expires after epoch {epoch}")
+                });
+                println!(
+                    "{}: {} broadcast {}{}",
+                    entry.name, entry.digest, entry.trigger, expiration
+                );
+            }
+            Ok(())
+        }
+        VaultCommand::Remove { name } => {
+            let mut vault = Vault::load(vault_path)?;
+            let entry = vault
+                .remove(&name)
+                .ok_or_else(|| anyhow!("The vault has no transaction named {name}"))?;
+            vault.save(vault_path)?;
+            println!("Removed transaction {} ({})", entry.name, entry.digest);
+            Ok(())
+        }
+        VaultCommand::Run { poll_interval_secs } => {
+            run_vault(
+                vault_path,
+                context,
+                Duration::from_secs(poll_interval_secs.max(1)),
+            )
+            .await
+        }
+    }
+}
+
+async fn run_vault(
+    vault_path: &Path,
+    context: &WalletContext,
+    poll_interval: Duration,
+) -> Result<(), anyhow::Error> {
+    let password = read_password(PASSWORD_ENV_VAR, false)?;
+    let client = context.get_client().await?;
+    let (fired_sender, mut fired_receiver) = mpsc::unbounded_channel();
+    // The decrypted transactions, by name.
+    let mut transactions: BTreeMap<String, Transaction> = BTreeMap::new();
+    let mut fired_events = HashSet::new();
+    let mut interval = tokio::time::interval(poll_interval);
+
+    loop {
+        let mut vault = Vault::load(vault_path)?;
+        if vault.entries.is_empty() {
+            println!("The vault is empty");
+            return Ok(());
+        }
+        let epoch = client
+            .governance_api()
+            .get_latest_sui_system_state()
+            .await?
+            .epoch;
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+
+        let mut done = Vec::new();
+        for entry in &vault.entries {
+            if !transactions.contains_key(&entry.name) {
+                transactions.insert(entry.name.clone(), entry.decrypt(&password)?);
+                if let Trigger::Event { filter } = &entry.trigger {
+                    tokio::spawn(watch_event(
+                        client.clone(),
+                        entry.name.clone(),
+                        filter.clone(),
+                        fired_sender.clone(),
+                    ));
+                }
+            }
+            if entry.is_expired(epoch) {
+                println!(
+                    "Dropping transaction {} ({}), it expired after epoch {}",
+                    entry.name,
+                    entry.digest,
+                    entry.expiration_epoch.unwrap_or_default()
+                );
+                done.push(entry.name.clone());
+            } else if entry.trigger.has_fired(now_ms, epoch) || fired_events.contains(&entry.name) {
+                let transaction = transactions[&entry.name].clone();
+                match context.execute_transaction_may_fail(transaction).await {
+                    Ok(response) => {
+                        println!(
+                            "Broadcast transaction {} ({}): {}",
+                            entry.name,
+                            entry.digest,
+                            if response.status_ok().unwrap_or(false) {
+                                "success"
+                            } else {
+                                "failure"
+                            }
+                        );
+                        done.push(entry.name.clone());
+                    }
+                    // The transaction is retried at the next poll.
+                    Err(e) => warn!("Cannot broadcast transaction {}: {e}", entry.name),
+                }
+            }
+        }
+
+        if !done.is_empty() {
+            // Reload the vault, in case transactions were added while broadcasting.
+            vault = Vault::load(vault_path)?;
+            for name in &done {
+                vault.remove(name);
+                transactions.remove(name);
+                fired_events.remove(name);
+            }
+            vault.save(vault_path)?;
+        }
+
+        tokio::select! {
+            _ = interval.tick() => {}
+            Some(name) = fired_receiver.recv() => {
+                fired_events.insert(name);
+            }
+        }
+    }
+}
+
+/// Notifies `fired` with `name` when the first event matching `filter` is emitted after the
+/// watch started. Failed subscriptions are restarted with backoff, from the latest event when
+/// the watch started, so that events emitted in between are not missed.
+async fn watch_event(
+    client: SuiClient,
+    name: String,
+    filter: EventFilter,
+    fired: mpsc::UnboundedSender<String>,
+) {
+    let backoff = BackoffPolicy::default();
+    let mut attempt = 0;
+    let start = loop {
+        match client
+            .event_api()
+            .query_events(filter.clone(), None, Some(1), true)
+            .await
+        {
+            Ok(page) => break page.data.first().map(|event| event.id),
+            Err(e) => {
+                warn!("Cannot query the latest event of transaction {name}: {e}");
+                tokio::time::sleep(backoff.delay_for_attempt(attempt)).await;
+                attempt = attempt.saturating_add(1);
+            }
+        }
+    };
+    loop {
+        match next_event(&client, &filter, start).await {
+            Ok(()) => {
+                let _ = fired.send(name);
+                return;
+            }
+            Err(e) => {
+                let delay = backoff.delay_for_attempt(attempt);
+                warn!(
+                    "The event subscription of transaction {name} failed: {e}, \
+                     restarting in {delay:?}"
+                );
+                tokio::time::sleep(delay).await;
+                attempt = attempt.saturating_add(1);
+            }
+        }
+    }
+}
+
+/// Waits for an event matching `filter` emitted after `start`, or after the first event if
+/// `start` is None.
+async fn next_event(
+    client: &SuiClient,
+    filter: &EventFilter,
+    start: Option<EventID>,
+) -> Result<(), anyhow::Error> {
+    // Subscribe first, so that no event falls between the query and the subscription.
+    let mut events = client
+        .event_api()
+        .subscribe_events(filter.clone(), start)
+        .await?;
+    let emitted = client
+        .event_api()
+        .query_events(filter.clone(), start, Some(1), false)
+        .await?;
+    if !emitted.data.is_empty() {
+        return Ok(());
+    }
+    match events.next().await {
+        Some(Ok(_)) => Ok(()),
+        Some(Err(e)) => Err(e.into()),
+        None => Err(anyhow!("the subscription ended")),
+    }
+}
@@ -7,7 +7,9 @@ use std::os::unix::prelude::FileExt;
 use std::str::FromStr;
 use std::{fmt::Write, fs::read_dir, path::PathBuf, str, thread, time::Duration};
 
+use clap::Parser;
 use expect_test::expect;
+use fastcrypto::encoding::{Base64, Encoding};
 use move_package::BuildConfig as MoveBuildConfig;
 use serde_json::json;
 use sui::key_identity::{get_identity_address, KeyIdentity};
@@ -34,7 +36,7 @@ use sui_json_rpc_types::{
     OwnedObjectRef, SuiObjectData, SuiObjectDataFilter, SuiObjectDataOptions, SuiObjectResponse,
     SuiObjectResponseQuery, SuiTransactionBlockEffects, SuiTransactionBlockEffectsAPI,
 };
-use sui_keys::keystore::AccountKeystore;
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
 use sui_macros::sim_test;
 use sui_move_build::{BuildConfig, SuiPackageHooks};
 use sui_sdk::sui_client_config::SuiClientConfig;
@@ -58,9 +60,22 @@ async fn test_genesis() -> Result<(), anyhow::Error> {
     let config = working_dir.join(SUI_NETWORK_CONFIG);
 
     // Start network without authorities
+    let start = SuiCommand::Start {
+        config: Some(config.clone()),
+        no_full_node: false,
+        force_regenesis: false,
+        epoch_duration_ms: None,
+    }
+    .execute()
+    .await;
+    assert!(matches!(start, Err(..)));
+
+    // Regenesis only applies to the default config dir
     let start = SuiCommand::Start {
         config: Some(config),
         no_full_node: false,
+        force_regenesis: true,
+        epoch_duration_ms: None,
     }
     .execute()
     .await;
@@ -337,6 +352,9 @@ async fn test_gas_command() -> Result<(), anyhow::Error> {
 
     SuiClientCommands::Gas {
         address: Some(KeyIdentity::Address(address)),
+        split: None,
+        merge: false,
+        gas_budget: None,
     }
     .execute(context)
     .await?
@@ -359,6 +377,9 @@ async fn test_gas_command() -> Result<(), anyhow::Error> {
     // Fetch gas again, and use the alias instead of the address
     SuiClientCommands::Gas {
         address: Some(KeyIdentity::Alias(alias)),
+        split: None,
+        merge: false,
+        gas_budget: None,
     }
     .execute(context)
     .await?
@@ -367,6 +388,82 @@ async fn test_gas_command() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_gas_split_and_merge() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await;
+    let rgp = test_cluster.get_reference_gas_price().await;
+    let address = test_cluster.get_address_0();
+    let context = &mut test_cluster.wallet;
+    let initial_count = context.gas_objects(address).await?.len();
+
+    let result = SuiClientCommands::Gas {
+        address: Some(KeyIdentity::Address(address)),
+        split: Some(3),
+        merge: false,
+        gas_budget: Some(rgp * TEST_ONLY_GAS_UNIT_FOR_SPLIT_COIN),
+    }
+    .execute(context)
+    .await?;
+    let SuiClientCommandResult::Gas(coins) = result else {
+        panic!("Command failed with unexpected result: {result:?}")
+    };
+    assert_eq!(coins.len(), initial_count + 2);
+
+    let result = SuiClientCommands::Gas {
+        address: Some(KeyIdentity::Address(address)),
+        split: None,
+        merge: true,
+        gas_budget: Some(rgp * TEST_ONLY_GAS_UNIT_FOR_SPLIT_COIN),
+    }
+    .execute(context)
+    .await?;
+    let SuiClientCommandResult::Gas(coins) = result else {
+        panic!("Command failed with unexpected result: {result:?}")
+    };
+    assert_eq!(coins.len(), 1);
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_ptb_split_and_transfer() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await;
+    let rgp = test_cluster.get_reference_gas_price().await;
+    let address = test_cluster.get_address_0();
+    let recipient = SuiAddress::random_for_testing_only();
+    let context = &mut test_cluster.wallet;
+
+    let args = [
+        "--split-coins",
+        "gas",
+        "[1000, 2000]",
+        "--assign",
+        "coins",
+        "--transfer-objects",
+        "[coins.0, coins.1]",
+        &recipient.to_string(),
+        "--gas-budget",
+        &(rgp * TEST_ONLY_GAS_UNIT_FOR_SPLIT_COIN).to_string(),
+    ];
+    let result = SuiClientCommands::Ptb {
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+    }
+    .execute(context)
+    .await?;
+    let SuiClientCommandResult::Ptb(response) = result else {
+        panic!("Command failed with unexpected result: {result:?}")
+    };
+    assert!(response.status_ok().unwrap());
+
+    let coins = context.gas_objects(recipient).await?;
+    let mut balances: Vec<_> = coins.iter().map(|(balance, _)| *balance).collect();
+    balances.sort();
+    assert_eq!(balances, vec![1000, 2000]);
+    assert!(!context.gas_objects(address).await?.is_empty());
+
+    Ok(())
+}
+
 #[sim_test]
 async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
     let mut test_cluster = TestClusterBuilder::new().build().await;
@@ -1875,6 +1972,83 @@ async fn test_switch_command() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_switch_env_restores_active_address() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await;
+    let rpc = cluster.rpc_url().to_string();
+    let addr2 = cluster.get_address_1();
+    let context = cluster.wallet_mut();
+    let addr1 = context.active_address()?;
+    let initial_env = context.config.active_env.clone().unwrap();
+
+    // An env with its own keystore, holding a single key.
+    let temp_dir = tempfile::tempdir()?;
+    let keystore_path = temp_dir.path().join("other.keystore");
+    let mut other_keystore = FileBasedKeystore::new(&keystore_path)?;
+    other_keystore.add_key(None, SuiKeyPair::Ed25519(get_key_pair().1))?;
+    let other_addr = other_keystore.addresses()[0];
+
+    for (alias, keystore) in [("same-keys", None), ("other-keys", Some(keystore_path))] {
+        SuiClientCommands::NewEnv {
+            alias: alias.to_string(),
+            rpc: rpc.clone(),
+            ws: None,
+            keystore,
+        }
+        .execute(context)
+        .await?;
+    }
+
+    // Without a keystore, the env keeps the active address, then remembers it.
+    SuiClientCommands::Switch {
+        address: None,
+        env: Some("same-keys".to_string()),
+    }
+    .execute(context)
+    .await?;
+    assert_eq!(context.active_address()?, addr1);
+    SuiClientCommands::Switch {
+        address: Some(KeyIdentity::Address(addr2)),
+        env: None,
+    }
+    .execute(context)
+    .await?;
+
+    SuiClientCommands::Switch {
+        address: None,
+        env: Some(initial_env.clone()),
+    }
+    .execute(context)
+    .await?;
+    assert_eq!(context.active_address()?, addr1);
+
+    // The env keystore replaces the current one.
+    SuiClientCommands::Switch {
+        address: None,
+        env: Some("other-keys".to_string()),
+    }
+    .execute(context)
+    .await?;
+    assert_eq!(context.active_address()?, other_addr);
+    assert_eq!(context.config.keystore.addresses(), vec![other_addr]);
+
+    // An env without a keystore keeps the one in use, and does not record it.
+    SuiClientCommands::Switch {
+        address: None,
+        env: Some("same-keys".to_string()),
+    }
+    .execute(context)
+    .await?;
+    assert_eq!(context.active_address()?, other_addr);
+    assert_eq!(context.config.keystore.addresses(), vec![other_addr]);
+    assert!(context
+        .config
+        .envs
+        .iter()
+        .all(|env| env.alias == "other-keys" || env.keystore.is_none()));
+    Ok(())
+}
+
 #[sim_test]
 async fn test_new_address_command_by_flag() -> Result<(), anyhow::Error> {
     let mut cluster = TestClusterBuilder::new().build().await;
@@ -2373,6 +2547,27 @@ async fn test_execute_signed_tx() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_execute_combined_signed_tx() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await;
+    let context = &mut test_cluster.wallet;
+    let mut txns = batch_make_transfer_transactions(context, 1).await;
+    let txn = txns.swap_remove(0);
+    let digest = *txn.digest();
+
+    let signed_tx_bytes = Base64::encode(bcs::to_bytes(txn.data())?);
+    let resp = SuiClientCommands::ExecuteCombinedSignedTx { signed_tx_bytes }
+        .execute(context)
+        .await?;
+    if let SuiClientCommandResult::ExecuteSignedTx(r) = resp {
+        assert_eq!(r.digest, digest);
+        assert!(r.status_ok().unwrap(), "Command failed: {:?}", r);
+    } else {
+        panic!("Command failed with unexpected result: {:?}", resp);
+    }
+    Ok(())
+}
+
 #[sim_test]
 async fn test_serialize_tx() -> Result<(), anyhow::Error> {
     let mut test_cluster = TestClusterBuilder::new().build().await;
@@ -2403,7 +2598,7 @@ async fn test_serialize_tx() -> Result<(), anyhow::Error> {
         .data;
     let coin = object_refs.get(1).unwrap().object().unwrap().object_id;
 
-    SuiClientCommands::TransferSui {
+    let result = SuiClientCommands::TransferSui {
         to: KeyIdentity::Address(address1),
         sui_coin_object_id: coin,
         gas_budget: rgp * TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
@@ -2413,6 +2608,10 @@ async fn test_serialize_tx() -> Result<(), anyhow::Error> {
     }
     .execute(context)
     .await?;
+    let SuiClientCommandResult::SerializedUnsignedTransaction(tx_data) = &result else {
+        panic!("Expected a serialized unsigned transaction, got {result:?}");
+    };
+    assert!(format!("{result}").contains(&format!("Transaction Digest: {}", tx_data.digest())));
 
     SuiClientCommands::TransferSui {
         to: KeyIdentity::Address(address1),
@@ -2674,3 +2873,77 @@ async fn key_identity_test() {
         get_identity_address(None, context).unwrap()
     );
 }
+
+#[test]
+fn test_json_output_flag() -> Result<(), anyhow::Error> {
+    // `--json` is global, so it is accepted before or after the subcommand.
+    for args in [
+        vec!["sui", "client", "--json", "gas"],
+        vec!["sui", "client", "gas", "--json"],
+        vec!["sui", "keytool", "list", "--json"],
+    ] {
+        assert!(SuiCommand::try_parse_from(&args)?.json_output(), "{args:?}");
+    }
+    assert!(!SuiCommand::try_parse_from(["sui", "client", "gas"])?.json_output());
+
+    let err = anyhow::anyhow!("connection refused").context("Cannot connect to the network");
+    let output = serde_json::to_value(sui::sui_commands::JsonErrorOutput::from(&err))?;
+    assert_eq!(
+        output,
+        json!({
+            "error": "Cannot connect to the network",
+            "causes": ["connection refused"],
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_completion() -> Result<(), anyhow::Error> {
+    use clap::CommandFactory;
+    use clap_complete::Shell;
+    use sui::completion::{cache_objects, completion_values, print_completions, CompletionValues};
+    use sui_config::Config;
+
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+        let mut out = vec![];
+        print_completions(shell, &mut SuiCommand::command(), &mut out);
+        let script = String::from_utf8(out)?;
+        assert!(script.contains("completion --values addresses"), "{shell}");
+        assert!(script.contains("completion --values objects"), "{shell}");
+    }
+
+    let temp_dir = tempfile::tempdir()?;
+    let mut keystore = FileBasedKeystore::new(&temp_dir.path().join(SUI_KEYSTORE_FILENAME))?;
+    keystore.add_key(
+        Some("alice".to_string()),
+        SuiKeyPair::Ed25519(get_key_pair().1),
+    )?;
+    let address = keystore.addresses()[0];
+    let bob = SuiAddress::random_for_testing_only();
+    keystore.address_book_mut().add("bob", bob)?;
+    keystore.address_book().save()?;
+    let config_path = temp_dir.path().join(SUI_CLIENT_CONFIG);
+    SuiClientConfig::new(keystore.into()).save(&config_path)?;
+
+    assert_eq!(
+        completion_values(CompletionValues::Addresses, &config_path),
+        vec![
+            address.to_string(),
+            "@alice".to_string(),
+            "@bob".to_string()
+        ]
+    );
+
+    assert!(completion_values(CompletionValues::Objects, &config_path).is_empty());
+    let coin = GasCoin::new(ObjectID::random(), 100);
+    cache_objects(
+        &config_path,
+        &SuiClientCommandResult::Gas(vec![coin.clone()]),
+    );
+    assert_eq!(
+        completion_values(CompletionValues::Objects, &config_path),
+        vec![coin.id().to_string()]
+    );
+    Ok(())
+}
@@ -936,6 +936,8 @@ impl TestClusterBuilder {
             alias: "localnet".to_string(),
             rpc: fullnode_handle.rpc_url.clone(),
             ws: Some(fullnode_handle.ws_url.clone()),
+            active_address: None,
+            keystore: None,
         });
         wallet_conf.active_env = Some("localnet".to_string());
 
@@ -0,0 +1,119 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::traits::ToFromBytes;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::Index;
+use proptest::strategy::ValueTree;
+use sui_types::crypto::{get_key_pair, AccountKeyPair, SignatureScheme};
+use sui_types::signature::GenericSignature;
+use sui_types::transaction::{Transaction, TransactionData};
+use sui_types::utils::to_sender_signed_transaction;
+
+use transaction_fuzzer::account_universe::AccountCurrent;
+use transaction_fuzzer::account_universe::AccountData;
+use transaction_fuzzer::{
+    executor::{assert_is_acceptable_result, Executor},
+    transaction_data_gen::transaction_data_gen,
+};
+
+const NUM_CASES: usize = 1000;
+
+/// Overwrites the bytes at the given positions.
+fn mutate_bytes(bytes: &mut [u8], mutations: &[(Index, u8)]) {
+    if bytes.is_empty() {
+        return;
+    }
+    for (index, byte) in mutations {
+        bytes[index.index(bytes.len())] = *byte;
+    }
+}
+
+fn gen_mutations() -> impl Strategy<Value = Vec<(Index, u8)>> {
+    vec((any::<Index>(), any::<u8>()), 1..8)
+}
+
+/// Signature bytes starting with the flag of any of the signature schemes, so that they reach the
+/// parser of the scheme.
+fn gen_signature_bytes() -> impl Strategy<Value = Vec<u8>> {
+    (
+        prop_oneof![
+            Just(SignatureScheme::ED25519),
+            Just(SignatureScheme::Secp256k1),
+            Just(SignatureScheme::Secp256r1),
+            Just(SignatureScheme::MultiSig),
+            Just(SignatureScheme::ZkLoginAuthenticator),
+        ],
+        vec(any::<u8>(), 0..512),
+    )
+        .prop_map(|(scheme, bytes)| {
+            let mut signature = vec![scheme.flag()];
+            signature.extend(bytes);
+            signature
+        })
+}
+
+#[test]
+#[cfg_attr(msim, ignore)]
+fn mutated_transaction_data() {
+    // Transaction data with corrupted bytes which still deserializes must be handled like any
+    // other transaction.
+    let mut exec = Executor::new();
+    let account = AccountCurrent::new(AccountData::new_random());
+    let strategy = (
+        transaction_data_gen(account.initial_data.account.address),
+        gen_mutations(),
+    );
+    let mut runner = proptest::test_runner::TestRunner::deterministic();
+    for _ in 0..NUM_CASES {
+        let (tx_data, mutations) = strategy.new_tree(&mut runner).unwrap().current();
+        let mut bytes = bcs::to_bytes(&tx_data).unwrap();
+        mutate_bytes(&mut bytes, &mutations);
+        let Ok(tx_data) = bcs::from_bytes::<TransactionData>(&bytes) else {
+            continue;
+        };
+        let signed_txn = to_sender_signed_transaction(tx_data, &account.initial_data.account.key);
+        let result = exec.execute_transaction(signed_txn);
+        assert_is_acceptable_result(&result);
+    }
+}
+
+#[test]
+#[cfg_attr(msim, ignore)]
+fn hostile_signatures() {
+    // Transactions signed by another key, with corrupted signatures or with arbitrary signature
+    // bytes must be rejected.
+    let mut exec = Executor::new();
+    let account = AccountCurrent::new(AccountData::new_random());
+    let (_, other_key): (_, AccountKeyPair) = get_key_pair();
+    let strategy = (
+        transaction_data_gen(account.initial_data.account.address),
+        gen_mutations(),
+        gen_signature_bytes(),
+    );
+    let mut runner = proptest::test_runner::TestRunner::deterministic();
+    for _ in 0..NUM_CASES {
+        let (tx_data, mutations, signature_bytes) =
+            strategy.new_tree(&mut runner).unwrap().current();
+
+        let signed_txn = to_sender_signed_transaction(tx_data.clone(), &other_key);
+        assert!(exec.execute_transaction(signed_txn).is_err());
+
+        let signed_txn =
+            to_sender_signed_transaction(tx_data.clone(), &account.initial_data.account.key);
+        let mut corrupted = signed_txn.data().tx_signatures()[0].as_ref().to_vec();
+        mutate_bytes(&mut corrupted, &mutations);
+        if corrupted.as_slice() != signed_txn.data().tx_signatures()[0].as_ref() {
+            if let Ok(signature) = GenericSignature::from_bytes(&corrupted) {
+                let txn = Transaction::from_generic_sig_data(tx_data.clone(), vec![signature]);
+                assert!(exec.execute_transaction(txn).is_err());
+            }
+        }
+
+        if let Ok(signature) = GenericSignature::from_bytes(&signature_bytes) {
+            let txn = Transaction::from_generic_sig_data(tx_data, vec![signature]);
+            assert!(exec.execute_transaction(txn).is_err());
+        }
+    }
+}
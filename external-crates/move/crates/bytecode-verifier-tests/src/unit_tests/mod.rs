@@ -53,5 +53,6 @@ pub(crate) fn production_config() -> VerifierConfig {
         max_constant_vector_len: Some(DEFAULT_MAX_CONSTANT_VECTOR_LEN),
         max_idenfitier_len: Some(DEFAULT_MAX_IDENTIFIER_LENGTH),
         allow_receiving_object_id: true,
+        reject_mutable_random_on_entry_functions: true,
     }
 }
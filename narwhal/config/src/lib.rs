@@ -67,6 +67,9 @@ pub enum ConfigError {
 
     #[error("Failed to write config file '{file}': {message}")]
     ExportError { file: String, message: String },
+
+    #[error("Invalid parameters: {0}")]
+    InvalidParameters(String),
 }
 
 #[derive(Error, Debug)]
@@ -358,6 +361,48 @@ impl Default for Parameters {
 }
 
 impl Parameters {
+    /// Checks that the parameters are consistent, so that misconfigured nodes fail on startup
+    /// rather than stall consensus.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let ensure = |condition: bool, message: &str| {
+            if condition {
+                Ok(())
+            } else {
+                Err(ConfigError::InvalidParameters(message.to_string()))
+            }
+        };
+        ensure(
+            self.header_num_of_batches_threshold > 0,
+            "header_num_of_batches_threshold must be positive",
+        )?;
+        ensure(
+            self.header_num_of_batches_threshold <= self.max_header_num_of_batches,
+            "header_num_of_batches_threshold must not exceed max_header_num_of_batches",
+        )?;
+        ensure(
+            !self.min_header_delay.is_zero(),
+            "min_header_delay must be positive",
+        )?;
+        ensure(
+            self.min_header_delay <= self.max_header_delay,
+            "min_header_delay must not exceed max_header_delay",
+        )?;
+        ensure(self.gc_depth > 0, "gc_depth must be positive")?;
+        ensure(
+            self.sync_retry_nodes > 0,
+            "sync_retry_nodes must be positive",
+        )?;
+        ensure(self.batch_size > 0, "batch_size must be positive")?;
+        ensure(
+            !self.max_batch_delay.is_zero(),
+            "max_batch_delay must be positive",
+        )?;
+        ensure(
+            self.max_concurrent_requests > 0,
+            "max_concurrent_requests must be positive",
+        )
+    }
+
     pub fn with_available_ports(&self) -> Self {
         let mut params = self.clone();
         params.prometheus_metrics = params.prometheus_metrics.with_available_port();
@@ -30,6 +30,7 @@ use std::{
     collections::{BTreeMap, HashMap},
     fs::File,
     io::Write,
+    time::Duration,
 };
 use tempfile::tempdir;
 use test_utils::CommitteeFixture;
@@ -177,6 +178,36 @@ fn parameters_snapshot_matches() {
     assert_json_snapshot!("parameters", parameters)
 }
 
+#[test]
+fn parameters_validation() {
+    assert!(Parameters::default().validate().is_ok());
+
+    let parameters = Parameters {
+        min_header_delay: Duration::from_secs(2),
+        max_header_delay: Duration::from_secs(1),
+        ..Parameters::default()
+    };
+    assert!(parameters.validate().is_err());
+
+    let parameters = Parameters {
+        header_num_of_batches_threshold: 2_000,
+        ..Parameters::default()
+    };
+    assert!(parameters.validate().is_err());
+
+    let parameters = Parameters {
+        batch_size: 0,
+        ..Parameters::default()
+    };
+    assert!(parameters.validate().is_err());
+
+    let parameters = Parameters {
+        max_batch_delay: Duration::ZERO,
+        ..Parameters::default()
+    };
+    assert!(parameters.validate().is_err());
+}
+
 #[test]
 fn parameters_import_snapshot_matches() {
     // GIVEN
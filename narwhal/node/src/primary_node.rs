@@ -3,9 +3,10 @@
 use crate::metrics::new_registry;
 use crate::{try_join_all, FuturesUnordered, NodeError};
 use anemo::PeerId;
-use config::{AuthorityIdentifier, ChainIdentifier, Committee, Parameters, WorkerCache};
+use config::{AuthorityIdentifier, ChainIdentifier, Committee, Epoch, Parameters, WorkerCache};
 use crypto::{KeyPair, NetworkKeyPair, PublicKey};
 use executor::{get_restored_consensus_output, ExecutionState, Executor, SubscriberResult};
+use fastcrypto::hash::Hash as _;
 use fastcrypto::traits::{KeyPair as _, VerifyingKey};
 use mysten_metrics::metered_channel;
 use mysten_metrics::{RegistryID, RegistryService};
@@ -17,12 +18,20 @@ use primary::{Primary, PrimaryChannelMetrics, NUM_SHUTDOWN_RECEIVERS};
 use prometheus::{IntGauge, Registry};
 use std::sync::Arc;
 use std::time::Instant;
-use storage::NodeStorage;
+use storage::{NodeStorage, StoreResult};
 use sui_protocol_config::ProtocolConfig;
 use tokio::sync::{watch, RwLock};
 use tokio::task::JoinHandle;
 use tracing::{info, instrument};
-use types::{Certificate, ConditionalBroadcastReceiver, PreSubscribedBroadcastSender, Round};
+use types::{
+    Certificate, CertificateDigest, ConditionalBroadcastReceiver, PreSubscribedBroadcastSender,
+    Round, SequenceNumber,
+};
+
+/// The window where the schedule change takes place in consensus. It represents number
+/// of committed sub dags.
+/// TODO: move this to node properties
+pub const CONSENSUS_SCHEDULE_CHANGE_SUB_DAGS: u64 = 300;
 
 struct PrimaryNodeInner {
     // The configuration parameters.
@@ -39,14 +48,34 @@ struct PrimaryNodeInner {
     tx_shutdown: Option<PreSubscribedBroadcastSender>,
     // Peer ID used for local connections.
     own_peer_id: Option<PeerId>,
+    // The store and the committee of the running node, to report the status of consensus.
+    store: Option<NodeStorage>,
+    committee: Option<Committee>,
 }
 
-impl PrimaryNodeInner {
-    /// The window where the schedule change takes place in consensus. It represents number
-    /// of committed sub dags.
-    /// TODO: move this to node properties
-    const CONSENSUS_SCHEDULE_CHANGE_SUB_DAGS: u64 = 300;
+/// A snapshot of the DAG of a running primary, used to diagnose consensus stalls.
+#[derive(Debug)]
+pub struct ConsensusStatus {
+    pub epoch: Epoch,
+    /// The highest round of the certificates in the DAG.
+    pub highest_round: Round,
+    /// The index of the latest committed sub dag, and the round of its leader.
+    pub latest_sub_dag: Option<(SequenceNumber, Round)>,
+    /// The latest certificate of every authority in the DAG.
+    pub frontier: Vec<AuthorityFrontier>,
+}
 
+#[derive(Debug)]
+pub struct AuthorityFrontier {
+    pub authority: AuthorityIdentifier,
+    pub hostname: String,
+    /// The round and digest of the latest certificate of the authority, if any.
+    pub last_certificate: Option<(Round, CertificateDigest)>,
+    /// The highest round of the certificates of the authority committed by consensus.
+    pub last_committed_round: Option<Round>,
+}
+
+impl PrimaryNodeInner {
     // Starts the primary node with the provided info. If the node is already running then this
     // method will return an error instead.
     #[instrument(level = "info", skip_all)]
@@ -77,6 +106,8 @@ impl PrimaryNodeInner {
         }
 
         self.own_peer_id = Some(PeerId(network_keypair.public().0.to_bytes()));
+        self.store = Some(store.clone());
+        self.committee = Some(committee.clone());
 
         // create a new registry
         let registry = new_registry();
@@ -140,6 +171,8 @@ impl PrimaryNodeInner {
         try_join_all(&mut self.handles).await.unwrap();
 
         self.swap_registry(None);
+        self.store = None;
+        self.committee = None;
 
         info!(
             "Narwhal primary shutdown is complete - took {} seconds",
@@ -158,6 +191,39 @@ impl PrimaryNodeInner {
         self.handles.iter().any(|h| !h.is_finished())
     }
 
+    // Reads the frontier of the DAG and the progress of consensus from the store of the node.
+    // Returns None if the node is not running.
+    fn consensus_status(&self) -> StoreResult<Option<ConsensusStatus>> {
+        let (Some(store), Some(committee)) = (self.store.as_ref(), self.committee.as_ref()) else {
+            return Ok(None);
+        };
+        let last_committed = store.consensus_store.read_last_committed();
+        let frontier = committee
+            .authorities()
+            .map(|authority| {
+                let last_certificate = store
+                    .certificate_store
+                    .last_round(authority.id())?
+                    .map(|certificate| (certificate.round(), certificate.digest()));
+                Ok(AuthorityFrontier {
+                    authority: authority.id(),
+                    hostname: authority.hostname().to_string(),
+                    last_certificate,
+                    last_committed_round: last_committed.get(&authority.id()).copied(),
+                })
+            })
+            .collect::<StoreResult<Vec<_>>>()?;
+        Ok(Some(ConsensusStatus {
+            epoch: committee.epoch(),
+            highest_round: store.certificate_store.highest_round_number(),
+            latest_sub_dag: store
+                .consensus_store
+                .get_latest_sub_dag()
+                .map(|commit| (commit.sub_dag_index(), commit.leader_round())),
+            frontier,
+        }))
+    }
+
     // Accepts an Option registry. If it's Some, then the new registry will be added in the
     // registry service and the registry_id will be updated. Also, any previous registry will
     // be removed. If None is passed, then the registry_id is updated to None and any old
@@ -338,7 +404,7 @@ impl PrimaryNodeInner {
             store.consensus_store.clone(),
             protocol_config.clone(),
             consensus_metrics.clone(),
-            Self::CONSENSUS_SCHEDULE_CHANGE_SUB_DAGS,
+            CONSENSUS_SCHEDULE_CHANGE_SUB_DAGS,
             leader_schedule.clone(),
         );
         let consensus_handles = Consensus::spawn(
@@ -394,6 +460,8 @@ impl PrimaryNode {
             client: None,
             tx_shutdown: None,
             own_peer_id: None,
+            store: None,
+            committee: None,
         };
 
         Self {
@@ -455,6 +523,11 @@ impl PrimaryNode {
         guard.wait().await
     }
 
+    pub async fn consensus_status(&self) -> StoreResult<Option<ConsensusStatus>> {
+        let guard = self.internal.read().await;
+        guard.consensus_status()
+    }
+
     pub async fn registry(&self) -> Option<(RegistryID, Registry)> {
         let guard = self.internal.read().await;
         guard.registry.clone()
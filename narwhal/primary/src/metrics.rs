@@ -319,6 +319,8 @@ pub struct PrimaryMetrics {
     pub duplicate_certificates_processed: IntCounter,
     /// The current Narwhal round in proposer
     pub current_round: IntGauge,
+    /// The number of rounds the proposer skipped when jumping ahead to the round of its parents.
+    pub proposer_rounds_skipped: IntCounter,
     /// Latency distribution for generating proposals
     pub proposal_latency: HistogramVec,
     /// The highest Narwhal round of certificates that have been accepted.
@@ -447,6 +449,12 @@ impl PrimaryMetrics {
                 registry
             )
             .unwrap(),
+            proposer_rounds_skipped: register_int_counter_with_registry!(
+                "proposer_rounds_skipped",
+                "Number of rounds the proposer skipped when jumping ahead to the round of its parents",
+                registry
+            )
+            .unwrap(),
             proposal_latency: register_histogram_vec_with_registry!(
                 "proposal_latency",
                 "Time distribution between node proposals",
@@ -652,6 +652,7 @@ impl Proposer {
                         Ordering::Greater => {
                             // We accept round bigger than our current round to jump ahead in case we were
                             // late (or just joined the network).
+                            self.metrics.proposer_rounds_skipped.inc_by(round - self.round - 1);
                             self.round = round;
                             let _ = self.tx_narwhal_round_updates.send(self.round);
                             self.last_parents = parents;
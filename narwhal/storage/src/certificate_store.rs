@@ -565,6 +565,30 @@ impl<T: Cache> CertificateStore<T> {
             .collect()
     }
 
+    /// Retrieves all the certificates of the provided round, sorted by origin.
+    pub fn at_round(&self, round: Round) -> StoreResult<Vec<Certificate>> {
+        let digests = self
+            .certificate_id_by_round
+            .unbounded_iter()
+            .skip_to(&(round, AuthorityIdentifier::default()))?
+            .take_while(|((r, _), _)| *r == round)
+            .map(|(_, digest)| digest)
+            .collect::<Vec<_>>();
+
+        self.certificates_by_id
+            .multi_get(digests.clone())?
+            .into_iter()
+            .map(|opt_cert| {
+                opt_cert.ok_or_else(|| {
+                    RocksDBError(format!(
+                        "Certificate with some digests not found, CertificateStore invariant violation: {:?}",
+                        digests
+                    ))
+                })
+            })
+            .collect()
+    }
+
     /// Retrieves origins with certificates in each round >= the provided round.
     pub fn origins_after_round(
         &self,
@@ -1058,6 +1082,14 @@ mod test {
         // AND none should be left in the original set
         assert!(certs_ids_over_cutoff_round.is_empty());
 
+        // WHEN get the certificates of a single round.
+        let result = store
+            .at_round(round_cutoff)
+            .expect("Error returned while reading at_round");
+        assert_eq!(result.len() as u64, certs_per_round);
+        assert!(result.iter().all(|c| c.round() == round_cutoff));
+        assert!(store.at_round(total_rounds + 1).unwrap().is_empty());
+
         // WHEN get rounds per origin.
         let rounds = store
             .origins_after_round(round_cutoff)
@@ -193,6 +193,7 @@ impl BatchFetcher {
         let max_timeout = Duration::from_secs(60);
         let mut timeout = Duration::from_secs(10);
         let mut attempt = 0usize;
+        let peer = worker.to_string();
         loop {
             attempt += 1;
             debug!(
@@ -221,12 +222,20 @@ impl BatchFetcher {
                             .worker_batch_fetch
                             .with_label_values(&["remote", "timeout"])
                             .inc();
+                        self.metrics
+                            .worker_batch_fetch_failures
+                            .with_label_values(&[&peer, "timeout"])
+                            .inc();
                         debug!("Timed out retrieving payloads {digests:?} from {worker} attempt {attempt}: {err}");
                     } else if err.to_string().contains("[Protocol violation]") {
                         self.metrics
                             .worker_batch_fetch
                             .with_label_values(&["remote", "fail"])
                             .inc();
+                        self.metrics
+                            .worker_batch_fetch_failures
+                            .with_label_values(&[&peer, "protocol_violation"])
+                            .inc();
                         debug!("Failed retrieving payloads {digests:?} from possibly byzantine {worker} attempt {attempt}: {err}");
                         // Do not bother retrying if the remote worker is byzantine.
                         return HashMap::new();
@@ -235,6 +244,10 @@ impl BatchFetcher {
                             .worker_batch_fetch
                             .with_label_values(&["remote", "fail"])
                             .inc();
+                        self.metrics
+                            .worker_batch_fetch_failures
+                            .with_label_values(&[&peer, "error"])
+                            .inc();
                         debug!("Error retrieving payloads {digests:?} from {worker} attempt {attempt}: {err}");
                     }
                 }
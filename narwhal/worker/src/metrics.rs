@@ -67,6 +67,8 @@ pub struct WorkerMetrics {
     pub batch_broadcast_quorum_latency: Histogram,
     /// Counter of remote/local batch fetch statuses.
     pub worker_batch_fetch: IntCounterVec,
+    /// Counter of the failures to fetch batches from remote workers, per peer and reason.
+    pub worker_batch_fetch_failures: IntCounterVec,
     /// Time it takes to download a payload from local worker peer
     pub worker_local_fetch_latency: Histogram,
     /// Time it takes to download a payload from remote peer
@@ -129,6 +131,13 @@ impl WorkerMetrics {
                 registry
             )
             .unwrap(),
+            worker_batch_fetch_failures: register_int_counter_vec_with_registry!(
+                "worker_batch_fetch_failures",
+                "Counter of the failures to fetch batches from remote workers, per peer and reason",
+                &["peer", "reason"],
+                registry
+            )
+            .unwrap(),
             worker_local_fetch_latency: register_histogram_with_registry!(
                 "worker_local_fetch_latency",
                 "Time it takes to download a payload from local storage",
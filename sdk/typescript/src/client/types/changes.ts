@@ -9,3 +9,4 @@ export type SuiObjectChangeMutated = Extract<SuiObjectChange, { type: 'mutated'
 export type SuiObjectChangeDeleted = Extract<SuiObjectChange, { type: 'deleted' }>;
 export type SuiObjectChangeWrapped = Extract<SuiObjectChange, { type: 'wrapped' }>;
 export type SuiObjectChangeCreated = Extract<SuiObjectChange, { type: 'created' }>;
+export type SuiObjectChangeUnwrapped = Extract<SuiObjectChange, { type: 'unwrapped' }>;
@@ -470,6 +470,15 @@ export type SuiObjectChange =
 			sender: string;
 			type: 'created';
 			version: string;
+	  } /** Object unwrapped from another object */
+	| {
+			digest: string;
+			objectId: string;
+			objectType: string;
+			owner: ObjectOwner;
+			sender: string;
+			type: 'unwrapped';
+			version: string;
 	  };
 export interface SuiObjectData {
 	/**
@@ -73,6 +73,7 @@ mod checked {
         protocol_config: &'r ProtocolConfig,
         metrics: Arc<LimitsMetrics>,
         current_epoch_id: EpochId,
+        gas_price: u64,
     ) -> NativeContextExtensions<'r> {
         let mut extensions = NativeContextExtensions::default();
         extensions.add(ObjectRuntime::new(
@@ -82,6 +83,7 @@ mod checked {
             protocol_config,
             metrics,
             current_epoch_id,
+            gas_price,
         ));
         extensions.add(NativesCostTable::from_protocol_config(protocol_config));
         extensions
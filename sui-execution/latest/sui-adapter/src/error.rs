@@ -11,7 +11,7 @@ use move_core_types::{
     vm_status::{StatusCode, StatusType},
 };
 use move_vm_runtime::move_vm::MoveVM;
-use sui_types::error::{ExecutionError, SuiError};
+use sui_types::error::{ExecutionError, SuiError, VMMemoryLimitExceededSubStatusCode};
 use sui_types::execution_status::{ExecutionFailureStatus, MoveLocation, MoveLocationOpt};
 
 pub(crate) fn convert_vm_error<S: MoveResolver<Err = SuiError>>(
@@ -56,6 +56,12 @@ pub(crate) fn convert_vm_error<S: MoveResolver<Err = SuiError>>(
             )
         }
         (StatusCode::OUT_OF_GAS, _, _) => ExecutionFailureStatus::InsufficientGas,
+        (StatusCode::MEMORY_LIMIT_EXCEEDED, Some(sub_status), _)
+            if sub_status
+                == VMMemoryLimitExceededSubStatusCode::INSTRUCTION_LIMIT_EXCEEDED as u64 =>
+        {
+            ExecutionFailureStatus::InstructionLimitExceeded
+        }
         (_, _, location) => match error.major_status().status_type() {
             StatusType::Execution => {
                 debug_assert!(error.major_status() != StatusCode::ABORTED);
@@ -100,6 +100,10 @@ pub mod checked {
             self.gas_status.is_unmetered()
         }
 
+        pub fn gas_price(&self) -> u64 {
+            self.gas_status.gas_price()
+        }
+
         pub fn move_gas_status(&self) -> &GasStatus {
             self.gas_status.move_gas_status()
         }
@@ -198,6 +198,7 @@ mod checked {
                 protocol_config,
                 metrics.clone(),
                 tx_context.epoch(),
+                gas_charger.gas_price(),
             );
 
             // Set the profiler if in debug mode
@@ -488,6 +488,7 @@ mod checked {
         // For newly published packages, runtime ID matches storage ID.
         let storage_id = runtime_id;
         let dependencies = fetch_packages(context, &dep_ids)?;
+        check_package_dependencies(context, runtime_id, &modules, &dependencies)?;
         let package =
             context.new_package(&modules, dependencies.iter().map(|p| p.move_package()))?;
 
@@ -596,6 +597,7 @@ mod checked {
         let storage_id = context.tx_context.fresh_id();
 
         let dependencies = fetch_packages(context, &dep_ids)?;
+        check_package_dependencies(context, runtime_id, &modules, &dependencies)?;
         let package = context.upgrade_package(
             storage_id,
             current_package.move_package(),
@@ -741,6 +743,66 @@ mod checked {
         }
     }
 
+    /// Checks that the dependencies of a published or upgraded package link the modules it
+    /// imports: no two dependencies are versions of the same package, and every module imported
+    /// from a dependency exists in the version of it that is linked. Without this, an import of a
+    /// missing module would only fail when the package is loaded by the VM.
+    fn check_package_dependencies(
+        context: &ExecutionContext<'_, '_, '_>,
+        runtime_id: ObjectID,
+        modules: &[CompiledModule],
+        dependencies: &[PackageObject],
+    ) -> Result<(), ExecutionError> {
+        if !context.protocol_config.check_package_dependencies() {
+            return Ok(());
+        }
+
+        let mut linkage: BTreeMap<ObjectID, &MovePackage> = BTreeMap::new();
+        for dependency in dependencies {
+            let package = dependency.move_package();
+            if let Some(other) = linkage.insert(package.original_package_id(), package) {
+                return Err(ExecutionError::new_with_source(
+                    ExecutionErrorKind::PublishUpgradeMissingDependency,
+                    format!(
+                        "Dependencies {} and {} are versions of the same package {}",
+                        other.id(),
+                        package.id(),
+                        package.original_package_id()
+                    ),
+                ));
+            }
+        }
+
+        for module in modules {
+            for dependency in module.immediate_dependencies() {
+                let address = ObjectID::from(*dependency.address());
+                if address == runtime_id {
+                    continue;
+                }
+                // Immediate dependencies that are not linked at all are reported when building
+                // the linkage table.
+                let Some(package) = linkage.get(&address) else {
+                    continue;
+                };
+                if !package
+                    .serialized_module_map()
+                    .contains_key(dependency.name().as_str())
+                {
+                    return Err(ExecutionError::new_with_source(
+                        ExecutionErrorKind::PublishUpgradeMissingDependency,
+                        format!(
+                            "Module {} imports {}, which is not in dependency {}",
+                            module.self_id(),
+                            dependency,
+                            package.id()
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /***************************************************************************************************
      * Move execution
      **************************************************************************************************/
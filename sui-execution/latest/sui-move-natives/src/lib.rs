@@ -30,7 +30,7 @@ use self::{
     transfer::{
         TransferFreezeObjectCostParams, TransferInternalCostParams, TransferShareObjectCostParams,
     },
-    tx_context::TxContextDeriveIdCostParams,
+    tx_context::{TxContextDeriveIdCostParams, TxContextGasPriceCostParams},
     types::TypesIsOneTimeWitnessCostParams,
     validator::ValidatorValidateMetadataBcsCostParams,
 };
@@ -103,6 +103,7 @@ pub struct NativesCostTable {
 
     // TxContext
     pub tx_context_derive_id_cost_params: TxContextDeriveIdCostParams,
+    pub tx_context_gas_price_cost_params: TxContextGasPriceCostParams,
 
     // Type
     pub type_is_one_time_witness_cost_params: TypesIsOneTimeWitnessCostParams,
@@ -315,6 +316,12 @@ impl NativesCostTable {
                     .tx_context_derive_id_cost_base()
                     .into(),
             },
+            tx_context_gas_price_cost_params: TxContextGasPriceCostParams {
+                tx_context_gas_price_cost_base: protocol_config
+                    .tx_context_gas_price_cost_base_as_option()
+                    .unwrap_or(0)
+                    .into(),
+            },
             type_is_one_time_witness_cost_params: TypesIsOneTimeWitnessCostParams {
                 types_is_one_time_witness_cost_base: protocol_config
                     .types_is_one_time_witness_cost_base()
@@ -698,6 +705,11 @@ pub fn all_natives(silent: bool) -> NativeFunctionTable {
             "derive_id",
             make_native!(tx_context::derive_id),
         ),
+        (
+            "tx_context",
+            "native_gas_price",
+            make_native!(tx_context::gas_price),
+        ),
         (
             "types",
             "is_one_time_witness",
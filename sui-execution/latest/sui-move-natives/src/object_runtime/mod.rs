@@ -104,6 +104,8 @@ pub struct ObjectRuntime<'a> {
 
     pub(crate) protocol_config: &'a ProtocolConfig,
     pub(crate) metrics: Arc<LimitsMetrics>,
+    // gas price of the transaction, as read by `tx_context::gas_price`
+    pub(crate) gas_price: u64,
 }
 
 pub enum TransferResult {
@@ -132,6 +134,7 @@ impl<'a> ObjectRuntime<'a> {
         protocol_config: &'a ProtocolConfig,
         metrics: Arc<LimitsMetrics>,
         epoch_id: EpochId,
+        gas_price: u64,
     ) -> Self {
         let mut input_object_owners = BTreeMap::new();
         let mut root_version = BTreeMap::new();
@@ -175,6 +178,7 @@ impl<'a> ObjectRuntime<'a> {
             is_metered,
             protocol_config,
             metrics,
+            gas_price,
         }
     }
 
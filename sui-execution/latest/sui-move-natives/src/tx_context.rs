@@ -54,3 +54,39 @@ pub fn derive_id(
         smallvec![Value::address(address)],
     ))
 }
+
+#[derive(Clone)]
+pub struct TxContextGasPriceCostParams {
+    pub tx_context_gas_price_cost_base: InternalGas,
+}
+/***************************************************************************************************
+ * native fun native_gas_price
+ * Implementation of the Move native function `fun native_gas_price(): u64`
+ *   gas cost: tx_context_gas_price_cost_base                | we operate on fixed size data structures
+ **************************************************************************************************/
+pub fn gas_price(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.is_empty());
+
+    let tx_context_gas_price_cost_params = context
+        .extensions_mut()
+        .get::<NativesCostTable>()
+        .tx_context_gas_price_cost_params
+        .clone();
+    native_charge_gas_early_exit!(
+        context,
+        tx_context_gas_price_cost_params.tx_context_gas_price_cost_base
+    );
+
+    let obj_runtime: &ObjectRuntime = context.extensions().get();
+    let gas_price = obj_runtime.gas_price;
+
+    Ok(NativeResult::ok(
+        context.gas_used(),
+        smallvec![Value::u64(gas_price)],
+    ))
+}